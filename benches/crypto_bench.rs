@@ -59,6 +59,30 @@ fn symmetric_encryption_benchmark(c: &mut Criterion) {
     group.finish();
 }
 
+fn batch_encryption_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_encryption");
+    
+    // 1000 database-sized fields of 64 bytes
+    let fields = vec![vec![0u8; 64]; 1000];
+    let records: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+    let key = AesGcm::generate_key().unwrap();
+    
+    group.bench_function("AES-256-GCM_encrypt_1000x64_one_shot", |b| {
+        b.iter(|| {
+            records.iter().map(|record| AesGcm::encrypt(black_box(record), black_box(&key)).unwrap()).collect::<Vec<_>>()
+        })
+    });
+    
+    let context = AesGcmContext::new(&key).unwrap();
+    group.bench_function("AES-256-GCM_encrypt_1000x64_batch", |b| {
+        b.iter(|| {
+            context.encrypt_batch(black_box(&records)).unwrap()
+        })
+    });
+    
+    group.finish();
+}
+
 fn hashing_benchmark(c: &mut Criterion) {
     let mut group = c.benchmark_group("hashing");
     
@@ -125,6 +149,13 @@ fn signature_benchmark(c: &mut Criterion) {
         })
     });
     
+    let ed25519_context = Ed25519VerifierContext::new(ed25519_keypair.verifying_key());
+    group.bench_function("Ed25519_verify_precomputed", |b| {
+        b.iter(|| {
+            ed25519_context.verify(black_box(message), black_box(&ed25519_signature)).unwrap()
+        })
+    });
+    
     // ECDSA P-256
     let ecdsa_keypair = EcdsaCrypto::generate_keypair().unwrap();
     group.bench_function("ECDSA_P256_sign", |b| {
@@ -144,6 +175,13 @@ fn signature_benchmark(c: &mut Criterion) {
         })
     });
     
+    let ecdsa_context = EcdsaVerifierContext::new(ecdsa_keypair.verifying_key());
+    group.bench_function("ECDSA_P256_verify_precomputed", |b| {
+        b.iter(|| {
+            ecdsa_context.verify(black_box(message), black_box(&ecdsa_signature)).unwrap()
+        })
+    });
+    
     group.finish();
 }
 
@@ -158,6 +196,13 @@ fn key_derivation_benchmark(c: &mut Criterion) {
             Argon2Kdf::derive_key(black_box(password), black_box(&salt), black_box(32)).unwrap()
         })
     });
+
+    let mut argon2 = Argon2Context::default();
+    group.bench_function("Argon2_context_derive_32bytes", |b| {
+        b.iter(|| {
+            argon2.derive_key(black_box(password), black_box(&salt), black_box(32)).unwrap()
+        })
+    });
     
     group.bench_function("PBKDF2_SHA256_100k_32bytes", |b| {
         b.iter(|| {
@@ -177,6 +222,7 @@ fn key_derivation_benchmark(c: &mut Criterion) {
 criterion_group!(
     benches,
     symmetric_encryption_benchmark,
+    batch_encryption_benchmark,
     hashing_benchmark,
     signature_benchmark,
     key_derivation_benchmark
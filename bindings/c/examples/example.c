@@ -0,0 +1,64 @@
+/*
+ * Build: cargo build --release
+ *        cc examples/example.c -Iinclude -Ltarget/release -lsilver -o example
+ * Run:   LD_LIBRARY_PATH=target/release ./example
+ */
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#include "libsilver.h"
+
+static int check(libsilver_status status, const char *what) {
+    if (status != LIBSILVER_STATUS_OK) {
+        const char *detail = libsilver_last_error_message();
+        fprintf(stderr, "%s: %s (%s)\n", what, libsilver_status_message(status), detail ? detail : "");
+        return 0;
+    }
+    return 1;
+}
+
+int main(void) {
+    const char *message = "Hello from C";
+    size_t message_len = strlen(message);
+    printf("libsilver %s\n", libsilver_version());
+
+    uint8_t key[LIBSILVER_SYMMETRIC_KEY_SIZE];
+    if (!check(libsilver_random_bytes(key, sizeof key), "random")) return 1;
+
+    /* Query the ciphertext size, then encrypt into an exactly sized buffer */
+    size_t ciphertext_len = 0;
+    libsilver_aes_gcm_encrypt(key, sizeof key, (const uint8_t *)message, message_len, NULL, 0, NULL, &ciphertext_len);
+    uint8_t *ciphertext = malloc(ciphertext_len);
+    if (!check(libsilver_aes_gcm_encrypt(key, sizeof key, (const uint8_t *)message, message_len, NULL, 0, ciphertext, &ciphertext_len), "encrypt")) return 1;
+
+    char plaintext[64];
+    size_t plaintext_len = sizeof plaintext - 1;
+    if (!check(libsilver_aes_gcm_decrypt(key, sizeof key, ciphertext, ciphertext_len, NULL, 0, (uint8_t *)plaintext, &plaintext_len), "decrypt")) return 1;
+    plaintext[plaintext_len] = '\0';
+    printf("AES-256-GCM round trip: %s (%zu ciphertext bytes)\n", plaintext, ciphertext_len);
+
+    /* Tampering is detected */
+    ciphertext[ciphertext_len - 1] ^= 1;
+    plaintext_len = sizeof plaintext - 1;
+    libsilver_status status = libsilver_aes_gcm_decrypt(key, sizeof key, ciphertext, ciphertext_len, NULL, 0, (uint8_t *)plaintext, &plaintext_len);
+    printf("Tampered ciphertext: %s\n", libsilver_status_message(status));
+    free(ciphertext);
+
+    uint8_t digest[32];
+    size_t digest_len = sizeof digest;
+    if (!check(libsilver_sha256((const uint8_t *)message, message_len, digest, &digest_len), "sha256")) return 1;
+    printf("SHA-256: ");
+    for (size_t i = 0; i < digest_len; i++) printf("%02x", digest[i]);
+    printf("\n");
+
+    uint8_t private_key[LIBSILVER_ED25519_KEY_SIZE], public_key[LIBSILVER_ED25519_KEY_SIZE];
+    uint8_t signature[LIBSILVER_ED25519_SIGNATURE_SIZE];
+    size_t signature_len = sizeof signature;
+    if (!check(libsilver_ed25519_generate_keypair(private_key, public_key), "keygen")) return 1;
+    if (!check(libsilver_ed25519_sign(private_key, sizeof private_key, (const uint8_t *)message, message_len, signature, &signature_len), "sign")) return 1;
+    status = libsilver_ed25519_verify(public_key, sizeof public_key, (const uint8_t *)message, message_len, signature, signature_len);
+    printf("Ed25519 signature: %s\n", status == LIBSILVER_STATUS_OK ? "valid" : "invalid");
+
+    return status == LIBSILVER_STATUS_OK ? 0 : 1;
+}
@@ -0,0 +1,376 @@
+/* LibSilver C ABI - MIT License */
+
+#ifndef LIBSILVER_H
+#define LIBSILVER_H
+
+/* Generated with cbindgen:0.26.0 */
+
+/* Generated by cbindgen from bindings/c/src. Do not edit by hand. */
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+/**
+ * Size in bytes of AES-256-GCM and ChaCha20-Poly1305 keys
+ */
+#define LIBSILVER_SYMMETRIC_KEY_SIZE 32
+
+/**
+ * Bytes added to the plaintext by AEAD encryption (12-byte nonce + 16-byte tag)
+ */
+#define LIBSILVER_AEAD_OVERHEAD 28
+
+/**
+ * Size in bytes of Ed25519 private and public keys
+ */
+#define LIBSILVER_ED25519_KEY_SIZE 32
+
+/**
+ * Size in bytes of an Ed25519 signature
+ */
+#define LIBSILVER_ED25519_SIGNATURE_SIZE 64
+
+/**
+ * Result of every libsilver C function
+ */
+typedef enum {
+  /**
+   * Success
+   */
+  LIBSILVER_STATUS_OK = 0,
+  /**
+   * A required pointer was NULL
+   */
+  LIBSILVER_STATUS_NULL_POINTER = 1,
+  /**
+   * An output buffer was too small; the required size was written to its length
+   */
+  LIBSILVER_STATUS_BUFFER_TOO_SMALL = 2,
+  /**
+   * Invalid input data or parameters
+   */
+  LIBSILVER_STATUS_INVALID_INPUT = 3,
+  /**
+   * Invalid key
+   */
+  LIBSILVER_STATUS_INVALID_KEY = 4,
+  /**
+   * Encryption failed
+   */
+  LIBSILVER_STATUS_ENCRYPTION_FAILED = 5,
+  /**
+   * Decryption or authentication failed
+   */
+  LIBSILVER_STATUS_DECRYPTION_FAILED = 6,
+  /**
+   * Key generation failed
+   */
+  LIBSILVER_STATUS_KEY_GENERATION_FAILED = 7,
+  /**
+   * Signature generation failed
+   */
+  LIBSILVER_STATUS_SIGNATURE_FAILED = 8,
+  /**
+   * Signature verification failed or the signature is invalid
+   */
+  LIBSILVER_STATUS_VERIFICATION_FAILED = 9,
+  /**
+   * Hash operation failed
+   */
+  LIBSILVER_STATUS_HASH_FAILED = 10,
+  /**
+   * Key derivation failed
+   */
+  LIBSILVER_STATUS_KEY_DERIVATION_FAILED = 11,
+  /**
+   * Random number generation failed
+   */
+  LIBSILVER_STATUS_RANDOM_GENERATION_FAILED = 12,
+  /**
+   * Encoding or decoding failed
+   */
+  LIBSILVER_STATUS_ENCODING_FAILED = 13,
+  /**
+   * I/O operation failed
+   */
+  LIBSILVER_STATUS_IO_FAILED = 14,
+  /**
+   * Internal error, including a caught panic
+   */
+  LIBSILVER_STATUS_INTERNAL_ERROR = 15,
+} libsilver_status;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Library version as a NUL-terminated string
+ */
+const char *libsilver_version(void);
+
+/**
+ * Static description of a status code
+ */
+const char *libsilver_status_message(libsilver_status status);
+
+/**
+ * Detailed message for the last error on this thread, or NULL
+ *
+ * The pointer stays valid until the next libsilver call on the same thread.
+ */
+const char *libsilver_last_error_message(void);
+
+/**
+ * Fill `out` with `out_len` cryptographically secure random bytes
+ */
+libsilver_status libsilver_random_bytes(uint8_t *out, size_t out_len);
+
+/**
+ * Compare two buffers in constant time; different lengths compare unequal
+ */
+bool libsilver_constant_time_eq(const uint8_t *a, size_t a_len, const uint8_t *b, size_t b_len);
+
+/**
+ * Generate an Ed25519 key pair into two LIBSILVER_ED25519_KEY_SIZE-byte buffers
+ */
+libsilver_status libsilver_ed25519_generate_keypair(uint8_t *private_key_out,
+                                                    uint8_t *public_key_out);
+
+/**
+ * Compute the LIBSILVER_ED25519_KEY_SIZE-byte public key of a 32-byte Ed25519 private key
+ */
+libsilver_status libsilver_ed25519_public_key(const uint8_t *private_key,
+                                              size_t private_key_len,
+                                              uint8_t *public_key_out);
+
+/**
+ * Upper bound on the PEM size of either half of an RSA key pair with `bits`-bit modulus
+ *
+ * Buffers of this size are always large enough for `libsilver_rsa_generate_keypair`.
+ */
+size_t libsilver_rsa_pem_max_size(size_t bits);
+
+/**
+ * Generate an RSA key pair as PKCS#8 private and SPKI public PEM (not NUL-terminated)
+ *
+ * Both capacities are checked against `libsilver_rsa_pem_max_size(bits)` before the
+ * (slow) generation starts, so a too-small buffer never discards a generated key.
+ */
+libsilver_status libsilver_rsa_generate_keypair(size_t bits,
+                                                uint8_t *private_pem_out,
+                                                size_t *private_pem_len,
+                                                uint8_t *public_pem_out,
+                                                size_t *public_pem_len);
+
+/**
+ * Encrypt with AES-256-GCM; output is nonce || ciphertext || tag
+ */
+libsilver_status libsilver_aes_gcm_encrypt(const uint8_t *key,
+                                           size_t key_len,
+                                           const uint8_t *plaintext,
+                                           size_t plaintext_len,
+                                           const uint8_t *aad,
+                                           size_t aad_len,
+                                           uint8_t *out,
+                                           size_t *out_len);
+
+/**
+ * Decrypt AES-256-GCM nonce || ciphertext || tag
+ */
+libsilver_status libsilver_aes_gcm_decrypt(const uint8_t *key,
+                                           size_t key_len,
+                                           const uint8_t *ciphertext,
+                                           size_t ciphertext_len,
+                                           const uint8_t *aad,
+                                           size_t aad_len,
+                                           uint8_t *out,
+                                           size_t *out_len);
+
+/**
+ * Encrypt with ChaCha20-Poly1305; output is nonce || ciphertext || tag
+ */
+libsilver_status libsilver_chacha20_poly1305_encrypt(const uint8_t *key,
+                                                     size_t key_len,
+                                                     const uint8_t *plaintext,
+                                                     size_t plaintext_len,
+                                                     const uint8_t *aad,
+                                                     size_t aad_len,
+                                                     uint8_t *out,
+                                                     size_t *out_len);
+
+/**
+ * Decrypt ChaCha20-Poly1305 nonce || ciphertext || tag
+ */
+libsilver_status libsilver_chacha20_poly1305_decrypt(const uint8_t *key,
+                                                     size_t key_len,
+                                                     const uint8_t *ciphertext,
+                                                     size_t ciphertext_len,
+                                                     const uint8_t *aad,
+                                                     size_t aad_len,
+                                                     uint8_t *out,
+                                                     size_t *out_len);
+
+/**
+ * SHA-256 (32-byte output)
+ */
+libsilver_status libsilver_sha256(const uint8_t *data,
+                                  size_t data_len,
+                                  uint8_t *out,
+                                  size_t *out_len);
+
+/**
+ * SHA-512 (64-byte output)
+ */
+libsilver_status libsilver_sha512(const uint8_t *data,
+                                  size_t data_len,
+                                  uint8_t *out,
+                                  size_t *out_len);
+
+/**
+ * SHA3-256 (32-byte output)
+ */
+libsilver_status libsilver_sha3_256(const uint8_t *data,
+                                    size_t data_len,
+                                    uint8_t *out,
+                                    size_t *out_len);
+
+/**
+ * SHA3-512 (64-byte output)
+ */
+libsilver_status libsilver_sha3_512(const uint8_t *data,
+                                    size_t data_len,
+                                    uint8_t *out,
+                                    size_t *out_len);
+
+/**
+ * BLAKE3 (32-byte output)
+ */
+libsilver_status libsilver_blake3(const uint8_t *data,
+                                  size_t data_len,
+                                  uint8_t *out,
+                                  size_t *out_len);
+
+/**
+ * HMAC-SHA256 (32-byte output)
+ */
+libsilver_status libsilver_hmac_sha256(const uint8_t *key,
+                                       size_t key_len,
+                                       const uint8_t *message,
+                                       size_t message_len,
+                                       uint8_t *out,
+                                       size_t *out_len);
+
+/**
+ * HMAC-SHA512 (64-byte output)
+ */
+libsilver_status libsilver_hmac_sha512(const uint8_t *key,
+                                       size_t key_len,
+                                       const uint8_t *message,
+                                       size_t message_len,
+                                       uint8_t *out,
+                                       size_t *out_len);
+
+/**
+ * Sign a message with a 32-byte Ed25519 private key (64-byte output)
+ */
+libsilver_status libsilver_ed25519_sign(const uint8_t *private_key,
+                                        size_t private_key_len,
+                                        const uint8_t *message,
+                                        size_t message_len,
+                                        uint8_t *out,
+                                        size_t *out_len);
+
+/**
+ * Verify an Ed25519 signature against a 32-byte public key
+ *
+ * Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+ */
+libsilver_status libsilver_ed25519_verify(const uint8_t *public_key,
+                                          size_t public_key_len,
+                                          const uint8_t *message,
+                                          size_t message_len,
+                                          const uint8_t *signature,
+                                          size_t signature_len);
+
+/**
+ * Sign a message with a 32-byte ECDSA P-256 private key (64-byte r || s output)
+ */
+libsilver_status libsilver_ecdsa_p256_sign(const uint8_t *private_key,
+                                           size_t private_key_len,
+                                           const uint8_t *message,
+                                           size_t message_len,
+                                           uint8_t *out,
+                                           size_t *out_len);
+
+/**
+ * Verify an ECDSA P-256 signature against a SEC1 public key
+ *
+ * Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+ */
+libsilver_status libsilver_ecdsa_p256_verify(const uint8_t *public_key,
+                                             size_t public_key_len,
+                                             const uint8_t *message,
+                                             size_t message_len,
+                                             const uint8_t *signature,
+                                             size_t signature_len);
+
+/**
+ * Derive a key from a password with Argon2id (libsilver default cost)
+ */
+libsilver_status libsilver_argon2id(const uint8_t *password,
+                                    size_t password_len,
+                                    const uint8_t *salt,
+                                    size_t salt_len,
+                                    uint8_t *out,
+                                    size_t out_len);
+
+/**
+ * Hash a password with Argon2id into a PHC string for storage
+ *
+ * The output is UTF-8 and not NUL-terminated.
+ */
+libsilver_status libsilver_argon2_hash_password(const uint8_t *password,
+                                                size_t password_len,
+                                                uint8_t *out,
+                                                size_t *out_len);
+
+/**
+ * Check a password against an Argon2 PHC string
+ *
+ * Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+ */
+libsilver_status libsilver_argon2_verify_password(const uint8_t *password,
+                                                  size_t password_len,
+                                                  const uint8_t *hash,
+                                                  size_t hash_len);
+
+/**
+ * Derive a key from a password with PBKDF2-HMAC-SHA256
+ */
+libsilver_status libsilver_pbkdf2_sha256(const uint8_t *password,
+                                         size_t password_len,
+                                         const uint8_t *salt,
+                                         size_t salt_len,
+                                         uint32_t iterations,
+                                         uint8_t *out,
+                                         size_t out_len);
+
+/**
+ * Derive a key with HKDF-SHA256; an absent salt uses the RFC 5869 default
+ */
+libsilver_status libsilver_hkdf_sha256(const uint8_t *input_key,
+                                       size_t input_key_len,
+                                       const uint8_t *salt,
+                                       size_t salt_len,
+                                       const uint8_t *info,
+                                       size_t info_len,
+                                       uint8_t *out,
+                                       size_t out_len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* LIBSILVER_H */
@@ -0,0 +1,108 @@
+//! Renders `libsilver::api::FUNCTIONS` into `src/generated.rs`
+//!
+//! The generated file is checked in so cbindgen sees plain functions. The
+//! test below fails when it is out of date; set `LIBSILVER_UPDATE_BINDINGS=1`
+//! to rewrite it.
+
+use libsilver::api::{ApiFunction, ApiType, FUNCTIONS};
+use std::fmt::Write as _;
+use std::path::Path;
+
+fn render_function(function: &ApiFunction) -> String {
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for param in function.params {
+        let name = param.name;
+        match param.ty {
+            ApiType::Bytes => {
+                params.push(format!("{name}: *const u8, {name}_len: usize,"));
+                args.push(format!("input({name}, {name}_len)?"));
+            }
+            ApiType::OptionalBytes => {
+                params.push(format!("{name}: *const u8, {name}_len: usize,"));
+                args.push(format!("optional_input({name}, {name}_len)?"));
+            }
+            ApiType::Str => {
+                params.push(format!("{name}: *const u8, {name}_len: usize,"));
+                args.push(format!("input_str({name}, {name}_len)?"));
+            }
+            ApiType::U32 => {
+                params.push(format!("{name}: u32,"));
+                args.push(name.to_string());
+            }
+            ApiType::OutputLength => args.push("out_len".to_string()),
+            ApiType::SecretBytes | ApiType::Bool => unreachable!("{} is not a parameter kind", function.name),
+        }
+    }
+
+    let fixed_output = function.params.iter().any(|param| param.ty == ApiType::OutputLength);
+    let mut doc = vec![function.doc.trim().to_string()];
+    let body = match function.returns {
+        _ if fixed_output => {
+            params.push("out: *mut u8, out_len: usize,".to_string());
+            "output(out, out_len)?.copy_from_slice(&result);\n        Ok(())".to_string()
+        }
+        ApiType::Bytes | ApiType::SecretBytes => {
+            params.push("out: *mut u8, out_len: *mut usize,".to_string());
+            "write_output(&result, out, out_len)".to_string()
+        }
+        ApiType::Str => {
+            params.push("out: *mut u8, out_len: *mut usize,".to_string());
+            doc.extend([String::new(), "The output is UTF-8 and not NUL-terminated.".to_string()]);
+            "write_output(result.as_bytes(), out, out_len)".to_string()
+        }
+        ApiType::Bool => {
+            doc.extend([String::new(), "Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.".to_string()]);
+            "if result {\n            Ok(())\n        } else {\n            Err(FfiError::new(LibsilverStatus::VerificationFailed, \"Verification failed\"))\n        }".to_string()
+        }
+        ApiType::OptionalBytes | ApiType::U32 | ApiType::OutputLength => unreachable!("{} is not a return kind", function.name),
+    };
+
+    let mut out = String::new();
+    for line in doc {
+        if line.is_empty() {
+            out.push_str("///\n");
+        } else {
+            let _ = writeln!(out, "/// {line}");
+        }
+    }
+    let _ = writeln!(out, "#[no_mangle]");
+    let _ = writeln!(out, "pub unsafe extern \"C\" fn libsilver_{}(", function.name);
+    for param in params {
+        let _ = writeln!(out, "    {param}");
+    }
+    let _ = writeln!(out, ") -> LibsilverStatus {{");
+    let _ = writeln!(out, "    ffi_call(|| {{");
+    let _ = writeln!(out, "        let result = api::{}({})?;", function.name, args.join(", "));
+    let _ = writeln!(out, "        {body}");
+    let _ = writeln!(out, "    }})");
+    let _ = writeln!(out, "}}");
+    out
+}
+
+/// Source of `src/generated.rs`
+pub fn render() -> String {
+    let mut out = String::from(
+        "// @generated by src/codegen.rs from libsilver::api::FUNCTIONS; do not edit.\n\
+         // Regenerate with `LIBSILVER_UPDATE_BINDINGS=1 cargo test`.\n\
+         \n\
+         use super::*;\n\
+         use libsilver::api;\n",
+    );
+    for function in FUNCTIONS {
+        out.push('\n');
+        out.push_str(&render_function(function));
+    }
+    out
+}
+
+#[test]
+fn test_generated_bindings_are_current() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/generated.rs");
+    let expected = render();
+    if std::env::var_os("LIBSILVER_UPDATE_BINDINGS").is_some() {
+        std::fs::write(&path, &expected).unwrap();
+    }
+    let actual = std::fs::read_to_string(&path).unwrap_or_default();
+    assert!(actual == expected, "src/generated.rs is out of date; rerun with LIBSILVER_UPDATE_BINDINGS=1");
+}
@@ -0,0 +1,287 @@
+// @generated by src/codegen.rs from libsilver::api::FUNCTIONS; do not edit.
+// Regenerate with `LIBSILVER_UPDATE_BINDINGS=1 cargo test`.
+
+use super::*;
+use libsilver::api;
+
+/// Encrypt with AES-256-GCM; output is nonce || ciphertext || tag
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_aes_gcm_encrypt(
+    key: *const u8, key_len: usize,
+    plaintext: *const u8, plaintext_len: usize,
+    aad: *const u8, aad_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::aes_gcm_encrypt(input(key, key_len)?, input(plaintext, plaintext_len)?, optional_input(aad, aad_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// Decrypt AES-256-GCM nonce || ciphertext || tag
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_aes_gcm_decrypt(
+    key: *const u8, key_len: usize,
+    ciphertext: *const u8, ciphertext_len: usize,
+    aad: *const u8, aad_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::aes_gcm_decrypt(input(key, key_len)?, input(ciphertext, ciphertext_len)?, optional_input(aad, aad_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// Encrypt with ChaCha20-Poly1305; output is nonce || ciphertext || tag
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_chacha20_poly1305_encrypt(
+    key: *const u8, key_len: usize,
+    plaintext: *const u8, plaintext_len: usize,
+    aad: *const u8, aad_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::chacha20_poly1305_encrypt(input(key, key_len)?, input(plaintext, plaintext_len)?, optional_input(aad, aad_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// Decrypt ChaCha20-Poly1305 nonce || ciphertext || tag
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_chacha20_poly1305_decrypt(
+    key: *const u8, key_len: usize,
+    ciphertext: *const u8, ciphertext_len: usize,
+    aad: *const u8, aad_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::chacha20_poly1305_decrypt(input(key, key_len)?, input(ciphertext, ciphertext_len)?, optional_input(aad, aad_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// SHA-256 (32-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_sha256(
+    data: *const u8, data_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::sha256(input(data, data_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// SHA-512 (64-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_sha512(
+    data: *const u8, data_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::sha512(input(data, data_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// SHA3-256 (32-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_sha3_256(
+    data: *const u8, data_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::sha3_256(input(data, data_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// SHA3-512 (64-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_sha3_512(
+    data: *const u8, data_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::sha3_512(input(data, data_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// BLAKE3 (32-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_blake3(
+    data: *const u8, data_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::blake3(input(data, data_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// HMAC-SHA256 (32-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_hmac_sha256(
+    key: *const u8, key_len: usize,
+    message: *const u8, message_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::hmac_sha256(input(key, key_len)?, input(message, message_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// HMAC-SHA512 (64-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_hmac_sha512(
+    key: *const u8, key_len: usize,
+    message: *const u8, message_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::hmac_sha512(input(key, key_len)?, input(message, message_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// Sign a message with a 32-byte Ed25519 private key (64-byte output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_ed25519_sign(
+    private_key: *const u8, private_key_len: usize,
+    message: *const u8, message_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::ed25519_sign(input(private_key, private_key_len)?, input(message, message_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// Verify an Ed25519 signature against a 32-byte public key
+///
+/// Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_ed25519_verify(
+    public_key: *const u8, public_key_len: usize,
+    message: *const u8, message_len: usize,
+    signature: *const u8, signature_len: usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::ed25519_verify(input(public_key, public_key_len)?, input(message, message_len)?, input(signature, signature_len)?)?;
+        if result {
+            Ok(())
+        } else {
+            Err(FfiError::new(LibsilverStatus::VerificationFailed, "Verification failed"))
+        }
+    })
+}
+
+/// Sign a message with a 32-byte ECDSA P-256 private key (64-byte r || s output)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_ecdsa_p256_sign(
+    private_key: *const u8, private_key_len: usize,
+    message: *const u8, message_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::ecdsa_p256_sign(input(private_key, private_key_len)?, input(message, message_len)?)?;
+        write_output(&result, out, out_len)
+    })
+}
+
+/// Verify an ECDSA P-256 signature against a SEC1 public key
+///
+/// Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_ecdsa_p256_verify(
+    public_key: *const u8, public_key_len: usize,
+    message: *const u8, message_len: usize,
+    signature: *const u8, signature_len: usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::ecdsa_p256_verify(input(public_key, public_key_len)?, input(message, message_len)?, input(signature, signature_len)?)?;
+        if result {
+            Ok(())
+        } else {
+            Err(FfiError::new(LibsilverStatus::VerificationFailed, "Verification failed"))
+        }
+    })
+}
+
+/// Derive a key from a password with Argon2id (libsilver default cost)
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_argon2id(
+    password: *const u8, password_len: usize,
+    salt: *const u8, salt_len: usize,
+    out: *mut u8, out_len: usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::argon2id(input(password, password_len)?, input(salt, salt_len)?, out_len)?;
+        output(out, out_len)?.copy_from_slice(&result);
+        Ok(())
+    })
+}
+
+/// Hash a password with Argon2id into a PHC string for storage
+///
+/// The output is UTF-8 and not NUL-terminated.
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_argon2_hash_password(
+    password: *const u8, password_len: usize,
+    out: *mut u8, out_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::argon2_hash_password(input(password, password_len)?)?;
+        write_output(result.as_bytes(), out, out_len)
+    })
+}
+
+/// Check a password against an Argon2 PHC string
+///
+/// Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_argon2_verify_password(
+    password: *const u8, password_len: usize,
+    hash: *const u8, hash_len: usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::argon2_verify_password(input(password, password_len)?, input_str(hash, hash_len)?)?;
+        if result {
+            Ok(())
+        } else {
+            Err(FfiError::new(LibsilverStatus::VerificationFailed, "Verification failed"))
+        }
+    })
+}
+
+/// Derive a key from a password with PBKDF2-HMAC-SHA256
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_pbkdf2_sha256(
+    password: *const u8, password_len: usize,
+    salt: *const u8, salt_len: usize,
+    iterations: u32,
+    out: *mut u8, out_len: usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::pbkdf2_sha256(input(password, password_len)?, input(salt, salt_len)?, iterations, out_len)?;
+        output(out, out_len)?.copy_from_slice(&result);
+        Ok(())
+    })
+}
+
+/// Derive a key with HKDF-SHA256; an absent salt uses the RFC 5869 default
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_hkdf_sha256(
+    input_key: *const u8, input_key_len: usize,
+    salt: *const u8, salt_len: usize,
+    info: *const u8, info_len: usize,
+    out: *mut u8, out_len: usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        let result = api::hkdf_sha256(input(input_key, input_key_len)?, optional_input(salt, salt_len)?, input(info, info_len)?, out_len)?;
+        output(out, out_len)?.copy_from_slice(&result);
+        Ok(())
+    })
+}
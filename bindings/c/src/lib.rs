@@ -0,0 +1,484 @@
+//! Stable C ABI for LibSilver
+//!
+//! Every function returns a [`LibsilverStatus`] and never panics across the
+//! FFI boundary. Conventions shared by all functions:
+//!
+//! - Input buffers are `(pointer, length)` pairs; the pointer may be NULL
+//!   only when the length is 0. Optional inputs (AAD, the HKDF salt) are
+//!   absent when the pointer is NULL and the length is 0.
+//! - Variable-length output is written to `(out, out_len)`: `*out_len` holds
+//!   the capacity of `out` on entry and the number of bytes written on
+//!   return. If the capacity is too small, `LIBSILVER_STATUS_BUFFER_TOO_SMALL`
+//!   is returned and `*out_len` holds the required size, so passing
+//!   `out = NULL, *out_len = 0` queries the size.
+//! - Fixed-length output such as random bytes or derived keys fills exactly
+//!   `out_len` bytes.
+//! - After an error, `libsilver_last_error_message()` describes it on the
+//!   calling thread.
+//!
+//! The byte-level functions in `generated.rs` are rendered from
+//! `libsilver::api`, the same declarations every other binding is generated
+//! from; only key generation and utilities are written by hand here.
+
+// Pointer validity is the caller's contract, documented above and in libsilver.h
+#![allow(clippy::missing_safety_doc)]
+
+use libsilver::core::*;
+use libsilver::error::CryptoError;
+use std::cell::RefCell;
+use std::ffi::{c_char, CString};
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+
+#[cfg(test)]
+mod codegen;
+mod generated;
+
+pub use generated::*;
+
+/// Size in bytes of AES-256-GCM and ChaCha20-Poly1305 keys
+pub const LIBSILVER_SYMMETRIC_KEY_SIZE: usize = 32;
+/// Bytes added to the plaintext by AEAD encryption (12-byte nonce + 16-byte tag)
+pub const LIBSILVER_AEAD_OVERHEAD: usize = 28;
+/// Size in bytes of Ed25519 private and public keys
+pub const LIBSILVER_ED25519_KEY_SIZE: usize = 32;
+/// Size in bytes of an Ed25519 signature
+pub const LIBSILVER_ED25519_SIGNATURE_SIZE: usize = 64;
+
+/// Result of every libsilver C function
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LibsilverStatus {
+    /// Success
+    Ok = 0,
+    /// A required pointer was NULL
+    NullPointer = 1,
+    /// An output buffer was too small; the required size was written to its length
+    BufferTooSmall = 2,
+    /// Invalid input data or parameters
+    InvalidInput = 3,
+    /// Invalid key
+    InvalidKey = 4,
+    /// Encryption failed
+    EncryptionFailed = 5,
+    /// Decryption or authentication failed
+    DecryptionFailed = 6,
+    /// Key generation failed
+    KeyGenerationFailed = 7,
+    /// Signature generation failed
+    SignatureFailed = 8,
+    /// Signature verification failed or the signature is invalid
+    VerificationFailed = 9,
+    /// Hash operation failed
+    HashFailed = 10,
+    /// Key derivation failed
+    KeyDerivationFailed = 11,
+    /// Random number generation failed
+    RandomGenerationFailed = 12,
+    /// Encoding or decoding failed
+    EncodingFailed = 13,
+    /// I/O operation failed
+    IoFailed = 14,
+    /// Internal error, including a caught panic
+    InternalError = 15,
+}
+
+impl From<&CryptoError> for LibsilverStatus {
+    fn from(err: &CryptoError) -> Self {
+        // `CryptoError::code` numbers match the statuses below
+        match err.code() {
+            3 => LibsilverStatus::InvalidInput,
+            4 => LibsilverStatus::InvalidKey,
+            5 => LibsilverStatus::EncryptionFailed,
+            6 => LibsilverStatus::DecryptionFailed,
+            7 => LibsilverStatus::KeyGenerationFailed,
+            8 => LibsilverStatus::SignatureFailed,
+            9 => LibsilverStatus::VerificationFailed,
+            10 => LibsilverStatus::HashFailed,
+            11 => LibsilverStatus::KeyDerivationFailed,
+            12 => LibsilverStatus::RandomGenerationFailed,
+            13 => LibsilverStatus::EncodingFailed,
+            14 => LibsilverStatus::IoFailed,
+            _ => LibsilverStatus::InternalError,
+        }
+    }
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+fn set_last_error(message: String) {
+    let message = CString::new(message).unwrap_or_default();
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message));
+}
+
+/// Error raised inside an FFI call, recorded as the thread's last error
+struct FfiError {
+    status: LibsilverStatus,
+    message: String,
+}
+
+impl From<CryptoError> for FfiError {
+    fn from(err: CryptoError) -> Self {
+        Self { status: LibsilverStatus::from(&err), message: err.to_string() }
+    }
+}
+
+impl FfiError {
+    fn new(status: LibsilverStatus, message: &str) -> Self {
+        Self { status, message: message.to_string() }
+    }
+}
+
+/// Run an FFI body, converting errors and panics into a status code
+fn ffi_call(body: impl FnOnce() -> Result<(), FfiError>) -> LibsilverStatus {
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(Ok(())) => LibsilverStatus::Ok,
+        Ok(Err(err)) => {
+            set_last_error(err.message);
+            err.status
+        }
+        Err(_) => {
+            set_last_error("Internal error: panic in libsilver".to_string());
+            LibsilverStatus::InternalError
+        }
+    }
+}
+
+/// Borrow an input buffer; NULL is only accepted for an empty buffer
+unsafe fn input<'a>(data: *const u8, len: usize) -> Result<&'a [u8], FfiError> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if data.is_null() {
+        return Err(FfiError::new(LibsilverStatus::NullPointer, "Input pointer is NULL"));
+    }
+    Ok(slice::from_raw_parts(data, len))
+}
+
+/// Borrow a fixed-length output buffer
+unsafe fn output<'a>(out: *mut u8, len: usize) -> Result<&'a mut [u8], FfiError> {
+    if len == 0 {
+        return Ok(&mut []);
+    }
+    if out.is_null() {
+        return Err(FfiError::new(LibsilverStatus::NullPointer, "Output pointer is NULL"));
+    }
+    Ok(slice::from_raw_parts_mut(out, len))
+}
+
+/// Borrow an optional input buffer; NULL with length 0 means absent
+unsafe fn optional_input<'a>(data: *const u8, len: usize) -> Result<Option<&'a [u8]>, FfiError> {
+    if data.is_null() && len == 0 {
+        return Ok(None);
+    }
+    input(data, len).map(Some)
+}
+
+/// Borrow a UTF-8 input string
+unsafe fn input_str<'a>(data: *const u8, len: usize) -> Result<&'a str, FfiError> {
+    std::str::from_utf8(input(data, len)?)
+        .map_err(|_| FfiError::new(LibsilverStatus::InvalidInput, "Input is not valid UTF-8"))
+}
+
+/// Copy variable-length output into `(out, *out_len)`, reporting the required size if it does not fit
+unsafe fn write_output(data: &[u8], out: *mut u8, out_len: *mut usize) -> Result<(), FfiError> {
+    if out_len.is_null() {
+        return Err(FfiError::new(LibsilverStatus::NullPointer, "Output length pointer is NULL"));
+    }
+    let capacity = *out_len;
+    *out_len = data.len();
+    if capacity < data.len() {
+        return Err(FfiError::new(LibsilverStatus::BufferTooSmall, "Output buffer too small"));
+    }
+    output(out, data.len())?.copy_from_slice(data);
+    Ok(())
+}
+
+/// Library version as a NUL-terminated string
+#[no_mangle]
+pub extern "C" fn libsilver_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr() as *const c_char
+}
+
+/// Static description of a status code
+#[no_mangle]
+pub extern "C" fn libsilver_status_message(status: LibsilverStatus) -> *const c_char {
+    let message: &'static str = match status {
+        LibsilverStatus::Ok => "OK\0",
+        LibsilverStatus::NullPointer => "NULL pointer\0",
+        LibsilverStatus::BufferTooSmall => "Output buffer too small\0",
+        LibsilverStatus::InvalidInput => "Invalid input\0",
+        LibsilverStatus::InvalidKey => "Invalid key\0",
+        LibsilverStatus::EncryptionFailed => "Encryption failed\0",
+        LibsilverStatus::DecryptionFailed => "Decryption failed\0",
+        LibsilverStatus::KeyGenerationFailed => "Key generation failed\0",
+        LibsilverStatus::SignatureFailed => "Signature generation failed\0",
+        LibsilverStatus::VerificationFailed => "Signature verification failed\0",
+        LibsilverStatus::HashFailed => "Hash operation failed\0",
+        LibsilverStatus::KeyDerivationFailed => "Key derivation failed\0",
+        LibsilverStatus::RandomGenerationFailed => "Random number generation failed\0",
+        LibsilverStatus::EncodingFailed => "Encoding/Decoding failed\0",
+        LibsilverStatus::IoFailed => "I/O operation failed\0",
+        LibsilverStatus::InternalError => "Internal error\0",
+    };
+    message.as_ptr() as *const c_char
+}
+
+/// Detailed message for the last error on this thread, or NULL
+///
+/// The pointer stays valid until the next libsilver call on the same thread.
+#[no_mangle]
+pub extern "C" fn libsilver_last_error_message() -> *const c_char {
+    LAST_ERROR.with(|last| last.borrow().as_ref().map_or(ptr::null(), |message| message.as_ptr()))
+}
+
+/// Fill `out` with `out_len` cryptographically secure random bytes
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_random_bytes(out: *mut u8, out_len: usize) -> LibsilverStatus {
+    ffi_call(|| {
+        let bytes = SecureRandom::generate_bytes(out_len)?;
+        output(out, out_len)?.copy_from_slice(&bytes);
+        Ok(())
+    })
+}
+
+/// Compare two buffers in constant time; different lengths compare unequal
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_constant_time_eq(a: *const u8, a_len: usize, b: *const u8, b_len: usize) -> bool {
+    match (input(a, a_len), input(b, b_len)) {
+        (Ok(a), Ok(b)) => ConstantTime::bytes_eq(a, b),
+        _ => false,
+    }
+}
+
+/// Generate an Ed25519 key pair into two LIBSILVER_ED25519_KEY_SIZE-byte buffers
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_ed25519_generate_keypair(private_key_out: *mut u8, public_key_out: *mut u8) -> LibsilverStatus {
+    ffi_call(|| {
+        let keypair = Ed25519Crypto::generate_keypair()?;
+        output(private_key_out, LIBSILVER_ED25519_KEY_SIZE)?.copy_from_slice(&keypair.private_key_bytes());
+        output(public_key_out, LIBSILVER_ED25519_KEY_SIZE)?.copy_from_slice(&keypair.public_key_bytes());
+        Ok(())
+    })
+}
+
+/// Compute the LIBSILVER_ED25519_KEY_SIZE-byte public key of a 32-byte Ed25519 private key
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_ed25519_public_key(private_key: *const u8, private_key_len: usize, public_key_out: *mut u8) -> LibsilverStatus {
+    ffi_call(|| {
+        let keypair = Ed25519KeyPair::from_private_key_bytes(input(private_key, private_key_len)?)?;
+        output(public_key_out, LIBSILVER_ED25519_KEY_SIZE)?.copy_from_slice(&keypair.public_key_bytes());
+        Ok(())
+    })
+}
+
+/// Upper bound on the PEM size of either half of an RSA key pair with `bits`-bit modulus
+///
+/// Buffers of this size are always large enough for `libsilver_rsa_generate_keypair`.
+#[no_mangle]
+pub extern "C" fn libsilver_rsa_pem_max_size(bits: usize) -> usize {
+    // PKCS#8 DER holds two modulus-sized and five half-sized integers plus framing
+    let der = (bits.saturating_add(7) / 8).saturating_mul(5).saturating_add(128);
+    let base64 = der.saturating_add(2) / 3 * 4;
+    base64.saturating_add(base64 / 64 + 1).saturating_add(64)
+}
+
+/// Generate an RSA key pair as PKCS#8 private and SPKI public PEM (not NUL-terminated)
+///
+/// Both capacities are checked against `libsilver_rsa_pem_max_size(bits)` before the
+/// (slow) generation starts, so a too-small buffer never discards a generated key.
+#[no_mangle]
+pub unsafe extern "C" fn libsilver_rsa_generate_keypair(
+    bits: usize,
+    private_pem_out: *mut u8, private_pem_len: *mut usize,
+    public_pem_out: *mut u8, public_pem_len: *mut usize,
+) -> LibsilverStatus {
+    ffi_call(|| {
+        if private_pem_len.is_null() || public_pem_len.is_null() {
+            return Err(FfiError::new(LibsilverStatus::NullPointer, "Output length pointer is NULL"));
+        }
+        let required = libsilver_rsa_pem_max_size(bits);
+        if *private_pem_len < required || *public_pem_len < required {
+            *private_pem_len = required;
+            *public_pem_len = required;
+            return Err(FfiError::new(LibsilverStatus::BufferTooSmall, "Output buffer too small"));
+        }
+
+        let keypair = RsaKeyPair::generate(bits)?;
+        write_output(keypair.private_key_pem()?.as_bytes(), private_pem_out, private_pem_len)?;
+        write_output(keypair.public_key_pem()?.as_bytes(), public_pem_out, public_pem_len)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn last_error() -> String {
+        let message = libsilver_last_error_message();
+        assert!(!message.is_null());
+        unsafe { CStr::from_ptr(message) }.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_aes_gcm_round_trip_and_size_query() {
+        let mut key = [0u8; LIBSILVER_SYMMETRIC_KEY_SIZE];
+        let plaintext = b"hello from C";
+        unsafe {
+            assert_eq!(libsilver_random_bytes(key.as_mut_ptr(), key.len()), LibsilverStatus::Ok);
+
+            let mut len = 0;
+            let status = libsilver_aes_gcm_encrypt(key.as_ptr(), key.len(), plaintext.as_ptr(), plaintext.len(), ptr::null(), 0, ptr::null_mut(), &mut len);
+            assert_eq!(status, LibsilverStatus::BufferTooSmall);
+            assert_eq!(len, plaintext.len() + LIBSILVER_AEAD_OVERHEAD);
+
+            let mut ciphertext = vec![0u8; len];
+            let status = libsilver_aes_gcm_encrypt(key.as_ptr(), key.len(), plaintext.as_ptr(), plaintext.len(), b"aad".as_ptr(), 3, ciphertext.as_mut_ptr(), &mut len);
+            assert_eq!(status, LibsilverStatus::Ok);
+
+            let mut decrypted = vec![0u8; 64];
+            let mut decrypted_len = decrypted.len();
+            let status = libsilver_aes_gcm_decrypt(key.as_ptr(), key.len(), ciphertext.as_ptr(), len, b"aad".as_ptr(), 3, decrypted.as_mut_ptr(), &mut decrypted_len);
+            assert_eq!(status, LibsilverStatus::Ok);
+            assert_eq!(&decrypted[..decrypted_len], plaintext);
+
+            ciphertext[20] ^= 1;
+            let status = libsilver_aes_gcm_decrypt(key.as_ptr(), key.len(), ciphertext.as_ptr(), len, b"aad".as_ptr(), 3, decrypted.as_mut_ptr(), &mut decrypted_len);
+            assert_eq!(status, LibsilverStatus::DecryptionFailed);
+            assert!(last_error().starts_with("Decryption failed"));
+        }
+    }
+
+    #[test]
+    fn test_chacha20_poly1305_matches_core() {
+        let key = [7u8; LIBSILVER_SYMMETRIC_KEY_SIZE];
+        let sealed = ChaCha20Poly1305Cipher::encrypt_with_aad(b"data", &ChaChaKey::from_bytes(&key).unwrap(), b"").unwrap();
+        let mut out = [0u8; 16];
+        let mut len = out.len();
+        let status = unsafe { libsilver_chacha20_poly1305_decrypt(key.as_ptr(), key.len(), sealed.as_ptr(), sealed.len(), ptr::null(), 0, out.as_mut_ptr(), &mut len) };
+        assert_eq!(status, LibsilverStatus::Ok);
+        assert_eq!(&out[..len], b"data");
+    }
+
+    #[test]
+    fn test_hashes_match_core() {
+        let mut out = [0u8; 64];
+        let mut len = out.len();
+        assert_eq!(unsafe { libsilver_sha256(b"abc".as_ptr(), 3, out.as_mut_ptr(), &mut len) }, LibsilverStatus::Ok);
+        assert_eq!(&out[..len], Sha256Hash::hash(b"abc").unwrap().as_slice());
+
+        let mut len = out.len();
+        assert_eq!(unsafe { libsilver_blake3(ptr::null(), 0, out.as_mut_ptr(), &mut len) }, LibsilverStatus::Ok);
+        assert_eq!(&out[..len], Blake3Hash::hash(b"").unwrap().as_slice());
+
+        let mut len = out.len();
+        assert_eq!(unsafe { libsilver_hmac_sha512(b"key".as_ptr(), 3, b"msg".as_ptr(), 3, out.as_mut_ptr(), &mut len) }, LibsilverStatus::Ok);
+        let mut hmac = StreamingHasher::hmac_sha512(b"key").unwrap();
+        hmac.update(b"msg");
+        assert_eq!(&out[..len], hmac.finalize().as_slice());
+
+        let mut len = 16;
+        assert_eq!(unsafe { libsilver_sha512(b"abc".as_ptr(), 3, out.as_mut_ptr(), &mut len) }, LibsilverStatus::BufferTooSmall);
+        assert_eq!(len, 64);
+        assert!(unsafe { libsilver_constant_time_eq(b"abc".as_ptr(), 3, b"abc".as_ptr(), 3) });
+        assert!(!unsafe { libsilver_constant_time_eq(b"abc".as_ptr(), 3, b"abd".as_ptr(), 3) });
+    }
+
+    #[test]
+    fn test_ed25519_sign_verify() {
+        let mut private_key = [0u8; LIBSILVER_ED25519_KEY_SIZE];
+        let mut public_key = [0u8; LIBSILVER_ED25519_KEY_SIZE];
+        let mut signature = [0u8; LIBSILVER_ED25519_SIGNATURE_SIZE];
+        let mut signature_len = signature.len();
+        unsafe {
+            assert_eq!(libsilver_ed25519_generate_keypair(private_key.as_mut_ptr(), public_key.as_mut_ptr()), LibsilverStatus::Ok);
+            assert_eq!(libsilver_ed25519_sign(private_key.as_ptr(), 32, b"msg".as_ptr(), 3, signature.as_mut_ptr(), &mut signature_len), LibsilverStatus::Ok);
+            assert_eq!(libsilver_ed25519_verify(public_key.as_ptr(), 32, b"msg".as_ptr(), 3, signature.as_ptr(), signature_len), LibsilverStatus::Ok);
+            assert_eq!(libsilver_ed25519_verify(public_key.as_ptr(), 32, b"msG".as_ptr(), 3, signature.as_ptr(), signature_len), LibsilverStatus::VerificationFailed);
+
+            let mut derived = [0u8; LIBSILVER_ED25519_KEY_SIZE];
+            assert_eq!(libsilver_ed25519_public_key(private_key.as_ptr(), 32, derived.as_mut_ptr()), LibsilverStatus::Ok);
+            assert_eq!(derived, public_key);
+            assert_eq!(libsilver_ed25519_public_key(private_key.as_ptr(), 31, derived.as_mut_ptr()), LibsilverStatus::InvalidKey);
+        }
+    }
+
+    #[test]
+    fn test_rsa_generate_keypair() {
+        let max = libsilver_rsa_pem_max_size(2048);
+        let mut private_pem = vec![0u8; max];
+        let mut public_pem = vec![0u8; max];
+        let mut private_len = 16;
+        let mut public_len = max;
+        unsafe {
+            let status = libsilver_rsa_generate_keypair(2048, private_pem.as_mut_ptr(), &mut private_len, public_pem.as_mut_ptr(), &mut public_len);
+            assert_eq!(status, LibsilverStatus::BufferTooSmall);
+            assert_eq!(private_len, max);
+
+            let status = libsilver_rsa_generate_keypair(2048, private_pem.as_mut_ptr(), &mut private_len, public_pem.as_mut_ptr(), &mut public_len);
+            assert_eq!(status, LibsilverStatus::Ok);
+        }
+        let private_pem = std::str::from_utf8(&private_pem[..private_len]).unwrap();
+        let keypair = RsaKeyPair::from_private_key_pem(private_pem).unwrap();
+        assert_eq!(keypair.public_key_pem().unwrap().as_bytes(), &public_pem[..public_len]);
+        assert!(libsilver_rsa_pem_max_size(4096) > libsilver_rsa_pem_max_size(2048));
+
+        let mut buffer = vec![0u8; libsilver_rsa_pem_max_size(1024)];
+        let (mut private_len, mut public_len) = (buffer.len(), buffer.len());
+        let status = unsafe { libsilver_rsa_generate_keypair(1024, buffer.as_mut_ptr(), &mut private_len, buffer.as_mut_ptr(), &mut public_len) };
+        assert_eq!(status, LibsilverStatus::InvalidInput);
+    }
+
+    #[test]
+    fn test_kdfs_match_core() {
+        let mut out = [0u8; 32];
+        unsafe {
+            assert_eq!(libsilver_pbkdf2_sha256(b"password".as_ptr(), 8, b"saltsalt".as_ptr(), 8, 1000, out.as_mut_ptr(), out.len()), LibsilverStatus::Ok);
+            assert_eq!(out.as_slice(), Pbkdf2Kdf::derive_sha256(b"password", b"saltsalt", 1000, 32).unwrap().as_slice());
+
+            assert_eq!(libsilver_hkdf_sha256(b"ikm".as_ptr(), 3, ptr::null(), 0, b"info".as_ptr(), 4, out.as_mut_ptr(), out.len()), LibsilverStatus::Ok);
+            assert_eq!(out.as_slice(), HkdfKdf::derive_sha256(b"ikm", None, b"info", 32).unwrap().as_slice());
+        }
+    }
+
+    #[test]
+    fn test_password_hashing_and_ecdsa() {
+        let mut hash = [0u8; 128];
+        let mut hash_len = hash.len();
+        unsafe {
+            assert_eq!(libsilver_argon2_hash_password(b"hunter2".as_ptr(), 7, hash.as_mut_ptr(), &mut hash_len), LibsilverStatus::Ok);
+            assert!(hash[..hash_len].starts_with(b"$argon2id$"));
+            assert_eq!(libsilver_argon2_verify_password(b"hunter2".as_ptr(), 7, hash.as_ptr(), hash_len), LibsilverStatus::Ok);
+            assert_eq!(libsilver_argon2_verify_password(b"hunter3".as_ptr(), 7, hash.as_ptr(), hash_len), LibsilverStatus::VerificationFailed);
+            assert_eq!(libsilver_argon2_verify_password(b"hunter2".as_ptr(), 7, [0xff].as_ptr(), 1), LibsilverStatus::InvalidInput);
+        }
+
+        let keypair = EcdsaKeyPair::generate().unwrap();
+        let (private_key, public_key) = (keypair.private_key_bytes(), keypair.public_key_bytes());
+        let mut signature = [0u8; 64];
+        let mut signature_len = signature.len();
+        unsafe {
+            assert_eq!(libsilver_ecdsa_p256_sign(private_key.as_ptr(), private_key.len(), b"msg".as_ptr(), 3, signature.as_mut_ptr(), &mut signature_len), LibsilverStatus::Ok);
+            assert_eq!(libsilver_ecdsa_p256_verify(public_key.as_ptr(), public_key.len(), b"msg".as_ptr(), 3, signature.as_ptr(), signature_len), LibsilverStatus::Ok);
+            assert_eq!(libsilver_ecdsa_p256_verify(public_key.as_ptr(), public_key.len(), b"msG".as_ptr(), 3, signature.as_ptr(), signature_len), LibsilverStatus::VerificationFailed);
+        }
+    }
+
+    #[test]
+    fn test_null_pointers_and_status_messages() {
+        let mut len = 32;
+        assert_eq!(unsafe { libsilver_sha256(ptr::null(), 3, ptr::null_mut(), &mut len) }, LibsilverStatus::NullPointer);
+        assert_eq!(unsafe { libsilver_sha256(b"abc".as_ptr(), 3, ptr::null_mut(), ptr::null_mut()) }, LibsilverStatus::NullPointer);
+        assert_eq!(unsafe { libsilver_random_bytes(ptr::null_mut(), 16) }, LibsilverStatus::NullPointer);
+        assert_eq!(last_error(), "Output pointer is NULL");
+
+        let message = unsafe { CStr::from_ptr(libsilver_status_message(LibsilverStatus::BufferTooSmall)) };
+        assert_eq!(message.to_str().unwrap(), "Output buffer too small");
+        let version = unsafe { CStr::from_ptr(libsilver_version()) };
+        assert_eq!(version.to_str().unwrap(), env!("CARGO_PKG_VERSION"));
+    }
+}
@@ -0,0 +1,113 @@
+using System;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>Ed25519 key pair whose private key is held in unmanaged memory</summary>
+    public sealed class Ed25519KeyPair : IDisposable
+    {
+        private readonly SecretKeyHandle _privateKey;
+        private readonly byte[] _publicKey;
+
+        private Ed25519KeyPair(SecretKeyHandle privateKey, byte[] publicKey)
+        {
+            _privateKey = privateKey;
+            _publicKey = publicKey;
+        }
+
+        /// <summary>Size in bytes of a signature</summary>
+        public const int SignatureSize = NativeMethods.Ed25519SignatureSize;
+
+        /// <summary>32-byte public key</summary>
+        public ReadOnlySpan<byte> PublicKey => _publicKey;
+
+        /// <summary>Generate a random key pair</summary>
+        public static unsafe Ed25519KeyPair Generate()
+        {
+            var handle = SecretKeyHandle.Allocate(NativeMethods.Ed25519KeySize);
+            try
+            {
+                byte[] publicKey = new byte[NativeMethods.Ed25519KeySize];
+                using (var key = handle.Borrow())
+                fixed (byte* pub = publicKey)
+                {
+                    NativeMethods.Check(NativeMethods.libsilver_ed25519_generate_keypair(key.Pointer, pub));
+                }
+                return new Ed25519KeyPair(handle, publicKey);
+            }
+            catch
+            {
+                handle.Dispose();
+                throw;
+            }
+        }
+
+        /// <summary>Import a 32-byte private key and compute its public key</summary>
+        public static unsafe Ed25519KeyPair Import(ReadOnlySpan<byte> privateKey)
+        {
+            var handle = SecretKeyHandle.FromBytes(privateKey);
+            try
+            {
+                byte[] publicKey = new byte[NativeMethods.Ed25519KeySize];
+                using (var key = handle.Borrow())
+                fixed (byte* pub = publicKey)
+                {
+                    NativeMethods.Check(NativeMethods.libsilver_ed25519_public_key(key.Pointer, key.Length, pub));
+                }
+                return new Ed25519KeyPair(handle, publicKey);
+            }
+            catch
+            {
+                handle.Dispose();
+                throw;
+            }
+        }
+
+        /// <summary>Sign <paramref name="message"/> into <paramref name="destination"/>, returning the bytes written</summary>
+        public unsafe int Sign(ReadOnlySpan<byte> message, Span<byte> destination)
+        {
+            using var key = _privateKey.Borrow();
+            fixed (byte* m = message)
+            fixed (byte* d = destination)
+            {
+                nuint written = (nuint)destination.Length;
+                NativeMethods.Check(NativeMethods.libsilver_ed25519_sign(key.Pointer, key.Length, m, (nuint)message.Length, d, &written));
+                return (int)written;
+            }
+        }
+
+        /// <summary>Sign <paramref name="message"/></summary>
+        public byte[] Sign(ReadOnlySpan<byte> message)
+        {
+            byte[] signature = new byte[SignatureSize];
+            Sign(message, signature);
+            return signature;
+        }
+
+        /// <summary>Verify a signature against this key pair's public key</summary>
+        public bool Verify(ReadOnlySpan<byte> message, ReadOnlySpan<byte> signature) => Verify(_publicKey, message, signature);
+
+        /// <summary>Verify a signature against a raw 32-byte public key; false if it does not match</summary>
+        public static unsafe bool Verify(ReadOnlySpan<byte> publicKey, ReadOnlySpan<byte> message, ReadOnlySpan<byte> signature)
+        {
+            fixed (byte* k = publicKey)
+            fixed (byte* m = message)
+            fixed (byte* sig = signature)
+            {
+                var status = NativeMethods.libsilver_ed25519_verify(k, (nuint)publicKey.Length, m, (nuint)message.Length, sig, (nuint)signature.Length);
+                if (status == LibsilverStatus.VerificationFailed)
+                {
+                    return false;
+                }
+                NativeMethods.Check(status);
+                return true;
+            }
+        }
+
+        /// <summary>Copy the raw private key into managed memory</summary>
+        public byte[] ExportPrivateKey() => _privateKey.ToArray();
+
+        /// <summary>Zero and free the private key</summary>
+        public void Dispose() => _privateKey.Dispose();
+    }
+}
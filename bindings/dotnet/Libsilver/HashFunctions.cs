@@ -0,0 +1,105 @@
+using System;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>Hashes, MACs and constant-time comparison</summary>
+    public static class HashFunctions
+    {
+        /// <summary>Write the SHA-256 digest (32 bytes) into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TrySha256(ReadOnlySpan<byte> data, Span<byte> destination, out int bytesWritten)
+            => TryDigest(&NativeMethods.libsilver_sha256, data, destination, out bytesWritten);
+
+        /// <summary>Write the SHA-512 digest (64 bytes) into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TrySha512(ReadOnlySpan<byte> data, Span<byte> destination, out int bytesWritten)
+            => TryDigest(&NativeMethods.libsilver_sha512, data, destination, out bytesWritten);
+
+        /// <summary>Write the SHA3-256 digest (32 bytes) into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TrySha3_256(ReadOnlySpan<byte> data, Span<byte> destination, out int bytesWritten)
+            => TryDigest(&NativeMethods.libsilver_sha3_256, data, destination, out bytesWritten);
+
+        /// <summary>Write the BLAKE3 digest (32 bytes) into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryBlake3(ReadOnlySpan<byte> data, Span<byte> destination, out int bytesWritten)
+            => TryDigest(&NativeMethods.libsilver_blake3, data, destination, out bytesWritten);
+
+        /// <summary>Write HMAC-SHA256 (32 bytes) into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryHmacSha256(ReadOnlySpan<byte> key, ReadOnlySpan<byte> message, Span<byte> destination, out int bytesWritten)
+            => TryMac(&NativeMethods.libsilver_hmac_sha256, key, message, destination, out bytesWritten);
+
+        /// <summary>Write HMAC-SHA512 (64 bytes) into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryHmacSha512(ReadOnlySpan<byte> key, ReadOnlySpan<byte> message, Span<byte> destination, out int bytesWritten)
+            => TryMac(&NativeMethods.libsilver_hmac_sha512, key, message, destination, out bytesWritten);
+
+        /// <summary>SHA-256 digest</summary>
+        public static byte[] Sha256(ReadOnlySpan<byte> data) => Allocate(32, data, TrySha256);
+
+        /// <summary>SHA-512 digest</summary>
+        public static byte[] Sha512(ReadOnlySpan<byte> data) => Allocate(64, data, TrySha512);
+
+        /// <summary>SHA3-256 digest</summary>
+        public static byte[] Sha3_256(ReadOnlySpan<byte> data) => Allocate(32, data, TrySha3_256);
+
+        /// <summary>BLAKE3 digest</summary>
+        public static byte[] Blake3(ReadOnlySpan<byte> data) => Allocate(32, data, TryBlake3);
+
+        /// <summary>HMAC-SHA256</summary>
+        public static byte[] HmacSha256(ReadOnlySpan<byte> key, ReadOnlySpan<byte> message)
+        {
+            byte[] mac = new byte[32];
+            TryHmacSha256(key, message, mac, out _);
+            return mac;
+        }
+
+        /// <summary>HMAC-SHA512</summary>
+        public static byte[] HmacSha512(ReadOnlySpan<byte> key, ReadOnlySpan<byte> message)
+        {
+            byte[] mac = new byte[64];
+            TryHmacSha512(key, message, mac, out _);
+            return mac;
+        }
+
+        /// <summary>Compare two byte strings in constant time; different lengths compare unequal</summary>
+        public static unsafe bool ConstantTimeEquals(ReadOnlySpan<byte> a, ReadOnlySpan<byte> b)
+        {
+            fixed (byte* x = a)
+            fixed (byte* y = b)
+            {
+                return NativeMethods.libsilver_constant_time_eq(x, (nuint)a.Length, y, (nuint)b.Length);
+            }
+        }
+
+        private delegate bool TryHash(ReadOnlySpan<byte> data, Span<byte> destination, out int bytesWritten);
+
+        private static byte[] Allocate(int size, ReadOnlySpan<byte> data, TryHash hash)
+        {
+            byte[] digest = new byte[size];
+            hash(data, digest, out _);
+            return digest;
+        }
+
+        private static unsafe bool TryDigest(
+            delegate*<byte*, nuint, byte*, nuint*, LibsilverStatus> function,
+            ReadOnlySpan<byte> data, Span<byte> destination, out int bytesWritten)
+        {
+            fixed (byte* d = data)
+            fixed (byte* o = destination)
+            {
+                nuint written = (nuint)destination.Length;
+                return NativeMethods.TryCheck(function(d, (nuint)data.Length, o, &written), written, out bytesWritten);
+            }
+        }
+
+        private static unsafe bool TryMac(
+            delegate*<byte*, nuint, byte*, nuint, byte*, nuint*, LibsilverStatus> function,
+            ReadOnlySpan<byte> key, ReadOnlySpan<byte> message, Span<byte> destination, out int bytesWritten)
+        {
+            fixed (byte* k = key)
+            fixed (byte* m = message)
+            fixed (byte* o = destination)
+            {
+                nuint written = (nuint)destination.Length;
+                return NativeMethods.TryCheck(function(k, (nuint)key.Length, m, (nuint)message.Length, o, &written), written, out bytesWritten);
+            }
+        }
+    }
+}
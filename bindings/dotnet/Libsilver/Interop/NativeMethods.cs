@@ -0,0 +1,132 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace Libsilver.Interop
+{
+    /// <summary>Raw P/Invoke declarations for libsilver.h</summary>
+    internal static unsafe class NativeMethods
+    {
+        // Resolves to libsilver.so, libsilver.dylib or silver.dll
+        private const string Library = "silver";
+
+        internal const int SymmetricKeySize = 32;
+        internal const int AeadOverhead = 28;
+        internal const int Ed25519KeySize = 32;
+        internal const int Ed25519SignatureSize = 64;
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern IntPtr libsilver_version();
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern IntPtr libsilver_status_message(LibsilverStatus status);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern IntPtr libsilver_last_error_message();
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_random_bytes(byte* @out, nuint outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_aes_gcm_encrypt(
+            byte* key, nuint keyLen, byte* plaintext, nuint plaintextLen, byte* aad, nuint aadLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_aes_gcm_decrypt(
+            byte* key, nuint keyLen, byte* ciphertext, nuint ciphertextLen, byte* aad, nuint aadLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_chacha20_poly1305_encrypt(
+            byte* key, nuint keyLen, byte* plaintext, nuint plaintextLen, byte* aad, nuint aadLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_chacha20_poly1305_decrypt(
+            byte* key, nuint keyLen, byte* ciphertext, nuint ciphertextLen, byte* aad, nuint aadLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_sha256(byte* data, nuint dataLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_sha512(byte* data, nuint dataLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_sha3_256(byte* data, nuint dataLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_blake3(byte* data, nuint dataLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_hmac_sha256(
+            byte* key, nuint keyLen, byte* message, nuint messageLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_hmac_sha512(
+            byte* key, nuint keyLen, byte* message, nuint messageLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        [return: MarshalAs(UnmanagedType.U1)]
+        internal static extern bool libsilver_constant_time_eq(byte* a, nuint aLen, byte* b, nuint bLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_ed25519_generate_keypair(byte* privateKeyOut, byte* publicKeyOut);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_ed25519_public_key(byte* privateKey, nuint privateKeyLen, byte* publicKeyOut);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_ed25519_sign(
+            byte* privateKey, nuint privateKeyLen, byte* message, nuint messageLen, byte* @out, nuint* outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_ed25519_verify(
+            byte* publicKey, nuint publicKeyLen, byte* message, nuint messageLen, byte* signature, nuint signatureLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern nuint libsilver_rsa_pem_max_size(nuint bits);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_rsa_generate_keypair(
+            nuint bits, byte* privatePemOut, nuint* privatePemLen, byte* publicPemOut, nuint* publicPemLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_argon2id(
+            byte* password, nuint passwordLen, byte* salt, nuint saltLen, byte* @out, nuint outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_pbkdf2_sha256(
+            byte* password, nuint passwordLen, byte* salt, nuint saltLen, uint iterations, byte* @out, nuint outLen);
+
+        [DllImport(Library, CallingConvention = CallingConvention.Cdecl)]
+        internal static extern LibsilverStatus libsilver_hkdf_sha256(
+            byte* inputKey, nuint inputKeyLen, byte* salt, nuint saltLen, byte* info, nuint infoLen, byte* @out, nuint outLen);
+
+        /// <summary>Like <see cref="Check"/>, but report a too-small destination as false instead of throwing</summary>
+        internal static bool TryCheck(LibsilverStatus status, nuint written, out int bytesWritten)
+        {
+            if (status == LibsilverStatus.BufferTooSmall)
+            {
+                bytesWritten = 0;
+                return false;
+            }
+            Check(status);
+            bytesWritten = (int)written;
+            return true;
+        }
+
+        /// <summary>Throw a <see cref="LibsilverException"/> unless <paramref name="status"/> is Ok</summary>
+        internal static void Check(LibsilverStatus status)
+        {
+            if (status == LibsilverStatus.Ok)
+            {
+                return;
+            }
+
+            IntPtr detail = libsilver_last_error_message();
+            string message = Marshal.PtrToStringUTF8(detail != IntPtr.Zero ? detail : libsilver_status_message(status)) ?? status.ToString();
+            if (status == LibsilverStatus.BufferTooSmall)
+            {
+                throw new ArgumentException(message, "destination");
+            }
+            throw new LibsilverException(status, message);
+        }
+    }
+}
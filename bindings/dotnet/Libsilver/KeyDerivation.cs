@@ -0,0 +1,43 @@
+using System;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>Password hashing and key derivation; each fills <c>destination</c> exactly</summary>
+    public static class KeyDerivation
+    {
+        /// <summary>Argon2id with the libsilver default cost</summary>
+        public static unsafe void Argon2(ReadOnlySpan<byte> password, ReadOnlySpan<byte> salt, Span<byte> destination)
+        {
+            fixed (byte* p = password)
+            fixed (byte* s = salt)
+            fixed (byte* o = destination)
+            {
+                NativeMethods.Check(NativeMethods.libsilver_argon2id(p, (nuint)password.Length, s, (nuint)salt.Length, o, (nuint)destination.Length));
+            }
+        }
+
+        /// <summary>PBKDF2-HMAC-SHA256</summary>
+        public static unsafe void Pbkdf2(ReadOnlySpan<byte> password, ReadOnlySpan<byte> salt, uint iterations, Span<byte> destination)
+        {
+            fixed (byte* p = password)
+            fixed (byte* s = salt)
+            fixed (byte* o = destination)
+            {
+                NativeMethods.Check(NativeMethods.libsilver_pbkdf2_sha256(p, (nuint)password.Length, s, (nuint)salt.Length, iterations, o, (nuint)destination.Length));
+            }
+        }
+
+        /// <summary>HKDF-SHA256; an empty salt is equivalent to the RFC 5869 default</summary>
+        public static unsafe void Hkdf(ReadOnlySpan<byte> inputKey, ReadOnlySpan<byte> salt, ReadOnlySpan<byte> info, Span<byte> destination)
+        {
+            fixed (byte* k = inputKey)
+            fixed (byte* s = salt)
+            fixed (byte* i = info)
+            fixed (byte* o = destination)
+            {
+                NativeMethods.Check(NativeMethods.libsilver_hkdf_sha256(k, (nuint)inputKey.Length, s, (nuint)salt.Length, i, (nuint)info.Length, o, (nuint)destination.Length));
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+using System.Security.Cryptography;
+
+namespace Libsilver
+{
+    /// <summary>Error reported by the native library</summary>
+    public sealed class LibsilverException : CryptographicException
+    {
+        /// <summary>Status code returned by the failing call</summary>
+        public LibsilverStatus Status { get; }
+
+        internal LibsilverException(LibsilverStatus status, string message) : base(message)
+        {
+            Status = status;
+        }
+    }
+}
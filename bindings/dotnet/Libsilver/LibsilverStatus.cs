@@ -0,0 +1,39 @@
+namespace Libsilver
+{
+    /// <summary>Result code of every libsilver native function</summary>
+    public enum LibsilverStatus
+    {
+        /// <summary>Success</summary>
+        Ok = 0,
+        /// <summary>A required pointer was NULL</summary>
+        NullPointer = 1,
+        /// <summary>An output buffer was too small</summary>
+        BufferTooSmall = 2,
+        /// <summary>Invalid input data or parameters</summary>
+        InvalidInput = 3,
+        /// <summary>Invalid key</summary>
+        InvalidKey = 4,
+        /// <summary>Encryption failed</summary>
+        EncryptionFailed = 5,
+        /// <summary>Decryption or authentication failed</summary>
+        DecryptionFailed = 6,
+        /// <summary>Key generation failed</summary>
+        KeyGenerationFailed = 7,
+        /// <summary>Signature generation failed</summary>
+        SignatureFailed = 8,
+        /// <summary>Signature verification failed or the signature is invalid</summary>
+        VerificationFailed = 9,
+        /// <summary>Hash operation failed</summary>
+        HashFailed = 10,
+        /// <summary>Key derivation failed</summary>
+        KeyDerivationFailed = 11,
+        /// <summary>Random number generation failed</summary>
+        RandomGenerationFailed = 12,
+        /// <summary>Encoding or decoding failed</summary>
+        EncodingFailed = 13,
+        /// <summary>I/O operation failed</summary>
+        IoFailed = 14,
+        /// <summary>Internal error, including a caught panic</summary>
+        InternalError = 15,
+    }
+}
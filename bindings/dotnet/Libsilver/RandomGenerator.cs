@@ -0,0 +1,29 @@
+using System;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>Cryptographically secure random bytes</summary>
+    public static class RandomGenerator
+    {
+        /// <summary>Fill <paramref name="destination"/> with random bytes</summary>
+        public static unsafe void Fill(Span<byte> destination)
+        {
+            fixed (byte* o = destination)
+            {
+                NativeMethods.Check(NativeMethods.libsilver_random_bytes(o, (nuint)destination.Length));
+            }
+        }
+
+        /// <summary>Generate <paramref name="length"/> random bytes</summary>
+        public static byte[] GetBytes(int length)
+        {
+            byte[] bytes = new byte[length];
+            Fill(bytes);
+            return bytes;
+        }
+
+        /// <summary>Random 32-byte salt</summary>
+        public static byte[] GenerateSalt() => GetBytes(32);
+    }
+}
@@ -0,0 +1,41 @@
+using System;
+using System.Text;
+using System.Threading;
+using System.Threading.Tasks;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>RSA key pair as PKCS#8 private and SPKI public PEM</summary>
+    public sealed record RsaKeyPair(string PrivateKeyPem, string PublicKeyPem);
+
+    /// <summary>RSA key generation</summary>
+    public static class Rsa
+    {
+        /// <summary>Generate an RSA key pair on the calling thread</summary>
+        public static unsafe RsaKeyPair GenerateKeyPair(int bits = 2048)
+        {
+            nuint capacity = NativeMethods.libsilver_rsa_pem_max_size((nuint)bits);
+            byte[] privatePem = new byte[(int)capacity];
+            byte[] publicPem = new byte[(int)capacity];
+            try
+            {
+                fixed (byte* priv = privatePem)
+                fixed (byte* pub = publicPem)
+                {
+                    nuint privateLen = capacity, publicLen = capacity;
+                    NativeMethods.Check(NativeMethods.libsilver_rsa_generate_keypair((nuint)bits, priv, &privateLen, pub, &publicLen));
+                    return new RsaKeyPair(Encoding.ASCII.GetString(priv, (int)privateLen), Encoding.ASCII.GetString(pub, (int)publicLen));
+                }
+            }
+            finally
+            {
+                Array.Clear(privatePem);
+            }
+        }
+
+        /// <summary>Generate an RSA key pair on the thread pool</summary>
+        public static Task<RsaKeyPair> GenerateKeyPairAsync(int bits = 2048, CancellationToken cancellationToken = default)
+            => Task.Run(() => GenerateKeyPair(bits), cancellationToken);
+    }
+}
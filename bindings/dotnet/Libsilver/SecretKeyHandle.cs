@@ -0,0 +1,81 @@
+using System;
+using System.Runtime.InteropServices;
+
+namespace Libsilver
+{
+    /// <summary>
+    /// Unmanaged buffer holding secret key bytes, outside the reach of the GC's
+    /// copying and zeroed when released
+    /// </summary>
+    public sealed class SecretKeyHandle : SafeHandle
+    {
+        /// <summary>Key length in bytes</summary>
+        public int Length { get; }
+
+        private SecretKeyHandle(int length) : base(IntPtr.Zero, ownsHandle: true)
+        {
+            Length = length;
+            SetHandle(Marshal.AllocHGlobal(length));
+        }
+
+        /// <inheritdoc />
+        public override bool IsInvalid => handle == IntPtr.Zero;
+
+        /// <summary>Allocate an uninitialised key buffer for a native call to fill</summary>
+        internal static SecretKeyHandle Allocate(int length) => new SecretKeyHandle(length);
+
+        /// <summary>Copy <paramref name="key"/> into a new handle</summary>
+        internal static unsafe SecretKeyHandle FromBytes(ReadOnlySpan<byte> key)
+        {
+            var handle = new SecretKeyHandle(key.Length);
+            key.CopyTo(new Span<byte>((void*)handle.handle, key.Length));
+            return handle;
+        }
+
+        /// <summary>Borrow the key pointer, keeping the handle alive until the lease is disposed</summary>
+        internal Lease Borrow() => new Lease(this);
+
+        /// <summary>Copy the key bytes out of unmanaged memory</summary>
+        internal byte[] ToArray()
+        {
+            using var lease = Borrow();
+            return lease.AsSpan().ToArray();
+        }
+
+        internal unsafe ref struct Lease
+        {
+            private readonly SecretKeyHandle _key;
+            private bool _added;
+
+            internal Lease(SecretKeyHandle key)
+            {
+                _key = key;
+                _added = false;
+                key.DangerousAddRef(ref _added);
+            }
+
+            public byte* Pointer => (byte*)_key.handle;
+
+            public nuint Length => (nuint)_key.Length;
+
+            public Span<byte> AsSpan() => new Span<byte>(Pointer, _key.Length);
+
+            public void Dispose()
+            {
+                if (_added)
+                {
+                    _key.DangerousRelease();
+                    _added = false;
+                }
+            }
+        }
+
+        /// <inheritdoc />
+        protected override unsafe bool ReleaseHandle()
+        {
+            new Span<byte>((void*)handle, Length).Clear();
+            Marshal.FreeHGlobal(handle);
+            return true;
+        }
+    }
+}
@@ -0,0 +1,85 @@
+using System;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>
+    /// AES-256-GCM and ChaCha20-Poly1305 authenticated encryption
+    ///
+    /// Ciphertext is <c>nonce || ciphertext || tag</c>, compatible with the Rust and Node APIs.
+    /// </summary>
+    public static class SymmetricCrypto
+    {
+        /// <summary>Bytes added to the plaintext by encryption (12-byte nonce + 16-byte tag)</summary>
+        public const int Overhead = NativeMethods.AeadOverhead;
+
+        /// <summary>Ciphertext length for a plaintext of <paramref name="plaintextLength"/> bytes</summary>
+        public static int GetCiphertextLength(int plaintextLength) => plaintextLength + Overhead;
+
+        /// <summary>Maximum plaintext length for a ciphertext of <paramref name="ciphertextLength"/> bytes</summary>
+        public static int GetPlaintextLength(int ciphertextLength) => Math.Max(ciphertextLength - Overhead, 0);
+
+        /// <summary>Encrypt with AES-256-GCM into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryEncryptAes(ReadOnlySpan<byte> plaintext, SymmetricKey key, Span<byte> destination, out int bytesWritten, ReadOnlySpan<byte> associatedData = default)
+            => TryAead(&NativeMethods.libsilver_aes_gcm_encrypt, plaintext, key, destination, out bytesWritten, associatedData);
+
+        /// <summary>Decrypt AES-256-GCM ciphertext into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryDecryptAes(ReadOnlySpan<byte> ciphertext, SymmetricKey key, Span<byte> destination, out int bytesWritten, ReadOnlySpan<byte> associatedData = default)
+            => TryAead(&NativeMethods.libsilver_aes_gcm_decrypt, ciphertext, key, destination, out bytesWritten, associatedData);
+
+        /// <summary>Encrypt with ChaCha20-Poly1305 into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryEncryptChaCha20(ReadOnlySpan<byte> plaintext, SymmetricKey key, Span<byte> destination, out int bytesWritten, ReadOnlySpan<byte> associatedData = default)
+            => TryAead(&NativeMethods.libsilver_chacha20_poly1305_encrypt, plaintext, key, destination, out bytesWritten, associatedData);
+
+        /// <summary>Decrypt ChaCha20-Poly1305 ciphertext into <paramref name="destination"/>; false if it is too small</summary>
+        public static unsafe bool TryDecryptChaCha20(ReadOnlySpan<byte> ciphertext, SymmetricKey key, Span<byte> destination, out int bytesWritten, ReadOnlySpan<byte> associatedData = default)
+            => TryAead(&NativeMethods.libsilver_chacha20_poly1305_decrypt, ciphertext, key, destination, out bytesWritten, associatedData);
+
+        /// <summary>Encrypt with AES-256-GCM</summary>
+        public static byte[] EncryptAes(ReadOnlySpan<byte> plaintext, SymmetricKey key, ReadOnlySpan<byte> associatedData = default)
+        {
+            byte[] ciphertext = new byte[GetCiphertextLength(plaintext.Length)];
+            TryEncryptAes(plaintext, key, ciphertext, out _, associatedData);
+            return ciphertext;
+        }
+
+        /// <summary>Decrypt AES-256-GCM ciphertext</summary>
+        public static byte[] DecryptAes(ReadOnlySpan<byte> ciphertext, SymmetricKey key, ReadOnlySpan<byte> associatedData = default)
+        {
+            byte[] plaintext = new byte[GetPlaintextLength(ciphertext.Length)];
+            TryDecryptAes(ciphertext, key, plaintext, out int written, associatedData);
+            return plaintext[..written];
+        }
+
+        /// <summary>Encrypt with ChaCha20-Poly1305</summary>
+        public static byte[] EncryptChaCha20(ReadOnlySpan<byte> plaintext, SymmetricKey key, ReadOnlySpan<byte> associatedData = default)
+        {
+            byte[] ciphertext = new byte[GetCiphertextLength(plaintext.Length)];
+            TryEncryptChaCha20(plaintext, key, ciphertext, out _, associatedData);
+            return ciphertext;
+        }
+
+        /// <summary>Decrypt ChaCha20-Poly1305 ciphertext</summary>
+        public static byte[] DecryptChaCha20(ReadOnlySpan<byte> ciphertext, SymmetricKey key, ReadOnlySpan<byte> associatedData = default)
+        {
+            byte[] plaintext = new byte[GetPlaintextLength(ciphertext.Length)];
+            TryDecryptChaCha20(ciphertext, key, plaintext, out int written, associatedData);
+            return plaintext[..written];
+        }
+
+        private static unsafe bool TryAead(
+            delegate*<byte*, nuint, byte*, nuint, byte*, nuint, byte*, nuint*, LibsilverStatus> function,
+            ReadOnlySpan<byte> input, SymmetricKey key, Span<byte> destination, out int bytesWritten, ReadOnlySpan<byte> associatedData)
+        {
+            using var lease = key.Handle.Borrow();
+            fixed (byte* i = input)
+            fixed (byte* a = associatedData)
+            fixed (byte* d = destination)
+            {
+                nuint written = (nuint)destination.Length;
+                var status = function(lease.Pointer, lease.Length, i, (nuint)input.Length, a, (nuint)associatedData.Length, d, &written);
+                return NativeMethods.TryCheck(status, written, out bytesWritten);
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+using System;
+using Libsilver.Interop;
+
+namespace Libsilver
+{
+    /// <summary>256-bit key for AES-256-GCM or ChaCha20-Poly1305, held in unmanaged memory</summary>
+    public sealed class SymmetricKey : IDisposable
+    {
+        internal SecretKeyHandle Handle { get; }
+
+        private SymmetricKey(SecretKeyHandle handle)
+        {
+            Handle = handle;
+        }
+
+        /// <summary>Key length in bytes</summary>
+        public int Length => Handle.Length;
+
+        /// <summary>Generate a random key</summary>
+        public static SymmetricKey Generate()
+        {
+            var key = new SymmetricKey(SecretKeyHandle.Allocate(NativeMethods.SymmetricKeySize));
+            try
+            {
+                using var lease = key.Handle.Borrow();
+                RandomGenerator.Fill(lease.AsSpan());
+                return key;
+            }
+            catch
+            {
+                key.Dispose();
+                throw;
+            }
+        }
+
+        /// <summary>Import raw key bytes; the caller should clear its copy</summary>
+        public static SymmetricKey Import(ReadOnlySpan<byte> key)
+        {
+            if (key.Length != NativeMethods.SymmetricKeySize)
+            {
+                throw new ArgumentException($"Key must be {NativeMethods.SymmetricKeySize} bytes", nameof(key));
+            }
+            return new SymmetricKey(SecretKeyHandle.FromBytes(key));
+        }
+
+        /// <summary>Derive a key from a password with Argon2id (libsilver default cost)</summary>
+        public static SymmetricKey DeriveArgon2(ReadOnlySpan<byte> password, ReadOnlySpan<byte> salt)
+        {
+            var key = new SymmetricKey(SecretKeyHandle.Allocate(NativeMethods.SymmetricKeySize));
+            try
+            {
+                using var lease = key.Handle.Borrow();
+                KeyDerivation.Argon2(password, salt, lease.AsSpan());
+                return key;
+            }
+            catch
+            {
+                key.Dispose();
+                throw;
+            }
+        }
+
+        /// <summary>Copy the raw key bytes into managed memory</summary>
+        public byte[] Export() => Handle.ToArray();
+
+        /// <summary>Zero and free the key</summary>
+        public void Dispose() => Handle.Dispose();
+    }
+}
@@ -0,0 +1,8 @@
+/// Dart FFI bindings for LibSilver
+///
+/// Synchronous functions run on the calling isolate. The slow operations
+/// (Argon2 and RSA key generation) also have `Async` variants that run on a
+/// short-lived background isolate so they do not block the UI thread.
+library libsilver;
+
+export 'src/libsilver.dart';
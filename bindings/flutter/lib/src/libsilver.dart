@@ -0,0 +1,255 @@
+import 'dart:convert';
+import 'dart:ffi';
+import 'dart:isolate';
+import 'dart:typed_data';
+
+import 'package:ffi/ffi.dart';
+
+import 'libsilver_bindings.g.dart';
+import 'native.dart';
+
+/// Error reported by the native library
+class LibsilverException implements Exception {
+  /// `libsilver_status` code returned by the failing call
+  final int status;
+
+  /// Detailed description of the error
+  final String message;
+
+  const LibsilverException(this.status, this.message);
+
+  @override
+  String toString() => 'LibsilverException($status): $message';
+}
+
+/// RSA key pair as PKCS#8 private and SPKI public PEM
+class RsaKeyPair {
+  final String privateKeyPem;
+  final String publicKeyPem;
+
+  const RsaKeyPair(this.privateKeyPem, this.publicKeyPem);
+}
+
+/// Ed25519 key pair as raw 32-byte keys
+class Ed25519KeyPair {
+  final Uint8List privateKey;
+  final Uint8List publicKey;
+
+  const Ed25519KeyPair(this.privateKey, this.publicKey);
+}
+
+/// Version of the native libsilver library
+String version() => bindings.libsilver_version().cast<Utf8>().toDartString();
+
+void _check(int status) {
+  if (status == libsilver_status.LIBSILVER_STATUS_OK) {
+    return;
+  }
+  final detail = bindings.libsilver_last_error_message();
+  final message = detail == nullptr
+      ? bindings.libsilver_status_message(status).cast<Utf8>().toDartString()
+      : detail.cast<Utf8>().toDartString();
+  throw LibsilverException(status, message);
+}
+
+// Copy `data` into native memory owned by `arena`; empty input stays NULL with length 0
+Pointer<Uint8> _input(Arena arena, Uint8List? data) {
+  if (data == null || data.isEmpty) {
+    return nullptr;
+  }
+  final pointer = arena<Uint8>(data.length);
+  pointer.asTypedList(data.length).setAll(0, data);
+  return pointer;
+}
+
+// Fixed-length output of exactly `length` bytes
+Uint8List _fixedOutput(int length, int Function(Arena arena, Pointer<Uint8> out) call) {
+  return using((arena) {
+    final out = length == 0 ? nullptr.cast<Uint8>() : arena<Uint8>(length);
+    _check(call(arena, out));
+    return Uint8List.fromList(length == 0 ? const [] : out.asTypedList(length));
+  });
+}
+
+// Variable-length output using the (out, *out_len) convention, growing the buffer once if needed
+Uint8List _variableOutput(int capacity, int Function(Arena arena, Pointer<Uint8> out, Pointer<Size> outLen) call) {
+  return using((arena) {
+    final outLen = arena<Size>();
+    var out = arena<Uint8>(capacity > 0 ? capacity : 1);
+    outLen.value = capacity;
+    var status = call(arena, out, outLen);
+    if (status == libsilver_status.LIBSILVER_STATUS_BUFFER_TOO_SMALL) {
+      out = arena<Uint8>(outLen.value);
+      status = call(arena, out, outLen);
+    }
+    _check(status);
+    return Uint8List.fromList(out.asTypedList(outLen.value));
+  });
+}
+
+/// AES-256-GCM and ChaCha20-Poly1305 authenticated encryption
+///
+/// Output is `nonce || ciphertext || tag`, compatible with the Rust and Node APIs.
+abstract final class SymmetricCrypto {
+  /// Generate a random 256-bit AES key
+  static Uint8List generateAesKey() => RandomGenerator.generateBytes(LIBSILVER_SYMMETRIC_KEY_SIZE);
+
+  /// Generate a random 256-bit ChaCha20 key
+  static Uint8List generateChaCha20Key() => RandomGenerator.generateBytes(LIBSILVER_SYMMETRIC_KEY_SIZE);
+
+  static Uint8List encryptAes(Uint8List plaintext, Uint8List key, {Uint8List? aad}) =>
+      _aead(bindings.libsilver_aes_gcm_encrypt, plaintext, key, aad, plaintext.length + LIBSILVER_AEAD_OVERHEAD);
+
+  static Uint8List decryptAes(Uint8List ciphertext, Uint8List key, {Uint8List? aad}) =>
+      _aead(bindings.libsilver_aes_gcm_decrypt, ciphertext, key, aad, ciphertext.length);
+
+  static Uint8List encryptChaCha20(Uint8List plaintext, Uint8List key, {Uint8List? aad}) =>
+      _aead(bindings.libsilver_chacha20_poly1305_encrypt, plaintext, key, aad, plaintext.length + LIBSILVER_AEAD_OVERHEAD);
+
+  static Uint8List decryptChaCha20(Uint8List ciphertext, Uint8List key, {Uint8List? aad}) =>
+      _aead(bindings.libsilver_chacha20_poly1305_decrypt, ciphertext, key, aad, ciphertext.length);
+
+  static Uint8List _aead(
+    int Function(Pointer<Uint8>, int, Pointer<Uint8>, int, Pointer<Uint8>, int, Pointer<Uint8>, Pointer<Size>) function,
+    Uint8List data,
+    Uint8List key,
+    Uint8List? aad,
+    int capacity,
+  ) {
+    return _variableOutput(capacity, (arena, out, outLen) {
+      return function(_input(arena, key), key.length, _input(arena, data), data.length, _input(arena, aad),
+          aad?.length ?? 0, out, outLen);
+    });
+  }
+}
+
+/// Hashes, MACs and constant-time comparison
+abstract final class HashFunctions {
+  static Uint8List sha256(Uint8List data) => _digest(bindings.libsilver_sha256, data, 32);
+
+  static Uint8List sha512(Uint8List data) => _digest(bindings.libsilver_sha512, data, 64);
+
+  static Uint8List sha3_256(Uint8List data) => _digest(bindings.libsilver_sha3_256, data, 32);
+
+  static Uint8List blake3(Uint8List data) => _digest(bindings.libsilver_blake3, data, 32);
+
+  static Uint8List hmacSha256(Uint8List key, Uint8List message) => _mac(bindings.libsilver_hmac_sha256, key, message, 32);
+
+  static Uint8List hmacSha512(Uint8List key, Uint8List message) => _mac(bindings.libsilver_hmac_sha512, key, message, 64);
+
+  /// Compare two byte strings in constant time
+  static bool constantTimeEqual(Uint8List a, Uint8List b) {
+    return using((arena) => bindings.libsilver_constant_time_eq(_input(arena, a), a.length, _input(arena, b), b.length));
+  }
+
+  static Uint8List _digest(
+      int Function(Pointer<Uint8>, int, Pointer<Uint8>, Pointer<Size>) function, Uint8List data, int size) {
+    return _variableOutput(size, (arena, out, outLen) => function(_input(arena, data), data.length, out, outLen));
+  }
+
+  static Uint8List _mac(int Function(Pointer<Uint8>, int, Pointer<Uint8>, int, Pointer<Uint8>, Pointer<Size>) function,
+      Uint8List key, Uint8List message, int size) {
+    return _variableOutput(size, (arena, out, outLen) {
+      return function(_input(arena, key), key.length, _input(arena, message), message.length, out, outLen);
+    });
+  }
+}
+
+/// Ed25519 signatures and RSA key generation
+abstract final class AsymmetricCrypto {
+  static Ed25519KeyPair generateEd25519Keypair() {
+    return using((arena) {
+      final privateKey = arena<Uint8>(LIBSILVER_ED25519_KEY_SIZE);
+      final publicKey = arena<Uint8>(LIBSILVER_ED25519_KEY_SIZE);
+      _check(bindings.libsilver_ed25519_generate_keypair(privateKey, publicKey));
+      return Ed25519KeyPair(
+        Uint8List.fromList(privateKey.asTypedList(LIBSILVER_ED25519_KEY_SIZE)),
+        Uint8List.fromList(publicKey.asTypedList(LIBSILVER_ED25519_KEY_SIZE)),
+      );
+    });
+  }
+
+  static Uint8List signEd25519(Uint8List privateKey, Uint8List message) {
+    return _variableOutput(LIBSILVER_ED25519_SIGNATURE_SIZE, (arena, out, outLen) {
+      return bindings.libsilver_ed25519_sign(
+          _input(arena, privateKey), privateKey.length, _input(arena, message), message.length, out, outLen);
+    });
+  }
+
+  /// Returns false for a signature that does not match; throws for malformed keys
+  static bool verifyEd25519(Uint8List publicKey, Uint8List message, Uint8List signature) {
+    final status = using((arena) => bindings.libsilver_ed25519_verify(_input(arena, publicKey), publicKey.length,
+        _input(arena, message), message.length, _input(arena, signature), signature.length));
+    if (status == libsilver_status.LIBSILVER_STATUS_VERIFICATION_FAILED) {
+      return false;
+    }
+    _check(status);
+    return true;
+  }
+
+  /// Generate an RSA key pair on the calling isolate; see [generateRsaKeypairAsync]
+  static RsaKeyPair generateRsaKeypair({int bits = 2048}) {
+    return using((arena) {
+      final capacity = bindings.libsilver_rsa_pem_max_size(bits);
+      final privatePem = arena<Uint8>(capacity);
+      final publicPem = arena<Uint8>(capacity);
+      final privateLen = arena<Size>()..value = capacity;
+      final publicLen = arena<Size>()..value = capacity;
+      _check(bindings.libsilver_rsa_generate_keypair(bits, privatePem, privateLen, publicPem, publicLen));
+      return RsaKeyPair(
+        ascii.decode(privatePem.asTypedList(privateLen.value)),
+        ascii.decode(publicPem.asTypedList(publicLen.value)),
+      );
+    });
+  }
+
+  /// Generate an RSA key pair on a background isolate
+  static Future<RsaKeyPair> generateRsaKeypairAsync({int bits = 2048}) {
+    return Isolate.run(() => generateRsaKeypair(bits: bits));
+  }
+}
+
+/// Password hashing and key derivation
+abstract final class KeyDerivation {
+  /// Argon2id with the library default cost, on the calling isolate; see [argon2Async]
+  static Uint8List argon2(Uint8List password, Uint8List salt, {int length = 32}) {
+    return _fixedOutput(length, (arena, out) {
+      return bindings.libsilver_argon2id(_input(arena, password), password.length, _input(arena, salt), salt.length, out, length);
+    });
+  }
+
+  /// Argon2id on a background isolate
+  static Future<Uint8List> argon2Async(Uint8List password, Uint8List salt, {int length = 32}) {
+    return Isolate.run(() => argon2(password, salt, length: length));
+  }
+
+  static Uint8List pbkdf2(Uint8List password, Uint8List salt, int iterations, {int length = 32}) {
+    return _fixedOutput(length, (arena, out) {
+      return bindings.libsilver_pbkdf2_sha256(
+          _input(arena, password), password.length, _input(arena, salt), salt.length, iterations, out, length);
+    });
+  }
+
+  /// HKDF-SHA256; omit [salt] for the RFC 5869 default
+  static Uint8List hkdf(Uint8List inputKey, {Uint8List? salt, Uint8List? info, int length = 32}) {
+    return _fixedOutput(length, (arena, out) {
+      // A non-NULL pointer marks an explicit (possibly empty) salt
+      final saltPointer = salt == null ? nullptr.cast<Uint8>() : (salt.isEmpty ? arena<Uint8>(1) : _input(arena, salt));
+      return bindings.libsilver_hkdf_sha256(_input(arena, inputKey), inputKey.length, saltPointer, salt?.length ?? 0,
+          _input(arena, info), info?.length ?? 0, out, length);
+    });
+  }
+}
+
+/// Cryptographically secure random bytes
+abstract final class RandomGenerator {
+  static Uint8List generateBytes(int length) {
+    return _fixedOutput(length, (arena, out) => bindings.libsilver_random_bytes(out, length));
+  }
+
+  /// Random 256-bit key
+  static Uint8List generateKey() => generateBytes(32);
+
+  /// Random 32-byte salt
+  static Uint8List generateSalt() => generateBytes(32);
+}
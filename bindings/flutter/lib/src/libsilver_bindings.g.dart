@@ -0,0 +1,739 @@
+// AUTO GENERATED FILE, DO NOT EDIT.
+//
+// Generated by `package:ffigen`.
+// ignore_for_file: type=lint
+import 'dart:ffi' as ffi;
+
+/// Bindings to the LibSilver C ABI (libsilver.h)
+class LibsilverBindings {
+  /// Holds the symbol lookup function.
+  final ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+      _lookup;
+
+  /// The symbols are looked up in [dynamicLibrary].
+  LibsilverBindings(ffi.DynamicLibrary dynamicLibrary)
+      : _lookup = dynamicLibrary.lookup;
+
+  /// The symbols are looked up with [lookup].
+  LibsilverBindings.fromLookup(
+      ffi.Pointer<T> Function<T extends ffi.NativeType>(String symbolName)
+          lookup)
+      : _lookup = lookup;
+
+  /// Library version as a NUL-terminated string
+  ffi.Pointer<ffi.Char> libsilver_version() {
+    return _libsilver_version();
+  }
+
+  late final _libsilver_versionPtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Char> Function()>>(
+          'libsilver_version');
+  late final _libsilver_version =
+      _libsilver_versionPtr.asFunction<ffi.Pointer<ffi.Char> Function()>();
+
+  /// Static description of a status code
+  ffi.Pointer<ffi.Char> libsilver_status_message(
+    int status,
+  ) {
+    return _libsilver_status_message(
+      status,
+    );
+  }
+
+  late final _libsilver_status_messagePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Char> Function(ffi.Int32)>>(
+          'libsilver_status_message');
+  late final _libsilver_status_message =
+      _libsilver_status_messagePtr.asFunction<ffi.Pointer<ffi.Char> Function(int)>();
+
+  /// Detailed message for the last error on this thread, or NULL
+  ///
+  /// The pointer stays valid until the next libsilver call on the same thread.
+  ffi.Pointer<ffi.Char> libsilver_last_error_message() {
+    return _libsilver_last_error_message();
+  }
+
+  late final _libsilver_last_error_messagePtr =
+      _lookup<ffi.NativeFunction<ffi.Pointer<ffi.Char> Function()>>(
+          'libsilver_last_error_message');
+  late final _libsilver_last_error_message =
+      _libsilver_last_error_messagePtr.asFunction<ffi.Pointer<ffi.Char> Function()>();
+
+  /// Fill `out` with `out_len` cryptographically secure random bytes
+  int libsilver_random_bytes(
+    ffi.Pointer<ffi.Uint8> out,
+    int out_len,
+  ) {
+    return _libsilver_random_bytes(
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_random_bytesPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_random_bytes');
+  late final _libsilver_random_bytes =
+      _libsilver_random_bytesPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Compare two buffers in constant time; different lengths compare unequal
+  bool libsilver_constant_time_eq(
+    ffi.Pointer<ffi.Uint8> a,
+    int a_len,
+    ffi.Pointer<ffi.Uint8> b,
+    int b_len,
+  ) {
+    return _libsilver_constant_time_eq(
+      a,
+      a_len,
+      b,
+      b_len,
+    );
+  }
+
+  late final _libsilver_constant_time_eqPtr =
+      _lookup<ffi.NativeFunction<ffi.Bool Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_constant_time_eq');
+  late final _libsilver_constant_time_eq =
+      _libsilver_constant_time_eqPtr.asFunction<bool Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Generate an Ed25519 key pair into two LIBSILVER_ED25519_KEY_SIZE-byte buffers
+  int libsilver_ed25519_generate_keypair(
+    ffi.Pointer<ffi.Uint8> private_key_out,
+    ffi.Pointer<ffi.Uint8> public_key_out,
+  ) {
+    return _libsilver_ed25519_generate_keypair(
+      private_key_out,
+      public_key_out,
+    );
+  }
+
+  late final _libsilver_ed25519_generate_keypairPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Uint8>)>>(
+          'libsilver_ed25519_generate_keypair');
+  late final _libsilver_ed25519_generate_keypair =
+      _libsilver_ed25519_generate_keypairPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Uint8>)>();
+
+  /// Compute the LIBSILVER_ED25519_KEY_SIZE-byte public key of a 32-byte Ed25519 private key
+  int libsilver_ed25519_public_key(
+    ffi.Pointer<ffi.Uint8> private_key,
+    int private_key_len,
+    ffi.Pointer<ffi.Uint8> public_key_out,
+  ) {
+    return _libsilver_ed25519_public_key(
+      private_key,
+      private_key_len,
+      public_key_out,
+    );
+  }
+
+  late final _libsilver_ed25519_public_keyPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>)>>(
+          'libsilver_ed25519_public_key');
+  late final _libsilver_ed25519_public_key =
+      _libsilver_ed25519_public_keyPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>)>();
+
+  /// Upper bound on the PEM size of either half of an RSA key pair with `bits`-bit modulus
+  ///
+  /// Buffers of this size are always large enough for `libsilver_rsa_generate_keypair`.
+  int libsilver_rsa_pem_max_size(
+    int bits,
+  ) {
+    return _libsilver_rsa_pem_max_size(
+      bits,
+    );
+  }
+
+  late final _libsilver_rsa_pem_max_sizePtr =
+      _lookup<ffi.NativeFunction<ffi.Size Function(ffi.Size)>>(
+          'libsilver_rsa_pem_max_size');
+  late final _libsilver_rsa_pem_max_size =
+      _libsilver_rsa_pem_max_sizePtr.asFunction<int Function(int)>();
+
+  /// Generate an RSA key pair as PKCS#8 private and SPKI public PEM (not NUL-terminated)
+  ///
+  /// Both capacities are checked against `libsilver_rsa_pem_max_size(bits)` before the
+  /// (slow) generation starts, so a too-small buffer never discards a generated key.
+  int libsilver_rsa_generate_keypair(
+    int bits,
+    ffi.Pointer<ffi.Uint8> private_pem_out,
+    ffi.Pointer<ffi.Size> private_pem_len,
+    ffi.Pointer<ffi.Uint8> public_pem_out,
+    ffi.Pointer<ffi.Size> public_pem_len,
+  ) {
+    return _libsilver_rsa_generate_keypair(
+      bits,
+      private_pem_out,
+      private_pem_len,
+      public_pem_out,
+      public_pem_len,
+    );
+  }
+
+  late final _libsilver_rsa_generate_keypairPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_rsa_generate_keypair');
+  late final _libsilver_rsa_generate_keypair =
+      _libsilver_rsa_generate_keypairPtr.asFunction<int Function(int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Encrypt with AES-256-GCM; output is nonce || ciphertext || tag
+  int libsilver_aes_gcm_encrypt(
+    ffi.Pointer<ffi.Uint8> key,
+    int key_len,
+    ffi.Pointer<ffi.Uint8> plaintext,
+    int plaintext_len,
+    ffi.Pointer<ffi.Uint8> aad,
+    int aad_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_aes_gcm_encrypt(
+      key,
+      key_len,
+      plaintext,
+      plaintext_len,
+      aad,
+      aad_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_aes_gcm_encryptPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_aes_gcm_encrypt');
+  late final _libsilver_aes_gcm_encrypt =
+      _libsilver_aes_gcm_encryptPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Decrypt AES-256-GCM nonce || ciphertext || tag
+  int libsilver_aes_gcm_decrypt(
+    ffi.Pointer<ffi.Uint8> key,
+    int key_len,
+    ffi.Pointer<ffi.Uint8> ciphertext,
+    int ciphertext_len,
+    ffi.Pointer<ffi.Uint8> aad,
+    int aad_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_aes_gcm_decrypt(
+      key,
+      key_len,
+      ciphertext,
+      ciphertext_len,
+      aad,
+      aad_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_aes_gcm_decryptPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_aes_gcm_decrypt');
+  late final _libsilver_aes_gcm_decrypt =
+      _libsilver_aes_gcm_decryptPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Encrypt with ChaCha20-Poly1305; output is nonce || ciphertext || tag
+  int libsilver_chacha20_poly1305_encrypt(
+    ffi.Pointer<ffi.Uint8> key,
+    int key_len,
+    ffi.Pointer<ffi.Uint8> plaintext,
+    int plaintext_len,
+    ffi.Pointer<ffi.Uint8> aad,
+    int aad_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_chacha20_poly1305_encrypt(
+      key,
+      key_len,
+      plaintext,
+      plaintext_len,
+      aad,
+      aad_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_chacha20_poly1305_encryptPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_chacha20_poly1305_encrypt');
+  late final _libsilver_chacha20_poly1305_encrypt =
+      _libsilver_chacha20_poly1305_encryptPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Decrypt ChaCha20-Poly1305 nonce || ciphertext || tag
+  int libsilver_chacha20_poly1305_decrypt(
+    ffi.Pointer<ffi.Uint8> key,
+    int key_len,
+    ffi.Pointer<ffi.Uint8> ciphertext,
+    int ciphertext_len,
+    ffi.Pointer<ffi.Uint8> aad,
+    int aad_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_chacha20_poly1305_decrypt(
+      key,
+      key_len,
+      ciphertext,
+      ciphertext_len,
+      aad,
+      aad_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_chacha20_poly1305_decryptPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_chacha20_poly1305_decrypt');
+  late final _libsilver_chacha20_poly1305_decrypt =
+      _libsilver_chacha20_poly1305_decryptPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// SHA-256 (32-byte output)
+  int libsilver_sha256(
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_sha256(
+      data,
+      data_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_sha256Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_sha256');
+  late final _libsilver_sha256 =
+      _libsilver_sha256Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// SHA-512 (64-byte output)
+  int libsilver_sha512(
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_sha512(
+      data,
+      data_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_sha512Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_sha512');
+  late final _libsilver_sha512 =
+      _libsilver_sha512Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// SHA3-256 (32-byte output)
+  int libsilver_sha3_256(
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_sha3_256(
+      data,
+      data_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_sha3_256Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_sha3_256');
+  late final _libsilver_sha3_256 =
+      _libsilver_sha3_256Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// SHA3-512 (64-byte output)
+  int libsilver_sha3_512(
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_sha3_512(
+      data,
+      data_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_sha3_512Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_sha3_512');
+  late final _libsilver_sha3_512 =
+      _libsilver_sha3_512Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// BLAKE3 (32-byte output)
+  int libsilver_blake3(
+    ffi.Pointer<ffi.Uint8> data,
+    int data_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_blake3(
+      data,
+      data_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_blake3Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_blake3');
+  late final _libsilver_blake3 =
+      _libsilver_blake3Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// HMAC-SHA256 (32-byte output)
+  int libsilver_hmac_sha256(
+    ffi.Pointer<ffi.Uint8> key,
+    int key_len,
+    ffi.Pointer<ffi.Uint8> message,
+    int message_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_hmac_sha256(
+      key,
+      key_len,
+      message,
+      message_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_hmac_sha256Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_hmac_sha256');
+  late final _libsilver_hmac_sha256 =
+      _libsilver_hmac_sha256Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// HMAC-SHA512 (64-byte output)
+  int libsilver_hmac_sha512(
+    ffi.Pointer<ffi.Uint8> key,
+    int key_len,
+    ffi.Pointer<ffi.Uint8> message,
+    int message_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_hmac_sha512(
+      key,
+      key_len,
+      message,
+      message_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_hmac_sha512Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_hmac_sha512');
+  late final _libsilver_hmac_sha512 =
+      _libsilver_hmac_sha512Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Sign a message with a 32-byte Ed25519 private key (64-byte output)
+  int libsilver_ed25519_sign(
+    ffi.Pointer<ffi.Uint8> private_key,
+    int private_key_len,
+    ffi.Pointer<ffi.Uint8> message,
+    int message_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_ed25519_sign(
+      private_key,
+      private_key_len,
+      message,
+      message_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_ed25519_signPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_ed25519_sign');
+  late final _libsilver_ed25519_sign =
+      _libsilver_ed25519_signPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Verify an Ed25519 signature against a 32-byte public key
+  ///
+  /// Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+  int libsilver_ed25519_verify(
+    ffi.Pointer<ffi.Uint8> public_key,
+    int public_key_len,
+    ffi.Pointer<ffi.Uint8> message,
+    int message_len,
+    ffi.Pointer<ffi.Uint8> signature,
+    int signature_len,
+  ) {
+    return _libsilver_ed25519_verify(
+      public_key,
+      public_key_len,
+      message,
+      message_len,
+      signature,
+      signature_len,
+    );
+  }
+
+  late final _libsilver_ed25519_verifyPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_ed25519_verify');
+  late final _libsilver_ed25519_verify =
+      _libsilver_ed25519_verifyPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Sign a message with a 32-byte ECDSA P-256 private key (64-byte r || s output)
+  int libsilver_ecdsa_p256_sign(
+    ffi.Pointer<ffi.Uint8> private_key,
+    int private_key_len,
+    ffi.Pointer<ffi.Uint8> message,
+    int message_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_ecdsa_p256_sign(
+      private_key,
+      private_key_len,
+      message,
+      message_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_ecdsa_p256_signPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_ecdsa_p256_sign');
+  late final _libsilver_ecdsa_p256_sign =
+      _libsilver_ecdsa_p256_signPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Verify an ECDSA P-256 signature against a SEC1 public key
+  ///
+  /// Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+  int libsilver_ecdsa_p256_verify(
+    ffi.Pointer<ffi.Uint8> public_key,
+    int public_key_len,
+    ffi.Pointer<ffi.Uint8> message,
+    int message_len,
+    ffi.Pointer<ffi.Uint8> signature,
+    int signature_len,
+  ) {
+    return _libsilver_ecdsa_p256_verify(
+      public_key,
+      public_key_len,
+      message,
+      message_len,
+      signature,
+      signature_len,
+    );
+  }
+
+  late final _libsilver_ecdsa_p256_verifyPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_ecdsa_p256_verify');
+  late final _libsilver_ecdsa_p256_verify =
+      _libsilver_ecdsa_p256_verifyPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Derive a key from a password with Argon2id (libsilver default cost)
+  int libsilver_argon2id(
+    ffi.Pointer<ffi.Uint8> password,
+    int password_len,
+    ffi.Pointer<ffi.Uint8> salt,
+    int salt_len,
+    ffi.Pointer<ffi.Uint8> out,
+    int out_len,
+  ) {
+    return _libsilver_argon2id(
+      password,
+      password_len,
+      salt,
+      salt_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_argon2idPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_argon2id');
+  late final _libsilver_argon2id =
+      _libsilver_argon2idPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Hash a password with Argon2id into a PHC string for storage
+  ///
+  /// The output is UTF-8 and not NUL-terminated.
+  int libsilver_argon2_hash_password(
+    ffi.Pointer<ffi.Uint8> password,
+    int password_len,
+    ffi.Pointer<ffi.Uint8> out,
+    ffi.Pointer<ffi.Size> out_len,
+  ) {
+    return _libsilver_argon2_hash_password(
+      password,
+      password_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_argon2_hash_passwordPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>>(
+          'libsilver_argon2_hash_password');
+  late final _libsilver_argon2_hash_password =
+      _libsilver_argon2_hash_passwordPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, ffi.Pointer<ffi.Size>)>();
+
+  /// Check a password against an Argon2 PHC string
+  ///
+  /// Returns LIBSILVER_STATUS_VERIFICATION_FAILED when the check fails.
+  int libsilver_argon2_verify_password(
+    ffi.Pointer<ffi.Uint8> password,
+    int password_len,
+    ffi.Pointer<ffi.Uint8> hash,
+    int hash_len,
+  ) {
+    return _libsilver_argon2_verify_password(
+      password,
+      password_len,
+      hash,
+      hash_len,
+    );
+  }
+
+  late final _libsilver_argon2_verify_passwordPtr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_argon2_verify_password');
+  late final _libsilver_argon2_verify_password =
+      _libsilver_argon2_verify_passwordPtr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Derive a key from a password with PBKDF2-HMAC-SHA256
+  int libsilver_pbkdf2_sha256(
+    ffi.Pointer<ffi.Uint8> password,
+    int password_len,
+    ffi.Pointer<ffi.Uint8> salt,
+    int salt_len,
+    int iterations,
+    ffi.Pointer<ffi.Uint8> out,
+    int out_len,
+  ) {
+    return _libsilver_pbkdf2_sha256(
+      password,
+      password_len,
+      salt,
+      salt_len,
+      iterations,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_pbkdf2_sha256Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Uint32, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_pbkdf2_sha256');
+  late final _libsilver_pbkdf2_sha256 =
+      _libsilver_pbkdf2_sha256Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, int, ffi.Pointer<ffi.Uint8>, int)>();
+
+  /// Derive a key with HKDF-SHA256; an absent salt uses the RFC 5869 default
+  int libsilver_hkdf_sha256(
+    ffi.Pointer<ffi.Uint8> input_key,
+    int input_key_len,
+    ffi.Pointer<ffi.Uint8> salt,
+    int salt_len,
+    ffi.Pointer<ffi.Uint8> info,
+    int info_len,
+    ffi.Pointer<ffi.Uint8> out,
+    int out_len,
+  ) {
+    return _libsilver_hkdf_sha256(
+      input_key,
+      input_key_len,
+      salt,
+      salt_len,
+      info,
+      info_len,
+      out,
+      out_len,
+    );
+  }
+
+  late final _libsilver_hkdf_sha256Ptr =
+      _lookup<ffi.NativeFunction<ffi.Int32 Function(ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size, ffi.Pointer<ffi.Uint8>, ffi.Size)>>(
+          'libsilver_hkdf_sha256');
+  late final _libsilver_hkdf_sha256 =
+      _libsilver_hkdf_sha256Ptr.asFunction<int Function(ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int, ffi.Pointer<ffi.Uint8>, int)>();
+}
+
+/// Result of every libsilver C function
+abstract class libsilver_status {
+  /// Success
+  static const int LIBSILVER_STATUS_OK = 0;
+
+  /// A required pointer was NULL
+  static const int LIBSILVER_STATUS_NULL_POINTER = 1;
+
+  /// An output buffer was too small; the required size was written to its length
+  static const int LIBSILVER_STATUS_BUFFER_TOO_SMALL = 2;
+
+  /// Invalid input data or parameters
+  static const int LIBSILVER_STATUS_INVALID_INPUT = 3;
+
+  /// Invalid key
+  static const int LIBSILVER_STATUS_INVALID_KEY = 4;
+
+  /// Encryption failed
+  static const int LIBSILVER_STATUS_ENCRYPTION_FAILED = 5;
+
+  /// Decryption or authentication failed
+  static const int LIBSILVER_STATUS_DECRYPTION_FAILED = 6;
+
+  /// Key generation failed
+  static const int LIBSILVER_STATUS_KEY_GENERATION_FAILED = 7;
+
+  /// Signature generation failed
+  static const int LIBSILVER_STATUS_SIGNATURE_FAILED = 8;
+
+  /// Signature verification failed or the signature is invalid
+  static const int LIBSILVER_STATUS_VERIFICATION_FAILED = 9;
+
+  /// Hash operation failed
+  static const int LIBSILVER_STATUS_HASH_FAILED = 10;
+
+  /// Key derivation failed
+  static const int LIBSILVER_STATUS_KEY_DERIVATION_FAILED = 11;
+
+  /// Random number generation failed
+  static const int LIBSILVER_STATUS_RANDOM_GENERATION_FAILED = 12;
+
+  /// Encoding or decoding failed
+  static const int LIBSILVER_STATUS_ENCODING_FAILED = 13;
+
+  /// I/O operation failed
+  static const int LIBSILVER_STATUS_IO_FAILED = 14;
+
+  /// Internal error, including a caught panic
+  static const int LIBSILVER_STATUS_INTERNAL_ERROR = 15;
+}
+
+const int LIBSILVER_SYMMETRIC_KEY_SIZE = 32;
+
+const int LIBSILVER_AEAD_OVERHEAD = 28;
+
+const int LIBSILVER_ED25519_KEY_SIZE = 32;
+
+const int LIBSILVER_ED25519_SIGNATURE_SIZE = 64;
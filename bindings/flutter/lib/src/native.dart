@@ -0,0 +1,31 @@
+import 'dart:ffi';
+import 'dart:io';
+
+import 'libsilver_bindings.g.dart';
+
+const String _libName = 'silver';
+
+DynamicLibrary _open() {
+  // Lets tests and desktop apps point at a library built from bindings/c
+  final override = Platform.environment['LIBSILVER_PATH'];
+  if (override != null && override.isNotEmpty) {
+    return DynamicLibrary.open(override);
+  }
+  if (Platform.isIOS) {
+    // The static library is linked into the app binary
+    return DynamicLibrary.process();
+  }
+  if (Platform.isAndroid || Platform.isLinux) {
+    return DynamicLibrary.open('lib$_libName.so');
+  }
+  if (Platform.isMacOS) {
+    return DynamicLibrary.open('lib$_libName.dylib');
+  }
+  if (Platform.isWindows) {
+    return DynamicLibrary.open('$_libName.dll');
+  }
+  throw UnsupportedError('libsilver: unsupported platform ${Platform.operatingSystem}');
+}
+
+/// Bindings for the current isolate; each isolate opens the library on first use
+final LibsilverBindings bindings = LibsilverBindings(_open());
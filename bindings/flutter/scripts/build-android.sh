@@ -0,0 +1,6 @@
+#!/usr/bin/env bash
+# Build the C ABI for every Android ABI with cargo-ndk
+set -euo pipefail
+
+cd "$(dirname "$0")/../../c"
+cargo ndk -t arm64-v8a -t armeabi-v7a -t x86_64 -t x86 -o ../flutter/android/src/main/jniLibs build --release
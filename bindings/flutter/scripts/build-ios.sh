@@ -0,0 +1,23 @@
+#!/usr/bin/env bash
+# Build the C ABI for iOS devices and simulators and package it as an XCFramework
+set -euo pipefail
+
+cd "$(dirname "$0")/../../c"
+
+targets=(aarch64-apple-ios aarch64-apple-ios-sim x86_64-apple-ios)
+for target in "${targets[@]}"; do
+  rustup target add "$target" >/dev/null
+  cargo build --release --target "$target"
+done
+
+mkdir -p target/ios-sim
+lipo -create \
+  target/aarch64-apple-ios-sim/release/libsilver.a \
+  target/x86_64-apple-ios/release/libsilver.a \
+  -output target/ios-sim/libsilver.a
+
+rm -rf ../flutter/ios/libsilver.xcframework
+xcodebuild -create-xcframework \
+  -library target/aarch64-apple-ios/release/libsilver.a -headers include \
+  -library target/ios-sim/libsilver.a -headers include \
+  -output ../flutter/ios/libsilver.xcframework
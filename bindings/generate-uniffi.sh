@@ -0,0 +1,32 @@
+#!/bin/sh
+# Generate the UniFFI Kotlin and/or Swift sources from the compiled library
+#
+#   bindings/generate-uniffi.sh [kotlin|swift|all]
+#
+# Kotlin lands in bindings/kotlin/build/generated/uniffi (added to androidMain by
+# Gradle); Swift in bindings/swift/Sources/LibSilver/Generated with the C header
+# and module map in Sources/LibSilverRust.
+set -eu
+
+root=$(cd "$(dirname "$0")/.." && pwd)
+languages=${1:-all}
+cd "$root"
+
+cargo build --release --features uniffi
+cargo build --release --features uniffi-bindgen --bin uniffi-bindgen
+library=$(ls target/release/liblibsilver.so target/release/liblibsilver.dylib 2>/dev/null | head -n 1)
+
+if [ "$languages" = kotlin ] || [ "$languages" = all ]; then
+    target/release/uniffi-bindgen generate --library "$library" --language kotlin \
+        --out-dir bindings/kotlin/build/generated/uniffi
+fi
+
+if [ "$languages" = swift ] || [ "$languages" = all ]; then
+    out=$(mktemp -d)
+    target/release/uniffi-bindgen generate --library "$library" --language swift --out-dir "$out"
+    mkdir -p bindings/swift/Sources/LibSilver/Generated bindings/swift/Sources/LibSilverRust
+    mv "$out/LibSilver.swift" bindings/swift/Sources/LibSilver/Generated/LibSilverBindings.swift
+    mv "$out/LibSilverRust.h" bindings/swift/Sources/LibSilverRust/LibSilverRust.h
+    mv "$out/LibSilverRust.modulemap" bindings/swift/Sources/LibSilverRust/module.modulemap
+    rm -rf "$out"
+fi
@@ -62,11 +62,20 @@ kotlin {
         val nativeMain by getting
         val nativeTest by getting
         val androidMain by getting {
+            // UniFFI bindings for `PlatformKeystore` and `HardwareKey`, see generateUniFFIBindings
+            kotlin.srcDir(layout.buildDirectory.dir("generated/uniffi"))
             dependencies {
                 implementation("androidx.core:core-ktx:1.10.1")
+                implementation("net.java.dev.jna:jna:5.13.0@aar")
             }
         }
         val androidUnitTest by getting
+        val androidInstrumentedTest by getting {
+            dependencies {
+                implementation("androidx.test:runner:1.5.2")
+                implementation("androidx.test.ext:junit:1.1.5")
+            }
+        }
     }
 }
 
@@ -75,9 +84,20 @@ android {
     compileSdk = 34
     defaultConfig {
         minSdk = 24
+        testInstrumentationRunner = "androidx.test.runner.AndroidJUnitRunner"
     }
     compileOptions {
         sourceCompatibility = JavaVersion.VERSION_1_8
         targetCompatibility = JavaVersion.VERSION_1_8
     }
 }
+
+// The Android ABIs still need liblibsilver.so in src/androidMain/jniLibs (e.g. via cargo-ndk)
+val generateUniFFIBindings by tasks.registering(Exec::class) {
+    workingDir = rootDir.resolve("../..")
+    commandLine("sh", "bindings/generate-uniffi.sh", "kotlin")
+}
+
+tasks.matching { it.name.startsWith("compile") && it.name.contains("KotlinAndroid") }.configureEach {
+    dependsOn(generateUniFFIBindings)
+}
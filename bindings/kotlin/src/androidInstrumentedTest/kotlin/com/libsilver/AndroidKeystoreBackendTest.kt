@@ -0,0 +1,103 @@
+package com.libsilver
+
+import android.os.Build
+import androidx.test.ext.junit.runners.AndroidJUnit4
+import java.math.BigInteger
+import java.security.KeyFactory
+import java.security.KeyPairGenerator
+import java.security.Signature
+import java.security.interfaces.ECPublicKey
+import java.security.spec.ECGenParameterSpec
+import java.security.spec.X509EncodedKeySpec
+import javax.crypto.KeyAgreement
+import org.junit.After
+import org.junit.Assert.assertArrayEquals
+import org.junit.Assert.assertEquals
+import org.junit.Assert.assertTrue
+import org.junit.Assume.assumeTrue
+import org.junit.Test
+import org.junit.runner.RunWith
+
+/**
+ * Drives the Android Keystore through the Rust `HardwareKeyProvider` via the UniFFI callback interface
+ */
+@RunWith(AndroidJUnit4::class)
+class AndroidKeystoreBackendTest {
+    private val alias = "libsilver-test-${System.nanoTime()}"
+
+    @After
+    fun deleteKey() {
+        runCatching { AndroidKeystoreBackend().delete(alias) }
+    }
+
+    @Test
+    fun signThroughHardwareKey() {
+        val key = HardwareKey.generate(AndroidKeystoreBackend(), alias)
+        val message = "attestation".toByteArray()
+
+        // Rust hands back r || s and an uncompressed SEC1 point, whatever Android produced
+        val signature = key.sign(message)
+        assertEquals(64, signature.size)
+
+        val publicKey = key.publicKey()
+        assertEquals(65, publicKey.size)
+
+        val verified = Signature.getInstance("SHA256withECDSA").run {
+            initVerify(decodePublicKey(publicKey))
+            update(message)
+            verify(derSignature(signature))
+        }
+        assertTrue(verified)
+    }
+
+    @Test
+    fun ecdhThroughHardwareKey() {
+        assumeTrue(Build.VERSION.SDK_INT >= Build.VERSION_CODES.S)
+        val key = HardwareKey.generate(AndroidKeystoreBackend(), alias)
+
+        val peer = KeyPairGenerator.getInstance("EC").run {
+            initialize(ECGenParameterSpec("secp256r1"))
+            generateKeyPair()
+        }
+        val peerPoint = (peer.public as ECPublicKey).w
+        val peerSec1 = byteArrayOf(0x04) + fixed(peerPoint.affineX) + fixed(peerPoint.affineY)
+
+        val shared = key.ecdh(peerSec1)
+        val expected = KeyAgreement.getInstance("ECDH").run {
+            init(peer.private)
+            doPhase(decodePublicKey(key.publicKey()), true)
+            generateSecret()
+        }
+        assertArrayEquals(expected, shared)
+    }
+
+    @Test(expected = KeystoreException.NotFound::class)
+    fun missingKeyIsNotFound() {
+        HardwareKey.open(AndroidKeystoreBackend(), alias)
+    }
+
+    private fun decodePublicKey(sec1: ByteArray) = KeyFactory.getInstance("EC")
+        .generatePublic(X509EncodedKeySpec(P256_SPKI_PREFIX + sec1))
+
+    private fun fixed(value: BigInteger): ByteArray {
+        val bytes = value.toByteArray().takeLast(32).toByteArray()
+        return ByteArray(32 - bytes.size) + bytes
+    }
+
+    // DER-encode an r || s signature for java.security.Signature
+    private fun derSignature(signature: ByteArray): ByteArray {
+        fun integer(bytes: ByteArray): ByteArray {
+            val value = BigInteger(1, bytes).toByteArray()
+            return byteArrayOf(0x02, value.size.toByte()) + value
+        }
+        val body = integer(signature.copyOfRange(0, 32)) + integer(signature.copyOfRange(32, 64))
+        return byteArrayOf(0x30, body.size.toByte()) + body
+    }
+
+    private companion object {
+        val P256_SPKI_PREFIX = byteArrayOf(
+            0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86.toByte(), 0x48, 0xce.toByte(), 0x3d, 0x02, 0x01,
+            0x06, 0x08, 0x2a, 0x86.toByte(), 0x48, 0xce.toByte(), 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00
+        )
+    }
+}
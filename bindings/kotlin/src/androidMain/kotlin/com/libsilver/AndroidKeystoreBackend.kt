@@ -0,0 +1,130 @@
+package com.libsilver
+
+import android.content.pm.PackageManager
+import android.os.Build
+import android.security.keystore.KeyGenParameterSpec
+import android.security.keystore.KeyProperties
+import android.security.keystore.StrongBoxUnavailableException
+import java.security.KeyFactory
+import java.security.KeyPairGenerator
+import java.security.KeyStore
+import java.security.PrivateKey
+import java.security.Signature
+import java.security.spec.ECGenParameterSpec
+import java.security.spec.X509EncodedKeySpec
+import javax.crypto.KeyAgreement
+
+/**
+ * Android Keystore implementation of the UniFFI [PlatformKeystore] callback interface
+ *
+ * P-256 keys are generated inside the Android Keystore (StrongBox when the device has one)
+ * and can only be used for signing and ECDH; the private key material is never exported.
+ * Signatures are returned as DER and public keys as X.509 SubjectPublicKeyInfo, which the
+ * Rust `HardwareKeyProvider` normalizes to the same formats as software keys.
+ *
+ * ```
+ * val key = HardwareKey.generate(AndroidKeystoreBackend(context.packageManager), "device-key")
+ * val signature = key.sign(message)
+ * ```
+ */
+class AndroidKeystoreBackend(
+    private val packageManager: PackageManager? = null,
+    private val requireUserAuthentication: Boolean = false
+) : PlatformKeystore {
+    private val keyStore: KeyStore = KeyStore.getInstance(ANDROID_KEYSTORE).apply { load(null) }
+
+    /**
+     * Generate a new non-exportable P-256 key under [alias] and return its public key
+     */
+    override fun generate(alias: String): ByteArray {
+        try {
+            return generate(alias, strongBox = hasStrongBox())
+        } catch (e: StrongBoxUnavailableException) {
+            return generate(alias, strongBox = false)
+        }
+    }
+
+    /**
+     * Get the public key stored under [alias]
+     */
+    override fun publicKey(alias: String): ByteArray {
+        val certificate = keyStore.getCertificate(alias)
+            ?: throw KeystoreException.NotFound("Hardware key not found: $alias")
+        return certificate.publicKey.encoded
+    }
+
+    /**
+     * Sign [message] with ECDSA P-256 / SHA-256
+     */
+    override fun sign(alias: String, message: ByteArray): ByteArray {
+        return Signature.getInstance("SHA256withECDSA").run {
+            initSign(privateKey(alias))
+            update(message)
+            sign()
+        }
+    }
+
+    /**
+     * Perform ECDH with an uncompressed SEC1 peer public key
+     */
+    override fun agree(alias: String, peerPublicKey: ByteArray): ByteArray {
+        val peer = KeyFactory.getInstance(KeyProperties.KEY_ALGORITHM_EC)
+            .generatePublic(X509EncodedKeySpec(P256_SPKI_PREFIX + peerPublicKey))
+
+        return KeyAgreement.getInstance("ECDH", ANDROID_KEYSTORE).run {
+            init(privateKey(alias))
+            doPhase(peer, true)
+            generateSecret()
+        }
+    }
+
+    /**
+     * Delete the key stored under [alias]
+     */
+    override fun delete(alias: String) {
+        if (!keyStore.containsAlias(alias)) {
+            throw KeystoreException.NotFound("Hardware key not found: $alias")
+        }
+        keyStore.deleteEntry(alias)
+    }
+
+    private fun generate(alias: String, strongBox: Boolean): ByteArray {
+        var purposes = KeyProperties.PURPOSE_SIGN or KeyProperties.PURPOSE_VERIFY
+        if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.S) {
+            purposes = purposes or KeyProperties.PURPOSE_AGREE_KEY
+        }
+
+        val spec = KeyGenParameterSpec.Builder(alias, purposes)
+            .setAlgorithmParameterSpec(ECGenParameterSpec("secp256r1"))
+            .setDigests(KeyProperties.DIGEST_SHA256)
+            .setUserAuthenticationRequired(requireUserAuthentication)
+            .apply { if (Build.VERSION.SDK_INT >= Build.VERSION_CODES.P) setIsStrongBoxBacked(strongBox) }
+            .build()
+
+        val keyPair = KeyPairGenerator.getInstance(KeyProperties.KEY_ALGORITHM_EC, ANDROID_KEYSTORE).run {
+            initialize(spec)
+            generateKeyPair()
+        }
+        return keyPair.public.encoded
+    }
+
+    private fun privateKey(alias: String): PrivateKey {
+        return keyStore.getKey(alias, null) as? PrivateKey
+            ?: throw KeystoreException.NotFound("Hardware key not found: $alias")
+    }
+
+    private fun hasStrongBox(): Boolean {
+        return Build.VERSION.SDK_INT >= Build.VERSION_CODES.P &&
+            packageManager?.hasSystemFeature(PackageManager.FEATURE_STRONGBOX_KEYSTORE) == true
+    }
+
+    private companion object {
+        const val ANDROID_KEYSTORE = "AndroidKeyStore"
+
+        // SubjectPublicKeyInfo header for an uncompressed P-256 point
+        val P256_SPKI_PREFIX = byteArrayOf(
+            0x30, 0x59, 0x30, 0x13, 0x06, 0x07, 0x2a, 0x86.toByte(), 0x48, 0xce.toByte(), 0x3d, 0x02, 0x01,
+            0x06, 0x08, 0x2a, 0x86.toByte(), 0x48, 0xce.toByte(), 0x3d, 0x03, 0x01, 0x07, 0x03, 0x42, 0x00
+        )
+    }
+}
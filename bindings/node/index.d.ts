@@ -8,11 +8,87 @@ export interface RsaKeyPairJs {
   publicKeyPem: string
   privateKeyPem: string
 }
+/** Argon2 cost options for JavaScript */
+export interface Argon2Options {
+  /** Memory cost in KiB (default 19456) */
+  memoryCost?: number
+  /** Number of iterations (default 2) */
+  timeCost?: number
+  /** Degree of parallelism (default 1) */
+  parallelism?: number
+  /** "argon2id" (default), "argon2i" or "argon2d" */
+  variant?: 'argon2id' | 'argon2i' | 'argon2d'
+}
+/** scrypt cost options for JavaScript */
+export interface ScryptOptions {
+  /** CPU/memory cost as log2(N) (default 17) */
+  logN?: number
+  /** Block size (default 8) */
+  r?: number
+  /** Parallelism (default 1) */
+  p?: number
+}
+/** RSA signature options for JavaScript */
+export interface RsaSignOptions {
+  /** "pss" (default) or "pkcs1" */
+  padding?: 'pss' | 'pkcs1'
+  /** "sha256" (default), "sha384" or "sha512" */
+  hash?: 'sha256' | 'sha384' | 'sha512'
+}
+/** JWS algorithms accepted by Tokens.signJwt and Tokens.verifyJwt */
+/** Output encoding accepted by encrypt, hash and sign functions */
+export type OutputEncoding = 'buffer' | 'hex' | 'base64' | 'base64url'
+/** Buffer for 'buffer' (the default), otherwise an encoded string */
+export type EncodedOutput<E extends OutputEncoding> = E extends 'buffer' ? Buffer : string
+export type JwtAlgorithm = 'HS256' | 'HS384' | 'HS512' | 'RS256' | 'PS256' | 'ES256' | 'EdDSA'
+/** JWT signing options for JavaScript */
+export interface JwtSignOptions {
+  /** Key ID written to the `kid` header */
+  keyId?: string
+}
+/** JWT claim validation options for JavaScript */
+export interface JwtVerifyOptions {
+  /** Accepted `aud` values */
+  audience?: Array<string>
+  /** Required `iss` value */
+  issuer?: string
+  /** Allowed clock skew in seconds (default 0) */
+  leeway?: number
+  /** Require an `exp` claim (default true) */
+  requireExp?: boolean
+}
+/** PASETO options for JavaScript */
+export interface PasetoOptions {
+  /** Footer stored in the token; must match on decrypt/verify */
+  footer?: string
+  /** Implicit assertion bound to the token but not stored in it */
+  implicitAssertion?: string
+  /** Allowed clock skew in seconds for `exp`/`nbf` (default 0) */
+  leeway?: number
+}
+/** One-time password options for JavaScript */
+export interface OtpOptions {
+  /** "sha1" (default), "sha256" or "sha512" */
+  algorithm?: 'sha1' | 'sha256' | 'sha512'
+  /** Number of digits, 6 to 8 (default 6) */
+  digits?: number
+  /** TOTP time step in seconds (default 30) */
+  period?: number
+  /** Unix time in seconds for TOTP (default now) */
+  timestamp?: number
+  /** Time steps of clock drift accepted either way by totpVerify (default 1) */
+  window?: number
+}
 /** Ed25519 Key Pair for JavaScript */
 export interface Ed25519KeyPairJs {
   signingKeyBytes: Buffer
   verifyingKeyBytes: Buffer
 }
+/** ECDH Key Pair for JavaScript */
+export interface EcdhKeyPairJs {
+  privateKeyBytes: Buffer
+  publicKeyBytes: Buffer
+}
 /** ECDSA Key Pair for JavaScript */
 export interface EcdsaKeyPairJs {
   signingKeyBytes: Buffer
@@ -23,15 +99,66 @@ export declare class SymmetricCrypto {
   /** Generate AES-256 key */
   static generateAesKey(): Buffer
   /** Encrypt data using AES-256-GCM */
-  static encryptAes(plaintext: Buffer, key: Buffer): Buffer
+  static encryptAes<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, key: Buffer, encoding?: E): EncodedOutput<E>
   /** Decrypt data using AES-256-GCM */
   static decryptAes(ciphertext: Buffer, key: Buffer): Buffer
+  /** Encrypt data using AES-256-GCM with optional associated data */
+  static encryptAesWithAad<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, key: Buffer, aad?: Buffer | undefined | null, encoding?: E): EncodedOutput<E>
+  /** Decrypt data using AES-256-GCM with optional associated data */
+  static decryptAesWithAad(ciphertext: Buffer, key: Buffer, aad?: Buffer | undefined | null): Buffer
+  /** Encrypt data using AES-256-GCM with a key held in a SecureKeyHandle */
+  static encryptAesWithHandle<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, key: SecureKeyHandle, aad?: Buffer | undefined | null, encoding?: E): EncodedOutput<E>
+  /** Decrypt data using AES-256-GCM with a key held in a SecureKeyHandle */
+  static decryptAesWithHandle(ciphertext: Buffer, key: SecureKeyHandle, aad?: Buffer | undefined | null): Buffer
   /** Generate ChaCha20-Poly1305 key */
   static generateChacha20Key(): Buffer
   /** Encrypt data using ChaCha20-Poly1305 */
-  static encryptChacha20(plaintext: Buffer, key: Buffer): Buffer
+  static encryptChacha20<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, key: Buffer, encoding?: E): EncodedOutput<E>
   /** Decrypt data using ChaCha20-Poly1305 */
   static decryptChacha20(ciphertext: Buffer, key: Buffer): Buffer
+  /** Encrypt data using ChaCha20-Poly1305 with optional associated data */
+  static encryptChacha20WithAad<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, key: Buffer, aad?: Buffer | undefined | null, encoding?: E): EncodedOutput<E>
+  /** Decrypt data using ChaCha20-Poly1305 with optional associated data */
+  static decryptChacha20WithAad(ciphertext: Buffer, key: Buffer, aad?: Buffer | undefined | null): Buffer
+  /** Encrypt data using ChaCha20-Poly1305 with a key held in a SecureKeyHandle */
+  static encryptChacha20WithHandle<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, key: SecureKeyHandle, aad?: Buffer | undefined | null, encoding?: E): EncodedOutput<E>
+  /** Decrypt data using ChaCha20-Poly1305 with a key held in a SecureKeyHandle */
+  static decryptChacha20WithHandle(ciphertext: Buffer, key: SecureKeyHandle, aad?: Buffer | undefined | null): Buffer
+  /** Size of the `nonce + ciphertext + tag` output for a plaintext length (AES-GCM and ChaCha20) */
+  static ciphertextLength(plaintextLength: number): number
+  /** Encrypt with AES-256-GCM directly into `output`, returning the number of bytes written */
+  static encryptAesInto(plaintext: Buffer, key: Buffer, output: Buffer, aad?: Buffer | undefined | null): number
+  /** Decrypt AES-256-GCM directly into `output`, returning the number of bytes written */
+  static decryptAesInto(ciphertext: Buffer, key: Buffer, output: Buffer, aad?: Buffer | undefined | null): number
+  /** Encrypt with ChaCha20-Poly1305 directly into `output`, returning the number of bytes written */
+  static encryptChacha20Into(plaintext: Buffer, key: Buffer, output: Buffer, aad?: Buffer | undefined | null): number
+  /** Decrypt ChaCha20-Poly1305 directly into `output`, returning the number of bytes written */
+  static decryptChacha20Into(ciphertext: Buffer, key: Buffer, output: Buffer, aad?: Buffer | undefined | null): number
+}
+/** Incremental AES-256-GCM encryption for large inputs */
+export declare class AesGcmEncryptStream {
+  /** Start a new stream under a 32-byte key */
+  constructor(key: Buffer)
+  /** Add plaintext, returning any ciphertext that is ready to be written */
+  update(chunk: Buffer): Buffer
+  /** Seal the final segment and end the stream */
+  finish(): Buffer
+}
+/** Incremental decryption of streams produced by AesGcmEncryptStream */
+export declare class AesGcmDecryptStream {
+  /** Start decrypting a stream under a 32-byte key */
+  constructor(key: Buffer)
+  /** Add ciphertext, returning any plaintext that has been authenticated */
+  update(chunk: Buffer): Buffer
+  /** Authenticate the final segment and end the stream */
+  finish(): Buffer
+}
+/** Path-based AES-256-GCM file encryption that streams on the thread pool */
+export declare class FileCrypto {
+  /** Encrypt `inPath` into `outPath` under a 32-byte key, resolving to the number of bytes written */
+  static encryptFile(inPath: string, outPath: string, key: Buffer, onProgress?: (progress: number) => void): Promise<number>
+  /** Decrypt a file written by encryptFile; `outPath` is only created once the whole file authenticates */
+  static decryptFile(inPath: string, outPath: string, key: Buffer, onProgress?: (progress: number) => void): Promise<number>
 }
 /** Asymmetric Encryption Module */
 export declare class AsymmetricCrypto {
@@ -39,56 +166,166 @@ export declare class AsymmetricCrypto {
   static generateRsaKeypair(): RsaKeyPairJs
   /** Generate RSA key pair with custom bit size */
   static generateRsaKeypairWithSize(bits: number): RsaKeyPairJs
+  /** Generate RSA-2048 key pair on the thread pool, optionally reporting progress */
+  static generateRsaKeypairAsync(onProgress?: (progress: number) => void): Promise<RsaKeyPairJs>
+  /** Generate RSA key pair with custom bit size on the thread pool, optionally reporting progress */
+  static generateRsaKeypairWithSizeAsync(bits: number, onProgress?: (progress: number) => void): Promise<RsaKeyPairJs>
   /** Encrypt data using RSA-OAEP */
-  static encryptRsa(plaintext: Buffer, publicKeyPem: string): Buffer
+  static encryptRsa<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, publicKeyPem: string, encoding?: E): EncodedOutput<E>
   /** Decrypt data using RSA-OAEP */
   static decryptRsa(ciphertext: Buffer, privateKeyPem: string): Buffer
+  /** Encrypt data using RSA-OAEP on the thread pool */
+  static encryptRsaAsync(plaintext: Buffer, publicKeyPem: string): Promise<Buffer>
+  /** Decrypt data using RSA-OAEP on the thread pool */
+  static decryptRsaAsync(ciphertext: Buffer, privateKeyPem: string): Promise<Buffer>
+  /** Sign data using RSA (PSS with SHA-256 unless overridden) */
+  static signRsa<E extends OutputEncoding = 'buffer'>(message: Buffer, privateKeyPem: string, options?: RsaSignOptions | undefined | null, encoding?: E): EncodedOutput<E>
+  /** Verify RSA signature (PSS with SHA-256 unless overridden) */
+  static verifyRsa(message: Buffer, signature: Buffer, publicKeyPem: string, options?: RsaSignOptions | undefined | null): boolean
   /** Generate Ed25519 key pair */
   static generateEd25519Keypair(): Ed25519KeyPairJs
   /** Sign data using Ed25519 */
-  static signEd25519(message: Buffer, signingKeyBytes: Buffer): Buffer
+  static signEd25519<E extends OutputEncoding = 'buffer'>(message: Buffer, signingKeyBytes: Buffer, encoding?: E): EncodedOutput<E>
+  /** Sign data using Ed25519 with a private key held in a SecureKeyHandle */
+  static signEd25519WithHandle<E extends OutputEncoding = 'buffer'>(message: Buffer, signingKey: SecureKeyHandle, encoding?: E): EncodedOutput<E>
   /** Verify Ed25519 signature */
   static verifyEd25519(message: Buffer, signature: Buffer, verifyingKeyBytes: Buffer): boolean
   /** Generate ECDSA P-256 key pair */
   static generateEcdsaKeypair(): EcdsaKeyPairJs
   /** Sign data using ECDSA P-256 */
-  static signEcdsa(message: Buffer, signingKeyBytes: Buffer): Buffer
+  static signEcdsa<E extends OutputEncoding = 'buffer'>(message: Buffer, signingKeyBytes: Buffer, encoding?: E): EncodedOutput<E>
+  /** Sign data using ECDSA P-256 with a private key held in a SecureKeyHandle */
+  static signEcdsaWithHandle<E extends OutputEncoding = 'buffer'>(message: Buffer, signingKey: SecureKeyHandle, encoding?: E): EncodedOutput<E>
   /** Verify ECDSA P-256 signature */
   static verifyEcdsa(message: Buffer, signature: Buffer, verifyingKeyBytes: Buffer): boolean
+  /** Generate X25519 key pair for key agreement and ECIES */
+  static generateX25519Keypair(): EcdhKeyPairJs
+  /** Generate P-256 key pair for key agreement and ECIES */
+  static generateEcdhP256Keypair(): EcdhKeyPairJs
+  /** Compute the raw ECDH shared secret ("x25519" by default, or "p256") */
+  static deriveSharedSecret(privateKeyBytes: Buffer, peerPublicKeyBytes: Buffer, curve?: 'x25519' | 'p256'): Buffer
+  /** Encrypt to an X25519 (default) or P-256 public key with ECIES */
+  static eciesEncrypt<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, recipientPublicKeyBytes: Buffer, curve?: 'x25519' | 'p256', encoding?: E): EncodedOutput<E>
+  /** Decrypt an ECIES message with the recipient's private key */
+  static eciesDecrypt(ciphertext: Buffer, privateKeyBytes: Buffer, curve?: 'x25519' | 'p256'): Buffer
 }
 /** Hash Functions Module */
 export declare class HashFunctions {
   /** Compute SHA-256 hash */
-  static sha256(data: Buffer): Buffer
+  static sha256<E extends OutputEncoding = 'buffer'>(data: Buffer, encoding?: E): EncodedOutput<E>
   /** Compute SHA-256 hash and return as hex string */
   static sha256Hex(data: Buffer): string
+  /** Verify data against a SHA-256 hash in constant time */
+  static verifySha256(data: Buffer, expected: Buffer): boolean
   /** Compute SHA-512 hash */
-  static sha512(data: Buffer): Buffer
+  static sha512<E extends OutputEncoding = 'buffer'>(data: Buffer, encoding?: E): EncodedOutput<E>
   /** Compute SHA-512 hash and return as hex string */
   static sha512Hex(data: Buffer): string
+  /** Verify data against a SHA-512 hash in constant time */
+  static verifySha512(data: Buffer, expected: Buffer): boolean
   /** Compute BLAKE3 hash */
-  static blake3(data: Buffer): Buffer
+  static blake3<E extends OutputEncoding = 'buffer'>(data: Buffer, encoding?: E): EncodedOutput<E>
   /** Compute BLAKE3 hash and return as hex string */
   static blake3Hex(data: Buffer): string
   /** Compute BLAKE3 hash with custom length */
-  static blake3WithLength(data: Buffer, length: number): Buffer
+  static blake3WithLength<E extends OutputEncoding = 'buffer'>(data: Buffer, length: number, encoding?: E): EncodedOutput<E>
+  /** Compute keyed BLAKE3 hash (MAC) with a 32-byte key */
+  static blake3Keyed<E extends OutputEncoding = 'buffer'>(key: Buffer, data: Buffer, encoding?: E): EncodedOutput<E>
+  /** Derive key material with BLAKE3 in key derivation mode */
+  static blake3DeriveKey(context: string, material: Buffer, length: number): Buffer
+  /** Compute SHA3-256 hash */
+  static sha3_256<E extends OutputEncoding = 'buffer'>(data: Buffer, encoding?: E): EncodedOutput<E>
+  /** Compute SHA3-256 hash and return as hex string */
+  static sha3_256Hex(data: Buffer): string
+  /** Compute SHA3-512 hash */
+  static sha3_512<E extends OutputEncoding = 'buffer'>(data: Buffer, encoding?: E): EncodedOutput<E>
+  /** Compute SHA3-512 hash and return as hex string */
+  static sha3_512Hex(data: Buffer): string
   /** Compute HMAC-SHA256 */
-  static hmacSha256(key: Buffer, message: Buffer): Buffer
+  static hmacSha256<E extends OutputEncoding = 'buffer'>(key: Buffer, message: Buffer, encoding?: E): EncodedOutput<E>
+  /** Compute HMAC-SHA256 and return as hex string */
+  static hmacSha256Hex(key: Buffer, message: Buffer): string
   /** Verify HMAC-SHA256 */
   static verifyHmacSha256(key: Buffer, message: Buffer, expectedMac: Buffer): boolean
+  /** Compute HMAC-SHA256 with a key held in a SecureKeyHandle */
+  static hmacSha256WithHandle<E extends OutputEncoding = 'buffer'>(key: SecureKeyHandle, message: Buffer, encoding?: E): EncodedOutput<E>
   /** Compute HMAC-SHA512 */
-  static hmacSha512(key: Buffer, message: Buffer): Buffer
+  static hmacSha512<E extends OutputEncoding = 'buffer'>(key: Buffer, message: Buffer, encoding?: E): EncodedOutput<E>
+  /** Compute HMAC-SHA512 and return as hex string */
+  static hmacSha512Hex(key: Buffer, message: Buffer): string
   /** Verify HMAC-SHA512 */
   static verifyHmacSha512(key: Buffer, message: Buffer, expectedMac: Buffer): boolean
+  /** Compare two buffers in constant time; unlike node:crypto, different lengths return false */
+  static timingSafeEqual(a: Buffer, b: Buffer): boolean
+}
+/** Incremental SHA-256 hasher */
+export declare class Sha256Stream {
+  constructor()
+  /** Feed the next chunk of input; returns `this` for chaining */
+  update(data: Buffer | string): this
+  /** Finish the hash; the object cannot be used afterwards */
+  digest(encoding?: string | undefined | null): Buffer | string
+  /** Copy the current state into an independent hasher */
+  copy(): Sha256Stream
+}
+/** Incremental SHA-512 hasher */
+export declare class Sha512Stream {
+  constructor()
+  /** Feed the next chunk of input; returns `this` for chaining */
+  update(data: Buffer | string): this
+  /** Finish the hash; the object cannot be used afterwards */
+  digest(encoding?: string | undefined | null): Buffer | string
+  /** Copy the current state into an independent hasher */
+  copy(): Sha512Stream
+}
+/** Incremental BLAKE3 hasher */
+export declare class Blake3Stream {
+  constructor()
+  /** Feed the next chunk of input; returns `this` for chaining */
+  update(data: Buffer | string): this
+  /** Finish the hash; the object cannot be used afterwards */
+  digest(encoding?: string | undefined | null): Buffer | string
+  /** Copy the current state into an independent hasher */
+  copy(): Blake3Stream
+}
+/** Incremental HMAC-SHA256 */
+export declare class HmacSha256Stream {
+  constructor(key: Buffer)
+  /** Feed the next chunk of input; returns `this` for chaining */
+  update(data: Buffer | string): this
+  /** Finish the hash; the object cannot be used afterwards */
+  digest(encoding?: string | undefined | null): Buffer | string
+  /** Copy the current state into an independent hasher */
+  copy(): HmacSha256Stream
+}
+/** Incremental HMAC-SHA512 */
+export declare class HmacSha512Stream {
+  constructor(key: Buffer)
+  /** Feed the next chunk of input; returns `this` for chaining */
+  update(data: Buffer | string): this
+  /** Finish the hash; the object cannot be used afterwards */
+  digest(encoding?: string | undefined | null): Buffer | string
+  /** Copy the current state into an independent hasher */
+  copy(): HmacSha512Stream
 }
 /** Key Derivation Functions Module */
 export declare class KeyDerivation {
-  /** Derive key using Argon2 */
-  static argon2(password: Buffer, salt: Buffer, length: number): Buffer
+  /** Derive key using Argon2 (Argon2id with libsilver defaults unless overridden) */
+  static argon2(password: Buffer, salt: Buffer, length: number, options?: Argon2Options | undefined | null): Buffer
   /** Derive key using PBKDF2-SHA256 */
   static pbkdf2Sha256(password: Buffer, salt: Buffer, iterations: number, length: number): Buffer
   /** Derive key using PBKDF2-SHA512 */
   static pbkdf2Sha512(password: Buffer, salt: Buffer, iterations: number, length: number): Buffer
+  /** Derive key using Argon2 on the thread pool */
+  static argon2Async(password: Buffer, salt: Buffer, length: number, options?: Argon2Options | undefined | null): Promise<Buffer>
+  /** Derive key using PBKDF2-SHA256 on the thread pool; `signal` cancels it between batches */
+  static pbkdf2Sha256Async(password: Buffer, salt: Buffer, iterations: number, length: number, signal?: AbortSignal, onProgress?: (progress: number) => void): Promise<Buffer>
+  /** Derive key using PBKDF2-SHA512 on the thread pool; `signal` cancels it between batches */
+  static pbkdf2Sha512Async(password: Buffer, salt: Buffer, iterations: number, length: number, signal?: AbortSignal, onProgress?: (progress: number) => void): Promise<Buffer>
+  /** Derive key using scrypt (N = 2^17, r = 8, p = 1 unless overridden) */
+  static scrypt(password: Buffer, salt: Buffer, length: number, options?: ScryptOptions | undefined | null): Buffer
+  /** Derive key using scrypt on the thread pool; `signal` cancels it between batches */
+  static scryptAsync(password: Buffer, salt: Buffer, length: number, options?: ScryptOptions, signal?: AbortSignal, onProgress?: (progress: number) => void): Promise<Buffer>
   /** Derive key using HKDF-SHA256 */
   static hkdfSha256(inputKey: Buffer, salt: Buffer | undefined | null, info: Buffer | undefined | null, length: number): Buffer
   /** Derive key using HKDF-SHA512 */
@@ -105,3 +342,210 @@ export declare class RandomGenerator {
   /** Generate salt */
   static generateSalt(): Buffer
 }
+/** Key format conversion between PEM, DER, JWK and raw bytes */
+export declare class KeyFormats {
+  /** Convert a PEM key (PKCS#8, SPKI, PKCS#1 or SEC1) to a JWK JSON string */
+  static toJwk(pem: string): string
+  /** Convert a JWK JSON string to PEM (PKCS#8 for private keys, SPKI for public keys) */
+  static fromJwk(jwk: string): string
+  /** Convert a PEM key to DER (PKCS#8 for private keys, SPKI for public keys) */
+  static toDer(pem: string): Buffer
+  /** Convert a PKCS#8 DER private key to PEM */
+  static fromPkcs8Der(der: Buffer): string
+  /** Convert an SPKI DER public key to PEM */
+  static fromSpkiDer(der: Buffer): string
+  /** Convert a PEM ECDSA P-256 or Ed25519 key to the raw bytes used by AsymmetricCrypto */
+  static toRaw(pem: string): Buffer
+  /** Convert a raw private key ("p256" scalar or "ed25519" seed) to PKCS#8 PEM */
+  static fromRawPrivate(raw: Buffer, algorithm: 'p256' | 'ed25519'): string
+  /** Convert a raw public key ("p256" SEC1 point or "ed25519" key) to SPKI PEM */
+  static fromRawPublic(raw: Buffer, algorithm: 'p256' | 'ed25519'): string
+  /** Extract the SPKI PEM public key from a PEM private key */
+  static toPublicKeyPem(pem: string): string
+}
+/** JWT and PASETO v4 tokens; claims and payloads are JSON strings */
+export declare class Tokens {
+  /**
+   * Sign a JWT. `key` is the HMAC secret for HS*, a PEM private key for RS256/PS256,
+   * or a PEM or raw private key for ES256/EdDSA
+   */
+  static signJwt(claims: string, algorithm: JwtAlgorithm, key: Buffer | string, options?: JwtSignOptions | undefined | null): string
+  /**
+   * Verify a JWT signed with `algorithm` and return its claims. `key` is the HMAC secret,
+   * a PEM key for any asymmetric algorithm, or a raw public key for ES256/EdDSA
+   */
+  static verifyJwt(token: string, algorithm: JwtAlgorithm, key: Buffer | string, options?: JwtVerifyOptions | undefined | null): string
+  /** Decode a JWT header without verifying the token, e.g. to look up the key by `kid` */
+  static decodeJwtHeader(token: string): string
+  /** Encrypt a JSON payload into a v4.local PASETO with a 32-byte key */
+  static encryptPaseto(payload: string, key: Buffer, options?: PasetoOptions | undefined | null): string
+  /** Decrypt a v4.local PASETO, check its `exp`/`nbf` claims and return the JSON payload */
+  static decryptPaseto(token: string, key: Buffer, options?: PasetoOptions | undefined | null): string
+  /** Sign a JSON payload into a v4.public PASETO with a PEM or raw Ed25519 private key */
+  static signPaseto(payload: string, secretKey: Buffer | string, options?: PasetoOptions | undefined | null): string
+  /** Verify a v4.public PASETO, check its `exp`/`nbf` claims and return the JSON payload */
+  static verifyPaseto(token: string, publicKey: Buffer | string, options?: PasetoOptions | undefined | null): string
+  /** Read a PASETO footer without verifying the token, e.g. to look up the key by `kid` */
+  static pasetoFooter(token: string): string
+}
+/** HOTP and TOTP one-time passwords with base32 secrets, compatible with authenticator apps */
+export declare class Otp {
+  /** Generate a random 160-bit base32 secret */
+  static generateSecret(): string
+  /** Compute the HOTP code for `counter` */
+  static hotpGenerate(secret: string, counter: number, options?: OtpOptions | undefined | null): string
+  /** Compute the TOTP code for `options.timestamp` (Unix seconds) or the current time */
+  static totpGenerate(secret: string, options?: OtpOptions | undefined | null): string
+  /** Check a TOTP code, accepting `options.window` time steps of drift either way (default 1) */
+  static totpVerify(code: string, secret: string, options?: OtpOptions | undefined | null): boolean
+  /** Build the otpauth:// URI that authenticator apps import from a QR code */
+  static totpUri(secret: string, issuer: string, account: string, options?: OtpOptions | undefined | null): string
+}
+/** Key material kept in Rust memory and zeroized on destroy() or garbage collection */
+export declare class SecureKeyHandle {
+  /** Generate a random key of `length` bytes (e.g. 32 for AES-256 or ChaCha20) */
+  static generate(length: number): SecureKeyHandle
+  /** Copy key bytes into a handle; wipe the source with `buffer.fill(0)` afterwards */
+  static fromBuffer(key: Buffer): SecureKeyHandle
+  /** Derive a key with Argon2 without the key ever reaching the JavaScript heap */
+  static deriveArgon2(password: Buffer, salt: Buffer, length: number, options?: Argon2Options | undefined | null): SecureKeyHandle
+  /** Key length in bytes */
+  get length(): number
+  /** Whether destroy() has been called */
+  get destroyed(): boolean
+  /** Zeroize the key immediately; the handle cannot be used afterwards */
+  destroy(): void
+}
+/** Ed25519 key pair whose private key never leaves native memory */
+export declare class Ed25519KeyPair {
+  /** Generate a new key pair */
+  static generate(): Ed25519KeyPair
+  /** Import a PKCS#8 PEM private key */
+  static fromPem(pem: string): Ed25519KeyPair
+  /** Import a 32-byte private key, such as `signingKeyBytes` from generateEd25519Keypair */
+  static fromPrivateKeyBytes(bytes: Buffer): Ed25519KeyPair
+  /** Sign a message */
+  sign<E extends OutputEncoding = 'buffer'>(message: Buffer, encoding?: E): EncodedOutput<E>
+  /** Verify a signature made by this key pair */
+  verify(message: Buffer, signature: Buffer): boolean
+  /** Raw 32-byte public key */
+  publicKey(): Buffer
+  /** Public key as SPKI PEM */
+  toPem(): string
+  /** Export the private key as PKCS#8 PEM, copying it onto the JavaScript heap */
+  exportPrivateKeyPem(): string
+  /** Whether destroy() has been called */
+  get destroyed(): boolean
+  /** Zeroize the private key immediately; the key pair cannot be used afterwards */
+  destroy(): void
+}
+/** ECDSA P-256 key pair whose private key never leaves native memory */
+export declare class EcdsaKeyPair {
+  /** Generate a new key pair */
+  static generate(): EcdsaKeyPair
+  /** Import a PKCS#8 or SEC1 PEM private key */
+  static fromPem(pem: string): EcdsaKeyPair
+  /** Import a 32-byte private scalar, such as `signingKeyBytes` from generateEcdsaKeypair */
+  static fromPrivateKeyBytes(bytes: Buffer): EcdsaKeyPair
+  /** Sign a message */
+  sign<E extends OutputEncoding = 'buffer'>(message: Buffer, encoding?: E): EncodedOutput<E>
+  /** Verify a signature made by this key pair */
+  verify(message: Buffer, signature: Buffer): boolean
+  /** Public key as an uncompressed SEC1 point */
+  publicKey(): Buffer
+  /** Public key as SPKI PEM */
+  toPem(): string
+  /** Export the private key as PKCS#8 PEM, copying it onto the JavaScript heap */
+  exportPrivateKeyPem(): string
+  /** Whether destroy() has been called */
+  get destroyed(): boolean
+  /** Zeroize the private key immediately; the key pair cannot be used afterwards */
+  destroy(): void
+}
+/** RSA key pair whose private key never leaves native memory */
+export declare class RsaKeyPair {
+  /** Generate a new key pair (2048 bits unless overridden) */
+  static generate(bits?: number | undefined | null): RsaKeyPair
+  /** Generate a new key pair on the thread pool, optionally reporting progress */
+  static generateAsync(bits?: number, onProgress?: (progress: number) => void): Promise<RsaKeyPair>
+  /** Import a PKCS#8 or PKCS#1 PEM private key */
+  static fromPem(pem: string): RsaKeyPair
+  /** Sign a message (PSS with SHA-256 unless overridden) */
+  sign<E extends OutputEncoding = 'buffer'>(message: Buffer, options?: RsaSignOptions | undefined | null, encoding?: E): EncodedOutput<E>
+  /** Verify a signature made by this key pair (PSS with SHA-256 unless overridden) */
+  verify(message: Buffer, signature: Buffer, options?: RsaSignOptions | undefined | null): boolean
+  /** Encrypt to this key pair using RSA-OAEP */
+  encrypt<E extends OutputEncoding = 'buffer'>(plaintext: Buffer, encoding?: E): EncodedOutput<E>
+  /** Decrypt RSA-OAEP ciphertext */
+  decrypt(ciphertext: Buffer): Buffer
+  /** Public key as SPKI PEM */
+  toPem(): string
+  /** Export the private key as PKCS#8 PEM, copying it onto the JavaScript heap */
+  exportPrivateKeyPem(): string
+  /** Whether destroy() has been called */
+  get destroyed(): boolean
+  /** Zeroize the private key immediately; the key pair cannot be used afterwards */
+  destroy(): void
+}
+/** X25519 or P-256 key agreement key pair whose private key never leaves native memory */
+export declare class EcdhKeyPair {
+  /** Generate a new key pair ("x25519" by default, or "p256") */
+  static generate(curve?: string | undefined | null): EcdhKeyPair
+  /** Import a 32-byte private key ("x25519" by default, or "p256") */
+  static fromPrivateKeyBytes(bytes: Buffer, curve?: string | undefined | null): EcdhKeyPair
+  /** Curve name, "x25519" or "p256" */
+  get curve(): string
+  /** Public key bytes (uncompressed SEC1 for P-256) */
+  publicKey(): Buffer
+  /** Compute the raw ECDH shared secret with a peer public key */
+  deriveSharedSecret(peerPublicKey: Buffer): Buffer
+  /** Decrypt an ECIES message addressed to this key pair */
+  decrypt(ciphertext: Buffer): Buffer
+  /** Whether destroy() has been called */
+  get destroyed(): boolean
+  /** Zeroize the private key immediately; the key pair cannot be used afterwards */
+  destroy(): void
+}
+/** Byte-level operations shared with every libsilver binding */
+export declare class Api {
+  /** Encrypt with AES-256-GCM; output is nonce || ciphertext || tag */
+  static aesGcmEncrypt(key: Buffer, plaintext: Buffer, aad?: Buffer | undefined | null): Buffer
+  /** Decrypt AES-256-GCM nonce || ciphertext || tag */
+  static aesGcmDecrypt(key: Buffer, ciphertext: Buffer, aad?: Buffer | undefined | null): Buffer
+  /** Encrypt with ChaCha20-Poly1305; output is nonce || ciphertext || tag */
+  static chacha20Poly1305Encrypt(key: Buffer, plaintext: Buffer, aad?: Buffer | undefined | null): Buffer
+  /** Decrypt ChaCha20-Poly1305 nonce || ciphertext || tag */
+  static chacha20Poly1305Decrypt(key: Buffer, ciphertext: Buffer, aad?: Buffer | undefined | null): Buffer
+  /** SHA-256 (32-byte output) */
+  static sha256(data: Buffer): Buffer
+  /** SHA-512 (64-byte output) */
+  static sha512(data: Buffer): Buffer
+  /** SHA3-256 (32-byte output) */
+  static sha3_256(data: Buffer): Buffer
+  /** SHA3-512 (64-byte output) */
+  static sha3_512(data: Buffer): Buffer
+  /** BLAKE3 (32-byte output) */
+  static blake3(data: Buffer): Buffer
+  /** HMAC-SHA256 (32-byte output) */
+  static hmacSha256(key: Buffer, message: Buffer): Buffer
+  /** HMAC-SHA512 (64-byte output) */
+  static hmacSha512(key: Buffer, message: Buffer): Buffer
+  /** Sign a message with a 32-byte Ed25519 private key (64-byte output) */
+  static ed25519Sign(privateKey: Buffer, message: Buffer): Buffer
+  /** Verify an Ed25519 signature against a 32-byte public key */
+  static ed25519Verify(publicKey: Buffer, message: Buffer, signature: Buffer): boolean
+  /** Sign a message with a 32-byte ECDSA P-256 private key (64-byte r || s output) */
+  static ecdsaP256Sign(privateKey: Buffer, message: Buffer): Buffer
+  /** Verify an ECDSA P-256 signature against a SEC1 public key */
+  static ecdsaP256Verify(publicKey: Buffer, message: Buffer, signature: Buffer): boolean
+  /** Derive a key from a password with Argon2id (libsilver default cost) */
+  static argon2id(password: Buffer, salt: Buffer, length: number): Buffer
+  /** Hash a password with Argon2id into a PHC string for storage */
+  static argon2HashPassword(password: Buffer): string
+  /** Check a password against an Argon2 PHC string */
+  static argon2VerifyPassword(password: Buffer, hash: string): boolean
+  /** Derive a key from a password with PBKDF2-HMAC-SHA256 */
+  static pbkdf2Sha256(password: Buffer, salt: Buffer, iterations: number, length: number): Buffer
+  /** Derive a key with HKDF-SHA256; an absent salt uses the RFC 5869 default */
+  static hkdfSha256(inputKey: Buffer, salt: Buffer | undefined | null, info: Buffer, length: number): Buffer
+}
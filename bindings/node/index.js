@@ -310,10 +310,27 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { SymmetricCrypto, AsymmetricCrypto, HashFunctions, KeyDerivation, RandomGenerator } = nativeBinding
+const { SymmetricCrypto, AesGcmEncryptStream, AesGcmDecryptStream, FileCrypto, AsymmetricCrypto, HashFunctions, Sha256Stream, Sha512Stream, Blake3Stream, HmacSha256Stream, HmacSha512Stream, KeyDerivation, RandomGenerator, KeyFormats, Tokens, Otp, SecureKeyHandle, Ed25519KeyPair, EcdsaKeyPair, RsaKeyPair, EcdhKeyPair, Api } = nativeBinding
 
 module.exports.SymmetricCrypto = SymmetricCrypto
+module.exports.AesGcmEncryptStream = AesGcmEncryptStream
+module.exports.AesGcmDecryptStream = AesGcmDecryptStream
+module.exports.FileCrypto = FileCrypto
 module.exports.AsymmetricCrypto = AsymmetricCrypto
 module.exports.HashFunctions = HashFunctions
+module.exports.Sha256Stream = Sha256Stream
+module.exports.Sha512Stream = Sha512Stream
+module.exports.Blake3Stream = Blake3Stream
+module.exports.HmacSha256Stream = HmacSha256Stream
+module.exports.HmacSha512Stream = HmacSha512Stream
 module.exports.KeyDerivation = KeyDerivation
 module.exports.RandomGenerator = RandomGenerator
+module.exports.KeyFormats = KeyFormats
+module.exports.Tokens = Tokens
+module.exports.Otp = Otp
+module.exports.SecureKeyHandle = SecureKeyHandle
+module.exports.Ed25519KeyPair = Ed25519KeyPair
+module.exports.EcdsaKeyPair = EcdsaKeyPair
+module.exports.RsaKeyPair = RsaKeyPair
+module.exports.EcdhKeyPair = EcdhKeyPair
+module.exports.Api = Api
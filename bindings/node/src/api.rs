@@ -0,0 +1,98 @@
+//! `Api` class generated from `libsilver::for_each_api!`
+//!
+//! Every function declared in `libsilver::api` becomes a static method with
+//! the same parameters in camelCase, so it can never drift from the C ABI and
+//! the other bindings. Bytes are Buffers, optional bytes accept `null` or
+//! `undefined`, and output lengths are numbers.
+
+use napi::bindgen_prelude::*;
+use napi_derive::napi;
+
+use crate::crypto_error_to_napi;
+
+/// Byte-level operations shared with every libsilver binding
+#[napi]
+pub struct Api;
+
+macro_rules! napi_api {
+    ($(
+        #[doc = $doc:literal]
+        fn $name:ident($($param:ident: $kind:ident),* $(,)?) -> $returns:ident $body:block
+    )*) => {
+        $(napi_api!(@method [$doc] $name [] [] $($param: $kind,)* -> $returns);)*
+    };
+
+    // Map each parameter kind to a JS-facing type and the argument passed to core
+    (@method $doc:tt $name:ident [$($params:tt)*] [$($args:tt)*] $param:ident: bytes, $($rest:tt)*) => {
+        napi_api!(@method $doc $name [$($params)* $param: Buffer,] [$($args)* &$param,] $($rest)*);
+    };
+    (@method $doc:tt $name:ident [$($params:tt)*] [$($args:tt)*] $param:ident: optional_bytes, $($rest:tt)*) => {
+        napi_api!(@method $doc $name [$($params)* $param: Option<Buffer>,] [$($args)* $param.as_deref(),] $($rest)*);
+    };
+    (@method $doc:tt $name:ident [$($params:tt)*] [$($args:tt)*] $param:ident: str, $($rest:tt)*) => {
+        napi_api!(@method $doc $name [$($params)* $param: String,] [$($args)* &$param,] $($rest)*);
+    };
+    (@method $doc:tt $name:ident [$($params:tt)*] [$($args:tt)*] $param:ident: u32, $($rest:tt)*) => {
+        napi_api!(@method $doc $name [$($params)* $param: u32,] [$($args)* $param,] $($rest)*);
+    };
+    (@method $doc:tt $name:ident [$($params:tt)*] [$($args:tt)*] $param:ident: output_length, $($rest:tt)*) => {
+        napi_api!(@method $doc $name [$($params)* $param: u32,] [$($args)* $param as usize,] $($rest)*);
+    };
+
+    // Keep the digits of these names apart, as the hand-written classes do
+    (@method $doc:tt sha3_256 $params:tt $args:tt -> $returns:ident) => {
+        napi_api!(@emit $doc [js_name = "sha3_256"] sha3_256 $params $args $returns);
+    };
+    (@method $doc:tt sha3_512 $params:tt $args:tt -> $returns:ident) => {
+        napi_api!(@emit $doc [js_name = "sha3_512"] sha3_512 $params $args $returns);
+    };
+    (@method $doc:tt argon2id $params:tt $args:tt -> $returns:ident) => {
+        napi_api!(@emit $doc [js_name = "argon2id"] argon2id $params $args $returns);
+    };
+    (@method $doc:tt $name:ident $params:tt $args:tt -> $returns:ident) => {
+        napi_api!(@emit $doc [] $name $params $args $returns);
+    };
+
+    (@emit [$doc:literal] [$($js_name:tt)*] $name:ident [$($params:tt)*] [$($args:tt)*] bytes) => {
+        #[napi]
+        impl Api {
+            #[doc = $doc]
+            #[napi($($js_name)*)]
+            pub fn $name($($params)*) -> napi::Result<Buffer> {
+                libsilver::api::$name($($args)*).map(Buffer::from).map_err(crypto_error_to_napi)
+            }
+        }
+    };
+    (@emit [$doc:literal] [$($js_name:tt)*] $name:ident [$($params:tt)*] [$($args:tt)*] secret_bytes) => {
+        #[napi]
+        impl Api {
+            #[doc = $doc]
+            #[napi($($js_name)*)]
+            pub fn $name($($params)*) -> napi::Result<Buffer> {
+                libsilver::api::$name($($args)*).map(|secret| Buffer::from(secret.as_slice())).map_err(crypto_error_to_napi)
+            }
+        }
+    };
+    (@emit [$doc:literal] [$($js_name:tt)*] $name:ident [$($params:tt)*] [$($args:tt)*] str) => {
+        #[napi]
+        impl Api {
+            #[doc = $doc]
+            #[napi($($js_name)*)]
+            pub fn $name($($params)*) -> napi::Result<String> {
+                libsilver::api::$name($($args)*).map_err(crypto_error_to_napi)
+            }
+        }
+    };
+    (@emit [$doc:literal] [$($js_name:tt)*] $name:ident [$($params:tt)*] [$($args:tt)*] bool) => {
+        #[napi]
+        impl Api {
+            #[doc = $doc]
+            #[napi($($js_name)*)]
+            pub fn $name($($params)*) -> napi::Result<bool> {
+                libsilver::api::$name($($args)*).map_err(crypto_error_to_napi)
+            }
+        }
+    };
+}
+
+libsilver::for_each_api!(napi_api);
@@ -1,7 +1,17 @@
 use napi::bindgen_prelude::*;
+use napi::{JsFunction, JsObject};
+use napi::threadsafe_function::{ErrorStrategy, ThreadsafeFunction, ThreadsafeFunctionCallMode};
 use napi_derive::napi;
+use std::fs::{self, File};
+use std::io::{ErrorKind, Read, Write};
+use std::path::{Path, PathBuf};
+use std::marker::PhantomData;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use libsilver::core::*;
-use libsilver::error::CryptoError;
+use libsilver::error::{CryptoError, CryptoResult};
+
+mod api;
 
 /// Convert CryptoError to napi::Error
 fn crypto_error_to_napi(err: CryptoError) -> napi::Error {
@@ -15,6 +25,194 @@ macro_rules! to_napi_result {
     };
 }
 
+/// Check raw AES-256 key bytes from JS
+fn aes_key(key: &[u8]) -> napi::Result<Aes256Key> {
+    to_napi_result!(Aes256Key::from_bytes(key))
+}
+
+/// Check raw ChaCha20-Poly1305 key bytes from JS
+fn chacha_key(key: &[u8]) -> napi::Result<ChaChaKey> {
+    to_napi_result!(ChaChaKey::from_bytes(key))
+}
+
+/// HMAC over a JS key of any length, as `node:crypto` accepts
+fn hmac(hasher: CryptoResult<StreamingHasher>, message: &[u8]) -> napi::Result<Vec<u8>> {
+    let mut hasher = to_napi_result!(hasher)?;
+    hasher.update(message);
+    Ok(hasher.finalize())
+}
+
+/// Background task that runs a libsilver operation on the libuv thread pool
+pub struct BufferTask {
+    operation: Option<Box<dyn FnOnce() -> CryptoResult<Vec<u8>> + Send>>,
+}
+
+impl BufferTask {
+    fn spawn(operation: impl FnOnce() -> CryptoResult<Vec<u8>> + Send + 'static) -> AsyncTask<Self> {
+        AsyncTask::new(Self { operation: Some(Box::new(operation)) })
+    }
+}
+
+impl Task for BufferTask {
+    type Output = Vec<u8>;
+    type JsValue = Buffer;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let operation = self.operation.take()
+            .ok_or_else(|| napi::Error::from_reason("Task already completed"))?;
+        to_napi_result!(operation())
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(Buffer::from(output))
+    }
+}
+
+/// JavaScript callback receiving progress in [0, 1] from the thread pool
+type ProgressCallback = ThreadsafeFunction<f64, ErrorStrategy::Fatal>;
+
+/// Queue a progress update without blocking the worker thread
+fn report_progress(callback: Option<&ProgressCallback>, fraction: f64) {
+    if let Some(callback) = callback {
+        callback.call(fraction, ThreadsafeFunctionCallMode::NonBlocking);
+    }
+}
+
+/// Cancellation flag set by an AbortSignal and polled between KDF batches
+type AbortFlag = Arc<AtomicBool>;
+
+/// Register an `abort` listener on an optional AbortSignal
+fn abort_flag(env: &Env, signal: Option<JsObject>) -> napi::Result<AbortFlag> {
+    let flag = Arc::new(AtomicBool::new(false));
+    if let Some(signal) = signal {
+        flag.store(signal.get_named_property::<bool>("aborted")?, Ordering::Relaxed);
+        let listener_flag = flag.clone();
+        let listener = env.create_function_from_closure("onabort", move |_| {
+            listener_flag.store(true, Ordering::Relaxed);
+            Ok(())
+        })?;
+        let add_event_listener: JsFunction = signal.get_named_property("addEventListener")?;
+        add_event_listener.call(Some(&signal), &[env.create_string("abort")?.into_unknown(), listener.into_unknown()])?;
+    }
+    Ok(flag)
+}
+
+/// Key derivation that polls a progress callback returning whether to continue
+type KdfOperation = Box<dyn FnOnce(&mut dyn FnMut(f64) -> bool) -> CryptoResult<Vec<u8>> + Send>;
+
+/// Background key derivation that reports progress and stops early once aborted
+pub struct KdfTask {
+    derive: Option<KdfOperation>,
+    aborted: AbortFlag,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl KdfTask {
+    fn spawn(
+        env: &Env,
+        signal: Option<JsObject>,
+        on_progress: Option<ProgressCallback>,
+        derive: impl FnOnce(&mut dyn FnMut(f64) -> bool) -> CryptoResult<Vec<u8>> + Send + 'static,
+    ) -> napi::Result<AsyncTask<Self>> {
+        let aborted = abort_flag(env, signal)?;
+        Ok(AsyncTask::new(Self { derive: Some(Box::new(derive)), aborted, on_progress }))
+    }
+}
+
+impl Task for KdfTask {
+    type Output = Vec<u8>;
+    type JsValue = Buffer;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let derive = self.derive.take()
+            .ok_or_else(|| napi::Error::from_reason("Task already completed"))?;
+        let on_progress = self.on_progress.take();
+        let aborted = self.aborted.clone();
+        if aborted.load(Ordering::Relaxed) {
+            return Err(napi::Error::new(Status::Cancelled, "AbortError"));
+        }
+
+        let result = derive(&mut |fraction| {
+            report_progress(on_progress.as_ref(), fraction);
+            !aborted.load(Ordering::Relaxed)
+        });
+        if aborted.load(Ordering::Relaxed) {
+            return Err(napi::Error::new(Status::Cancelled, "AbortError"));
+        }
+        to_napi_result!(result)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(Buffer::from(output))
+    }
+}
+
+/// Background RSA key generation task, resolving to a plain object or a RsaKeyPair instance
+pub struct RsaKeypairTask<T = RsaKeyPairJs> {
+    bits: usize,
+    on_progress: Option<ProgressCallback>,
+    output: PhantomData<T>,
+}
+
+impl<T: From<RsaKeyPair> + ToNapiValue + TypeName + Send + 'static> RsaKeypairTask<T> {
+    fn spawn(bits: usize, on_progress: Option<ProgressCallback>) -> AsyncTask<Self> {
+        AsyncTask::new(Self { bits, on_progress, output: PhantomData })
+    }
+}
+
+impl<T: From<RsaKeyPair> + ToNapiValue + TypeName + Send + 'static> Task for RsaKeypairTask<T> {
+    type Output = T;
+    type JsValue = T;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        // Taking the callback releases it once generation ends, so it cannot keep the process alive
+        let keypair = match self.on_progress.take() {
+            Some(callback) => RsaKeyPair::generate_with_progress(self.bits, |primes| {
+                report_progress(Some(&callback), primes as f64 / 2.0)
+            }),
+            None => RsaCrypto::generate_keypair_with_size(self.bits),
+        };
+        Ok(T::from(to_napi_result!(keypair)?))
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+// Worker-thread safety: every binding type must be Send. Static APIs hold no
+// state and can run on any thread, async tasks move onto the libuv pool, and
+// class instances are owned by the JS thread that created them.
+const _: () = {
+    const fn assert_send<T: Send>() {}
+    assert_send::<BufferTask>();
+    assert_send::<RsaKeypairTask>();
+    assert_send::<KdfTask>();
+    assert_send::<FileTask>();
+    assert_send::<SymmetricCrypto>();
+    assert_send::<AesGcmEncryptStream>();
+    assert_send::<AesGcmDecryptStream>();
+    assert_send::<FileCrypto>();
+    assert_send::<AsymmetricCrypto>();
+    assert_send::<HashFunctions>();
+    assert_send::<Sha256Stream>();
+    assert_send::<Sha512Stream>();
+    assert_send::<Blake3Stream>();
+    assert_send::<HmacSha256Stream>();
+    assert_send::<HmacSha512Stream>();
+    assert_send::<KeyDerivation>();
+    assert_send::<RandomGenerator>();
+    assert_send::<KeyFormats>();
+    assert_send::<Tokens>();
+    assert_send::<Otp>();
+    assert_send::<SecureKeyHandle>();
+    assert_send::<Ed25519KeyPairHandle>();
+    assert_send::<EcdsaKeyPairHandle>();
+    assert_send::<RsaKeyPairHandle>();
+    assert_send::<EcdhKeyPairHandle>();
+    assert_send::<RsaKeypairTask<RsaKeyPairHandle>>();
+};
+
 /// Symmetric Encryption Module
 #[napi]
 pub struct SymmetricCrypto;
@@ -25,20 +223,52 @@ impl SymmetricCrypto {
     #[napi]
     pub fn generate_aes_key() -> napi::Result<Buffer> {
         let key = to_napi_result!(AesGcm::generate_key())?;
-        Ok(Buffer::from(key))
+        Ok(Buffer::from(key.as_bytes()))
     }
 
     /// Encrypt data using AES-256-GCM
     #[napi]
-    pub fn encrypt_aes(plaintext: Buffer, key: Buffer) -> napi::Result<Buffer> {
-        let ciphertext = to_napi_result!(AesGcm::encrypt(&plaintext, &key))?;
-        Ok(Buffer::from(ciphertext))
+    pub fn encrypt_aes(plaintext: Buffer, key: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let ciphertext = to_napi_result!(AesGcm::encrypt(&plaintext, &aes_key(&key)?))?;
+        encode_output(ciphertext, encoding)
     }
 
     /// Decrypt data using AES-256-GCM
     #[napi]
     pub fn decrypt_aes(ciphertext: Buffer, key: Buffer) -> napi::Result<Buffer> {
-        let plaintext = to_napi_result!(AesGcm::decrypt(&ciphertext, &key))?;
+        let plaintext = to_napi_result!(AesGcm::decrypt(&ciphertext, &aes_key(&key)?))?;
+        Ok(Buffer::from(plaintext))
+    }
+
+    /// Encrypt data using AES-256-GCM with optional associated data
+    #[napi]
+    pub fn encrypt_aes_with_aad(plaintext: Buffer, key: Buffer, aad: Option<Buffer>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let ciphertext = to_napi_result!(AesGcm::encrypt_with_aad(&plaintext, &aes_key(&key)?, aad))?;
+        encode_output(ciphertext, encoding)
+    }
+
+    /// Decrypt data using AES-256-GCM with optional associated data
+    #[napi]
+    pub fn decrypt_aes_with_aad(ciphertext: Buffer, key: Buffer, aad: Option<Buffer>) -> napi::Result<Buffer> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let plaintext = to_napi_result!(AesGcm::decrypt_with_aad(&ciphertext, &aes_key(&key)?, aad))?;
+        Ok(Buffer::from(plaintext))
+    }
+
+    /// Encrypt data using AES-256-GCM with a key held in a SecureKeyHandle
+    #[napi]
+    pub fn encrypt_aes_with_handle(plaintext: Buffer, key: &SecureKeyHandle, aad: Option<Buffer>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let ciphertext = to_napi_result!(AesGcm::encrypt_with_aad(&plaintext, &aes_key(key.bytes()?)?, aad))?;
+        encode_output(ciphertext, encoding)
+    }
+
+    /// Decrypt data using AES-256-GCM with a key held in a SecureKeyHandle
+    #[napi]
+    pub fn decrypt_aes_with_handle(ciphertext: Buffer, key: &SecureKeyHandle, aad: Option<Buffer>) -> napi::Result<Buffer> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let plaintext = to_napi_result!(AesGcm::decrypt_with_aad(&ciphertext, &aes_key(key.bytes()?)?, aad))?;
         Ok(Buffer::from(plaintext))
     }
 
@@ -46,22 +276,290 @@ impl SymmetricCrypto {
     #[napi]
     pub fn generate_chacha20_key() -> napi::Result<Buffer> {
         let key = to_napi_result!(ChaCha20Poly1305Cipher::generate_key())?;
-        Ok(Buffer::from(key))
+        Ok(Buffer::from(key.as_bytes()))
     }
 
     /// Encrypt data using ChaCha20-Poly1305
     #[napi]
-    pub fn encrypt_chacha20(plaintext: Buffer, key: Buffer) -> napi::Result<Buffer> {
-        let ciphertext = to_napi_result!(ChaCha20Poly1305Cipher::encrypt(&plaintext, &key))?;
-        Ok(Buffer::from(ciphertext))
+    pub fn encrypt_chacha20(plaintext: Buffer, key: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let ciphertext = to_napi_result!(ChaCha20Poly1305Cipher::encrypt(&plaintext, &chacha_key(&key)?))?;
+        encode_output(ciphertext, encoding)
     }
 
     /// Decrypt data using ChaCha20-Poly1305
     #[napi]
     pub fn decrypt_chacha20(ciphertext: Buffer, key: Buffer) -> napi::Result<Buffer> {
-        let plaintext = to_napi_result!(ChaCha20Poly1305Cipher::decrypt(&ciphertext, &key))?;
+        let plaintext = to_napi_result!(ChaCha20Poly1305Cipher::decrypt(&ciphertext, &chacha_key(&key)?))?;
+        Ok(Buffer::from(plaintext))
+    }
+
+    /// Encrypt data using ChaCha20-Poly1305 with optional associated data
+    #[napi]
+    pub fn encrypt_chacha20_with_aad(plaintext: Buffer, key: Buffer, aad: Option<Buffer>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let ciphertext = to_napi_result!(ChaCha20Poly1305Cipher::encrypt_with_aad(&plaintext, &chacha_key(&key)?, aad))?;
+        encode_output(ciphertext, encoding)
+    }
+
+    /// Decrypt data using ChaCha20-Poly1305 with optional associated data
+    #[napi]
+    pub fn decrypt_chacha20_with_aad(ciphertext: Buffer, key: Buffer, aad: Option<Buffer>) -> napi::Result<Buffer> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let plaintext = to_napi_result!(ChaCha20Poly1305Cipher::decrypt_with_aad(&ciphertext, &chacha_key(&key)?, aad))?;
+        Ok(Buffer::from(plaintext))
+    }
+
+    /// Encrypt data using ChaCha20-Poly1305 with a key held in a SecureKeyHandle
+    #[napi]
+    pub fn encrypt_chacha20_with_handle(plaintext: Buffer, key: &SecureKeyHandle, aad: Option<Buffer>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let ciphertext = to_napi_result!(ChaCha20Poly1305Cipher::encrypt_with_aad(&plaintext, &chacha_key(key.bytes()?)?, aad))?;
+        encode_output(ciphertext, encoding)
+    }
+
+    /// Decrypt data using ChaCha20-Poly1305 with a key held in a SecureKeyHandle
+    #[napi]
+    pub fn decrypt_chacha20_with_handle(ciphertext: Buffer, key: &SecureKeyHandle, aad: Option<Buffer>) -> napi::Result<Buffer> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let plaintext = to_napi_result!(ChaCha20Poly1305Cipher::decrypt_with_aad(&ciphertext, &chacha_key(key.bytes()?)?, aad))?;
         Ok(Buffer::from(plaintext))
     }
+
+    /// Size of the `nonce + ciphertext + tag` output for a plaintext length (AES-GCM and ChaCha20)
+    #[napi]
+    pub fn ciphertext_length(plaintext_length: u32) -> u32 {
+        AesGcm::ciphertext_len(plaintext_length as usize) as u32
+    }
+
+    /// Encrypt with AES-256-GCM directly into `output`, returning the number of bytes written
+    #[napi]
+    pub fn encrypt_aes_into(plaintext: Buffer, key: Buffer, mut output: Buffer, aad: Option<Buffer>) -> napi::Result<u32> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let written = to_napi_result!(AesGcm::encrypt_into(&plaintext, &aes_key(&key)?, aad, &mut output))?;
+        Ok(written as u32)
+    }
+
+    /// Decrypt AES-256-GCM directly into `output`, returning the number of bytes written
+    #[napi]
+    pub fn decrypt_aes_into(ciphertext: Buffer, key: Buffer, mut output: Buffer, aad: Option<Buffer>) -> napi::Result<u32> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let written = to_napi_result!(AesGcm::decrypt_into(&ciphertext, &aes_key(&key)?, aad, &mut output))?;
+        Ok(written as u32)
+    }
+
+    /// Encrypt with ChaCha20-Poly1305 directly into `output`, returning the number of bytes written
+    #[napi]
+    pub fn encrypt_chacha20_into(plaintext: Buffer, key: Buffer, mut output: Buffer, aad: Option<Buffer>) -> napi::Result<u32> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let written = to_napi_result!(ChaCha20Poly1305Cipher::encrypt_into(&plaintext, &chacha_key(&key)?, aad, &mut output))?;
+        Ok(written as u32)
+    }
+
+    /// Decrypt ChaCha20-Poly1305 directly into `output`, returning the number of bytes written
+    #[napi]
+    pub fn decrypt_chacha20_into(ciphertext: Buffer, key: Buffer, mut output: Buffer, aad: Option<Buffer>) -> napi::Result<u32> {
+        let aad = aad.as_deref().unwrap_or_default();
+        let written = to_napi_result!(ChaCha20Poly1305Cipher::decrypt_into(&ciphertext, &chacha_key(&key)?, aad, &mut output))?;
+        Ok(written as u32)
+    }
+}
+
+/// Incremental AES-256-GCM encryption for large inputs
+#[napi]
+pub struct AesGcmEncryptStream {
+    encryptor: Option<AesGcmStreamEncryptor>,
+}
+
+#[napi]
+impl AesGcmEncryptStream {
+    /// Start a new stream under a 32-byte key
+    #[napi(constructor)]
+    pub fn new(key: Buffer) -> napi::Result<Self> {
+        let encryptor = to_napi_result!(AesGcmStreamEncryptor::new(&key))?;
+        Ok(Self { encryptor: Some(encryptor) })
+    }
+
+    /// Add plaintext, returning any ciphertext that is ready to be written
+    #[napi]
+    pub fn update(&mut self, chunk: Buffer) -> napi::Result<Buffer> {
+        let encryptor = self.encryptor.as_mut()
+            .ok_or_else(|| napi::Error::from_reason("Stream already finished"))?;
+        let output = to_napi_result!(encryptor.update(&chunk))?;
+        Ok(Buffer::from(output))
+    }
+
+    /// Seal the final segment and end the stream
+    #[napi]
+    pub fn finish(&mut self) -> napi::Result<Buffer> {
+        let encryptor = self.encryptor.take()
+            .ok_or_else(|| napi::Error::from_reason("Stream already finished"))?;
+        let output = to_napi_result!(encryptor.finish())?;
+        Ok(Buffer::from(output))
+    }
+}
+
+/// Incremental decryption of streams produced by AesGcmEncryptStream
+#[napi]
+pub struct AesGcmDecryptStream {
+    decryptor: Option<AesGcmStreamDecryptor>,
+}
+
+#[napi]
+impl AesGcmDecryptStream {
+    /// Start decrypting a stream under a 32-byte key
+    #[napi(constructor)]
+    pub fn new(key: Buffer) -> napi::Result<Self> {
+        let decryptor = to_napi_result!(AesGcmStreamDecryptor::new(&key))?;
+        Ok(Self { decryptor: Some(decryptor) })
+    }
+
+    /// Add ciphertext, returning any plaintext that has been authenticated
+    #[napi]
+    pub fn update(&mut self, chunk: Buffer) -> napi::Result<Buffer> {
+        let decryptor = self.decryptor.as_mut()
+            .ok_or_else(|| napi::Error::from_reason("Stream already finished"))?;
+        let output = to_napi_result!(decryptor.update(&chunk))?;
+        Ok(Buffer::from(output))
+    }
+
+    /// Authenticate the final segment and end the stream
+    #[napi]
+    pub fn finish(&mut self) -> napi::Result<Buffer> {
+        let decryptor = self.decryptor.take()
+            .ok_or_else(|| napi::Error::from_reason("Stream already finished"))?;
+        let output = to_napi_result!(decryptor.finish())?;
+        Ok(Buffer::from(output))
+    }
+}
+
+/// Direction of a file operation, holding the stream state it feeds
+enum FileCipher {
+    Encrypt(Box<AesGcmStreamEncryptor>),
+    Decrypt(Box<AesGcmStreamDecryptor>),
+}
+
+impl FileCipher {
+    fn update(&mut self, chunk: &[u8]) -> CryptoResult<Vec<u8>> {
+        match self {
+            FileCipher::Encrypt(encryptor) => encryptor.update(chunk),
+            FileCipher::Decrypt(decryptor) => decryptor.update(chunk),
+        }
+    }
+
+    fn finish(self) -> CryptoResult<Vec<u8>> {
+        match self {
+            FileCipher::Encrypt(encryptor) => encryptor.finish(),
+            FileCipher::Decrypt(decryptor) => decryptor.finish(),
+        }
+    }
+}
+
+/// Convert a filesystem error, naming the path involved
+fn io_error(path: &Path, err: std::io::Error) -> napi::Error {
+    napi::Error::from_reason(format!("{}: {}", path.display(), err))
+}
+
+/// Background task streaming a file through AES-256-GCM on the thread pool
+///
+/// Output goes to a temporary file beside the destination that is renamed
+/// into place only once the whole stream has been processed, so a failed or
+/// tampered decryption never leaves partial plaintext at `output`.
+pub struct FileTask {
+    cipher: Option<FileCipher>,
+    input: PathBuf,
+    output: PathBuf,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl FileTask {
+    fn spawn(cipher: CryptoResult<FileCipher>, input: String, output: String, on_progress: Option<ProgressCallback>) -> napi::Result<AsyncTask<Self>> {
+        let cipher = to_napi_result!(cipher)?;
+        Ok(AsyncTask::new(Self { cipher: Some(cipher), input: input.into(), output: output.into(), on_progress }))
+    }
+
+    fn temporary_path(&self) -> PathBuf {
+        let mut name = self.output.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+        name.push(".partial");
+        self.output.with_file_name(name)
+    }
+
+    fn process(&self, mut cipher: FileCipher, temporary: &Path, on_progress: Option<&ProgressCallback>) -> napi::Result<u64> {
+        let mut reader = File::open(&self.input).map_err(|err| io_error(&self.input, err))?;
+        let total = reader.metadata().map_err(|err| io_error(&self.input, err))?.len();
+        let mut writer = File::create(temporary).map_err(|err| io_error(temporary, err))?;
+
+        let mut buffer = vec![0u8; STREAM_CHUNK_SIZE];
+        let mut processed = 0u64;
+        let mut written = 0u64;
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(err) if err.kind() == ErrorKind::Interrupted => continue,
+                Err(err) => return Err(io_error(&self.input, err)),
+            };
+            let output = to_napi_result!(cipher.update(&buffer[..read]))?;
+            writer.write_all(&output).map_err(|err| io_error(temporary, err))?;
+            processed += read as u64;
+            written += output.len() as u64;
+            if total > 0 {
+                report_progress(on_progress, processed.min(total) as f64 / total as f64);
+            }
+        }
+
+        let output = to_napi_result!(cipher.finish())?;
+        writer.write_all(&output).map_err(|err| io_error(temporary, err))?;
+        writer.sync_all().map_err(|err| io_error(temporary, err))?;
+        report_progress(on_progress, 1.0);
+        Ok(written + output.len() as u64)
+    }
+}
+
+impl Task for FileTask {
+    type Output = f64;
+    type JsValue = f64;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        let cipher = self.cipher.take()
+            .ok_or_else(|| napi::Error::from_reason("Task already completed"))?;
+        let on_progress = self.on_progress.take();
+        let temporary = self.temporary_path();
+
+        let written = self.process(cipher, &temporary, on_progress.as_ref())
+            .and_then(|written| {
+                fs::rename(&temporary, &self.output).map_err(|err| io_error(&self.output, err))?;
+                Ok(written)
+            });
+        if written.is_err() {
+            let _ = fs::remove_file(&temporary);
+        }
+        Ok(written? as f64)
+    }
+
+    fn resolve(&mut self, _env: Env, output: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(output)
+    }
+}
+
+/// Path-based AES-256-GCM file encryption that streams on the thread pool
+#[napi]
+pub struct FileCrypto;
+
+#[napi]
+impl FileCrypto {
+    /// Encrypt `inPath` into `outPath` under a 32-byte key, resolving to the number of bytes written
+    #[napi(ts_args_type = "inPath: string, outPath: string, key: Buffer, onProgress?: (progress: number) => void")]
+    pub fn encrypt_file(in_path: String, out_path: String, key: Buffer, on_progress: Option<ProgressCallback>) -> napi::Result<AsyncTask<FileTask>> {
+        let cipher = AesGcmStreamEncryptor::new(&key).map(|encryptor| FileCipher::Encrypt(Box::new(encryptor)));
+        FileTask::spawn(cipher, in_path, out_path, on_progress)
+    }
+
+    /// Decrypt a file written by encryptFile; `outPath` is only created once the whole file authenticates
+    #[napi(ts_args_type = "inPath: string, outPath: string, key: Buffer, onProgress?: (progress: number) => void")]
+    pub fn decrypt_file(in_path: String, out_path: String, key: Buffer, on_progress: Option<ProgressCallback>) -> napi::Result<AsyncTask<FileTask>> {
+        let cipher = AesGcmStreamDecryptor::new(&key).map(|decryptor| FileCipher::Decrypt(Box::new(decryptor)));
+        FileTask::spawn(cipher, in_path, out_path, on_progress)
+    }
 }
 
 /// Asymmetric Encryption Module
@@ -84,12 +582,24 @@ impl AsymmetricCrypto {
         Ok(RsaKeyPairJs::from(keypair))
     }
 
+    /// Generate RSA-2048 key pair on the thread pool, optionally reporting progress
+    #[napi(ts_args_type = "onProgress?: (progress: number) => void")]
+    pub fn generate_rsa_keypair_async(on_progress: Option<ProgressCallback>) -> AsyncTask<RsaKeypairTask> {
+        RsaKeypairTask::spawn(2048, on_progress)
+    }
+
+    /// Generate RSA key pair with custom bit size on the thread pool, optionally reporting progress
+    #[napi(ts_args_type = "bits: number, onProgress?: (progress: number) => void")]
+    pub fn generate_rsa_keypair_with_size_async(bits: u32, on_progress: Option<ProgressCallback>) -> AsyncTask<RsaKeypairTask> {
+        RsaKeypairTask::spawn(bits as usize, on_progress)
+    }
+
     /// Encrypt data using RSA-OAEP
     #[napi]
-    pub fn encrypt_rsa(plaintext: Buffer, public_key_pem: String) -> napi::Result<Buffer> {
+    pub fn encrypt_rsa(plaintext: Buffer, public_key_pem: String, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let public_key = to_napi_result!(RsaKeyPair::from_public_key_pem(&public_key_pem))?;
         let ciphertext = to_napi_result!(RsaCrypto::encrypt(&plaintext, &public_key))?;
-        Ok(Buffer::from(ciphertext))
+        encode_output(ciphertext, encoding)
     }
 
     /// Decrypt data using RSA-OAEP
@@ -100,6 +610,42 @@ impl AsymmetricCrypto {
         Ok(Buffer::from(plaintext))
     }
 
+    /// Encrypt data using RSA-OAEP on the thread pool
+    #[napi]
+    pub fn encrypt_rsa_async(plaintext: Buffer, public_key_pem: String) -> AsyncTask<BufferTask> {
+        BufferTask::spawn(move || {
+            let public_key = RsaKeyPair::from_public_key_pem(&public_key_pem)?;
+            RsaCrypto::encrypt(&plaintext, &public_key)
+        })
+    }
+
+    /// Decrypt data using RSA-OAEP on the thread pool
+    #[napi]
+    pub fn decrypt_rsa_async(ciphertext: Buffer, private_key_pem: String) -> AsyncTask<BufferTask> {
+        BufferTask::spawn(move || {
+            let keypair = RsaKeyPair::from_private_key_pem(&private_key_pem)?;
+            RsaCrypto::decrypt(&ciphertext, keypair.private_key())
+        })
+    }
+
+    /// Sign data using RSA (PSS with SHA-256 unless overridden)
+    #[napi]
+    pub fn sign_rsa(message: Buffer, private_key_pem: String, options: Option<RsaSignOptions>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let (padding, hash) = rsa_sign_options(options)?;
+        let keypair = to_napi_result!(RsaKeyPair::from_private_key_pem(&private_key_pem))?;
+        let signature = to_napi_result!(RsaCrypto::sign(&message, keypair.private_key(), padding, hash))?;
+        encode_output(signature, encoding)
+    }
+
+    /// Verify RSA signature (PSS with SHA-256 unless overridden)
+    #[napi]
+    pub fn verify_rsa(message: Buffer, signature: Buffer, public_key_pem: String, options: Option<RsaSignOptions>) -> napi::Result<bool> {
+        let (padding, hash) = rsa_sign_options(options)?;
+        let public_key = to_napi_result!(RsaKeyPair::from_public_key_pem(&public_key_pem))?;
+        let is_valid = to_napi_result!(RsaCrypto::verify(&message, &signature, &public_key, padding, hash))?;
+        Ok(is_valid)
+    }
+
     /// Generate Ed25519 key pair
     #[napi]
     pub fn generate_ed25519_keypair() -> napi::Result<Ed25519KeyPairJs> {
@@ -109,10 +655,18 @@ impl AsymmetricCrypto {
 
     /// Sign data using Ed25519
     #[napi]
-    pub fn sign_ed25519(message: Buffer, signing_key_bytes: Buffer) -> napi::Result<Buffer> {
+    pub fn sign_ed25519(message: Buffer, signing_key_bytes: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let keypair = to_napi_result!(Ed25519KeyPair::from_private_key_bytes(&signing_key_bytes))?;
         let signature = to_napi_result!(Ed25519Crypto::sign(&message, keypair.signing_key()))?;
-        Ok(Buffer::from(signature))
+        encode_output(signature, encoding)
+    }
+
+    /// Sign data using Ed25519 with a private key held in a SecureKeyHandle
+    #[napi]
+    pub fn sign_ed25519_with_handle(message: Buffer, signing_key: &SecureKeyHandle, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let keypair = to_napi_result!(Ed25519KeyPair::from_private_key_bytes(signing_key.bytes()?))?;
+        let signature = to_napi_result!(Ed25519Crypto::sign(&message, keypair.signing_key()))?;
+        encode_output(signature, encoding)
     }
 
     /// Verify Ed25519 signature
@@ -132,10 +686,18 @@ impl AsymmetricCrypto {
 
     /// Sign data using ECDSA P-256
     #[napi]
-    pub fn sign_ecdsa(message: Buffer, signing_key_bytes: Buffer) -> napi::Result<Buffer> {
+    pub fn sign_ecdsa(message: Buffer, signing_key_bytes: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let keypair = to_napi_result!(EcdsaKeyPair::from_private_key_bytes(&signing_key_bytes))?;
         let signature = to_napi_result!(EcdsaCrypto::sign(&message, keypair.signing_key()))?;
-        Ok(Buffer::from(signature))
+        encode_output(signature, encoding)
+    }
+
+    /// Sign data using ECDSA P-256 with a private key held in a SecureKeyHandle
+    #[napi]
+    pub fn sign_ecdsa_with_handle(message: Buffer, signing_key: &SecureKeyHandle, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let keypair = to_napi_result!(EcdsaKeyPair::from_private_key_bytes(signing_key.bytes()?))?;
+        let signature = to_napi_result!(EcdsaCrypto::sign(&message, keypair.signing_key()))?;
+        encode_output(signature, encoding)
     }
 
     /// Verify ECDSA P-256 signature
@@ -145,6 +707,52 @@ impl AsymmetricCrypto {
         let is_valid = to_napi_result!(EcdsaCrypto::verify(&message, &signature, &verifying_key))?;
         Ok(is_valid)
     }
+
+    /// Generate X25519 key pair for key agreement and ECIES
+    #[napi]
+    pub fn generate_x25519_keypair() -> napi::Result<EcdhKeyPairJs> {
+        let keypair = to_napi_result!(Ecdh::generate_keypair(EcdhCurve::X25519))?;
+        Ok(EcdhKeyPairJs::from(keypair))
+    }
+
+    /// Generate P-256 key pair for key agreement and ECIES
+    #[napi]
+    pub fn generate_ecdh_p256_keypair() -> napi::Result<EcdhKeyPairJs> {
+        let keypair = to_napi_result!(Ecdh::generate_keypair(EcdhCurve::P256))?;
+        Ok(EcdhKeyPairJs::from(keypair))
+    }
+
+    /// Compute the raw ECDH shared secret ("x25519" by default, or "p256")
+    #[napi]
+    pub fn derive_shared_secret(private_key_bytes: Buffer, peer_public_key_bytes: Buffer, curve: Option<String>) -> napi::Result<Buffer> {
+        let keypair = to_napi_result!(EcdhKeyPair::from_private_key_bytes(ecdh_curve(curve)?, &private_key_bytes))?;
+        let shared = to_napi_result!(Ecdh::derive_shared_secret(&keypair, &peer_public_key_bytes))?;
+        Ok(Buffer::from(shared.as_slice()))
+    }
+
+    /// Encrypt to an X25519 (default) or P-256 public key with ECIES
+    #[napi]
+    pub fn ecies_encrypt(plaintext: Buffer, recipient_public_key_bytes: Buffer, curve: Option<String>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let ciphertext = to_napi_result!(Ecies::encrypt(&plaintext, &recipient_public_key_bytes, ecdh_curve(curve)?))?;
+        encode_output(ciphertext, encoding)
+    }
+
+    /// Decrypt an ECIES message with the recipient's private key
+    #[napi]
+    pub fn ecies_decrypt(ciphertext: Buffer, private_key_bytes: Buffer, curve: Option<String>) -> napi::Result<Buffer> {
+        let keypair = to_napi_result!(EcdhKeyPair::from_private_key_bytes(ecdh_curve(curve)?, &private_key_bytes))?;
+        let plaintext = to_napi_result!(Ecies::decrypt(&ciphertext, &keypair))?;
+        Ok(Buffer::from(plaintext))
+    }
+}
+
+/// Parse a JavaScript curve name for key agreement
+fn ecdh_curve(curve: Option<String>) -> napi::Result<EcdhCurve> {
+    match curve.as_deref() {
+        None | Some("x25519") => Ok(EcdhCurve::X25519),
+        Some("p256") => Ok(EcdhCurve::P256),
+        Some(other) => Err(napi::Error::from_reason(format!("Unsupported ECDH curve: {}", other))),
+    }
 }
 
 /// Hash Functions Module
@@ -155,9 +763,9 @@ pub struct HashFunctions;
 impl HashFunctions {
     /// Compute SHA-256 hash
     #[napi]
-    pub fn sha256(data: Buffer) -> napi::Result<Buffer> {
+    pub fn sha256(data: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let hash = to_napi_result!(Sha256Hash::hash(&data))?;
-        Ok(Buffer::from(hash))
+        encode_output(hash, encoding)
     }
 
     /// Compute SHA-256 hash and return as hex string
@@ -167,11 +775,18 @@ impl HashFunctions {
         Ok(hex)
     }
 
+    /// Verify data against a SHA-256 hash in constant time
+    #[napi]
+    pub fn verify_sha256(data: Buffer, expected: Buffer) -> napi::Result<bool> {
+        let is_valid = to_napi_result!(Sha256Hash::verify(&data, &expected))?;
+        Ok(is_valid)
+    }
+
     /// Compute SHA-512 hash
     #[napi]
-    pub fn sha512(data: Buffer) -> napi::Result<Buffer> {
+    pub fn sha512(data: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let hash = to_napi_result!(Sha512Hash::hash(&data))?;
-        Ok(Buffer::from(hash))
+        encode_output(hash, encoding)
     }
 
     /// Compute SHA-512 hash and return as hex string
@@ -181,11 +796,18 @@ impl HashFunctions {
         Ok(hex)
     }
 
+    /// Verify data against a SHA-512 hash in constant time
+    #[napi]
+    pub fn verify_sha512(data: Buffer, expected: Buffer) -> napi::Result<bool> {
+        let is_valid = to_napi_result!(Sha512Hash::verify(&data, &expected))?;
+        Ok(is_valid)
+    }
+
     /// Compute BLAKE3 hash
     #[napi]
-    pub fn blake3(data: Buffer) -> napi::Result<Buffer> {
+    pub fn blake3(data: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let hash = to_napi_result!(Blake3Hash::hash(&data))?;
-        Ok(Buffer::from(hash))
+        encode_output(hash, encoding)
     }
 
     /// Compute BLAKE3 hash and return as hex string
@@ -197,74 +819,324 @@ impl HashFunctions {
 
     /// Compute BLAKE3 hash with custom length
     #[napi]
-    pub fn blake3_with_length(data: Buffer, length: u32) -> napi::Result<Buffer> {
+    pub fn blake3_with_length(data: Buffer, length: u32, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
         let hash = to_napi_result!(Blake3Hash::hash_with_length(&data, length as usize))?;
-        Ok(Buffer::from(hash))
+        encode_output(hash, encoding)
     }
 
-    /// Compute HMAC-SHA256
+    /// Compute keyed BLAKE3 hash (MAC) with a 32-byte key
     #[napi]
-    pub fn hmac_sha256(key: Buffer, message: Buffer) -> napi::Result<Buffer> {
-        let mac = to_napi_result!(Hmac::sha256(&key, &message))?;
-        Ok(Buffer::from(mac))
+    pub fn blake3_keyed(key: Buffer, data: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let hash = to_napi_result!(Blake3Hash::keyed_hash(&key, &data))?;
+        encode_output(hash, encoding)
     }
 
-    /// Verify HMAC-SHA256
+    /// Derive key material with BLAKE3 in key derivation mode
     #[napi]
-    pub fn verify_hmac_sha256(key: Buffer, message: Buffer, expected_mac: Buffer) -> napi::Result<bool> {
-        let is_valid = to_napi_result!(Hmac::verify_sha256(&key, &message, &expected_mac))?;
-        Ok(is_valid)
+    pub fn blake3_derive_key(context: String, material: Buffer, length: u32) -> napi::Result<Buffer> {
+        let key = to_napi_result!(Blake3Hash::derive_key(&context, &material, length as usize))?;
+        Ok(Buffer::from(key))
     }
 
-    /// Compute HMAC-SHA512
-    #[napi]
-    pub fn hmac_sha512(key: Buffer, message: Buffer) -> napi::Result<Buffer> {
-        let mac = to_napi_result!(Hmac::sha512(&key, &message))?;
-        Ok(Buffer::from(mac))
+    /// Compute SHA3-256 hash
+    #[napi(js_name = "sha3_256")]
+    pub fn sha3_256(data: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let hash = to_napi_result!(Sha3_256Hash::hash(&data))?;
+        encode_output(hash, encoding)
     }
 
-    /// Verify HMAC-SHA512
-    #[napi]
-    pub fn verify_hmac_sha512(key: Buffer, message: Buffer, expected_mac: Buffer) -> napi::Result<bool> {
-        let is_valid = to_napi_result!(Hmac::verify_sha512(&key, &message, &expected_mac))?;
-        Ok(is_valid)
+    /// Compute SHA3-256 hash and return as hex string
+    #[napi(js_name = "sha3_256Hex")]
+    pub fn sha3_256_hex(data: Buffer) -> napi::Result<String> {
+        let hex = to_napi_result!(Sha3_256Hash::hash_hex(&data))?;
+        Ok(hex)
     }
-}
 
-/// Key Derivation Functions Module
-#[napi]
-pub struct KeyDerivation;
+    /// Compute SHA3-512 hash
+    #[napi(js_name = "sha3_512")]
+    pub fn sha3_512(data: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let hash = to_napi_result!(Sha3_512Hash::hash(&data))?;
+        encode_output(hash, encoding)
+    }
 
-#[napi]
-impl KeyDerivation {
-    /// Derive key using Argon2
-    #[napi]
-    pub fn argon2(password: Buffer, salt: Buffer, length: u32) -> napi::Result<Buffer> {
-        let key = to_napi_result!(Argon2Kdf::derive_key(&password, &salt, length as usize))?;
-        Ok(Buffer::from(key))
+    /// Compute SHA3-512 hash and return as hex string
+    #[napi(js_name = "sha3_512Hex")]
+    pub fn sha3_512_hex(data: Buffer) -> napi::Result<String> {
+        let hex = to_napi_result!(Sha3_512Hash::hash_hex(&data))?;
+        Ok(hex)
     }
 
-    /// Derive key using PBKDF2-SHA256
+    /// Compute HMAC-SHA256
     #[napi]
-    pub fn pbkdf2_sha256(password: Buffer, salt: Buffer, iterations: u32, length: u32) -> napi::Result<Buffer> {
-        let key = to_napi_result!(Pbkdf2Kdf::derive_sha256(&password, &salt, iterations, length as usize))?;
-        Ok(Buffer::from(key))
+    pub fn hmac_sha256(key: Buffer, message: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let mac = hmac(StreamingHasher::hmac_sha256(&key), &message)?;
+        encode_output(mac, encoding)
     }
 
-    /// Derive key using PBKDF2-SHA512
+    /// Compute HMAC-SHA256 and return as hex string
     #[napi]
-    pub fn pbkdf2_sha512(password: Buffer, salt: Buffer, iterations: u32, length: u32) -> napi::Result<Buffer> {
-        let key = to_napi_result!(Pbkdf2Kdf::derive_sha512(&password, &salt, iterations, length as usize))?;
-        Ok(Buffer::from(key))
+    pub fn hmac_sha256_hex(key: Buffer, message: Buffer) -> napi::Result<String> {
+        let mac = hmac(StreamingHasher::hmac_sha256(&key), &message)?;
+        Ok(hex::encode(mac))
     }
 
-    /// Derive key using HKDF-SHA256
+    /// Verify HMAC-SHA256
     #[napi]
-    pub fn hkdf_sha256(input_key: Buffer, salt: Option<Buffer>, info: Option<Buffer>, length: u32) -> napi::Result<Buffer> {
-        let salt_ref = salt.as_ref().map(|s| s.as_ref());
-        let info_bytes = info.as_ref().map(|i| i.as_ref()).unwrap_or(&[]);
-        let key = to_napi_result!(HkdfKdf::derive_sha256(&input_key, salt_ref, info_bytes, length as usize))?;
-        Ok(Buffer::from(key))
+    pub fn verify_hmac_sha256(key: Buffer, message: Buffer, expected_mac: Buffer) -> napi::Result<bool> {
+        let mac = hmac(StreamingHasher::hmac_sha256(&key), &message)?;
+        Ok(ConstantTime::bytes_eq(&mac, &expected_mac))
+    }
+
+    /// Compute HMAC-SHA256 with a key held in a SecureKeyHandle
+    #[napi]
+    pub fn hmac_sha256_with_handle(key: &SecureKeyHandle, message: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let mac = hmac(StreamingHasher::hmac_sha256(key.bytes()?), &message)?;
+        encode_output(mac, encoding)
+    }
+
+    /// Compute HMAC-SHA512
+    #[napi]
+    pub fn hmac_sha512(key: Buffer, message: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let mac = hmac(StreamingHasher::hmac_sha512(&key), &message)?;
+        encode_output(mac, encoding)
+    }
+
+    /// Compute HMAC-SHA512 and return as hex string
+    #[napi]
+    pub fn hmac_sha512_hex(key: Buffer, message: Buffer) -> napi::Result<String> {
+        let mac = hmac(StreamingHasher::hmac_sha512(&key), &message)?;
+        Ok(hex::encode(mac))
+    }
+
+    /// Verify HMAC-SHA512
+    #[napi]
+    pub fn verify_hmac_sha512(key: Buffer, message: Buffer, expected_mac: Buffer) -> napi::Result<bool> {
+        let mac = hmac(StreamingHasher::hmac_sha512(&key), &message)?;
+        Ok(ConstantTime::bytes_eq(&mac, &expected_mac))
+    }
+
+    /// Compare two buffers in constant time; unlike node:crypto, different lengths return false
+    #[napi]
+    pub fn timing_safe_equal(a: Buffer, b: Buffer) -> bool {
+        ConstantTime::bytes_eq(&a, &b)
+    }
+}
+
+/// Encode binary output the way node:crypto `Hash#digest(encoding)` does
+fn encode_output(digest: Vec<u8>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+    use base64::Engine;
+    match encoding.as_deref() {
+        None | Some("buffer") => Ok(Either::A(Buffer::from(digest))),
+        Some("hex") => Ok(Either::B(hex::encode(digest))),
+        Some("base64") => Ok(Either::B(base64::engine::general_purpose::STANDARD.encode(digest))),
+        Some("base64url") => Ok(Either::B(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(digest))),
+        Some(other) => Err(napi::Error::from_reason(format!("Unsupported output encoding: {}", other))),
+    }
+}
+
+/// Convert hasher input to bytes, accepting strings as UTF-8
+fn input_bytes(data: &Either<Buffer, String>) -> &[u8] {
+    match data {
+        Either::A(buffer) => buffer,
+        Either::B(string) => string.as_bytes(),
+    }
+}
+
+/// Define an incremental hasher class mirroring node:crypto's Hash
+macro_rules! streaming_hasher {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[napi]
+        pub struct $name {
+            hasher: Option<StreamingHasher>,
+        }
+
+        #[napi]
+        impl $name {
+            /// Feed the next chunk of input; returns `this` for chaining
+            #[napi]
+            pub fn update(&mut self, this: This, data: Either<Buffer, String>) -> napi::Result<This> {
+                self.hasher.as_mut()
+                    .ok_or_else(|| napi::Error::from_reason("Digest already called"))?
+                    .update(input_bytes(&data));
+                Ok(this)
+            }
+
+            /// Finish the hash; the object cannot be used afterwards
+            #[napi]
+            pub fn digest(&mut self, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+                let hasher = self.hasher.take()
+                    .ok_or_else(|| napi::Error::from_reason("Digest already called"))?;
+                encode_output(hasher.finalize(), encoding)
+            }
+
+            /// Copy the current state into an independent hasher
+            #[napi]
+            pub fn copy(&self) -> napi::Result<$name> {
+                let hasher = self.hasher.clone()
+                    .ok_or_else(|| napi::Error::from_reason("Digest already called"))?;
+                Ok($name { hasher: Some(hasher) })
+            }
+        }
+    };
+}
+
+streaming_hasher!(
+    /// Incremental SHA-256 hasher
+    Sha256Stream
+);
+streaming_hasher!(
+    /// Incremental SHA-512 hasher
+    Sha512Stream
+);
+streaming_hasher!(
+    /// Incremental BLAKE3 hasher
+    Blake3Stream
+);
+streaming_hasher!(
+    /// Incremental HMAC-SHA256
+    HmacSha256Stream
+);
+streaming_hasher!(
+    /// Incremental HMAC-SHA512
+    HmacSha512Stream
+);
+
+impl Default for Sha256Stream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[napi]
+impl Sha256Stream {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self { hasher: Some(StreamingHasher::sha256()) }
+    }
+}
+
+impl Default for Sha512Stream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[napi]
+impl Sha512Stream {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self { hasher: Some(StreamingHasher::sha512()) }
+    }
+}
+
+impl Default for Blake3Stream {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[napi]
+impl Blake3Stream {
+    #[napi(constructor)]
+    pub fn new() -> Self {
+        Self { hasher: Some(StreamingHasher::blake3()) }
+    }
+}
+
+#[napi]
+impl HmacSha256Stream {
+    #[napi(constructor)]
+    pub fn new(key: Buffer) -> napi::Result<Self> {
+        let hasher = to_napi_result!(StreamingHasher::hmac_sha256(&key))?;
+        Ok(Self { hasher: Some(hasher) })
+    }
+}
+
+#[napi]
+impl HmacSha512Stream {
+    #[napi(constructor)]
+    pub fn new(key: Buffer) -> napi::Result<Self> {
+        let hasher = to_napi_result!(StreamingHasher::hmac_sha512(&key))?;
+        Ok(Self { hasher: Some(hasher) })
+    }
+}
+
+/// Key Derivation Functions Module
+#[napi]
+pub struct KeyDerivation;
+
+#[napi]
+impl KeyDerivation {
+    /// Derive key using Argon2 (Argon2id with libsilver defaults unless overridden)
+    #[napi]
+    pub fn argon2(password: Buffer, salt: Buffer, length: u32, options: Option<Argon2Options>) -> napi::Result<Buffer> {
+        let params = Argon2Params::from_options(options)?;
+        let key = to_napi_result!(params.derive_key(&password, &salt, length as usize))?;
+        Ok(Buffer::from(key))
+    }
+
+    /// Derive key using PBKDF2-SHA256
+    #[napi]
+    pub fn pbkdf2_sha256(password: Buffer, salt: Buffer, iterations: u32, length: u32) -> napi::Result<Buffer> {
+        let key = to_napi_result!(Pbkdf2Kdf::derive_sha256(&password, &salt, iterations, length as usize))?;
+        Ok(Buffer::from(key))
+    }
+
+    /// Derive key using PBKDF2-SHA512
+    #[napi]
+    pub fn pbkdf2_sha512(password: Buffer, salt: Buffer, iterations: u32, length: u32) -> napi::Result<Buffer> {
+        let key = to_napi_result!(Pbkdf2Kdf::derive_sha512(&password, &salt, iterations, length as usize))?;
+        Ok(Buffer::from(key))
+    }
+
+    /// Derive key using Argon2 on the thread pool
+    #[napi]
+    pub fn argon2_async(password: Buffer, salt: Buffer, length: u32, options: Option<Argon2Options>) -> napi::Result<AsyncTask<BufferTask>> {
+        let params = Argon2Params::from_options(options)?;
+        Ok(BufferTask::spawn(move || params.derive_key(&password, &salt, length as usize)))
+    }
+
+    /// Derive key using PBKDF2-SHA256 on the thread pool; `signal` cancels it between batches
+    #[napi(ts_args_type = "password: Buffer, salt: Buffer, iterations: number, length: number, signal?: AbortSignal, onProgress?: (progress: number) => void")]
+    pub fn pbkdf2_sha256_async(env: Env, password: Buffer, salt: Buffer, iterations: u32, length: u32, signal: Option<JsObject>, on_progress: Option<ProgressCallback>) -> napi::Result<AsyncTask<KdfTask>> {
+        KdfTask::spawn(&env, signal, on_progress, move |progress| {
+            Pbkdf2Kdf::derive_sha256_with_progress(&password, &salt, iterations, length as usize, progress)
+        })
+    }
+
+    /// Derive key using PBKDF2-SHA512 on the thread pool; `signal` cancels it between batches
+    #[napi(ts_args_type = "password: Buffer, salt: Buffer, iterations: number, length: number, signal?: AbortSignal, onProgress?: (progress: number) => void")]
+    pub fn pbkdf2_sha512_async(env: Env, password: Buffer, salt: Buffer, iterations: u32, length: u32, signal: Option<JsObject>, on_progress: Option<ProgressCallback>) -> napi::Result<AsyncTask<KdfTask>> {
+        KdfTask::spawn(&env, signal, on_progress, move |progress| {
+            Pbkdf2Kdf::derive_sha512_with_progress(&password, &salt, iterations, length as usize, progress)
+        })
+    }
+
+    /// Derive key using scrypt (N = 2^17, r = 8, p = 1 unless overridden)
+    #[napi]
+    pub fn scrypt(password: Buffer, salt: Buffer, length: u32, options: Option<ScryptOptions>) -> napi::Result<Buffer> {
+        let params = ScryptParams::from_options(options)?;
+        let key = to_napi_result!(ScryptKdf::derive_key(&password, &salt, params.log_n, params.r, params.p, length as usize))?;
+        Ok(Buffer::from(key))
+    }
+
+    /// Derive key using scrypt on the thread pool; `signal` cancels it between batches
+    #[napi(ts_args_type = "password: Buffer, salt: Buffer, length: number, options?: ScryptOptions, signal?: AbortSignal, onProgress?: (progress: number) => void")]
+    pub fn scrypt_async(env: Env, password: Buffer, salt: Buffer, length: u32, options: Option<ScryptOptions>, signal: Option<JsObject>, on_progress: Option<ProgressCallback>) -> napi::Result<AsyncTask<KdfTask>> {
+        let params = ScryptParams::from_options(options)?;
+        KdfTask::spawn(&env, signal, on_progress, move |progress| {
+            ScryptKdf::derive_key_with_progress(&password, &salt, params.log_n, params.r, params.p, length as usize, progress)
+        })
+    }
+
+    /// Derive key using HKDF-SHA256
+    #[napi]
+    pub fn hkdf_sha256(input_key: Buffer, salt: Option<Buffer>, info: Option<Buffer>, length: u32) -> napi::Result<Buffer> {
+        let salt_ref = salt.as_ref().map(|s| s.as_ref());
+        let info_bytes = info.as_ref().map(|i| i.as_ref()).unwrap_or(&[]);
+        let key = to_napi_result!(HkdfKdf::derive_sha256(&input_key, salt_ref, info_bytes, length as usize))?;
+        Ok(Buffer::from(key))
     }
 
     /// Derive key using HKDF-SHA512
@@ -323,11 +1195,879 @@ impl From<RsaKeyPair> for RsaKeyPairJs {
     fn from(keypair: RsaKeyPair) -> Self {
         Self {
             public_key_pem: keypair.public_key_pem().unwrap_or_default(),
-            private_key_pem: keypair.private_key_pem().unwrap_or_default(),
+            private_key_pem: keypair.private_key_pem().map(|pem| pem.to_string()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Key format conversion between PEM, DER, JWK and raw bytes
+#[napi]
+pub struct KeyFormats;
+
+#[napi]
+impl KeyFormats {
+    /// Convert a PEM key (PKCS#8, SPKI, PKCS#1 or SEC1) to a JWK JSON string
+    #[napi]
+    pub fn to_jwk(pem: String) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_pem(&pem))?;
+        let jwk = to_napi_result!(key.to_jwk())?;
+        Ok(jwk.to_string())
+    }
+
+    /// Convert a JWK JSON string to PEM (PKCS#8 for private keys, SPKI for public keys)
+    #[napi]
+    pub fn from_jwk(jwk: String) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_jwk(&jwk))?;
+        key_to_pem(&key)
+    }
+
+    /// Convert a PEM key to DER (PKCS#8 for private keys, SPKI for public keys)
+    #[napi]
+    pub fn to_der(pem: String) -> napi::Result<Buffer> {
+        let key = to_napi_result!(AsymmetricKey::from_pem(&pem))?;
+        let der = to_napi_result!(key.to_der())?;
+        Ok(Buffer::from(der.as_slice()))
+    }
+
+    /// Convert a PKCS#8 DER private key to PEM
+    #[napi]
+    pub fn from_pkcs8_der(der: Buffer) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_pkcs8_der(&der))?;
+        key_to_pem(&key)
+    }
+
+    /// Convert an SPKI DER public key to PEM
+    #[napi]
+    pub fn from_spki_der(der: Buffer) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_spki_der(&der))?;
+        key_to_pem(&key)
+    }
+
+    /// Convert a PEM ECDSA P-256 or Ed25519 key to the raw bytes used by AsymmetricCrypto
+    #[napi]
+    pub fn to_raw(pem: String) -> napi::Result<Buffer> {
+        let key = to_napi_result!(AsymmetricKey::from_pem(&pem))?;
+        let raw = to_napi_result!(key.to_raw())?;
+        Ok(Buffer::from(raw.as_slice()))
+    }
+
+    /// Convert a raw private key ("p256" scalar or "ed25519" seed) to PKCS#8 PEM
+    #[napi]
+    pub fn from_raw_private(raw: Buffer, algorithm: String) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_raw_private(key_algorithm(&algorithm)?, &raw))?;
+        key_to_pem(&key)
+    }
+
+    /// Convert a raw public key ("p256" SEC1 point or "ed25519" key) to SPKI PEM
+    #[napi]
+    pub fn from_raw_public(raw: Buffer, algorithm: String) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_raw_public(key_algorithm(&algorithm)?, &raw))?;
+        key_to_pem(&key)
+    }
+
+    /// Extract the SPKI PEM public key from a PEM private key
+    #[napi]
+    pub fn to_public_key_pem(pem: String) -> napi::Result<String> {
+        let key = to_napi_result!(AsymmetricKey::from_pem(&pem))?;
+        key_to_pem(&key.public_key())
+    }
+}
+
+/// Encode a key as PEM for JavaScript
+fn key_to_pem(key: &AsymmetricKey) -> napi::Result<String> {
+    let pem = to_napi_result!(key.to_pem())?;
+    Ok(pem.to_string())
+}
+
+/// Parse a JavaScript algorithm name for raw key import
+fn key_algorithm(algorithm: &str) -> napi::Result<KeyAlgorithm> {
+    match algorithm {
+        "p256" => Ok(KeyAlgorithm::EcdsaP256),
+        "ed25519" => Ok(KeyAlgorithm::Ed25519),
+        other => Err(napi::Error::from_reason(format!("Unsupported raw key algorithm: {}", other))),
+    }
+}
+
+/// JWT and PASETO v4 tokens; claims and payloads are JSON strings
+#[napi]
+pub struct Tokens;
+
+#[napi]
+impl Tokens {
+    /// Sign a JWT. `key` is the HMAC secret for HS*, a PEM private key for RS256/PS256,
+    /// or a PEM or raw private key for ES256/EdDSA
+    #[napi]
+    pub fn sign_jwt(claims: String, algorithm: String, key: Either<Buffer, String>, options: Option<JwtSignOptions>) -> napi::Result<String> {
+        let claims = json_object(&claims)?;
+        let algorithm = to_napi_result!(JwtAlgorithm::from_name(&algorithm))?;
+        let key_id = options.and_then(|options| options.key_id);
+        with_jwt_signing_key(algorithm, &key, |key| Jwt::sign_with_key_id(&claims, algorithm, key, key_id.as_deref()))
+    }
+
+    /// Verify a JWT signed with `algorithm` and return its claims. `key` is the HMAC secret,
+    /// a PEM key for any asymmetric algorithm, or a raw public key for ES256/EdDSA
+    #[napi]
+    pub fn verify_jwt(token: String, algorithm: String, key: Either<Buffer, String>, options: Option<JwtVerifyOptions>) -> napi::Result<String> {
+        let algorithm = to_napi_result!(JwtAlgorithm::from_name(&algorithm))?;
+        let mut validation = JwtValidation::new(algorithm);
+        if let Some(options) = options {
+            validation.validate_exp = options.require_exp.unwrap_or(true);
+            validation.leeway = options.leeway.unwrap_or(0) as u64;
+            validation.audience = options.audience;
+            validation.issuer = options.issuer;
+        }
+        let claims = with_jwt_verifying_key(algorithm, &key, |key| Jwt::verify(&token, key, &validation))?;
+        Ok(serde_json::Value::Object(claims).to_string())
+    }
+
+    /// Decode a JWT header without verifying the token, e.g. to look up the key by `kid`
+    #[napi]
+    pub fn decode_jwt_header(token: String) -> napi::Result<String> {
+        let header = to_napi_result!(Jwt::decode_header(&token))?;
+        Ok(serde_json::Value::Object(header).to_string())
+    }
+
+    /// Encrypt a JSON payload into a v4.local PASETO with a 32-byte key
+    #[napi]
+    pub fn encrypt_paseto(payload: String, key: Buffer, options: Option<PasetoOptions>) -> napi::Result<String> {
+        let options = PasetoParams::from_options(options);
+        json_object(&payload)?;
+        to_napi_result!(Paseto::encrypt_local(payload.as_bytes(), &key, options.footer.as_bytes(), options.implicit.as_bytes()))
+    }
+
+    /// Decrypt a v4.local PASETO, check its `exp`/`nbf` claims and return the JSON payload
+    #[napi]
+    pub fn decrypt_paseto(token: String, key: Buffer, options: Option<PasetoOptions>) -> napi::Result<String> {
+        let options = PasetoParams::from_options(options);
+        let payload = to_napi_result!(Paseto::decrypt_local(&token, &key, options.footer.as_bytes(), options.implicit.as_bytes()))?;
+        options.validate(payload)
+    }
+
+    /// Sign a JSON payload into a v4.public PASETO with a PEM or raw Ed25519 private key
+    #[napi]
+    pub fn sign_paseto(payload: String, secret_key: Either<Buffer, String>, options: Option<PasetoOptions>) -> napi::Result<String> {
+        let options = PasetoParams::from_options(options);
+        json_object(&payload)?;
+        let keypair = to_napi_result!(Ed25519KeyPair::from_private_key_bytes(&raw_key_bytes(&secret_key, false)?))?;
+        to_napi_result!(Paseto::sign_public(payload.as_bytes(), &keypair, options.footer.as_bytes(), options.implicit.as_bytes()))
+    }
+
+    /// Verify a v4.public PASETO, check its `exp`/`nbf` claims and return the JSON payload
+    #[napi]
+    pub fn verify_paseto(token: String, public_key: Either<Buffer, String>, options: Option<PasetoOptions>) -> napi::Result<String> {
+        let options = PasetoParams::from_options(options);
+        let public_key = to_napi_result!(Ed25519KeyPair::verifying_key_from_bytes(&raw_key_bytes(&public_key, true)?))?;
+        let payload = to_napi_result!(Paseto::verify_public(&token, &public_key, options.footer.as_bytes(), options.implicit.as_bytes()))?;
+        options.validate(payload)
+    }
+
+    /// Read a PASETO footer without verifying the token, e.g. to look up the key by `kid`
+    #[napi]
+    pub fn paseto_footer(token: String) -> napi::Result<String> {
+        let footer = to_napi_result!(Paseto::footer(&token))?;
+        String::from_utf8(footer).map_err(|_| napi::Error::from_reason("PASETO footer is not valid UTF-8"))
+    }
+}
+
+/// Parse a JSON object passed from JavaScript
+fn json_object(json: &str) -> napi::Result<serde_json::Map<String, serde_json::Value>> {
+    match serde_json::from_str(json) {
+        Ok(serde_json::Value::Object(object)) => Ok(object),
+        _ => Err(napi::Error::from_reason("Expected a JSON object")),
+    }
+}
+
+/// Raw ECDSA P-256 or Ed25519 key bytes from a raw Buffer or a PEM string
+fn raw_key_bytes(key: &Either<Buffer, String>, public: bool) -> napi::Result<Vec<u8>> {
+    match key {
+        Either::A(raw) => Ok(raw.to_vec()),
+        Either::B(pem) => {
+            let mut key = to_napi_result!(AsymmetricKey::from_pem(pem))?;
+            if public {
+                key = key.public_key();
+            }
+            let raw = to_napi_result!(key.to_raw())?;
+            Ok(raw.to_vec())
+        }
+    }
+}
+
+/// Resolve the JavaScript key for signing a JWT with `algorithm`
+fn with_jwt_signing_key<R>(algorithm: JwtAlgorithm, key: &Either<Buffer, String>, sign: impl FnOnce(JwtSigningKey<'_>) -> CryptoResult<R>) -> napi::Result<R> {
+    let result = match algorithm {
+        JwtAlgorithm::HS256 | JwtAlgorithm::HS384 | JwtAlgorithm::HS512 => sign(JwtSigningKey::Hmac(input_bytes(key))),
+        JwtAlgorithm::RS256 | JwtAlgorithm::PS256 => {
+            let Either::B(pem) = key else {
+                return Err(napi::Error::from_reason("RSA JWT keys must be PEM strings"));
+            };
+            let keypair = to_napi_result!(RsaKeyPair::from_private_key_pem(pem))?;
+            sign(JwtSigningKey::Rsa(&keypair))
+        }
+        JwtAlgorithm::ES256 => {
+            let keypair = to_napi_result!(EcdsaKeyPair::from_private_key_bytes(&raw_key_bytes(key, false)?))?;
+            sign(JwtSigningKey::Ecdsa(&keypair))
         }
+        JwtAlgorithm::EdDSA => {
+            let keypair = to_napi_result!(Ed25519KeyPair::from_private_key_bytes(&raw_key_bytes(key, false)?))?;
+            sign(JwtSigningKey::Ed25519(&keypair))
+        }
+    };
+    to_napi_result!(result)
+}
+
+/// Resolve the JavaScript key for verifying a JWT with `algorithm`
+fn with_jwt_verifying_key<R>(algorithm: JwtAlgorithm, key: &Either<Buffer, String>, verify: impl FnOnce(JwtVerifyingKey<'_>) -> CryptoResult<R>) -> napi::Result<R> {
+    let result = match algorithm {
+        JwtAlgorithm::HS256 | JwtAlgorithm::HS384 | JwtAlgorithm::HS512 => verify(JwtVerifyingKey::Hmac(input_bytes(key))),
+        JwtAlgorithm::RS256 | JwtAlgorithm::PS256 => {
+            let Either::B(pem) = key else {
+                return Err(napi::Error::from_reason("RSA JWT keys must be PEM strings"));
+            };
+            let public_pem = key_to_pem(&to_napi_result!(AsymmetricKey::from_pem(pem))?.public_key())?;
+            let public_key = to_napi_result!(RsaKeyPair::from_public_key_pem(&public_pem))?;
+            verify(JwtVerifyingKey::Rsa(&public_key))
+        }
+        JwtAlgorithm::ES256 => {
+            let public_key = to_napi_result!(EcdsaKeyPair::verifying_key_from_bytes(&raw_key_bytes(key, true)?))?;
+            verify(JwtVerifyingKey::Ecdsa(&public_key))
+        }
+        JwtAlgorithm::EdDSA => {
+            let public_key = to_napi_result!(Ed25519KeyPair::verifying_key_from_bytes(&raw_key_bytes(key, true)?))?;
+            verify(JwtVerifyingKey::Ed25519(&public_key))
+        }
+    };
+    to_napi_result!(result)
+}
+
+/// HOTP and TOTP one-time passwords with base32 secrets, compatible with authenticator apps
+#[napi]
+pub struct Otp;
+
+#[napi]
+impl Otp {
+    /// Generate a random 160-bit base32 secret
+    #[napi]
+    pub fn generate_secret() -> napi::Result<String> {
+        let secret = to_napi_result!(libsilver::core::Otp::generate_secret())?;
+        Ok(libsilver::core::Otp::base32_encode(&secret))
+    }
+
+    /// Compute the HOTP code for `counter`
+    #[napi]
+    pub fn hotp_generate(secret: String, counter: i64, options: Option<OtpOptions>) -> napi::Result<String> {
+        let counter = u64::try_from(counter).map_err(|_| napi::Error::from_reason("HOTP counter must not be negative"))?;
+        let params = OtpParams::from_options(options)?;
+        let secret = to_napi_result!(libsilver::core::Otp::base32_decode(&secret))?;
+        to_napi_result!(libsilver::core::Otp::hotp(&secret, counter, &params.config))
+    }
+
+    /// Compute the TOTP code for `options.timestamp` (Unix seconds) or the current time
+    #[napi]
+    pub fn totp_generate(secret: String, options: Option<OtpOptions>) -> napi::Result<String> {
+        let params = OtpParams::from_options(options)?;
+        let secret = to_napi_result!(libsilver::core::Otp::base32_decode(&secret))?;
+        to_napi_result!(libsilver::core::Otp::totp(&secret, params.timestamp, &params.config))
+    }
+
+    /// Check a TOTP code, accepting `options.window` time steps of drift either way (default 1)
+    #[napi]
+    pub fn totp_verify(code: String, secret: String, options: Option<OtpOptions>) -> napi::Result<bool> {
+        let params = OtpParams::from_options(options)?;
+        let secret = to_napi_result!(libsilver::core::Otp::base32_decode(&secret))?;
+        to_napi_result!(libsilver::core::Otp::verify_totp(&secret, &code, params.timestamp, params.window, &params.config))
+    }
+
+    /// Build the otpauth:// URI that authenticator apps import from a QR code
+    #[napi]
+    pub fn totp_uri(secret: String, issuer: String, account: String, options: Option<OtpOptions>) -> napi::Result<String> {
+        let params = OtpParams::from_options(options)?;
+        let secret = to_napi_result!(libsilver::core::Otp::base32_decode(&secret))?;
+        Ok(libsilver::core::Otp::provisioning_uri(&secret, &issuer, &account, &params.config))
     }
 }
 
+/// Key material kept in Rust memory and zeroized on destroy() or garbage collection
+#[napi]
+pub struct SecureKeyHandle {
+    key: Option<SecureKey>,
+}
+
+#[napi]
+impl SecureKeyHandle {
+    /// Generate a random key of `length` bytes (e.g. 32 for AES-256 or ChaCha20)
+    #[napi(factory)]
+    pub fn generate(length: u32) -> napi::Result<Self> {
+        let key = to_napi_result!(SecureRandom::generate_key(length as usize))?;
+        Ok(Self { key: Some(key) })
+    }
+
+    /// Copy key bytes into a handle; wipe the source with `buffer.fill(0)` afterwards
+    #[napi(factory)]
+    pub fn from_buffer(key: Buffer) -> Self {
+        Self { key: Some(SecureKey::new(key.to_vec())) }
+    }
+
+    /// Derive a key with Argon2 without the key ever reaching the JavaScript heap
+    #[napi(factory)]
+    pub fn derive_argon2(password: Buffer, salt: Buffer, length: u32, options: Option<Argon2Options>) -> napi::Result<Self> {
+        let params = Argon2Params::from_options(options)?;
+        let key = to_napi_result!(params.derive_key(&password, &salt, length as usize))?;
+        Ok(Self { key: Some(SecureKey::new(key)) })
+    }
+
+    /// Key length in bytes
+    #[napi(getter)]
+    pub fn length(&self) -> napi::Result<u32> {
+        Ok(self.bytes()?.len() as u32)
+    }
+
+    /// Whether destroy() has been called
+    #[napi(getter)]
+    pub fn destroyed(&self) -> bool {
+        self.key.is_none()
+    }
+
+    /// Zeroize the key immediately; the handle cannot be used afterwards
+    #[napi]
+    pub fn destroy(&mut self) {
+        self.key = None;
+    }
+}
+
+impl SecureKeyHandle {
+    fn bytes(&self) -> napi::Result<&[u8]> {
+        self.key.as_ref()
+            .map(SecureKey::as_bytes)
+            .ok_or_else(|| napi::Error::from_reason("Key handle has been destroyed"))
+    }
+}
+
+/// Import a PEM private key of the expected algorithm through its raw bytes
+fn private_key_from_pem<T>(pem: &str, algorithm: KeyAlgorithm, import: impl FnOnce(&[u8]) -> CryptoResult<T>) -> napi::Result<T> {
+    let key = to_napi_result!(AsymmetricKey::from_pem(pem))?;
+    if !key.is_private() || key.algorithm() != algorithm {
+        return Err(napi::Error::from_reason(format!("Expected a {:?} private key", algorithm)));
+    }
+    let raw = to_napi_result!(key.to_raw())?;
+    to_napi_result!(import(&raw))
+}
+
+/// Borrow the key pair held by a class instance, failing once it is destroyed
+fn live_keypair<T>(keypair: &Option<T>) -> napi::Result<&T> {
+    keypair.as_ref()
+        .ok_or_else(|| napi::Error::from_reason("Key pair has been destroyed"))
+}
+
+/// Ed25519 key pair whose private key never leaves native memory
+#[napi(js_name = "Ed25519KeyPair")]
+pub struct Ed25519KeyPairHandle {
+    keypair: Option<Ed25519KeyPair>,
+}
+
+#[napi]
+impl Ed25519KeyPairHandle {
+    /// Generate a new key pair
+    #[napi(factory)]
+    pub fn generate() -> napi::Result<Self> {
+        let keypair = to_napi_result!(Ed25519Crypto::generate_keypair())?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Import a PKCS#8 PEM private key
+    #[napi(factory)]
+    pub fn from_pem(pem: String) -> napi::Result<Self> {
+        let keypair = private_key_from_pem(&pem, KeyAlgorithm::Ed25519, Ed25519KeyPair::from_private_key_bytes)?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Import a 32-byte private key, such as `signingKeyBytes` from generateEd25519Keypair
+    #[napi(factory)]
+    pub fn from_private_key_bytes(bytes: Buffer) -> napi::Result<Self> {
+        let keypair = to_napi_result!(Ed25519KeyPair::from_private_key_bytes(&bytes))?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Sign a message
+    #[napi]
+    pub fn sign(&self, message: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let signature = to_napi_result!(Ed25519Crypto::sign(&message, live_keypair(&self.keypair)?.signing_key()))?;
+        encode_output(signature, encoding)
+    }
+
+    /// Verify a signature made by this key pair
+    #[napi]
+    pub fn verify(&self, message: Buffer, signature: Buffer) -> napi::Result<bool> {
+        to_napi_result!(Ed25519Crypto::verify(&message, &signature, live_keypair(&self.keypair)?.verifying_key()))
+    }
+
+    /// Raw 32-byte public key
+    #[napi]
+    pub fn public_key(&self) -> napi::Result<Buffer> {
+        Ok(Buffer::from(live_keypair(&self.keypair)?.public_key_bytes()))
+    }
+
+    /// Public key as SPKI PEM
+    #[napi]
+    pub fn to_pem(&self) -> napi::Result<String> {
+        key_to_pem(&AsymmetricKey::from(live_keypair(&self.keypair)?).public_key())
+    }
+
+    /// Export the private key as PKCS#8 PEM, copying it onto the JavaScript heap
+    #[napi]
+    pub fn export_private_key_pem(&self) -> napi::Result<String> {
+        key_to_pem(&AsymmetricKey::from(live_keypair(&self.keypair)?))
+    }
+
+    /// Whether destroy() has been called
+    #[napi(getter)]
+    pub fn destroyed(&self) -> bool {
+        self.keypair.is_none()
+    }
+
+    /// Zeroize the private key immediately; the key pair cannot be used afterwards
+    #[napi]
+    pub fn destroy(&mut self) {
+        self.keypair = None;
+    }
+}
+
+/// ECDSA P-256 key pair whose private key never leaves native memory
+#[napi(js_name = "EcdsaKeyPair")]
+pub struct EcdsaKeyPairHandle {
+    keypair: Option<EcdsaKeyPair>,
+}
+
+#[napi]
+impl EcdsaKeyPairHandle {
+    /// Generate a new key pair
+    #[napi(factory)]
+    pub fn generate() -> napi::Result<Self> {
+        let keypair = to_napi_result!(EcdsaCrypto::generate_keypair())?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Import a PKCS#8 or SEC1 PEM private key
+    #[napi(factory)]
+    pub fn from_pem(pem: String) -> napi::Result<Self> {
+        let keypair = private_key_from_pem(&pem, KeyAlgorithm::EcdsaP256, EcdsaKeyPair::from_private_key_bytes)?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Import a 32-byte private scalar, such as `signingKeyBytes` from generateEcdsaKeypair
+    #[napi(factory)]
+    pub fn from_private_key_bytes(bytes: Buffer) -> napi::Result<Self> {
+        let keypair = to_napi_result!(EcdsaKeyPair::from_private_key_bytes(&bytes))?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Sign a message
+    #[napi]
+    pub fn sign(&self, message: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let signature = to_napi_result!(EcdsaCrypto::sign(&message, live_keypair(&self.keypair)?.signing_key()))?;
+        encode_output(signature, encoding)
+    }
+
+    /// Verify a signature made by this key pair
+    #[napi]
+    pub fn verify(&self, message: Buffer, signature: Buffer) -> napi::Result<bool> {
+        to_napi_result!(EcdsaCrypto::verify(&message, &signature, live_keypair(&self.keypair)?.verifying_key()))
+    }
+
+    /// Public key as an uncompressed SEC1 point
+    #[napi]
+    pub fn public_key(&self) -> napi::Result<Buffer> {
+        Ok(Buffer::from(live_keypair(&self.keypair)?.public_key_bytes()))
+    }
+
+    /// Public key as SPKI PEM
+    #[napi]
+    pub fn to_pem(&self) -> napi::Result<String> {
+        key_to_pem(&AsymmetricKey::from(live_keypair(&self.keypair)?).public_key())
+    }
+
+    /// Export the private key as PKCS#8 PEM, copying it onto the JavaScript heap
+    #[napi]
+    pub fn export_private_key_pem(&self) -> napi::Result<String> {
+        key_to_pem(&AsymmetricKey::from(live_keypair(&self.keypair)?))
+    }
+
+    /// Whether destroy() has been called
+    #[napi(getter)]
+    pub fn destroyed(&self) -> bool {
+        self.keypair.is_none()
+    }
+
+    /// Zeroize the private key immediately; the key pair cannot be used afterwards
+    #[napi]
+    pub fn destroy(&mut self) {
+        self.keypair = None;
+    }
+}
+
+/// RSA key pair whose private key never leaves native memory
+#[napi(js_name = "RsaKeyPair")]
+pub struct RsaKeyPairHandle {
+    keypair: Option<RsaKeyPair>,
+}
+
+impl From<RsaKeyPair> for RsaKeyPairHandle {
+    fn from(keypair: RsaKeyPair) -> Self {
+        Self { keypair: Some(keypair) }
+    }
+}
+
+#[napi]
+impl RsaKeyPairHandle {
+    /// Generate a new key pair (2048 bits unless overridden)
+    #[napi(factory)]
+    pub fn generate(bits: Option<u32>) -> napi::Result<Self> {
+        let keypair = to_napi_result!(RsaCrypto::generate_keypair_with_size(bits.unwrap_or(2048) as usize))?;
+        Ok(Self::from(keypair))
+    }
+
+    /// Generate a new key pair on the thread pool, optionally reporting progress
+    #[napi(ts_args_type = "bits?: number, onProgress?: (progress: number) => void", ts_return_type = "Promise<RsaKeyPair>")]
+    pub fn generate_async(bits: Option<u32>, on_progress: Option<ProgressCallback>) -> AsyncTask<RsaKeypairTask<RsaKeyPairHandle>> {
+        RsaKeypairTask::spawn(bits.unwrap_or(2048) as usize, on_progress)
+    }
+
+    /// Import a PKCS#8 or PKCS#1 PEM private key
+    #[napi(factory)]
+    pub fn from_pem(pem: String) -> napi::Result<Self> {
+        let keypair = to_napi_result!(RsaKeyPair::from_private_key_pem(&pem))?;
+        Ok(Self::from(keypair))
+    }
+
+    /// Sign a message (PSS with SHA-256 unless overridden)
+    #[napi]
+    pub fn sign(&self, message: Buffer, options: Option<RsaSignOptions>, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let (padding, hash) = rsa_sign_options(options)?;
+        let signature = to_napi_result!(RsaCrypto::sign(&message, live_keypair(&self.keypair)?.private_key(), padding, hash))?;
+        encode_output(signature, encoding)
+    }
+
+    /// Verify a signature made by this key pair (PSS with SHA-256 unless overridden)
+    #[napi]
+    pub fn verify(&self, message: Buffer, signature: Buffer, options: Option<RsaSignOptions>) -> napi::Result<bool> {
+        let (padding, hash) = rsa_sign_options(options)?;
+        to_napi_result!(RsaCrypto::verify(&message, &signature, live_keypair(&self.keypair)?.public_key(), padding, hash))
+    }
+
+    /// Encrypt to this key pair using RSA-OAEP
+    #[napi]
+    pub fn encrypt(&self, plaintext: Buffer, encoding: Option<String>) -> napi::Result<Either<Buffer, String>> {
+        let ciphertext = to_napi_result!(RsaCrypto::encrypt(&plaintext, live_keypair(&self.keypair)?.public_key()))?;
+        encode_output(ciphertext, encoding)
+    }
+
+    /// Decrypt RSA-OAEP ciphertext
+    #[napi]
+    pub fn decrypt(&self, ciphertext: Buffer) -> napi::Result<Buffer> {
+        let plaintext = to_napi_result!(RsaCrypto::decrypt(&ciphertext, live_keypair(&self.keypair)?.private_key()))?;
+        Ok(Buffer::from(plaintext))
+    }
+
+    /// Public key as SPKI PEM
+    #[napi]
+    pub fn to_pem(&self) -> napi::Result<String> {
+        to_napi_result!(live_keypair(&self.keypair)?.public_key_pem())
+    }
+
+    /// Export the private key as PKCS#8 PEM, copying it onto the JavaScript heap
+    #[napi]
+    pub fn export_private_key_pem(&self) -> napi::Result<String> {
+        let pem = to_napi_result!(live_keypair(&self.keypair)?.private_key_pem())?;
+        Ok(pem.to_string())
+    }
+
+    /// Whether destroy() has been called
+    #[napi(getter)]
+    pub fn destroyed(&self) -> bool {
+        self.keypair.is_none()
+    }
+
+    /// Zeroize the private key immediately; the key pair cannot be used afterwards
+    #[napi]
+    pub fn destroy(&mut self) {
+        self.keypair = None;
+    }
+}
+
+/// X25519 or P-256 key agreement key pair whose private key never leaves native memory
+#[napi(js_name = "EcdhKeyPair")]
+pub struct EcdhKeyPairHandle {
+    keypair: Option<EcdhKeyPair>,
+}
+
+#[napi]
+impl EcdhKeyPairHandle {
+    /// Generate a new key pair ("x25519" by default, or "p256")
+    #[napi(factory)]
+    pub fn generate(curve: Option<String>) -> napi::Result<Self> {
+        let keypair = to_napi_result!(Ecdh::generate_keypair(ecdh_curve(curve)?))?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Import a 32-byte private key ("x25519" by default, or "p256")
+    #[napi(factory)]
+    pub fn from_private_key_bytes(bytes: Buffer, curve: Option<String>) -> napi::Result<Self> {
+        let keypair = to_napi_result!(EcdhKeyPair::from_private_key_bytes(ecdh_curve(curve)?, &bytes))?;
+        Ok(Self { keypair: Some(keypair) })
+    }
+
+    /// Curve name, "x25519" or "p256"
+    #[napi(getter)]
+    pub fn curve(&self) -> napi::Result<String> {
+        let name = match live_keypair(&self.keypair)?.curve() {
+            EcdhCurve::X25519 => "x25519",
+            EcdhCurve::P256 => "p256",
+        };
+        Ok(name.to_string())
+    }
+
+    /// Public key bytes (uncompressed SEC1 for P-256)
+    #[napi]
+    pub fn public_key(&self) -> napi::Result<Buffer> {
+        Ok(Buffer::from(live_keypair(&self.keypair)?.public_key_bytes()))
+    }
+
+    /// Compute the raw ECDH shared secret with a peer public key
+    #[napi]
+    pub fn derive_shared_secret(&self, peer_public_key: Buffer) -> napi::Result<Buffer> {
+        let shared = to_napi_result!(Ecdh::derive_shared_secret(live_keypair(&self.keypair)?, &peer_public_key))?;
+        Ok(Buffer::from(shared.as_slice()))
+    }
+
+    /// Decrypt an ECIES message addressed to this key pair
+    #[napi]
+    pub fn decrypt(&self, ciphertext: Buffer) -> napi::Result<Buffer> {
+        let plaintext = to_napi_result!(Ecies::decrypt(&ciphertext, live_keypair(&self.keypair)?))?;
+        Ok(Buffer::from(plaintext))
+    }
+
+    /// Whether destroy() has been called
+    #[napi(getter)]
+    pub fn destroyed(&self) -> bool {
+        self.keypair.is_none()
+    }
+
+    /// Zeroize the private key immediately; the key pair cannot be used afterwards
+    #[napi]
+    pub fn destroy(&mut self) {
+        self.keypair = None;
+    }
+}
+
+/// Argon2 cost options for JavaScript
+#[napi(object)]
+pub struct Argon2Options {
+    /// Memory cost in KiB (default 19456)
+    pub memory_cost: Option<u32>,
+    /// Number of iterations (default 2)
+    pub time_cost: Option<u32>,
+    /// Degree of parallelism (default 1)
+    pub parallelism: Option<u32>,
+    /// "argon2id" (default), "argon2i" or "argon2d"
+    pub variant: Option<String>,
+}
+
+/// Argon2 options with defaults applied
+struct Argon2Params {
+    variant: Argon2Variant,
+    memory_kib: u32,
+    iterations: u32,
+    parallelism: u32,
+}
+
+impl Argon2Params {
+    fn from_options(options: Option<Argon2Options>) -> napi::Result<Self> {
+        let options = options.unwrap_or(Argon2Options { memory_cost: None, time_cost: None, parallelism: None, variant: None });
+        let variant = match options.variant.as_deref() {
+            None | Some("argon2id") => Argon2Variant::Argon2id,
+            Some("argon2i") => Argon2Variant::Argon2i,
+            Some("argon2d") => Argon2Variant::Argon2d,
+            Some(other) => return Err(napi::Error::from_reason(format!("Unsupported Argon2 variant: {}", other))),
+        };
+        Ok(Self {
+            variant,
+            memory_kib: options.memory_cost.unwrap_or(Argon2Kdf::DEFAULT_MEMORY_KIB),
+            iterations: options.time_cost.unwrap_or(Argon2Kdf::DEFAULT_ITERATIONS),
+            parallelism: options.parallelism.unwrap_or(Argon2Kdf::DEFAULT_PARALLELISM),
+        })
+    }
+
+    fn derive_key(&self, password: &[u8], salt: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Argon2Kdf::derive_key_with_params(password, salt, self.variant, self.memory_kib, self.iterations, self.parallelism, length)
+    }
+}
+
+/// JWT signing options for JavaScript
+#[napi(object)]
+pub struct JwtSignOptions {
+    /// Key ID written to the `kid` header
+    pub key_id: Option<String>,
+}
+
+/// JWT claim validation options for JavaScript
+#[napi(object)]
+pub struct JwtVerifyOptions {
+    /// Accepted `aud` values
+    pub audience: Option<Vec<String>>,
+    /// Required `iss` value
+    pub issuer: Option<String>,
+    /// Allowed clock skew in seconds (default 0)
+    pub leeway: Option<u32>,
+    /// Require an `exp` claim (default true)
+    pub require_exp: Option<bool>,
+}
+
+/// PASETO options for JavaScript
+#[napi(object)]
+pub struct PasetoOptions {
+    /// Footer stored in the token; must match on decrypt/verify
+    pub footer: Option<String>,
+    /// Implicit assertion bound to the token but not stored in it
+    pub implicit_assertion: Option<String>,
+    /// Allowed clock skew in seconds for `exp`/`nbf` (default 0)
+    pub leeway: Option<u32>,
+}
+
+/// PASETO options with defaults applied
+struct PasetoParams {
+    footer: String,
+    implicit: String,
+    leeway: u64,
+}
+
+impl PasetoParams {
+    fn from_options(options: Option<PasetoOptions>) -> Self {
+        let options = options.unwrap_or(PasetoOptions { footer: None, implicit_assertion: None, leeway: None });
+        Self {
+            footer: options.footer.unwrap_or_default(),
+            implicit: options.implicit_assertion.unwrap_or_default(),
+            leeway: options.leeway.unwrap_or(0) as u64,
+        }
+    }
+
+    /// Check the time claims of a decrypted or verified payload and return it as a string
+    fn validate(&self, payload: Vec<u8>) -> napi::Result<String> {
+        let payload = String::from_utf8(payload).map_err(|_| napi::Error::from_reason("Expected a JSON object"))?;
+        let claims = json_object(&payload)?;
+        to_napi_result!(Paseto::validate_claims(&claims, self.leeway))?;
+        Ok(payload)
+    }
+}
+
+/// One-time password options for JavaScript
+#[napi(object)]
+pub struct OtpOptions {
+    /// "sha1" (default), "sha256" or "sha512"
+    pub algorithm: Option<String>,
+    /// Number of digits, 6 to 8 (default 6)
+    pub digits: Option<u32>,
+    /// TOTP time step in seconds (default 30)
+    pub period: Option<u32>,
+    /// Unix time in seconds for TOTP (default now)
+    pub timestamp: Option<i64>,
+    /// Time steps of clock drift accepted either way by totpVerify (default 1)
+    pub window: Option<u32>,
+}
+
+/// One-time password options with defaults applied
+struct OtpParams {
+    config: OtpConfig,
+    timestamp: u64,
+    window: u64,
+}
+
+impl OtpParams {
+    fn from_options(options: Option<OtpOptions>) -> napi::Result<Self> {
+        let options = options.unwrap_or(OtpOptions { algorithm: None, digits: None, period: None, timestamp: None, window: None });
+        let algorithm = match options.algorithm.as_deref() {
+            None | Some("sha1") => OtpAlgorithm::Sha1,
+            Some("sha256") => OtpAlgorithm::Sha256,
+            Some("sha512") => OtpAlgorithm::Sha512,
+            Some(other) => return Err(napi::Error::from_reason(format!("Unsupported OTP algorithm: {}", other))),
+        };
+        let defaults = OtpConfig::default();
+        let timestamp = match options.timestamp {
+            Some(timestamp) => u64::try_from(timestamp).map_err(|_| napi::Error::from_reason("OTP timestamp must not be negative"))?,
+            None => std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+        };
+        Ok(Self {
+            config: OtpConfig {
+                algorithm,
+                digits: options.digits.unwrap_or(defaults.digits),
+                period: options.period.map_or(defaults.period, u64::from),
+            },
+            timestamp,
+            window: options.window.unwrap_or(1) as u64,
+        })
+    }
+}
+
+/// scrypt cost options for JavaScript
+#[napi(object)]
+pub struct ScryptOptions {
+    /// CPU/memory cost as log2(N) (default 17)
+    pub log_n: Option<u32>,
+    /// Block size (default 8)
+    pub r: Option<u32>,
+    /// Parallelism (default 1)
+    pub p: Option<u32>,
+}
+
+/// scrypt options with defaults applied
+struct ScryptParams {
+    log_n: u8,
+    r: u32,
+    p: u32,
+}
+
+impl ScryptParams {
+    fn from_options(options: Option<ScryptOptions>) -> napi::Result<Self> {
+        let options = options.unwrap_or(ScryptOptions { log_n: None, r: None, p: None });
+        let log_n = match options.log_n {
+            Some(log_n) => u8::try_from(log_n).map_err(|_| napi::Error::from_reason("scrypt logN is out of range"))?,
+            None => ScryptKdf::DEFAULT_LOG_N,
+        };
+        Ok(Self {
+            log_n,
+            r: options.r.unwrap_or(ScryptKdf::DEFAULT_R),
+            p: options.p.unwrap_or(ScryptKdf::DEFAULT_P),
+        })
+    }
+}
+
+/// RSA signature options for JavaScript
+#[napi(object)]
+pub struct RsaSignOptions {
+    /// "pss" (default) or "pkcs1"
+    pub padding: Option<String>,
+    /// "sha256" (default), "sha384" or "sha512"
+    pub hash: Option<String>,
+}
+
+/// Resolve RSA signature options, applying the PSS/SHA-256 defaults
+fn rsa_sign_options(options: Option<RsaSignOptions>) -> napi::Result<(RsaSignaturePadding, RsaSignatureHash)> {
+    let options = options.unwrap_or(RsaSignOptions { padding: None, hash: None });
+    let padding = match options.padding.as_deref() {
+        None | Some("pss") => RsaSignaturePadding::Pss,
+        Some("pkcs1") => RsaSignaturePadding::Pkcs1v15,
+        Some(other) => return Err(napi::Error::from_reason(format!("Unsupported RSA padding: {}", other))),
+    };
+    let hash = match options.hash.as_deref() {
+        None | Some("sha256") => RsaSignatureHash::Sha256,
+        Some("sha384") => RsaSignatureHash::Sha384,
+        Some("sha512") => RsaSignatureHash::Sha512,
+        Some(other) => return Err(napi::Error::from_reason(format!("Unsupported RSA signature hash: {}", other))),
+    };
+    Ok((padding, hash))
+}
+
 /// Ed25519 Key Pair for JavaScript
 #[napi(object)]
 pub struct Ed25519KeyPairJs {
@@ -338,12 +2078,28 @@ pub struct Ed25519KeyPairJs {
 impl From<Ed25519KeyPair> for Ed25519KeyPairJs {
     fn from(keypair: Ed25519KeyPair) -> Self {
         Self {
-            signing_key_bytes: Buffer::from(keypair.private_key_bytes()),
+            signing_key_bytes: Buffer::from(keypair.private_key_bytes().as_slice()),
             verifying_key_bytes: Buffer::from(keypair.public_key_bytes()),
         }
     }
 }
 
+/// ECDH Key Pair for JavaScript
+#[napi(object)]
+pub struct EcdhKeyPairJs {
+    pub private_key_bytes: Buffer,
+    pub public_key_bytes: Buffer,
+}
+
+impl From<EcdhKeyPair> for EcdhKeyPairJs {
+    fn from(keypair: EcdhKeyPair) -> Self {
+        Self {
+            private_key_bytes: Buffer::from(keypair.private_key_bytes().as_slice()),
+            public_key_bytes: Buffer::from(keypair.public_key_bytes()),
+        }
+    }
+}
+
 /// ECDSA Key Pair for JavaScript
 #[napi(object)]
 pub struct EcdsaKeyPairJs {
@@ -354,7 +2110,7 @@ pub struct EcdsaKeyPairJs {
 impl From<EcdsaKeyPair> for EcdsaKeyPairJs {
     fn from(keypair: EcdsaKeyPair) -> Self {
         Self {
-            signing_key_bytes: Buffer::from(keypair.private_key_bytes()),
+            signing_key_bytes: Buffer::from(keypair.private_key_bytes().as_slice()),
             verifying_key_bytes: Buffer::from(keypair.public_key_bytes()),
         }
     }
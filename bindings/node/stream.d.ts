@@ -0,0 +1,9 @@
+/* tslint:disable */
+/* eslint-disable */
+
+import { Transform, TransformOptions } from 'stream'
+
+/** Create a Transform stream that encrypts with AES-256-GCM under a 32-byte key */
+export declare function createEncryptStream(key: Buffer, options?: TransformOptions): Transform
+/** Create a Transform stream that decrypts the output of createEncryptStream */
+export declare function createDecryptStream(key: Buffer, options?: TransformOptions): Transform
@@ -0,0 +1,38 @@
+const { Transform } = require('stream')
+const { AesGcmEncryptStream, AesGcmDecryptStream } = require('./index.js')
+
+// Wrap a native update()/finish() object as a Node.js Transform stream
+function wrapNative(native, options) {
+  return new Transform({
+    ...options,
+    transform(chunk, encoding, callback) {
+      try {
+        const output = native.update(Buffer.isBuffer(chunk) ? chunk : Buffer.from(chunk, encoding))
+        callback(null, output.length > 0 ? output : undefined)
+      } catch (error) {
+        callback(error)
+      }
+    },
+    flush(callback) {
+      try {
+        const output = native.finish()
+        callback(null, output.length > 0 ? output : undefined)
+      } catch (error) {
+        callback(error)
+      }
+    },
+  })
+}
+
+/** Create a Transform stream that encrypts with AES-256-GCM under a 32-byte key */
+function createEncryptStream(key, options) {
+  return wrapNative(new AesGcmEncryptStream(key), options)
+}
+
+/** Create a Transform stream that decrypts the output of createEncryptStream */
+function createDecryptStream(key, options) {
+  return wrapNative(new AesGcmDecryptStream(key), options)
+}
+
+module.exports.createEncryptStream = createEncryptStream
+module.exports.createDecryptStream = createDecryptStream
@@ -1,4 +1,11 @@
-const { SymmetricCrypto, AsymmetricCrypto, HashFunctions, KeyDerivation, RandomGenerator } = require('../index.js');
+const {
+  SymmetricCrypto, AesGcmEncryptStream, AesGcmDecryptStream, AsymmetricCrypto, HashFunctions, KeyDerivation, RandomGenerator,
+  Sha256Stream, Sha512Stream, Blake3Stream, HmacSha256Stream, HmacSha512Stream, SecureKeyHandle, KeyFormats, Tokens, Otp,
+  FileCrypto, Ed25519KeyPair, EcdsaKeyPair, RsaKeyPair, EcdhKeyPair, Api,
+} = require('../index.js');
+const { createEncryptStream, createDecryptStream } = require('../stream.js');
+const { Readable } = require('stream');
+const { pipeline } = require('stream/promises');
 
 function assert(condition, message) {
   if (!condition) {
@@ -29,6 +36,172 @@ function testSymmetricCrypto() {
   
   assert(plaintext.equals(decrypted2), 'ChaCha20 decryption should match original plaintext');
   console.log('✓ ChaCha20-Poly1305 encryption/decryption works');
+  
+  // Test associated data
+  const aad = Buffer.from('record-id:42', 'utf8');
+  const cases = [
+    ['AES-256-GCM', aesKey, SymmetricCrypto.encryptAesWithAad, SymmetricCrypto.decryptAesWithAad, SymmetricCrypto.decryptAes],
+    ['ChaCha20-Poly1305', chachaKey, SymmetricCrypto.encryptChacha20WithAad, SymmetricCrypto.decryptChacha20WithAad, SymmetricCrypto.decryptChacha20],
+  ];
+  for (const [name, key, encrypt, decrypt, decryptPlain] of cases) {
+    const sealed = encrypt(plaintext, key, aad);
+    assert(plaintext.equals(decrypt(sealed, key, aad)), `${name} AAD decryption should match original plaintext`);
+    
+    let threw = false;
+    try {
+      decrypt(sealed, key, Buffer.from('record-id:43', 'utf8'));
+    } catch (error) {
+      threw = true;
+    }
+    assert(threw, `${name} decryption with the wrong AAD should fail`);
+    
+    // Omitting the AAD matches the plain API
+    assert(plaintext.equals(decryptPlain(encrypt(plaintext, key), key)), `${name} without AAD should match the plain API`);
+    console.log(`✓ ${name} with AAD works`);
+  }
+  
+  // Test encrypting/decrypting into caller-provided buffers
+  const large = require('crypto').randomBytes(10 * 1024 * 1024);
+  const intoCases = [
+    ['AES-256-GCM', aesKey, SymmetricCrypto.encryptAesInto, SymmetricCrypto.decryptAesInto, SymmetricCrypto.decryptAesWithAad],
+    ['ChaCha20-Poly1305', chachaKey, SymmetricCrypto.encryptChacha20Into, SymmetricCrypto.decryptChacha20Into, SymmetricCrypto.decryptChacha20WithAad],
+  ];
+  for (const [name, key, encryptInto, decryptInto, decrypt] of intoCases) {
+    const sealed = Buffer.alloc(SymmetricCrypto.ciphertextLength(large.length));
+    assert(encryptInto(large, key, sealed, aad) === sealed.length, `${name} should fill the output buffer`);
+    assert(decrypt(sealed, key, aad).equals(large), `${name} output should decrypt with the regular API`);
+    
+    const opened = Buffer.alloc(large.length + 8);
+    const written = decryptInto(sealed, key, opened, aad);
+    assert(written === large.length && opened.subarray(0, written).equals(large), `${name} should decrypt into the output buffer`);
+    
+    let threw = false;
+    try {
+      encryptInto(large, key, Buffer.alloc(large.length));
+    } catch (error) {
+      threw = true;
+    }
+    assert(threw, `${name} should reject an output buffer that is too small`);
+    console.log(`✓ ${name} into caller buffers works`);
+  }
+}
+
+async function testStreamingEncryption() {
+  console.log('Testing Streaming Encryption...');
+  
+  const key = SymmetricCrypto.generateAesKey();
+  const plaintext = RandomGenerator.generateBytes(200 * 1024 + 123);
+  
+  const encryptor = new AesGcmEncryptStream(key);
+  const parts = [];
+  for (let offset = 0; offset < plaintext.length; offset += 10000) {
+    parts.push(encryptor.update(plaintext.subarray(offset, offset + 10000)));
+  }
+  parts.push(encryptor.finish());
+  const ciphertext = Buffer.concat(parts);
+  
+  const decryptor = new AesGcmDecryptStream(key);
+  const decrypted = Buffer.concat([decryptor.update(ciphertext), decryptor.finish()]);
+  assert(plaintext.equals(decrypted), 'Streamed decryption should match original plaintext');
+  console.log('✓ AES-GCM encrypt/decrypt stream classes work');
+  
+  let threw = false;
+  try {
+    const truncated = new AesGcmDecryptStream(key);
+    truncated.update(ciphertext.subarray(0, ciphertext.length - 1));
+    truncated.finish();
+  } catch (error) {
+    threw = true;
+  }
+  assert(threw, 'Truncated stream should fail to decrypt');
+  console.log('✓ Truncated streams are rejected');
+  
+  const collected = [];
+  await pipeline(
+    Readable.from([plaintext.subarray(0, 70000), plaintext.subarray(70000)]),
+    createEncryptStream(key),
+    createDecryptStream(key),
+    async function (source) {
+      for await (const chunk of source) {
+        collected.push(chunk);
+      }
+    }
+  );
+  assert(plaintext.equals(Buffer.concat(collected)), 'Transform stream pipeline should round-trip');
+  console.log('✓ Transform stream pipeline works');
+  
+  let rejected = false;
+  try {
+    const tampered = Buffer.from(ciphertext);
+    tampered[20] ^= 1;
+    await pipeline(Readable.from([tampered]), createDecryptStream(key), async function (source) {
+      for await (const chunk of source) {
+        collected.push(chunk);
+      }
+    });
+  } catch (error) {
+    rejected = true;
+  }
+  assert(rejected, 'Tampered ciphertext should error the pipeline');
+  console.log('✓ Tampered streams error the pipeline');
+}
+
+async function testFileEncryption() {
+  console.log('Testing File Encryption...');
+  
+  const fs = require('fs');
+  const os = require('os');
+  const path = require('path');
+  const dir = fs.mkdtempSync(path.join(os.tmpdir(), 'libsilver-'));
+  const key = SymmetricCrypto.generateAesKey();
+  const plaintext = RandomGenerator.generateBytes(300 * 1024 + 7);
+  const source = path.join(dir, 'document.pdf');
+  const encrypted = path.join(dir, 'document.pdf.enc');
+  const decrypted = path.join(dir, 'document.out.pdf');
+  fs.writeFileSync(source, plaintext);
+  
+  try {
+    const progress = [];
+    const written = await FileCrypto.encryptFile(source, encrypted, key, (fraction) => progress.push(fraction));
+    assert(written === fs.statSync(encrypted).size, 'encryptFile should resolve to the bytes written');
+    await new Promise((resolve) => setImmediate(resolve));
+    assert(progress.length > 0 && progress[progress.length - 1] === 1, 'encryptFile should report progress up to 1');
+    console.log('✓ encryptFile works');
+    
+    await FileCrypto.decryptFile(encrypted, decrypted, key);
+    assert(plaintext.equals(fs.readFileSync(decrypted)), 'decryptFile should restore the original file');
+    console.log('✓ decryptFile works');
+    
+    const decryptor = new AesGcmDecryptStream(key);
+    const ciphertext = fs.readFileSync(encrypted);
+    const streamed = Buffer.concat([decryptor.update(ciphertext), decryptor.finish()]);
+    assert(plaintext.equals(streamed), 'Encrypted files should use the stream format');
+    console.log('✓ Encrypted files interoperate with AesGcmDecryptStream');
+    
+    ciphertext[ciphertext.length - 100] ^= 1;
+    fs.writeFileSync(encrypted, ciphertext);
+    const tamperedOut = path.join(dir, 'tampered.pdf');
+    let rejected = false;
+    try {
+      await FileCrypto.decryptFile(encrypted, tamperedOut, key);
+    } catch (error) {
+      rejected = true;
+    }
+    assert(rejected, 'Tampered files should fail to decrypt');
+    assert(fs.readdirSync(dir).every((name) => !name.startsWith('tampered')), 'Failed decryption should leave no output behind');
+    console.log('✓ Tampered files are rejected without partial output');
+    
+    rejected = false;
+    try {
+      await FileCrypto.encryptFile(path.join(dir, 'missing'), encrypted, key);
+    } catch (error) {
+      rejected = true;
+    }
+    assert(rejected, 'Missing input files should reject');
+    console.log('✓ Missing input files are rejected');
+  } finally {
+    fs.rmSync(dir, { recursive: true, force: true });
+  }
 }
 
 function testAsymmetricCrypto() {
@@ -46,6 +219,24 @@ function testAsymmetricCrypto() {
   assert(message.equals(rsaDecrypted), 'RSA decryption should match original message');
   console.log('✓ RSA-OAEP encryption/decryption works');
   
+  // Test RSA signatures, cross-checked against node:crypto
+  const crypto = require('crypto');
+  const pssSignature = AsymmetricCrypto.signRsa(message, rsaKeypair.privateKeyPem);
+  assert(AsymmetricCrypto.verifyRsa(message, pssSignature, rsaKeypair.publicKeyPem), 'RSA-PSS signature should verify');
+  assert(!AsymmetricCrypto.verifyRsa(Buffer.from('tampered'), pssSignature, rsaKeypair.publicKeyPem), 'RSA-PSS should reject a different message');
+  assert(crypto.verify('sha256', message, {
+    key: rsaKeypair.publicKeyPem,
+    padding: crypto.constants.RSA_PKCS1_PSS_PADDING,
+    saltLength: crypto.constants.RSA_PSS_SALTLEN_DIGEST,
+  }, pssSignature), 'node:crypto should verify the RSA-PSS signature');
+  
+  const pkcs1Options = { padding: 'pkcs1', hash: 'sha512' };
+  const pkcs1Signature = AsymmetricCrypto.signRsa(message, rsaKeypair.privateKeyPem, pkcs1Options);
+  assert(pkcs1Signature.equals(crypto.sign('sha512', message, rsaKeypair.privateKeyPem)), 'PKCS#1 v1.5 signature should match node:crypto');
+  assert(AsymmetricCrypto.verifyRsa(message, pkcs1Signature, rsaKeypair.publicKeyPem, pkcs1Options), 'PKCS#1 v1.5 signature should verify');
+  assert(!AsymmetricCrypto.verifyRsa(message, pkcs1Signature, rsaKeypair.publicKeyPem), 'Signature should not verify under different options');
+  console.log('✓ RSA-PSS and PKCS#1 v1.5 signing/verification work');
+  
   // Test Ed25519
   const ed25519Keypair = AsymmetricCrypto.generateEd25519Keypair();
   assert(ed25519Keypair.signingKeyBytes.length === 32, 'Ed25519 signing key should be 32 bytes');
@@ -67,6 +258,47 @@ function testAsymmetricCrypto() {
   
   assert(ecdsaValid === true, 'ECDSA signature should be valid');
   console.log('✓ ECDSA P-256 signing/verification works');
+  
+  // Test X25519 key agreement against node:crypto
+  const alice = AsymmetricCrypto.generateX25519Keypair();
+  const bob = AsymmetricCrypto.generateX25519Keypair();
+  assert(alice.privateKeyBytes.length === 32 && alice.publicKeyBytes.length === 32, 'X25519 keys should be 32 bytes');
+  const x25519Shared = AsymmetricCrypto.deriveSharedSecret(alice.privateKeyBytes, bob.publicKeyBytes);
+  assert(x25519Shared.equals(AsymmetricCrypto.deriveSharedSecret(bob.privateKeyBytes, alice.publicKeyBytes, 'x25519')), 'X25519 shared secrets should match');
+  const x25519Jwk = (keypair) => ({
+    kty: 'OKP', crv: 'X25519',
+    x: keypair.publicKeyBytes.toString('base64url'),
+    d: keypair.privateKeyBytes.toString('base64url'),
+  });
+  const nodeShared = crypto.diffieHellman({
+    privateKey: crypto.createPrivateKey({ key: x25519Jwk(alice), format: 'jwk' }),
+    publicKey: crypto.createPublicKey({ key: x25519Jwk(bob), format: 'jwk' }),
+  });
+  assert(x25519Shared.equals(nodeShared), 'X25519 shared secret should match node:crypto');
+  console.log('✓ X25519 key agreement works');
+  
+  // Test P-256 key agreement against node:crypto
+  const p256Alice = AsymmetricCrypto.generateEcdhP256Keypair();
+  const p256Bob = crypto.createECDH('prime256v1');
+  p256Bob.generateKeys();
+  const p256Shared = AsymmetricCrypto.deriveSharedSecret(p256Alice.privateKeyBytes, p256Bob.getPublicKey(), 'p256');
+  assert(p256Shared.equals(p256Bob.computeSecret(p256Alice.publicKeyBytes)), 'P-256 shared secret should match node:crypto');
+  console.log('✓ P-256 key agreement works');
+  
+  // Test ECIES
+  for (const [curve, recipient] of [['x25519', bob], ['p256', p256Alice]]) {
+    const eciesCiphertext = AsymmetricCrypto.eciesEncrypt(message, recipient.publicKeyBytes, curve);
+    assert(AsymmetricCrypto.eciesDecrypt(eciesCiphertext, recipient.privateKeyBytes, curve).equals(message), `ECIES (${curve}) should round-trip`);
+    eciesCiphertext[eciesCiphertext.length - 1] ^= 1;
+    let threw = false;
+    try {
+      AsymmetricCrypto.eciesDecrypt(eciesCiphertext, recipient.privateKeyBytes, curve);
+    } catch (error) {
+      threw = true;
+    }
+    assert(threw, `ECIES (${curve}) should reject tampered ciphertext`);
+  }
+  console.log('✓ ECIES encryption/decryption works');
 }
 
 function testHashFunctions() {
@@ -95,6 +327,28 @@ function testHashFunctions() {
   assert(blake3Custom.length === 64, 'BLAKE3 custom length should work');
   console.log('✓ BLAKE3 hashing works');
   
+  // Test SHA-3 against node:crypto
+  const crypto = require('crypto');
+  assert(HashFunctions.sha3_256(data).equals(crypto.createHash('sha3-256').update(data).digest()), 'SHA3-256 should match node:crypto');
+  assert(HashFunctions.sha3_256Hex(data) === crypto.createHash('sha3-256').update(data).digest('hex'), 'SHA3-256 hex should match node:crypto');
+  assert(HashFunctions.sha3_512(data).equals(crypto.createHash('sha3-512').update(data).digest()), 'SHA3-512 should match node:crypto');
+  assert(HashFunctions.sha3_512Hex(data).length === 128, 'SHA3-512 hex should be 128 characters');
+  console.log('✓ SHA-3 hashing works');
+  
+  // Test keyed BLAKE3 and key derivation (official BLAKE3 test vectors, empty input)
+  const blake3Key = Buffer.from('whats the Elvish word for friend', 'utf8');
+  assert(HashFunctions.blake3Keyed(blake3Key, Buffer.alloc(0)).toString('hex') === '92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26', 'Keyed BLAKE3 should match the test vector');
+  const derived = HashFunctions.blake3DeriveKey('BLAKE3 2019-12-27 16:29:52 test vectors context', Buffer.alloc(0), 32);
+  assert(derived.toString('hex') === '2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d', 'BLAKE3 derive_key should match the test vector');
+  let threw = false;
+  try {
+    HashFunctions.blake3Keyed(Buffer.alloc(16), data);
+  } catch (error) {
+    threw = true;
+  }
+  assert(threw, 'Keyed BLAKE3 should reject keys that are not 32 bytes');
+  console.log('✓ Keyed BLAKE3 and BLAKE3 key derivation work');
+  
   // Test HMAC
   const key = Buffer.from('secret key', 'utf8');
   const hmacSha256 = HashFunctions.hmacSha256(key, data);
@@ -102,6 +356,102 @@ function testHashFunctions() {
   
   assert(hmacValid === true, 'HMAC-SHA256 verification should work');
   console.log('✓ HMAC-SHA256 works');
+  
+  // Test verification and hex helpers
+  assert(HashFunctions.verifySha256(data, sha256Hash), 'SHA-256 verification should accept the right hash');
+  assert(!HashFunctions.verifySha256(Buffer.from('other data'), sha256Hash), 'SHA-256 verification should reject other data');
+  assert(HashFunctions.verifySha512(data, sha512Hash), 'SHA-512 verification should accept the right hash');
+  assert(HashFunctions.hmacSha256Hex(key, data) === crypto.createHmac('sha256', key).update(data).digest('hex'), 'HMAC-SHA256 hex should match node:crypto');
+  assert(HashFunctions.hmacSha512Hex(key, data) === crypto.createHmac('sha512', key).update(data).digest('hex'), 'HMAC-SHA512 hex should match node:crypto');
+  console.log('✓ Hash verification and hex HMAC helpers work');
+  
+  assert(HashFunctions.timingSafeEqual(hmacSha256, Buffer.from(hmacSha256)), 'timingSafeEqual should accept equal buffers');
+  const flipped = Buffer.from(hmacSha256);
+  flipped[31] ^= 1;
+  assert(!HashFunctions.timingSafeEqual(hmacSha256, flipped), 'timingSafeEqual should reject different buffers');
+  assert(!HashFunctions.timingSafeEqual(hmacSha256, hmacSha256.subarray(0, 16)), 'timingSafeEqual should return false for different lengths');
+  console.log('✓ timingSafeEqual works');
+}
+
+function testOutputEncodings() {
+  console.log('Testing Output Encodings...');
+  
+  const crypto = require('crypto');
+  const data = Buffer.from('encode me', 'utf8');
+  const key = SymmetricCrypto.generateAesKey();
+  
+  const digest = HashFunctions.sha256(data);
+  assert(Buffer.isBuffer(HashFunctions.sha256(data, 'buffer')), "'buffer' should return a Buffer");
+  assert(HashFunctions.sha256(data, 'hex') === digest.toString('hex'), 'hex digest should match');
+  assert(HashFunctions.sha256(data, 'base64') === digest.toString('base64'), 'base64 digest should match');
+  assert(HashFunctions.sha256(data, 'base64url') === digest.toString('base64url'), 'base64url digest should match');
+  assert(HashFunctions.sha3_512(data, 'hex') === crypto.createHash('sha3-512').update(data).digest('hex'), 'SHA3-512 hex should match node:crypto');
+  assert(HashFunctions.hmacSha256(key, data, 'base64') === crypto.createHmac('sha256', key).update(data).digest('base64'), 'HMAC base64 should match node:crypto');
+  console.log('✓ Hash and HMAC encodings work');
+  
+  const ciphertext = SymmetricCrypto.encryptAes(data, key, 'base64');
+  assert(typeof ciphertext === 'string', 'Encrypted output should be a string');
+  assert(data.equals(SymmetricCrypto.decryptAes(Buffer.from(ciphertext, 'base64'), key)), 'base64 ciphertext should decrypt');
+  const chacha = SymmetricCrypto.encryptChacha20WithAad(data, key, null, 'hex');
+  assert(data.equals(SymmetricCrypto.decryptChacha20(Buffer.from(chacha, 'hex'), key)), 'hex ChaCha20 ciphertext should decrypt');
+  console.log('✓ Encryption encodings work');
+  
+  const ed25519 = AsymmetricCrypto.generateEd25519Keypair();
+  const signature = AsymmetricCrypto.signEd25519(data, ed25519.signingKeyBytes, 'base64url');
+  assert(AsymmetricCrypto.verifyEd25519(data, Buffer.from(signature, 'base64url'), ed25519.verifyingKeyBytes), 'base64url signature should verify');
+  const keypair = EcdsaKeyPair.generate();
+  assert(keypair.verify(data, Buffer.from(keypair.sign(data, 'hex'), 'hex')), 'Key pair hex signature should verify');
+  console.log('✓ Signature encodings work');
+  
+  let threw = false;
+  try {
+    HashFunctions.sha256(data, 'latin1');
+  } catch (error) {
+    threw = error.message.includes('Unsupported output encoding');
+  }
+  assert(threw, 'Unknown encodings should throw');
+  console.log('✓ Unknown encodings are rejected');
+}
+
+function testStreamingHashers() {
+  console.log('Testing Streaming Hashers...');
+  
+  const data = Buffer.from('Hello, streaming world! This arrives in chunks.', 'utf8');
+  const key = Buffer.from('secret key', 'utf8');
+  const chunks = [data.subarray(0, 7), data.subarray(7, 20), data.subarray(20)];
+  const cases = [
+    ['SHA-256', () => new Sha256Stream(), HashFunctions.sha256(data)],
+    ['SHA-512', () => new Sha512Stream(), HashFunctions.sha512(data)],
+    ['BLAKE3', () => new Blake3Stream(), HashFunctions.blake3(data)],
+    ['HMAC-SHA256', () => new HmacSha256Stream(key), HashFunctions.hmacSha256(key, data)],
+    ['HMAC-SHA512', () => new HmacSha512Stream(key), HashFunctions.hmacSha512(key, data)],
+  ];
+  
+  for (const [name, create, expected] of cases) {
+    const hasher = create();
+    for (const chunk of chunks) {
+      hasher.update(chunk);
+    }
+    const copy = hasher.copy();
+    assert(hasher.digest().equals(expected), `${name} stream should match one-shot hash`);
+    assert(copy.digest('hex') === expected.toString('hex'), `${name} copy should match one-shot hash`);
+    console.log(`✓ ${name} stream works`);
+  }
+  
+  const chained = new Sha256Stream().update('Hello, ').update(Buffer.from('World!')).digest('base64');
+  assert(chained === HashFunctions.sha256(Buffer.from('Hello, World!')).toString('base64'), 'Chained updates should hash strings as UTF-8');
+  console.log('✓ Chained updates and string input work');
+  
+  const finished = new Blake3Stream();
+  finished.digest();
+  let threw = false;
+  try {
+    finished.update(data);
+  } catch (error) {
+    threw = true;
+  }
+  assert(threw, 'Updating after digest should throw');
+  console.log('✓ Digest finalizes the hasher');
 }
 
 function testKeyDerivation() {
@@ -115,6 +465,26 @@ function testKeyDerivation() {
   assert(argon2Key.length === 32, 'Argon2 derived key should be 32 bytes');
   console.log('✓ Argon2 key derivation works');
   
+  // Test Argon2 options against a libsodium crypto_pwhash vector
+  const vectorPassword = Buffer.from('correct horse battery staple', 'utf8');
+  const vectorSalt = Buffer.from('0123456789abcdef', 'utf8');
+  const tuned = KeyDerivation.argon2(vectorPassword, vectorSalt, 32, { memoryCost: 4096, timeCost: 3, parallelism: 1 });
+  assert(tuned.toString('hex') === '2f8186f7b07132513ebb79a01833cb875faee5432f2c21d0093c70202b339837', 'Argon2id with options should match libsodium');
+  const argon2i = KeyDerivation.argon2(vectorPassword, vectorSalt, 32, { memoryCost: 4096, timeCost: 3, variant: 'argon2i' });
+  assert(argon2i.toString('hex') === '118d7623bb86ca8047cee42e156b7717f2afbbdf5f241636a98b33d8a652ae3f', 'Argon2i should match libsodium');
+  assert(KeyDerivation.argon2(password, salt, 32, {}).equals(argon2Key), 'Empty options should use the defaults');
+  
+  for (const options of [{ timeCost: 0 }, { memoryCost: 8, parallelism: 4 }, { variant: 'scrypt' }]) {
+    let threw = false;
+    try {
+      KeyDerivation.argon2(password, salt, 32, options);
+    } catch (error) {
+      threw = true;
+    }
+    assert(threw, `Argon2 should reject invalid options ${JSON.stringify(options)}`);
+  }
+  console.log('✓ Argon2 options work');
+  
   // Test PBKDF2
   const pbkdf2Key = KeyDerivation.pbkdf2Sha256(password, salt, 10000, 32);
   assert(pbkdf2Key.length === 32, 'PBKDF2 derived key should be 32 bytes');
@@ -145,25 +515,552 @@ function testRandomGenerator() {
   console.log('✓ Random generation works');
 }
 
-function runAllTests() {
+function testKeyFormats() {
+  console.log('Testing Key Formats...');
+  
+  const crypto = require('crypto');
+  const sortedJson = (jwk) => JSON.stringify(Object.fromEntries(Object.entries(jwk).sort()));
+  const nodeKeys = [
+    ['RSA', crypto.generateKeyPairSync('rsa', { modulusLength: 2048 })],
+    ['P-256', crypto.generateKeyPairSync('ec', { namedCurve: 'P-256' })],
+    ['Ed25519', crypto.generateKeyPairSync('ed25519')],
+  ];
+  
+  for (const [name, { privateKey, publicKey }] of nodeKeys) {
+    const privatePem = privateKey.export({ type: 'pkcs8', format: 'pem' });
+    const publicPem = publicKey.export({ type: 'spki', format: 'pem' });
+    
+    // JWK output matches node:crypto, and round-trips back to the same PEM
+    const jwk = KeyFormats.toJwk(privatePem);
+    assert(sortedJson(JSON.parse(jwk)) === sortedJson(privateKey.export({ format: 'jwk' })), `${name} private JWK should match node:crypto`);
+    assert(KeyFormats.fromJwk(jwk) === privatePem, `${name} private JWK should convert back to PEM`);
+    assert(sortedJson(JSON.parse(KeyFormats.toJwk(publicPem))) === sortedJson(publicKey.export({ format: 'jwk' })), `${name} public JWK should match node:crypto`);
+    assert(KeyFormats.toPublicKeyPem(privatePem) === publicPem, `${name} public key extraction should match`);
+    
+    // DER round-trips
+    const der = KeyFormats.toDer(privatePem);
+    assert(der.equals(privateKey.export({ type: 'pkcs8', format: 'der' })), `${name} PKCS#8 DER should match node:crypto`);
+    assert(KeyFormats.fromPkcs8Der(der) === privatePem, `${name} PKCS#8 DER should convert back to PEM`);
+    assert(KeyFormats.fromSpkiDer(publicKey.export({ type: 'spki', format: 'der' })) === publicPem, `${name} SPKI DER should convert to PEM`);
+    console.log(`✓ ${name} PEM/DER/JWK conversions work`);
+  }
+  
+  // OpenSSL-style PKCS#1 and SEC1 PEM
+  const rsaPkcs1 = nodeKeys[0][1].privateKey.export({ type: 'pkcs1', format: 'pem' });
+  assert(KeyFormats.toDer(rsaPkcs1).equals(nodeKeys[0][1].privateKey.export({ type: 'pkcs8', format: 'der' })), 'PKCS#1 PEM should convert to PKCS#8');
+  const ecSec1 = nodeKeys[1][1].privateKey.export({ type: 'sec1', format: 'pem' });
+  assert(KeyFormats.toJwk(ecSec1) === KeyFormats.toJwk(nodeKeys[1][1].privateKey.export({ type: 'pkcs8', format: 'pem' })), 'SEC1 PEM should convert to JWK');
+  console.log('✓ PKCS#1 and SEC1 inputs work');
+  
+  // Raw bytes bridge to the AsymmetricCrypto APIs
+  const message = Buffer.from('raw key bridge', 'utf8');
+  const ed25519 = AsymmetricCrypto.generateEd25519Keypair();
+  const ed25519Pem = KeyFormats.fromRawPrivate(ed25519.signingKeyBytes, 'ed25519');
+  assert(crypto.verify(null, message, KeyFormats.fromRawPublic(ed25519.verifyingKeyBytes, 'ed25519'), AsymmetricCrypto.signEd25519(message, ed25519.signingKeyBytes)), 'Raw Ed25519 key should work with node:crypto');
+  assert(KeyFormats.toRaw(ed25519Pem).equals(ed25519.signingKeyBytes), 'Ed25519 raw round-trip should match');
+  const ecdsa = AsymmetricCrypto.generateEcdsaKeypair();
+  const ecdsaPublicPem = KeyFormats.fromRawPublic(ecdsa.verifyingKeyBytes, 'p256');
+  assert(KeyFormats.toRaw(ecdsaPublicPem).equals(ecdsa.verifyingKeyBytes), 'P-256 raw public round-trip should match');
+  assert(KeyFormats.toRaw(KeyFormats.fromRawPrivate(ecdsa.signingKeyBytes, 'p256')).equals(ecdsa.signingKeyBytes), 'P-256 raw private round-trip should match');
+  console.log('✓ Raw key conversions work');
+  
+  for (const bad of [() => KeyFormats.toRaw(rsaPkcs1), () => KeyFormats.fromJwk('{"kty":"oct","k":"AA"}'), () => KeyFormats.fromRawPrivate(Buffer.alloc(32), 'x25519')]) {
+    let threw = false;
+    try {
+      bad();
+    } catch (error) {
+      threw = true;
+    }
+    assert(threw, 'Unsupported conversions should throw');
+  }
+  console.log('✓ Unsupported conversions throw');
+}
+
+function testTokens() {
+  console.log('Testing Tokens...');
+  
+  const crypto = require('crypto');
+  const now = Math.floor(Date.now() / 1000);
+  const claims = JSON.stringify({ sub: 'user-1', aud: 'api', iss: 'libsilver', exp: now + 300 });
+  const throws = (fn) => {
+    try {
+      fn();
+    } catch (error) {
+      return error.message;
+    }
+    return null;
+  };
+  
+  // HS256 signatures match node:crypto
+  const secret = Buffer.from('a-very-secret-hmac-key-of-32-bytes!', 'utf8');
+  const hsToken = Tokens.signJwt(claims, 'HS256', secret, { keyId: 'k1' });
+  const [header, payload, signature] = hsToken.split('.');
+  assert(crypto.createHmac('sha256', secret).update(`${header}.${payload}`).digest('base64url') === signature, 'HS256 signature should match node:crypto');
+  assert(JSON.parse(Tokens.decodeJwtHeader(hsToken)).kid === 'k1', 'JWT header should contain kid');
+  const verified = JSON.parse(Tokens.verifyJwt(hsToken, 'HS256', secret.toString('utf8'), { audience: ['api'], issuer: 'libsilver' }));
+  assert(verified.sub === 'user-1', 'HS256 claims should round-trip');
+  assert(throws(() => Tokens.verifyJwt(hsToken, 'HS384', secret)), 'Unexpected JWT algorithm should be rejected');
+  assert(throws(() => Tokens.verifyJwt(hsToken, 'HS256', secret, { audience: ['web'] })), 'Wrong audience should be rejected');
+  console.log('✓ HS256 JWT works');
+  
+  // Asymmetric JWTs verify with node:crypto
+  const rsa = crypto.generateKeyPairSync('rsa', { modulusLength: 2048 });
+  const ec = crypto.generateKeyPairSync('ec', { namedCurve: 'P-256' });
+  const ed = crypto.generateKeyPairSync('ed25519');
+  const cases = [
+    ['RS256', rsa, (input, sig, key) => crypto.verify('sha256', input, key, sig)],
+    ['PS256', rsa, (input, sig, key) => crypto.verify('sha256', input, { key, padding: crypto.constants.RSA_PKCS1_PSS_PADDING }, sig)],
+    ['ES256', ec, (input, sig, key) => crypto.verify('sha256', input, { key, dsaEncoding: 'ieee-p1363' }, sig)],
+    ['EdDSA', ed, (input, sig, key) => crypto.verify(null, input, key, sig)],
+  ];
+  for (const [algorithm, { privateKey, publicKey }, nodeVerify] of cases) {
+    const privatePem = privateKey.export({ type: 'pkcs8', format: 'pem' });
+    const publicPem = publicKey.export({ type: 'spki', format: 'pem' });
+    const token = Tokens.signJwt(claims, algorithm, privatePem);
+    const signingInput = token.slice(0, token.lastIndexOf('.'));
+    const sig = Buffer.from(token.slice(token.lastIndexOf('.') + 1), 'base64url');
+    assert(nodeVerify(Buffer.from(signingInput), sig, publicKey), `${algorithm} JWT should verify with node:crypto`);
+    assert(JSON.parse(Tokens.verifyJwt(token, algorithm, publicPem)).iss === 'libsilver', `${algorithm} JWT should verify`);
+    console.log(`✓ ${algorithm} JWT works`);
+  }
+  const ed25519 = AsymmetricCrypto.generateEd25519Keypair();
+  const rawToken = Tokens.signJwt(claims, 'EdDSA', ed25519.signingKeyBytes);
+  assert(JSON.parse(Tokens.verifyJwt(rawToken, 'EdDSA', ed25519.verifyingKeyBytes)).sub === 'user-1', 'Raw EdDSA keys should work');
+  const expired = Tokens.signJwt(JSON.stringify({ exp: now - 10 }), 'HS256', secret);
+  assert(/expired/.test(throws(() => Tokens.verifyJwt(expired, 'HS256', secret))), 'Expired JWT should be rejected');
+  assert(Tokens.verifyJwt(expired, 'HS256', secret, { leeway: 60 }), 'Leeway should accept recently expired JWT');
+  console.log('✓ Raw keys and expiry work');
+  
+  // PASETO v4.local
+  const localKey = SymmetricCrypto.generateAesKey();
+  const pasetoClaims = JSON.stringify({ sub: 'user-1', exp: new Date((now + 300) * 1000).toISOString() });
+  const local = Tokens.encryptPaseto(pasetoClaims, localKey, { footer: '{"kid":"local-1"}', implicitAssertion: 'tenant-a' });
+  assert(local.startsWith('v4.local.'), 'Local PASETO should have the v4.local header');
+  assert(Tokens.pasetoFooter(local) === '{"kid":"local-1"}', 'PASETO footer should be readable');
+  assert(Tokens.decryptPaseto(local, localKey, { footer: '{"kid":"local-1"}', implicitAssertion: 'tenant-a' }) === pasetoClaims, 'Local PASETO should round-trip');
+  assert(throws(() => Tokens.decryptPaseto(local, localKey, { footer: '{"kid":"local-1"}', implicitAssertion: 'tenant-b' })), 'Wrong implicit assertion should be rejected');
+  assert(throws(() => Tokens.decryptPaseto(local, localKey)), 'Missing footer should be rejected');
+  console.log('✓ PASETO v4.local works');
+  
+  // PASETO v4.public, including the 4-S-1 test vector
+  const vectorSeed = Buffer.from('b4cbfb43df4ce210727d953e4a713307fa19bb7d9f85041438d9e11b942a3774', 'hex');
+  const vectorPublic = Buffer.from('1eb9dbbbbc047c03fd70604e0071f0987e16b28b757225c11f00415d0e20b1a2', 'hex');
+  const vectorToken = 'v4.public.eyJkYXRhIjoidGhpcyBpcyBhIHNpZ25lZCBtZXNzYWdlIiwiZXhwIjoiMjAyMi0wMS0wMVQwMDowMDowMCswMDowMCJ9bg_XBBzds8lTZShVlwwKSgeKpLT3yukTw6JUz3W4h_ExsQV-P0V54zemZDcAxFaSeef1QlXEFtkqxT1ciiQEDA';
+  assert(Tokens.signPaseto('{"data":"this is a signed message","exp":"2022-01-01T00:00:00+00:00"}', vectorSeed) === vectorToken, 'v4.public should match test vector 4-S-1');
+  assert(/expired/.test(throws(() => Tokens.verifyPaseto(vectorToken, vectorPublic))), 'Expired PASETO should be rejected');
+  const signed = Tokens.signPaseto(pasetoClaims, ed.privateKey.export({ type: 'pkcs8', format: 'pem' }));
+  assert(Tokens.verifyPaseto(signed, ed.publicKey.export({ type: 'spki', format: 'pem' })) === pasetoClaims, 'Public PASETO should verify with a PEM key');
+  assert(/signature/.test(throws(() => Tokens.verifyPaseto(signed, vectorPublic))), 'Wrong key should be rejected');
+  console.log('✓ PASETO v4.public works');
+}
+
+function testOtp() {
+  console.log('Testing OTP...');
+  
+  // RFC 6238 test vectors
+  const secret = 'GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ';
+  assert(Otp.totpGenerate(secret, { digits: 8, timestamp: 59 }) === '94287082', 'TOTP should match RFC 6238 vector');
+  assert(Otp.totpGenerate(secret, { digits: 8, timestamp: 1111111109 }) === '07081804', 'TOTP should match RFC 6238 vector');
+  assert(Otp.hotpGenerate(secret, 1) === '287082', 'HOTP should match RFC 4226 vector');
+  console.log('✓ RFC 4226/6238 vectors match');
+  
+  const generated = Otp.generateSecret();
+  assert(generated.length === 32, 'Generated secret should be 160 bits of base32');
+  const code = Otp.totpGenerate(generated);
+  assert(/^\d{6}$/.test(code), 'TOTP code should have 6 digits');
+  assert(Otp.totpVerify(code, generated), 'Current TOTP code should verify');
+  const timestamp = 1700000000;
+  const previous = Otp.totpGenerate(generated, { timestamp: timestamp - 30 });
+  assert(Otp.totpVerify(previous, generated, { timestamp }), 'Previous TOTP code should verify within the window');
+  assert(!Otp.totpVerify(previous, generated, { timestamp, window: 0 }), 'Window 0 should reject the previous code');
+  assert(!Otp.totpVerify(previous, generated, { timestamp: timestamp + 60 }), 'Codes outside the window should not verify');
+  console.log('✓ TOTP generate/verify works');
+  
+  const uri = Otp.totpUri(secret, 'ACME Co', 'alice@example.com');
+  assert(uri === 'otpauth://totp/ACME%20Co:alice%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=ACME%20Co&algorithm=SHA1&digits=6&period=30', 'Provisioning URI should match');
+  console.log('✓ Provisioning URI works');
+}
+
+function testSecureKeyHandle() {
+  console.log('Testing SecureKeyHandle...');
+  
+  const plaintext = Buffer.from('handled secret', 'utf8');
+  const aad = Buffer.from('context', 'utf8');
+  
+  const aesHandle = SecureKeyHandle.generate(32);
+  assert(aesHandle.length === 32 && !aesHandle.destroyed, 'Generated handle should hold a 32-byte key');
+  const ciphertext = SymmetricCrypto.encryptAesWithHandle(plaintext, aesHandle, aad);
+  assert(plaintext.equals(SymmetricCrypto.decryptAesWithHandle(ciphertext, aesHandle, aad)), 'AES decryption by handle should match');
+  const chachaCiphertext = SymmetricCrypto.encryptChacha20WithHandle(plaintext, aesHandle);
+  assert(plaintext.equals(SymmetricCrypto.decryptChacha20WithHandle(chachaCiphertext, aesHandle)), 'ChaCha20 decryption by handle should match');
+  console.log('✓ Encryption by handle works');
+  
+  // Handles interoperate with the Buffer-based API
+  const rawKey = SymmetricCrypto.generateAesKey();
+  const imported = SecureKeyHandle.fromBuffer(rawKey);
+  assert(plaintext.equals(SymmetricCrypto.decryptAes(SymmetricCrypto.encryptAesWithHandle(plaintext, imported), rawKey)), 'Imported handle should match the raw key');
+  assert(HashFunctions.hmacSha256WithHandle(imported, plaintext).equals(HashFunctions.hmacSha256(rawKey, plaintext)), 'HMAC by handle should match');
+  rawKey.fill(0);
+  assert(plaintext.equals(SymmetricCrypto.decryptAesWithHandle(SymmetricCrypto.encryptAesWithHandle(plaintext, imported), imported)), 'Handle should own a copy of the key');
+  
+  const password = Buffer.from('password123', 'utf8');
+  const salt = RandomGenerator.generateSalt();
+  const options = { memoryCost: 4096, timeCost: 1 };
+  const derived = SecureKeyHandle.deriveArgon2(password, salt, 32, options);
+  const derivedRaw = KeyDerivation.argon2(password, salt, 32, options);
+  assert(HashFunctions.hmacSha256WithHandle(derived, plaintext).equals(HashFunctions.hmacSha256(derivedRaw, plaintext)), 'Argon2 handle should match the derived key');
+  console.log('✓ Handles import and derive keys');
+  
+  const ed25519 = AsymmetricCrypto.generateEd25519Keypair();
+  const ed25519Handle = SecureKeyHandle.fromBuffer(ed25519.signingKeyBytes);
+  const ed25519Signature = AsymmetricCrypto.signEd25519WithHandle(plaintext, ed25519Handle);
+  assert(AsymmetricCrypto.verifyEd25519(plaintext, ed25519Signature, ed25519.verifyingKeyBytes), 'Ed25519 signature by handle should verify');
+  const ecdsa = AsymmetricCrypto.generateEcdsaKeypair();
+  const ecdsaSignature = AsymmetricCrypto.signEcdsaWithHandle(plaintext, SecureKeyHandle.fromBuffer(ecdsa.signingKeyBytes));
+  assert(AsymmetricCrypto.verifyEcdsa(plaintext, ecdsaSignature, ecdsa.verifyingKeyBytes), 'ECDSA signature by handle should verify');
+  console.log('✓ Signing by handle works');
+  
+  aesHandle.destroy();
+  assert(aesHandle.destroyed, 'Handle should report destroyed');
+  let threw = false;
+  try {
+    SymmetricCrypto.decryptAesWithHandle(ciphertext, aesHandle, aad);
+  } catch (error) {
+    threw = error.message.includes('destroyed');
+  }
+  assert(threw, 'Destroyed handle should not be usable');
+  console.log('✓ destroy() invalidates the handle');
+}
+
+async function testKeyPairObjects() {
+  console.log('Testing Key Pair Objects...');
+  
+  const crypto = require('crypto');
+  const message = Buffer.from('key pair objects', 'utf8');
+  
+  const ed25519 = Ed25519KeyPair.generate();
+  const edSignature = ed25519.sign(message);
+  assert(ed25519.verify(message, edSignature), 'Ed25519 key pair should verify its own signature');
+  assert(AsymmetricCrypto.verifyEd25519(message, edSignature, ed25519.publicKey()), 'Ed25519 public key should work with the static API');
+  assert(crypto.verify(null, message, ed25519.toPem(), edSignature), 'Ed25519 toPem should be usable by node:crypto');
+  const edImported = Ed25519KeyPair.fromPem(ed25519.exportPrivateKeyPem());
+  assert(edImported.publicKey().equals(ed25519.publicKey()), 'Ed25519 PEM export should round-trip');
+  const legacy = AsymmetricCrypto.generateEd25519Keypair();
+  assert(Ed25519KeyPair.fromPrivateKeyBytes(legacy.signingKeyBytes).publicKey().equals(legacy.verifyingKeyBytes), 'Plain Ed25519 objects should import');
+  assert(!Object.keys(ed25519).some((name) => Buffer.isBuffer(ed25519[name])), 'Key pair objects should expose no key bytes as properties');
+  console.log('✓ Ed25519KeyPair works');
+  
+  const ecdsa = EcdsaKeyPair.generate();
+  const ecdsaSignature = ecdsa.sign(message);
+  assert(ecdsa.verify(message, ecdsaSignature), 'ECDSA key pair should verify its own signature');
+  assert(AsymmetricCrypto.verifyEcdsa(message, ecdsaSignature, ecdsa.publicKey()), 'ECDSA public key should work with the static API');
+  assert(crypto.verify('sha256', message, { key: ecdsa.toPem(), dsaEncoding: 'ieee-p1363' }, ecdsaSignature), 'ECDSA toPem should be usable by node:crypto');
+  const nodeEc = crypto.generateKeyPairSync('ec', { namedCurve: 'P-256' });
+  const ecImported = EcdsaKeyPair.fromPem(nodeEc.privateKey.export({ type: 'sec1', format: 'pem' }));
+  assert(crypto.verify('sha256', message, { key: nodeEc.publicKey, dsaEncoding: 'ieee-p1363' }, ecImported.sign(message)), 'Imported SEC1 key should sign');
+  let threw = false;
+  try {
+    EcdsaKeyPair.fromPem(ed25519.exportPrivateKeyPem());
+  } catch (error) {
+    threw = true;
+  }
+  assert(threw, 'Importing a key of the wrong algorithm should throw');
+  console.log('✓ EcdsaKeyPair works');
+  
+  const rsa = await RsaKeyPair.generateAsync();
+  assert(rsa instanceof RsaKeyPair, 'generateAsync should resolve to a RsaKeyPair');
+  const rsaSignature = rsa.sign(message, { padding: 'pkcs1' });
+  assert(rsa.verify(message, rsaSignature, { padding: 'pkcs1' }), 'RSA key pair should verify its own signature');
+  assert(crypto.verify('sha256', message, rsa.toPem(), rsaSignature), 'RSA toPem should be usable by node:crypto');
+  assert(message.equals(rsa.decrypt(rsa.encrypt(message))), 'RSA key pair should round-trip OAEP');
+  assert(message.equals(AsymmetricCrypto.decryptRsa(AsymmetricCrypto.encryptRsa(message, rsa.toPem()), rsa.exportPrivateKeyPem())), 'RSA PEM export should work with the static API');
+  assert(RsaKeyPair.fromPem(rsa.exportPrivateKeyPem()).toPem() === rsa.toPem(), 'RSA PEM export should round-trip');
+  console.log('✓ RsaKeyPair works');
+  
+  const alice = EcdhKeyPair.generate();
+  const bob = EcdhKeyPair.generate();
+  assert(alice.curve === 'x25519', 'ECDH should default to X25519');
+  assert(alice.deriveSharedSecret(bob.publicKey()).equals(bob.deriveSharedSecret(alice.publicKey())), 'ECDH shared secrets should match');
+  const p256 = EcdhKeyPair.generate('p256');
+  assert(p256.curve === 'p256' && p256.publicKey().length === 65, 'P-256 key pair should use uncompressed points');
+  assert(message.equals(p256.decrypt(AsymmetricCrypto.eciesEncrypt(message, p256.publicKey(), 'p256'))), 'ECIES should decrypt with the key pair');
+  console.log('✓ EcdhKeyPair works');
+  
+  ed25519.destroy();
+  assert(ed25519.destroyed, 'Key pair should report destroyed');
+  threw = false;
+  try {
+    ed25519.sign(message);
+  } catch (error) {
+    threw = error.message.includes('destroyed');
+  }
+  assert(threw, 'Destroyed key pair should not be usable');
+  console.log('✓ destroy() invalidates the key pair');
+}
+
+async function testAsyncOperations() {
+  console.log('Testing Async Operations...');
+  
+  const password = Buffer.from('password123', 'utf8');
+  const salt = RandomGenerator.generateSalt();
+  
+  // The event loop keeps running while Argon2 runs on the thread pool
+  let ticks = 0;
+  const timer = setInterval(() => ticks++, 1);
+  const argon2Key = await KeyDerivation.argon2Async(password, salt, 32);
+  clearInterval(timer);
+  assert(argon2Key.equals(KeyDerivation.argon2(password, salt, 32)), 'Async Argon2 should match sync Argon2');
+  const argon2Options = { memoryCost: 8192, timeCost: 1, parallelism: 2 };
+  const tunedKey = await KeyDerivation.argon2Async(password, salt, 32, argon2Options);
+  assert(tunedKey.equals(KeyDerivation.argon2(password, salt, 32, argon2Options)), 'Async Argon2 with options should match sync');
+  assert(ticks > 0, 'Event loop should not be blocked by async Argon2');
+  console.log('✓ Async Argon2 works');
+  
+  const [pbkdf2Sha256, pbkdf2Sha512] = await Promise.all([
+    KeyDerivation.pbkdf2Sha256Async(password, salt, 10000, 32),
+    KeyDerivation.pbkdf2Sha512Async(password, salt, 10000, 64),
+  ]);
+  assert(pbkdf2Sha256.equals(KeyDerivation.pbkdf2Sha256(password, salt, 10000, 32)), 'Async PBKDF2-SHA256 should match sync');
+  assert(pbkdf2Sha512.equals(KeyDerivation.pbkdf2Sha512(password, salt, 10000, 64)), 'Async PBKDF2-SHA512 should match sync');
+  console.log('✓ Async PBKDF2 works');
+  
+  // scrypt matches node:crypto, and both slow KDFs can be aborted mid-derivation
+  const crypto = require('crypto');
+  const scryptOptions = { logN: 10, r: 8, p: 16 };
+  const progress = [];
+  const scryptKey = await KeyDerivation.scryptAsync(password, salt, 64, scryptOptions, undefined, (fraction) => progress.push(fraction));
+  assert(scryptKey.equals(crypto.scryptSync(password, salt, 64, { N: 1024, r: 8, p: 16 })), 'Async scrypt should match node:crypto');
+  assert(scryptKey.equals(KeyDerivation.scrypt(password, salt, 64, scryptOptions)), 'Async scrypt should match sync');
+  await new Promise((resolve) => setImmediate(resolve));
+  assert(progress.length > 1 && progress[progress.length - 1] === 1, 'scrypt should report batched progress');
+  console.log('✓ Async scrypt works');
+  
+  const rejectsWithAbort = async (promise) => {
+    try {
+      await promise;
+    } catch (error) {
+      return error.message === 'AbortError';
+    }
+    return false;
+  };
+  const controller = new AbortController();
+  const started = Date.now();
+  const pending = KeyDerivation.pbkdf2Sha256Async(password, salt, 50_000_000, 32, controller.signal);
+  setTimeout(() => controller.abort(), 20);
+  assert(await rejectsWithAbort(pending), 'Aborted PBKDF2 should reject with AbortError');
+  assert(Date.now() - started < 5000, 'Aborted PBKDF2 should stop early');
+  const scryptController = new AbortController();
+  const pendingScrypt = KeyDerivation.scryptAsync(password, salt, 32, { logN: 16, r: 8, p: 64 }, scryptController.signal);
+  setTimeout(() => scryptController.abort(), 20);
+  assert(await rejectsWithAbort(pendingScrypt), 'Aborted scrypt should reject with AbortError');
+  assert(await rejectsWithAbort(KeyDerivation.pbkdf2Sha512Async(password, salt, 1000, 32, AbortSignal.abort())), 'Pre-aborted signal should reject');
+  console.log('✓ AbortSignal cancels async KDFs');
+  
+  const rsaKeypair = await AsymmetricCrypto.generateRsaKeypairAsync();
+  const message = Buffer.from('async RSA message', 'utf8');
+  const ciphertext = await AsymmetricCrypto.encryptRsaAsync(message, rsaKeypair.publicKeyPem);
+  const decrypted = await AsymmetricCrypto.decryptRsaAsync(ciphertext, rsaKeypair.privateKeyPem);
+  assert(message.equals(decrypted), 'Async RSA decryption should match original message');
+  console.log('✓ Async RSA key generation and encryption work');
+  
+  let rejected = false;
+  try {
+    await AsymmetricCrypto.generateRsaKeypairWithSizeAsync(1024);
+  } catch (error) {
+    rejected = true;
+  }
+  assert(rejected, 'Async RSA key generation should reject keys below 2048 bits');
+  console.log('✓ Async errors reject the promise');
+}
+
+function testGeneratedApi() {
+  console.log('Testing Generated Api...');
+  
+  const key = RandomGenerator.generateKey(32);
+  const data = Buffer.from('generated from libsilver::api');
+  const aad = Buffer.from('header');
+
+  const sealed = Api.aesGcmEncrypt(key, data, aad);
+  assert(SymmetricCrypto.decryptAesWithAad(sealed, key, aad).equals(data), 'Api AES-GCM output should open with SymmetricCrypto');
+  assert(Api.chacha20Poly1305Decrypt(key, Api.chacha20Poly1305Encrypt(key, data, null), undefined).equals(data), 'Api ChaCha20-Poly1305 should round-trip');
+  console.log('✓ AEAD functions interoperate with SymmetricCrypto');
+
+  assert(Api.sha3_256(data).equals(HashFunctions.sha3_256(data)), 'Api SHA3-256 should match HashFunctions');
+  assert(Api.hmacSha512(key, data).equals(HashFunctions.hmacSha512(key, data)), 'Api HMAC-SHA512 should match HashFunctions');
+  assert(Api.hkdfSha256(key, null, aad, 42).equals(KeyDerivation.hkdfSha256(key, null, aad, 42)), 'Api HKDF should match KeyDerivation');
+  console.log('✓ Hash and KDF functions match the hand-written classes');
+
+  const keypair = AsymmetricCrypto.generateEcdsaKeypair();
+  const signature = Api.ecdsaP256Sign(keypair.signingKeyBytes, data);
+  assert(AsymmetricCrypto.verifyEcdsa(data, signature, keypair.verifyingKeyBytes), 'Api ECDSA signature should verify with AsymmetricCrypto');
+  assert(!Api.ecdsaP256Verify(keypair.verifyingKeyBytes, aad, signature), 'Api ECDSA should reject another message');
+  console.log('✓ Signature functions work');
+
+  const hash = Api.argon2HashPassword(Buffer.from('hunter2'));
+  assert(hash.startsWith('$argon2id$'), 'Password hash should be an Argon2id PHC string');
+  assert(Api.argon2VerifyPassword(Buffer.from('hunter2'), hash), 'Correct password should verify');
+  assert(!Api.argon2VerifyPassword(Buffer.from('hunter3'), hash), 'Wrong password should not verify');
+  console.log('✓ Argon2 password hashing works');
+}
+
+function testKnownAnswerVectors() {
+  console.log('Testing Known-Answer Vectors...');
+  
+  const fs = require('fs');
+  const path = require('path');
+  const { vectors } = JSON.parse(fs.readFileSync(path.join(__dirname, '../../../test-vectors/kat.json'), 'utf8'));
+  const hex = (value) => Buffer.from(value, 'hex');
+  const spkiPem = (der) => `-----BEGIN PUBLIC KEY-----\n${hex(der).toString('base64').match(/.{1,64}/g).join('\n')}\n-----END PUBLIC KEY-----\n`;
+  const verifyRsa = (padding) => (i) => Buffer.from([AsymmetricCrypto.verifyRsa(hex(i.message), hex(i.signature), spkiPem(i.public_key), { padding }) ? 1 : 0]);
+
+  const compute = {
+    'sha256': (i) => HashFunctions.sha256(hex(i.message)),
+    'sha512': (i) => HashFunctions.sha512(hex(i.message)),
+    'sha3-256': (i) => HashFunctions.sha3_256(hex(i.message)),
+    'sha3-512': (i) => HashFunctions.sha3_512(hex(i.message)),
+    'blake3': (i) => HashFunctions.blake3(hex(i.message)),
+    'blake3-keyed': (i) => HashFunctions.blake3Keyed(hex(i.key), hex(i.message)),
+    'hmac-sha256': (i) => HashFunctions.hmacSha256(hex(i.key), hex(i.message)),
+    'hmac-sha512': (i) => HashFunctions.hmacSha512(hex(i.key), hex(i.message)),
+    'hkdf-sha256': (i) => KeyDerivation.hkdfSha256(hex(i.ikm), hex(i.salt), hex(i.info), i.length),
+    'pbkdf2-sha256': (i) => KeyDerivation.pbkdf2Sha256(hex(i.password), hex(i.salt), i.iterations, i.length),
+    'scrypt': (i) => KeyDerivation.scrypt(hex(i.password), hex(i.salt), i.length, { logN: i.log_n, r: i.r, p: i.p }),
+    'argon2id': (i) => KeyDerivation.argon2(hex(i.password), hex(i.salt), i.length, {
+      memoryCost: i.memory_kib, timeCost: i.iterations, parallelism: i.parallelism, variant: 'argon2id',
+    }),
+    'ed25519': (i) => AsymmetricCrypto.signEd25519(hex(i.message), hex(i.private_key)),
+    'ecdsa-p256-sha256': (i) => AsymmetricCrypto.signEcdsa(hex(i.message), hex(i.private_key)),
+    'x25519': (i) => AsymmetricCrypto.deriveSharedSecret(hex(i.private_key), hex(i.peer_public_key), 'x25519'),
+    'rsa-pkcs1v15-sha256-verify': verifyRsa('pkcs1'),
+    'rsa-pss-sha256-verify': verifyRsa('pss'),
+  };
+
+  // Encryption uses a random nonce, so AEAD vectors are checked by decrypting the expected output
+  const open = {
+    'aes-256-gcm': (i, sealed) => SymmetricCrypto.decryptAesWithAad(sealed, hex(i.key), hex(i.aad)),
+    'chacha20-poly1305': (i, sealed) => SymmetricCrypto.decryptChacha20WithAad(sealed, hex(i.key), hex(i.aad)),
+  };
+
+  let checked = 0;
+  for (const { algorithm, description, inputs, expected } of vectors) {
+    const label = `${algorithm} (${description})`;
+    if (inputs.secret !== undefined) {
+      // The Node.js API has no Argon2 secret key (RFC 9106 vector)
+      continue;
+    }
+    checked++;
+    if (compute[algorithm]) {
+      assert(compute[algorithm](inputs).toString('hex') === expected, `${label} should match core`);
+    } else {
+      assert(open[algorithm], `No Node.js runner for ${label}`);
+      assert(open[algorithm](inputs, hex(expected)).toString('hex') === inputs.plaintext, `${label} should decrypt to the plaintext`);
+    }
+  }
+  console.log(`✓ ${checked} known-answer vectors match core`);
+}
+
+async function testWorkerThreads() {
+  console.log('Testing Worker Threads...');
+  
+  // Progress callbacks fire on the main thread while RSA keys are generated on the pool
+  const progress = [];
+  const keypair = await AsymmetricCrypto.generateRsaKeypairAsync((fraction) => progress.push(fraction));
+  await new Promise((resolve) => setImmediate(resolve));
+  assert(keypair.privateKeyPem.includes('PRIVATE KEY'), 'RSA key generation with progress should produce a key');
+  assert(progress.length >= 2 && progress[progress.length - 1] === 1, 'RSA key generation should report progress up to 1');
+  assert(progress.every((fraction) => fraction > 0 && fraction <= 1), 'Progress should be a fraction');
+  console.log('✓ RSA key generation progress callbacks work');
+  
+  // The addon loads in several workers at once and shares no state between them
+  const { Worker } = require('worker_threads');
+  const path = require('path');
+  const key = SymmetricCrypto.generateAesKey();
+  const workerSource = `
+    const { parentPort, workerData } = require('worker_threads');
+    const { SymmetricCrypto, HashFunctions, Sha256Stream } = require(workerData.modulePath);
+    const message = Buffer.from('from worker ' + workerData.id);
+    const hasher = new Sha256Stream();
+    for (let i = 0; i < 100; i++) hasher.update(message);
+    parentPort.postMessage({
+      ciphertext: SymmetricCrypto.encryptAes(message, Buffer.from(workerData.key)),
+      digest: hasher.digest('hex'),
+      oneShot: HashFunctions.sha256Hex(Buffer.concat(Array(100).fill(message))),
+    });
+  `;
+  const results = await Promise.all([0, 1, 2, 3].map((id) => new Promise((resolve, reject) => {
+    const worker = new Worker(workerSource, {
+      eval: true,
+      workerData: { id, key, modulePath: path.join(__dirname, '..', 'index.js') },
+    });
+    worker.once('message', resolve);
+    worker.once('error', reject);
+  })));
+  results.forEach((result, id) => {
+    const plaintext = SymmetricCrypto.decryptAes(Buffer.from(result.ciphertext), key);
+    assert(plaintext.toString() === `from worker ${id}`, 'Worker ciphertext should decrypt on the main thread');
+    assert(result.digest === result.oneShot, 'Streaming hasher in a worker should match one-shot hashing');
+  });
+  console.log('✓ Bindings work from worker_threads');
+}
+
+async function runAllTests() {
   try {
     console.log('🧪 Running LibSilver Node.js binding tests...\n');
     
     testSymmetricCrypto();
     console.log();
     
+    await testStreamingEncryption();
+    console.log();
+    
+    await testFileEncryption();
+    console.log();
+    
     testAsymmetricCrypto();
     console.log();
     
     testHashFunctions();
     console.log();
     
+    testOutputEncodings();
+    console.log();
+    
+    testStreamingHashers();
+    console.log();
+    
     testKeyDerivation();
     console.log();
     
     testRandomGenerator();
     console.log();
     
+    testKeyFormats();
+    console.log();
+    
+    testTokens();
+    console.log();
+    
+    testOtp();
+    console.log();
+    
+    testSecureKeyHandle();
+    console.log();
+    
+    await testKeyPairObjects();
+    console.log();
+    
+    await testAsyncOperations();
+    console.log();
+    
+    await testWorkerThreads();
+    console.log();
+    
+    testGeneratedApi();
+    console.log();
+    
+    testKnownAnswerVectors();
+    console.log();
+    
     console.log('🎉 All tests passed!');
   } catch (error) {
     console.error('❌ Test failed:', error.message);
@@ -177,9 +1074,22 @@ if (require.main === module) {
 
 module.exports = {
   testSymmetricCrypto,
+  testStreamingEncryption,
+  testFileEncryption,
   testAsymmetricCrypto,
   testHashFunctions,
+  testOutputEncodings,
+  testStreamingHashers,
   testKeyDerivation,
   testRandomGenerator,
+  testKeyFormats,
+  testTokens,
+  testOtp,
+  testSecureKeyHandle,
+  testKeyPairObjects,
+  testAsyncOperations,
+  testWorkerThreads,
+  testGeneratedApi,
+  testKnownAnswerVectors,
   runAllTests
 };
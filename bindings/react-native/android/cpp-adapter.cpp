@@ -0,0 +1,12 @@
+#include <jni.h>
+#include <jsi/jsi.h>
+
+#include "LibsilverJsi.h"
+
+extern "C" JNIEXPORT void JNICALL
+Java_com_libsilver_LibsilverModule_nativeInstall(JNIEnv *, jclass, jlong jsiRuntime) {
+  auto *runtime = reinterpret_cast<facebook::jsi::Runtime *>(jsiRuntime);
+  if (runtime != nullptr) {
+    libsilver::install(*runtime);
+  }
+}
@@ -0,0 +1,41 @@
+package com.libsilver;
+
+import androidx.annotation.NonNull;
+
+import com.facebook.react.bridge.JavaScriptContextHolder;
+import com.facebook.react.bridge.ReactApplicationContext;
+import com.facebook.react.bridge.ReactContextBaseJavaModule;
+import com.facebook.react.bridge.ReactMethod;
+import com.facebook.react.module.annotations.ReactModule;
+
+/** Installs the libsilver JSI bindings into the JavaScript runtime */
+@ReactModule(name = LibsilverModule.NAME)
+public class LibsilverModule extends ReactContextBaseJavaModule {
+  public static final String NAME = "Libsilver";
+
+  static {
+    System.loadLibrary("libsilver-jsi");
+  }
+
+  private static native void nativeInstall(long jsiRuntime);
+
+  public LibsilverModule(ReactApplicationContext reactContext) {
+    super(reactContext);
+  }
+
+  @Override
+  @NonNull
+  public String getName() {
+    return NAME;
+  }
+
+  @ReactMethod(isBlockingSynchronousMethod = true)
+  public boolean install() {
+    JavaScriptContextHolder jsContext = getReactApplicationContext().getJavaScriptContextHolder();
+    if (jsContext == null || jsContext.get() == 0) {
+      return false;
+    }
+    nativeInstall(jsContext.get());
+    return true;
+  }
+}
@@ -0,0 +1,217 @@
+#include "LibsilverJsi.h"
+
+#include <memory>
+#include <string>
+#include <utility>
+#include <vector>
+
+#include "libsilver.h"
+
+namespace libsilver {
+
+using namespace facebook;
+
+namespace {
+
+// Owns bytes handed to JavaScript as an ArrayBuffer
+class ByteBuffer : public jsi::MutableBuffer {
+public:
+  explicit ByteBuffer(std::vector<uint8_t> data) : data_(std::move(data)) {}
+  size_t size() const override { return data_.size(); }
+  uint8_t *data() override { return data_.data(); }
+
+private:
+  std::vector<uint8_t> data_;
+};
+
+struct Bytes {
+  const uint8_t *data;
+  size_t size;
+};
+
+Bytes bytesArg(jsi::Runtime &rt, const jsi::Value *args, size_t count, size_t index, const char *name) {
+  if (index >= count || !args[index].isObject() || !args[index].asObject(rt).isArrayBuffer(rt)) {
+    throw jsi::JSError(rt, std::string("libsilver: ") + name + " must be an ArrayBuffer");
+  }
+  auto buffer = args[index].asObject(rt).getArrayBuffer(rt);
+  return {buffer.data(rt), buffer.size(rt)};
+}
+
+Bytes optionalBytesArg(jsi::Runtime &rt, const jsi::Value *args, size_t count, size_t index, const char *name) {
+  if (index >= count || args[index].isUndefined() || args[index].isNull()) {
+    return {nullptr, 0};
+  }
+  return bytesArg(rt, args, count, index, name);
+}
+
+size_t sizeArg(jsi::Runtime &rt, const jsi::Value *args, size_t count, size_t index, const char *name) {
+  if (index >= count || !args[index].isNumber() || args[index].asNumber() < 0) {
+    throw jsi::JSError(rt, std::string("libsilver: ") + name + " must be a non-negative number");
+  }
+  return static_cast<size_t>(args[index].asNumber());
+}
+
+void check(jsi::Runtime &rt, libsilver_status status) {
+  if (status != LIBSILVER_STATUS_OK) {
+    const char *detail = libsilver_last_error_message();
+    throw jsi::JSError(rt, detail != nullptr ? detail : libsilver_status_message(status));
+  }
+}
+
+jsi::Value arrayBuffer(jsi::Runtime &rt, std::vector<uint8_t> data) {
+  return jsi::ArrayBuffer(rt, std::make_shared<ByteBuffer>(std::move(data)));
+}
+
+// Call a C function using the (out, out_len) convention, sizing the output from a first query
+template <typename Call>
+jsi::Value variableOutput(jsi::Runtime &rt, size_t expected, Call call) {
+  std::vector<uint8_t> out(expected);
+  size_t len = out.size();
+  libsilver_status status = call(out.data(), &len);
+  if (status == LIBSILVER_STATUS_BUFFER_TOO_SMALL) {
+    out.resize(len);
+    status = call(out.data(), &len);
+  }
+  check(rt, status);
+  out.resize(len);
+  return arrayBuffer(rt, std::move(out));
+}
+
+using AeadFunction = libsilver_status (*)(const uint8_t *, size_t, const uint8_t *, size_t, const uint8_t *, size_t, uint8_t *, size_t *);
+using DigestFunction = libsilver_status (*)(const uint8_t *, size_t, uint8_t *, size_t *);
+using MacFunction = libsilver_status (*)(const uint8_t *, size_t, const uint8_t *, size_t, uint8_t *, size_t *);
+
+// (input, key, aad?) -> ArrayBuffer
+jsi::Value aead(jsi::Runtime &rt, const jsi::Value *args, size_t count, AeadFunction function, bool encrypt) {
+  Bytes input = bytesArg(rt, args, count, 0, "data");
+  Bytes key = bytesArg(rt, args, count, 1, "key");
+  Bytes aad = optionalBytesArg(rt, args, count, 2, "aad");
+  size_t expected = encrypt ? input.size + LIBSILVER_AEAD_OVERHEAD
+                            : (input.size > LIBSILVER_AEAD_OVERHEAD ? input.size - LIBSILVER_AEAD_OVERHEAD : 0);
+  return variableOutput(rt, expected, [&](uint8_t *out, size_t *len) {
+    return function(key.data, key.size, input.data, input.size, aad.data, aad.size, out, len);
+  });
+}
+
+void define(jsi::Runtime &rt, jsi::Object &target, const char *name, unsigned int argc, jsi::HostFunctionType body) {
+  auto id = jsi::PropNameID::forAscii(rt, name);
+  target.setProperty(rt, id, jsi::Function::createFromHostFunction(rt, id, argc, std::move(body)));
+}
+
+void defineDigest(jsi::Runtime &rt, jsi::Object &target, const char *name, size_t size, DigestFunction function) {
+  define(rt, target, name, 1, [size, function](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes data = bytesArg(rt, args, count, 0, "data");
+    return variableOutput(rt, size, [&](uint8_t *out, size_t *len) { return function(data.data, data.size, out, len); });
+  });
+}
+
+void defineMac(jsi::Runtime &rt, jsi::Object &target, const char *name, size_t size, MacFunction function) {
+  define(rt, target, name, 2, [size, function](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes key = bytesArg(rt, args, count, 0, "key");
+    Bytes message = bytesArg(rt, args, count, 1, "message");
+    return variableOutput(rt, size, [&](uint8_t *out, size_t *len) {
+      return function(key.data, key.size, message.data, message.size, out, len);
+    });
+  });
+}
+
+void defineAead(jsi::Runtime &rt, jsi::Object &target, const char *name, AeadFunction function, bool encrypt) {
+  define(rt, target, name, 3, [function, encrypt](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    return aead(rt, args, count, function, encrypt);
+  });
+}
+
+} // namespace
+
+void install(jsi::Runtime &rt) {
+  jsi::Object module(rt);
+
+  define(rt, module, "version", 0, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *, size_t) {
+    return jsi::Value(jsi::String::createFromUtf8(rt, libsilver_version()));
+  });
+
+  define(rt, module, "randomBytes", 1, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    std::vector<uint8_t> out(sizeArg(rt, args, count, 0, "length"));
+    check(rt, libsilver_random_bytes(out.data(), out.size()));
+    return arrayBuffer(rt, std::move(out));
+  });
+
+  defineAead(rt, module, "aesGcmEncrypt", libsilver_aes_gcm_encrypt, true);
+  defineAead(rt, module, "aesGcmDecrypt", libsilver_aes_gcm_decrypt, false);
+  defineAead(rt, module, "chacha20Poly1305Encrypt", libsilver_chacha20_poly1305_encrypt, true);
+  defineAead(rt, module, "chacha20Poly1305Decrypt", libsilver_chacha20_poly1305_decrypt, false);
+
+  defineDigest(rt, module, "sha256", 32, libsilver_sha256);
+  defineDigest(rt, module, "sha512", 64, libsilver_sha512);
+  defineDigest(rt, module, "sha3_256", 32, libsilver_sha3_256);
+  defineDigest(rt, module, "blake3", 32, libsilver_blake3);
+  defineMac(rt, module, "hmacSha256", 32, libsilver_hmac_sha256);
+  defineMac(rt, module, "hmacSha512", 64, libsilver_hmac_sha512);
+
+  define(rt, module, "constantTimeEqual", 2, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes a = bytesArg(rt, args, count, 0, "a");
+    Bytes b = bytesArg(rt, args, count, 1, "b");
+    return jsi::Value(libsilver_constant_time_eq(a.data, a.size, b.data, b.size));
+  });
+
+  define(rt, module, "ed25519GenerateKeypair", 0, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *, size_t) {
+    std::vector<uint8_t> privateKey(LIBSILVER_ED25519_KEY_SIZE);
+    std::vector<uint8_t> publicKey(LIBSILVER_ED25519_KEY_SIZE);
+    check(rt, libsilver_ed25519_generate_keypair(privateKey.data(), publicKey.data()));
+    jsi::Object keypair(rt);
+    keypair.setProperty(rt, "privateKey", arrayBuffer(rt, std::move(privateKey)));
+    keypair.setProperty(rt, "publicKey", arrayBuffer(rt, std::move(publicKey)));
+    return jsi::Value(rt, keypair);
+  });
+
+  define(rt, module, "ed25519Sign", 2, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes privateKey = bytesArg(rt, args, count, 0, "privateKey");
+    Bytes message = bytesArg(rt, args, count, 1, "message");
+    return variableOutput(rt, LIBSILVER_ED25519_SIGNATURE_SIZE, [&](uint8_t *out, size_t *len) {
+      return libsilver_ed25519_sign(privateKey.data, privateKey.size, message.data, message.size, out, len);
+    });
+  });
+
+  define(rt, module, "ed25519Verify", 3, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes publicKey = bytesArg(rt, args, count, 0, "publicKey");
+    Bytes message = bytesArg(rt, args, count, 1, "message");
+    Bytes signature = bytesArg(rt, args, count, 2, "signature");
+    libsilver_status status = libsilver_ed25519_verify(publicKey.data, publicKey.size, message.data, message.size,
+                                                       signature.data, signature.size);
+    if (status == LIBSILVER_STATUS_VERIFICATION_FAILED) {
+      return jsi::Value(false);
+    }
+    check(rt, status);
+    return jsi::Value(true);
+  });
+
+  define(rt, module, "argon2id", 3, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes password = bytesArg(rt, args, count, 0, "password");
+    Bytes salt = bytesArg(rt, args, count, 1, "salt");
+    std::vector<uint8_t> out(sizeArg(rt, args, count, 2, "length"));
+    check(rt, libsilver_argon2id(password.data, password.size, salt.data, salt.size, out.data(), out.size()));
+    return arrayBuffer(rt, std::move(out));
+  });
+
+  define(rt, module, "pbkdf2Sha256", 4, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes password = bytesArg(rt, args, count, 0, "password");
+    Bytes salt = bytesArg(rt, args, count, 1, "salt");
+    auto iterations = static_cast<uint32_t>(sizeArg(rt, args, count, 2, "iterations"));
+    std::vector<uint8_t> out(sizeArg(rt, args, count, 3, "length"));
+    check(rt, libsilver_pbkdf2_sha256(password.data, password.size, salt.data, salt.size, iterations, out.data(), out.size()));
+    return arrayBuffer(rt, std::move(out));
+  });
+
+  define(rt, module, "hkdfSha256", 4, [](jsi::Runtime &rt, const jsi::Value &, const jsi::Value *args, size_t count) {
+    Bytes inputKey = bytesArg(rt, args, count, 0, "inputKey");
+    Bytes salt = optionalBytesArg(rt, args, count, 1, "salt");
+    Bytes info = optionalBytesArg(rt, args, count, 2, "info");
+    std::vector<uint8_t> out(sizeArg(rt, args, count, 3, "length"));
+    check(rt, libsilver_hkdf_sha256(inputKey.data, inputKey.size, salt.data, salt.size, info.data, info.size, out.data(), out.size()));
+    return arrayBuffer(rt, std::move(out));
+  });
+
+  rt.global().setProperty(rt, "__libsilver", std::move(module));
+}
+
+} // namespace libsilver
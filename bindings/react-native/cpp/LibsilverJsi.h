@@ -0,0 +1,12 @@
+#pragma once
+
+#include <jsi/jsi.h>
+
+namespace libsilver {
+
+// Install the `__libsilver` host object on the runtime's global object.
+// Every function takes and returns ArrayBuffers, so no base64 or bridge
+// serialization happens between JavaScript and the C ABI.
+void install(facebook::jsi::Runtime &runtime);
+
+} // namespace libsilver
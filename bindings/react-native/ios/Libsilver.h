@@ -0,0 +1,5 @@
+#import <React/RCTBridgeModule.h>
+
+/// Installs the libsilver JSI bindings into the JavaScript runtime
+@interface Libsilver : NSObject <RCTBridgeModule>
+@end
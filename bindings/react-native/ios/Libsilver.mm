@@ -0,0 +1,31 @@
+#import "Libsilver.h"
+
+#import <React/RCTBridge+Private.h>
+#import <React/RCTUtils.h>
+#import <jsi/jsi.h>
+
+#import "LibsilverJsi.h"
+
+@implementation Libsilver
+
+@synthesize bridge = _bridge;
+
+RCT_EXPORT_MODULE(Libsilver)
+
++ (BOOL)requiresMainQueueSetup
+{
+  return NO;
+}
+
+RCT_EXPORT_BLOCKING_SYNCHRONOUS_METHOD(install)
+{
+  RCTCxxBridge *cxxBridge = (RCTCxxBridge *)self.bridge;
+  if (cxxBridge == nil || cxxBridge.runtime == nil) {
+    return @false;
+  }
+
+  libsilver::install(*(facebook::jsi::Runtime *)cxxBridge.runtime);
+  return @true;
+}
+
+@end
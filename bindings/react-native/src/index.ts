@@ -0,0 +1,204 @@
+import { NativeModules, Platform } from 'react-native';
+
+/** Bytes accepted by every libsilver function */
+export type BinaryLike = Uint8Array | ArrayBuffer;
+
+/** Ed25519 key pair as raw 32-byte keys */
+export interface Ed25519KeyPair {
+  privateKey: Uint8Array;
+  publicKey: Uint8Array;
+}
+
+interface NativeLibsilver {
+  version(): string;
+  randomBytes(length: number): ArrayBuffer;
+  aesGcmEncrypt(data: ArrayBuffer, key: ArrayBuffer, aad?: ArrayBuffer): ArrayBuffer;
+  aesGcmDecrypt(data: ArrayBuffer, key: ArrayBuffer, aad?: ArrayBuffer): ArrayBuffer;
+  chacha20Poly1305Encrypt(data: ArrayBuffer, key: ArrayBuffer, aad?: ArrayBuffer): ArrayBuffer;
+  chacha20Poly1305Decrypt(data: ArrayBuffer, key: ArrayBuffer, aad?: ArrayBuffer): ArrayBuffer;
+  sha256(data: ArrayBuffer): ArrayBuffer;
+  sha512(data: ArrayBuffer): ArrayBuffer;
+  sha3_256(data: ArrayBuffer): ArrayBuffer;
+  blake3(data: ArrayBuffer): ArrayBuffer;
+  hmacSha256(key: ArrayBuffer, message: ArrayBuffer): ArrayBuffer;
+  hmacSha512(key: ArrayBuffer, message: ArrayBuffer): ArrayBuffer;
+  constantTimeEqual(a: ArrayBuffer, b: ArrayBuffer): boolean;
+  ed25519GenerateKeypair(): { privateKey: ArrayBuffer; publicKey: ArrayBuffer };
+  ed25519Sign(privateKey: ArrayBuffer, message: ArrayBuffer): ArrayBuffer;
+  ed25519Verify(publicKey: ArrayBuffer, message: ArrayBuffer, signature: ArrayBuffer): boolean;
+  argon2id(password: ArrayBuffer, salt: ArrayBuffer, length: number): ArrayBuffer;
+  pbkdf2Sha256(password: ArrayBuffer, salt: ArrayBuffer, iterations: number, length: number): ArrayBuffer;
+  hkdfSha256(inputKey: ArrayBuffer, salt: ArrayBuffer | undefined, info: ArrayBuffer | undefined, length: number): ArrayBuffer;
+}
+
+declare global {
+  // eslint-disable-next-line no-var
+  var __libsilver: NativeLibsilver | undefined;
+}
+
+const LINKING_ERROR =
+  `The package 'libsilver-react-native' doesn't seem to be linked. Make sure: \n\n` +
+  Platform.select({ ios: "- You have run 'pod install'\n", default: '' }) +
+  '- You rebuilt the app after installing the package\n' +
+  '- You are not using Expo Go\n';
+
+let native: NativeLibsilver | undefined;
+
+// Install the JSI host object on first use; the native module only exposes `install()`
+function libsilver(): NativeLibsilver {
+  if (native) {
+    return native;
+  }
+  if (global.__libsilver == null) {
+    const module = NativeModules.Libsilver;
+    if (module == null) {
+      throw new Error(LINKING_ERROR);
+    }
+    if (module.install() !== true || global.__libsilver == null) {
+      throw new Error('libsilver: failed to install JSI bindings (remote debugging is not supported)');
+    }
+  }
+  native = global.__libsilver;
+  return native;
+}
+
+// Hand the native side an ArrayBuffer covering exactly the view's bytes, copying only when needed
+function toArrayBuffer(data: BinaryLike): ArrayBuffer {
+  if (data instanceof ArrayBuffer) {
+    return data;
+  }
+  if (data.byteOffset === 0 && data.byteLength === data.buffer.byteLength && data.buffer instanceof ArrayBuffer) {
+    return data.buffer;
+  }
+  return data.slice().buffer as ArrayBuffer;
+}
+
+function optional(data: BinaryLike | undefined): ArrayBuffer | undefined {
+  return data === undefined ? undefined : toArrayBuffer(data);
+}
+
+/** Encode a string as UTF-8 bytes */
+export function utf8(text: string): Uint8Array {
+  return new TextEncoder().encode(text);
+}
+
+/** Version of the native libsilver library */
+export function version(): string {
+  return libsilver().version();
+}
+
+/** AES-256-GCM and ChaCha20-Poly1305 authenticated encryption */
+export const SymmetricCrypto = {
+  /** Generate a random 256-bit AES key */
+  generateAesKey(): Uint8Array {
+    return RandomGenerator.generateKey();
+  },
+
+  /** Generate a random 256-bit ChaCha20 key */
+  generateChaCha20Key(): Uint8Array {
+    return RandomGenerator.generateKey();
+  },
+
+  /** Encrypt with AES-256-GCM; the output is nonce || ciphertext || tag */
+  encryptAes(plaintext: BinaryLike, key: BinaryLike, aad?: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().aesGcmEncrypt(toArrayBuffer(plaintext), toArrayBuffer(key), optional(aad)));
+  },
+
+  /** Decrypt AES-256-GCM output from `encryptAes` */
+  decryptAes(ciphertext: BinaryLike, key: BinaryLike, aad?: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().aesGcmDecrypt(toArrayBuffer(ciphertext), toArrayBuffer(key), optional(aad)));
+  },
+
+  /** Encrypt with ChaCha20-Poly1305; the output is nonce || ciphertext || tag */
+  encryptChaCha20(plaintext: BinaryLike, key: BinaryLike, aad?: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().chacha20Poly1305Encrypt(toArrayBuffer(plaintext), toArrayBuffer(key), optional(aad)));
+  },
+
+  /** Decrypt ChaCha20-Poly1305 output from `encryptChaCha20` */
+  decryptChaCha20(ciphertext: BinaryLike, key: BinaryLike, aad?: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().chacha20Poly1305Decrypt(toArrayBuffer(ciphertext), toArrayBuffer(key), optional(aad)));
+  },
+};
+
+/** Hashes, MACs and constant-time comparison */
+export const HashFunctions = {
+  sha256(data: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().sha256(toArrayBuffer(data)));
+  },
+
+  sha512(data: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().sha512(toArrayBuffer(data)));
+  },
+
+  sha3_256(data: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().sha3_256(toArrayBuffer(data)));
+  },
+
+  blake3(data: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().blake3(toArrayBuffer(data)));
+  },
+
+  hmacSha256(key: BinaryLike, message: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().hmacSha256(toArrayBuffer(key), toArrayBuffer(message)));
+  },
+
+  hmacSha512(key: BinaryLike, message: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().hmacSha512(toArrayBuffer(key), toArrayBuffer(message)));
+  },
+
+  /** Compare two byte strings in constant time */
+  constantTimeEqual(a: BinaryLike, b: BinaryLike): boolean {
+    return libsilver().constantTimeEqual(toArrayBuffer(a), toArrayBuffer(b));
+  },
+};
+
+/** Ed25519 signatures */
+export const AsymmetricCrypto = {
+  generateEd25519Keypair(): Ed25519KeyPair {
+    const { privateKey, publicKey } = libsilver().ed25519GenerateKeypair();
+    return { privateKey: new Uint8Array(privateKey), publicKey: new Uint8Array(publicKey) };
+  },
+
+  signEd25519(privateKey: BinaryLike, message: BinaryLike): Uint8Array {
+    return new Uint8Array(libsilver().ed25519Sign(toArrayBuffer(privateKey), toArrayBuffer(message)));
+  },
+
+  /** Returns false for a signature that does not match; throws for malformed keys */
+  verifyEd25519(publicKey: BinaryLike, message: BinaryLike, signature: BinaryLike): boolean {
+    return libsilver().ed25519Verify(toArrayBuffer(publicKey), toArrayBuffer(message), toArrayBuffer(signature));
+  },
+};
+
+/** Password hashing and key derivation */
+export const KeyDerivation = {
+  /** Argon2id with the library defaults; runs synchronously on the JS thread */
+  argon2(password: BinaryLike, salt: BinaryLike, length = 32): Uint8Array {
+    return new Uint8Array(libsilver().argon2id(toArrayBuffer(password), toArrayBuffer(salt), length));
+  },
+
+  pbkdf2(password: BinaryLike, salt: BinaryLike, iterations: number, length = 32): Uint8Array {
+    return new Uint8Array(libsilver().pbkdf2Sha256(toArrayBuffer(password), toArrayBuffer(salt), iterations, length));
+  },
+
+  /** HKDF-SHA256; omit `salt` for the RFC 5869 default */
+  hkdf(inputKey: BinaryLike, salt: BinaryLike | undefined, info: BinaryLike | undefined, length = 32): Uint8Array {
+    return new Uint8Array(libsilver().hkdfSha256(toArrayBuffer(inputKey), optional(salt), optional(info), length));
+  },
+};
+
+/** Cryptographically secure random bytes */
+export const RandomGenerator = {
+  generateBytes(length: number): Uint8Array {
+    return new Uint8Array(libsilver().randomBytes(length));
+  },
+
+  /** Random 256-bit key */
+  generateKey(): Uint8Array {
+    return RandomGenerator.generateBytes(32);
+  },
+
+  /** Random 32-byte salt */
+  generateSalt(): Uint8Array {
+    return RandomGenerator.generateBytes(32);
+  },
+};
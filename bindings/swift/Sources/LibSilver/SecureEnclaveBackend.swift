@@ -0,0 +1,139 @@
+import Foundation
+import Security
+
+// MARK: - Secure Enclave Backend
+
+/// Secure Enclave implementation of the UniFFI `PlatformKeystore` callback interface
+///
+/// P-256 keys are generated inside the Secure Enclave and can only be used for signing
+/// and ECDH; the private key material never leaves the hardware. Signatures are returned
+/// as X9.62 DER and public keys as SEC1, which the Rust `HardwareKeyProvider` normalizes
+/// to the same formats as software keys.
+///
+///     let key = try HardwareKey.generate(keystore: SecureEnclaveBackend(), alias: "device-key")
+///     let signature = try key.sign(message: message)
+@available(iOS 13.0, macOS 10.15, *)
+public final class SecureEnclaveBackend: PlatformKeystore {
+    private let accessControlFlags: SecAccessControlCreateFlags
+
+    /// Create a backend; pass `.userPresence` or `.biometryAny` to gate key use on authentication
+    public init(accessControlFlags: SecAccessControlCreateFlags = .privateKeyUsage) {
+        self.accessControlFlags = accessControlFlags.union(.privateKeyUsage)
+    }
+
+    /// Generate a new non-exportable P-256 key under `alias` and return its public key
+    public func generate(alias: String) throws -> Data {
+        var error: Unmanaged<CFError>?
+        guard let access = SecAccessControlCreateWithFlags(
+            kCFAllocatorDefault,
+            kSecAttrAccessibleWhenUnlockedThisDeviceOnly,
+            accessControlFlags,
+            &error
+        ) else {
+            throw Self.error(error)
+        }
+
+        let attributes: [String: Any] = [
+            kSecAttrKeyType as String: kSecAttrKeyTypeECSECPrimeRandom,
+            kSecAttrKeySizeInBits as String: 256,
+            kSecAttrTokenID as String: kSecAttrTokenIDSecureEnclave,
+            kSecPrivateKeyAttrs as String: [
+                kSecAttrIsPermanent as String: true,
+                kSecAttrApplicationTag as String: Self.tag(alias),
+                kSecAttrAccessControl as String: access,
+            ],
+        ]
+
+        guard let privateKey = SecKeyCreateRandomKey(attributes as CFDictionary, &error) else {
+            throw Self.error(error)
+        }
+        return try Self.publicKeyData(privateKey)
+    }
+
+    /// Get the public key stored under `alias`
+    public func publicKey(alias: String) throws -> Data {
+        try Self.publicKeyData(try privateKey(alias))
+    }
+
+    /// Sign `message` with ECDSA P-256 / SHA-256
+    public func sign(alias: String, message: Data) throws -> Data {
+        var error: Unmanaged<CFError>?
+        guard let signature = SecKeyCreateSignature(
+            try privateKey(alias),
+            .ecdsaSignatureMessageX962SHA256,
+            message as CFData,
+            &error
+        ) else {
+            throw Self.error(error)
+        }
+        return signature as Data
+    }
+
+    /// Perform ECDH with an uncompressed SEC1 peer public key
+    public func agree(alias: String, peerPublicKey: Data) throws -> Data {
+        var error: Unmanaged<CFError>?
+        let peerAttributes: [String: Any] = [
+            kSecAttrKeyType as String: kSecAttrKeyTypeECSECPrimeRandom,
+            kSecAttrKeyClass as String: kSecAttrKeyClassPublic,
+        ]
+        guard let peer = SecKeyCreateWithData(peerPublicKey as CFData, peerAttributes as CFDictionary, &error) else {
+            throw Self.error(error)
+        }
+
+        guard let shared = SecKeyCopyKeyExchangeResult(
+            try privateKey(alias),
+            .ecdhKeyExchangeStandard,
+            peer,
+            [:] as CFDictionary,
+            &error
+        ) else {
+            throw Self.error(error)
+        }
+        return shared as Data
+    }
+
+    /// Delete the key stored under `alias`
+    public func delete(alias: String) throws {
+        let query: [String: Any] = [
+            kSecClass as String: kSecClassKey,
+            kSecAttrApplicationTag as String: Self.tag(alias),
+        ]
+        let status = SecItemDelete(query as CFDictionary)
+        guard status == errSecSuccess else {
+            throw KeystoreError.NotFound(message: "Hardware key not found: \(alias)")
+        }
+    }
+
+    private func privateKey(_ alias: String) throws -> SecKey {
+        let query: [String: Any] = [
+            kSecClass as String: kSecClassKey,
+            kSecAttrApplicationTag as String: Self.tag(alias),
+            kSecAttrKeyType as String: kSecAttrKeyTypeECSECPrimeRandom,
+            kSecReturnRef as String: true,
+        ]
+
+        var item: CFTypeRef?
+        guard SecItemCopyMatching(query as CFDictionary, &item) == errSecSuccess, let key = item else {
+            throw KeystoreError.NotFound(message: "Hardware key not found: \(alias)")
+        }
+        return key as! SecKey
+    }
+
+    private static func publicKeyData(_ privateKey: SecKey) throws -> Data {
+        var error: Unmanaged<CFError>?
+        guard let publicKey = SecKeyCopyPublicKey(privateKey),
+              let data = SecKeyCopyExternalRepresentation(publicKey, &error) else {
+            throw Self.error(error)
+        }
+        return data as Data
+    }
+
+    private static func tag(_ alias: String) -> Data {
+        Data("com.libsilver.hardware.\(alias)".utf8)
+    }
+
+    private static func error(_ error: Unmanaged<CFError>?) -> KeystoreError {
+        let message = error.map { $0.takeRetainedValue().localizedDescription } ?? "Secure Enclave operation failed"
+        return .Platform(message: message)
+    }
+}
@@ -0,0 +1,49 @@
+import CryptoKit
+import Foundation
+import XCTest
+@testable import LibSilver
+
+/// Drives the Secure Enclave through the Rust `HardwareKeyProvider` via the UniFFI callback interface
+@available(iOS 14.0, macOS 11.0, *)
+final class SecureEnclaveBackendTests: XCTestCase {
+    private let alias = "libsilver-test-\(UUID().uuidString)"
+
+    override func setUpWithError() throws {
+        try XCTSkipUnless(SecureEnclave.isAvailable, "Secure Enclave not available")
+    }
+
+    override func tearDown() {
+        try? SecureEnclaveBackend().delete(alias: alias)
+    }
+
+    func testSignThroughHardwareKey() throws {
+        let key = try HardwareKey.generate(keystore: SecureEnclaveBackend(), alias: alias)
+        let message = Data("attestation".utf8)
+
+        // Rust hands back r || s and an uncompressed SEC1 point, whatever the enclave produced
+        let signature = try key.sign(message: message)
+        XCTAssertEqual(signature.count, 64)
+
+        let publicKey = try P256.Signing.PublicKey(x963Representation: try key.publicKey())
+        let ecdsa = try P256.Signing.ECDSASignature(rawRepresentation: signature)
+        XCTAssertTrue(publicKey.isValidSignature(ecdsa, for: message))
+    }
+
+    func testEcdhThroughHardwareKey() throws {
+        let key = try HardwareKey.generate(keystore: SecureEnclaveBackend(), alias: alias)
+        let peer = P256.KeyAgreement.PrivateKey()
+
+        let shared = try key.ecdh(peerPublicKey: peer.publicKey.x963Representation)
+        let devicePublic = try P256.KeyAgreement.PublicKey(x963Representation: try key.publicKey())
+        let expected = try peer.sharedSecretFromKeyAgreement(with: devicePublic)
+        XCTAssertEqual(shared, expected.withUnsafeBytes { Data($0) })
+    }
+
+    func testMissingKeyIsNotFound() {
+        XCTAssertThrowsError(try HardwareKey.open(keystore: SecureEnclaveBackend(), alias: alias)) { error in
+            guard case KeystoreError.NotFound = error else {
+                return XCTFail("unexpected error \(error)")
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+package libsilver_test
+
+import (
+	"fmt"
+	"log"
+
+	libsilver "github.com/DangVTNhan/libsilver/contrib/go"
+)
+
+func Example() {
+	key, err := libsilver.GenerateKey()
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	ciphertext, err := libsilver.AESGCMEncrypt(key, []byte("card number"), []byte("user:42"))
+	if err != nil {
+		log.Fatal(err)
+	}
+	plaintext, err := libsilver.AESGCMDecrypt(key, ciphertext, []byte("user:42"))
+	if err != nil {
+		log.Fatal(err)
+	}
+	fmt.Println(string(plaintext))
+	// Output: card number
+}
+
+func ExampleEd25519Verify() {
+	privateKey, publicKey, err := libsilver.Ed25519GenerateKey()
+	if err != nil {
+		log.Fatal(err)
+	}
+	signature, err := libsilver.Ed25519Sign(privateKey, []byte("release v1.2.0"))
+	if err != nil {
+		log.Fatal(err)
+	}
+
+	ok, err := libsilver.Ed25519Verify(publicKey, []byte("release v1.2.0"), signature)
+	fmt.Println(ok, err)
+	ok, err = libsilver.Ed25519Verify(publicKey, []byte("release v6.6.6"), signature)
+	fmt.Println(ok, err)
+	// Output:
+	// true <nil>
+	// false <nil>
+}
+
+func ExampleArgon2HashPassword() {
+	hash, err := libsilver.Argon2HashPassword([]byte("hunter2"))
+	if err != nil {
+		log.Fatal(err)
+	}
+	// Store hash; later, at login:
+	ok, err := libsilver.Argon2VerifyPassword([]byte("hunter2"), hash)
+	fmt.Println(ok, err)
+	// Output: true <nil>
+}
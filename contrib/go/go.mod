@@ -0,0 +1,3 @@
+module github.com/DangVTNhan/libsilver/contrib/go
+
+go 1.21
@@ -0,0 +1,442 @@
+// Package libsilver wraps the libsilver C ABI for Go services.
+//
+// Build the C library before using the package:
+//
+//	cargo build --release --manifest-path bindings/c/Cargo.toml
+//
+// Every function takes and returns plain byte slices; no memory is shared
+// with the library after a call returns. Output formats are the same as the
+// Rust core and the other bindings, so a ciphertext sealed here can be opened
+// from Node.js, .NET or Rust and vice versa.
+package libsilver
+
+/*
+#cgo CFLAGS: -I${SRCDIR}/../../bindings/c/include
+#cgo LDFLAGS: -L${SRCDIR}/../../bindings/c/target/release -lsilver
+#cgo linux darwin LDFLAGS: -Wl,-rpath,${SRCDIR}/../../bindings/c/target/release
+#include "libsilver.h"
+*/
+import "C"
+
+import (
+	"errors"
+	"runtime"
+	"unsafe"
+)
+
+// Sizes shared with the C header.
+const (
+	SymmetricKeySize       = C.LIBSILVER_SYMMETRIC_KEY_SIZE
+	AEADOverhead           = C.LIBSILVER_AEAD_OVERHEAD
+	Ed25519KeySize         = C.LIBSILVER_ED25519_KEY_SIZE
+	Ed25519SignatureSize   = C.LIBSILVER_ED25519_SIGNATURE_SIZE
+	ECDSAP256SignatureSize = 64
+)
+
+// Status is a libsilver_status code.
+type Status int
+
+// Status codes returned by the C ABI.
+const (
+	StatusOK                     Status = C.LIBSILVER_STATUS_OK
+	StatusNullPointer            Status = C.LIBSILVER_STATUS_NULL_POINTER
+	StatusBufferTooSmall         Status = C.LIBSILVER_STATUS_BUFFER_TOO_SMALL
+	StatusInvalidInput           Status = C.LIBSILVER_STATUS_INVALID_INPUT
+	StatusInvalidKey             Status = C.LIBSILVER_STATUS_INVALID_KEY
+	StatusEncryptionFailed       Status = C.LIBSILVER_STATUS_ENCRYPTION_FAILED
+	StatusDecryptionFailed       Status = C.LIBSILVER_STATUS_DECRYPTION_FAILED
+	StatusKeyGenerationFailed    Status = C.LIBSILVER_STATUS_KEY_GENERATION_FAILED
+	StatusSignatureFailed        Status = C.LIBSILVER_STATUS_SIGNATURE_FAILED
+	StatusVerificationFailed     Status = C.LIBSILVER_STATUS_VERIFICATION_FAILED
+	StatusHashFailed             Status = C.LIBSILVER_STATUS_HASH_FAILED
+	StatusKeyDerivationFailed    Status = C.LIBSILVER_STATUS_KEY_DERIVATION_FAILED
+	StatusRandomGenerationFailed Status = C.LIBSILVER_STATUS_RANDOM_GENERATION_FAILED
+	StatusEncodingFailed         Status = C.LIBSILVER_STATUS_ENCODING_FAILED
+	StatusIOFailed               Status = C.LIBSILVER_STATUS_IO_FAILED
+	StatusInternalError          Status = C.LIBSILVER_STATUS_INTERNAL_ERROR
+)
+
+// String returns the library's static description of the status.
+func (s Status) String() string {
+	return C.GoString(C.libsilver_status_message(C.libsilver_status(s)))
+}
+
+// Error is a failed libsilver call.
+type Error struct {
+	Status  Status
+	Message string
+}
+
+func (e *Error) Error() string {
+	return "libsilver: " + e.Message
+}
+
+// Is reports whether target is an *Error with the same status, so
+// errors.Is(err, &Error{Status: StatusDecryptionFailed}) works.
+func (e *Error) Is(target error) bool {
+	t, ok := target.(*Error)
+	return ok && t.Status == e.Status
+}
+
+// Version returns the version of the linked library.
+func Version() string {
+	return C.GoString(C.libsilver_version())
+}
+
+// call runs fn and converts a failed status into an *Error.
+//
+// The detailed message is thread-local in the library, so the goroutine is
+// locked to its OS thread until the message has been read.
+func call(fn func() C.libsilver_status) error {
+	runtime.LockOSThread()
+	defer runtime.UnlockOSThread()
+	status := Status(fn())
+	if status == StatusOK {
+		return nil
+	}
+	message := status.String()
+	if detail := C.libsilver_last_error_message(); detail != nil {
+		message = C.GoString(detail)
+	}
+	return &Error{Status: status, Message: message}
+}
+
+// check is call for functions whose only failure is a verification failure
+// of the inputs: that failure becomes false, any other error is returned.
+func check(fn func() C.libsilver_status) (bool, error) {
+	err := call(fn)
+	var e *Error
+	if errors.As(err, &e) && e.Status == StatusVerificationFailed {
+		return false, nil
+	}
+	return err == nil, err
+}
+
+// present backs non-nil empty optional inputs, which must not be NULL.
+var present [1]byte
+
+// in returns a pointer to the first byte of b and its length; an empty slice
+// becomes (NULL, 0).
+func in(b []byte) (*C.uint8_t, C.size_t) {
+	if len(b) == 0 {
+		return nil, 0
+	}
+	return (*C.uint8_t)(unsafe.Pointer(&b[0])), C.size_t(len(b))
+}
+
+// optional is in for optional inputs: nil is absent, a non-nil empty slice
+// is present and empty.
+func optional(b []byte) (*C.uint8_t, C.size_t) {
+	if b == nil {
+		return nil, 0
+	}
+	if len(b) == 0 {
+		return (*C.uint8_t)(unsafe.Pointer(&present[0])), 0
+	}
+	return in(b)
+}
+
+// variable collects variable-length output. capacity is a first guess; if the
+// library reports BUFFER_TOO_SMALL the call is repeated once with the
+// required size.
+func variable(capacity int, fn func(out *C.uint8_t, outLen *C.size_t) C.libsilver_status) ([]byte, error) {
+	buf := make([]byte, max(capacity, 1))
+	outLen := C.size_t(len(buf))
+	err := call(func() C.libsilver_status {
+		return fn((*C.uint8_t)(unsafe.Pointer(&buf[0])), &outLen)
+	})
+	var e *Error
+	if errors.As(err, &e) && e.Status == StatusBufferTooSmall {
+		buf = make([]byte, max(int(outLen), 1))
+		outLen = C.size_t(len(buf))
+		err = call(func() C.libsilver_status {
+			return fn((*C.uint8_t)(unsafe.Pointer(&buf[0])), &outLen)
+		})
+	}
+	if err != nil {
+		return nil, err
+	}
+	return buf[:outLen], nil
+}
+
+// fixed collects exactly length bytes of output.
+func fixed(length int, fn func(out *C.uint8_t, outLen C.size_t) C.libsilver_status) ([]byte, error) {
+	if length < 0 {
+		return nil, &Error{Status: StatusInvalidInput, Message: "negative output length"}
+	}
+	buf := make([]byte, max(length, 1))
+	if err := call(func() C.libsilver_status {
+		return fn((*C.uint8_t)(unsafe.Pointer(&buf[0])), C.size_t(length))
+	}); err != nil {
+		return nil, err
+	}
+	return buf[:length], nil
+}
+
+// RandomBytes returns n cryptographically secure random bytes.
+func RandomBytes(n int) ([]byte, error) {
+	return fixed(n, func(out *C.uint8_t, outLen C.size_t) C.libsilver_status {
+		return C.libsilver_random_bytes(out, outLen)
+	})
+}
+
+// GenerateKey returns a random SymmetricKeySize-byte key for AES-GCM or
+// ChaCha20-Poly1305.
+func GenerateKey() ([]byte, error) {
+	return RandomBytes(SymmetricKeySize)
+}
+
+// ConstantTimeEqual compares a and b in constant time; different lengths
+// compare unequal.
+func ConstantTimeEqual(a, b []byte) bool {
+	aPtr, aLen := in(a)
+	bPtr, bLen := in(b)
+	return bool(C.libsilver_constant_time_eq(aPtr, aLen, bPtr, bLen))
+}
+
+type aeadFunc func(key *C.uint8_t, keyLen C.size_t, data *C.uint8_t, dataLen C.size_t, aad *C.uint8_t, aadLen C.size_t, out *C.uint8_t, outLen *C.size_t) C.libsilver_status
+
+func aead(fn aeadFunc, key, data, aad []byte, capacity int) ([]byte, error) {
+	keyPtr, keyLen := in(key)
+	dataPtr, dataLen := in(data)
+	aadPtr, aadLen := optional(aad)
+	return variable(capacity, func(out *C.uint8_t, outLen *C.size_t) C.libsilver_status {
+		return fn(keyPtr, keyLen, dataPtr, dataLen, aadPtr, aadLen, out, outLen)
+	})
+}
+
+// AESGCMEncrypt encrypts with AES-256-GCM and returns nonce || ciphertext || tag.
+// aad may be nil.
+func AESGCMEncrypt(key, plaintext, aad []byte) ([]byte, error) {
+	return aead(func(k *C.uint8_t, kl C.size_t, d *C.uint8_t, dl C.size_t, a *C.uint8_t, al C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_aes_gcm_encrypt(k, kl, d, dl, a, al, o, ol)
+	}, key, plaintext, aad, len(plaintext)+AEADOverhead)
+}
+
+// AESGCMDecrypt decrypts nonce || ciphertext || tag produced by AESGCMEncrypt.
+func AESGCMDecrypt(key, ciphertext, aad []byte) ([]byte, error) {
+	return aead(func(k *C.uint8_t, kl C.size_t, d *C.uint8_t, dl C.size_t, a *C.uint8_t, al C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_aes_gcm_decrypt(k, kl, d, dl, a, al, o, ol)
+	}, key, ciphertext, aad, len(ciphertext))
+}
+
+// ChaCha20Poly1305Encrypt encrypts with ChaCha20-Poly1305 and returns
+// nonce || ciphertext || tag. aad may be nil.
+func ChaCha20Poly1305Encrypt(key, plaintext, aad []byte) ([]byte, error) {
+	return aead(func(k *C.uint8_t, kl C.size_t, d *C.uint8_t, dl C.size_t, a *C.uint8_t, al C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_chacha20_poly1305_encrypt(k, kl, d, dl, a, al, o, ol)
+	}, key, plaintext, aad, len(plaintext)+AEADOverhead)
+}
+
+// ChaCha20Poly1305Decrypt decrypts nonce || ciphertext || tag produced by
+// ChaCha20Poly1305Encrypt.
+func ChaCha20Poly1305Decrypt(key, ciphertext, aad []byte) ([]byte, error) {
+	return aead(func(k *C.uint8_t, kl C.size_t, d *C.uint8_t, dl C.size_t, a *C.uint8_t, al C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_chacha20_poly1305_decrypt(k, kl, d, dl, a, al, o, ol)
+	}, key, ciphertext, aad, len(ciphertext))
+}
+
+type hashFunc func(data *C.uint8_t, dataLen C.size_t, out *C.uint8_t, outLen *C.size_t) C.libsilver_status
+
+func hash(fn hashFunc, data []byte, size int) ([]byte, error) {
+	dataPtr, dataLen := in(data)
+	return variable(size, func(out *C.uint8_t, outLen *C.size_t) C.libsilver_status {
+		return fn(dataPtr, dataLen, out, outLen)
+	})
+}
+
+// SHA256 returns the 32-byte SHA-256 digest of data.
+func SHA256(data []byte) ([]byte, error) {
+	return hash(func(d *C.uint8_t, dl C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_sha256(d, dl, o, ol)
+	}, data, 32)
+}
+
+// SHA512 returns the 64-byte SHA-512 digest of data.
+func SHA512(data []byte) ([]byte, error) {
+	return hash(func(d *C.uint8_t, dl C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_sha512(d, dl, o, ol)
+	}, data, 64)
+}
+
+// SHA3_256 returns the 32-byte SHA3-256 digest of data.
+func SHA3_256(data []byte) ([]byte, error) {
+	return hash(func(d *C.uint8_t, dl C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_sha3_256(d, dl, o, ol)
+	}, data, 32)
+}
+
+// SHA3_512 returns the 64-byte SHA3-512 digest of data.
+func SHA3_512(data []byte) ([]byte, error) {
+	return hash(func(d *C.uint8_t, dl C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_sha3_512(d, dl, o, ol)
+	}, data, 64)
+}
+
+// BLAKE3 returns the 32-byte BLAKE3 digest of data.
+func BLAKE3(data []byte) ([]byte, error) {
+	return hash(func(d *C.uint8_t, dl C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_blake3(d, dl, o, ol)
+	}, data, 32)
+}
+
+type macFunc func(key *C.uint8_t, keyLen C.size_t, message *C.uint8_t, messageLen C.size_t, out *C.uint8_t, outLen *C.size_t) C.libsilver_status
+
+func mac(fn macFunc, key, message []byte, size int) ([]byte, error) {
+	keyPtr, keyLen := in(key)
+	messagePtr, messageLen := in(message)
+	return variable(size, func(out *C.uint8_t, outLen *C.size_t) C.libsilver_status {
+		return fn(keyPtr, keyLen, messagePtr, messageLen, out, outLen)
+	})
+}
+
+// HMACSHA256 returns the 32-byte HMAC-SHA256 of message.
+func HMACSHA256(key, message []byte) ([]byte, error) {
+	return mac(func(k *C.uint8_t, kl C.size_t, m *C.uint8_t, ml C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_hmac_sha256(k, kl, m, ml, o, ol)
+	}, key, message, 32)
+}
+
+// HMACSHA512 returns the 64-byte HMAC-SHA512 of message.
+func HMACSHA512(key, message []byte) ([]byte, error) {
+	return mac(func(k *C.uint8_t, kl C.size_t, m *C.uint8_t, ml C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_hmac_sha512(k, kl, m, ml, o, ol)
+	}, key, message, 64)
+}
+
+// Ed25519GenerateKey returns a new 32-byte Ed25519 private key and its
+// public key.
+func Ed25519GenerateKey() (privateKey, publicKey []byte, err error) {
+	privateKey = make([]byte, Ed25519KeySize)
+	publicKey = make([]byte, Ed25519KeySize)
+	err = call(func() C.libsilver_status {
+		return C.libsilver_ed25519_generate_keypair((*C.uint8_t)(unsafe.Pointer(&privateKey[0])), (*C.uint8_t)(unsafe.Pointer(&publicKey[0])))
+	})
+	if err != nil {
+		return nil, nil, err
+	}
+	return privateKey, publicKey, nil
+}
+
+// Ed25519PublicKey returns the public key of a 32-byte Ed25519 private key.
+func Ed25519PublicKey(privateKey []byte) ([]byte, error) {
+	keyPtr, keyLen := in(privateKey)
+	return fixed(Ed25519KeySize, func(out *C.uint8_t, _ C.size_t) C.libsilver_status {
+		return C.libsilver_ed25519_public_key(keyPtr, keyLen, out)
+	})
+}
+
+type verifyFunc func(key *C.uint8_t, keyLen C.size_t, message *C.uint8_t, messageLen C.size_t, signature *C.uint8_t, signatureLen C.size_t) C.libsilver_status
+
+func verify(fn verifyFunc, publicKey, message, signature []byte) (bool, error) {
+	keyPtr, keyLen := in(publicKey)
+	messagePtr, messageLen := in(message)
+	signaturePtr, signatureLen := in(signature)
+	return check(func() C.libsilver_status {
+		return fn(keyPtr, keyLen, messagePtr, messageLen, signaturePtr, signatureLen)
+	})
+}
+
+// Ed25519Sign signs message with a 32-byte Ed25519 private key.
+func Ed25519Sign(privateKey, message []byte) ([]byte, error) {
+	return mac(func(k *C.uint8_t, kl C.size_t, m *C.uint8_t, ml C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_ed25519_sign(k, kl, m, ml, o, ol)
+	}, privateKey, message, Ed25519SignatureSize)
+}
+
+// Ed25519Verify reports whether signature is a valid signature of message.
+// A malformed key or signature is an error, a wrong signature is false.
+func Ed25519Verify(publicKey, message, signature []byte) (bool, error) {
+	return verify(func(k *C.uint8_t, kl C.size_t, m *C.uint8_t, ml C.size_t, s *C.uint8_t, sl C.size_t) C.libsilver_status {
+		return C.libsilver_ed25519_verify(k, kl, m, ml, s, sl)
+	}, publicKey, message, signature)
+}
+
+// ECDSAP256Sign signs message with a 32-byte ECDSA P-256 private key and
+// returns the 64-byte r || s signature.
+func ECDSAP256Sign(privateKey, message []byte) ([]byte, error) {
+	return mac(func(k *C.uint8_t, kl C.size_t, m *C.uint8_t, ml C.size_t, o *C.uint8_t, ol *C.size_t) C.libsilver_status {
+		return C.libsilver_ecdsa_p256_sign(k, kl, m, ml, o, ol)
+	}, privateKey, message, ECDSAP256SignatureSize)
+}
+
+// ECDSAP256Verify reports whether signature is a valid r || s signature of
+// message for a SEC1-encoded public key.
+func ECDSAP256Verify(publicKey, message, signature []byte) (bool, error) {
+	return verify(func(k *C.uint8_t, kl C.size_t, m *C.uint8_t, ml C.size_t, s *C.uint8_t, sl C.size_t) C.libsilver_status {
+		return C.libsilver_ecdsa_p256_verify(k, kl, m, ml, s, sl)
+	}, publicKey, message, signature)
+}
+
+// GenerateRSAKeyPair generates an RSA key pair and returns the PKCS#8
+// private key and SPKI public key as PEM.
+func GenerateRSAKeyPair(bits int) (privatePEM, publicPEM string, err error) {
+	if bits <= 0 {
+		return "", "", &Error{Status: StatusInvalidInput, Message: "RSA key size must be positive"}
+	}
+	size := int(C.libsilver_rsa_pem_max_size(C.size_t(bits)))
+	private := make([]byte, size)
+	public := make([]byte, size)
+	privateLen := C.size_t(size)
+	publicLen := C.size_t(size)
+	err = call(func() C.libsilver_status {
+		return C.libsilver_rsa_generate_keypair(C.size_t(bits),
+			(*C.uint8_t)(unsafe.Pointer(&private[0])), &privateLen,
+			(*C.uint8_t)(unsafe.Pointer(&public[0])), &publicLen)
+	})
+	if err != nil {
+		return "", "", err
+	}
+	return string(private[:privateLen]), string(public[:publicLen]), nil
+}
+
+// Argon2id derives length bytes from password and salt with the libsilver
+// default Argon2id cost.
+func Argon2id(password, salt []byte, length int) ([]byte, error) {
+	passwordPtr, passwordLen := in(password)
+	saltPtr, saltLen := in(salt)
+	return fixed(length, func(out *C.uint8_t, outLen C.size_t) C.libsilver_status {
+		return C.libsilver_argon2id(passwordPtr, passwordLen, saltPtr, saltLen, out, outLen)
+	})
+}
+
+// Argon2HashPassword hashes password into a PHC string for storage.
+func Argon2HashPassword(password []byte) (string, error) {
+	passwordPtr, passwordLen := in(password)
+	hash, err := variable(128, func(out *C.uint8_t, outLen *C.size_t) C.libsilver_status {
+		return C.libsilver_argon2_hash_password(passwordPtr, passwordLen, out, outLen)
+	})
+	return string(hash), err
+}
+
+// Argon2VerifyPassword reports whether password matches a PHC string from
+// Argon2HashPassword. A malformed hash is an error, a wrong password is false.
+func Argon2VerifyPassword(password []byte, hash string) (bool, error) {
+	passwordPtr, passwordLen := in(password)
+	hashBytes := []byte(hash)
+	hashPtr, hashLen := in(hashBytes)
+	return check(func() C.libsilver_status {
+		return C.libsilver_argon2_verify_password(passwordPtr, passwordLen, hashPtr, hashLen)
+	})
+}
+
+// PBKDF2SHA256 derives length bytes from password and salt with
+// PBKDF2-HMAC-SHA256.
+func PBKDF2SHA256(password, salt []byte, iterations uint32, length int) ([]byte, error) {
+	passwordPtr, passwordLen := in(password)
+	saltPtr, saltLen := in(salt)
+	return fixed(length, func(out *C.uint8_t, outLen C.size_t) C.libsilver_status {
+		return C.libsilver_pbkdf2_sha256(passwordPtr, passwordLen, saltPtr, saltLen, C.uint32_t(iterations), out, outLen)
+	})
+}
+
+// HKDFSHA256 derives length bytes from inputKey with HKDF-SHA256. A nil salt
+// uses the RFC 5869 default.
+func HKDFSHA256(inputKey, salt, info []byte, length int) ([]byte, error) {
+	keyPtr, keyLen := in(inputKey)
+	saltPtr, saltLen := optional(salt)
+	infoPtr, infoLen := in(info)
+	return fixed(length, func(out *C.uint8_t, outLen C.size_t) C.libsilver_status {
+		return C.libsilver_hkdf_sha256(keyPtr, keyLen, saltPtr, saltLen, infoPtr, infoLen, out, outLen)
+	})
+}
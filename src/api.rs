@@ -0,0 +1,276 @@
+//! Single-source definition of the byte-level binding surface
+//!
+//! Every operation the language bindings expose over plain bytes is declared
+//! once, in [`for_each_api!`](crate::for_each_api). The declaration produces
+//! the Rust facade the bindings call (`libsilver::api::aes_gcm_encrypt`, ...)
+//! and [`FUNCTIONS`], a description of each signature. Bindings either expand
+//! the declarations with their own `macro_rules!` callback (N-API) or render
+//! [`FUNCTIONS`] into checked-in sources that their tests keep current (the C
+//! ABI, whose header cbindgen reads from plain source). Adding an entry is
+//! enough to expose it everywhere with identical parameters and semantics.
+//!
+//! Parameter and return kinds are deliberately few so that every target can
+//! map them; see [`ApiType`].
+
+use crate::error::CryptoResult;
+use zeroize::Zeroizing;
+
+/// Kind of a parameter or return value in the binding surface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiType {
+    /// Byte string (`&[u8]`)
+    Bytes,
+    /// Optional byte string (`Option<&[u8]>`); targets must keep absent distinct from empty
+    OptionalBytes,
+    /// UTF-8 string (`&str` / `String`)
+    Str,
+    /// Unsigned 32-bit parameter such as an iteration count
+    U32,
+    /// Requested output length in bytes; targets with caller-provided buffers take it from the buffer
+    OutputLength,
+    /// Byte string holding secret material (`Zeroizing<Vec<u8>>`)
+    SecretBytes,
+    /// Boolean result of a check; targets with status codes report `false` as a verification failure
+    Bool,
+}
+
+/// A named parameter of an [`ApiFunction`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiParam {
+    pub name: &'static str,
+    pub ty: ApiType,
+}
+
+/// Signature of one function of the binding surface
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ApiFunction {
+    /// snake_case name, also the name of the Rust facade function
+    pub name: &'static str,
+    /// One-line description shared by every binding
+    pub doc: &'static str,
+    pub params: &'static [ApiParam],
+    pub returns: ApiType,
+}
+
+impl ApiFunction {
+    /// Look up a function by name
+    pub fn find(name: &str) -> Option<&'static ApiFunction> {
+        FUNCTIONS.iter().find(|function| function.name == name)
+    }
+}
+
+/// Invoke `$callback! { ... }` with the declaration of every function
+///
+/// Each entry is `/// doc` followed by `fn name(param: kind, ...) -> kind { body }`,
+/// where kinds are the snake_case names of [`ApiType`](crate::api::ApiType)
+/// variants. Bindings that can be generated by `macro_rules!` pass their own
+/// callback and ignore the bodies, calling `libsilver::api::name` instead.
+#[macro_export]
+macro_rules! for_each_api {
+    ($callback:ident) => {
+        $callback! {
+            /// Encrypt with AES-256-GCM; output is nonce || ciphertext || tag
+            fn aes_gcm_encrypt(key: bytes, plaintext: bytes, aad: optional_bytes) -> bytes {
+                $crate::core::AesGcm::encrypt_with_aad(plaintext, &$crate::core::Aes256Key::from_bytes(key)?, aad.unwrap_or_default())
+            }
+
+            /// Decrypt AES-256-GCM nonce || ciphertext || tag
+            fn aes_gcm_decrypt(key: bytes, ciphertext: bytes, aad: optional_bytes) -> secret_bytes {
+                $crate::core::AesGcm::decrypt_with_aad_zeroizing(ciphertext, &$crate::core::Aes256Key::from_bytes(key)?, aad.unwrap_or_default())
+            }
+
+            /// Encrypt with ChaCha20-Poly1305; output is nonce || ciphertext || tag
+            fn chacha20_poly1305_encrypt(key: bytes, plaintext: bytes, aad: optional_bytes) -> bytes {
+                $crate::core::ChaCha20Poly1305Cipher::encrypt_with_aad(plaintext, &$crate::core::ChaChaKey::from_bytes(key)?, aad.unwrap_or_default())
+            }
+
+            /// Decrypt ChaCha20-Poly1305 nonce || ciphertext || tag
+            fn chacha20_poly1305_decrypt(key: bytes, ciphertext: bytes, aad: optional_bytes) -> secret_bytes {
+                $crate::core::ChaCha20Poly1305Cipher::decrypt_with_aad_zeroizing(ciphertext, &$crate::core::ChaChaKey::from_bytes(key)?, aad.unwrap_or_default())
+            }
+
+            /// SHA-256 (32-byte output)
+            fn sha256(data: bytes) -> bytes {
+                $crate::core::Sha256Hash::hash(data)
+            }
+
+            /// SHA-512 (64-byte output)
+            fn sha512(data: bytes) -> bytes {
+                $crate::core::Sha512Hash::hash(data)
+            }
+
+            /// SHA3-256 (32-byte output)
+            fn sha3_256(data: bytes) -> bytes {
+                $crate::core::Sha3_256Hash::hash(data)
+            }
+
+            /// SHA3-512 (64-byte output)
+            fn sha3_512(data: bytes) -> bytes {
+                $crate::core::Sha3_512Hash::hash(data)
+            }
+
+            /// BLAKE3 (32-byte output)
+            fn blake3(data: bytes) -> bytes {
+                $crate::core::Blake3Hash::hash(data)
+            }
+
+            /// HMAC-SHA256 (32-byte output)
+            fn hmac_sha256(key: bytes, message: bytes) -> bytes {
+                // Any key length, as RFC 2104 allows
+                let mut hasher = $crate::core::StreamingHasher::hmac_sha256(key)?;
+                hasher.update(message);
+                Ok(hasher.finalize())
+            }
+
+            /// HMAC-SHA512 (64-byte output)
+            fn hmac_sha512(key: bytes, message: bytes) -> bytes {
+                // Any key length, as RFC 2104 allows
+                let mut hasher = $crate::core::StreamingHasher::hmac_sha512(key)?;
+                hasher.update(message);
+                Ok(hasher.finalize())
+            }
+
+            /// Sign a message with a 32-byte Ed25519 private key (64-byte output)
+            fn ed25519_sign(private_key: bytes, message: bytes) -> bytes {
+                let keypair = $crate::core::Ed25519KeyPair::from_private_key_bytes(private_key)?;
+                $crate::core::Ed25519Crypto::sign(message, keypair.signing_key())
+            }
+
+            /// Verify an Ed25519 signature against a 32-byte public key
+            fn ed25519_verify(public_key: bytes, message: bytes, signature: bytes) -> bool {
+                $crate::core::Ed25519Crypto::verify(message, signature, &$crate::core::Ed25519KeyPair::verifying_key_from_bytes(public_key)?)
+            }
+
+            /// Sign a message with a 32-byte ECDSA P-256 private key (64-byte r || s output)
+            fn ecdsa_p256_sign(private_key: bytes, message: bytes) -> bytes {
+                let keypair = $crate::core::EcdsaKeyPair::from_private_key_bytes(private_key)?;
+                $crate::core::EcdsaCrypto::sign(message, keypair.signing_key())
+            }
+
+            /// Verify an ECDSA P-256 signature against a SEC1 public key
+            fn ecdsa_p256_verify(public_key: bytes, message: bytes, signature: bytes) -> bool {
+                $crate::core::EcdsaCrypto::verify(message, signature, &$crate::core::EcdsaKeyPair::verifying_key_from_bytes(public_key)?)
+            }
+
+            /// Derive a key from a password with Argon2id (libsilver default cost)
+            fn argon2id(password: bytes, salt: bytes, length: output_length) -> secret_bytes {
+                $crate::core::Argon2Kdf::derive_key_zeroizing(password, salt, length)
+            }
+
+            /// Hash a password with Argon2id into a PHC string for storage
+            fn argon2_hash_password(password: bytes) -> str {
+                $crate::core::Argon2Kdf::hash_password(password)
+            }
+
+            /// Check a password against an Argon2 PHC string
+            fn argon2_verify_password(password: bytes, hash: str) -> bool {
+                $crate::core::Argon2Kdf::verify_password(password, hash)
+            }
+
+            /// Derive a key from a password with PBKDF2-HMAC-SHA256
+            fn pbkdf2_sha256(password: bytes, salt: bytes, iterations: u32, length: output_length) -> secret_bytes {
+                $crate::core::Pbkdf2Kdf::derive_sha256_zeroizing(password, salt, iterations, length)
+            }
+
+            /// Derive a key with HKDF-SHA256; an absent salt uses the RFC 5869 default
+            fn hkdf_sha256(input_key: bytes, salt: optional_bytes, info: bytes, length: output_length) -> secret_bytes {
+                $crate::core::HkdfKdf::derive_sha256_zeroizing(input_key, salt, info, length)
+            }
+        }
+    };
+}
+
+macro_rules! define_api {
+    ($(
+        #[doc = $doc:literal]
+        fn $name:ident($($param:ident: $kind:ident),* $(,)?) -> $returns:ident $body:block
+    )*) => {
+        $(
+            #[doc = $doc]
+            pub fn $name($($param: define_api!(@param $kind)),*) -> CryptoResult<define_api!(@return $returns)> $body
+        )*
+
+        /// Every function of the binding surface, in declaration order
+        pub const FUNCTIONS: &[ApiFunction] = &[$(
+            ApiFunction {
+                name: stringify!($name),
+                doc: $doc,
+                params: &[$(ApiParam { name: stringify!($param), ty: define_api!(@type $kind) }),*],
+                returns: define_api!(@type $returns),
+            }
+        ),*];
+    };
+
+    (@param bytes) => { &[u8] };
+    (@param optional_bytes) => { Option<&[u8]> };
+    (@param str) => { &str };
+    (@param u32) => { u32 };
+    (@param output_length) => { usize };
+
+    (@return bytes) => { Vec<u8> };
+    (@return secret_bytes) => { Zeroizing<Vec<u8>> };
+    (@return str) => { String };
+    (@return bool) => { bool };
+
+    (@type bytes) => { ApiType::Bytes };
+    (@type optional_bytes) => { ApiType::OptionalBytes };
+    (@type str) => { ApiType::Str };
+    (@type u32) => { ApiType::U32 };
+    (@type output_length) => { ApiType::OutputLength };
+    (@type secret_bytes) => { ApiType::SecretBytes };
+    (@type bool) => { ApiType::Bool };
+}
+
+for_each_api!(define_api);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{AesGcm, Aes256Key, EcdsaKeyPair, HkdfKdf, Sha3_512Hash};
+
+    #[test]
+    fn test_facade_matches_core() {
+        let key = [7u8; 32];
+        let sealed = aes_gcm_encrypt(&key, b"data", Some(b"aad")).unwrap();
+        assert_eq!(AesGcm::decrypt_with_aad(&sealed, &Aes256Key::from_bytes(&key).unwrap(), b"aad").unwrap(), b"data");
+        assert_eq!(aes_gcm_decrypt(&key, &sealed, Some(b"aad")).unwrap().as_slice(), b"data");
+        assert!(aes_gcm_decrypt(&key, &sealed, None).is_err());
+
+        // No AAD and empty AAD authenticate the same way
+        let sealed = chacha20_poly1305_encrypt(&key, b"data", None).unwrap();
+        assert_eq!(chacha20_poly1305_decrypt(&key, &sealed, Some(b"")).unwrap().as_slice(), b"data");
+
+        assert_eq!(sha3_512(b"abc").unwrap(), Sha3_512Hash::hash(b"abc").unwrap());
+        assert_eq!(hkdf_sha256(b"ikm", None, b"info", 42).unwrap().as_slice(), HkdfKdf::derive_sha256(b"ikm", None, b"info", 42).unwrap());
+
+        let keypair = EcdsaKeyPair::generate().unwrap();
+        let signature = ecdsa_p256_sign(&keypair.private_key_bytes(), b"message").unwrap();
+        assert!(ecdsa_p256_verify(&keypair.public_key_bytes(), b"message", &signature).unwrap());
+        assert!(!ecdsa_p256_verify(&keypair.public_key_bytes(), b"other", &signature).unwrap());
+
+        let hash = argon2_hash_password(b"hunter2").unwrap();
+        assert!(argon2_verify_password(b"hunter2", &hash).unwrap());
+        assert!(!argon2_verify_password(b"hunter3", &hash).unwrap());
+    }
+
+    #[test]
+    fn test_function_descriptions() {
+        let names: Vec<&str> = FUNCTIONS.iter().map(|function| function.name).collect();
+        let mut unique = names.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), names.len());
+
+        let hkdf = ApiFunction::find("hkdf_sha256").unwrap();
+        assert_eq!(hkdf.doc.trim(), "Derive a key with HKDF-SHA256; an absent salt uses the RFC 5869 default");
+        assert_eq!(hkdf.params, &[
+            ApiParam { name: "input_key", ty: ApiType::Bytes },
+            ApiParam { name: "salt", ty: ApiType::OptionalBytes },
+            ApiParam { name: "info", ty: ApiType::Bytes },
+            ApiParam { name: "length", ty: ApiType::OutputLength },
+        ]);
+        assert_eq!(hkdf.returns, ApiType::SecretBytes);
+        assert_eq!(ApiFunction::find("argon2_verify_password").unwrap().returns, ApiType::Bool);
+        assert!(ApiFunction::find("md5").is_none());
+    }
+}
@@ -0,0 +1,444 @@
+//! `libsilver-cli`: key generation, envelope encryption, signatures, hashing
+//! and key derivation from the command line
+//!
+//! Ciphertexts are the library's [`Envelope`] format and signatures the same
+//! bytes [`Signer`] produces, so files written by applications can be opened
+//! here and vice versa. Symmetric keys are stored as hex text, asymmetric keys
+//! as PKCS#8 / SPKI PEM. Inputs default to stdin and outputs to stdout (`-`).
+
+use clap::{value_parser, Arg, ArgAction, ArgMatches, Command};
+use libsilver::core::kdf::PBKDF2_DEFAULT_ITERATIONS;
+use libsilver::prelude::*;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process::ExitCode;
+use zeroize::Zeroizing;
+
+const SYMMETRIC_ALGORITHMS: [&str; 2] = ["AES-256-GCM", "ChaCha20-Poly1305"];
+const KEYGEN_ALGORITHMS: [&str; 5] = ["AES-256-GCM", "ChaCha20-Poly1305", "Ed25519", "ECDSA-P256-SHA256", "RSA-PSS-SHA256"];
+const HASH_ALGORITHMS: [&str; 5] = ["SHA-256", "SHA-512", "SHA3-256", "SHA3-512", "BLAKE3"];
+const KDF_ALGORITHMS: [&str; 4] = ["Argon2id", "scrypt", "PBKDF2-SHA256", "HKDF-SHA256"];
+
+#[derive(Debug)]
+enum CliError {
+    Crypto(CryptoError),
+    Io(String, io::Error),
+    Usage(&'static str),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Crypto(err) => write!(f, "{err}"),
+            CliError::Io(path, err) => write!(f, "{path}: {err}"),
+            CliError::Usage(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<CryptoError> for CliError {
+    fn from(err: CryptoError) -> Self {
+        CliError::Crypto(err)
+    }
+}
+
+type CliResult<T> = Result<T, CliError>;
+
+fn main() -> ExitCode {
+    let matches = command().get_matches();
+    match run(&matches) {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(err) => {
+            eprintln!("libsilver-cli: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn command() -> Command {
+    let input = Arg::new("in").short('i').long("in").value_name("FILE").default_value("-").help("Input file, `-` for stdin");
+    let output = Arg::new("out").short('o').long("out").value_name("FILE").default_value("-").help("Output file, `-` for stdout");
+    let key = Arg::new("key").short('k').long("key").value_name("FILE").required(true);
+    let armor = Arg::new("armor").long("armor").action(ArgAction::SetTrue).help("Write ASCII armor instead of binary");
+
+    Command::new("libsilver-cli")
+        .about("Work with libsilver keys, envelopes, signatures and hashes")
+        .version(env!("CARGO_PKG_VERSION"))
+        .subcommand_required(true)
+        .subcommand(
+            Command::new("keygen")
+                .about("Generate a key: hex for ciphers, PKCS#8 PEM for signature schemes; key files are created owner-only and never overwritten")
+                .arg(Arg::new("algorithm").required(true).value_parser(KEYGEN_ALGORITHMS))
+                .arg(Arg::new("bits").long("bits").value_parser(value_parser!(usize)).default_value("3072").help("RSA modulus size"))
+                .arg(Arg::new("public-out").long("public-out").value_name("FILE").help("Also write the SPKI PEM public key"))
+                .arg(output.clone()),
+        )
+        .subcommand(
+            Command::new("encrypt")
+                .about("Encrypt into an envelope under a hex key-encryption key")
+                .arg(key.clone().help("Hex key-encryption key file"))
+                .arg(Arg::new("key-id").long("key-id").default_value("default").help("Label recorded in the envelope header"))
+                .arg(Arg::new("algorithm").short('a').long("algorithm").value_parser(SYMMETRIC_ALGORITHMS).default_value("AES-256-GCM"))
+                .arg(armor.clone())
+                .arg(input.clone())
+                .arg(output.clone()),
+        )
+        .subcommand(
+            Command::new("decrypt")
+                .about("Decrypt a binary or armored envelope; the cipher is read from its header")
+                .arg(key.clone().help("Hex key-encryption key file"))
+                .arg(input.clone())
+                .arg(output.clone()),
+        )
+        .subcommand(
+            Command::new("sign")
+                .about("Sign with a PEM private key (Ed25519, ECDSA P-256 or RSA-PSS)")
+                .arg(key.clone().help("PEM private key file"))
+                .arg(armor)
+                .arg(input.clone())
+                .arg(output.clone()),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Verify a signature; exits 0 if valid and 1 if not")
+                .arg(key.clone().help("PEM public or private key file"))
+                .arg(Arg::new("signature").short('s').long("signature").value_name("FILE").required(true))
+                .arg(input.clone()),
+        )
+        .subcommand(
+            Command::new("hash")
+                .about("Print the hex digest of the input")
+                .arg(Arg::new("algorithm").short('a').long("algorithm").value_parser(HASH_ALGORITHMS).default_value("SHA-256"))
+                .arg(input.clone()),
+        )
+        .subcommand(
+            Command::new("kdf")
+                .about("Derive a key from a password or input key material and print it as hex")
+                .arg(Arg::new("algorithm").required(true).value_parser(KDF_ALGORITHMS))
+                .arg(Arg::new("salt").long("salt").value_name("HEX").required(true))
+                .arg(Arg::new("info").long("info").value_name("HEX").default_value("").help("HKDF context"))
+                .arg(Arg::new("length").short('l').long("length").value_parser(value_parser!(usize)).default_value("32"))
+                .arg(Arg::new("iterations").long("iterations").value_parser(value_parser!(u32)).help("PBKDF2 iterations"))
+                .arg(Arg::new("keep-newline").long("keep-newline").action(ArgAction::SetTrue).help("Do not strip one trailing newline from the input"))
+                .arg(input),
+        )
+}
+
+// `Ok(false)` is a signature that did not verify
+fn run(matches: &ArgMatches) -> CliResult<bool> {
+    match matches.subcommand() {
+        Some(("keygen", args)) => keygen(args),
+        Some(("encrypt", args)) => {
+            let kek = read_hex_key(arg(args, "key"))?;
+            let algorithm = Algorithm::from_name(arg(args, "algorithm"))?;
+            let plaintext = Zeroizing::new(read_input(arg(args, "in"))?);
+            let envelope = Envelope::encrypt_with_algorithm(&plaintext, &kek, arg(args, "key-id"), algorithm)?;
+            if args.get_flag("armor") {
+                return write_output(arg(args, "out"), Armor::encode_envelope(&envelope)?.as_bytes());
+            }
+            write_output(arg(args, "out"), &envelope)
+        }
+        Some(("decrypt", args)) => {
+            let kek = read_hex_key(arg(args, "key"))?;
+            let plaintext = Envelope::decrypt_zeroizing(&dearmor(read_input(arg(args, "in"))?, ArmorKind::Message)?, &kek)?;
+            write_output(arg(args, "out"), &plaintext)
+        }
+        Some(("sign", args)) => {
+            let key = read_pem_key(arg(args, "key"))?;
+            if !key.is_private() {
+                return Err(CliError::Usage("signing needs a private key"));
+            }
+            let algorithm = signature_algorithm(&key);
+            let private_key = match key.algorithm() {
+                KeyAlgorithm::Rsa => key.to_der()?,
+                _ => key.to_raw()?,
+            };
+            let signature = algorithm.signer_from_private_key(&private_key)?.sign(&read_input(arg(args, "in"))?)?;
+            if args.get_flag("armor") {
+                let armored = Armor::encode(ArmorKind::Signature, &[("Algorithm", algorithm.as_str())], &signature)?;
+                return write_output(arg(args, "out"), armored.as_bytes());
+            }
+            write_output(arg(args, "out"), &signature)
+        }
+        Some(("verify", args)) => {
+            let key = read_pem_key(arg(args, "key"))?.public_key();
+            let public_key = match key.algorithm() {
+                KeyAlgorithm::Rsa => key.to_der()?,
+                _ => key.to_raw()?,
+            };
+            let verifier = signature_algorithm(&key).verifier_from_public_key(&public_key)?;
+            let signature = dearmor(read_file(arg(args, "signature"))?, ArmorKind::Signature)?;
+            let valid = verifier.verify(&read_input(arg(args, "in"))?, &signature).unwrap_or(false);
+            println!("{}", if valid { "OK" } else { "FAILED" });
+            Ok(valid)
+        }
+        Some(("hash", args)) => {
+            let path = arg(args, "in");
+            // Stream the input where a streaming hasher exists so large files aren't buffered
+            let hasher = match arg(args, "algorithm") {
+                "SHA-256" => Some(StreamingHasher::sha256()),
+                "SHA-512" => Some(StreamingHasher::sha512()),
+                "BLAKE3" => Some(StreamingHasher::blake3()),
+                _ => None,
+            };
+            let digest = match hasher {
+                Some(mut hasher) => {
+                    if path == "-" {
+                        hasher.update_reader(io::stdin().lock())?;
+                    } else {
+                        hasher.update_reader(fs::File::open(path).map_err(|err| CliError::Io(path.to_string(), err))?)?;
+                    }
+                    hasher.finalize()
+                }
+                None => {
+                    let data = read_input(path)?;
+                    match arg(args, "algorithm") {
+                        "SHA3-512" => Sha3_512Hash::hash(&data)?,
+                        _ => Sha3_256Hash::hash(&data)?,
+                    }
+                }
+            };
+            println!("{}", encode_hex(&digest));
+            Ok(true)
+        }
+        Some(("kdf", args)) => kdf(args),
+        _ => Err(CliError::Usage("unknown command")),
+    }
+}
+
+fn keygen(args: &ArgMatches) -> CliResult<bool> {
+    let algorithm = Algorithm::from_name(arg(args, "algorithm"))?;
+    let private_key = match algorithm {
+        Algorithm::Aes256Gcm | Algorithm::ChaCha20Poly1305 => {
+            if args.get_one::<String>("public-out").is_some() {
+                return Err(CliError::Usage("--public-out only applies to signature keys"));
+            }
+            let key = Zeroizing::new(algorithm.aead()?.generate_key()?);
+            let hex_key = Zeroizing::new(encode_hex_ct(&key) + "\n");
+            return write_key(arg(args, "out"), hex_key.as_bytes());
+        }
+        Algorithm::Ed25519 => AsymmetricKey::from(&Ed25519KeyPair::generate()?),
+        Algorithm::EcdsaP256Sha256 => AsymmetricKey::from(&EcdsaKeyPair::generate()?),
+        Algorithm::RsaPssSha256 => {
+            let bits = *args.get_one::<usize>("bits").expect("defaulted");
+            AsymmetricKey::from(&RsaKeyPair::generate_with_options(&RsaKeyGenOptions { bits, threads: 0 }, |_| {})?)
+        }
+    };
+
+    if let Some(path) = args.get_one::<String>("public-out") {
+        write_output(path, private_key.public_key().to_pem()?.as_bytes())?;
+    }
+    write_key(arg(args, "out"), private_key.to_pem()?.as_bytes())
+}
+
+fn kdf(args: &ArgMatches) -> CliResult<bool> {
+    let salt = decode_hex(arg(args, "salt"))?;
+    let info = decode_hex(arg(args, "info"))?;
+    let length = *args.get_one::<usize>("length").expect("defaulted");
+
+    let mut secret = Zeroizing::new(read_input(arg(args, "in"))?);
+    if !args.get_flag("keep-newline") {
+        let newline = if secret.ends_with(b"\r\n") { 2 } else { usize::from(secret.ends_with(b"\n")) };
+        let len = secret.len() - newline;
+        secret.truncate(len);
+    }
+
+    let key = match arg(args, "algorithm") {
+        "Argon2id" => Argon2Kdf::derive_key_zeroizing(&secret, &salt, length)?,
+        "scrypt" => ScryptKdf::derive_key_zeroizing(&secret, &salt, ScryptKdf::DEFAULT_LOG_N, ScryptKdf::DEFAULT_R, ScryptKdf::DEFAULT_P, length)?,
+        "PBKDF2-SHA256" => {
+            let iterations = args.get_one::<u32>("iterations").copied().unwrap_or(PBKDF2_DEFAULT_ITERATIONS);
+            Pbkdf2Kdf::derive_sha256_zeroizing(&secret, &salt, iterations, length)?
+        }
+        _ => HkdfKdf::derive_sha256_zeroizing(&secret, Some(&salt), &info, length)?,
+    };
+    println!("{}", Zeroizing::new(encode_hex_ct(&key)).as_str());
+    Ok(true)
+}
+
+#[inline]
+fn arg<'a>(args: &'a ArgMatches, name: &str) -> &'a str {
+    args.get_one::<String>(name).map(String::as_str).expect("required or defaulted")
+}
+
+fn signature_algorithm(key: &AsymmetricKey) -> Algorithm {
+    match key.algorithm() {
+        KeyAlgorithm::Rsa => Algorithm::RsaPssSha256,
+        KeyAlgorithm::EcdsaP256 => Algorithm::EcdsaP256Sha256,
+        KeyAlgorithm::Ed25519 => Algorithm::Ed25519,
+    }
+}
+
+fn decode_hex(text: &str) -> CliResult<Vec<u8>> {
+    libsilver::encoding::decode_hex(text.trim()).map_err(|_| CliError::Usage("expected hex"))
+}
+
+fn read_file(path: &str) -> CliResult<Vec<u8>> {
+    fs::read(path).map_err(|err| CliError::Io(path.to_string(), err))
+}
+
+fn read_input(path: &str) -> CliResult<Vec<u8>> {
+    if path != "-" {
+        return read_file(path);
+    }
+    let mut data = Vec::new();
+    io::stdin().read_to_end(&mut data).map_err(|err| CliError::Io("stdin".to_string(), err))?;
+    Ok(data)
+}
+
+fn write_output(path: &str, data: &[u8]) -> CliResult<bool> {
+    let result = if path == "-" {
+        io::stdout().lock().write_all(data).and_then(|_| io::stdout().flush())
+    } else {
+        fs::write(path, data)
+    };
+    result.map_err(|err| CliError::Io(if path == "-" { "stdout".to_string() } else { path.to_string() }, err))?;
+    Ok(true)
+}
+
+// Key material: a new file readable by the owner only, never an existing one
+fn write_key(path: &str, data: &[u8]) -> CliResult<bool> {
+    if path == "-" {
+        return write_output(path, data);
+    }
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)
+        .and_then(|mut file| file.write_all(data).and_then(|_| file.sync_all()))
+        .map_err(|err| CliError::Io(path.to_string(), err))?;
+    Ok(true)
+}
+
+// Armored input is recognised by its BEGIN line; anything else is taken as binary
+fn dearmor(data: Vec<u8>, kind: ArmorKind) -> CliResult<Vec<u8>> {
+    let armored = match core::str::from_utf8(&data) {
+        Ok(text) if text.trim_start().starts_with("-----BEGIN LIBSILVER ") => Armor::decode(text)?,
+        _ => return Ok(data),
+    };
+    if armored.kind != kind {
+        return Err(CliError::Usage("armored input is of the wrong kind"));
+    }
+    Ok(armored.data.to_vec())
+}
+
+fn read_hex_key(path: &str) -> CliResult<Aes256Key> {
+    let text = Zeroizing::new(read_file(path)?);
+    let text = core::str::from_utf8(&text).map_err(|_| CliError::Usage("key file must be hex text"))?;
+    let key = Zeroizing::new(decode_hex_ct(text.trim()).map_err(|_| CliError::Usage("expected hex"))?);
+    Ok(Aes256Key::from_bytes(&key)?)
+}
+
+fn read_pem_key(path: &str) -> CliResult<AsymmetricKey> {
+    let pem = Zeroizing::new(read_file(path)?);
+    let pem = core::str::from_utf8(&pem).map_err(|_| CliError::Usage("key file must be PEM text"))?;
+    Ok(AsymmetricKey::from_pem(pem)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn temp_path(name: &str) -> String {
+        let path: PathBuf = std::env::temp_dir().join(format!("libsilver-cli-{}-{name}", SecureRandom::token_hex(8).unwrap()));
+        path.to_string_lossy().into_owned()
+    }
+
+    fn cli(args: &[&str]) -> CliResult<bool> {
+        run(&command().try_get_matches_from(std::iter::once("libsilver-cli").chain(args.iter().copied())).unwrap())
+    }
+
+    #[test]
+    fn test_command_is_well_formed() {
+        command().debug_assert();
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let (key, input, envelope, output) = (temp_path("key"), temp_path("in"), temp_path("env"), temp_path("out"));
+        fs::write(&input, b"release artifact").unwrap();
+
+        assert!(cli(&["keygen", "ChaCha20-Poly1305", "-o", &key]).unwrap());
+        assert!(cli(&["encrypt", "-k", &key, "--key-id", "ops-2024", "-a", "ChaCha20-Poly1305", "-i", &input, "-o", &envelope]).unwrap());
+        let header_bytes = fs::read(&envelope).unwrap();
+        let header = Envelope::header(&header_bytes).unwrap();
+        assert_eq!((header.algorithm, header.key_id), (Algorithm::ChaCha20Poly1305, "ops-2024"));
+
+        // Envelopes are interchangeable with the library
+        let kek = Aes256Key::from_bytes(&hex::decode(fs::read_to_string(&key).unwrap().trim()).unwrap()).unwrap();
+        assert_eq!(Envelope::decrypt(&header_bytes, &kek).unwrap(), b"release artifact");
+        fs::write(&envelope, Envelope::encrypt(b"from an application", &kek, "app").unwrap()).unwrap();
+        assert!(cli(&["decrypt", "-k", &key, "-i", &envelope, "-o", &output]).unwrap());
+        assert_eq!(fs::read(&output).unwrap(), b"from an application");
+
+        assert!(cli(&["encrypt", "-k", &key, "--armor", "-i", &input, "-o", &envelope]).unwrap());
+        assert!(fs::read_to_string(&envelope).unwrap().starts_with("-----BEGIN LIBSILVER MESSAGE-----"));
+        assert!(cli(&["decrypt", "-k", &key, "-i", &envelope, "-o", &output]).unwrap());
+        assert_eq!(fs::read(&output).unwrap(), b"release artifact");
+
+        fs::write(&key, hex::encode(AesGcm::generate_key().unwrap().as_bytes())).unwrap();
+        assert!(matches!(cli(&["decrypt", "-k", &key, "-i", &envelope, "-o", &output]), Err(CliError::Crypto(_))));
+        for path in [key, input, envelope, output] {
+            fs::remove_file(path).ok();
+        }
+    }
+
+    #[test]
+    fn test_sign_verify() {
+        let (key, public, input, signature) = (temp_path("key"), temp_path("pub"), temp_path("in"), temp_path("sig"));
+        fs::write(&input, b"manifest").unwrap();
+
+        for algorithm in ["Ed25519", "ECDSA-P256-SHA256"] {
+            fs::remove_file(&key).ok();
+            fs::remove_file(&public).ok();
+            assert!(cli(&["keygen", algorithm, "--public-out", &public, "-o", &key]).unwrap());
+            assert!(cli(&["sign", "-k", &key, "-i", &input, "-o", &signature]).unwrap());
+            assert!(cli(&["verify", "-k", &public, "-s", &signature, "-i", &input]).unwrap());
+            assert!(cli(&["verify", "-k", &key, "-s", &signature, "-i", &input]).unwrap());
+            assert!(cli(&["sign", "-k", &key, "--armor", "-i", &input, "-o", &signature]).unwrap());
+            assert!(cli(&["verify", "-k", &public, "-s", &signature, "-i", &input]).unwrap());
+            assert!(matches!(dearmor(fs::read(&signature).unwrap(), ArmorKind::Message), Err(CliError::Usage(_))));
+
+            fs::write(&input, b"tampered").unwrap();
+            assert!(!cli(&["verify", "-k", &public, "-s", &signature, "-i", &input]).unwrap());
+            fs::write(&input, b"manifest").unwrap();
+            assert!(matches!(cli(&["sign", "-k", &public, "-i", &input, "-o", &signature]), Err(CliError::Usage(_))));
+        }
+        for path in [key, public, input, signature] {
+            fs::remove_file(path).ok();
+        }
+    }
+
+    #[test]
+    fn test_keygen_protects_key_files() {
+        let (key, public) = (temp_path("key"), temp_path("pub"));
+        assert!(cli(&["keygen", "Ed25519", "--public-out", &public, "-o", &key]).unwrap());
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&key).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        // An existing key is never clobbered
+        let pem = fs::read(&key).unwrap();
+        assert!(matches!(cli(&["keygen", "AES-256-GCM", "-o", &key]), Err(CliError::Io(..))));
+        assert_eq!(fs::read(&key).unwrap(), pem);
+        for path in [key, public] {
+            fs::remove_file(path).ok();
+        }
+    }
+
+    #[test]
+    fn test_usage_errors() {
+        assert!(command().try_get_matches_from(["libsilver-cli", "keygen", "DES"]).is_err());
+        assert!(command().try_get_matches_from(["libsilver-cli", "encrypt"]).is_err());
+        assert!(matches!(cli(&["kdf", "HKDF-SHA256", "--salt", "zz", "-i", "/nonexistent"]), Err(CliError::Usage(_))));
+        assert!(matches!(cli(&["decrypt", "-k", "/nonexistent/key"]), Err(CliError::Io(..))));
+    }
+}
@@ -0,0 +1,11 @@
+//! Generates the Kotlin and Swift bindings from the compiled library:
+//!
+//! ```text
+//! cargo build --release --features uniffi
+//! cargo run --features uniffi-bindgen --bin uniffi-bindgen -- generate \
+//!     --library target/release/liblibsilver.so --language kotlin --out-dir bindings/kotlin/src/androidMain/kotlin
+//! ```
+
+fn main() {
+    uniffi::uniffi_bindgen_main()
+}
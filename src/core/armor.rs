@@ -0,0 +1,280 @@
+use crate::error::{CryptoError, CryptoResult, ARMOR_INVALID_FORMAT, ARMOR_INVALID_HEADER, ARMOR_UNSUPPORTED_VERSION, ARMOR_CHECKSUM_MISMATCH};
+#[cfg(feature = "aes")]
+use crate::core::envelope::Envelope;
+use crate::core::encoding::{Base64Variant, decode_base64, decode_base64_ct, encode_base64, encode_base64_ct};
+use zeroize::Zeroizing;
+use alloc::{format, string::String, vec::Vec};
+
+// Armor layout (after OpenPGP, RFC 4880 section 6.2):
+//   -----BEGIN LIBSILVER <KIND>-----
+//   Name: value            (zero or more headers)
+//                          (blank line)
+//   base64, 64 columns
+//   =<base64 CRC-24 of the data>
+//   -----END LIBSILVER <KIND>-----
+const ARMOR_LINE_WIDTH: usize = 64;
+const ARMOR_VERSION: &str = "1";
+const CRC24_INIT: u32 = 0x00B7_04CE;
+const CRC24_POLY: u32 = 0x0186_4CFB;
+
+/// What an armored block contains, named in its BEGIN/END lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmorKind {
+    /// A ciphertext such as an [`Envelope`](crate::core::Envelope)
+    Message,
+    /// A detached signature
+    Signature,
+    /// A public key
+    PublicKey,
+    /// An unencrypted private key
+    PrivateKey,
+    /// A passphrase-protected key export blob
+    KeyExport,
+}
+
+impl ArmorKind {
+    const ALL: [ArmorKind; 5] = [ArmorKind::Message, ArmorKind::Signature, ArmorKind::PublicKey, ArmorKind::PrivateKey, ArmorKind::KeyExport];
+
+    /// Label in the BEGIN/END lines, e.g. `"LIBSILVER MESSAGE"`
+    #[inline]
+    pub fn label(self) -> &'static str {
+        match self {
+            ArmorKind::Message => "LIBSILVER MESSAGE",
+            ArmorKind::Signature => "LIBSILVER SIGNATURE",
+            ArmorKind::PublicKey => "LIBSILVER PUBLIC KEY",
+            ArmorKind::PrivateKey => "LIBSILVER PRIVATE KEY",
+            ArmorKind::KeyExport => "LIBSILVER KEY EXPORT",
+        }
+    }
+
+    #[inline]
+    fn from_label(label: &str) -> CryptoResult<Self> {
+        Self::ALL.into_iter()
+            .find(|kind| kind.label() == label)
+            .ok_or(CryptoError::EncodingFailed(ARMOR_INVALID_FORMAT))
+    }
+}
+
+/// A decoded armored block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Armored {
+    /// Kind named in the BEGIN/END lines
+    pub kind: ArmorKind,
+    /// `Name: value` headers in order; informational only, they are not authenticated
+    pub headers: Vec<(String, String)>,
+    /// The decoded bytes, zeroized on drop since they may be key material
+    pub data: Zeroizing<Vec<u8>>,
+}
+
+impl Armored {
+    /// Value of the first header called `name`, compared case-insensitively
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// ASCII armor: base64 blocks with BEGIN/END lines, metadata headers and a
+/// CRC-24 checksum, so binary envelopes, signatures and keys survive email,
+/// YAML and JSON
+///
+/// The checksum catches transport damage only; integrity comes from the
+/// armored data itself (an AEAD tag, a signature).
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let kek = AesGcm::generate_key()?;
+/// let envelope = Envelope::encrypt(b"customer record", &kek, "kek-2024")?;
+/// let armored = Armor::encode_envelope(&envelope)?;
+/// assert!(armored.starts_with("-----BEGIN LIBSILVER MESSAGE-----\nVersion: 1\nAlgorithm: AES-256-GCM\n"));
+///
+/// let decoded = Armor::decode(&armored)?;
+/// assert_eq!(decoded.kind, ArmorKind::Message);
+/// assert_eq!(decoded.header("key-id"), Some("kek-2024"));
+/// assert_eq!(Envelope::decrypt(&decoded.data, &kek)?, b"customer record");
+/// # Ok::<(), CryptoError>(())
+/// ```
+pub struct Armor;
+
+impl Armor {
+    /// Armor `data` with a `Version` header followed by `headers`
+    ///
+    /// Header names must be non-empty ASCII without `:` or whitespace, other
+    /// than `Version`, and values must fit on one line.
+    pub fn encode(kind: ArmorKind, headers: &[(&str, &str)], data: &[u8]) -> CryptoResult<Zeroizing<String>> {
+        for (name, value) in headers {
+            let valid_name = !name.is_empty() && !name.eq_ignore_ascii_case("Version")
+                && name.bytes().all(|byte| byte.is_ascii_graphic() && byte != b':');
+            if !valid_name || value.contains(['\r', '\n']) || *value != value.trim() {
+                return Err(CryptoError::InvalidInput(ARMOR_INVALID_HEADER));
+            }
+        }
+
+        let body = Zeroizing::new(encode_base64_ct(data, Base64Variant::Standard));
+        let mut armored = Zeroizing::new(String::with_capacity(body.len() + body.len() / ARMOR_LINE_WIDTH + 128));
+        armored.push_str(&format!("-----BEGIN {}-----\nVersion: {ARMOR_VERSION}\n", kind.label()));
+        for (name, value) in headers {
+            armored.push_str(name);
+            armored.push_str(": ");
+            armored.push_str(value);
+            armored.push('\n');
+        }
+        armored.push('\n');
+        // Base64 output is ASCII, so every chunk is valid UTF-8
+        for line in body.as_bytes().chunks(ARMOR_LINE_WIDTH) {
+            armored.push_str(core::str::from_utf8(line).unwrap_or_default());
+            armored.push('\n');
+        }
+        let crc = crc24(data).to_be_bytes();
+        armored.push('=');
+        armored.push_str(&encode_base64(&crc[1..], Base64Variant::Standard));
+        armored.push_str(&format!("\n-----END {}-----\n", kind.label()));
+        Ok(armored)
+    }
+
+    /// Armor an envelope as a `MESSAGE` with its payload cipher and key ID as headers
+    #[cfg(feature = "aes")]
+    pub fn encode_envelope(envelope: &[u8]) -> CryptoResult<Zeroizing<String>> {
+        let header = Envelope::header(envelope)?;
+        let mut headers = alloc::vec![("Algorithm", header.algorithm.as_str())];
+        // Key IDs are free-form; leave out any that cannot be a header value
+        let key_id = header.key_id;
+        if !key_id.is_empty() && !key_id.contains(['\r', '\n']) && key_id == key_id.trim() {
+            headers.push(("Key-Id", key_id));
+        }
+        Self::encode(ArmorKind::Message, &headers, envelope)
+    }
+
+    /// Decode the first armored block in `text`, ignoring anything around it
+    ///
+    /// Accepts LF or CRLF line endings and rejects a missing or wrong checksum.
+    pub fn decode(text: &str) -> CryptoResult<Armored> {
+        let mut lines = text.lines().map(str::trim_end);
+        let label = lines.by_ref()
+            .find_map(|line| line.strip_prefix("-----BEGIN ").and_then(|rest| rest.strip_suffix("-----")))
+            .ok_or(CryptoError::EncodingFailed(ARMOR_INVALID_FORMAT))?;
+        let kind = ArmorKind::from_label(label)?;
+        let end = format!("-----END {label}-----");
+
+        let mut headers = Vec::new();
+        for line in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            let (name, value) = line.split_once(':').ok_or(CryptoError::EncodingFailed(ARMOR_INVALID_HEADER))?;
+            headers.push((String::from(name.trim()), String::from(value.trim())));
+        }
+        let version = headers.iter().find(|(name, _)| name.eq_ignore_ascii_case("Version"));
+        if version.is_some_and(|(_, version)| version != ARMOR_VERSION) {
+            return Err(CryptoError::EncodingFailed(ARMOR_UNSUPPORTED_VERSION));
+        }
+
+        let mut body = Zeroizing::new(String::new());
+        let mut checksum = None;
+        let mut terminated = false;
+        for line in lines {
+            let line = line.trim_start();
+            if line == end {
+                terminated = true;
+                break;
+            }
+            match line.strip_prefix('=') {
+                Some(crc) if checksum.is_none() => checksum = Some(crc),
+                _ if checksum.is_some() => return Err(CryptoError::EncodingFailed(ARMOR_INVALID_FORMAT)),
+                _ => body.push_str(line),
+            }
+        }
+        let checksum = checksum.filter(|_| terminated).ok_or(CryptoError::EncodingFailed(ARMOR_INVALID_FORMAT))?;
+
+        let data = decode_base64_ct(body.as_bytes(), Base64Variant::Standard)
+            .map_err(|_| CryptoError::EncodingFailed(ARMOR_INVALID_FORMAT))?;
+        let crc = decode_base64(checksum, Base64Variant::Standard)
+            .map_err(|_| CryptoError::EncodingFailed(ARMOR_INVALID_FORMAT))?;
+        if crc[..] != crc24(&data).to_be_bytes()[1..] {
+            return Err(CryptoError::EncodingFailed(ARMOR_CHECKSUM_MISMATCH));
+        }
+        Ok(Armored { kind, headers, data })
+    }
+}
+
+// CRC-24 as defined by RFC 4880 section 6.1
+fn crc24(data: &[u8]) -> u32 {
+    let mut crc = CRC24_INIT;
+    for &byte in data {
+        crc ^= u32::from(byte) << 16;
+        for _ in 0..8 {
+            crc <<= 1;
+            if crc & 0x0100_0000 != 0 {
+                crc ^= CRC24_POLY;
+            }
+        }
+    }
+    crc & 0x00FF_FFFF
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_armor_roundtrip() {
+        let data: Vec<u8> = (0..=255u8).collect();
+        let armored = Armor::encode(ArmorKind::Signature, &[("Comment", "release 1.2")], &data).unwrap();
+        let lines: Vec<&str> = armored.lines().collect();
+        assert_eq!(lines[..4], ["-----BEGIN LIBSILVER SIGNATURE-----", "Version: 1", "Comment: release 1.2", ""]);
+        assert_eq!(lines.last(), Some(&"-----END LIBSILVER SIGNATURE-----"));
+        assert!(lines.iter().all(|line| line.len() <= ARMOR_LINE_WIDTH));
+
+        let decoded = Armor::decode(&armored).unwrap();
+        assert_eq!(decoded.kind, ArmorKind::Signature);
+        assert_eq!((decoded.header("version"), decoded.header("Comment")), (Some("1"), Some("release 1.2")));
+        assert_eq!(decoded.data.as_slice(), data);
+
+        // Surrounding text and CRLF line endings, as after a trip through email
+        let mailed = format!("Hi,\r\n\r\n{}\r\nThanks\r\n", armored.replace('\n', "\r\n"));
+        assert_eq!(Armor::decode(&mailed).unwrap().data.as_slice(), data);
+
+        let empty = Armor::encode(ArmorKind::PublicKey, &[], b"").unwrap();
+        assert!(Armor::decode(&empty).unwrap().data.is_empty());
+    }
+
+    #[test]
+    fn test_armor_crc24() {
+        // RFC 4880 checksum of "123456789"
+        assert_eq!(crc24(b"123456789"), 0x21CF02);
+        assert_eq!(crc24(b""), CRC24_INIT);
+    }
+
+    #[test]
+    fn test_armor_rejects_damage() {
+        let armored = Armor::encode(ArmorKind::PrivateKey, &[], &[7u8; 100]).unwrap();
+
+        let damaged = armored.replacen("BwcH", "BwcI", 1);
+        assert_eq!(Armor::decode(&damaged).unwrap_err(), CryptoError::EncodingFailed(ARMOR_CHECKSUM_MISMATCH));
+        let truncated = &armored[..armored.find("-----END").unwrap()];
+        assert!(Armor::decode(truncated).is_err());
+        let unchecked: String = armored.lines().filter(|line| !line.starts_with('=')).map(|line| format!("{line}\n")).collect();
+        assert!(Armor::decode(&unchecked).is_err());
+        assert!(Armor::decode(&armored.replace("PRIVATE KEY", "SECRET KEY")).is_err());
+        assert_eq!(Armor::decode(&armored.replace("Version: 1", "Version: 2")).unwrap_err(), CryptoError::EncodingFailed(ARMOR_UNSUPPORTED_VERSION));
+        assert!(Armor::decode("no armor here").is_err());
+
+        assert_eq!(Armor::encode(ArmorKind::Message, &[("Bad Name", "x")], b"").unwrap_err(), CryptoError::InvalidInput(ARMOR_INVALID_HEADER));
+        assert!(Armor::encode(ArmorKind::Message, &[("Comment", "two\nlines")], b"").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "aes")]
+    fn test_armor_envelope() {
+        use crate::core::symmetric::AesGcm;
+
+        let kek = AesGcm::generate_key().unwrap();
+        let envelope = Envelope::encrypt(b"record", &kek, "kek-2024").unwrap();
+        let decoded = Armor::decode(&Armor::encode_envelope(&envelope).unwrap()).unwrap();
+        assert_eq!((decoded.kind, decoded.header("Algorithm"), decoded.header("Key-Id")), (ArmorKind::Message, Some("AES-256-GCM"), Some("kek-2024")));
+        assert_eq!(Envelope::decrypt(&decoded.data, &kek).unwrap(), b"record");
+        assert!(Armor::encode_envelope(b"not an envelope").is_err());
+    }
+}
@@ -1,18 +1,139 @@
-use crate::error::{CryptoError, CryptoResult, RSA_KEY_SIZE_TOO_SMALL, RSA_KEY_GENERATION_FAILED, RSA_ENCRYPTION_FAILED, RSA_DECRYPTION_FAILED, PRIVATE_KEY_ENCODING_FAILED, PUBLIC_KEY_ENCODING_FAILED, PRIVATE_KEY_DECODING_FAILED, PUBLIC_KEY_DECODING_FAILED, INVALID_ECDSA_PRIVATE_KEY, INVALID_ECDSA_PUBLIC_KEY, INVALID_SIGNATURE_FORMAT, ED25519_PRIVATE_KEY_INVALID_SIZE, ED25519_PUBLIC_KEY_INVALID_SIZE, ED25519_SIGNATURE_INVALID_SIZE, INVALID_ED25519_PUBLIC_KEY};
-use rsa::{RsaPrivateKey, RsaPublicKey, Oaep, pkcs8::{EncodePrivateKey, EncodePublicKey, DecodePrivateKey, DecodePublicKey}};
-use rsa::sha2::Sha256;
-use p256::ecdsa::{SigningKey, VerifyingKey, Signature, signature::{Signer, Verifier}};
+#[cfg(any(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use crate::error::{CryptoError, CryptoResult};
+#[cfg(feature = "rsa")]
+use crate::error::{RSA_KEY_SIZE_TOO_SMALL, RSA_KEY_GENERATION_FAILED, RSA_ENCRYPTION_FAILED, RSA_DECRYPTION_FAILED, RSA_SIGNING_FAILED, PRIVATE_KEY_ENCODING_FAILED, PUBLIC_KEY_ENCODING_FAILED, PRIVATE_KEY_DECODING_FAILED, PUBLIC_KEY_DECODING_FAILED, RSA_WEAK_PUBLIC_EXPONENT};
+#[cfg(any(feature = "rsa", all(feature = "std", feature = "ecdsa", feature = "ed25519")))]
+use crate::error::{PEM_INVALID_BOUNDARY, PEM_INVALID_BODY, PEM_HEADERS_UNSUPPORTED};
+#[cfg(feature = "rsa")]
+use crate::error::{PEM_UNEXPECTED_LABEL, PEM_ENCRYPTED_KEY, DER_INVALID_STRUCTURE, KEY_ALGORITHM_MISMATCH};
+#[cfg(any(feature = "rsa", feature = "ecdsa"))]
+use crate::error::INVALID_SIGNATURE_FORMAT;
+#[cfg(any(feature = "ecdsa", feature = "ed25519"))]
+use crate::error::SIGNATURE_CONTEXT_TOO_LONG;
+#[cfg(feature = "ecdsa")]
+use crate::error::{INVALID_ECDSA_PRIVATE_KEY, INVALID_ECDSA_PUBLIC_KEY, ECDSA_SIGNING_FAILED};
+#[cfg(feature = "ed25519")]
+use crate::error::{ED25519_PRIVATE_KEY_INVALID_SIZE, ED25519_PUBLIC_KEY_INVALID_SIZE, ED25519_SIGNATURE_INVALID_SIZE, INVALID_ED25519_PUBLIC_KEY, ED25519_WEAK_PUBLIC_KEY, ED25519_SIGNING_FAILED};
+#[cfg(feature = "rsa")]
+use rsa::{BigUint, RsaPrivateKey, RsaPublicKey, Oaep, traits::PublicKeyParts, pkcs8::{EncodePrivateKey, EncodePublicKey, PrivateKeyInfo}};
+#[cfg(feature = "rsa")]
+use rsa::pkcs8::{der::pem, spki::SubjectPublicKeyInfoRef};
+#[cfg(all(not(feature = "rsa"), feature = "std", feature = "ecdsa", feature = "ed25519"))]
+use der::pem;
+#[cfg(feature = "rsa")]
+use rsa::pkcs1::ALGORITHM_OID as RSA_ENCRYPTION;
+#[cfg(feature = "rsa")]
+use rsa::sha2::{Sha256, Sha384, Sha512};
+#[cfg(feature = "rsa")]
+use rsa::signature::{RandomizedSigner, SignatureEncoding};
+#[cfg(feature = "rsa")]
+use num_bigint_dig::RandPrime;
+#[cfg(feature = "rsa")]
+use rand::{CryptoRng, RngCore};
+#[cfg(all(feature = "rsa", feature = "std"))]
+use rand::SeedableRng;
+#[cfg(all(feature = "rsa", feature = "std"))]
+use rand_chacha::ChaCha20Rng;
+#[cfg(all(feature = "rsa", feature = "std"))]
+use crate::error::RANDOM_GENERATION_FAILED;
+#[cfg(feature = "ecdsa")]
+use p256::ecdsa::{SigningKey, VerifyingKey, Signature};
+#[cfg(feature = "ecdsa")]
+use p256::ecdsa::signature::hazmat::{PrehashSigner, PrehashVerifier};
+#[cfg(any(feature = "ecdsa", feature = "ed25519"))]
+use sha2::{Digest, Sha512 as ContextSha512};
+#[cfg(feature = "ed25519")]
 use ed25519_dalek::{SigningKey as Ed25519SigningKey, VerifyingKey as Ed25519VerifyingKey, Signature as Ed25519Signature};
-
+// All three algorithm crates re-export the same `signature` traits
+#[cfg(feature = "ecdsa")]
+use p256::ecdsa::signature::{Signer, Verifier};
+#[cfg(all(feature = "ed25519", not(feature = "ecdsa")))]
+use ed25519_dalek::{Signer, Verifier};
+#[cfg(all(feature = "rsa", not(any(feature = "ecdsa", feature = "ed25519"))))]
+use rsa::signature::{Signer, Verifier};
+#[cfg(any(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
 use rand::rngs::OsRng;
+#[cfg(any(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+#[cfg(feature = "ed25519")]
+use zeroize::Zeroize;
+#[cfg(any(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use alloc::vec::Vec;
+#[cfg(feature = "rsa")]
+use alloc::string::String;
+
+// F4, the public exponent used by `RsaPrivateKey::new`
+#[cfg(feature = "rsa")]
+const RSA_PUBLIC_EXPONENT: u32 = 65537;
+
+/// How thoroughly imported keys are checked beyond being well formed
+///
+/// RSA private keys are always checked for consistency, and ECDSA P-256
+/// keys for a scalar in range and a public point on the curve that is not
+/// the identity, whatever the setting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KeyValidation {
+    /// Also reject RSA moduli under 2048 bits, RSA public exponents that are
+    /// even or below 65537, and small-order (including identity) Ed25519 public keys
+    #[default]
+    Strict,
+    /// Only the structural checks, for interop with legacy systems
+    Legacy,
+}
+
+impl KeyValidation {
+    /// Check an RSA public key, or the public half of a private key
+    #[cfg(feature = "rsa")]
+    pub fn check_rsa(self, key: &impl PublicKeyParts) -> CryptoResult<()> {
+        if self == KeyValidation::Legacy {
+            return Ok(());
+        }
+        if key.n().bits() < 2048 {
+            return Err(CryptoError::InvalidKey(RSA_KEY_SIZE_TOO_SMALL));
+        }
+        if key.e().to_bytes_le()[0] & 1 == 0 || *key.e() < BigUint::from(RSA_PUBLIC_EXPONENT) {
+            return Err(CryptoError::InvalidKey(RSA_WEAK_PUBLIC_EXPONENT));
+        }
+        Ok(())
+    }
+
+    /// Check an Ed25519 public key
+    #[cfg(feature = "ed25519")]
+    pub fn check_ed25519(self, key: &Ed25519VerifyingKey) -> CryptoResult<()> {
+        if self == KeyValidation::Strict && key.is_weak() {
+            return Err(CryptoError::InvalidKey(ED25519_WEAK_PUBLIC_KEY));
+        }
+        Ok(())
+    }
+}
+
+/// Options for [`RsaKeyPair::generate_with_options`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "rsa")]
+pub struct RsaKeyGenOptions {
+    /// Modulus size in bits, at least 2048 (e.g. 2048, 3072 or 4096)
+    pub bits: usize,
+    /// Threads searching for primes in parallel; 0 uses every available core.
+    /// Without the `std` feature the search always runs on the calling thread
+    pub threads: usize,
+}
+
+#[cfg(feature = "rsa")]
+impl Default for RsaKeyGenOptions {
+    fn default() -> Self {
+        Self { bits: 2048, threads: 1 }
+    }
+}
 
 /// RSA key pair
 #[derive(Clone)]
+#[cfg(feature = "rsa")]
 pub struct RsaKeyPair {
     private_key: RsaPrivateKey,
     public_key: RsaPublicKey,
 }
 
+#[cfg(feature = "rsa")]
 impl RsaKeyPair {
     /// Generate a new RSA key pair with specified bit size
     pub fn generate(bits: usize) -> CryptoResult<Self> {
@@ -31,6 +152,69 @@ impl RsaKeyPair {
         })
     }
 
+    /// Generate a new RSA key pair, calling `progress` with the number of primes found so far (1, then 2)
+    ///
+    /// Prime search dominates key generation time, so this lets callers show
+    /// progress on large keys. The count restarts in the rare case a prime
+    /// pair is rejected.
+    #[inline]
+    pub fn generate_with_progress(bits: usize, progress: impl FnMut(usize)) -> CryptoResult<Self> {
+        Self::generate_with_options(&RsaKeyGenOptions { bits, threads: 1 }, progress)
+    }
+
+    /// Generate a new RSA key pair, searching for primes on `options.threads` threads
+    ///
+    /// `progress` is called as for [`Self::generate_with_progress`], always on
+    /// the calling thread.
+    #[inline]
+    pub fn generate_with_options(options: &RsaKeyGenOptions, progress: impl FnMut(usize)) -> CryptoResult<Self> {
+        Self::generate_with_rng(options, &mut OsRng, progress)
+    }
+
+    /// Generate a new RSA key pair, drawing prime candidates from `rng`
+    ///
+    /// Prime search reads thousands of random candidates; a long-lived
+    /// [`SecureRng`](crate::core::random::SecureRng) serves them from memory
+    /// instead of asking the OS for each one. Search threads get their own
+    /// generators seeded from `rng`.
+    pub fn generate_with_rng<R: RngCore + CryptoRng>(options: &RsaKeyGenOptions, rng: &mut R, mut progress: impl FnMut(usize)) -> CryptoResult<Self> {
+        if options.bits < 2048 {
+            return Err(CryptoError::InvalidInput(RSA_KEY_SIZE_TOO_SMALL));
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let threads = match options.threads {
+                0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+                n => n,
+            };
+            if threads > 1 {
+                let private_key = search_primes_parallel(options.bits, threads, rng, progress)?;
+                let public_key = RsaPublicKey::from(&private_key);
+                return Ok(Self { private_key, public_key });
+            }
+        }
+
+        let bits = options.bits;
+        let private_key = loop {
+            let p = rng.gen_prime(bits - bits / 2);
+            progress(1);
+            let q = rng.gen_prime(bits / 2);
+            progress(2);
+
+            if let Some(key) = key_from_primes(p, q, bits) {
+                break key;
+            }
+        };
+
+        let public_key = RsaPublicKey::from(&private_key);
+
+        Ok(Self {
+            private_key,
+            public_key,
+        })
+    }
+
     /// Get the public key
     #[inline]
     pub fn public_key(&self) -> &RsaPublicKey {
@@ -44,10 +228,10 @@ impl RsaKeyPair {
     }
 
     /// Export private key as PEM
-    pub fn private_key_pem(&self) -> CryptoResult<String> {
+    #[inline]
+    pub fn private_key_pem(&self) -> CryptoResult<Zeroizing<String>> {
         self.private_key.to_pkcs8_pem(rsa::pkcs8::LineEnding::LF)
             .map_err(|_| CryptoError::EncodingFailed(PRIVATE_KEY_ENCODING_FAILED))
-            .map(|pem| pem.to_string())
     }
 
     /// Export public key as PEM
@@ -56,10 +240,42 @@ impl RsaKeyPair {
             .map_err(|_| CryptoError::EncodingFailed(PUBLIC_KEY_ENCODING_FAILED))
     }
 
-    /// Import private key from PEM
+    /// Import private key from PEM, rejecting weak keys ([`KeyValidation::Strict`])
+    #[inline]
     pub fn from_private_key_pem(pem: &str) -> CryptoResult<Self> {
-        let private_key = RsaPrivateKey::from_pkcs8_pem(pem)
+        Self::from_private_key_pem_with_validation(pem, KeyValidation::Strict)
+    }
+
+    /// Import private key from PEM with the given checks
+    pub fn from_private_key_pem_with_validation(pem: &str, validation: KeyValidation) -> CryptoResult<Self> {
+        let (label, der) = decode_key_pem(pem)?;
+        expect_pem_label(label, "PRIVATE KEY")?;
+        Self::from_private_key_der_with_validation(&der, validation)
+    }
+
+    /// Export private key as PKCS#8 DER
+    pub fn private_key_der(&self) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.private_key.to_pkcs8_der()
+            .map_err(|_| CryptoError::EncodingFailed(PRIVATE_KEY_ENCODING_FAILED))
+            .map(|der| Zeroizing::new(der.as_bytes().to_vec()))
+    }
+
+    /// Import private key from PKCS#8 DER, rejecting weak keys ([`KeyValidation::Strict`])
+    #[inline]
+    pub fn from_private_key_der(der: &[u8]) -> CryptoResult<Self> {
+        Self::from_private_key_der_with_validation(der, KeyValidation::Strict)
+    }
+
+    /// Import private key from PKCS#8 DER with the given checks
+    pub fn from_private_key_der_with_validation(der: &[u8], validation: KeyValidation) -> CryptoResult<Self> {
+        let info = PrivateKeyInfo::try_from(der)
+            .map_err(|_| CryptoError::InvalidKey(DER_INVALID_STRUCTURE))?;
+        if info.algorithm.oid != RSA_ENCRYPTION {
+            return Err(CryptoError::InvalidKey(KEY_ALGORITHM_MISMATCH));
+        }
+        let private_key = RsaPrivateKey::try_from(info)
             .map_err(|_| CryptoError::InvalidKey(PRIVATE_KEY_DECODING_FAILED))?;
+        validation.check_rsa(&private_key)?;
 
         let public_key = RsaPublicKey::from(&private_key);
 
@@ -69,16 +285,151 @@ impl RsaKeyPair {
         })
     }
 
-    /// Import public key from PEM
+    /// Import public key from PEM, rejecting weak keys ([`KeyValidation::Strict`])
+    #[inline]
     pub fn from_public_key_pem(pem: &str) -> CryptoResult<RsaPublicKey> {
-        RsaPublicKey::from_public_key_pem(pem)
-            .map_err(|_| CryptoError::InvalidKey(PUBLIC_KEY_DECODING_FAILED))
+        Self::from_public_key_pem_with_validation(pem, KeyValidation::Strict)
     }
+
+    /// Import public key from PEM with the given checks
+    pub fn from_public_key_pem_with_validation(pem: &str, validation: KeyValidation) -> CryptoResult<RsaPublicKey> {
+        let (label, der) = decode_key_pem(pem)?;
+        expect_pem_label(label, "PUBLIC KEY")?;
+        let spki = SubjectPublicKeyInfoRef::try_from(der.as_slice())
+            .map_err(|_| CryptoError::InvalidKey(DER_INVALID_STRUCTURE))?;
+        if spki.algorithm.oid != RSA_ENCRYPTION {
+            return Err(CryptoError::InvalidKey(KEY_ALGORITHM_MISMATCH));
+        }
+        let public_key = RsaPublicKey::try_from(spki)
+            .map_err(|_| CryptoError::InvalidKey(PUBLIC_KEY_DECODING_FAILED))?;
+        validation.check_rsa(&public_key)?;
+        Ok(public_key)
+    }
+}
+
+// The wrapped private key zeroizes itself on drop
+#[cfg(feature = "rsa")]
+impl ZeroizeOnDrop for RsaKeyPair {}
+
+// Decode a PEM document, naming the part at fault: the BEGIN/END lines, RFC 1421
+// headers or the base64 body. Errors never include the input
+#[cfg(any(feature = "rsa", all(feature = "std", feature = "ecdsa", feature = "ed25519")))]
+pub(crate) fn decode_key_pem(pem: &str) -> CryptoResult<(&str, Zeroizing<Vec<u8>>)> {
+    let (label, der) = pem::decode_vec(pem.trim().as_bytes()).map_err(|err| CryptoError::InvalidKey(match err {
+        pem::Error::Base64(_) | pem::Error::CharacterEncoding | pem::Error::EncapsulatedText | pem::Error::Length => PEM_INVALID_BODY,
+        pem::Error::HeaderDisallowed => PEM_HEADERS_UNSUPPORTED,
+        _ => PEM_INVALID_BOUNDARY,
+    }))?;
+    Ok((label, Zeroizing::new(der)))
 }
 
-/// RSA encryption and decryption
+// Password-protected PKCS#8 gets its own error: it is the most common wrong input
+#[cfg(feature = "rsa")]
+pub(crate) fn expect_pem_label(label: &str, expected: &str) -> CryptoResult<()> {
+    match label {
+        _ if label == expected => Ok(()),
+        "ENCRYPTED PRIVATE KEY" => Err(CryptoError::InvalidKey(PEM_ENCRYPTED_KEY)),
+        _ => Err(CryptoError::InvalidKey(PEM_UNEXPECTED_LABEL)),
+    }
+}
+
+// Build a key from two primes, rejecting a short modulus or an e that shares
+// a factor with p-1 or q-1
+#[cfg(feature = "rsa")]
+fn key_from_primes(p: BigUint, q: BigUint, bits: usize) -> Option<RsaPrivateKey> {
+    let key = RsaPrivateKey::from_p_q(p, q, BigUint::from(RSA_PUBLIC_EXPONENT)).ok()?;
+    (key.n().bits() == bits).then_some(key)
+}
+
+// Race `threads` workers for the two primes. Each worker alternates between
+// the p and q sizes with its own ChaCha20 generator; workers still searching
+// when the key is found finish their current prime in the background.
+#[cfg(all(feature = "rsa", feature = "std"))]
+fn search_primes_parallel<R: RngCore + CryptoRng>(bits: usize, threads: usize, rng: &mut R, mut progress: impl FnMut(usize)) -> CryptoResult<RsaPrivateKey> {
+    use std::sync::{mpsc, Arc};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    let sizes = [bits - bits / 2, bits / 2];
+    let (sender, receiver) = mpsc::channel();
+    let stop = Arc::new(AtomicBool::new(false));
+    let _stop_workers = StopOnDrop(Arc::clone(&stop));
+
+    for worker in 0..threads {
+        let mut seed = Zeroizing::new(<ChaCha20Rng as SeedableRng>::Seed::default());
+        rng.try_fill_bytes(seed.as_mut())
+            .map_err(|_| CryptoError::RandomGenerationFailed(RANDOM_GENERATION_FAILED))?;
+        let mut worker_rng = ChaCha20Rng::from_seed(*seed);
+        let (sender, stop) = (sender.clone(), Arc::clone(&stop));
+        std::thread::Builder::new()
+            .name("libsilver-rsa-keygen".into())
+            .spawn(move || {
+                for &size in sizes.iter().cycle().skip(worker % 2) {
+                    if stop.load(Ordering::Relaxed) || sender.send(worker_rng.gen_prime(size)).is_err() {
+                        break;
+                    }
+                }
+            })
+            .map_err(|_| CryptoError::KeyGenerationFailed(RSA_KEY_GENERATION_FAILED))?;
+    }
+    drop(sender);
+
+    let (mut p, mut q) = (None, None);
+    for prime in receiver {
+        if p.is_none() && prime.bits() == sizes[0] {
+            p = Some(prime);
+            progress(1);
+        } else if p.is_some() && prime.bits() == sizes[1] && p.as_ref() != Some(&prime) {
+            q = Some(prime);
+            progress(2);
+        }
+        if let (Some(p_prime), Some(q_prime)) = (&p, &q) {
+            if let Some(key) = key_from_primes(p_prime.clone(), q_prime.clone(), bits) {
+                return Ok(key);
+            }
+            (p, q) = (None, None);
+        }
+    }
+    Err(CryptoError::KeyGenerationFailed(RSA_KEY_GENERATION_FAILED))
+}
+
+// Tells prime search workers to stop once the search returns
+#[cfg(all(feature = "rsa", feature = "std"))]
+struct StopOnDrop(std::sync::Arc<std::sync::atomic::AtomicBool>);
+
+#[cfg(all(feature = "rsa", feature = "std"))]
+impl Drop for StopOnDrop {
+    fn drop(&mut self) {
+        self.0.store(true, std::sync::atomic::Ordering::Relaxed);
+    }
+}
+
+/// RSA signature padding schemes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "rsa")]
+pub enum RsaSignaturePadding {
+    /// RSASSA-PKCS1-v1_5
+    Pkcs1v15,
+    /// RSASSA-PSS with MGF1 over the same hash and a hash-length salt
+    Pss,
+}
+
+/// Hash functions for RSA signatures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "rsa")]
+pub enum RsaSignatureHash {
+    /// SHA-256
+    Sha256,
+    /// SHA-384
+    Sha384,
+    /// SHA-512
+    Sha512,
+}
+
+/// RSA encryption, decryption, signing and verification
+#[cfg(feature = "rsa")]
 pub struct RsaCrypto;
 
+#[cfg(feature = "rsa")]
 impl RsaCrypto {
     /// Generate a new RSA-2048 key pair
     #[inline]
@@ -107,15 +458,71 @@ impl RsaCrypto {
         private_key.decrypt(padding, ciphertext)
             .map_err(|_| CryptoError::DecryptionFailed(RSA_DECRYPTION_FAILED))
     }
+
+    /// Decrypt data using RSA-OAEP, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(ciphertext: &[u8], private_key: &RsaPrivateKey) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(ciphertext, private_key).map(Zeroizing::new)
+    }
+
+    /// Sign data using RSA with the given padding and hash
+    pub fn sign(message: &[u8], private_key: &RsaPrivateKey, padding: RsaSignaturePadding, hash: RsaSignatureHash) -> CryptoResult<Vec<u8>> {
+        match hash {
+            RsaSignatureHash::Sha256 => rsa_sign::<Sha256>(message, private_key, padding),
+            RsaSignatureHash::Sha384 => rsa_sign::<Sha384>(message, private_key, padding),
+            RsaSignatureHash::Sha512 => rsa_sign::<Sha512>(message, private_key, padding),
+        }
+    }
+
+    /// Verify an RSA signature with the given padding and hash
+    pub fn verify(message: &[u8], signature: &[u8], public_key: &RsaPublicKey, padding: RsaSignaturePadding, hash: RsaSignatureHash) -> CryptoResult<bool> {
+        match hash {
+            RsaSignatureHash::Sha256 => rsa_verify::<Sha256>(message, signature, public_key, padding),
+            RsaSignatureHash::Sha384 => rsa_verify::<Sha384>(message, signature, public_key, padding),
+            RsaSignatureHash::Sha512 => rsa_verify::<Sha512>(message, signature, public_key, padding),
+        }
+    }
+}
+
+#[cfg(feature = "rsa")]
+fn rsa_sign<D>(message: &[u8], private_key: &RsaPrivateKey, padding: RsaSignaturePadding) -> CryptoResult<Vec<u8>>
+where
+    D: rsa::sha2::Digest + rsa::pkcs8::AssociatedOid + rsa::sha2::digest::FixedOutputReset,
+{
+    let signature = match padding {
+        RsaSignaturePadding::Pkcs1v15 => rsa::pkcs1v15::SigningKey::<D>::new(private_key.clone())
+            .try_sign(message)
+            .map(|signature| signature.to_vec()),
+        RsaSignaturePadding::Pss => rsa::pss::SigningKey::<D>::new(private_key.clone())
+            .try_sign_with_rng(&mut OsRng, message)
+            .map(|signature| signature.to_vec()),
+    };
+    signature.map_err(|_| CryptoError::SignatureFailed(RSA_SIGNING_FAILED))
+}
+
+#[cfg(feature = "rsa")]
+fn rsa_verify<D>(message: &[u8], signature: &[u8], public_key: &RsaPublicKey, padding: RsaSignaturePadding) -> CryptoResult<bool>
+where
+    D: rsa::sha2::Digest + rsa::pkcs8::AssociatedOid + rsa::sha2::digest::FixedOutputReset,
+{
+    let valid = match padding {
+        RsaSignaturePadding::Pkcs1v15 => rsa::pkcs1v15::Signature::try_from(signature)
+            .map(|signature| rsa::pkcs1v15::VerifyingKey::<D>::new(public_key.clone()).verify(message, &signature).is_ok()),
+        RsaSignaturePadding::Pss => rsa::pss::Signature::try_from(signature)
+            .map(|signature| rsa::pss::VerifyingKey::<D>::new(public_key.clone()).verify(message, &signature).is_ok()),
+    };
+    valid.map_err(|_| CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT))
 }
 
 /// ECDSA P-256 key pair
 #[derive(Clone)]
+#[cfg(feature = "ecdsa")]
 pub struct EcdsaKeyPair {
     signing_key: SigningKey,
     verifying_key: VerifyingKey,
 }
 
+#[cfg(feature = "ecdsa")]
 impl EcdsaKeyPair {
     /// Generate a new ECDSA P-256 key pair
     pub fn generate() -> CryptoResult<Self> {
@@ -142,8 +549,8 @@ impl EcdsaKeyPair {
 
     /// Export private key bytes
     #[inline]
-    pub fn private_key_bytes(&self) -> Vec<u8> {
-        self.signing_key.to_bytes().to_vec()
+    pub fn private_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(Zeroizing::new(self.signing_key.to_bytes()).to_vec())
     }
 
     /// Export public key bytes
@@ -152,7 +559,7 @@ impl EcdsaKeyPair {
         self.verifying_key.to_encoded_point(false).as_bytes().to_vec()
     }
 
-    /// Import from private key bytes
+    /// Import from private key bytes; the scalar must be in `1..n`
     pub fn from_private_key_bytes(bytes: &[u8]) -> CryptoResult<Self> {
         let signing_key = SigningKey::from_slice(bytes)
             .map_err(|_| CryptoError::InvalidKey(INVALID_ECDSA_PRIVATE_KEY))?;
@@ -165,16 +572,22 @@ impl EcdsaKeyPair {
         })
     }
 
-    /// Import verifying key from bytes
+    /// Import verifying key from SEC1 bytes; the point must be on the curve and not the identity
     pub fn verifying_key_from_bytes(bytes: &[u8]) -> CryptoResult<VerifyingKey> {
         VerifyingKey::from_sec1_bytes(bytes)
             .map_err(|_| CryptoError::InvalidKey(INVALID_ECDSA_PUBLIC_KEY))
     }
 }
 
+// The wrapped private key zeroizes itself on drop
+#[cfg(feature = "ecdsa")]
+impl ZeroizeOnDrop for EcdsaKeyPair {}
+
 /// ECDSA P-256 digital signatures
+#[cfg(feature = "ecdsa")]
 pub struct EcdsaCrypto;
 
+#[cfg(feature = "ecdsa")]
 impl EcdsaCrypto {
     /// Generate a new ECDSA P-256 key pair
     #[inline]
@@ -184,8 +597,14 @@ impl EcdsaCrypto {
 
     /// Sign data using ECDSA P-256
     pub fn sign(message: &[u8], signing_key: &SigningKey) -> CryptoResult<Vec<u8>> {
+        Ok(Self::sign_array(message, signing_key)?.to_vec())
+    }
+
+    /// Sign with ECDSA P-256, returning the 64-byte `r || s` signature without allocating
+    #[inline]
+    pub fn sign_array(message: &[u8], signing_key: &SigningKey) -> CryptoResult<[u8; 64]> {
         let signature: Signature = signing_key.sign(message);
-        Ok(signature.to_bytes().to_vec())
+        Ok(signature.to_bytes().into())
     }
 
     /// Verify ECDSA P-256 signature
@@ -198,15 +617,49 @@ impl EcdsaCrypto {
             Err(_) => Ok(false),
         }
     }
+
+    /// Sign with ECDSA P-256 bound to a domain-separation `context` of at most 255 bytes
+    ///
+    /// Signs the SHA-512 hash of `len(context) || context || message` (ECDSA
+    /// with SHA-512 over that encoding), so a signature made for one context
+    /// never verifies under another, nor under the plain SHA-256 [`Self::verify`].
+    pub fn sign_with_context(message: &[u8], context: &[u8], signing_key: &SigningKey) -> CryptoResult<Vec<u8>> {
+        let signature: Signature = signing_key.sign_prehash(&context_prehash(message, context)?)
+            .map_err(|_| CryptoError::SignatureFailed(ECDSA_SIGNING_FAILED))?;
+        Ok(signature.to_bytes().to_vec())
+    }
+
+    /// Verify a signature made by [`Self::sign_with_context`] with the same `context`
+    pub fn verify_with_context(message: &[u8], context: &[u8], signature: &[u8], verifying_key: &VerifyingKey) -> CryptoResult<bool> {
+        let prehash = context_prehash(message, context)?;
+        let signature = Signature::from_slice(signature)
+            .map_err(|_| CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT))?;
+        Ok(verifying_key.verify_prehash(&prehash, &signature).is_ok())
+    }
+}
+
+// SHA-512(len(context) || context || message); the length byte keeps the encoding unambiguous
+#[cfg(feature = "ecdsa")]
+fn context_prehash(message: &[u8], context: &[u8]) -> CryptoResult<[u8; 64]> {
+    let len = u8::try_from(context.len())
+        .map_err(|_| CryptoError::InvalidInput(SIGNATURE_CONTEXT_TOO_LONG))?;
+    Ok(ContextSha512::new()
+        .chain_update([len])
+        .chain_update(context)
+        .chain_update(message)
+        .finalize()
+        .into())
 }
 
 /// Ed25519 key pair
 #[derive(Clone)]
+#[cfg(feature = "ed25519")]
 pub struct Ed25519KeyPair {
     signing_key: Ed25519SigningKey,
     verifying_key: Ed25519VerifyingKey,
 }
 
+#[cfg(feature = "ed25519")]
 impl Ed25519KeyPair {
     /// Generate a new Ed25519 key pair
     pub fn generate() -> CryptoResult<Self> {
@@ -215,6 +668,7 @@ impl Ed25519KeyPair {
         OsRng.fill_bytes(&mut secret_bytes);
 
         let signing_key = Ed25519SigningKey::from_bytes(&secret_bytes);
+        secret_bytes.zeroize();
         let verifying_key = signing_key.verifying_key();
 
         Ok(Self {
@@ -237,8 +691,8 @@ impl Ed25519KeyPair {
 
     /// Export private key bytes
     #[inline]
-    pub fn private_key_bytes(&self) -> Vec<u8> {
-        self.signing_key.to_bytes().to_vec()
+    pub fn private_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(Zeroizing::new(self.signing_key.to_bytes()).to_vec())
     }
 
     /// Export public key bytes
@@ -262,20 +716,34 @@ impl Ed25519KeyPair {
         })
     }
 
-    /// Import verifying key from bytes
+    /// Import verifying key from bytes, rejecting small-order keys ([`KeyValidation::Strict`])
+    #[inline]
     pub fn verifying_key_from_bytes(bytes: &[u8]) -> CryptoResult<Ed25519VerifyingKey> {
+        Self::verifying_key_from_bytes_with_validation(bytes, KeyValidation::Strict)
+    }
+
+    /// Import verifying key from bytes with the given checks
+    pub fn verifying_key_from_bytes_with_validation(bytes: &[u8], validation: KeyValidation) -> CryptoResult<Ed25519VerifyingKey> {
         if bytes.len() != 32 {
             return Err(CryptoError::InvalidKey(ED25519_PUBLIC_KEY_INVALID_SIZE));
         }
 
-        Ed25519VerifyingKey::from_bytes(bytes.try_into().unwrap())
-            .map_err(|_| CryptoError::InvalidKey(INVALID_ED25519_PUBLIC_KEY))
+        let verifying_key = Ed25519VerifyingKey::from_bytes(bytes.try_into().unwrap())
+            .map_err(|_| CryptoError::InvalidKey(INVALID_ED25519_PUBLIC_KEY))?;
+        validation.check_ed25519(&verifying_key)?;
+        Ok(verifying_key)
     }
 }
 
+// The wrapped private key zeroizes itself on drop
+#[cfg(feature = "ed25519")]
+impl ZeroizeOnDrop for Ed25519KeyPair {}
+
 /// Ed25519 digital signatures
+#[cfg(feature = "ed25519")]
 pub struct Ed25519Crypto;
 
+#[cfg(feature = "ed25519")]
 impl Ed25519Crypto {
     /// Generate a new Ed25519 key pair
     #[inline]
@@ -285,8 +753,13 @@ impl Ed25519Crypto {
 
     /// Sign data using Ed25519
     pub fn sign(message: &[u8], signing_key: &Ed25519SigningKey) -> CryptoResult<Vec<u8>> {
-        let signature = signing_key.sign(message);
-        Ok(signature.to_bytes().to_vec())
+        Ok(Self::sign_array(message, signing_key)?.to_vec())
+    }
+
+    /// Sign with Ed25519, returning the 64-byte signature without allocating
+    #[inline]
+    pub fn sign_array(message: &[u8], signing_key: &Ed25519SigningKey) -> CryptoResult<[u8; 64]> {
+        Ok(signing_key.sign(message).to_bytes())
     }
 
     /// Verify Ed25519 signature
@@ -302,13 +775,39 @@ impl Ed25519Crypto {
             Err(_) => Ok(false),
         }
     }
+
+    /// Sign with Ed25519ph (RFC 8032) bound to a domain-separation `context` of at most 255 bytes
+    ///
+    /// A signature made for one context never verifies under another, nor
+    /// under the plain [`Self::verify`].
+    pub fn sign_with_context(message: &[u8], context: &[u8], signing_key: &Ed25519SigningKey) -> CryptoResult<Vec<u8>> {
+        if context.len() > usize::from(u8::MAX) {
+            return Err(CryptoError::InvalidInput(SIGNATURE_CONTEXT_TOO_LONG));
+        }
+        let signature = signing_key.sign_prehashed(ContextSha512::new().chain_update(message), Some(context))
+            .map_err(|_| CryptoError::SignatureFailed(ED25519_SIGNING_FAILED))?;
+        Ok(signature.to_bytes().to_vec())
+    }
+
+    /// Verify a signature made by [`Self::sign_with_context`] with the same `context`
+    pub fn verify_with_context(message: &[u8], context: &[u8], signature: &[u8], verifying_key: &Ed25519VerifyingKey) -> CryptoResult<bool> {
+        if context.len() > usize::from(u8::MAX) {
+            return Err(CryptoError::InvalidInput(SIGNATURE_CONTEXT_TOO_LONG));
+        }
+        if signature.len() != 64 {
+            return Err(CryptoError::InvalidInput(ED25519_SIGNATURE_INVALID_SIZE));
+        }
+
+        let signature = Ed25519Signature::from_bytes(signature.try_into().unwrap());
+        Ok(verifying_key.verify_prehashed(ContextSha512::new().chain_update(message), Some(context), &signature).is_ok())
+    }
 }
 
 
 
 
 
-#[cfg(test)]
+#[cfg(all(test, feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
 mod tests {
     use super::*;
     use rsa::traits::PublicKeyParts;
@@ -333,6 +832,42 @@ mod tests {
         assert_eq!(keypair.public_key().n(), imported_public.n());
     }
 
+    #[test]
+    fn test_rsa_generate_with_progress() {
+        let mut found = Vec::new();
+        let keypair = RsaKeyPair::generate_with_progress(2048, |primes| found.push(primes)).unwrap();
+        assert_eq!(found.len() % 2, 0);
+        assert_eq!(&found[found.len() - 2..], &[1, 2]);
+
+        assert_eq!(keypair.public_key().n().bits(), 2048);
+        assert_eq!(keypair.public_key().e(), &BigUint::from(65537u32));
+        keypair.private_key().validate().unwrap();
+        let ciphertext = RsaCrypto::encrypt(b"progress", keypair.public_key()).unwrap();
+        assert_eq!(RsaCrypto::decrypt(&ciphertext, keypair.private_key()).unwrap(), b"progress");
+
+        assert!(RsaKeyPair::generate_with_progress(1024, |_| {}).is_err());
+    }
+
+    #[test]
+    fn test_rsa_generate_with_options() {
+        use crate::core::random::SecureRng;
+
+        // Parallel prime search with a reused generator
+        let mut rng = SecureRng::new().unwrap();
+        let mut found = Vec::new();
+        let options = RsaKeyGenOptions { bits: 2048, threads: 4 };
+        let keypair = RsaKeyPair::generate_with_rng(&options, &mut rng, |primes| found.push(primes)).unwrap();
+        assert_eq!(&found[found.len() - 2..], &[1, 2]);
+        assert_eq!(keypair.public_key().n().bits(), 2048);
+        keypair.private_key().validate().unwrap();
+        let signature = RsaCrypto::sign(b"options", keypair.private_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256).unwrap();
+        assert!(RsaCrypto::verify(b"options", &signature, keypair.public_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256).unwrap());
+
+        let keypair = RsaKeyPair::generate_with_options(&RsaKeyGenOptions { threads: 0, ..RsaKeyGenOptions::default() }, |_| {}).unwrap();
+        assert_eq!(keypair.public_key().n().bits(), 2048);
+        assert!(RsaKeyPair::generate_with_options(&RsaKeyGenOptions { bits: 1024, threads: 2 }, |_| {}).is_err());
+    }
+
     #[test]
     fn test_rsa_encrypt_decrypt() {
         let keypair = RsaCrypto::generate_keypair().unwrap();
@@ -343,6 +878,47 @@ mod tests {
 
         let decrypted = RsaCrypto::decrypt(&ciphertext, keypair.private_key()).unwrap();
         assert_eq!(decrypted, plaintext);
+
+        let decrypted = RsaCrypto::decrypt_zeroizing(&ciphertext, keypair.private_key()).unwrap();
+        assert_eq!(decrypted.as_slice(), plaintext);
+    }
+
+    #[test]
+    fn test_rsa_sign_verify() {
+        let keypair = RsaCrypto::generate_keypair().unwrap();
+        let other = RsaCrypto::generate_keypair().unwrap();
+        let message = b"Sign this with RSA";
+
+        for padding in [RsaSignaturePadding::Pkcs1v15, RsaSignaturePadding::Pss] {
+            for hash in [RsaSignatureHash::Sha256, RsaSignatureHash::Sha384, RsaSignatureHash::Sha512] {
+                let signature = RsaCrypto::sign(message, keypair.private_key(), padding, hash).unwrap();
+                assert_eq!(signature.len(), 256);
+                assert!(RsaCrypto::verify(message, &signature, keypair.public_key(), padding, hash).unwrap());
+                assert!(!RsaCrypto::verify(b"Different message", &signature, keypair.public_key(), padding, hash).unwrap());
+                assert!(!RsaCrypto::verify(message, &signature, other.public_key(), padding, hash).unwrap());
+            }
+        }
+
+        // PKCS#1 v1.5 is deterministic and bound to the hash; PSS is randomized
+        let pkcs1 = RsaCrypto::sign(message, keypair.private_key(), RsaSignaturePadding::Pkcs1v15, RsaSignatureHash::Sha256).unwrap();
+        assert_eq!(pkcs1, RsaCrypto::sign(message, keypair.private_key(), RsaSignaturePadding::Pkcs1v15, RsaSignatureHash::Sha256).unwrap());
+        assert!(!RsaCrypto::verify(message, &pkcs1, keypair.public_key(), RsaSignaturePadding::Pkcs1v15, RsaSignatureHash::Sha512).unwrap());
+        assert!(!RsaCrypto::verify(message, &pkcs1, keypair.public_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256).unwrap());
+        let pss = RsaCrypto::sign(message, keypair.private_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256).unwrap();
+        assert_ne!(pss, RsaCrypto::sign(message, keypair.private_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256).unwrap());
+    }
+
+    #[test]
+    fn test_key_pairs_zeroize_on_drop() {
+        fn assert_zeroize_on_drop<T: ZeroizeOnDrop>() {}
+        assert_zeroize_on_drop::<RsaKeyPair>();
+        assert_zeroize_on_drop::<EcdsaKeyPair>();
+        assert_zeroize_on_drop::<Ed25519KeyPair>();
+
+        // The markers above rely on the wrapped private keys wiping themselves
+        assert_zeroize_on_drop::<RsaPrivateKey>();
+        assert_zeroize_on_drop::<SigningKey>();
+        assert_zeroize_on_drop::<Ed25519SigningKey>();
     }
 
     #[test]
@@ -376,6 +952,8 @@ mod tests {
 
         let signature = EcdsaCrypto::sign(message, keypair.signing_key()).unwrap();
         assert_eq!(signature.len(), 64); // ECDSA signature is 64 bytes
+        // RFC 6979 nonces are deterministic, so both forms agree
+        assert_eq!(EcdsaCrypto::sign_array(message, keypair.signing_key()).unwrap(), signature.as_slice());
 
         let is_valid = EcdsaCrypto::verify(message, &signature, keypair.verifying_key()).unwrap();
         assert!(is_valid);
@@ -410,6 +988,7 @@ mod tests {
 
         let signature = Ed25519Crypto::sign(message, keypair.signing_key()).unwrap();
         assert_eq!(signature.len(), 64); // Ed25519 signature is 64 bytes
+        assert_eq!(Ed25519Crypto::sign_array(message, keypair.signing_key()).unwrap(), signature.as_slice());
 
         let is_valid = Ed25519Crypto::verify(message, &signature, keypair.verifying_key()).unwrap();
         assert!(is_valid);
@@ -439,5 +1018,111 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_key_validation() {
+        // 1024-bit modulus
+        let small = RsaPrivateKey::new(&mut OsRng, 1024).unwrap();
+        let pem = small.to_pkcs8_pem(rsa::pkcs8::LineEnding::LF).unwrap();
+        assert_eq!(RsaKeyPair::from_private_key_pem(&pem).err(), Some(CryptoError::InvalidKey(RSA_KEY_SIZE_TOO_SMALL)));
+        assert!(RsaKeyPair::from_private_key_pem_with_validation(&pem, KeyValidation::Legacy).is_ok());
+        let der = small.to_pkcs8_der().unwrap();
+        assert_eq!(RsaKeyPair::from_private_key_der(der.as_bytes()).err(), Some(CryptoError::InvalidKey(RSA_KEY_SIZE_TOO_SMALL)));
+        assert!(RsaKeyPair::from_private_key_der_with_validation(der.as_bytes(), KeyValidation::Legacy).is_ok());
+        let public_pem = small.to_public_key().to_public_key_pem(rsa::pkcs8::LineEnding::LF).unwrap();
+        assert_eq!(RsaKeyPair::from_public_key_pem(&public_pem), Err(CryptoError::InvalidKey(RSA_KEY_SIZE_TOO_SMALL)));
+        assert!(RsaKeyPair::from_public_key_pem_with_validation(&public_pem, KeyValidation::Legacy).is_ok());
+
+        // e = 3
+        let low_exponent = RsaPrivateKey::new_with_exp(&mut OsRng, 2048, &BigUint::from(3u32)).unwrap();
+        let pem = low_exponent.to_pkcs8_pem(rsa::pkcs8::LineEnding::LF).unwrap();
+        assert_eq!(RsaKeyPair::from_private_key_pem(&pem).err(), Some(CryptoError::InvalidKey(RSA_WEAK_PUBLIC_EXPONENT)));
+        assert!(RsaKeyPair::from_private_key_pem_with_validation(&pem, KeyValidation::Legacy).is_ok());
+
+        // Generated keys pass the strict checks
+        let keypair = RsaKeyPair::generate(2048).unwrap();
+        assert!(RsaKeyPair::from_private_key_pem(&keypair.private_key_pem().unwrap()).is_ok());
+
+        // The Ed25519 identity point has small order
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert_eq!(Ed25519KeyPair::verifying_key_from_bytes(&identity), Err(CryptoError::InvalidKey(ED25519_WEAK_PUBLIC_KEY)));
+        assert!(Ed25519KeyPair::verifying_key_from_bytes_with_validation(&identity, KeyValidation::Legacy).is_ok());
+        let keypair = Ed25519KeyPair::generate().unwrap();
+        assert!(Ed25519KeyPair::verifying_key_from_bytes(&keypair.public_key_bytes()).is_ok());
+
+        // P-256 points off the curve are always rejected
+        let mut off_curve = [0u8; 65];
+        off_curve[0] = 4;
+        off_curve[32] = 1;
+        off_curve[64] = 1;
+        assert_eq!(EcdsaKeyPair::verifying_key_from_bytes(&off_curve), Err(CryptoError::InvalidKey(INVALID_ECDSA_PUBLIC_KEY)));
+        assert!(EcdsaKeyPair::from_private_key_bytes(&[0u8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_signing_context() {
+        let ecdsa = EcdsaKeyPair::generate().unwrap();
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        let long_context = [0u8; 256];
+
+        let signature = EcdsaCrypto::sign_with_context(b"message", b"invoice", ecdsa.signing_key()).unwrap();
+        assert!(EcdsaCrypto::verify_with_context(b"message", b"invoice", &signature, ecdsa.verifying_key()).unwrap());
+        assert!(!EcdsaCrypto::verify_with_context(b"message", b"receipt", &signature, ecdsa.verifying_key()).unwrap());
+        assert!(!EcdsaCrypto::verify_with_context(b"message", b"", &signature, ecdsa.verifying_key()).unwrap());
+        assert!(!EcdsaCrypto::verify(b"message", &signature, ecdsa.verifying_key()).unwrap());
+        // The length prefix keeps (context, message) splits apart
+        assert!(!EcdsaCrypto::verify_with_context(b"emessage", b"invoic", &signature, ecdsa.verifying_key()).unwrap());
+        let plain = EcdsaCrypto::sign(b"message", ecdsa.signing_key()).unwrap();
+        assert!(!EcdsaCrypto::verify_with_context(b"message", b"", &plain, ecdsa.verifying_key()).unwrap());
+        assert_eq!(EcdsaCrypto::sign_with_context(b"m", &long_context, ecdsa.signing_key()).unwrap_err(), CryptoError::InvalidInput(SIGNATURE_CONTEXT_TOO_LONG));
+        assert!(EcdsaCrypto::sign_with_context(b"m", &long_context[..255], ecdsa.signing_key()).is_ok());
+
+        let signature = Ed25519Crypto::sign_with_context(b"message", b"invoice", ed25519.signing_key()).unwrap();
+        assert!(Ed25519Crypto::verify_with_context(b"message", b"invoice", &signature, ed25519.verifying_key()).unwrap());
+        assert!(!Ed25519Crypto::verify_with_context(b"message", b"receipt", &signature, ed25519.verifying_key()).unwrap());
+        assert!(!Ed25519Crypto::verify(b"message", &signature, ed25519.verifying_key()).unwrap());
+        let plain = Ed25519Crypto::sign(b"message", ed25519.signing_key()).unwrap();
+        assert!(!Ed25519Crypto::verify_with_context(b"message", b"", &plain, ed25519.verifying_key()).unwrap());
+        assert_eq!(Ed25519Crypto::sign_with_context(b"m", &long_context, ed25519.signing_key()).unwrap_err(), CryptoError::InvalidInput(SIGNATURE_CONTEXT_TOO_LONG));
+        assert!(Ed25519Crypto::verify_with_context(b"m", b"", &[0u8; 63], ed25519.verifying_key()).is_err());
+
+        // RFC 8032 section 7.3, Ed25519ph "abc"
+        let keypair = Ed25519KeyPair::from_private_key_bytes(&hex::decode("833fe62409237b9d62ec77587520911e9a759cec1d19755b7da901b96dca3d42").unwrap()).unwrap();
+        let signature = Ed25519Crypto::sign_with_context(b"abc", b"", keypair.signing_key()).unwrap();
+        assert_eq!(hex::encode(&signature), "98a70222f0b8121aa9d30f813d683f809e462b469c7ff87639499bb94e6dae4131f85042463c2a355a2003d062adf5aaa10b8c61e636062aaad11c2a26083406");
+    }
 
+    #[test]
+    fn test_pem_der_import_errors() {
+        use ed25519_dalek::pkcs8::EncodePrivateKey as _;
+
+        let keypair = RsaKeyPair::generate(2048).unwrap();
+        let pem = keypair.private_key_pem().unwrap();
+        let public_pem = keypair.public_key_pem().unwrap();
+        let err = |pem: &str| RsaKeyPair::from_private_key_pem(pem).err().unwrap();
+
+        // PEM layer
+        assert_eq!(err(&pem.replace("-----END PRIVATE KEY-----", "")), CryptoError::InvalidKey(PEM_INVALID_BOUNDARY));
+        assert_eq!(err("not a key"), CryptoError::InvalidKey(PEM_INVALID_BOUNDARY));
+        assert_eq!(err(&pem.replacen("MII", "MI*", 1)), CryptoError::InvalidKey(PEM_INVALID_BODY));
+        let with_header = pem.replacen("-----\n", "-----\nProc-Type: 4,ENCRYPTED\n\n", 1);
+        assert_eq!(err(&with_header), CryptoError::InvalidKey(PEM_HEADERS_UNSUPPORTED));
+        assert_eq!(err(&public_pem), CryptoError::InvalidKey(PEM_UNEXPECTED_LABEL));
+        assert_eq!(err(&pem.replace("PRIVATE KEY", "ENCRYPTED PRIVATE KEY")), CryptoError::InvalidKey(PEM_ENCRYPTED_KEY));
+        assert_eq!(RsaKeyPair::from_public_key_pem(&pem).unwrap_err(), CryptoError::InvalidKey(PEM_UNEXPECTED_LABEL));
+
+        // DER layer
+        let der = keypair.private_key_der().unwrap();
+        assert_eq!(RsaKeyPair::from_private_key_der(&der[..der.len() - 1]).err(), Some(CryptoError::InvalidKey(DER_INVALID_STRUCTURE)));
+        let mut trailing = der.to_vec();
+        trailing.push(0);
+        assert_eq!(RsaKeyPair::from_private_key_der(&trailing).err(), Some(CryptoError::InvalidKey(DER_INVALID_STRUCTURE)));
+        let ed25519_pem = Ed25519KeyPair::generate().unwrap().signing_key().to_pkcs8_pem(rsa::pkcs8::LineEnding::LF).unwrap();
+        assert_eq!(err(&ed25519_pem), CryptoError::InvalidKey(KEY_ALGORITHM_MISMATCH));
+
+        // Well-formed input still imports
+        assert!(RsaKeyPair::from_private_key_pem(&pem).is_ok());
+        assert!(RsaKeyPair::from_private_key_der(&der).is_ok());
+        assert_eq!(RsaKeyPair::from_public_key_pem(&public_pem).unwrap(), *keypair.public_key());
+    }
 }
\ No newline at end of file
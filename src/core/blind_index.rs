@@ -0,0 +1,147 @@
+use crate::error::{CryptoError, CryptoResult, BLIND_INDEX_INVALID_LENGTH};
+use crate::core::hash::Hmac;
+use crate::core::kdf::HkdfKdf;
+use crate::core::keys::HmacKey;
+use alloc::{borrow::Cow, vec::Vec};
+
+// HKDF info prefix for per-column keys; the column name follows
+const COLUMN_KEY_INFO: &[u8] = b"libsilver blind index v1\0";
+const COLUMN_KEY_SIZE: usize = 32;
+
+/// How [`BlindIndex::compute`] normalizes a value before hashing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IndexNormalization {
+    /// Hash the value as given
+    #[default]
+    Exact,
+    /// Strip leading and trailing whitespace
+    Trim,
+    /// Strip whitespace and lowercase (Unicode default case mapping, no NFC),
+    /// e.g. for email addresses
+    CaseInsensitive,
+}
+
+/// Keyed, truncated HMAC-SHA256 of a value, for equality search over an encrypted column
+///
+/// Store the index next to the ciphertext and look rows up by the index of
+/// the search term. Without the key the index reveals nothing about the
+/// value, but equal values always get equal indexes, so use one key per
+/// column ([`BlindIndex::for_column`]) and truncate: shorter indexes
+/// (the default is 16 bytes) give false positives to filter after decrypting
+/// and leak less about which rows share a value.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let master_key = HmacKey::generate()?;
+/// let email_index = BlindIndex::for_column(&master_key, "users.email")?
+///     .with_normalization(IndexNormalization::CaseInsensitive);
+/// assert_eq!(email_index.compute("Alice@Example.com ")?, email_index.compute("alice@example.com")?);
+/// # Ok::<(), CryptoError>(())
+/// ```
+#[derive(Clone)]
+pub struct BlindIndex {
+    key: HmacKey,
+    normalization: IndexNormalization,
+    output_len: usize,
+}
+
+impl BlindIndex {
+    /// Default index length in bytes
+    pub const DEFAULT_LENGTH: usize = 16;
+
+    /// Index values under `index_key` as given, [`Self::DEFAULT_LENGTH`] bytes long
+    #[inline]
+    pub fn new(index_key: &HmacKey) -> Self {
+        Self {
+            key: index_key.clone(),
+            normalization: IndexNormalization::Exact,
+            output_len: Self::DEFAULT_LENGTH,
+        }
+    }
+
+    /// Index under a key derived from `master_key` for `column` (HKDF-SHA256)
+    ///
+    /// Name columns stably, e.g. `"users.email"`: renaming one changes every index.
+    pub fn for_column(master_key: &HmacKey, column: &str) -> CryptoResult<Self> {
+        let mut info = Vec::with_capacity(COLUMN_KEY_INFO.len() + column.len());
+        info.extend_from_slice(COLUMN_KEY_INFO);
+        info.extend_from_slice(column.as_bytes());
+        let key = HkdfKdf::derive_sha256_zeroizing(master_key.as_bytes(), None, &info, COLUMN_KEY_SIZE)?;
+        Ok(Self::new(&HmacKey::from_bytes(&key)?))
+    }
+
+    /// Normalize values before hashing
+    #[inline]
+    pub fn with_normalization(mut self, normalization: IndexNormalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    /// Truncate indexes to `output_len` bytes, from 1 to 32
+    #[inline]
+    pub fn with_output_len(mut self, output_len: usize) -> CryptoResult<Self> {
+        if !(1..=32).contains(&output_len) {
+            return Err(CryptoError::InvalidInput(BLIND_INDEX_INVALID_LENGTH));
+        }
+        self.output_len = output_len;
+        Ok(self)
+    }
+
+    /// Index length in bytes
+    #[inline]
+    pub fn output_len(&self) -> usize {
+        self.output_len
+    }
+
+    /// Index a text value after normalizing it
+    pub fn compute(&self, value: &str) -> CryptoResult<Vec<u8>> {
+        let normalized = match self.normalization {
+            IndexNormalization::Exact => Cow::Borrowed(value),
+            IndexNormalization::Trim => Cow::Borrowed(value.trim()),
+            IndexNormalization::CaseInsensitive => Cow::Owned(value.trim().to_lowercase()),
+        };
+        self.compute_bytes(normalized.as_bytes())
+    }
+
+    /// Index raw bytes, without normalization
+    pub fn compute_bytes(&self, value: &[u8]) -> CryptoResult<Vec<u8>> {
+        let mac = Hmac::sha256_array(&self.key, value)?;
+        Ok(mac[..self.output_len].to_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blind_index() {
+        let key = HmacKey::from_bytes(&[7u8; 32]).unwrap();
+        let index = BlindIndex::new(&key);
+        let full = Hmac::sha256(&key, b"alice@example.com").unwrap();
+        assert_eq!(index.compute("alice@example.com").unwrap(), full[..16]);
+        assert_eq!(index.compute_bytes(b"alice@example.com").unwrap(), full[..16]);
+        assert_ne!(index.compute("Alice@example.com").unwrap(), index.compute("alice@example.com").unwrap());
+
+        let trimmed = index.clone().with_normalization(IndexNormalization::Trim);
+        assert_eq!(trimmed.compute(" alice@example.com\n").unwrap(), full[..16]);
+        let folded = index.clone().with_normalization(IndexNormalization::CaseInsensitive);
+        assert_eq!(folded.compute(" ALICE@Example.COM").unwrap(), full[..16]);
+
+        let short = index.clone().with_output_len(4).unwrap();
+        assert_eq!(short.output_len(), 4);
+        assert_eq!(short.compute("alice@example.com").unwrap(), full[..4]);
+        assert_eq!(index.clone().with_output_len(32).unwrap().compute("alice@example.com").unwrap(), full);
+        for len in [0, 33] {
+            assert_eq!(index.clone().with_output_len(len).err(), Some(CryptoError::InvalidInput(BLIND_INDEX_INVALID_LENGTH)));
+        }
+
+        // Columns get independent keys
+        let email = BlindIndex::for_column(&key, "users.email").unwrap();
+        let phone = BlindIndex::for_column(&key, "users.phone").unwrap();
+        assert_eq!(email.compute("x").unwrap(), BlindIndex::for_column(&key, "users.email").unwrap().compute("x").unwrap());
+        assert_ne!(email.compute("x").unwrap(), phone.compute("x").unwrap());
+        assert_ne!(email.compute("x").unwrap(), index.compute("x").unwrap());
+    }
+}
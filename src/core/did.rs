@@ -0,0 +1,113 @@
+use crate::error::{CryptoError, CryptoResult, DID_KEY_INVALID, DID_KEY_UNSUPPORTED};
+use crate::core::encoding::{decode_base58, encode_base58};
+use crate::core::keyformat::{AsymmetricKey, KeyAlgorithm};
+
+const DID_KEY_PREFIX: &str = "did:key:";
+// Multibase prefix for base58btc
+const MULTIBASE_BASE58BTC: char = 'z';
+// Multicodec varints: ed25519-pub (0xed) and p256-pub (0x1200)
+const MULTICODEC_ED25519_PUB: [u8; 2] = [0xed, 0x01];
+const MULTICODEC_P256_PUB: [u8; 2] = [0x80, 0x24];
+
+/// `did:key` identifiers for Ed25519 and P-256 public keys
+///
+/// The key is multicodec-tagged and multibase (base58btc) encoded, as in the
+/// W3C did:key method. P-256 keys use the compressed SEC1 point.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let keypair = Ed25519Crypto::generate_keypair()?;
+/// let did = DidKey::encode(&AsymmetricKey::from(&keypair))?;
+/// assert!(did.starts_with("did:key:z6Mk"));
+/// assert_eq!(*DidKey::decode(&did)?.to_raw()?, keypair.public_key_bytes());
+/// # Ok::<(), CryptoError>(())
+/// ```
+pub struct DidKey;
+
+impl DidKey {
+    /// Encode a public key as `did:key:z...`; private keys are encoded by their public half
+    pub fn encode(key: &AsymmetricKey) -> CryptoResult<String> {
+        let (codec, public_key) = match key.public_key() {
+            AsymmetricKey::Ed25519Public(key) => (MULTICODEC_ED25519_PUB, key.to_bytes().to_vec()),
+            AsymmetricKey::EcdsaPublic(key) => (MULTICODEC_P256_PUB, key.to_encoded_point(true).as_bytes().to_vec()),
+            _ => return Err(CryptoError::InvalidKey(DID_KEY_UNSUPPORTED)),
+        };
+
+        let mut did = String::from(DID_KEY_PREFIX);
+        did.push(MULTIBASE_BASE58BTC);
+        did.push_str(&encode_base58(&[&codec[..], &public_key].concat()));
+        Ok(did)
+    }
+
+    /// Decode a `did:key` into its public key
+    ///
+    /// A DID URL fragment, as in `did:key:z6Mk...#z6Mk...`, is ignored.
+    pub fn decode(did: &str) -> CryptoResult<AsymmetricKey> {
+        let did = did.split_once('#').map_or(did, |(did, _)| did);
+        let multibase = did.strip_prefix(DID_KEY_PREFIX)
+            .and_then(|id| id.strip_prefix(MULTIBASE_BASE58BTC))
+            .ok_or(CryptoError::InvalidInput(DID_KEY_INVALID))?;
+        let bytes = decode_base58(multibase)
+            .map_err(|_| CryptoError::InvalidInput(DID_KEY_INVALID))?;
+
+        match bytes.split_at_checked(MULTICODEC_ED25519_PUB.len()) {
+            Some((codec, public_key)) if codec == MULTICODEC_ED25519_PUB => {
+                AsymmetricKey::from_raw_public(KeyAlgorithm::Ed25519, public_key)
+            }
+            // Only the compressed form is valid in did:key
+            Some((codec, public_key)) if codec == MULTICODEC_P256_PUB && public_key.len() == 33 => {
+                AsymmetricKey::from_raw_public(KeyAlgorithm::EcdsaP256, public_key)
+            }
+            Some((codec, _)) if codec == MULTICODEC_P256_PUB => Err(CryptoError::InvalidInput(DID_KEY_INVALID)),
+            _ => Err(CryptoError::InvalidKey(DID_KEY_UNSUPPORTED)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::{EcdsaCrypto, RsaCrypto};
+
+    #[test]
+    fn test_did_key_vectors() {
+        // W3C did:key test vectors
+        let ed25519 = "did:key:z6MkiTBz1ymuepAQ4HEHYSF1H8quG5GLVVQR3djdX3mDooWp";
+        let key = DidKey::decode(ed25519).unwrap();
+        assert_eq!(key.algorithm(), KeyAlgorithm::Ed25519);
+        assert_eq!(hex::encode(key.to_raw().unwrap()), "3b6a27bcceb6a42d62a3a8d02a6f0d73653215771de243a63ac048a18b59da29");
+        assert_eq!(DidKey::encode(&key).unwrap(), ed25519);
+
+        let p256 = "did:key:zDnaerDaTF5BXEavCrfRZEk316dpbLsfPDZ3WJ5hRTPFU2169";
+        let key = DidKey::decode(p256).unwrap();
+        assert_eq!(key.algorithm(), KeyAlgorithm::EcdsaP256);
+        assert_eq!(DidKey::encode(&key).unwrap(), p256);
+
+        // RFC 8037 appendix A.1 key
+        let key = AsymmetricKey::from_raw_public(KeyAlgorithm::Ed25519, &hex::decode("d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a").unwrap()).unwrap();
+        assert_eq!(DidKey::encode(&key).unwrap(), "did:key:z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw");
+        assert_eq!(DidKey::decode("did:key:z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw#z6MktwupdmLXVVqTzCw4i46r4uGyosGXRnR3XjN4Zq7oMMsw").unwrap().to_raw().unwrap(), key.to_raw().unwrap());
+    }
+
+    #[test]
+    fn test_did_key_roundtrip_and_errors() {
+        let ecdsa = AsymmetricKey::from(&EcdsaCrypto::generate_keypair().unwrap());
+        let did = DidKey::encode(&ecdsa).unwrap();
+        assert!(did.starts_with("did:key:zDn"));
+        assert_eq!(DidKey::decode(&did).unwrap().to_der().unwrap(), ecdsa.public_key().to_der().unwrap());
+
+        let rsa = AsymmetricKey::from(&RsaCrypto::generate_keypair().unwrap());
+        assert_eq!(DidKey::encode(&rsa).unwrap_err(), CryptoError::InvalidKey(DID_KEY_UNSUPPORTED));
+
+        let invalid = CryptoError::InvalidInput(DID_KEY_INVALID);
+        assert_eq!(DidKey::decode("did:web:example.com").unwrap_err(), invalid);
+        assert_eq!(DidKey::decode("did:key:f01ed").unwrap_err(), invalid);
+        assert_eq!(DidKey::decode("did:key:z0OIl").unwrap_err(), invalid);
+        // Uncompressed P-256 points are not valid did:key values
+        let uncompressed = [&MULTICODEC_P256_PUB[..], &ecdsa.public_key().to_raw().unwrap()].concat();
+        assert_eq!(DidKey::decode(&format!("did:key:z{}", encode_base58(&uncompressed))).unwrap_err(), invalid);
+        // secp256k1-pub (0xe7)
+        assert_eq!(DidKey::decode(&format!("did:key:z{}", encode_base58(&[0xe7, 0x01, 0x02]))).unwrap_err(), CryptoError::InvalidKey(DID_KEY_UNSUPPORTED));
+    }
+}
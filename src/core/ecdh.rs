@@ -0,0 +1,280 @@
+use crate::error::{CryptoError, CryptoResult, ECDH_INVALID_PRIVATE_KEY, ECDH_INVALID_PUBLIC_KEY, ECDH_WEAK_KEY};
+#[cfg(feature = "aes")]
+use crate::error::ECIES_INVALID_CIPHERTEXT;
+#[cfg(not(feature = "ecdsa"))]
+use crate::error::UNSUPPORTED_ALGORITHM;
+#[cfg(feature = "aes")]
+use crate::core::{kdf::HkdfKdf, keys::Aes256Key, symmetric::AesGcm};
+use crate::core::random::SecureRandom;
+#[cfg(feature = "ecdsa")]
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+use alloc::vec::Vec;
+
+// ECIES layout:
+//   ephemeral public key (32 for X25519, 65 uncompressed for P-256)
+//   | AES-GCM(nonce + plaintext + tag)
+// The AES key is HKDF-SHA256(shared secret, salt = ephemeral || recipient public key).
+const KEY_SIZE: usize = 32;
+const X25519_PUBLIC_KEY_SIZE: usize = 32;
+const P256_PUBLIC_KEY_SIZE: usize = 65;
+#[cfg(feature = "aes")]
+const KDF_INFO: &[u8] = b"libsilver ECIES v1 key";
+
+/// Curves supported for key agreement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EcdhCurve {
+    /// X25519 (RFC 7748)
+    #[default]
+    X25519,
+    /// NIST P-256 (`ecdsa` feature)
+    P256,
+}
+
+impl EcdhCurve {
+    /// Length of an encoded public key (uncompressed SEC1 for P-256)
+    pub fn public_key_len(self) -> usize {
+        match self {
+            EcdhCurve::X25519 => X25519_PUBLIC_KEY_SIZE,
+            EcdhCurve::P256 => P256_PUBLIC_KEY_SIZE,
+        }
+    }
+}
+
+/// Key pair for X25519 or P-256 key agreement
+#[derive(Clone)]
+pub struct EcdhKeyPair {
+    curve: EcdhCurve,
+    private_key: Zeroizing<Vec<u8>>,
+    public_key: Vec<u8>,
+}
+
+impl EcdhKeyPair {
+    /// Generate a new key pair on `curve`
+    pub fn generate(curve: EcdhCurve) -> CryptoResult<Self> {
+        match curve {
+            EcdhCurve::X25519 => Self::from_private_key_bytes(curve, SecureRandom::generate_key(KEY_SIZE)?.as_bytes()),
+            #[cfg(feature = "ecdsa")]
+            EcdhCurve::P256 => {
+                let secret = p256::SecretKey::random(&mut rand::rngs::OsRng);
+                Self::from_private_key_bytes(curve, &secret.to_bytes())
+            }
+            #[cfg(not(feature = "ecdsa"))]
+            EcdhCurve::P256 => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+        }
+    }
+
+    /// Import a 32-byte private key
+    pub fn from_private_key_bytes(curve: EcdhCurve, bytes: &[u8]) -> CryptoResult<Self> {
+        let public_key = match curve {
+            EcdhCurve::X25519 => X25519PublicKey::from(&x25519_secret(bytes)?).as_bytes().to_vec(),
+            #[cfg(feature = "ecdsa")]
+            EcdhCurve::P256 => p256_secret(bytes)?.public_key().to_encoded_point(false).as_bytes().to_vec(),
+            #[cfg(not(feature = "ecdsa"))]
+            EcdhCurve::P256 => return Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+        };
+        Ok(Self {
+            curve,
+            private_key: Zeroizing::new(bytes.to_vec()),
+            public_key,
+        })
+    }
+
+    /// Get the curve
+    #[inline]
+    pub fn curve(&self) -> EcdhCurve {
+        self.curve
+    }
+
+    /// Export private key bytes
+    #[inline]
+    pub fn private_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        self.private_key.clone()
+    }
+
+    /// Export public key bytes (uncompressed SEC1 for P-256)
+    #[inline]
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        self.public_key.clone()
+    }
+}
+
+/// Elliptic-curve Diffie-Hellman key agreement
+pub struct Ecdh;
+
+impl Ecdh {
+    /// Generate a key pair on `curve`
+    #[inline]
+    pub fn generate_keypair(curve: EcdhCurve) -> CryptoResult<EcdhKeyPair> {
+        EcdhKeyPair::generate(curve)
+    }
+
+    /// Compute the raw shared secret with a peer's public key
+    ///
+    /// Matches WebCrypto `deriveBits` and Node's `crypto.diffieHellman`. The
+    /// result is not uniformly random; pass it through a KDF such as
+    /// [`HkdfKdf`] before using it as a key.
+    pub fn derive_shared_secret(keypair: &EcdhKeyPair, peer_public_key: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        match keypair.curve {
+            EcdhCurve::X25519 => {
+                let peer: [u8; X25519_PUBLIC_KEY_SIZE] = peer_public_key.try_into()
+                    .map_err(|_| CryptoError::InvalidKey(ECDH_INVALID_PUBLIC_KEY))?;
+                let shared = x25519_secret(&keypair.private_key)?.diffie_hellman(&X25519PublicKey::from(peer));
+                if !shared.was_contributory() {
+                    return Err(CryptoError::InvalidKey(ECDH_WEAK_KEY));
+                }
+                Ok(Zeroizing::new(shared.as_bytes().to_vec()))
+            }
+            #[cfg(feature = "ecdsa")]
+            EcdhCurve::P256 => {
+                let peer = p256::PublicKey::from_sec1_bytes(peer_public_key)
+                    .map_err(|_| CryptoError::InvalidKey(ECDH_INVALID_PUBLIC_KEY))?;
+                let secret = p256_secret(&keypair.private_key)?;
+                let shared = p256::ecdh::diffie_hellman(secret.to_nonzero_scalar(), peer.as_affine());
+                Ok(Zeroizing::new(shared.raw_secret_bytes().to_vec()))
+            }
+            #[cfg(not(feature = "ecdsa"))]
+            EcdhCurve::P256 => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+        }
+    }
+}
+
+/// Hybrid public-key encryption (ECIES)
+///
+/// Encrypts to a recipient's X25519 or P-256 public key using an ephemeral
+/// key pair, HKDF-SHA256 and AES-256-GCM.
+#[cfg(feature = "aes")]
+pub struct Ecies;
+
+#[cfg(feature = "aes")]
+impl Ecies {
+    /// Encrypt `plaintext` to `recipient_public_key` on `curve`
+    pub fn encrypt(plaintext: &[u8], recipient_public_key: &[u8], curve: EcdhCurve) -> CryptoResult<Vec<u8>> {
+        let recipient_public_key = canonical_public_key(curve, recipient_public_key)?;
+        let ephemeral = EcdhKeyPair::generate(curve)?;
+        let shared = Ecdh::derive_shared_secret(&ephemeral, &recipient_public_key)?;
+        let key = derive_key(&shared, &ephemeral.public_key, &recipient_public_key)?;
+
+        let mut ciphertext = ephemeral.public_key_bytes();
+        ciphertext.extend_from_slice(&AesGcm::encrypt(plaintext, &key)?);
+        Ok(ciphertext)
+    }
+
+    /// Decrypt a message encrypted to `recipient`'s public key
+    pub fn decrypt(ciphertext: &[u8], recipient: &EcdhKeyPair) -> CryptoResult<Vec<u8>> {
+        let header_len = recipient.curve.public_key_len();
+        if ciphertext.len() < header_len {
+            return Err(CryptoError::DecryptionFailed(ECIES_INVALID_CIPHERTEXT));
+        }
+        let (ephemeral_public, ciphertext) = ciphertext.split_at(header_len);
+        let shared = Ecdh::derive_shared_secret(recipient, ephemeral_public)?;
+        let key = derive_key(&shared, ephemeral_public, &recipient.public_key)?;
+        AesGcm::decrypt(ciphertext, &key)
+    }
+
+    /// Decrypt a message encrypted to `recipient`'s public key, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(ciphertext: &[u8], recipient: &EcdhKeyPair) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(ciphertext, recipient).map(Zeroizing::new)
+    }
+}
+
+fn x25519_secret(bytes: &[u8]) -> CryptoResult<StaticSecret> {
+    let bytes: Zeroizing<[u8; KEY_SIZE]> = Zeroizing::new(bytes.try_into()
+        .map_err(|_| CryptoError::InvalidKey(ECDH_INVALID_PRIVATE_KEY))?);
+    Ok(StaticSecret::from(*bytes))
+}
+
+#[cfg(feature = "ecdsa")]
+fn p256_secret(bytes: &[u8]) -> CryptoResult<p256::SecretKey> {
+    p256::SecretKey::from_slice(bytes).map_err(|_| CryptoError::InvalidKey(ECDH_INVALID_PRIVATE_KEY))
+}
+
+// Bind the KDF to one encoding of the recipient key, so compressed and
+// uncompressed P-256 keys decrypt the same way
+#[cfg(feature = "aes")]
+fn canonical_public_key(curve: EcdhCurve, public_key: &[u8]) -> CryptoResult<Vec<u8>> {
+    match curve {
+        EcdhCurve::X25519 if public_key.len() == X25519_PUBLIC_KEY_SIZE => Ok(public_key.to_vec()),
+        EcdhCurve::X25519 => Err(CryptoError::InvalidKey(ECDH_INVALID_PUBLIC_KEY)),
+        #[cfg(feature = "ecdsa")]
+        EcdhCurve::P256 => p256::PublicKey::from_sec1_bytes(public_key)
+            .map(|key| key.to_encoded_point(false).as_bytes().to_vec())
+            .map_err(|_| CryptoError::InvalidKey(ECDH_INVALID_PUBLIC_KEY)),
+        #[cfg(not(feature = "ecdsa"))]
+        EcdhCurve::P256 => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+    }
+}
+
+#[cfg(feature = "aes")]
+fn derive_key(shared: &[u8], ephemeral_public: &[u8], recipient_public: &[u8]) -> CryptoResult<Aes256Key> {
+    let salt = [ephemeral_public, recipient_public].concat();
+    Aes256Key::from_bytes(&HkdfKdf::derive_sha256_zeroizing(shared, Some(&salt), KDF_INFO, KEY_SIZE)?)
+}
+
+#[cfg(all(test, feature = "aes", feature = "ecdsa"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x25519_rfc7748_vector() {
+        let alice = EcdhKeyPair::from_private_key_bytes(EcdhCurve::X25519, &hex::decode("77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a").unwrap()).unwrap();
+        let bob = EcdhKeyPair::from_private_key_bytes(EcdhCurve::X25519, &hex::decode("5dab087e624a8a4b79e17f8b83800ee66f3bb1292618b6fd1c2f8b27ff88e0eb").unwrap()).unwrap();
+        assert_eq!(hex::encode(alice.public_key_bytes()), "8520f0098930a754748b7ddcb43ef75a0dbf3a0d26381af4eba4a98eaa9b4e6a");
+        assert_eq!(hex::encode(bob.public_key_bytes()), "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f");
+
+        let shared = Ecdh::derive_shared_secret(&alice, &bob.public_key_bytes()).unwrap();
+        assert_eq!(hex::encode(&*shared), "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742");
+        assert_eq!(Ecdh::derive_shared_secret(&bob, &alice.public_key_bytes()).unwrap(), shared);
+
+        assert_eq!(
+            Ecdh::derive_shared_secret(&alice, &[0u8; 32]).unwrap_err(),
+            CryptoError::InvalidKey(ECDH_WEAK_KEY)
+        );
+        assert!(Ecdh::derive_shared_secret(&alice, &[9u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_p256_agreement() {
+        let alice = Ecdh::generate_keypair(EcdhCurve::P256).unwrap();
+        let bob = Ecdh::generate_keypair(EcdhCurve::P256).unwrap();
+        assert_eq!(alice.public_key_bytes().len(), 65);
+
+        let shared = Ecdh::derive_shared_secret(&alice, &bob.public_key_bytes()).unwrap();
+        assert_eq!(shared.len(), 32);
+        assert_eq!(Ecdh::derive_shared_secret(&bob, &alice.public_key_bytes()).unwrap(), shared);
+
+        let restored = EcdhKeyPair::from_private_key_bytes(EcdhCurve::P256, &alice.private_key_bytes()).unwrap();
+        assert_eq!(restored.public_key_bytes(), alice.public_key_bytes());
+        assert!(EcdhKeyPair::from_private_key_bytes(EcdhCurve::P256, &[0u8; 32]).is_err());
+        assert!(Ecdh::derive_shared_secret(&alice, &[4u8; 65]).is_err());
+    }
+
+    #[test]
+    fn test_ecies_roundtrip() {
+        for curve in [EcdhCurve::X25519, EcdhCurve::P256] {
+            let recipient = Ecdh::generate_keypair(curve).unwrap();
+            let ciphertext = Ecies::encrypt(b"hybrid encryption", &recipient.public_key_bytes(), curve).unwrap();
+            assert_eq!(ciphertext.len(), curve.public_key_len() + 12 + 17 + 16);
+            assert_eq!(Ecies::decrypt(&ciphertext, &recipient).unwrap(), b"hybrid encryption");
+            assert_eq!(Ecies::decrypt_zeroizing(&ciphertext, &recipient).unwrap().as_slice(), b"hybrid encryption");
+
+            // Wrong recipient, tampering, truncation
+            assert!(Ecies::decrypt(&ciphertext, &Ecdh::generate_keypair(curve).unwrap()).is_err());
+            let mut tampered = ciphertext.clone();
+            *tampered.last_mut().unwrap() ^= 1;
+            assert!(Ecies::decrypt(&tampered, &recipient).is_err());
+            assert_eq!(
+                Ecies::decrypt(&ciphertext[..curve.public_key_len() - 1], &recipient).unwrap_err(),
+                CryptoError::DecryptionFailed(ECIES_INVALID_CIPHERTEXT)
+            );
+        }
+
+        // Compressed P-256 recipient keys are accepted
+        let recipient = Ecdh::generate_keypair(EcdhCurve::P256).unwrap();
+        let compressed = p256::PublicKey::from_sec1_bytes(&recipient.public_key_bytes()).unwrap().to_encoded_point(true);
+        let ciphertext = Ecies::encrypt(b"compressed", compressed.as_bytes(), EcdhCurve::P256).unwrap();
+        assert_eq!(Ecies::decrypt(&ciphertext, &recipient).unwrap(), b"compressed");
+    }
+}
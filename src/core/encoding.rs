@@ -0,0 +1,670 @@
+use crate::error::{CryptoError, CryptoResult, OUTPUT_BUFFER_TOO_SMALL, ENCODER_INVALID_OUTPUT, BASE58CHECK_TOO_SHORT, BASE58CHECK_CHECKSUM_MISMATCH, BECH32_INVALID_HRP, BECH32_HRP_MISMATCH, BECH32_TOO_LONG, HEX_INVALID, BASE64_INVALID, BASE32_INVALID};
+use base64::{Engine as _, engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD}, DecodeSliceError};
+use bech32::{Bech32, Bech32m, Hrp, primitives::decode::CheckedHrpstring};
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroizing;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+// Reverse of BASE32_ALPHABET accepting either case; 0xff marks invalid characters
+const BASE32_DECODE: [u8; 256] = {
+    let mut table = [0xff; 256];
+    let mut index = 0;
+    while index < BASE32_ALPHABET.len() {
+        table[BASE32_ALPHABET[index] as usize] = index as u8;
+        table[BASE32_ALPHABET[index].to_ascii_lowercase() as usize] = index as u8;
+        index += 1;
+    }
+    table
+};
+const BASE58CHECK_CHECKSUM_LEN: usize = 4;
+
+/// Base64 alphabet and padding for the base64 helpers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base64Variant {
+    /// Standard alphabet with `=` padding (RFC 4648 section 4)
+    Standard,
+    /// URL-safe alphabet without padding (RFC 4648 section 5), as used by JOSE and PASETO
+    UrlSafeNoPad,
+}
+
+/// Base32 padding for the base32 helpers (RFC 4648 section 6 alphabet)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Base32Variant {
+    /// With `=` padding to a multiple of eight characters
+    Standard,
+    /// Without padding, as in `otpauth://` secrets
+    NoPad,
+}
+
+/// Checksum for the bech32 helpers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bech32Variant {
+    /// Original bech32 (BIP-173), as in segwit v0 addresses
+    Bech32,
+    /// bech32m (BIP-350), as in taproot addresses and newer formats
+    Bech32m,
+}
+
+/// Length of the lowercase hex encoding of `len` bytes
+#[inline]
+pub const fn hex_encoded_len(len: usize) -> usize {
+    len * 2
+}
+
+/// Length of the base64 encoding of `len` bytes
+#[inline]
+pub const fn base64_encoded_len(len: usize, variant: Base64Variant) -> usize {
+    match variant {
+        Base64Variant::Standard => len.div_ceil(3) * 4,
+        Base64Variant::UrlSafeNoPad => (len * 4).div_ceil(3),
+    }
+}
+
+/// Encode `input` as lowercase hex into `out`, which must hold at least [`hex_encoded_len`] bytes
+///
+/// Returns the encoded prefix of `out`.
+pub fn encode_hex_into<'a>(input: &[u8], out: &'a mut [u8]) -> CryptoResult<&'a str> {
+    let len = hex_encoded_len(input.len());
+    if out.len() < len {
+        return Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL));
+    }
+
+    for (byte, pair) in input.iter().zip(out.chunks_exact_mut(2)) {
+        pair[0] = HEX_DIGITS[usize::from(byte >> 4)];
+        pair[1] = HEX_DIGITS[usize::from(byte & 0x0f)];
+    }
+    ascii_str(&out[..len])
+}
+
+/// Decode hex (either case) into `out`, which must hold at least half the input length
+///
+/// Returns the number of bytes written.
+pub fn decode_hex_into(input: impl AsRef<[u8]>, out: &mut [u8]) -> CryptoResult<usize> {
+    let input = input.as_ref();
+    let len = input.len() / 2;
+    if out.len() < len {
+        return Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL));
+    }
+
+    hex::decode_to_slice(input, &mut out[..len])?;
+    Ok(len)
+}
+
+/// Encode `input` as base64 into `out`, which must hold at least [`base64_encoded_len`] bytes
+///
+/// Returns the encoded prefix of `out`.
+pub fn encode_base64_into<'a>(input: &[u8], out: &'a mut [u8], variant: Base64Variant) -> CryptoResult<&'a str> {
+    let written = match variant {
+        Base64Variant::Standard => STANDARD.encode_slice(input, out),
+        Base64Variant::UrlSafeNoPad => URL_SAFE_NO_PAD.encode_slice(input, out),
+    }
+    .map_err(|_| CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL))?;
+    ascii_str(&out[..written])
+}
+
+/// Decode base64 into `out`, which must hold the decoded bytes (at most three quarters of the input length)
+///
+/// Returns the number of bytes written.
+pub fn decode_base64_into(input: impl AsRef<[u8]>, out: &mut [u8], variant: Base64Variant) -> CryptoResult<usize> {
+    let decoded = match variant {
+        Base64Variant::Standard => STANDARD.decode_slice(input, out),
+        Base64Variant::UrlSafeNoPad => URL_SAFE_NO_PAD.decode_slice(input, out),
+    };
+    match decoded {
+        Ok(written) => Ok(written),
+        Err(DecodeSliceError::DecodeError(err)) => Err(err.into()),
+        Err(DecodeSliceError::OutputSliceTooSmall) => Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)),
+    }
+}
+
+/// Length of the base32 encoding of `len` bytes
+#[inline]
+pub const fn base32_encoded_len(len: usize, variant: Base32Variant) -> usize {
+    match variant {
+        Base32Variant::Standard => len.div_ceil(5) * 8,
+        Base32Variant::NoPad => (len * 8).div_ceil(5),
+    }
+}
+
+/// Encode `input` as lowercase hex
+#[inline]
+pub fn encode_hex(input: &[u8]) -> String {
+    hex::encode(input)
+}
+
+/// Decode hex (either case)
+#[inline]
+pub fn decode_hex(input: impl AsRef<[u8]>) -> CryptoResult<Vec<u8>> {
+    Ok(hex::decode(input)?)
+}
+
+/// Encode `input` as base64
+pub fn encode_base64(input: &[u8], variant: Base64Variant) -> String {
+    match variant {
+        Base64Variant::Standard => STANDARD.encode(input),
+        Base64Variant::UrlSafeNoPad => URL_SAFE_NO_PAD.encode(input),
+    }
+}
+
+/// Decode base64, rejecting non-canonical padding and trailing bits
+pub fn decode_base64(input: impl AsRef<[u8]>, variant: Base64Variant) -> CryptoResult<Vec<u8>> {
+    Ok(match variant {
+        Base64Variant::Standard => STANDARD.decode(input),
+        Base64Variant::UrlSafeNoPad => URL_SAFE_NO_PAD.decode(input),
+    }?)
+}
+
+/// Encode `input` as uppercase RFC 4648 base32
+pub fn encode_base32(input: &[u8], variant: Base32Variant) -> String {
+    encode_bits(input, 5, base32_padding(variant), |value| BASE32_ALPHABET[usize::from(value)])
+}
+
+/// Decode RFC 4648 base32 (either case), rejecting non-canonical padding and trailing bits
+pub fn decode_base32(input: impl AsRef<[u8]>, variant: Base32Variant) -> CryptoResult<Vec<u8>> {
+    let input = strip_padding(input.as_ref(), base32_padding(variant)).ok_or(CryptoError::EncodingFailed(BASE32_INVALID))?;
+    decode_bits(input, 5, |c| BASE32_DECODE[usize::from(c)]).ok_or(CryptoError::EncodingFailed(BASE32_INVALID))
+}
+
+/// Encode `input` as lowercase hex in constant time
+///
+/// Unlike [`encode_hex`], no table lookup or branch depends on the input bytes,
+/// so it is safe for keys and tokens.
+pub fn encode_hex_ct(input: &[u8]) -> String {
+    encode_bits(input, 4, None, hex_char_ct)
+}
+
+/// Decode hex (either case) in constant time
+///
+/// Only the input length and whether it was valid are observable through timing.
+pub fn decode_hex_ct(input: impl AsRef<[u8]>) -> CryptoResult<Zeroizing<Vec<u8>>> {
+    decode_bits(input.as_ref(), 4, hex_value_ct)
+        .map(Zeroizing::new)
+        .ok_or(CryptoError::EncodingFailed(HEX_INVALID))
+}
+
+/// Encode `input` as base64 in constant time
+pub fn encode_base64_ct(input: &[u8], variant: Base64Variant) -> String {
+    let url_safe = variant == Base64Variant::UrlSafeNoPad;
+    encode_bits(input, 6, base64_padding(variant), |value| base64_char_ct(value, url_safe))
+}
+
+/// Decode base64 in constant time, rejecting non-canonical padding and trailing bits
+///
+/// Only the input length, the amount of padding and whether it was valid are
+/// observable through timing.
+pub fn decode_base64_ct(input: impl AsRef<[u8]>, variant: Base64Variant) -> CryptoResult<Zeroizing<Vec<u8>>> {
+    let url_safe = variant == Base64Variant::UrlSafeNoPad;
+    strip_padding(input.as_ref(), base64_padding(variant))
+        .and_then(|input| decode_bits(input, 6, |c| base64_value_ct(c, url_safe)))
+        .map(Zeroizing::new)
+        .ok_or(CryptoError::EncodingFailed(BASE64_INVALID))
+}
+
+/// Encode `input` as uppercase base32 in constant time
+pub fn encode_base32_ct(input: &[u8], variant: Base32Variant) -> String {
+    encode_bits(input, 5, base32_padding(variant), base32_char_ct)
+}
+
+/// Decode base32 (either case) in constant time, rejecting non-canonical padding and trailing bits
+pub fn decode_base32_ct(input: impl AsRef<[u8]>, variant: Base32Variant) -> CryptoResult<Zeroizing<Vec<u8>>> {
+    strip_padding(input.as_ref(), base32_padding(variant))
+        .and_then(|input| decode_bits(input, 5, base32_value_ct))
+        .map(Zeroizing::new)
+        .ok_or(CryptoError::EncodingFailed(BASE32_INVALID))
+}
+
+/// Encode `input` as base58 (Bitcoin alphabet)
+#[inline]
+pub fn encode_base58(input: &[u8]) -> String {
+    bs58::encode(input).into_string()
+}
+
+/// Decode base58 (Bitcoin alphabet)
+#[inline]
+pub fn decode_base58(input: impl AsRef<[u8]>) -> CryptoResult<Vec<u8>> {
+    Ok(bs58::decode(input).into_vec()?)
+}
+
+/// Encode `payload` as Base58Check: base58 of `payload || SHA-256(SHA-256(payload))[..4]`
+///
+/// Version bytes, as in Bitcoin addresses and WIF keys, are part of `payload`.
+pub fn encode_base58check(payload: &[u8]) -> String {
+    let mut data = Vec::with_capacity(payload.len() + BASE58CHECK_CHECKSUM_LEN);
+    data.extend_from_slice(payload);
+    data.extend_from_slice(&base58check_checksum(payload));
+    encode_base58(&data)
+}
+
+/// Decode Base58Check and verify its checksum, returning the payload (including any version bytes)
+pub fn decode_base58check(input: impl AsRef<[u8]>) -> CryptoResult<Vec<u8>> {
+    let mut data = decode_base58(input)?;
+    let payload_len = data.len().checked_sub(BASE58CHECK_CHECKSUM_LEN)
+        .ok_or(CryptoError::EncodingFailed(BASE58CHECK_TOO_SHORT))?;
+    if !bool::from(base58check_checksum(&data[..payload_len]).ct_eq(&data[payload_len..])) {
+        return Err(CryptoError::VerificationFailed(BASE58CHECK_CHECKSUM_MISMATCH));
+    }
+    data.truncate(payload_len);
+    Ok(data)
+}
+
+#[inline]
+fn base58check_checksum(payload: &[u8]) -> [u8; BASE58CHECK_CHECKSUM_LEN] {
+    let hash = Sha256::digest(Sha256::digest(payload));
+    let mut checksum = [0u8; BASE58CHECK_CHECKSUM_LEN];
+    checksum.copy_from_slice(&hash[..BASE58CHECK_CHECKSUM_LEN]);
+    checksum
+}
+
+/// Encode `data` as a lowercase bech32 or bech32m string with human-readable part `hrp`
+///
+/// The HRP must be 1 to 83 ASCII characters in the range 33..=126. The
+/// BIP-173 limit of 90 characters is not enforced, so longer identifiers
+/// (up to 1023 characters) can be produced.
+pub fn encode_bech32(hrp: &str, data: &[u8], variant: Bech32Variant) -> CryptoResult<String> {
+    let hrp = Hrp::parse(hrp).map_err(|_| CryptoError::InvalidInput(BECH32_INVALID_HRP))?;
+    match variant {
+        Bech32Variant::Bech32 => bech32::encode::<Bech32>(hrp, data),
+        Bech32Variant::Bech32m => bech32::encode::<Bech32m>(hrp, data),
+    }
+    .map_err(|_| CryptoError::InvalidInput(BECH32_TOO_LONG))
+}
+
+/// Decode a bech32 or bech32m string, returning its lowercase human-readable part and data
+///
+/// Mixed-case strings are rejected, as are strings whose checksum is for the other variant.
+pub fn decode_bech32(input: &str, variant: Bech32Variant) -> CryptoResult<(String, Vec<u8>)> {
+    let checked = match variant {
+        Bech32Variant::Bech32 => CheckedHrpstring::new::<Bech32>(input),
+        Bech32Variant::Bech32m => CheckedHrpstring::new::<Bech32m>(input),
+    }?;
+    Ok((checked.hrp().to_lowercase(), checked.byte_iter().collect()))
+}
+
+/// Decode a bech32 or bech32m string whose human-readable part must equal `expected_hrp` (ignoring case)
+pub fn decode_bech32_with_hrp(input: &str, expected_hrp: &str, variant: Bech32Variant) -> CryptoResult<Vec<u8>> {
+    let (hrp, data) = decode_bech32(input, variant)?;
+    if !hrp.eq_ignore_ascii_case(expected_hrp) {
+        return Err(CryptoError::VerificationFailed(BECH32_HRP_MISMATCH));
+    }
+    Ok(data)
+}
+
+// Hex and base64 output is always ASCII
+#[inline]
+fn ascii_str(bytes: &[u8]) -> CryptoResult<&str> {
+    core::str::from_utf8(bytes).map_err(|_| CryptoError::InternalError(ENCODER_INVALID_OUTPUT))
+}
+
+// Block size in characters and maximum number of `=` for padded variants
+#[inline]
+const fn base64_padding(variant: Base64Variant) -> Option<(usize, usize)> {
+    match variant {
+        Base64Variant::Standard => Some((4, 2)),
+        Base64Variant::UrlSafeNoPad => None,
+    }
+}
+
+#[inline]
+const fn base32_padding(variant: Base32Variant) -> Option<(usize, usize)> {
+    match variant {
+        Base32Variant::Standard => Some((8, 6)),
+        Base32Variant::NoPad => None,
+    }
+}
+
+// Split `input` into `bits`-wide groups, most significant first, and map each to a character
+fn encode_bits(input: &[u8], bits: u32, padding: Option<(usize, usize)>, to_char: impl Fn(u8) -> u8) -> String {
+    let mask = (1u32 << bits) - 1;
+    let mut output = String::with_capacity((input.len() * 8).div_ceil(bits as usize) + 8);
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    for &byte in input {
+        buffer = (buffer << 8) | u32::from(byte);
+        buffered += 8;
+        while buffered >= bits {
+            buffered -= bits;
+            output.push(char::from(to_char(((buffer >> buffered) & mask) as u8)));
+        }
+    }
+    if buffered > 0 {
+        output.push(char::from(to_char(((buffer << (bits - buffered)) & mask) as u8)));
+    }
+    if let Some((block, _)) = padding {
+        while !output.len().is_multiple_of(block) {
+            output.push('=');
+        }
+    }
+    output
+}
+
+// Inverse of `encode_bits`; `to_value` returns a value wider than `bits` for
+// invalid characters. Errors are accumulated so the loop never exits early.
+fn decode_bits(input: &[u8], bits: u32, to_value: impl Fn(u8) -> u8) -> Option<Vec<u8>> {
+    // A trailing character that does not complete a byte is never canonical
+    if (input.len() * bits as usize) % 8 >= bits as usize {
+        return None;
+    }
+
+    let mask = (1u32 << bits) - 1;
+    let mut output = Vec::with_capacity(input.len() * bits as usize / 8);
+    let mut buffer = 0u32;
+    let mut buffered = 0;
+    let mut invalid = 0u32;
+    for &c in input {
+        let value = u32::from(to_value(c));
+        invalid |= value & !mask;
+        buffer = (buffer << bits) | (value & mask);
+        buffered += bits;
+        if buffered >= 8 {
+            buffered -= 8;
+            output.push((buffer >> buffered) as u8);
+        }
+    }
+    // Non-canonical encodings leave set bits after the last byte
+    invalid |= buffer & ((1 << buffered) - 1);
+    (invalid == 0).then_some(output)
+}
+
+// Remove the `=` padding from a padded encoding, checking that there is exactly enough
+fn strip_padding(input: &[u8], padding: Option<(usize, usize)>) -> Option<&[u8]> {
+    let Some((block, max_padding)) = padding else {
+        return Some(input);
+    };
+    if !input.len().is_multiple_of(block) {
+        return None;
+    }
+    let padded = input.iter().rev().take(max_padding).take_while(|&&c| c == b'=').count();
+    let data = &input[..input.len() - padded];
+    (data.len().div_ceil(block) * block == input.len()).then_some(data)
+}
+
+// Branch-free comparisons returning 0xff for true and 0 for false
+#[inline]
+fn ct_lt(a: u8, b: u8) -> u8 {
+    (u16::from(a).wrapping_sub(u16::from(b)) >> 8) as u8
+}
+
+#[inline]
+fn ct_eq(a: u8, b: u8) -> u8 {
+    (u16::from(a ^ b).wrapping_sub(1) >> 8) as u8
+}
+
+#[inline]
+fn ct_in_range(c: u8, low: u8, high: u8) -> u8 {
+    !ct_lt(c, low) & !ct_lt(high, c)
+}
+
+#[inline]
+fn hex_char_ct(value: u8) -> u8 {
+    let digit = ct_lt(value, 10);
+    (digit & value.wrapping_add(b'0')) | (!digit & value.wrapping_add(b'a' - 10))
+}
+
+#[inline]
+fn hex_value_ct(c: u8) -> u8 {
+    let digit = ct_in_range(c, b'0', b'9');
+    let lower = ct_in_range(c, b'a', b'f');
+    let upper = ct_in_range(c, b'A', b'F');
+    (digit & c.wrapping_sub(b'0'))
+        | (lower & c.wrapping_sub(b'a' - 10))
+        | (upper & c.wrapping_sub(b'A' - 10))
+        | !(digit | lower | upper)
+}
+
+#[inline]
+fn base64_symbols(url_safe: bool) -> (u8, u8) {
+    if url_safe { (b'-', b'_') } else { (b'+', b'/') }
+}
+
+#[inline]
+fn base64_char_ct(value: u8, url_safe: bool) -> u8 {
+    let (c62, c63) = base64_symbols(url_safe);
+    let upper = ct_lt(value, 26);
+    let lower = !upper & ct_lt(value, 52);
+    let digit = !ct_lt(value, 52) & ct_lt(value, 62);
+    (upper & value.wrapping_add(b'A'))
+        | (lower & value.wrapping_add(b'a' - 26))
+        | (digit & value.wrapping_sub(52).wrapping_add(b'0'))
+        | (ct_eq(value, 62) & c62)
+        | (ct_eq(value, 63) & c63)
+}
+
+#[inline]
+fn base64_value_ct(c: u8, url_safe: bool) -> u8 {
+    let (c62, c63) = base64_symbols(url_safe);
+    let upper = ct_in_range(c, b'A', b'Z');
+    let lower = ct_in_range(c, b'a', b'z');
+    let digit = ct_in_range(c, b'0', b'9');
+    let is62 = ct_eq(c, c62);
+    let is63 = ct_eq(c, c63);
+    (upper & c.wrapping_sub(b'A'))
+        | (lower & c.wrapping_sub(b'a' - 26))
+        | (digit & c.wrapping_add(52).wrapping_sub(b'0'))
+        | (is62 & 62)
+        | (is63 & 63)
+        | !(upper | lower | digit | is62 | is63)
+}
+
+#[inline]
+fn base32_char_ct(value: u8) -> u8 {
+    let letter = ct_lt(value, 26);
+    (letter & value.wrapping_add(b'A')) | (!letter & value.wrapping_sub(26).wrapping_add(b'2'))
+}
+
+#[inline]
+fn base32_value_ct(c: u8) -> u8 {
+    let upper = ct_in_range(c, b'A', b'Z');
+    let lower = ct_in_range(c, b'a', b'z');
+    let digit = ct_in_range(c, b'2', b'7');
+    (upper & c.wrapping_sub(b'A'))
+        | (lower & c.wrapping_sub(b'a'))
+        | (digit & c.wrapping_sub(b'2').wrapping_add(26))
+        | !(upper | lower | digit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::BECH32_CHECKSUM_MISMATCH;
+
+    #[test]
+    fn test_hex_into() {
+        let mut out = [0u8; 64];
+        assert_eq!(encode_hex_into(&[0x00, 0x9f, 0xff], &mut out).unwrap(), "009fff");
+        assert_eq!(encode_hex_into(&[], &mut out).unwrap(), "");
+        assert_eq!(encode_hex_into(&[0u8; 33], &mut out), Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)));
+
+        let mut decoded = [0u8; 4];
+        assert_eq!(decode_hex_into("009FfF", &mut decoded).unwrap(), 3);
+        assert_eq!(&decoded[..3], &[0x00, 0x9f, 0xff]);
+        assert_eq!(decode_hex_into("0011223344", &mut decoded), Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)));
+        assert!(decode_hex_into("abc", &mut decoded).is_err());
+        assert!(decode_hex_into("zz", &mut decoded).is_err());
+
+        let data: Vec<u8> = (0..=255).collect();
+        let mut out = [0u8; 512];
+        assert_eq!(encode_hex_into(&data, &mut out).unwrap(), hex::encode(&data));
+    }
+
+    #[test]
+    fn test_base64_into() {
+        let mut out = [0u8; 16];
+        for (input, standard, url_safe) in [(&b""[..], "", ""), (b"f", "Zg==", "Zg"), (b"fo", "Zm8=", "Zm8"), (b"foo", "Zm9v", "Zm9v"), (b"\xfb\xff", "+/8=", "-_8")] {
+            assert_eq!(encode_base64_into(input, &mut out, Base64Variant::Standard).unwrap(), standard);
+            assert_eq!(base64_encoded_len(input.len(), Base64Variant::Standard), standard.len());
+            assert_eq!(encode_base64_into(input, &mut out, Base64Variant::UrlSafeNoPad).unwrap(), url_safe);
+            assert_eq!(base64_encoded_len(input.len(), Base64Variant::UrlSafeNoPad), url_safe.len());
+
+            let mut decoded = [0u8; 3];
+            assert_eq!(decode_base64_into(standard, &mut decoded, Base64Variant::Standard).unwrap(), input.len());
+            assert_eq!(&decoded[..input.len()], input);
+            assert_eq!(decode_base64_into(url_safe, &mut decoded, Base64Variant::UrlSafeNoPad).unwrap(), input.len());
+            assert_eq!(&decoded[..input.len()], input);
+        }
+
+        assert_eq!(encode_base64_into(b"foobar", &mut [0u8; 7], Base64Variant::Standard), Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)));
+        assert_eq!(decode_base64_into("Zm9vYmFy", &mut [0u8; 5], Base64Variant::Standard), Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)));
+        assert!(decode_base64_into("Zm9v!", &mut out, Base64Variant::Standard).is_err());
+        assert!(decode_base64_into("+/8=", &mut out, Base64Variant::UrlSafeNoPad).is_err());
+    }
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        // RFC 4648 section 10
+        for (input, base64, base32, hex) in [
+            (&b""[..], "", "", ""),
+            (b"f", "Zg==", "MY======", "66"),
+            (b"fo", "Zm8=", "MZXQ====", "666f"),
+            (b"foo", "Zm9v", "MZXW6===", "666f6f"),
+            (b"foob", "Zm9vYg==", "MZXW6YQ=", "666f6f62"),
+            (b"fooba", "Zm9vYmE=", "MZXW6YTB", "666f6f6261"),
+            (b"foobar", "Zm9vYmFy", "MZXW6YTBOI======", "666f6f626172"),
+        ] {
+            assert_eq!(encode_hex(input), hex);
+            assert_eq!(encode_hex_ct(input), hex);
+            assert_eq!(decode_hex(hex).unwrap(), input);
+            assert_eq!(*decode_hex_ct(hex.to_uppercase()).unwrap(), input);
+
+            assert_eq!(encode_base64(input, Base64Variant::Standard), base64);
+            assert_eq!(encode_base64_ct(input, Base64Variant::Standard), base64);
+            assert_eq!(decode_base64(base64, Base64Variant::Standard).unwrap(), input);
+            assert_eq!(*decode_base64_ct(base64, Base64Variant::Standard).unwrap(), input);
+            let url_safe = base64.trim_end_matches('=');
+            assert_eq!(encode_base64_ct(input, Base64Variant::UrlSafeNoPad), url_safe);
+            assert_eq!(*decode_base64_ct(url_safe, Base64Variant::UrlSafeNoPad).unwrap(), input);
+
+            assert_eq!(encode_base32(input, Base32Variant::Standard), base32);
+            assert_eq!(encode_base32_ct(input, Base32Variant::Standard), base32);
+            assert_eq!(base32_encoded_len(input.len(), Base32Variant::Standard), base32.len());
+            assert_eq!(decode_base32(base32.to_lowercase(), Base32Variant::Standard).unwrap(), input);
+            assert_eq!(*decode_base32_ct(base32, Base32Variant::Standard).unwrap(), input);
+            let unpadded = base32.trim_end_matches('=');
+            assert_eq!(encode_base32_ct(input, Base32Variant::NoPad), unpadded);
+            assert_eq!(base32_encoded_len(input.len(), Base32Variant::NoPad), unpadded.len());
+            assert_eq!(decode_base32(unpadded, Base32Variant::NoPad).unwrap(), input);
+            assert_eq!(*decode_base32_ct(unpadded, Base32Variant::NoPad).unwrap(), input);
+        }
+    }
+
+    #[test]
+    fn test_constant_time_codecs_match_fast_codecs() {
+        let data: Vec<u8> = (0..=255).rev().chain(0..=255).collect();
+        for len in [0, 1, 2, 3, 4, 5, 31, 32, 33, 512] {
+            let input = &data[..len];
+            assert_eq!(encode_hex_ct(input), encode_hex(input));
+            assert_eq!(*decode_hex_ct(encode_hex(input)).unwrap(), input);
+            for variant in [Base64Variant::Standard, Base64Variant::UrlSafeNoPad] {
+                assert_eq!(encode_base64_ct(input, variant), encode_base64(input, variant));
+                assert_eq!(*decode_base64_ct(encode_base64(input, variant), variant).unwrap(), input);
+            }
+            for variant in [Base32Variant::Standard, Base32Variant::NoPad] {
+                assert_eq!(encode_base32_ct(input, variant), encode_base32(input, variant));
+                assert_eq!(*decode_base32_ct(encode_base32(input, variant), variant).unwrap(), input);
+            }
+        }
+
+        // Every byte is classified the same way by both decoders
+        for c in 0..=255u8 {
+            let hex = [b'0', c];
+            assert_eq!(decode_hex_ct(hex).ok().map(|decoded| decoded.to_vec()), decode_hex(hex).ok());
+            let base64 = [b'A', b'A', b'A', c];
+            for variant in [Base64Variant::Standard, Base64Variant::UrlSafeNoPad] {
+                assert_eq!(decode_base64_ct(base64, variant).ok().map(|decoded| decoded.to_vec()), decode_base64(base64, variant).ok());
+            }
+            let base32 = [b'A', b'A', b'A', b'A', b'A', b'A', b'A', c];
+            assert_eq!(decode_base32_ct(base32, Base32Variant::NoPad).ok().map(|decoded| decoded.to_vec()), decode_base32(base32, Base32Variant::NoPad).ok());
+        }
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        assert_eq!(decode_hex_ct("abc").unwrap_err(), CryptoError::EncodingFailed(HEX_INVALID));
+        assert_eq!(decode_hex_ct("0g").unwrap_err(), CryptoError::EncodingFailed(HEX_INVALID));
+
+        let base64 = CryptoError::EncodingFailed(BASE64_INVALID);
+        for (input, variant) in [
+            ("Zg=", Base64Variant::Standard), // not a whole block
+            ("Zg", Base64Variant::Standard), // missing padding
+            ("Zm9v====", Base64Variant::Standard),
+            ("Zh==", Base64Variant::Standard), // non-zero trailing bits
+            ("Zg==", Base64Variant::UrlSafeNoPad),
+            ("Z", Base64Variant::UrlSafeNoPad),
+            ("+/8", Base64Variant::UrlSafeNoPad),
+            ("-_8=", Base64Variant::Standard),
+        ] {
+            assert_eq!(decode_base64_ct(input, variant).unwrap_err(), base64, "{input}");
+            assert!(decode_base64(input, variant).is_err(), "{input}");
+        }
+
+        let base32 = CryptoError::EncodingFailed(BASE32_INVALID);
+        for (input, variant) in [
+            ("MY=====", Base32Variant::Standard),
+            ("MY", Base32Variant::Standard),
+            ("MZ======", Base32Variant::Standard), // non-zero trailing bits
+            ("M=======", Base32Variant::Standard),
+            ("MY======", Base32Variant::NoPad),
+            ("MZX", Base32Variant::NoPad),
+            ("MY1", Base32Variant::NoPad),
+        ] {
+            assert_eq!(decode_base32_ct(input, variant).unwrap_err(), base32, "{input}");
+            assert_eq!(decode_base32(input, variant).unwrap_err(), base32, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_base58() {
+        // draft-msporny-base58 examples; leading zero bytes become leading '1's
+        for (input, encoded) in [(&b""[..], ""), (b"Hello World!", "2NEpo7TZRRrLZSi2U"), (b"The quick brown fox jumps over the lazy dog.", "USm3fpXnKG5EUBx2ndxBDMPVciP5hGey2Jh4NDv6gmeo1LkMeiKrLJUUBk6Z"), (b"\x00\x00\x28\x7f\xb4\xcd", "11233QC4")] {
+            assert_eq!(encode_base58(input), encoded);
+            assert_eq!(decode_base58(encoded).unwrap(), input);
+        }
+        assert!(decode_base58("0OIl").is_err());
+    }
+
+    #[test]
+    fn test_base58check() {
+        // Bitcoin address for the uncompressed public key of private key 1 (version byte 0x00)
+        let payload = hex::decode("0091b24bf9f5288532960ac687abb035127b1d28a5").unwrap();
+        let address = "1EHNa6Q4Jz2uvNExL497mE43ikXhwF6kZm";
+        assert_eq!(encode_base58check(&payload), address);
+        assert_eq!(decode_base58check(address).unwrap(), payload);
+
+        let mut corrupted = String::from(address);
+        corrupted.replace_range(5..6, "R");
+        assert_eq!(decode_base58check(&corrupted).unwrap_err(), CryptoError::VerificationFailed(BASE58CHECK_CHECKSUM_MISMATCH));
+        assert_eq!(decode_base58check("111").unwrap_err(), CryptoError::EncodingFailed(BASE58CHECK_TOO_SHORT));
+        assert_eq!(decode_base58check(encode_base58check(b"")).unwrap(), b"");
+    }
+
+    #[test]
+    fn test_bech32() {
+        // BIP-173 and BIP-350 valid checksums (empty data)
+        assert_eq!(decode_bech32("A12UEL5L", Bech32Variant::Bech32).unwrap(), ("a".to_string(), Vec::new()));
+        assert_eq!(decode_bech32("a1lqfn3a", Bech32Variant::Bech32m).unwrap(), ("a".to_string(), Vec::new()));
+        assert_eq!(encode_bech32("A", b"", Bech32Variant::Bech32).unwrap(), "a12uel5l");
+        assert_eq!(encode_bech32("a", b"", Bech32Variant::Bech32m).unwrap(), "a1lqfn3a");
+
+        // The checksum of one variant is not accepted by the other
+        assert_eq!(decode_bech32("a12uel5l", Bech32Variant::Bech32m).unwrap_err(), CryptoError::VerificationFailed(BECH32_CHECKSUM_MISMATCH));
+        assert_eq!(decode_bech32("a12uel5m", Bech32Variant::Bech32).unwrap_err(), CryptoError::VerificationFailed(BECH32_CHECKSUM_MISMATCH));
+
+        for variant in [Bech32Variant::Bech32, Bech32Variant::Bech32m] {
+            let data: Vec<u8> = (0..=40).collect();
+            let encoded = encode_bech32("npub", &data, variant).unwrap();
+            assert_eq!(decode_bech32(&encoded, variant).unwrap(), ("npub".to_string(), data.clone()));
+            assert_eq!(decode_bech32_with_hrp(&encoded.to_uppercase(), "NPUB", variant).unwrap(), data);
+            assert_eq!(decode_bech32_with_hrp(&encoded, "nsec", variant).unwrap_err(), CryptoError::VerificationFailed(BECH32_HRP_MISMATCH));
+        }
+
+        // Invalid HRPs, mixed case and oversized output
+        assert_eq!(encode_bech32("", b"", Bech32Variant::Bech32).unwrap_err(), CryptoError::InvalidInput(BECH32_INVALID_HRP));
+        assert_eq!(encode_bech32("a b", b"", Bech32Variant::Bech32).unwrap_err(), CryptoError::InvalidInput(BECH32_INVALID_HRP));
+        assert_eq!(encode_bech32("a", &[0u8; 1024], Bech32Variant::Bech32).unwrap_err(), CryptoError::InvalidInput(BECH32_TOO_LONG));
+        assert!(decode_bech32("A12uEL5L", Bech32Variant::Bech32).is_err());
+        assert!(decode_bech32("no-separator", Bech32Variant::Bech32).is_err());
+    }
+}
@@ -0,0 +1,524 @@
+use crate::error::{CryptoError, CryptoResult, ENCRYPTOR_MISSING_KEY, ENCRYPTOR_KEY_MISMATCH, ENCRYPTOR_NONCE_REUSED, ENCRYPTOR_NONCE_EXHAUSTED};
+#[cfg(feature = "aes")]
+use crate::error::{ENCRYPTOR_ENVELOPE_KEK, ENCRYPTOR_ENVELOPE_OPTIONS};
+#[cfg(feature = "aes")]
+use crate::core::envelope::Envelope;
+#[cfg(feature = "compression")]
+use crate::core::envelope::EnvelopePadding;
+#[cfg(feature = "compression")]
+use crate::error::ENCRYPTOR_COMPRESSION_REQUIRES_ENVELOPE;
+use crate::core::keys::AeadKey;
+use crate::core::random::SecureRandom;
+use crate::core::registry::Algorithm;
+use crate::core::symmetric::Limits;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+// Bytes of the counter in `NonceStrategy::Counter` nonces; the rest of the
+// 12-byte nonce is the random prefix
+const COUNTER_SIZE: usize = 4;
+
+/// How an [`Encryptor`] picks the nonce of each message
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum NonceStrategy {
+    /// A fresh random nonce per message
+    #[default]
+    Random,
+    /// A 64-bit random prefix chosen once per `Encryptor`, followed by a 32-bit
+    /// big-endian message counter; never repeats for the encryptor's lifetime
+    ///
+    /// Encryptors sharing a key pick colliding prefixes with probability about
+    /// n²/2⁶⁵ for n encryptors, so keep n well below 2³² per key.
+    Counter,
+    /// A caller-supplied nonce, used for exactly one message (known-answer
+    /// tests, protocols that derive nonces themselves)
+    Explicit(Vec<u8>),
+}
+
+/// Fluent configuration over the AEAD ciphers and [`Envelope`]
+///
+/// Optional parameters are set on the builder instead of picking one of the
+/// `encrypt_with_*` variants. The output of a plain encryptor is the same
+/// `nonce || ciphertext || tag` as the cipher's own functions.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let key = ChaCha20Poly1305Cipher::generate_key()?;
+/// let mut encryptor = Encryptor::new()
+///     .algorithm(Algorithm::ChaCha20Poly1305)
+///     .aad(b"user:42")
+///     .nonce_strategy(NonceStrategy::Counter)
+///     .key(&key);
+/// let ciphertext = encryptor.encrypt(b"record")?;
+///
+/// let plaintext = Decryptor::new()
+///     .algorithm(Algorithm::ChaCha20Poly1305)
+///     .aad(b"user:42")
+///     .key(&key)
+///     .decrypt(&ciphertext)?;
+/// assert_eq!(plaintext, b"record");
+/// # Ok::<(), CryptoError>(())
+/// ```
+///
+/// Cloning copies the configuration but not the nonce state: the clone picks
+/// a fresh counter prefix, and cannot use an explicit nonce the original may
+/// already have used.
+pub struct Encryptor<'a> {
+    algorithm: Algorithm,
+    aad: &'a [u8],
+    nonce_strategy: NonceStrategy,
+    key: Option<AeadKey<'a>>,
+    envelope_key_id: Option<&'a str>,
+    compress: bool,
+    counter_prefix: Option<Vec<u8>>,
+    counter: u32,
+    explicit_nonce_used: bool,
+    limits: Limits,
+    messages: u64,
+}
+
+// Two encryptors with the same nonce state would repeat nonces under one key
+impl Clone for Encryptor<'_> {
+    fn clone(&self) -> Self {
+        Encryptor {
+            algorithm: self.algorithm,
+            aad: self.aad,
+            nonce_strategy: self.nonce_strategy.clone(),
+            key: self.key,
+            envelope_key_id: self.envelope_key_id,
+            compress: self.compress,
+            counter_prefix: None,
+            counter: 0,
+            explicit_nonce_used: true,
+            limits: self.limits,
+            messages: self.messages,
+        }
+    }
+}
+
+impl Default for Encryptor<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Never print the key
+impl core::fmt::Debug for Encryptor<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Encryptor")
+            .field("algorithm", &self.algorithm)
+            .field("nonce_strategy", &self.nonce_strategy)
+            .field("envelope_key_id", &self.envelope_key_id)
+            .field("compress", &self.compress)
+            .field("limits", &self.limits)
+            .field("messages", &self.messages)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Encryptor<'a> {
+    /// AES-256-GCM, no AAD, random nonces, default [`Limits`], no key
+    pub fn new() -> Self {
+        Encryptor {
+            algorithm: Algorithm::Aes256Gcm,
+            aad: &[],
+            nonce_strategy: NonceStrategy::Random,
+            key: None,
+            envelope_key_id: None,
+            compress: false,
+            counter_prefix: None,
+            counter: 0,
+            explicit_nonce_used: false,
+            limits: Limits::default(),
+            messages: 0,
+        }
+    }
+
+    /// Select the AEAD cipher
+    #[inline]
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Authenticate `aad` along with every message
+    #[inline]
+    pub fn aad(mut self, aad: &'a [u8]) -> Self {
+        self.aad = aad;
+        self
+    }
+
+    /// Select how nonces are chosen
+    #[inline]
+    pub fn nonce_strategy(mut self, nonce_strategy: NonceStrategy) -> Self {
+        self.nonce_strategy = nonce_strategy;
+        self.explicit_nonce_used = false;
+        self
+    }
+
+    /// Enforce size and usage `limits` on every message
+    #[inline]
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set the key; with [`Encryptor::envelope`] this is the KEK and must be an [`Aes256Key`]
+    ///
+    /// Otherwise the key type must match [`Encryptor::algorithm`]. Restarts the
+    /// count of messages checked against [`Limits::max_messages_per_key`].
+    ///
+    /// [`Aes256Key`]: crate::core::keys::Aes256Key
+    #[inline]
+    pub fn key(mut self, key: impl Into<AeadKey<'a>>) -> Self {
+        self.key = Some(key.into());
+        self.messages = 0;
+        self
+    }
+
+    /// Number of messages encrypted under the current key
+    #[inline]
+    pub fn messages_encrypted(&self) -> u64 {
+        self.messages
+    }
+
+    /// Write [`Envelope`] framing: each message gets a fresh data key wrapped
+    /// by the key, labelled `key_id`
+    #[cfg(feature = "aes")]
+    #[inline]
+    pub fn envelope(mut self, key_id: &'a str) -> Self {
+        self.envelope_key_id = Some(key_id);
+        self
+    }
+
+    /// Deflate-compress each message before sealing it; requires [`Encryptor::envelope`]
+    ///
+    /// The envelope header records the flag, so decryption needs no option.
+    /// See [`Envelope::encrypt_compressed`] for when compression leaks the
+    /// plaintext through the ciphertext length.
+    #[cfg(feature = "compression")]
+    #[inline]
+    pub fn compress(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
+    /// Encrypt one message; errors carry the operation and algorithm as context
+    pub fn encrypt(&mut self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let algorithm = self.algorithm;
+        let ciphertext = self.seal(plaintext).map_err(|err| err.with_context("encrypt", algorithm.as_str()))?;
+        self.messages += 1;
+        Ok(ciphertext)
+    }
+
+    fn seal(&mut self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let key = self.key.ok_or(CryptoError::InvalidKey(ENCRYPTOR_MISSING_KEY))?;
+        let cipher = self.algorithm.aead()?;
+        self.limits.check_message(plaintext.len(), self.aad.len())?;
+        self.limits.check_usage(self.messages)?;
+
+        #[cfg(feature = "aes")]
+        if let Some(key_id) = self.envelope_key_id {
+            if !self.aad.is_empty() || self.nonce_strategy != NonceStrategy::Random {
+                return Err(CryptoError::InvalidInput(ENCRYPTOR_ENVELOPE_OPTIONS));
+            }
+            let AeadKey::Aes256(key) = key else {
+                return Err(CryptoError::InvalidKey(ENCRYPTOR_ENVELOPE_KEK));
+            };
+            #[cfg(feature = "compression")]
+            if self.compress {
+                return Envelope::encrypt_compressed(plaintext, key, key_id, self.algorithm, EnvelopePadding::None);
+            }
+            return Envelope::encrypt_with_algorithm(plaintext, key, key_id, self.algorithm);
+        }
+        #[cfg(feature = "compression")]
+        if self.compress {
+            return Err(CryptoError::InvalidInput(ENCRYPTOR_COMPRESSION_REQUIRES_ENVELOPE));
+        }
+        if key.algorithm() != self.algorithm {
+            return Err(CryptoError::InvalidKey(ENCRYPTOR_KEY_MISMATCH));
+        }
+        let key = key.as_bytes();
+
+        match &self.nonce_strategy {
+            NonceStrategy::Random => cipher.encrypt_with_aad(plaintext, key, self.aad),
+            NonceStrategy::Counter => {
+                let prefix = match &self.counter_prefix {
+                    Some(prefix) => prefix.clone(),
+                    None => {
+                        let prefix = SecureRandom::generate_nonce(cipher.nonce_size() - COUNTER_SIZE)?;
+                        self.counter_prefix = Some(prefix.clone());
+                        prefix
+                    }
+                };
+                let mut nonce = prefix;
+                nonce.extend_from_slice(&self.counter.to_be_bytes());
+                self.counter = self.counter.checked_add(1)
+                    .ok_or(CryptoError::InvalidInput(ENCRYPTOR_NONCE_EXHAUSTED))?;
+                cipher.seal_with_nonce(plaintext, key, &nonce, self.aad)
+            }
+            NonceStrategy::Explicit(nonce) => {
+                if self.explicit_nonce_used {
+                    return Err(CryptoError::InvalidInput(ENCRYPTOR_NONCE_REUSED));
+                }
+                self.explicit_nonce_used = true;
+                cipher.seal_with_nonce(plaintext, key, nonce, self.aad)
+            }
+        }
+    }
+}
+
+/// Fluent configuration for opening what an [`Encryptor`] produced
+#[derive(Clone)]
+pub struct Decryptor<'a> {
+    algorithm: Algorithm,
+    aad: &'a [u8],
+    key: Option<AeadKey<'a>>,
+    envelope: bool,
+    limits: Limits,
+}
+
+impl Default for Decryptor<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Decryptor<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Decryptor")
+            .field("algorithm", &self.algorithm)
+            .field("envelope", &self.envelope)
+            .field("limits", &self.limits)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> Decryptor<'a> {
+    /// AES-256-GCM, no AAD, default [`Limits`], no key
+    pub fn new() -> Self {
+        Decryptor { algorithm: Algorithm::Aes256Gcm, aad: &[], key: None, envelope: false, limits: Limits::default() }
+    }
+
+    /// Select the AEAD cipher; envelopes record their own
+    #[inline]
+    pub fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
+    /// Expect `aad` to be authenticated with the message
+    #[inline]
+    pub fn aad(mut self, aad: &'a [u8]) -> Self {
+        self.aad = aad;
+        self
+    }
+
+    /// Reject messages whose plaintext or AAD would exceed `limits` before decrypting them
+    #[inline]
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set the key; with [`Decryptor::envelope`] this is the KEK and must be an
+    /// [`Aes256Key`], otherwise its type must match [`Decryptor::algorithm`]
+    ///
+    /// [`Aes256Key`]: crate::core::keys::Aes256Key
+    #[inline]
+    pub fn key(mut self, key: impl Into<AeadKey<'a>>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Read [`Envelope`] framing
+    #[cfg(feature = "aes")]
+    #[inline]
+    pub fn envelope(mut self) -> Self {
+        self.envelope = true;
+        self
+    }
+
+    /// Decrypt one message; errors carry the operation and algorithm as context
+    pub fn decrypt(&self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.open(ciphertext).map_err(|err| err.with_context("decrypt", self.algorithm.as_str()))
+    }
+
+    /// Decrypt one message, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(&self, ciphertext: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.decrypt(ciphertext).map(Zeroizing::new)
+    }
+
+    fn open(&self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let key = self.key.ok_or(CryptoError::InvalidKey(ENCRYPTOR_MISSING_KEY))?;
+        #[cfg(feature = "aes")]
+        if self.envelope {
+            if !self.aad.is_empty() {
+                return Err(CryptoError::InvalidInput(ENCRYPTOR_ENVELOPE_OPTIONS));
+            }
+            let AeadKey::Aes256(key) = key else {
+                return Err(CryptoError::InvalidKey(ENCRYPTOR_ENVELOPE_KEK));
+            };
+            let (algorithm, payload) = Envelope::payload(ciphertext)?;
+            self.check_limits(algorithm, payload)?;
+            return Envelope::decrypt_with_limit(ciphertext, key, self.limits.max_plaintext_len);
+        }
+        let cipher = self.algorithm.aead()?;
+        if key.algorithm() != self.algorithm {
+            return Err(CryptoError::InvalidKey(ENCRYPTOR_KEY_MISMATCH));
+        }
+        self.check_limits(self.algorithm, ciphertext)?;
+        cipher.decrypt_with_aad(ciphertext, key.as_bytes(), self.aad)
+    }
+
+    // The plaintext is the payload minus the nonce and tag
+    fn check_limits(&self, algorithm: Algorithm, payload: &[u8]) -> CryptoResult<()> {
+        let cipher = algorithm.aead()?;
+        let plaintext_len = payload.len().saturating_sub(cipher.nonce_size() + cipher.tag_size());
+        self.limits.check_message(plaintext_len, self.aad.len())
+    }
+}
+
+#[cfg(all(test, feature = "aes", feature = "chacha"))]
+mod tests {
+    use super::*;
+    use crate::core::symmetric::{AesGcm, ChaCha20Poly1305Cipher};
+    use crate::error::{LIMIT_PLAINTEXT_TOO_LARGE, LIMIT_AAD_TOO_LARGE, LIMIT_KEY_USAGE_EXCEEDED};
+
+    #[test]
+    fn test_encryptor_matches_static_functions() {
+        let key = AesGcm::generate_key().unwrap();
+
+        let ciphertext = Encryptor::new().key(&key).encrypt(b"data").unwrap();
+        assert_eq!(AesGcm::decrypt(&ciphertext, &key).unwrap(), b"data");
+
+        let chacha_key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+        let ciphertext = ChaCha20Poly1305Cipher::encrypt_with_aad(b"data", &chacha_key, b"aad").unwrap();
+        let decryptor = Decryptor::new().algorithm(Algorithm::ChaCha20Poly1305).aad(b"aad").key(&chacha_key);
+        assert_eq!(decryptor.decrypt(&ciphertext).unwrap(), b"data");
+        let err = decryptor.clone().aad(b"other").decrypt(&ciphertext).unwrap_err();
+        assert!(matches!(err.root(), CryptoError::DecryptionFailed(_)));
+        assert_eq!(err.context().map(|context| (context.operation, context.algorithm)), Some(("decrypt", "ChaCha20-Poly1305")));
+        assert!(Decryptor::new().key(&key).decrypt(&ciphertext).is_err());
+
+        assert_eq!(Encryptor::new().encrypt(b"data").unwrap_err(), CryptoError::InvalidKey(ENCRYPTOR_MISSING_KEY));
+        assert!(Encryptor::new().algorithm(Algorithm::Ed25519).key(&key).encrypt(b"data").is_err());
+    }
+
+    #[test]
+    fn test_encryptor_key_type_mismatch() {
+        let aes_key = AesGcm::generate_key().unwrap();
+        let chacha_key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+
+        let err = Encryptor::new().algorithm(Algorithm::ChaCha20Poly1305).key(&aes_key).encrypt(b"data").unwrap_err();
+        assert_eq!(err.root(), &CryptoError::InvalidKey(ENCRYPTOR_KEY_MISMATCH));
+        let err = Encryptor::new().key(&chacha_key).encrypt(b"data").unwrap_err();
+        assert_eq!(err.root(), &CryptoError::InvalidKey(ENCRYPTOR_KEY_MISMATCH));
+
+        let ciphertext = AesGcm::encrypt(b"data", &aes_key).unwrap();
+        let err = Decryptor::new().algorithm(Algorithm::ChaCha20Poly1305).key(&aes_key).decrypt(&ciphertext).unwrap_err();
+        assert_eq!(err.root(), &CryptoError::InvalidKey(ENCRYPTOR_KEY_MISMATCH));
+
+        // Envelopes wrap every data key under an AES-256 KEK, whatever the payload cipher
+        let err = Encryptor::new().algorithm(Algorithm::ChaCha20Poly1305).key(&chacha_key).envelope("kek").encrypt(b"data").unwrap_err();
+        assert_eq!(err.root(), &CryptoError::InvalidKey(ENCRYPTOR_ENVELOPE_KEK));
+        let envelope = Encryptor::new().key(&aes_key).envelope("kek").encrypt(b"data").unwrap();
+        let err = Decryptor::new().key(&chacha_key).envelope().decrypt(&envelope).unwrap_err();
+        assert_eq!(err.root(), &CryptoError::InvalidKey(ENCRYPTOR_ENVELOPE_KEK));
+    }
+
+    #[test]
+    fn test_encryptor_nonce_strategies() {
+        let key = AesGcm::generate_key().unwrap();
+
+        let mut encryptor = Encryptor::new().nonce_strategy(NonceStrategy::Counter).key(&key);
+        let first = encryptor.encrypt(b"one").unwrap();
+        let second = encryptor.encrypt(b"two").unwrap();
+        assert_eq!(first[..8], second[..8]);
+        assert_eq!(first[8..12], 0u32.to_be_bytes());
+        assert_eq!(second[8..12], 1u32.to_be_bytes());
+        assert_eq!(AesGcm::decrypt(&second, &key).unwrap(), b"two");
+
+        // A clone starts its own prefix instead of repeating the original's nonces
+        let mut clone = encryptor.clone();
+        let third = encryptor.encrypt(b"three").unwrap();
+        let cloned = clone.encrypt(b"three").unwrap();
+        assert_ne!(third[..12], cloned[..12]);
+        assert_eq!(cloned[8..12], 0u32.to_be_bytes());
+
+        let nonce = vec![9u8; 12];
+        let mut encryptor = Encryptor::new().nonce_strategy(NonceStrategy::Explicit(nonce.clone())).key(&key);
+        let ciphertext = encryptor.encrypt(b"once").unwrap();
+        assert_eq!(ciphertext[12..], AesGcm::encrypt_with_nonce(b"once", &key, &nonce).unwrap());
+        assert_eq!(encryptor.encrypt(b"twice").unwrap_err(), CryptoError::InvalidInput(ENCRYPTOR_NONCE_REUSED));
+
+        let fresh = Encryptor::new().nonce_strategy(NonceStrategy::Explicit(nonce)).key(&key);
+        assert_eq!(fresh.clone().encrypt(b"clone").unwrap_err(), CryptoError::InvalidInput(ENCRYPTOR_NONCE_REUSED));
+    }
+
+    #[test]
+    fn test_encryptor_envelope_framing() {
+        let kek = AesGcm::generate_key().unwrap();
+        let mut encryptor = Encryptor::new().algorithm(Algorithm::ChaCha20Poly1305).key(&kek).envelope("kek-2024");
+        let envelope = encryptor.encrypt(b"record").unwrap();
+
+        let header = Envelope::header(&envelope).unwrap();
+        assert_eq!((header.algorithm, header.key_id), (Algorithm::ChaCha20Poly1305, "kek-2024"));
+        assert_eq!(Decryptor::new().key(&kek).envelope().decrypt(&envelope).unwrap(), b"record");
+
+        let mut with_aad = Encryptor::new().aad(b"aad").key(&kek).envelope("kek");
+        assert_eq!(with_aad.encrypt(b"record").unwrap_err(), CryptoError::InvalidInput(ENCRYPTOR_ENVELOPE_OPTIONS));
+    }
+
+    #[test]
+    #[cfg(feature = "compression")]
+    fn test_encryptor_compression() {
+        let kek = AesGcm::generate_key().unwrap();
+        let record = b"record ".repeat(100);
+        let envelope = Encryptor::new().key(&kek).envelope("kek").compress(true).encrypt(&record).unwrap();
+        assert!(Envelope::header(&envelope).unwrap().compressed);
+        assert!(envelope.len() < record.len());
+        assert_eq!(Decryptor::new().key(&kek).envelope().decrypt(&envelope).unwrap(), record);
+
+        // Limits apply to the decompressed size
+        let limits = Limits { max_plaintext_len: 100, ..Limits::default() };
+        let err = Decryptor::new().limits(limits).key(&kek).envelope().decrypt(&envelope).unwrap_err();
+        assert_eq!(err.root(), &CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+
+        let mut plain = Encryptor::new().key(&kek).compress(true);
+        assert_eq!(plain.encrypt(b"record").unwrap_err().root(), &CryptoError::InvalidInput(ENCRYPTOR_COMPRESSION_REQUIRES_ENVELOPE));
+    }
+
+    #[test]
+    fn test_encryptor_limits() {
+        let key = AesGcm::generate_key().unwrap();
+        let limits = Limits { max_plaintext_len: 8, max_aad_len: 4, max_messages_per_key: 2 };
+
+        let mut encryptor = Encryptor::new().limits(limits).aad(b"aad").key(&key);
+        let ciphertext = encryptor.encrypt(b"12345678").unwrap();
+        assert_eq!(encryptor.encrypt(b"123456789").unwrap_err(), CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+        assert_eq!(encryptor.messages_encrypted(), 1);
+        encryptor.encrypt(b"two").unwrap();
+        assert_eq!(encryptor.encrypt(b"three").unwrap_err(), CryptoError::InvalidKey(LIMIT_KEY_USAGE_EXCEEDED));
+
+        // A new key restarts the count
+        let other = AesGcm::generate_key().unwrap();
+        let mut encryptor = encryptor.key(&other);
+        assert_eq!(encryptor.messages_encrypted(), 0);
+        assert!(encryptor.encrypt(b"three").is_ok());
+        assert_eq!(encryptor.aad(b"too long").encrypt(b"").unwrap_err(), CryptoError::InvalidInput(LIMIT_AAD_TOO_LARGE));
+
+        // Oversized input is rejected before it is decrypted
+        let decryptor = Decryptor::new().limits(limits).aad(b"aad").key(&key);
+        assert_eq!(decryptor.decrypt(&ciphertext).unwrap(), b"12345678");
+        let long = AesGcm::encrypt_with_aad(b"123456789", &key, b"aad").unwrap();
+        assert_eq!(decryptor.decrypt(&long).unwrap_err(), CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+
+        let envelope = Encryptor::new().key(&key).envelope("kek").encrypt(b"123456789").unwrap();
+        let decryptor = Decryptor::new().key(&key).envelope();
+        assert_eq!(decryptor.clone().limits(limits).decrypt(&envelope).unwrap_err(), CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+        assert_eq!(decryptor.limits(Limits { max_plaintext_len: 9, ..limits }).decrypt(&envelope).unwrap(), b"123456789");
+    }
+}
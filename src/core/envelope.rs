@@ -0,0 +1,929 @@
+use crate::error::{CryptoError, CryptoResult, ENVELOPE_INVALID_FORMAT, ENVELOPE_UNSUPPORTED_VERSION, ENVELOPE_KEY_ID_TOO_LONG, ENVELOPE_KEK_MISMATCH, ENVELOPE_UNWRAP_FAILED, ENVELOPE_INVALID_PADDING};
+#[cfg(not(feature = "compression"))]
+use crate::error::ENVELOPE_COMPRESSION_UNSUPPORTED;
+#[cfg(feature = "compression")]
+use crate::error::{ENVELOPE_INVALID_COMPRESSION, LIMIT_PLAINTEXT_TOO_LARGE};
+#[cfg(feature = "std")]
+use crate::error::{STREAM_WRITE_FAILED, ENVELOPE_STREAM_ALGORITHM};
+use crate::core::kdf::HkdfKdf;
+use crate::core::keys::Aes256Key;
+use crate::core::random::{SecureRandom, SecureKey};
+use crate::core::registry::Algorithm;
+use crate::core::stream::AesGcmStreamDecryptor;
+#[cfg(feature = "std")]
+use crate::core::stream::{self, AesGcmStreamEncryptor, STREAM_CHUNK_SIZE};
+use crate::core::symmetric::AesGcm;
+use zeroize::Zeroizing;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+
+// Envelope layout:
+//   magic "LSEV" | version (1) | KEK source (1) | key id length (2) | key id
+//   | wrapped DEK length (2) | wrapped DEK | AES-GCM(nonce + ciphertext + tag)
+// Version 2 adds the payload cipher's `Algorithm` ID after the version byte.
+// Version 3 follows it with the padding scheme (1) and bucket size (4), and the
+// plaintext carries ISO/IEC 7816-4 padding (0x80 then zeros) up to the padded length.
+// The top bit of the scheme byte marks a compressed payload: the plaintext length
+// as a big-endian u64 and then raw deflate, padded after compressing.
+// Version 4 has the version 1 header followed by the chunked `stream` format,
+// keyed with HKDF-SHA256 over the DEK and the header so the header stays bound.
+// Everything before the payload is authenticated as AAD.
+const ENVELOPE_MAGIC: &[u8; 4] = b"LSEV";
+const ENVELOPE_VERSION: u8 = 1;
+const ENVELOPE_VERSION_WITH_ALGORITHM: u8 = 2;
+const ENVELOPE_VERSION_WITH_PADDING: u8 = 3;
+const ENVELOPE_VERSION_STREAMED: u8 = 4;
+const STREAM_KEY_INFO: &[u8] = b"libsilver envelope stream";
+const DEK_SIZE: usize = 32;
+const PADDING_MARKER: u8 = 0x80;
+const COMPRESSED_FLAG: u8 = 0x80;
+#[cfg(feature = "compression")]
+const COMPRESSION_LEVEL: u8 = 6;
+
+/// Where the key-encryption key (KEK) that wraps the data key lives
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "kebab-case"))]
+pub enum KekSource {
+    /// A local AES-256 key held by the application
+    Local,
+    /// A key held by a remote KMS, see [`RemoteKms`]
+    RemoteKms,
+}
+
+impl KekSource {
+    #[inline]
+    fn to_byte(self) -> u8 {
+        match self {
+            KekSource::Local => 1,
+            KekSource::RemoteKms => 2,
+        }
+    }
+
+    #[inline]
+    fn from_byte(byte: u8) -> CryptoResult<Self> {
+        match byte {
+            1 => Ok(KekSource::Local),
+            2 => Ok(KekSource::RemoteKms),
+            _ => Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT)),
+        }
+    }
+}
+
+/// Padding applied to the plaintext before encryption, so the envelope size
+/// does not reveal the exact message size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(rename_all = "kebab-case"))]
+pub enum EnvelopePadding {
+    /// No padding
+    #[default]
+    None,
+    /// Padmé (Nikitin et al., PETS 2019): at most 12% overhead, leaking
+    /// O(log log n) bits of an n-byte length instead of all of them
+    Padme,
+    /// Round up to a multiple of the given size in bytes; one extra byte is
+    /// always added, so a message of exactly that size takes two buckets
+    Bucket(u32),
+}
+
+impl EnvelopePadding {
+    #[inline]
+    fn to_bytes(self) -> [u8; 5] {
+        let (scheme, size) = match self {
+            EnvelopePadding::None => (0, 0),
+            EnvelopePadding::Padme => (1, 0),
+            EnvelopePadding::Bucket(size) => (2, size),
+        };
+        let mut bytes = [scheme, 0, 0, 0, 0];
+        bytes[1..].copy_from_slice(&size.to_be_bytes());
+        bytes
+    }
+
+    #[inline]
+    fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        let size = u32::from_be_bytes([bytes[1], bytes[2], bytes[3], bytes[4]]);
+        match (bytes[0], size) {
+            (0, 0) => Ok(EnvelopePadding::None),
+            (1, 0) => Ok(EnvelopePadding::Padme),
+            (2, 1..) => Ok(EnvelopePadding::Bucket(size)),
+            _ => Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT)),
+        }
+    }
+
+    /// Padded length of a `len`-byte plaintext, including the padding marker
+    pub fn padded_len(self, len: usize) -> CryptoResult<usize> {
+        let unpadded = len.checked_add(1).ok_or(CryptoError::InvalidInput(ENVELOPE_INVALID_PADDING))?;
+        match self {
+            EnvelopePadding::None => Ok(len),
+            EnvelopePadding::Padme => {
+                if unpadded < 2 {
+                    return Ok(unpadded);
+                }
+                // E = floor(log2 L), S = floor(log2 E) + 1; zero the low E - S bits, rounding up
+                let e = usize::BITS - 1 - unpadded.leading_zeros();
+                let s = u32::BITS - e.leading_zeros();
+                let mask = (1usize << (e - s)) - 1;
+                unpadded.checked_add(mask)
+                    .map(|len| len & !mask)
+                    .ok_or(CryptoError::InvalidInput(ENVELOPE_INVALID_PADDING))
+            }
+            EnvelopePadding::Bucket(0) => Err(CryptoError::InvalidInput(ENVELOPE_INVALID_PADDING)),
+            EnvelopePadding::Bucket(size) => {
+                let size = size as usize;
+                unpadded.div_ceil(size).checked_mul(size)
+                    .ok_or(CryptoError::InvalidInput(ENVELOPE_INVALID_PADDING))
+            }
+        }
+    }
+}
+
+/// Parsed header of an envelope
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EnvelopeHeader<'a> {
+    /// Cipher of the payload; always AES-256-GCM for version 1 envelopes
+    pub algorithm: Algorithm,
+    /// Where the KEK lives
+    pub kek_source: KekSource,
+    /// Identifier of the KEK (local label or KMS key ID/ARN)
+    pub key_id: &'a str,
+    /// The data key, encrypted under the KEK
+    #[cfg_attr(feature = "serde", serde(with = "crate::core::serialization::bytes"))]
+    pub wrapped_key: &'a [u8],
+    /// Padding of the plaintext; `None` for version 1 and 2 envelopes
+    pub padding: EnvelopePadding,
+    /// Whether the plaintext was compressed before encryption
+    pub compressed: bool,
+    /// Whether the payload is in the chunked stream format (version 4)
+    pub streamed: bool,
+}
+
+/// Envelope encryption: data is encrypted under a fresh random data key (DEK)
+/// which is in turn wrapped by a key-encryption key (KEK)
+pub struct Envelope;
+
+impl Envelope {
+    /// Encrypt `plaintext` with a fresh DEK wrapped by a local AES-256 KEK
+    pub fn encrypt(plaintext: &[u8], kek: &Aes256Key, key_id: &str) -> CryptoResult<Vec<u8>> {
+        let dek = SecureRandom::generate_key(DEK_SIZE)?;
+        let wrapped_key = AesGcm::encrypt_with_aad(dek.as_bytes(), kek, key_id.as_bytes())?;
+        Self::seal(plaintext, &dek, None, KekSource::Local, key_id, &wrapped_key)
+    }
+
+    /// Encrypt `plaintext` with the AEAD `algorithm` under a fresh DEK wrapped by a local AES-256 KEK
+    ///
+    /// The algorithm ID is recorded in the header, so [`Envelope::decrypt`] needs no configuration.
+    pub fn encrypt_with_algorithm(plaintext: &[u8], kek: &Aes256Key, key_id: &str, algorithm: Algorithm) -> CryptoResult<Vec<u8>> {
+        algorithm.aead()?;
+        let dek = SecureRandom::generate_key(DEK_SIZE)?;
+        let wrapped_key = AesGcm::encrypt_with_aad(dek.as_bytes(), kek, key_id.as_bytes())?;
+        Self::seal(plaintext, &dek, Some(algorithm), KekSource::Local, key_id, &wrapped_key)
+    }
+
+    /// Like [`Envelope::encrypt_with_algorithm`], padding the plaintext first to hide its length
+    ///
+    /// The scheme is recorded in the header; [`Envelope::decrypt`] strips the
+    /// padding and [`Envelope::reencrypt`] keeps it.
+    pub fn encrypt_with_padding(plaintext: &[u8], kek: &Aes256Key, key_id: &str, algorithm: Algorithm, padding: EnvelopePadding) -> CryptoResult<Vec<u8>> {
+        Self::encrypt_with_options(plaintext, kek, key_id, algorithm, padding, false)
+    }
+
+    /// Like [`Envelope::encrypt_with_padding`], deflate-compressing the plaintext before padding it
+    ///
+    /// The flag is authenticated with the header, so [`Envelope::decrypt`]
+    /// knows to decompress, and decompression stops at the length recorded
+    /// inside the ciphertext. Only compress data no attacker can influence:
+    /// when attacker-chosen input is compressed together with a secret, the
+    /// ciphertext length reveals how much of the input matched the secret
+    /// (the CRIME and BREACH attacks). Padding blurs the length but does not
+    /// close that channel.
+    #[cfg(feature = "compression")]
+    pub fn encrypt_compressed(plaintext: &[u8], kek: &Aes256Key, key_id: &str, algorithm: Algorithm, padding: EnvelopePadding) -> CryptoResult<Vec<u8>> {
+        Self::encrypt_with_options(plaintext, kek, key_id, algorithm, padding, true)
+    }
+
+    fn encrypt_with_options(plaintext: &[u8], kek: &Aes256Key, key_id: &str, algorithm: Algorithm, padding: EnvelopePadding, compress: bool) -> CryptoResult<Vec<u8>> {
+        algorithm.aead()?;
+        let dek = SecureRandom::generate_key(DEK_SIZE)?;
+        let wrapped_key = AesGcm::encrypt_with_aad(dek.as_bytes(), kek, key_id.as_bytes())?;
+        if padding == EnvelopePadding::None && !compress {
+            return Self::seal(plaintext, &dek, Some(algorithm), KekSource::Local, key_id, &wrapped_key);
+        }
+
+        let compressed = if compress { Some(compress_payload(plaintext)?) } else { None };
+        let plaintext = compressed.as_deref().map_or(plaintext, |compressed| compressed.as_slice());
+        if padding == EnvelopePadding::None {
+            return Self::seal_v3(plaintext, &dek, algorithm, padding, compress, key_id, &wrapped_key);
+        }
+
+        let padded_len = padding.padded_len(plaintext.len())?;
+        let mut padded = Zeroizing::new(Vec::with_capacity(padded_len));
+        padded.extend_from_slice(plaintext);
+        padded.push(PADDING_MARKER);
+        padded.resize(padded_len, 0);
+        Self::seal_v3(&padded, &dek, algorithm, padding, compress, key_id, &wrapped_key)
+    }
+
+    /// Decrypt an envelope produced by [`Envelope::encrypt`]
+    #[inline]
+    pub fn decrypt(envelope: &[u8], kek: &Aes256Key) -> CryptoResult<Vec<u8>> {
+        Self::decrypt_with_limit(envelope, kek, usize::MAX)
+    }
+
+    // Compressed payloads declaring more than `max_plaintext_len` bytes are
+    // rejected before decompressing
+    pub(crate) fn decrypt_with_limit(envelope: &[u8], kek: &Aes256Key, max_plaintext_len: usize) -> CryptoResult<Vec<u8>> {
+        let (header, _, _) = Self::split(envelope)?;
+        if header.kek_source != KekSource::Local {
+            return Err(CryptoError::InvalidKey(ENVELOPE_KEK_MISMATCH));
+        }
+
+        let dek = Zeroizing::new(
+            AesGcm::decrypt_with_aad(header.wrapped_key, kek, header.key_id.as_bytes())
+                .map_err(|_| CryptoError::DecryptionFailed(ENVELOPE_UNWRAP_FAILED))?,
+        );
+        Self::open(envelope, &dek, max_plaintext_len)
+    }
+
+    /// Decrypt an envelope produced by [`Envelope::encrypt`], zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(envelope: &[u8], kek: &Aes256Key) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(envelope, kek).map(Zeroizing::new)
+    }
+
+    /// Decrypt an envelope under `old_kek` and seal its plaintext with `algorithm` under
+    /// a fresh DEK wrapped by `new_kek`, to migrate off a retired KEK or cipher in one call
+    ///
+    /// The old payload cipher, padding and compression are read from the header;
+    /// padding and compression are kept. The plaintext is held in a
+    /// buffer zeroized on return; payloads too large for memory belong in
+    /// streamed envelopes, migrated with [`Envelope::reencrypt_stream`].
+    pub fn reencrypt(envelope: &[u8], old_kek: &Aes256Key, new_kek: &Aes256Key, new_key_id: &str, algorithm: Algorithm) -> CryptoResult<Vec<u8>> {
+        algorithm.aead()?;
+        let header = Self::header(envelope)?;
+        let (padding, compressed) = (header.padding, header.compressed);
+        let plaintext = Self::decrypt_zeroizing(envelope, old_kek)?;
+        Self::encrypt_with_options(&plaintext, new_kek, new_key_id, algorithm, padding, compressed)
+    }
+
+    /// Encrypt everything `reader` produces into a streamed envelope written to `writer`
+    ///
+    /// The payload is AES-256-GCM in the chunked [`stream`](crate::core::stream)
+    /// format, so memory use stays at one chunk whatever the input size.
+    /// [`Envelope::decrypt`] also opens streamed envelopes held in memory.
+    /// Returns the number of bytes written.
+    #[cfg(feature = "std")]
+    pub fn encrypt_stream(mut reader: impl Read, mut writer: impl Write, kek: &Aes256Key, key_id: &str) -> CryptoResult<u64> {
+        let (header, key) = Self::stream_header(kek, key_id)?;
+        let mut encryptor = AesGcmStreamEncryptor::new(&key)?;
+        let mut written = write_all(&mut writer, &header)?;
+        loop {
+            let chunk = stream::read_segment(&mut reader, STREAM_CHUNK_SIZE)?;
+            if chunk.is_empty() {
+                break;
+            }
+            written += write_all(&mut writer, &encryptor.update(&chunk)?)?;
+        }
+        Ok(written + write_all(&mut writer, &encryptor.finish()?)?)
+    }
+
+    /// Decrypt a streamed envelope from `reader` to `writer`
+    ///
+    /// Plaintext is written chunk by chunk as each is authenticated; the
+    /// output is only known to be complete once this returns `Ok`. Returns
+    /// the number of plaintext bytes written.
+    #[cfg(feature = "std")]
+    pub fn decrypt_stream(mut reader: impl Read, mut writer: impl Write, kek: &Aes256Key) -> CryptoResult<u64> {
+        let key = Self::read_stream_header(&mut reader, kek)?;
+        let mut decryptor = AesGcmStreamDecryptor::new(&key)?;
+        let mut written = 0;
+        loop {
+            let chunk = stream::read_segment(&mut reader, STREAM_CHUNK_SIZE)?;
+            if chunk.is_empty() {
+                break;
+            }
+            written += write_all(&mut writer, &Zeroizing::new(decryptor.update(&chunk)?))?;
+        }
+        Ok(written + write_all(&mut writer, &Zeroizing::new(decryptor.finish()?))?)
+    }
+
+    /// Move a streamed envelope from `old_kek` to a fresh DEK wrapped by `new_kek`, one chunk at a time
+    ///
+    /// The streaming counterpart of [`Envelope::reencrypt`]: at most a chunk
+    /// of plaintext is in memory at once. If the input fails authentication
+    /// part way, the output is left without its final chunk and will not
+    /// decrypt. Returns the number of bytes written.
+    ///
+    /// The streamed format is AES-256-GCM only, so `algorithm` must be
+    /// [`Algorithm::Aes256Gcm`]; any other cipher is rejected before reading
+    /// `reader`. Use [`Envelope::reencrypt`] to move to another cipher.
+    #[cfg(feature = "std")]
+    pub fn reencrypt_stream(mut reader: impl Read, mut writer: impl Write, old_kek: &Aes256Key, new_kek: &Aes256Key, new_key_id: &str, algorithm: Algorithm) -> CryptoResult<u64> {
+        if algorithm != Algorithm::Aes256Gcm {
+            return Err(CryptoError::InvalidInput(ENVELOPE_STREAM_ALGORITHM));
+        }
+        let old_key = Self::read_stream_header(&mut reader, old_kek)?;
+        let mut decryptor = AesGcmStreamDecryptor::new(&old_key)?;
+        let (header, new_key) = Self::stream_header(new_kek, new_key_id)?;
+        let mut encryptor = AesGcmStreamEncryptor::new(&new_key)?;
+
+        let mut written = write_all(&mut writer, &header)?;
+        loop {
+            let chunk = stream::read_segment(&mut reader, STREAM_CHUNK_SIZE)?;
+            if chunk.is_empty() {
+                break;
+            }
+            let plaintext = Zeroizing::new(decryptor.update(&chunk)?);
+            written += write_all(&mut writer, &encryptor.update(&plaintext)?)?;
+        }
+        let plaintext = Zeroizing::new(decryptor.finish()?);
+        written += write_all(&mut writer, &encryptor.update(&plaintext)?)?;
+        Ok(written + write_all(&mut writer, &encryptor.finish()?)?)
+    }
+
+    /// Encrypt `plaintext` with a fresh DEK wrapped by the KMS key `key_id`
+    #[cfg(feature = "kms")]
+    pub async fn encrypt_with_kms(plaintext: &[u8], kms: &dyn RemoteKms, key_id: &str) -> CryptoResult<Vec<u8>> {
+        let dek = SecureRandom::generate_key(DEK_SIZE)?;
+        let wrapped_key = kms.wrap_key(key_id, dek.as_bytes()).await?;
+        Self::seal(plaintext, &dek, None, KekSource::RemoteKms, key_id, &wrapped_key)
+    }
+
+    /// Decrypt an envelope produced by [`Envelope::encrypt_with_kms`]
+    #[cfg(feature = "kms")]
+    pub async fn decrypt_with_kms(envelope: &[u8], kms: &dyn RemoteKms) -> CryptoResult<Vec<u8>> {
+        let (header, _, _) = Self::split(envelope)?;
+        if header.kek_source != KekSource::RemoteKms {
+            return Err(CryptoError::InvalidKey(ENVELOPE_KEK_MISMATCH));
+        }
+
+        let dek = kms.unwrap_key(header.key_id, header.wrapped_key).await?;
+        Self::open(envelope, dek.as_bytes(), usize::MAX)
+    }
+
+    /// Parse the envelope header without decrypting, e.g. to route to the right KEK
+    #[inline]
+    pub fn header(envelope: &[u8]) -> CryptoResult<EnvelopeHeader<'_>> {
+        Self::split(envelope).map(|(header, _, _)| header)
+    }
+
+    // Payload cipher and `nonce || ciphertext || tag`, for size checks before decrypting
+    pub(crate) fn payload(envelope: &[u8]) -> CryptoResult<(Algorithm, &[u8])> {
+        Self::split(envelope).map(|(header, _, ciphertext)| (header.algorithm, ciphertext))
+    }
+
+    // `None` writes a version 1 envelope, readable by every libsilver release
+    fn seal(plaintext: &[u8], dek: &SecureKey, algorithm: Option<Algorithm>, kek_source: KekSource, key_id: &str, wrapped_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let mut prefix = Vec::with_capacity(2);
+        match algorithm {
+            Some(algorithm) => prefix.extend_from_slice(&[ENVELOPE_VERSION_WITH_ALGORITHM, algorithm.id()]),
+            None => prefix.push(ENVELOPE_VERSION),
+        }
+        Self::seal_with_prefix(plaintext, dek, algorithm.unwrap_or(Algorithm::Aes256Gcm), &prefix, kek_source, key_id, wrapped_key)
+    }
+
+    // Version 3: `payload` is already compressed and padded
+    #[allow(clippy::too_many_arguments)]
+    fn seal_v3(payload: &[u8], dek: &SecureKey, algorithm: Algorithm, padding: EnvelopePadding, compressed: bool, key_id: &str, wrapped_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let mut options = padding.to_bytes();
+        if compressed {
+            options[0] |= COMPRESSED_FLAG;
+        }
+        let mut prefix = Vec::with_capacity(7);
+        prefix.extend_from_slice(&[ENVELOPE_VERSION_WITH_PADDING, algorithm.id()]);
+        prefix.extend_from_slice(&options);
+        Self::seal_with_prefix(payload, dek, algorithm, &prefix, KekSource::Local, key_id, wrapped_key)
+    }
+
+    // `prefix` is the version byte and whatever that version puts before the KEK source
+    fn seal_with_prefix(plaintext: &[u8], dek: &SecureKey, algorithm: Algorithm, prefix: &[u8], kek_source: KekSource, key_id: &str, wrapped_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let header = Self::build_header(prefix, kek_source, key_id, wrapped_key)?;
+        let ciphertext = algorithm.aead()?.encrypt_with_aad(plaintext, dek.as_bytes(), &header)?;
+
+        let mut envelope = header;
+        envelope.extend_from_slice(&ciphertext);
+        Ok(envelope)
+    }
+
+    fn build_header(prefix: &[u8], kek_source: KekSource, key_id: &str, wrapped_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        if key_id.len() > u16::MAX as usize || wrapped_key.len() > u16::MAX as usize {
+            return Err(CryptoError::InvalidInput(ENVELOPE_KEY_ID_TOO_LONG));
+        }
+
+        let mut header = Vec::with_capacity(4 + prefix.len() + 1 + 2 + key_id.len() + 2 + wrapped_key.len());
+        header.extend_from_slice(ENVELOPE_MAGIC);
+        header.extend_from_slice(prefix);
+        header.push(kek_source.to_byte());
+        header.extend_from_slice(&(key_id.len() as u16).to_be_bytes());
+        header.extend_from_slice(key_id.as_bytes());
+        header.extend_from_slice(&(wrapped_key.len() as u16).to_be_bytes());
+        header.extend_from_slice(wrapped_key);
+        Ok(header)
+    }
+
+    // Version 4 header under a fresh DEK, and the stream key bound to it
+    #[cfg(feature = "std")]
+    fn stream_header(kek: &Aes256Key, key_id: &str) -> CryptoResult<(Vec<u8>, Zeroizing<Vec<u8>>)> {
+        let dek = SecureRandom::generate_key(DEK_SIZE)?;
+        let wrapped_key = AesGcm::encrypt_with_aad(dek.as_bytes(), kek, key_id.as_bytes())?;
+        let header = Self::build_header(&[ENVELOPE_VERSION_STREAMED], KekSource::Local, key_id, &wrapped_key)?;
+        let key = stream_key(dek.as_bytes(), &header)?;
+        Ok((header, key))
+    }
+
+    // Read a version 4 header off `reader` and unwrap its stream key
+    #[cfg(feature = "std")]
+    fn read_stream_header(reader: &mut impl Read, kek: &Aes256Key) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        let mut header = read_exact(reader, 4 + 1 + 1)?;
+        if &header[..4] != ENVELOPE_MAGIC || header[4] != ENVELOPE_VERSION_STREAMED {
+            return Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT));
+        }
+        for _ in 0..2 {
+            let len = read_exact(reader, 2)?;
+            let field = read_exact(reader, u16::from_be_bytes([len[0], len[1]]) as usize)?;
+            header.extend_from_slice(&len);
+            header.extend_from_slice(&field);
+        }
+
+        let (parsed, _, _) = Self::split(&header)?;
+        if parsed.kek_source != KekSource::Local {
+            return Err(CryptoError::InvalidKey(ENVELOPE_KEK_MISMATCH));
+        }
+        let dek = Zeroizing::new(
+            AesGcm::decrypt_with_aad(parsed.wrapped_key, kek, parsed.key_id.as_bytes())
+                .map_err(|_| CryptoError::DecryptionFailed(ENVELOPE_UNWRAP_FAILED))?,
+        );
+        stream_key(&dek, &header)
+    }
+
+    fn open(envelope: &[u8], dek: &[u8], max_plaintext_len: usize) -> CryptoResult<Vec<u8>> {
+        let (header, header_bytes, ciphertext) = Self::split(envelope)?;
+        if header.streamed {
+            let mut decryptor = AesGcmStreamDecryptor::new(&stream_key(dek, header_bytes)?)?;
+            let mut plaintext = decryptor.update(ciphertext)?;
+            plaintext.extend_from_slice(&Zeroizing::new(decryptor.finish()?));
+            return Ok(plaintext);
+        }
+
+        let mut plaintext = header.algorithm.aead()?.decrypt_with_aad(ciphertext, dek, header_bytes)?;
+        if header.padding != EnvelopePadding::None {
+            // The last nonzero byte is the marker
+            let marker = plaintext.iter().rposition(|&byte| byte != 0)
+                .filter(|&pos| plaintext[pos] == PADDING_MARKER)
+                .ok_or(CryptoError::EncodingFailed(ENVELOPE_INVALID_PADDING))?;
+            plaintext.truncate(marker);
+        }
+        if header.compressed {
+            return decompress_payload(&Zeroizing::new(plaintext), max_plaintext_len);
+        }
+        Ok(plaintext)
+    }
+
+    fn split(envelope: &[u8]) -> CryptoResult<(EnvelopeHeader<'_>, &[u8], &[u8])> {
+        if envelope.len() < 8 || &envelope[..4] != ENVELOPE_MAGIC {
+            return Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT));
+        }
+
+        let (algorithm, padding, compressed, mut pos) = match envelope[4] {
+            ENVELOPE_VERSION | ENVELOPE_VERSION_STREAMED => (Algorithm::Aes256Gcm, EnvelopePadding::None, false, 5),
+            ENVELOPE_VERSION_WITH_ALGORITHM => (read_algorithm(envelope[5])?, EnvelopePadding::None, false, 6),
+            ENVELOPE_VERSION_WITH_PADDING => {
+                let mut options: [u8; 5] = envelope.get(6..11)
+                    .and_then(|options| options.try_into().ok())
+                    .ok_or(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?;
+                let compressed = options[0] & COMPRESSED_FLAG != 0;
+                options[0] &= !COMPRESSED_FLAG;
+                let padding = EnvelopePadding::from_bytes(&options)?;
+                // Version 2 covers neither padding nor compression
+                if padding == EnvelopePadding::None && !compressed {
+                    return Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT));
+                }
+                (read_algorithm(envelope[5])?, padding, compressed, 11)
+            }
+            _ => return Err(CryptoError::EncodingFailed(ENVELOPE_UNSUPPORTED_VERSION)),
+        };
+
+        let kek_source = KekSource::from_byte(*envelope.get(pos).ok_or(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?)?;
+        pos += 1;
+
+        let key_id = read_field(envelope, &mut pos)?;
+        let key_id = core::str::from_utf8(key_id)
+            .map_err(|_| CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?;
+        let wrapped_key = read_field(envelope, &mut pos)?;
+
+        let streamed = envelope[4] == ENVELOPE_VERSION_STREAMED;
+        let (header_bytes, ciphertext) = envelope.split_at(pos);
+        Ok((EnvelopeHeader { algorithm, kek_source, key_id, wrapped_key, padding, compressed, streamed }, header_bytes, ciphertext))
+    }
+}
+
+// Streamed payloads are keyed by the DEK and the exact header bytes
+fn stream_key(dek: &[u8], header: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+    let mut info = Vec::with_capacity(STREAM_KEY_INFO.len() + header.len());
+    info.extend_from_slice(STREAM_KEY_INFO);
+    info.extend_from_slice(header);
+    HkdfKdf::derive_sha256_zeroizing(dek, None, &info, DEK_SIZE)
+}
+
+#[cfg(feature = "std")]
+fn read_exact(reader: &mut impl Read, len: usize) -> CryptoResult<Vec<u8>> {
+    let bytes = stream::read_segment(reader, len)?;
+    if bytes.len() != len {
+        return Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT));
+    }
+    Ok(bytes.to_vec())
+}
+
+#[cfg(feature = "std")]
+fn write_all(writer: &mut impl Write, bytes: &[u8]) -> CryptoResult<u64> {
+    writer.write_all(bytes).map_err(|err| CryptoError::IoFailed(STREAM_WRITE_FAILED).caused_by(err))?;
+    Ok(bytes.len() as u64)
+}
+
+// Plaintext length (big-endian u64) followed by raw deflate
+#[cfg(feature = "compression")]
+fn compress_payload(plaintext: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+    let deflated = Zeroizing::new(miniz_oxide::deflate::compress_to_vec(plaintext, COMPRESSION_LEVEL));
+    let mut payload = Zeroizing::new(Vec::with_capacity(8 + deflated.len()));
+    payload.extend_from_slice(&(plaintext.len() as u64).to_be_bytes());
+    payload.extend_from_slice(&deflated);
+    Ok(payload)
+}
+
+#[cfg(not(feature = "compression"))]
+fn compress_payload(_plaintext: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+    Err(CryptoError::InvalidInput(ENVELOPE_COMPRESSION_UNSUPPORTED))
+}
+
+#[cfg(feature = "compression")]
+fn decompress_payload(payload: &[u8], max_plaintext_len: usize) -> CryptoResult<Vec<u8>> {
+    let (len, deflated) = payload.split_first_chunk::<8>()
+        .ok_or(CryptoError::EncodingFailed(ENVELOPE_INVALID_COMPRESSION))?;
+    let len = usize::try_from(u64::from_be_bytes(*len))
+        .map_err(|_| CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE))?;
+    if len > max_plaintext_len {
+        return Err(CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+    }
+
+    match miniz_oxide::inflate::decompress_to_vec_with_limit(deflated, len) {
+        Ok(plaintext) if plaintext.len() == len => Ok(plaintext),
+        Ok(plaintext) => {
+            drop(Zeroizing::new(plaintext));
+            Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_COMPRESSION))
+        }
+        Err(err) => {
+            drop(Zeroizing::new(err.output));
+            Err(CryptoError::EncodingFailed(ENVELOPE_INVALID_COMPRESSION))
+        }
+    }
+}
+
+#[cfg(not(feature = "compression"))]
+fn decompress_payload(_payload: &[u8], _max_plaintext_len: usize) -> CryptoResult<Vec<u8>> {
+    Err(CryptoError::EncodingFailed(ENVELOPE_COMPRESSION_UNSUPPORTED))
+}
+
+// Only AEAD algorithms can protect the payload
+fn read_algorithm(id: u8) -> CryptoResult<Algorithm> {
+    let algorithm = Algorithm::from_id(id)
+        .map_err(|_| CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?;
+    algorithm.aead().map_err(|_| CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?;
+    Ok(algorithm)
+}
+
+fn read_field<'a>(data: &'a [u8], pos: &mut usize) -> CryptoResult<&'a [u8]> {
+    let len_bytes = data.get(*pos..*pos + 2)
+        .ok_or(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?;
+    let len = u16::from_be_bytes([len_bytes[0], len_bytes[1]]) as usize;
+    let field = data.get(*pos + 2..*pos + 2 + len)
+        .ok_or(CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT))?;
+    *pos += 2 + len;
+    Ok(field)
+}
+
+/// A remote key management service (AWS KMS, GCP KMS, Vault Transit, ...)
+///
+/// Implemented out-of-tree; libsilver only needs key wrapping for [`Envelope`]
+/// and signing for applications that keep signing keys in the KMS. Transport
+/// or service errors should be reported as `CryptoError::IoFailed(KMS_REQUEST_FAILED)`.
+#[cfg(feature = "kms")]
+#[async_trait::async_trait]
+pub trait RemoteKms: Send + Sync {
+    /// Encrypt a data key under the KMS key `key_id`
+    async fn wrap_key(&self, key_id: &str, plaintext_key: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Decrypt a data key previously wrapped under `key_id`
+    async fn unwrap_key(&self, key_id: &str, wrapped_key: &[u8]) -> CryptoResult<SecureKey>;
+
+    /// Sign `message` with the KMS key `key_id`
+    async fn sign(&self, key_id: &str, message: &[u8]) -> CryptoResult<Vec<u8>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_envelope_local_kek() {
+        let kek = AesGcm::generate_key().unwrap();
+        let envelope = Envelope::encrypt(b"customer record", &kek, "kek-2024").unwrap();
+
+        let header = Envelope::header(&envelope).unwrap();
+        assert_eq!(header.algorithm, Algorithm::Aes256Gcm);
+        assert_eq!(header.kek_source, KekSource::Local);
+        assert_eq!(header.key_id, "kek-2024");
+
+        assert_eq!(Envelope::decrypt(&envelope, &kek).unwrap(), b"customer record");
+        assert_eq!(Envelope::decrypt_zeroizing(&envelope, &kek).unwrap().as_slice(), b"customer record");
+
+        let other_kek = AesGcm::generate_key().unwrap();
+        assert!(Envelope::decrypt(&envelope, &other_kek).is_err());
+    }
+
+    #[test]
+    fn test_envelope_tampering() {
+        let kek = AesGcm::generate_key().unwrap();
+        let envelope = Envelope::encrypt(b"data", &kek, "kek").unwrap();
+
+        // Header bytes are bound to the payload
+        let mut tampered = envelope.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(Envelope::decrypt(&tampered, &kek).is_err());
+
+        let mut relabeled = envelope.clone();
+        relabeled[8] = b'x';
+        assert!(Envelope::decrypt(&relabeled, &kek).is_err());
+
+        let mut versioned = envelope.clone();
+        versioned[4] = 9;
+        assert!(Envelope::header(&versioned).is_err());
+
+        assert!(Envelope::header(b"LSEV\x01\x01\xff\xff").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "chacha")]
+    fn test_envelope_algorithm_id() {
+        let kek = AesGcm::generate_key().unwrap();
+        for algorithm in [Algorithm::Aes256Gcm, Algorithm::ChaCha20Poly1305] {
+            let envelope = Envelope::encrypt_with_algorithm(b"record", &kek, "kek", algorithm).unwrap();
+            assert_eq!(envelope[4..6], [ENVELOPE_VERSION_WITH_ALGORITHM, algorithm.id()]);
+            assert_eq!(Envelope::header(&envelope).unwrap().algorithm, algorithm);
+            assert_eq!(Envelope::decrypt(&envelope, &kek).unwrap(), b"record");
+        }
+
+        // The algorithm ID is authenticated
+        let mut swapped = Envelope::encrypt_with_algorithm(b"record", &kek, "kek", Algorithm::ChaCha20Poly1305).unwrap();
+        swapped[5] = Algorithm::Aes256Gcm.id();
+        assert!(Envelope::decrypt(&swapped, &kek).is_err());
+
+        // Only AEAD algorithms can protect the payload
+        swapped[5] = Algorithm::Ed25519.id();
+        assert!(Envelope::header(&swapped).is_err());
+        assert!(Envelope::encrypt_with_algorithm(b"record", &kek, "kek", Algorithm::Ed25519).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "chacha")]
+    fn test_envelope_reencrypt() {
+        let old_kek = AesGcm::generate_key().unwrap();
+        let new_kek = AesGcm::generate_key().unwrap();
+        let envelope = Envelope::encrypt(b"customer record", &old_kek, "kek-2023").unwrap();
+
+        let migrated = Envelope::reencrypt(&envelope, &old_kek, &new_kek, "kek-2024", Algorithm::ChaCha20Poly1305).unwrap();
+        let header = Envelope::header(&migrated).unwrap();
+        assert_eq!((header.algorithm, header.key_id), (Algorithm::ChaCha20Poly1305, "kek-2024"));
+        assert_eq!(Envelope::decrypt(&migrated, &new_kek).unwrap(), b"customer record");
+        assert!(Envelope::decrypt(&migrated, &old_kek).is_err());
+
+        // Back again, and nothing is written on failure
+        let restored = Envelope::reencrypt(&migrated, &new_kek, &old_kek, "kek-2023", Algorithm::Aes256Gcm).unwrap();
+        assert_eq!(Envelope::decrypt(&restored, &old_kek).unwrap(), b"customer record");
+        assert_eq!(
+            Envelope::reencrypt(&envelope, &new_kek, &new_kek, "kek-2024", Algorithm::Aes256Gcm).unwrap_err(),
+            CryptoError::DecryptionFailed(ENVELOPE_UNWRAP_FAILED)
+        );
+        assert!(Envelope::reencrypt(&envelope, &old_kek, &new_kek, "kek-2024", Algorithm::Ed25519).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_envelope_reencrypt_stream() {
+        let old_kek = AesGcm::generate_key().unwrap();
+        let new_kek = AesGcm::generate_key().unwrap();
+        let plaintext: Vec<u8> = (0..STREAM_CHUNK_SIZE * 2 + 100).map(|i| i as u8).collect();
+
+        let mut envelope = Vec::new();
+        let written = Envelope::encrypt_stream(&plaintext[..], &mut envelope, &old_kek, "kek-2023").unwrap();
+        assert_eq!(written, envelope.len() as u64);
+        let header = Envelope::header(&envelope).unwrap();
+        assert!(header.streamed);
+        assert_eq!((header.algorithm, header.key_id), (Algorithm::Aes256Gcm, "kek-2023"));
+        assert_eq!(Envelope::decrypt(&envelope, &old_kek).unwrap(), plaintext);
+
+        let mut migrated = Vec::new();
+        Envelope::reencrypt_stream(&envelope[..], &mut migrated, &old_kek, &new_kek, "kek-2024", Algorithm::Aes256Gcm).unwrap();
+        assert_eq!(Envelope::header(&migrated).unwrap().key_id, "kek-2024");
+        let mut decrypted = Vec::new();
+        assert_eq!(Envelope::decrypt_stream(&migrated[..], &mut decrypted, &new_kek).unwrap(), plaintext.len() as u64);
+        assert_eq!(decrypted, plaintext);
+
+        assert_eq!(
+            Envelope::decrypt_stream(&migrated[..], &mut Vec::new(), &old_kek).unwrap_err(),
+            CryptoError::DecryptionFailed(ENVELOPE_UNWRAP_FAILED)
+        );
+        // Dropping the final segment, or editing the header, fails authentication
+        assert!(Envelope::decrypt_stream(&migrated[..migrated.len() - 120], &mut Vec::new(), &new_kek).is_err());
+        assert!(Envelope::reencrypt_stream(&migrated[..migrated.len() - 120], &mut Vec::new(), &new_kek, &old_kek, "kek-2023", Algorithm::Aes256Gcm).is_err());
+        // Streamed envelopes have no cipher ID, so there is nothing to migrate to
+        for algorithm in [Algorithm::ChaCha20Poly1305, Algorithm::Ed25519] {
+            assert_eq!(
+                Envelope::reencrypt_stream(&envelope[..], &mut Vec::new(), &old_kek, &new_kek, "kek-2024", algorithm).unwrap_err(),
+                CryptoError::InvalidInput(ENVELOPE_STREAM_ALGORITHM)
+            );
+        }
+        let mut renamed = migrated.clone();
+        renamed[8] ^= 1;
+        assert!(Envelope::decrypt(&renamed, &new_kek).is_err());
+
+        // Only streamed envelopes can be read as a stream
+        let single = Envelope::encrypt(b"customer record", &old_kek, "kek-2023").unwrap();
+        assert_eq!(
+            Envelope::decrypt_stream(&single[..], &mut Vec::new(), &old_kek).unwrap_err(),
+            CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT)
+        );
+    }
+
+    #[test]
+    fn test_envelope_padding() {
+        let kek = AesGcm::generate_key().unwrap();
+        let bucket = EnvelopePadding::Bucket(64);
+        let plaintexts: [&[u8]; 6] = [b"", b"a", b"\x80", b"trailing zeros\0\0", &[0x80; 63], &[0u8; 64]];
+        for plaintext in plaintexts {
+            for padding in [EnvelopePadding::Padme, bucket] {
+                let envelope = Envelope::encrypt_with_padding(plaintext, &kek, "kek", Algorithm::Aes256Gcm, padding).unwrap();
+                assert_eq!(Envelope::header(&envelope).unwrap().padding, padding);
+                assert_eq!(Envelope::decrypt(&envelope, &kek).unwrap(), plaintext);
+            }
+        }
+
+        // Every message under one bucket seals to the same size
+        let short = Envelope::encrypt_with_padding(b"yes", &kek, "kek", Algorithm::Aes256Gcm, bucket).unwrap();
+        let long = Envelope::encrypt_with_padding(&[1u8; 63], &kek, "kek", Algorithm::Aes256Gcm, bucket).unwrap();
+        let over = Envelope::encrypt_with_padding(&[1u8; 64], &kek, "kek", Algorithm::Aes256Gcm, bucket).unwrap();
+        assert_eq!(short.len(), long.len());
+        assert_eq!(over.len(), long.len() + 64);
+
+        for (len, padded) in [(0, 1), (7, 8), (8, 10), (99, 104), (999, 1024), (1023, 1024)] {
+            assert_eq!(EnvelopePadding::Padme.padded_len(len).unwrap(), padded);
+        }
+        assert_eq!(EnvelopePadding::None.padded_len(5).unwrap(), 5);
+        assert_eq!(EnvelopePadding::Bucket(0).padded_len(5), Err(CryptoError::InvalidInput(ENVELOPE_INVALID_PADDING)));
+        assert!(Envelope::encrypt_with_padding(b"x", &kek, "kek", Algorithm::Aes256Gcm, EnvelopePadding::Bucket(0)).is_err());
+
+        // No padding writes a version 2 envelope
+        let unpadded = Envelope::encrypt_with_padding(b"record", &kek, "kek", Algorithm::Aes256Gcm, EnvelopePadding::None).unwrap();
+        assert_eq!(unpadded[4], ENVELOPE_VERSION_WITH_ALGORITHM);
+        assert_eq!(Envelope::header(&unpadded).unwrap().padding, EnvelopePadding::None);
+
+        // The padding parameters are authenticated, and unknown schemes are rejected
+        let mut tampered = short.clone();
+        tampered[10] = 32;
+        assert!(Envelope::decrypt(&tampered, &kek).is_err());
+        let mut tampered = short.clone();
+        tampered[6] = 9;
+        assert_eq!(Envelope::header(&tampered).unwrap_err(), CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT));
+        assert!(Envelope::header(&short[..9]).is_err());
+
+        // Re-encryption keeps the padding
+        let new_kek = AesGcm::generate_key().unwrap();
+        let migrated = Envelope::reencrypt(&short, &kek, &new_kek, "kek-2", Algorithm::Aes256Gcm).unwrap();
+        assert_eq!(Envelope::header(&migrated).unwrap().padding, bucket);
+        assert_eq!(migrated.len(), short.len() + 2);
+        assert_eq!(Envelope::decrypt(&migrated, &new_kek).unwrap(), b"yes");
+    }
+
+    #[test]
+    #[cfg(feature = "compression")]
+    fn test_envelope_compression() {
+        use crate::error::{ENVELOPE_INVALID_COMPRESSION, LIMIT_PLAINTEXT_TOO_LARGE};
+
+        let kek = AesGcm::generate_key().unwrap();
+        let record = b"customer record ".repeat(256);
+        let envelope = Envelope::encrypt_compressed(&record, &kek, "kek", Algorithm::Aes256Gcm, EnvelopePadding::None).unwrap();
+        let header = Envelope::header(&envelope).unwrap();
+        assert!(header.compressed);
+        assert_eq!(header.padding, EnvelopePadding::None);
+        assert!(envelope.len() < record.len() / 10);
+        assert_eq!(Envelope::decrypt(&envelope, &kek).unwrap(), record);
+        assert!(!Envelope::header(&Envelope::encrypt(&record, &kek, "kek").unwrap()).unwrap().compressed);
+
+        // Compressed, then padded
+        for plaintext in [&b""[..], b"x", &record] {
+            let envelope = Envelope::encrypt_compressed(plaintext, &kek, "kek", Algorithm::Aes256Gcm, EnvelopePadding::Bucket(128)).unwrap();
+            let header = Envelope::header(&envelope).unwrap();
+            assert_eq!((header.padding, header.compressed), (EnvelopePadding::Bucket(128), true));
+            assert_eq!(Envelope::decrypt(&envelope, &kek).unwrap(), plaintext);
+        }
+
+        // The declared length bounds decompression
+        assert_eq!(Envelope::decrypt_with_limit(&envelope, &kek, record.len()).unwrap(), record);
+        assert_eq!(
+            Envelope::decrypt_with_limit(&envelope, &kek, record.len() - 1).unwrap_err(),
+            CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE)
+        );
+        let mut payload = compress_payload(&record).unwrap();
+        for len in [record.len() - 1, record.len() + 1] {
+            payload[..8].copy_from_slice(&(len as u64).to_be_bytes());
+            assert_eq!(decompress_payload(&payload, usize::MAX).unwrap_err(), CryptoError::EncodingFailed(ENVELOPE_INVALID_COMPRESSION));
+        }
+        assert_eq!(decompress_payload(&payload[..12], usize::MAX).unwrap_err(), CryptoError::EncodingFailed(ENVELOPE_INVALID_COMPRESSION));
+
+        // The flag is authenticated, and version 3 without padding or compression is invalid
+        let mut tampered = envelope.clone();
+        tampered[6] ^= COMPRESSED_FLAG;
+        assert_eq!(Envelope::header(&tampered).unwrap_err(), CryptoError::EncodingFailed(ENVELOPE_INVALID_FORMAT));
+        let padded = Envelope::encrypt_with_padding(b"record", &kek, "kek", Algorithm::Aes256Gcm, EnvelopePadding::Padme).unwrap();
+        let mut tampered = padded.clone();
+        tampered[6] ^= COMPRESSED_FLAG;
+        assert!(Envelope::decrypt(&tampered, &kek).is_err());
+
+        let new_kek = AesGcm::generate_key().unwrap();
+        let migrated = Envelope::reencrypt(&envelope, &kek, &new_kek, "kek-2", Algorithm::Aes256Gcm).unwrap();
+        assert!(Envelope::header(&migrated).unwrap().compressed);
+        assert_eq!(Envelope::decrypt(&migrated, &new_kek).unwrap(), record);
+    }
+
+    #[cfg(feature = "kms")]
+    mod kms {
+        use super::*;
+        use std::future::Future;
+        use std::sync::Arc;
+        use std::task::{Context, Poll, Wake, Waker};
+
+        struct ThreadWaker(std::thread::Thread);
+
+        impl Wake for ThreadWaker {
+            fn wake(self: Arc<Self>) {
+                self.0.unpark();
+            }
+        }
+
+        fn block_on<F: Future>(future: F) -> F::Output {
+            let mut future = std::pin::pin!(future);
+            let waker = Waker::from(Arc::new(ThreadWaker(std::thread::current())));
+            let mut cx = Context::from_waker(&waker);
+            loop {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    return output;
+                }
+                std::thread::park();
+            }
+        }
+
+        // Stand-in for a cloud KMS that holds one AES key per key ID
+        struct FakeKms {
+            key_id: &'static str,
+            key: Aes256Key,
+        }
+
+        #[async_trait::async_trait]
+        impl RemoteKms for FakeKms {
+            async fn wrap_key(&self, key_id: &str, plaintext_key: &[u8]) -> CryptoResult<Vec<u8>> {
+                assert_eq!(key_id, self.key_id);
+                AesGcm::encrypt(plaintext_key, &self.key)
+            }
+
+            async fn unwrap_key(&self, key_id: &str, wrapped_key: &[u8]) -> CryptoResult<SecureKey> {
+                assert_eq!(key_id, self.key_id);
+                AesGcm::decrypt(wrapped_key, &self.key).map(SecureKey::new)
+            }
+
+            async fn sign(&self, _key_id: &str, message: &[u8]) -> CryptoResult<Vec<u8>> {
+                crate::core::hash::Hmac::sha256(&crate::core::keys::HmacKey::from_bytes(self.key.as_bytes())?, message)
+            }
+        }
+
+        #[test]
+        fn test_envelope_remote_kms() {
+            let kms = FakeKms {
+                key_id: "arn:aws:kms:eu-west-1:111122223333:key/example",
+                key: AesGcm::generate_key().unwrap(),
+            };
+
+            let envelope = block_on(Envelope::encrypt_with_kms(b"payload", &kms, kms.key_id)).unwrap();
+            let header = Envelope::header(&envelope).unwrap();
+            assert_eq!(header.kek_source, KekSource::RemoteKms);
+            assert_eq!(header.key_id, kms.key_id);
+
+            assert_eq!(block_on(Envelope::decrypt_with_kms(&envelope, &kms)).unwrap(), b"payload");
+
+            // A KMS envelope cannot be opened as a local-KEK envelope and vice versa
+            assert!(Envelope::decrypt(&envelope, &kms.key).is_err());
+            let local = Envelope::encrypt(b"payload", &kms.key, kms.key_id).unwrap();
+            assert!(block_on(Envelope::decrypt_with_kms(&local, &kms)).is_err());
+
+            assert_eq!(block_on(kms.sign(kms.key_id, b"msg")).unwrap().len(), 32);
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use crate::error::{CryptoError, CryptoResult, KEY_EXPORT_INVALID_FORMAT, KEY_EXPORT_UNSUPPORTED_VERSION, KEY_EXPORT_WRONG_PASSPHRASE, KEY_EXPORT_TYPE_MISMATCH};
+use crate::core::asymmetric::{RsaKeyPair, EcdsaKeyPair, Ed25519KeyPair};
+use crate::core::keystore::{self, Keystore};
+use crate::core::random::{SecureRandom, SecureKey};
+use crate::core::symmetric::AesGcm;
+use argon2::Params;
+use zeroize::Zeroizing;
+
+// Blob layout:
+//   magic "LSKX" | version (1) | key type (1) | m_cost (4) | t_cost (4) | p_cost (4) | salt (32) | AES-GCM(nonce + key + tag)
+// The header is bound to the ciphertext as AAD so the key type and parameters cannot be swapped.
+const EXPORT_MAGIC: &[u8; 4] = b"LSKX";
+const EXPORT_VERSION: u8 = 1;
+const EXPORT_SALT_SIZE: usize = 32;
+const EXPORT_HEADER_SIZE: usize = 4 + 1 + 1 + 4 + 4 + 4 + EXPORT_SALT_SIZE;
+const EXPORT_KEY_SIZE: usize = 32;
+
+/// Kind of key stored in an export blob
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKeyType {
+    /// Raw symmetric key bytes
+    Symmetric,
+    /// RSA private key (PKCS#8 DER)
+    Rsa,
+    /// ECDSA P-256 private scalar (32 bytes)
+    EcdsaP256,
+    /// Ed25519 private seed (32 bytes)
+    Ed25519,
+}
+
+impl ExportKeyType {
+    #[inline]
+    fn to_byte(self) -> u8 {
+        match self {
+            ExportKeyType::Symmetric => 1,
+            ExportKeyType::Rsa => 2,
+            ExportKeyType::EcdsaP256 => 3,
+            ExportKeyType::Ed25519 => 4,
+        }
+    }
+
+    #[inline]
+    fn from_byte(byte: u8) -> CryptoResult<Self> {
+        match byte {
+            1 => Ok(ExportKeyType::Symmetric),
+            2 => Ok(ExportKeyType::Rsa),
+            3 => Ok(ExportKeyType::EcdsaP256),
+            4 => Ok(ExportKeyType::Ed25519),
+            _ => Err(CryptoError::EncodingFailed(KEY_EXPORT_INVALID_FORMAT)),
+        }
+    }
+}
+
+/// Key types that can be written to and read from a [`KeyExport`] blob
+pub trait ExportableKey: Sized {
+    /// Type tag recorded in the blob header
+    const KEY_TYPE: ExportKeyType;
+
+    /// Encode the private key material
+    fn to_export_bytes(&self) -> CryptoResult<Zeroizing<Vec<u8>>>;
+
+    /// Decode private key material produced by [`ExportableKey::to_export_bytes`]
+    fn from_export_bytes(bytes: &[u8]) -> CryptoResult<Self>;
+}
+
+impl ExportableKey for SecureKey {
+    const KEY_TYPE: ExportKeyType = ExportKeyType::Symmetric;
+
+    fn to_export_bytes(&self) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Ok(Zeroizing::new(self.as_bytes().to_vec()))
+    }
+
+    fn from_export_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        Ok(SecureKey::new(bytes.to_vec()))
+    }
+}
+
+impl ExportableKey for RsaKeyPair {
+    const KEY_TYPE: ExportKeyType = ExportKeyType::Rsa;
+
+    fn to_export_bytes(&self) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.private_key_der()
+    }
+
+    fn from_export_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        RsaKeyPair::from_private_key_der(bytes)
+    }
+}
+
+impl ExportableKey for EcdsaKeyPair {
+    const KEY_TYPE: ExportKeyType = ExportKeyType::EcdsaP256;
+
+    fn to_export_bytes(&self) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Ok(self.private_key_bytes())
+    }
+
+    fn from_export_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        EcdsaKeyPair::from_private_key_bytes(bytes)
+    }
+}
+
+impl ExportableKey for Ed25519KeyPair {
+    const KEY_TYPE: ExportKeyType = ExportKeyType::Ed25519;
+
+    fn to_export_bytes(&self) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Ok(self.private_key_bytes())
+    }
+
+    fn from_export_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        Ed25519KeyPair::from_private_key_bytes(bytes)
+    }
+}
+
+/// Password-protected, versioned key backup blobs (Argon2id + AES-256-GCM)
+pub struct KeyExport;
+
+impl KeyExport {
+    /// Encrypt a key under `passphrase` into a self-describing blob
+    pub fn export<K: ExportableKey>(key: &K, passphrase: &[u8]) -> CryptoResult<Vec<u8>> {
+        let params = Params::default();
+        let salt = SecureRandom::generate_salt()?;
+
+        let mut header = Vec::with_capacity(EXPORT_HEADER_SIZE);
+        header.extend_from_slice(EXPORT_MAGIC);
+        header.push(EXPORT_VERSION);
+        header.push(K::KEY_TYPE.to_byte());
+        header.extend_from_slice(&params.m_cost().to_be_bytes());
+        header.extend_from_slice(&params.t_cost().to_be_bytes());
+        header.extend_from_slice(&params.p_cost().to_be_bytes());
+        header.extend_from_slice(&salt);
+
+        let wrapping_key = Keystore::derive_wrapping_key(passphrase, &salt, params)?;
+        let payload = key.to_export_bytes()?;
+        let ciphertext = AesGcm::encrypt_with_aad(&payload, &wrapping_key, &header)?;
+
+        let mut result = header;
+        result.extend_from_slice(&ciphertext);
+        Ok(result)
+    }
+
+    /// Decrypt a blob produced by [`KeyExport::export`], checking it holds a `K`
+    pub fn import<K: ExportableKey>(blob: &[u8], passphrase: &[u8]) -> CryptoResult<K> {
+        if Self::key_type(blob)? != K::KEY_TYPE {
+            return Err(CryptoError::InvalidKey(KEY_EXPORT_TYPE_MISMATCH));
+        }
+
+        let (header, ciphertext) = blob.split_at(EXPORT_HEADER_SIZE);
+        let m_cost = u32::from_be_bytes(header[6..10].try_into().unwrap());
+        let t_cost = u32::from_be_bytes(header[10..14].try_into().unwrap());
+        let p_cost = u32::from_be_bytes(header[14..18].try_into().unwrap());
+        let salt = &header[18..];
+
+        let params = keystore::header_params(m_cost, t_cost, p_cost, EXPORT_KEY_SIZE, KEY_EXPORT_INVALID_FORMAT)?;
+
+        let wrapping_key = Keystore::derive_wrapping_key(passphrase, salt, params)?;
+        let payload = Zeroizing::new(
+            AesGcm::decrypt_with_aad(ciphertext, &wrapping_key, header)
+                .map_err(|_| CryptoError::DecryptionFailed(KEY_EXPORT_WRONG_PASSPHRASE))?,
+        );
+
+        K::from_export_bytes(&payload)
+    }
+
+    /// Read the key type from a blob header without decrypting it
+    pub fn key_type(blob: &[u8]) -> CryptoResult<ExportKeyType> {
+        if blob.len() < EXPORT_HEADER_SIZE || &blob[..4] != EXPORT_MAGIC {
+            return Err(CryptoError::EncodingFailed(KEY_EXPORT_INVALID_FORMAT));
+        }
+
+        if blob[4] != EXPORT_VERSION {
+            return Err(CryptoError::EncodingFailed(KEY_EXPORT_UNSUPPORTED_VERSION));
+        }
+
+        ExportKeyType::from_byte(blob[5])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::{EcdsaCrypto, Ed25519Crypto};
+    use crate::error::KEYSTORE_PARAMS_TOO_LARGE;
+
+    #[test]
+    fn test_export_symmetric_key() {
+        let key = SecureRandom::generate_key(32).unwrap();
+        let blob = KeyExport::export(&key, b"backup passphrase").unwrap();
+        assert_eq!(KeyExport::key_type(&blob).unwrap(), ExportKeyType::Symmetric);
+
+        let restored: SecureKey = KeyExport::import(&blob, b"backup passphrase").unwrap();
+        assert_eq!(restored.as_bytes(), key.as_bytes());
+        assert!(KeyExport::import::<SecureKey>(&blob, b"wrong passphrase").is_err());
+    }
+
+    #[test]
+    fn test_export_signing_keys() {
+        let message = b"signed after restore";
+
+        let ed25519 = Ed25519Crypto::generate_keypair().unwrap();
+        let blob = KeyExport::export(&ed25519, b"pass").unwrap();
+        let restored: Ed25519KeyPair = KeyExport::import(&blob, b"pass").unwrap();
+        let signature = Ed25519Crypto::sign(message, restored.signing_key()).unwrap();
+        assert!(Ed25519Crypto::verify(message, &signature, ed25519.verifying_key()).unwrap());
+
+        let ecdsa = EcdsaCrypto::generate_keypair().unwrap();
+        let blob = KeyExport::export(&ecdsa, b"pass").unwrap();
+        let restored: EcdsaKeyPair = KeyExport::import(&blob, b"pass").unwrap();
+        assert_eq!(restored.public_key_bytes(), ecdsa.public_key_bytes());
+
+        // A blob can only be imported as the key type it was exported from
+        assert!(KeyExport::import::<Ed25519KeyPair>(&blob, b"pass").is_err());
+    }
+
+    #[test]
+    fn test_export_rsa_key() {
+        let keypair = RsaKeyPair::generate(2048).unwrap();
+        let blob = KeyExport::export(&keypair, b"pass").unwrap();
+        assert_eq!(KeyExport::key_type(&blob).unwrap(), ExportKeyType::Rsa);
+
+        let restored: RsaKeyPair = KeyExport::import(&blob, b"pass").unwrap();
+        assert_eq!(restored.public_key(), keypair.public_key());
+    }
+
+    #[test]
+    fn test_export_tampering() {
+        let key = SecureRandom::generate_key(32).unwrap();
+        let blob = KeyExport::export(&key, b"pass").unwrap();
+
+        // Flipping the key type byte is caught by the AAD check
+        let mut retyped = blob.clone();
+        retyped[5] = ExportKeyType::Ed25519.to_byte();
+        assert!(KeyExport::import::<Ed25519KeyPair>(&retyped, b"pass").is_err());
+
+        let mut versioned = blob.clone();
+        versioned[4] = 2;
+        assert!(KeyExport::key_type(&versioned).is_err());
+
+        assert!(KeyExport::key_type(b"LSKX").is_err());
+        assert!(KeyExport::import::<SecureKey>(&blob[..blob.len() - 1], b"pass").is_err());
+    }
+
+    #[test]
+    fn test_export_rejects_inflated_header() {
+        let key = SecureRandom::generate_key(32).unwrap();
+        let blob = KeyExport::export(&key, b"pass").unwrap();
+
+        for offset in [6, 10, 14] {
+            let mut inflated = blob.clone();
+            inflated[offset..offset + 4].copy_from_slice(&u32::MAX.to_be_bytes());
+            assert_eq!(KeyExport::import::<SecureKey>(&inflated, b"pass").err(), Some(CryptoError::InvalidInput(KEYSTORE_PARAMS_TOO_LARGE)));
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::error::{CryptoError, CryptoResult, KEY_PROVIDER_UNSUPPORTED_OPERATION, HARDWARE_KEY_INVALID_PUBLIC_KEY, HARDWARE_KEY_INVALID_SIGNATURE};
+use crate::core::provider::KeyProvider;
+use crate::core::random::SecureKey;
+use p256::ecdsa::Signature;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::pkcs8::DecodePublicKey;
+use p256::PublicKey;
+use std::sync::Arc;
+
+/// A platform keystore whose private keys never leave secure hardware
+///
+/// Implemented by the Kotlin bindings on top of the Android Keystore (StrongBox
+/// when available) and by the Swift bindings on top of the Secure Enclave.
+/// Keys are P-256 and addressed by alias. Implementations may return signatures
+/// as DER or `r || s` and public keys as SEC1 or SPKI DER; [`HardwareKeyProvider`]
+/// normalizes both to the software formats.
+pub trait PlatformKeystore: Send + Sync {
+    /// Generate a new non-exportable key under `alias` and return its public key
+    fn generate(&self, alias: &str) -> CryptoResult<Vec<u8>>;
+
+    /// Get the public key of the key stored under `alias`
+    fn public_key(&self, alias: &str) -> CryptoResult<Vec<u8>>;
+
+    /// Sign `message` with ECDSA P-256 / SHA-256
+    fn sign(&self, alias: &str, message: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Perform ECDH with a SEC1-encoded peer public key, returning the shared x-coordinate
+    fn agree(&self, alias: &str, peer_public_key: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Delete the key stored under `alias`
+    fn delete(&self, alias: &str) -> CryptoResult<()>;
+}
+
+/// [`KeyProvider`] for a hardware-resident P-256 key
+///
+/// Supports signing and ECDH only; the private key cannot be exported and
+/// `decrypt` is unsupported.
+#[derive(Clone)]
+pub struct HardwareKeyProvider {
+    keystore: Arc<dyn PlatformKeystore>,
+    alias: String,
+}
+
+impl HardwareKeyProvider {
+    /// Generate a new hardware key under `alias`
+    pub fn generate(keystore: Arc<dyn PlatformKeystore>, alias: &str) -> CryptoResult<Self> {
+        keystore.generate(alias)?;
+        Ok(Self { keystore, alias: alias.to_string() })
+    }
+
+    /// Use an existing hardware key stored under `alias`
+    pub fn open(keystore: Arc<dyn PlatformKeystore>, alias: &str) -> CryptoResult<Self> {
+        keystore.public_key(alias)?;
+        Ok(Self { keystore, alias: alias.to_string() })
+    }
+
+    /// Get the key alias
+    #[inline]
+    pub fn alias(&self) -> &str {
+        &self.alias
+    }
+
+    /// Derive an ECDH shared secret with a peer's SEC1-encoded P-256 public key
+    pub fn ecdh(&self, peer_public_key: &[u8]) -> CryptoResult<SecureKey> {
+        let peer = PublicKey::from_sec1_bytes(peer_public_key)
+            .map_err(|_| CryptoError::InvalidKey(HARDWARE_KEY_INVALID_PUBLIC_KEY))?;
+        let uncompressed = peer.to_encoded_point(false);
+
+        self.keystore.agree(&self.alias, uncompressed.as_bytes()).map(SecureKey::new)
+    }
+
+    /// Delete the hardware key
+    #[inline]
+    pub fn delete(self) -> CryptoResult<()> {
+        self.keystore.delete(&self.alias)
+    }
+}
+
+impl KeyProvider for HardwareKeyProvider {
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        let signature = self.keystore.sign(&self.alias, message)?;
+
+        // Android and Apple return X9.62 DER; normalize to the fixed r || s form
+        let signature = match signature.len() {
+            64 => Signature::from_slice(&signature),
+            _ => Signature::from_der(&signature),
+        }
+        .map_err(|_| CryptoError::SignatureFailed(HARDWARE_KEY_INVALID_SIGNATURE))?;
+
+        Ok(signature.normalize_s().unwrap_or(signature).to_bytes().to_vec())
+    }
+
+    #[inline]
+    fn decrypt(&self, _ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        Err(CryptoError::InvalidKey(KEY_PROVIDER_UNSUPPORTED_OPERATION))
+    }
+
+    fn public_key(&self) -> CryptoResult<Vec<u8>> {
+        let encoded = self.keystore.public_key(&self.alias)?;
+
+        // Android returns X.509 SubjectPublicKeyInfo, Apple returns SEC1
+        let public_key = PublicKey::from_sec1_bytes(&encoded)
+            .or_else(|_| PublicKey::from_public_key_der(&encoded))
+            .map_err(|_| CryptoError::InvalidKey(HARDWARE_KEY_INVALID_PUBLIC_KEY))?;
+
+        Ok(public_key.to_encoded_point(false).as_bytes().to_vec())
+    }
+}
+
+impl std::fmt::Debug for HardwareKeyProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HardwareKeyProvider")
+            .field("alias", &self.alias)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::{EcdsaCrypto, EcdsaKeyPair};
+    use crate::error::KEYCHAIN_ENTRY_NOT_FOUND;
+    use p256::ecdsa::signature::Signer;
+    use p256::ecdsa::SigningKey;
+    use p256::pkcs8::EncodePublicKey;
+    use rand::rngs::OsRng;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    // Software stand-in that answers in the Android formats (DER signatures, SPKI keys)
+    #[derive(Default)]
+    struct FakeAndroidKeystore {
+        keys: Mutex<BTreeMap<String, SigningKey>>,
+    }
+
+    impl FakeAndroidKeystore {
+        fn key(&self, alias: &str) -> CryptoResult<SigningKey> {
+            self.keys.lock().unwrap().get(alias).cloned()
+                .ok_or(CryptoError::InvalidKey(KEYCHAIN_ENTRY_NOT_FOUND))
+        }
+    }
+
+    impl PlatformKeystore for FakeAndroidKeystore {
+        fn generate(&self, alias: &str) -> CryptoResult<Vec<u8>> {
+            let key = SigningKey::random(&mut OsRng);
+            self.keys.lock().unwrap().insert(alias.to_string(), key);
+            self.public_key(alias)
+        }
+
+        fn public_key(&self, alias: &str) -> CryptoResult<Vec<u8>> {
+            let der = self.key(alias)?.verifying_key().to_public_key_der().unwrap();
+            Ok(der.into_vec())
+        }
+
+        fn sign(&self, alias: &str, message: &[u8]) -> CryptoResult<Vec<u8>> {
+            let signature: Signature = self.key(alias)?.sign(message);
+            Ok(signature.to_der().as_bytes().to_vec())
+        }
+
+        fn agree(&self, alias: &str, peer_public_key: &[u8]) -> CryptoResult<Vec<u8>> {
+            let peer = PublicKey::from_sec1_bytes(peer_public_key).unwrap();
+            let secret = self.key(alias)?.as_nonzero_scalar().to_owned();
+            let shared = p256::ecdh::diffie_hellman(secret, peer.as_affine());
+            Ok(shared.raw_secret_bytes().to_vec())
+        }
+
+        fn delete(&self, alias: &str) -> CryptoResult<()> {
+            self.keys.lock().unwrap().remove(alias).map(|_| ())
+                .ok_or(CryptoError::InvalidKey(KEYCHAIN_ENTRY_NOT_FOUND))
+        }
+    }
+
+    #[test]
+    fn test_hardware_provider_matches_software_formats() {
+        let keystore: Arc<dyn PlatformKeystore> = Arc::new(FakeAndroidKeystore::default());
+        let provider = HardwareKeyProvider::generate(keystore.clone(), "device-key").unwrap();
+
+        let public_key = provider.public_key().unwrap();
+        assert_eq!(public_key.len(), 65);
+
+        let signature = provider.sign(b"attestation").unwrap();
+        assert_eq!(signature.len(), 64);
+
+        let verifying_key = EcdsaKeyPair::verifying_key_from_bytes(&public_key).unwrap();
+        assert!(EcdsaCrypto::verify(b"attestation", &signature, &verifying_key).unwrap());
+        assert!(provider.decrypt(b"data").is_err());
+
+        let reopened = HardwareKeyProvider::open(keystore.clone(), "device-key").unwrap();
+        assert_eq!(reopened.public_key().unwrap(), public_key);
+        assert!(HardwareKeyProvider::open(keystore, "missing").is_err());
+    }
+
+    #[test]
+    fn test_hardware_provider_ecdh() {
+        let keystore: Arc<dyn PlatformKeystore> = Arc::new(FakeAndroidKeystore::default());
+        let provider = HardwareKeyProvider::generate(keystore, "ecdh-key").unwrap();
+
+        let peer = p256::SecretKey::random(&mut OsRng);
+        let peer_public = peer.public_key().to_sec1_bytes();
+        let shared = provider.ecdh(&peer_public).unwrap();
+
+        let device_public = PublicKey::from_sec1_bytes(&provider.public_key().unwrap()).unwrap();
+        let expected = p256::ecdh::diffie_hellman(peer.to_nonzero_scalar(), device_public.as_affine());
+        assert_eq!(shared.as_bytes(), expected.raw_secret_bytes().as_slice());
+
+        assert!(provider.ecdh(&[0x04; 10]).is_err());
+        provider.delete().unwrap();
+    }
+}
@@ -1,6 +1,31 @@
-use crate::error::{CryptoError, CryptoResult, HASH_LENGTH_ZERO, INVALID_HMAC_KEY};
+use crate::error::{CryptoError, CryptoResult, INVALID_HMAC_KEY};
+use crate::core::encoding::encode_hex_into;
+use crate::core::keys::HmacKey;
+#[cfg(feature = "blake3")]
+use crate::error::{HASH_LENGTH_ZERO, BLAKE3_INVALID_KEY_LENGTH};
 use sha2::{Sha256, Sha512, Digest};
+use sha3::{Sha3_256, Sha3_512};
+#[cfg(feature = "blake3")]
 use blake3::Hasher as Blake3Hasher;
+use hmac::{Hmac as HmacImpl, Mac};
+use subtle::ConstantTimeEq;
+use alloc::{vec::Vec, string::String};
+#[cfg(feature = "blake3")]
+use alloc::boxed::Box;
+#[cfg(any(feature = "blake3", feature = "std"))]
+use alloc::vec;
+#[cfg(any(feature = "blake3", feature = "std"))]
+use zeroize::Zeroizing;
+#[cfg(feature = "std")]
+use crate::error::{HASH_READ_FAILED, HASH_CHUNK_SIZE_ZERO};
+#[cfg(feature = "std")]
+use std::io::{ErrorKind, Read};
+#[cfg(feature = "mmap")]
+use std::path::Path;
+
+/// Read size used by [`StreamingHasher::update_reader`] (64 KiB)
+#[cfg(feature = "std")]
+pub const DEFAULT_HASH_CHUNK_SIZE: usize = 64 * 1024;
 
 /// SHA-256 hashing
 pub struct Sha256Hash;
@@ -9,24 +34,33 @@ impl Sha256Hash {
     /// Compute SHA-256 hash of input data
     #[inline]
     pub fn hash(data: &[u8]) -> CryptoResult<Vec<u8>> {
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        Ok(hasher.finalize().to_vec())
+        Ok(Self::hash_array(data)?.to_vec())
+    }
+
+    /// Compute SHA-256 hash of input data as a fixed-size array, without allocating
+    #[inline]
+    pub fn hash_array(data: &[u8]) -> CryptoResult<[u8; 32]> {
+        Ok(Sha256::digest(data).into())
     }
 
     /// Compute SHA-256 hash and return as hex string
     #[inline]
     pub fn hash_hex(data: &[u8]) -> CryptoResult<String> {
-        let mut hasher = Sha256::new();
-        hasher.update(data);
-        Ok(hex::encode(hasher.finalize()))
+        let mut hex = [0u8; 64];
+        Ok(Self::hash_hex_into(data, &mut hex)?.into())
+    }
+
+    /// Compute SHA-256 hash as hex into `out` (at least 64 bytes) without allocating
+    #[inline]
+    pub fn hash_hex_into<'a>(data: &[u8], out: &'a mut [u8]) -> CryptoResult<&'a str> {
+        encode_hex_into(&Sha256::digest(data), out)
     }
 
     /// Verify data against a SHA-256 hash
     #[inline]
     pub fn verify(data: &[u8], expected_hash: &[u8]) -> CryptoResult<bool> {
-        let computed_hash = Self::hash(data)?;
-        Ok(computed_hash == expected_hash)
+        let computed_hash = Self::hash_array(data)?;
+        Ok(ConstantTime::bytes_eq(&computed_hash, expected_hash))
     }
 }
 
@@ -37,50 +71,159 @@ impl Sha512Hash {
     /// Compute SHA-512 hash of input data
     #[inline]
     pub fn hash(data: &[u8]) -> CryptoResult<Vec<u8>> {
-        let mut hasher = Sha512::new();
-        hasher.update(data);
-        Ok(hasher.finalize().to_vec())
+        Ok(Self::hash_array(data)?.to_vec())
+    }
+
+    /// Compute SHA-512 hash of input data as a fixed-size array, without allocating
+    #[inline]
+    pub fn hash_array(data: &[u8]) -> CryptoResult<[u8; 64]> {
+        Ok(Sha512::digest(data).into())
     }
 
     /// Compute SHA-512 hash and return as hex string
     #[inline]
     pub fn hash_hex(data: &[u8]) -> CryptoResult<String> {
-        let mut hasher = Sha512::new();
-        hasher.update(data);
-        Ok(hex::encode(hasher.finalize()))
+        let mut hex = [0u8; 128];
+        Ok(Self::hash_hex_into(data, &mut hex)?.into())
+    }
+
+    /// Compute SHA-512 hash as hex into `out` (at least 128 bytes) without allocating
+    #[inline]
+    pub fn hash_hex_into<'a>(data: &[u8], out: &'a mut [u8]) -> CryptoResult<&'a str> {
+        encode_hex_into(&Sha512::digest(data), out)
     }
 
     /// Verify data against a SHA-512 hash
     #[inline]
     pub fn verify(data: &[u8], expected_hash: &[u8]) -> CryptoResult<bool> {
-        let computed_hash = Self::hash(data)?;
-        Ok(computed_hash == expected_hash)
+        let computed_hash = Self::hash_array(data)?;
+        Ok(ConstantTime::bytes_eq(&computed_hash, expected_hash))
+    }
+}
+
+/// SHA3-256 hashing (FIPS 202)
+pub struct Sha3_256Hash;
+
+impl Sha3_256Hash {
+    /// Compute SHA3-256 hash of input data
+    #[inline]
+    pub fn hash(data: &[u8]) -> CryptoResult<Vec<u8>> {
+        Ok(Self::hash_array(data)?.to_vec())
+    }
+
+    /// Compute SHA3-256 hash of input data as a fixed-size array, without allocating
+    #[inline]
+    pub fn hash_array(data: &[u8]) -> CryptoResult<[u8; 32]> {
+        Ok(Sha3_256::digest(data).into())
+    }
+
+    /// Compute SHA3-256 hash and return as hex string
+    #[inline]
+    pub fn hash_hex(data: &[u8]) -> CryptoResult<String> {
+        let mut hex = [0u8; 64];
+        Ok(Self::hash_hex_into(data, &mut hex)?.into())
+    }
+
+    /// Compute SHA3-256 hash as hex into `out` (at least 64 bytes) without allocating
+    #[inline]
+    pub fn hash_hex_into<'a>(data: &[u8], out: &'a mut [u8]) -> CryptoResult<&'a str> {
+        encode_hex_into(&Sha3_256::digest(data), out)
+    }
+
+    /// Verify data against a SHA3-256 hash
+    #[inline]
+    pub fn verify(data: &[u8], expected_hash: &[u8]) -> CryptoResult<bool> {
+        let computed_hash = Self::hash_array(data)?;
+        Ok(ConstantTime::bytes_eq(&computed_hash, expected_hash))
+    }
+}
+
+/// SHA3-512 hashing (FIPS 202)
+pub struct Sha3_512Hash;
+
+impl Sha3_512Hash {
+    /// Compute SHA3-512 hash of input data
+    #[inline]
+    pub fn hash(data: &[u8]) -> CryptoResult<Vec<u8>> {
+        Ok(Self::hash_array(data)?.to_vec())
+    }
+
+    /// Compute SHA3-512 hash of input data as a fixed-size array, without allocating
+    #[inline]
+    pub fn hash_array(data: &[u8]) -> CryptoResult<[u8; 64]> {
+        Ok(Sha3_512::digest(data).into())
+    }
+
+    /// Compute SHA3-512 hash and return as hex string
+    #[inline]
+    pub fn hash_hex(data: &[u8]) -> CryptoResult<String> {
+        let mut hex = [0u8; 128];
+        Ok(Self::hash_hex_into(data, &mut hex)?.into())
+    }
+
+    /// Compute SHA3-512 hash as hex into `out` (at least 128 bytes) without allocating
+    #[inline]
+    pub fn hash_hex_into<'a>(data: &[u8], out: &'a mut [u8]) -> CryptoResult<&'a str> {
+        encode_hex_into(&Sha3_512::digest(data), out)
+    }
+
+    /// Verify data against a SHA3-512 hash
+    #[inline]
+    pub fn verify(data: &[u8], expected_hash: &[u8]) -> CryptoResult<bool> {
+        let computed_hash = Self::hash_array(data)?;
+        Ok(ConstantTime::bytes_eq(&computed_hash, expected_hash))
     }
 }
 
 /// BLAKE3 hashing
+#[cfg(feature = "blake3")]
 pub struct Blake3Hash;
 
+#[cfg(feature = "blake3")]
 impl Blake3Hash {
     /// Compute BLAKE3 hash of input data
     #[inline]
     pub fn hash(data: &[u8]) -> CryptoResult<Vec<u8>> {
-        let hash = blake3::hash(data);
-        Ok(hash.as_bytes().to_vec())
+        Ok(Self::hash_array(data)?.to_vec())
+    }
+
+    /// Compute BLAKE3 hash of input data as a fixed-size array, without allocating
+    #[inline]
+    pub fn hash_array(data: &[u8]) -> CryptoResult<[u8; 32]> {
+        Ok(blake3::hash(data).into())
     }
 
     /// Compute BLAKE3 hash and return as hex string
     #[inline]
     pub fn hash_hex(data: &[u8]) -> CryptoResult<String> {
-        let hash = blake3::hash(data);
-        Ok(hex::encode(hash.as_bytes()))
+        let mut hex = [0u8; 64];
+        Ok(Self::hash_hex_into(data, &mut hex)?.into())
+    }
+
+    /// Compute BLAKE3 hash as hex into `out` (at least 64 bytes) without allocating
+    #[inline]
+    pub fn hash_hex_into<'a>(data: &[u8], out: &'a mut [u8]) -> CryptoResult<&'a str> {
+        encode_hex_into(blake3::hash(data).as_bytes(), out)
+    }
+
+    /// Hash the file at `path` by memory-mapping it and hashing on the rayon thread pool
+    ///
+    /// The fastest way to hash large files on fast storage. Small files and
+    /// non-regular files such as pipes are read instead. The file must not be
+    /// modified while it is hashed.
+    #[cfg(feature = "mmap")]
+    pub fn hash_file_mmap(path: impl AsRef<Path>) -> CryptoResult<Vec<u8>> {
+        let mut hasher = Blake3Hasher::new();
+        hasher.update_mmap_rayon(path)
+            .map_err(|err| CryptoError::IoFailed(HASH_READ_FAILED).caused_by(err))?;
+        Ok(hasher.finalize().as_bytes().to_vec())
     }
 
     /// Verify data against a BLAKE3 hash
     #[inline]
     pub fn verify(data: &[u8], expected_hash: &[u8]) -> CryptoResult<bool> {
-        let computed_hash = Self::hash(data)?;
-        Ok(computed_hash == expected_hash)
+        let computed_hash = Self::hash_array(data)?;
+        Ok(ConstantTime::bytes_eq(&computed_hash, expected_hash))
     }
 
     /// Compute BLAKE3 hash with custom output length
@@ -96,60 +239,233 @@ impl Blake3Hash {
         hasher.finalize_xof().fill(&mut output);
         Ok(output)
     }
+
+    /// Compute a keyed BLAKE3 hash (MAC) with a 32-byte key
+    #[inline]
+    pub fn keyed_hash(key: &[u8], data: &[u8]) -> CryptoResult<Vec<u8>> {
+        let key: &[u8; 32] = key.try_into()
+            .map_err(|_| CryptoError::InvalidKey(BLAKE3_INVALID_KEY_LENGTH))?;
+        Ok(blake3::keyed_hash(key, data).as_bytes().to_vec())
+    }
+
+    /// Derive `length` bytes of key material in BLAKE3's key derivation mode
+    ///
+    /// `context` should be a hardcoded, globally unique, application-specific
+    /// string, e.g. `"example.com 2024-01-01 session tokens v1"`.
+    pub fn derive_key(context: &str, key_material: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        if length == 0 {
+            return Err(CryptoError::InvalidInput(HASH_LENGTH_ZERO));
+        }
+
+        let mut hasher = Blake3Hasher::new_derive_key(context);
+        hasher.update(key_material);
+        let mut output = vec![0u8; length];
+        hasher.finalize_xof().fill(&mut output);
+        Ok(output)
+    }
+
+    /// Derive key material in BLAKE3's key derivation mode, zeroizing it on drop
+    #[inline]
+    pub fn derive_key_zeroizing(context: &str, key_material: &[u8], length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_key(context, key_material, length).map(Zeroizing::new)
+    }
+}
+
+/// Constant-time comparison for MACs, hashes and other secret-dependent values
+pub struct ConstantTime;
+
+impl ConstantTime {
+    /// Compare two byte strings without leaking where they differ
+    ///
+    /// Only the lengths are compared in variable time, so use this for
+    /// fixed-size values such as MACs and digests.
+    #[inline]
+    pub fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+        a.ct_eq(b).into()
+    }
 }
 
 /// HMAC (Hash-based Message Authentication Code)
+///
+/// Keys are [`HmacKey`]s of at least 256 bits. Keys fixed by a protocol or
+/// test vector at another length go through [`StreamingHasher::hmac_sha256`].
 pub struct Hmac;
 
 impl Hmac {
     /// Compute HMAC-SHA256
     #[inline]
-    pub fn sha256(key: &[u8], message: &[u8]) -> CryptoResult<Vec<u8>> {
+    pub fn sha256(key: &HmacKey, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Ok(Self::sha256_array(key, message)?.to_vec())
+    }
+
+    /// Compute HMAC-SHA256 as a fixed-size array, without allocating
+    #[inline]
+    pub fn sha256_array(key: &HmacKey, message: &[u8]) -> CryptoResult<[u8; 32]> {
         use sha2::Sha256;
         use hmac::{Hmac as HmacImpl, Mac};
 
         type HmacSha256 = HmacImpl<Sha256>;
 
-        let mut mac = HmacSha256::new_from_slice(key)
+        let mut mac = HmacSha256::new_from_slice(key.as_bytes())
             .map_err(|_| CryptoError::InvalidKey(INVALID_HMAC_KEY))?;
 
         mac.update(message);
-        Ok(mac.finalize().into_bytes().to_vec())
+        Ok(mac.finalize().into_bytes().into())
     }
 
     /// Compute HMAC-SHA512
     #[inline]
-    pub fn sha512(key: &[u8], message: &[u8]) -> CryptoResult<Vec<u8>> {
+    pub fn sha512(key: &HmacKey, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Ok(Self::sha512_array(key, message)?.to_vec())
+    }
+
+    /// Compute HMAC-SHA512 as a fixed-size array, without allocating
+    #[inline]
+    pub fn sha512_array(key: &HmacKey, message: &[u8]) -> CryptoResult<[u8; 64]> {
         use sha2::Sha512;
         use hmac::{Hmac as HmacImpl, Mac};
 
         type HmacSha512 = HmacImpl<Sha512>;
 
-        let mut mac = HmacSha512::new_from_slice(key)
+        let mut mac = HmacSha512::new_from_slice(key.as_bytes())
             .map_err(|_| CryptoError::InvalidKey(INVALID_HMAC_KEY))?;
 
         mac.update(message);
-        Ok(mac.finalize().into_bytes().to_vec())
+        Ok(mac.finalize().into_bytes().into())
     }
 
     /// Verify HMAC-SHA256
     #[inline]
-    pub fn verify_sha256(key: &[u8], message: &[u8], expected_mac: &[u8]) -> CryptoResult<bool> {
-        let computed_mac = Self::sha256(key, message)?;
-        Ok(computed_mac == expected_mac)
+    pub fn verify_sha256(key: &HmacKey, message: &[u8], expected_mac: &[u8]) -> CryptoResult<bool> {
+        let computed_mac = Self::sha256_array(key, message)?;
+        Ok(ConstantTime::bytes_eq(&computed_mac, expected_mac))
     }
 
     /// Verify HMAC-SHA512
     #[inline]
-    pub fn verify_sha512(key: &[u8], message: &[u8], expected_mac: &[u8]) -> CryptoResult<bool> {
-        let computed_mac = Self::sha512(key, message)?;
-        Ok(computed_mac == expected_mac)
+    pub fn verify_sha512(key: &HmacKey, message: &[u8], expected_mac: &[u8]) -> CryptoResult<bool> {
+        let computed_mac = Self::sha512_array(key, message)?;
+        Ok(ConstantTime::bytes_eq(&computed_mac, expected_mac))
     }
 }
 
+/// Incremental hasher for data that arrives in chunks
+#[derive(Clone)]
+pub enum StreamingHasher {
+    /// SHA-256
+    Sha256(Sha256),
+    /// SHA-512
+    Sha512(Sha512),
+    /// BLAKE3 (32-byte output)
+    #[cfg(feature = "blake3")]
+    Blake3(Box<Blake3Hasher>),
+    /// HMAC-SHA256
+    HmacSha256(HmacImpl<Sha256>),
+    /// HMAC-SHA512
+    HmacSha512(HmacImpl<Sha512>),
+}
+
+impl StreamingHasher {
+    /// Start a SHA-256 hash
+    #[inline]
+    pub fn sha256() -> Self {
+        StreamingHasher::Sha256(Sha256::new())
+    }
+
+    /// Start a SHA-512 hash
+    #[inline]
+    pub fn sha512() -> Self {
+        StreamingHasher::Sha512(Sha512::new())
+    }
+
+    /// Start a BLAKE3 hash
+    #[cfg(feature = "blake3")]
+    #[inline]
+    pub fn blake3() -> Self {
+        StreamingHasher::Blake3(Box::new(Blake3Hasher::new()))
+    }
+
+    /// Start an HMAC-SHA256 computation
+    #[inline]
+    pub fn hmac_sha256(key: &[u8]) -> CryptoResult<Self> {
+        HmacImpl::<Sha256>::new_from_slice(key)
+            .map(StreamingHasher::HmacSha256)
+            .map_err(|_| CryptoError::InvalidKey(INVALID_HMAC_KEY))
+    }
+
+    /// Start an HMAC-SHA512 computation
+    #[inline]
+    pub fn hmac_sha512(key: &[u8]) -> CryptoResult<Self> {
+        HmacImpl::<Sha512>::new_from_slice(key)
+            .map(StreamingHasher::HmacSha512)
+            .map_err(|_| CryptoError::InvalidKey(INVALID_HMAC_KEY))
+    }
+
+    /// Feed the next chunk of input
+    #[inline]
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            StreamingHasher::Sha256(hasher) => Digest::update(hasher, data),
+            StreamingHasher::Sha512(hasher) => Digest::update(hasher, data),
+            #[cfg(feature = "blake3")]
+            StreamingHasher::Blake3(hasher) => {
+                hasher.update(data);
+            }
+            StreamingHasher::HmacSha256(mac) => mac.update(data),
+            StreamingHasher::HmacSha512(mac) => mac.update(data),
+        }
+    }
+
+    /// Feed everything `reader` produces, [`DEFAULT_HASH_CHUNK_SIZE`] bytes at a time
+    ///
+    /// Returns the number of bytes hashed.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn update_reader(&mut self, reader: impl Read) -> CryptoResult<u64> {
+        self.update_reader_with_chunk_size(reader, DEFAULT_HASH_CHUNK_SIZE)
+    }
 
+    /// Feed everything `reader` produces, reading up to `chunk_size` bytes at a time
+    ///
+    /// Larger chunks mean fewer read calls on fast storage (e.g. 1 MiB for
+    /// multi-gigabyte images). Returns the number of bytes hashed.
+    #[cfg(feature = "std")]
+    pub fn update_reader_with_chunk_size(&mut self, mut reader: impl Read, chunk_size: usize) -> CryptoResult<u64> {
+        if chunk_size == 0 {
+            return Err(CryptoError::InvalidInput(HASH_CHUNK_SIZE_ZERO));
+        }
+
+        // The input may be an HMAC message, so wipe the buffer afterwards
+        let mut buffer = Zeroizing::new(vec![0u8; chunk_size]);
+        let mut total = 0u64;
+        loop {
+            match reader.read(&mut buffer) {
+                Ok(0) => return Ok(total),
+                Ok(read) => {
+                    self.update(&buffer[..read]);
+                    total += read as u64;
+                }
+                Err(err) if err.kind() == ErrorKind::Interrupted => {}
+                Err(err) => return Err(CryptoError::IoFailed(HASH_READ_FAILED).caused_by(err)),
+            }
+        }
+    }
 
-#[cfg(test)]
+    /// Finish and return the digest or MAC
+    #[inline]
+    pub fn finalize(self) -> Vec<u8> {
+        match self {
+            StreamingHasher::Sha256(hasher) => hasher.finalize().to_vec(),
+            StreamingHasher::Sha512(hasher) => hasher.finalize().to_vec(),
+            #[cfg(feature = "blake3")]
+            StreamingHasher::Blake3(hasher) => hasher.finalize().as_bytes().to_vec(),
+            StreamingHasher::HmacSha256(mac) => mac.finalize().into_bytes().to_vec(),
+            StreamingHasher::HmacSha512(mac) => mac.finalize().into_bytes().to_vec(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "blake3"))]
 mod tests {
     use super::*;
 
@@ -172,6 +488,10 @@ mod tests {
         let hash_hex = Sha256Hash::hash_hex(data).unwrap();
 
         assert_eq!(hash_hex, "dffd6021bb2bd5b0af676290809ec3a53191dd81c7f70a4b28688a362182986f");
+
+        let mut out = [0u8; 64];
+        assert_eq!(Sha256Hash::hash_hex_into(data, &mut out).unwrap(), hash_hex);
+        assert!(Sha256Hash::hash_hex_into(data, &mut [0u8; 63]).is_err());
     }
 
     #[test]
@@ -217,33 +537,160 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_sha3_known_answers() {
+        // FIPS 202 "abc" vectors
+        assert_eq!(
+            Sha3_256Hash::hash_hex(b"abc").unwrap(),
+            "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532"
+        );
+        assert_eq!(
+            hex::encode(Sha3_512Hash::hash(b"abc").unwrap()),
+            "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0"
+        );
+        assert!(Sha3_256Hash::verify(b"abc", &Sha3_256Hash::hash(b"abc").unwrap()).unwrap());
+        assert!(!Sha3_512Hash::verify(b"abd", &Sha3_512Hash::hash(b"abc").unwrap()).unwrap());
+    }
+
+    #[test]
+    fn test_hash_arrays_match_vec_outputs() {
+        let data = b"fixed-size outputs";
+        assert_eq!(Sha256Hash::hash_array(data).unwrap(), Sha256Hash::hash(data).unwrap().as_slice());
+        assert_eq!(Sha512Hash::hash_array(data).unwrap(), Sha512Hash::hash(data).unwrap().as_slice());
+        assert_eq!(Sha3_256Hash::hash_array(data).unwrap(), Sha3_256Hash::hash(data).unwrap().as_slice());
+        assert_eq!(Sha3_512Hash::hash_array(data).unwrap(), Sha3_512Hash::hash(data).unwrap().as_slice());
+        assert_eq!(Blake3Hash::hash_array(data).unwrap(), Blake3Hash::hash(data).unwrap().as_slice());
+        assert_eq!(hex::encode(Sha256Hash::hash_array(b"abc").unwrap()), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad");
+
+        let key = HmacKey::generate().unwrap();
+        assert_eq!(Hmac::sha256_array(&key, data).unwrap(), Hmac::sha256(&key, data).unwrap().as_slice());
+        assert_eq!(Hmac::sha512_array(&key, data).unwrap(), Hmac::sha512(&key, data).unwrap().as_slice());
+    }
+
+    #[test]
+    fn test_blake3_keyed_and_derive_key() {
+        // Official BLAKE3 test vectors, empty input
+        let key = b"whats the Elvish word for friend";
+        assert_eq!(
+            hex::encode(Blake3Hash::keyed_hash(key, b"").unwrap()),
+            "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26"
+        );
+        assert_eq!(
+            hex::encode(Blake3Hash::derive_key("BLAKE3 2019-12-27 16:29:52 test vectors context", b"", 32).unwrap()),
+            "2cc39783c223154fea8dfb7c1b1660f2ac2dcbd1c1de8277b0b0dd39b7e50d7d"
+        );
+
+        assert_eq!(
+            Blake3Hash::keyed_hash(&key[..31], b"").unwrap_err(),
+            CryptoError::InvalidKey(BLAKE3_INVALID_KEY_LENGTH)
+        );
+        assert!(Blake3Hash::derive_key("context", b"material", 0).is_err());
+        assert_eq!(Blake3Hash::derive_key("context", b"material", 64).unwrap().len(), 64);
+        assert_eq!(
+            Blake3Hash::derive_key_zeroizing("context", b"material", 32).unwrap().as_slice(),
+            Blake3Hash::derive_key("context", b"material", 32).unwrap().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_constant_time_eq() {
+        assert!(ConstantTime::bytes_eq(b"same mac", b"same mac"));
+        assert!(ConstantTime::bytes_eq(b"", b""));
+        assert!(!ConstantTime::bytes_eq(b"same mac", b"same mad"));
+        assert!(!ConstantTime::bytes_eq(b"same mac", b"same ma"));
+
+        let key = HmacKey::generate().unwrap();
+        let mac = Hmac::sha256(&key, b"message").unwrap();
+        assert!(Hmac::verify_sha256(&key, b"message", &mac).unwrap());
+        assert!(!Hmac::verify_sha256(&key, b"message", &mac[..31]).unwrap());
+    }
+
     #[test]
     fn test_hmac_sha256() {
-        let key = b"secret_key";
+        let key = HmacKey::from_bytes(b"secret_key_of_at_least_32_bytes!").unwrap();
         let message = b"Hello, World!";
 
-        let mac = Hmac::sha256(key, message).unwrap();
+        let mac = Hmac::sha256(&key, message).unwrap();
         assert_eq!(mac.len(), 32); // SHA-256 output length
 
         // Verify HMAC
-        assert!(Hmac::verify_sha256(key, message, &mac).unwrap());
-        assert!(!Hmac::verify_sha256(b"wrong_key", message, &mac).unwrap());
+        assert!(Hmac::verify_sha256(&key, message, &mac).unwrap());
+        assert!(!Hmac::verify_sha256(&HmacKey::generate().unwrap(), message, &mac).unwrap());
     }
 
     #[test]
     fn test_hmac_sha512() {
-        let key = b"secret_key";
+        let key = HmacKey::from_bytes(b"secret_key_of_at_least_32_bytes!").unwrap();
         let message = b"Hello, World!";
 
-        let mac = Hmac::sha512(key, message).unwrap();
+        let mac = Hmac::sha512(&key, message).unwrap();
         assert_eq!(mac.len(), 64); // SHA-512 output length
 
         // Verify HMAC
-        assert!(Hmac::verify_sha512(key, message, &mac).unwrap());
-        assert!(!Hmac::verify_sha512(b"wrong_key", message, &mac).unwrap());
+        assert!(Hmac::verify_sha512(&key, message, &mac).unwrap());
+        assert!(!Hmac::verify_sha512(&HmacKey::generate().unwrap(), message, &mac).unwrap());
+    }
+
+
+
+    #[test]
+    fn test_streaming_hasher_matches_one_shot() {
+        let data = b"Hello, World! streamed in several chunks";
+        let key = HmacKey::generate().unwrap();
+        let cases = [
+            (StreamingHasher::sha256(), Sha256Hash::hash(data).unwrap()),
+            (StreamingHasher::sha512(), Sha512Hash::hash(data).unwrap()),
+            (StreamingHasher::blake3(), Blake3Hash::hash(data).unwrap()),
+            (StreamingHasher::hmac_sha256(key.as_bytes()).unwrap(), Hmac::sha256(&key, data).unwrap()),
+            (StreamingHasher::hmac_sha512(key.as_bytes()).unwrap(), Hmac::sha512(&key, data).unwrap()),
+        ];
+
+        for (mut hasher, expected) in cases {
+            for chunk in data.chunks(7) {
+                hasher.update(chunk);
+            }
+            let copy = hasher.clone();
+            assert_eq!(hasher.finalize(), expected);
+            assert_eq!(copy.finalize(), expected);
+        }
     }
 
+    #[test]
+    fn test_streaming_hasher_update_reader() {
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let expected = Blake3Hash::hash(&data).unwrap();
+
+        for chunk_size in [1, 4096, DEFAULT_HASH_CHUNK_SIZE, 1 << 20] {
+            let mut hasher = StreamingHasher::blake3();
+            assert_eq!(hasher.update_reader_with_chunk_size(&data[..], chunk_size).unwrap(), data.len() as u64);
+            assert_eq!(hasher.finalize(), expected);
+        }
+
+        let mut hasher = StreamingHasher::sha256();
+        assert_eq!(hasher.update_reader(&data[..]).unwrap(), data.len() as u64);
+        assert_eq!(hasher.finalize(), Sha256Hash::hash(&data).unwrap());
 
+        assert_eq!(
+            StreamingHasher::sha256().update_reader_with_chunk_size(&data[..], 0),
+            Err(CryptoError::InvalidInput(HASH_CHUNK_SIZE_ZERO))
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_blake3_hash_file_mmap() {
+        use crate::core::random::SecureRandom;
+
+        let path = std::env::temp_dir().join(format!("libsilver-hash-{}", SecureRandom::token_hex(8).unwrap()));
+        // Large enough that blake3 maps the file instead of reading it
+        let data = SecureRandom::generate_bytes(3 << 20).unwrap();
+        std::fs::write(&path, &data).unwrap();
+        let digest = Blake3Hash::hash_file_mmap(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(digest.unwrap(), Blake3Hash::hash(&data).unwrap());
+
+        assert_eq!(Blake3Hash::hash_file_mmap(&path).unwrap_err(), CryptoError::IoFailed(HASH_READ_FAILED));
+    }
 
     #[test]
     fn test_empty_data_hash() {
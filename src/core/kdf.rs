@@ -1,15 +1,60 @@
-use crate::error::{CryptoError, CryptoResult, ZERO_OUTPUT_LENGTH, ZERO_ITERATIONS, ARGON2_DERIVATION_FAILED, HKDF_SHA256_FAILED, HKDF_SHA512_FAILED, SALT_ENCODING_FAILED, ARGON2_HASHING_FAILED, INVALID_HASH_FORMAT};
+use crate::error::{CryptoError, CryptoResult, ZERO_OUTPUT_LENGTH, ZERO_ITERATIONS, HKDF_SHA256_FAILED, HKDF_SHA512_FAILED, SALT_ENCODING_FAILED, INVALID_HASH_FORMAT, INVALID_HMAC_KEY, KBKDF_OUTPUT_TOO_LONG, CONCAT_KDF_OUTPUT_TOO_LONG, X963_KDF_OUTPUT_TOO_LONG, PBKDF2_HASHING_FAILED, PBKDF2_PARAMS_TOO_LARGE, KDF_CANCELLED};
+#[cfg(feature = "argon2")]
+use crate::error::{ARGON2_DERIVATION_FAILED, ARGON2_HASHING_FAILED, ARGON2_INVALID_PARAMS};
+#[cfg(feature = "scrypt")]
+use crate::error::{SCRYPT_INVALID_PARAMS, SCRYPT_DERIVATION_FAILED, SCRYPT_HASHING_FAILED, SCRYPT_PARAMS_TOO_LARGE};
 use crate::core::random::SecureRandom;
-use argon2::{Argon2, PasswordHash, PasswordVerifier};
+use zeroize::Zeroizing;
+#[cfg(feature = "argon2")]
+use argon2::{Argon2, Block};
+#[cfg(feature = "argon2")]
+use zeroize::Zeroize;
+use pbkdf2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
 use hkdf::Hkdf;
+use hmac::{Hmac as HmacImpl, Mac};
+use hmac::digest::KeyInit;
 use pbkdf2::pbkdf2_hmac;
-use sha2::{Sha256, Sha512};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use alloc::{vec, vec::Vec, string::String, string::ToString};
+
+
+/// Argon2 algorithm variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg(feature = "argon2")]
+pub enum Argon2Variant {
+    /// Data-dependent memory access; fastest, but not side-channel resistant
+    Argon2d,
+    /// Data-independent memory access
+    Argon2i,
+    /// Hybrid of Argon2i and Argon2d (recommended)
+    #[default]
+    Argon2id,
+}
 
+#[cfg(feature = "argon2")]
+impl From<Argon2Variant> for argon2::Algorithm {
+    fn from(variant: Argon2Variant) -> Self {
+        match variant {
+            Argon2Variant::Argon2d => argon2::Algorithm::Argon2d,
+            Argon2Variant::Argon2i => argon2::Algorithm::Argon2i,
+            Argon2Variant::Argon2id => argon2::Algorithm::Argon2id,
+        }
+    }
+}
 
 /// Argon2 password hashing and verification
+#[cfg(feature = "argon2")]
 pub struct Argon2Kdf;
 
+#[cfg(feature = "argon2")]
 impl Argon2Kdf {
+    /// Default memory cost in KiB
+    pub const DEFAULT_MEMORY_KIB: u32 = argon2::Params::DEFAULT_M_COST;
+    /// Default number of iterations
+    pub const DEFAULT_ITERATIONS: u32 = argon2::Params::DEFAULT_T_COST;
+    /// Default degree of parallelism
+    pub const DEFAULT_PARALLELISM: u32 = argon2::Params::DEFAULT_P_COST;
+
     /// Hash a password using Argon2id with default parameters
     pub fn hash_password(password: &[u8]) -> CryptoResult<String> {
         let salt = SecureRandom::generate_salt()?;
@@ -18,8 +63,6 @@ impl Argon2Kdf {
 
     /// Hash a password using Argon2id with provided salt
     pub fn hash_password_with_salt(password: &[u8], salt: &[u8]) -> CryptoResult<String> {
-        use argon2::password_hash::{SaltString, PasswordHasher};
-
         let argon2 = Argon2::default();
         let salt_string = SaltString::encode_b64(salt)
             .map_err(|_| CryptoError::KeyDerivationFailed(SALT_ENCODING_FAILED))?;
@@ -57,6 +100,111 @@ impl Argon2Kdf {
 
         Ok(output)
     }
+
+    /// Derive a key from password using Argon2, zeroizing the output on drop
+    #[inline]
+    pub fn derive_key_zeroizing(password: &[u8], salt: &[u8], output_length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_key(password, salt, output_length).map(Zeroizing::new)
+    }
+
+    /// Derive a key from password using Argon2 with explicit cost parameters
+    ///
+    /// `memory_kib` is the memory cost in KiB, `iterations` the time cost and
+    /// `parallelism` the number of lanes. [`derive_key`](Self::derive_key)
+    /// uses Argon2id with the `DEFAULT_*` costs.
+    pub fn derive_key_with_params(password: &[u8], salt: &[u8], variant: Argon2Variant, memory_kib: u32, iterations: u32, parallelism: u32, output_length: usize) -> CryptoResult<Vec<u8>> {
+        if output_length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+
+        Argon2Context::new(variant, memory_kib, iterations, parallelism)?.derive_key(password, salt, output_length)
+    }
+
+    /// Derive a key from password using Argon2 with explicit cost parameters, zeroizing the output on drop
+    #[inline]
+    pub fn derive_key_with_params_zeroizing(password: &[u8], salt: &[u8], variant: Argon2Variant, memory_kib: u32, iterations: u32, parallelism: u32, output_length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_key_with_params(password, salt, variant, memory_kib, iterations, parallelism, output_length).map(Zeroizing::new)
+    }
+}
+
+/// Argon2 configured once for many derivations with the same costs
+///
+/// The parameters are validated when the context is built, and key
+/// derivation reuses the context's working memory (`memory_kib` KiB) instead
+/// of allocating it per call, which is why it takes `&mut self`. The memory
+/// is wiped after every derivation. Use one context per thread for parallel
+/// bulk jobs.
+#[cfg(feature = "argon2")]
+pub struct Argon2Context {
+    argon2: Argon2<'static>,
+    memory: Vec<Block>,
+}
+
+#[cfg(feature = "argon2")]
+impl Argon2Context {
+    /// Configure Argon2 with explicit costs; see [`Argon2Kdf::derive_key_with_params`]
+    pub fn new(variant: Argon2Variant, memory_kib: u32, iterations: u32, parallelism: u32) -> CryptoResult<Self> {
+        let params = argon2::Params::new(memory_kib, iterations, parallelism, None)
+            .map_err(|_| CryptoError::InvalidInput(ARGON2_INVALID_PARAMS))?;
+
+        Ok(Self {
+            argon2: Argon2::new(variant.into(), argon2::Version::V0x13, params),
+            memory: Vec::new(),
+        })
+    }
+
+    /// Derive a key from password, reusing the context's working memory
+    pub fn derive_key(&mut self, password: &[u8], salt: &[u8], output_length: usize) -> CryptoResult<Vec<u8>> {
+        if output_length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+
+        let block_count = self.argon2.params().block_count();
+        if self.memory.len() != block_count {
+            self.memory = vec![Block::default(); block_count];
+        }
+
+        let mut output = vec![0u8; output_length];
+        let result = self.argon2.hash_password_into_with_memory(password, salt, &mut output, &mut self.memory);
+        self.memory.iter_mut().for_each(Zeroize::zeroize);
+        result.map_err(|_| CryptoError::KeyDerivationFailed(ARGON2_DERIVATION_FAILED))?;
+
+        Ok(output)
+    }
+
+    /// Derive a key from password, zeroizing the output on drop
+    #[inline]
+    pub fn derive_key_zeroizing(&mut self, password: &[u8], salt: &[u8], output_length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.derive_key(password, salt, output_length).map(Zeroizing::new)
+    }
+
+    /// Hash a password with a random salt into a PHC string carrying the context's costs
+    pub fn hash_password(&self, password: &[u8]) -> CryptoResult<String> {
+        let salt = SecureRandom::generate_salt()?;
+        self.hash_password_with_salt(password, &salt)
+    }
+
+    /// Hash a password with provided salt into a PHC string carrying the context's costs
+    pub fn hash_password_with_salt(&self, password: &[u8], salt: &[u8]) -> CryptoResult<String> {
+        let salt_string = SaltString::encode_b64(salt)
+            .map_err(|_| CryptoError::KeyDerivationFailed(SALT_ENCODING_FAILED))?;
+
+        let password_hash = self.argon2.hash_password(password, &salt_string)
+            .map_err(|_| CryptoError::KeyDerivationFailed(ARGON2_HASHING_FAILED))?;
+
+        Ok(password_hash.to_string())
+    }
+}
+
+#[cfg(feature = "argon2")]
+impl Default for Argon2Context {
+    /// Argon2id with the `Argon2Kdf::DEFAULT_*` costs
+    fn default() -> Self {
+        Self {
+            argon2: Argon2::default(),
+            memory: Vec::new(),
+        }
+    }
 }
 
 /// HKDF (HMAC-based Key Derivation Function)
@@ -79,6 +227,12 @@ impl HkdfKdf {
         Ok(okm)
     }
 
+    /// Derive key using HKDF-SHA256, zeroizing the output on drop
+    #[inline]
+    pub fn derive_sha256_zeroizing(ikm: &[u8], salt: Option<&[u8]>, info: &[u8], length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_sha256(ikm, salt, info, length).map(Zeroizing::new)
+    }
+
     /// Derive key using HKDF-SHA512
     #[inline]
     pub fn derive_sha512(ikm: &[u8], salt: Option<&[u8]>, info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
@@ -94,12 +248,79 @@ impl HkdfKdf {
 
         Ok(okm)
     }
+
+    /// Derive key using HKDF-SHA512, zeroizing the output on drop
+    #[inline]
+    pub fn derive_sha512_zeroizing(ikm: &[u8], salt: Option<&[u8]>, info: &[u8], length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_sha512(ikm, salt, info, length).map(Zeroizing::new)
+    }
 }
 
+/// Default PBKDF2 iteration count for PHC password hashes (OWASP 2023 recommendation for HMAC-SHA256)
+pub const PBKDF2_DEFAULT_ITERATIONS: u32 = 600_000;
+
+/// Largest PBKDF2 iteration count accepted in a PHC string, so a stored hash cannot pin a CPU for minutes
+pub const PBKDF2_MAX_ITERATIONS: u32 = 10_000_000;
+
 /// PBKDF2 (Password-Based Key Derivation Function 2)
 pub struct Pbkdf2Kdf;
 
 impl Pbkdf2Kdf {
+    /// Hash a password using PBKDF2-HMAC-SHA256 with default iterations, returning a PHC string
+    pub fn hash_password(password: &[u8]) -> CryptoResult<String> {
+        let salt = SecureRandom::generate_salt()?;
+        Self::hash_password_with_params(password, &salt, PBKDF2_DEFAULT_ITERATIONS)
+    }
+
+    /// Hash a password using PBKDF2-HMAC-SHA256 with provided salt and iterations
+    ///
+    /// Output format: `$pbkdf2-sha256$i=<iterations>,l=32$<salt>$<hash>`
+    pub fn hash_password_with_params(password: &[u8], salt: &[u8], iterations: u32) -> CryptoResult<String> {
+        use pbkdf2::{Algorithm, Params, Pbkdf2};
+
+        if iterations == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_ITERATIONS));
+        }
+        if iterations > PBKDF2_MAX_ITERATIONS {
+            return Err(CryptoError::InvalidInput(PBKDF2_PARAMS_TOO_LARGE));
+        }
+
+        let salt_string = SaltString::encode_b64(salt)
+            .map_err(|_| CryptoError::KeyDerivationFailed(SALT_ENCODING_FAILED))?;
+
+        let params = Params { rounds: iterations, output_length: 32 };
+        let password_hash = Pbkdf2
+            .hash_password_customized(password, Some(Algorithm::Pbkdf2Sha256.ident()), None, params, &salt_string)
+            .map_err(|_| CryptoError::KeyDerivationFailed(PBKDF2_HASHING_FAILED))?;
+
+        Ok(password_hash.to_string())
+    }
+
+    /// Verify a password against a PBKDF2 PHC string (`pbkdf2-sha256` or `pbkdf2-sha512`)
+    ///
+    /// Hashes with more than [`PBKDF2_MAX_ITERATIONS`] iterations are
+    /// rejected before any work is done.
+    pub fn verify_password(password: &[u8], hash: &str) -> CryptoResult<bool> {
+        use pbkdf2::{Algorithm, Pbkdf2};
+
+        let parsed_hash = PasswordHash::new(hash)
+            .map_err(|_| CryptoError::InvalidInput(INVALID_HASH_FORMAT))?;
+
+        if parsed_hash.algorithm != Algorithm::Pbkdf2Sha256.ident()
+            && parsed_hash.algorithm != Algorithm::Pbkdf2Sha512.ident()
+        {
+            return Err(CryptoError::InvalidInput(INVALID_HASH_FORMAT));
+        }
+        if parsed_hash.params.get_decimal("i").is_some_and(|iterations| iterations > PBKDF2_MAX_ITERATIONS) {
+            return Err(CryptoError::InvalidInput(PBKDF2_PARAMS_TOO_LARGE));
+        }
+
+        match Pbkdf2.verify_password(password, &parsed_hash) {
+            Ok(()) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
     /// Derive key using PBKDF2-HMAC-SHA256
     #[inline]
     pub fn derive_sha256(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> CryptoResult<Vec<u8>> {
@@ -117,6 +338,12 @@ impl Pbkdf2Kdf {
         Ok(output)
     }
 
+    /// Derive key using PBKDF2-HMAC-SHA256, zeroizing the output on drop
+    #[inline]
+    pub fn derive_sha256_zeroizing(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_sha256(password, salt, iterations, length).map(Zeroizing::new)
+    }
+
     /// Derive key using PBKDF2-HMAC-SHA512
     #[inline]
     pub fn derive_sha512(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> CryptoResult<Vec<u8>> {
@@ -133,15 +360,473 @@ impl Pbkdf2Kdf {
 
         Ok(output)
     }
+
+    /// Derive key using PBKDF2-HMAC-SHA512, zeroizing the output on drop
+    #[inline]
+    pub fn derive_sha512_zeroizing(password: &[u8], salt: &[u8], iterations: u32, length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_sha512(password, salt, iterations, length).map(Zeroizing::new)
+    }
+
+    /// Derive key using PBKDF2-HMAC-SHA256 in batches, reporting progress in [0, 1]
+    ///
+    /// Returning `false` from `progress` cancels the derivation.
+    #[inline]
+    pub fn derive_sha256_with_progress(password: &[u8], salt: &[u8], iterations: u32, length: usize, progress: impl FnMut(f64) -> bool) -> CryptoResult<Vec<u8>> {
+        pbkdf2_with_progress::<HmacImpl<Sha256>>(password, salt, iterations, length, progress)
+    }
+
+    /// Derive key using PBKDF2-HMAC-SHA512 in batches, reporting progress in [0, 1]
+    ///
+    /// Returning `false` from `progress` cancels the derivation.
+    #[inline]
+    pub fn derive_sha512_with_progress(password: &[u8], salt: &[u8], iterations: u32, length: usize, progress: impl FnMut(f64) -> bool) -> CryptoResult<Vec<u8>> {
+        pbkdf2_with_progress::<HmacImpl<Sha512>>(password, salt, iterations, length, progress)
+    }
 }
 
+/// scrypt password hashing and key derivation
+#[cfg(feature = "scrypt")]
+pub struct ScryptKdf;
+
+#[cfg(feature = "scrypt")]
+impl ScryptKdf {
+    /// Default CPU/memory cost exponent (N = 2^17)
+    pub const DEFAULT_LOG_N: u8 = scrypt::Params::RECOMMENDED_LOG_N;
+    /// Default block size
+    pub const DEFAULT_R: u32 = scrypt::Params::RECOMMENDED_R;
+    /// Default parallelism
+    pub const DEFAULT_P: u32 = scrypt::Params::RECOMMENDED_P;
+    /// Largest memory cost (128 * r * 2^log_n bytes) accepted in a PHC string, 1 GiB
+    pub const MAX_MEMORY_BYTES: u64 = 1 << 30;
+    /// Largest parallelism accepted in a PHC string
+    pub const MAX_P: u32 = 16;
+
+    /// Hash a password using scrypt with recommended parameters, returning a PHC string
+    pub fn hash_password(password: &[u8]) -> CryptoResult<String> {
+        let salt = SecureRandom::generate_salt()?;
+        Self::hash_password_with_params(
+            password,
+            &salt,
+            scrypt::Params::RECOMMENDED_LOG_N,
+            scrypt::Params::RECOMMENDED_R,
+            scrypt::Params::RECOMMENDED_P,
+        )
+    }
+
+    /// Hash a password using scrypt with provided salt and cost parameters
+    ///
+    /// Output format: `$scrypt$ln=<log_n>,r=<r>,p=<p>$<salt>$<hash>`
+    pub fn hash_password_with_params(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32) -> CryptoResult<String> {
+        Self::check_max_params(log_n, r, p)?;
+        let params = scrypt::Params::new(log_n, r, p, scrypt::Params::RECOMMENDED_LEN)
+            .map_err(|_| CryptoError::InvalidInput(SCRYPT_INVALID_PARAMS))?;
 
+        let salt_string = SaltString::encode_b64(salt)
+            .map_err(|_| CryptoError::KeyDerivationFailed(SALT_ENCODING_FAILED))?;
+
+        let password_hash = scrypt::Scrypt
+            .hash_password_customized(password, None, None, params, &salt_string)
+            .map_err(|_| CryptoError::KeyDerivationFailed(SCRYPT_HASHING_FAILED))?;
+
+        Ok(password_hash.to_string())
+    }
+
+    /// Verify a password against a scrypt PHC string
+    ///
+    /// Hashes above [`Self::MAX_MEMORY_BYTES`] or [`Self::MAX_P`] are
+    /// rejected before any work is done.
+    pub fn verify_password(password: &[u8], hash: &str) -> CryptoResult<bool> {
+        let parsed_hash = PasswordHash::new(hash)
+            .map_err(|_| CryptoError::InvalidInput(INVALID_HASH_FORMAT))?;
+
+        if parsed_hash.algorithm != scrypt::ALG_ID {
+            return Err(CryptoError::InvalidInput(INVALID_HASH_FORMAT));
+        }
+        // Missing parameters take the defaults, as in the scrypt crate
+        let param = |name: &str, default: u32| parsed_hash.params.get_decimal(name).unwrap_or(default);
+        let log_n = u8::try_from(param("ln", u32::from(Self::DEFAULT_LOG_N)))
+            .map_err(|_| CryptoError::InvalidInput(SCRYPT_PARAMS_TOO_LARGE))?;
+        Self::check_max_params(log_n, param("r", Self::DEFAULT_R), param("p", Self::DEFAULT_P))?;
+
+        match scrypt::Scrypt.verify_password(password, &parsed_hash) {
+            Ok(()) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+
+    fn check_max_params(log_n: u8, r: u32, p: u32) -> CryptoResult<()> {
+        // 128 * r < 2^39, so the shift cannot overflow a u128 below log_n = 64
+        if log_n >= 64 || (128 * u128::from(r)) << log_n > u128::from(Self::MAX_MEMORY_BYTES) || p > Self::MAX_P {
+            return Err(CryptoError::InvalidInput(SCRYPT_PARAMS_TOO_LARGE));
+        }
+        Ok(())
+    }
+
+    /// Derive a key from password using scrypt with explicit cost parameters
+    pub fn derive_key(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32, output_length: usize) -> CryptoResult<Vec<u8>> {
+        if output_length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+
+        let params = scrypt::Params::new(log_n, r, p, output_length)
+            .map_err(|_| CryptoError::InvalidInput(SCRYPT_INVALID_PARAMS))?;
+
+        let mut output = vec![0u8; output_length];
+        scrypt::scrypt(password, salt, &params, &mut output)
+            .map_err(|_| CryptoError::KeyDerivationFailed(SCRYPT_DERIVATION_FAILED))?;
+
+        Ok(output)
+    }
+
+    /// Derive a key from password using scrypt, zeroizing the output on drop
+    #[inline]
+    pub fn derive_key_zeroizing(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32, output_length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_key(password, salt, log_n, r, p, output_length).map(Zeroizing::new)
+    }
+
+    /// Derive a key using scrypt in batches, reporting progress in [0, 1]
+    ///
+    /// Produces the same output as [`ScryptKdf::derive_key`]; returning `false`
+    /// from `progress` cancels the derivation.
+    pub fn derive_key_with_progress(password: &[u8], salt: &[u8], log_n: u8, r: u32, p: u32, output_length: usize, progress: impl FnMut(f64) -> bool) -> CryptoResult<Vec<u8>> {
+        if output_length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+        scrypt::Params::new(log_n, r, p, output_length)
+            .map_err(|_| CryptoError::InvalidInput(SCRYPT_INVALID_PARAMS))?;
+
+        let n = 1usize << log_n;
+        let block_len = 128 * r as usize;
+        let mut progress = KdfProgress::new(progress, 2 * n as u64 * p as u64);
+
+        let mut b = Zeroizing::new(vec![0u8; block_len * p as usize]);
+        pbkdf2_hmac::<Sha256>(password, salt, 1, &mut b);
+
+        let mut v = Zeroizing::new(vec![0u8; block_len * n]);
+        let mut t = Zeroizing::new(vec![0u8; block_len]);
+        for chunk in b.chunks_mut(block_len) {
+            scrypt_ro_mix(chunk, &mut v, &mut t, n, &mut progress)?;
+        }
+
+        let mut output = vec![0u8; output_length];
+        pbkdf2_hmac::<Sha256>(password, &b, 1, &mut output);
+        progress.finish();
+        Ok(output)
+    }
+}
+
+/// Steps between progress reports in the batched KDFs (a power of two)
+const KDF_PROGRESS_BATCH: u64 = 1024;
+
+/// Batches progress reports from a long-running KDF and turns `false` into cancellation
+struct KdfProgress<F> {
+    callback: F,
+    done: u64,
+    total: u64,
+}
+
+impl<F: FnMut(f64) -> bool> KdfProgress<F> {
+    fn new(callback: F, total: u64) -> Self {
+        Self { callback, done: 0, total: total.max(1) }
+    }
+
+    #[inline]
+    fn step(&mut self) -> CryptoResult<()> {
+        self.done += 1;
+        if self.done & (KDF_PROGRESS_BATCH - 1) == 0 && !(self.callback)(self.done as f64 / self.total as f64) {
+            return Err(CryptoError::KeyDerivationFailed(KDF_CANCELLED));
+        }
+        Ok(())
+    }
+
+    fn finish(&mut self) {
+        (self.callback)(1.0);
+    }
+}
+
+/// PBKDF2 (RFC 8018) over an HMAC, checking for cancellation between batches of iterations
+fn pbkdf2_with_progress<M: Mac + KeyInit + Clone>(password: &[u8], salt: &[u8], iterations: u32, length: usize, progress: impl FnMut(f64) -> bool) -> CryptoResult<Vec<u8>> {
+    if length == 0 {
+        return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+    }
+
+    if iterations == 0 {
+        return Err(CryptoError::InvalidInput(ZERO_ITERATIONS));
+    }
+
+    let prf = <M as Mac>::new_from_slice(password)
+        .map_err(|_| CryptoError::InvalidKey(INVALID_HMAC_KEY))?;
+    let block_len = <M as hmac::digest::OutputSizeUser>::output_size();
+    let blocks = (length - 1) / block_len + 1;
+    let mut progress = KdfProgress::new(progress, blocks as u64 * iterations as u64);
+
+    let mut output = vec![0u8; length];
+    for (index, chunk) in output.chunks_mut(block_len).enumerate() {
+        let mut mac = prf.clone();
+        mac.update(salt);
+        mac.update(&(index as u32 + 1).to_be_bytes());
+        let mut u = Zeroizing::new(mac.finalize().into_bytes().to_vec());
+        let mut block = u.clone();
+        progress.step()?;
+
+        for _ in 1..iterations {
+            let mut mac = prf.clone();
+            mac.update(&u);
+            u.copy_from_slice(&mac.finalize().into_bytes());
+            block.iter_mut().zip(u.iter()).for_each(|(b, u)| *b ^= u);
+            progress.step()?;
+        }
+        chunk.copy_from_slice(&block[..chunk.len()]);
+    }
+
+    progress.finish();
+    Ok(output)
+}
+
+/// scrypt ROMix over one `128 * r` byte block, reporting each BlockMix step
+#[cfg(feature = "scrypt")]
+fn scrypt_ro_mix<F: FnMut(f64) -> bool>(b: &mut [u8], v: &mut [u8], t: &mut [u8], n: usize, progress: &mut KdfProgress<F>) -> CryptoResult<()> {
+    let len = b.len();
+
+    for chunk in v.chunks_mut(len) {
+        chunk.copy_from_slice(b);
+        scrypt_block_mix(chunk, b);
+        progress.step()?;
+    }
+
+    for _ in 0..n {
+        // Integerify: N is a power of two, so the low bits of the last 64-byte block select V[j]
+        let j = u32::from_le_bytes([b[len - 64], b[len - 63], b[len - 62], b[len - 61]]) as usize & (n - 1);
+        t.iter_mut()
+            .zip(b.iter().zip(&v[j * len..(j + 1) * len]))
+            .for_each(|(t, (b, v))| *t = b ^ v);
+        scrypt_block_mix(t, b);
+        progress.step()?;
+    }
+
+    Ok(())
+}
+
+/// scrypt BlockMix with Salsa20/8
+#[cfg(feature = "scrypt")]
+fn scrypt_block_mix(input: &[u8], output: &mut [u8]) {
+    use salsa20::cipher::{typenum::U4, StreamCipherCore};
+    use salsa20::SalsaCore;
+
+    let mut x = [0u8; 64];
+    x.copy_from_slice(&input[input.len() - 64..]);
+
+    for (i, chunk) in input.chunks(64).enumerate() {
+        let mut state = [0u32; 16];
+        for (word, (x, c)) in state.iter_mut().zip(x.chunks_exact(4).zip(chunk.chunks_exact(4))) {
+            *word = u32::from_le_bytes([x[0] ^ c[0], x[1] ^ c[1], x[2] ^ c[2], x[3] ^ c[3]]);
+        }
+        SalsaCore::<U4>::from_raw_state(state).write_keystream_block((&mut x).into());
+
+        // Even blocks fill the first half of the output and odd blocks the second
+        let position = (i / 2) * 64 + if i % 2 == 0 { 0 } else { input.len() / 2 };
+        output[position..position + 64].copy_from_slice(&x);
+    }
+}
+
+/// SP 800-108 KBKDF (Key-Based Key Derivation Function) in counter mode
+///
+/// Each block is `PRF(key, [i]_32 || label || 0x00 || context || [L]_32)`, with a
+/// 32-bit big-endian counter starting at 1 and `L` the output length in bits.
+pub struct KbkdfKdf;
+
+impl KbkdfKdf {
+    /// Derive key using KBKDF counter mode with HMAC-SHA256
+    #[inline]
+    pub fn derive_counter(key: &[u8], label: &[u8], context: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_counter_sha256(key, label, context, length)
+    }
+
+    /// Derive key using KBKDF counter mode with HMAC-SHA256
+    #[inline]
+    pub fn derive_counter_sha256(key: &[u8], label: &[u8], context: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_counter_with::<HmacImpl<Sha256>>(key, label, context, length)
+    }
+
+    /// Derive key using KBKDF counter mode with HMAC-SHA512
+    #[inline]
+    pub fn derive_counter_sha512(key: &[u8], label: &[u8], context: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_counter_with::<HmacImpl<Sha512>>(key, label, context, length)
+    }
+
+    /// Derive key using KBKDF counter mode with HMAC-SHA256, zeroizing the output on drop
+    #[inline]
+    pub fn derive_counter_zeroizing(key: &[u8], label: &[u8], context: &[u8], length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive_counter(key, label, context, length).map(Zeroizing::new)
+    }
+
+    fn derive_counter_with<M: Mac + hmac::digest::KeyInit + Clone>(key: &[u8], label: &[u8], context: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        if length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+
+        // [L]_32 is the output length in bits and must fit in 32 bits
+        let length_bits = length
+            .checked_mul(8)
+            .and_then(|bits| u32::try_from(bits).ok())
+            .ok_or(CryptoError::InvalidInput(KBKDF_OUTPUT_TOO_LONG))?;
+
+        let prf = <M as Mac>::new_from_slice(key)
+            .map_err(|_| CryptoError::InvalidKey(INVALID_HMAC_KEY))?;
+
+        let mut output = Vec::with_capacity(length);
+        let mut counter: u32 = 1;
+
+        while output.len() < length {
+            let mut mac = prf.clone();
+            mac.update(&counter.to_be_bytes());
+            mac.update(label);
+            mac.update(&[0x00]);
+            mac.update(context);
+            mac.update(&length_bits.to_be_bytes());
+
+            let block = mac.finalize().into_bytes();
+            let take = (length - output.len()).min(block.len());
+            output.extend_from_slice(&block[..take]);
+
+            counter = counter
+                .checked_add(1)
+                .ok_or(CryptoError::InvalidInput(KBKDF_OUTPUT_TOO_LONG))?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// NIST SP 800-56C one-step key derivation (Concatenation KDF)
+///
+/// Each block is `H([i]_32 || Z || OtherInfo)`, with a 32-bit big-endian counter
+/// starting at 1. This is the KDF used by JOSE ECDH-ES.
+pub struct ConcatKdf;
+
+impl ConcatKdf {
+    /// Derive key from a shared secret using Concat KDF with SHA-256
+    #[inline]
+    pub fn derive(shared_secret: &[u8], other_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_sha256(shared_secret, other_info, length)
+    }
+
+    /// Derive key from a shared secret using Concat KDF with SHA-256
+    #[inline]
+    pub fn derive_sha256(shared_secret: &[u8], other_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_with::<Sha256>(shared_secret, other_info, length)
+    }
+
+    /// Derive key from a shared secret using Concat KDF with SHA-384
+    #[inline]
+    pub fn derive_sha384(shared_secret: &[u8], other_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_with::<Sha384>(shared_secret, other_info, length)
+    }
+
+    /// Derive key from a shared secret using Concat KDF with SHA-256, zeroizing the output on drop
+    #[inline]
+    pub fn derive_zeroizing(shared_secret: &[u8], other_info: &[u8], length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive(shared_secret, other_info, length).map(Zeroizing::new)
+    }
+
+    fn derive_with<D: Digest>(shared_secret: &[u8], other_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        if length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+
+        let mut output = Vec::with_capacity(length);
+        let mut counter: u32 = 1;
+
+        while output.len() < length {
+            let mut hasher = D::new();
+            hasher.update(counter.to_be_bytes());
+            hasher.update(shared_secret);
+            hasher.update(other_info);
+
+            let block = hasher.finalize();
+            let take = (length - output.len()).min(block.len());
+            output.extend_from_slice(&block[..take]);
+
+            counter = counter
+                .checked_add(1)
+                .ok_or(CryptoError::InvalidInput(CONCAT_KDF_OUTPUT_TOO_LONG))?;
+        }
+
+        Ok(output)
+    }
+}
+
+/// ANSI X9.63 key derivation function
+///
+/// Each block is `H(Z || [i]_32 || SharedInfo)`, with a 32-bit big-endian counter
+/// starting at 1. This matches the KDF used by Apple's `SecKeyCreateEncryptedData`
+/// ECIES algorithms (e.g. `eciesEncryptionStandardX963SHA256AESGCM`).
+pub struct X963Kdf;
+
+impl X963Kdf {
+    /// Derive key from a shared secret using X9.63 KDF with SHA-256
+    #[inline]
+    pub fn derive(shared_secret: &[u8], shared_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_sha256(shared_secret, shared_info, length)
+    }
+
+    /// Derive key from a shared secret using X9.63 KDF with SHA-256
+    #[inline]
+    pub fn derive_sha256(shared_secret: &[u8], shared_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_with::<Sha256>(shared_secret, shared_info, length)
+    }
+
+    /// Derive key from a shared secret using X9.63 KDF with SHA-384
+    #[inline]
+    pub fn derive_sha384(shared_secret: &[u8], shared_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_with::<Sha384>(shared_secret, shared_info, length)
+    }
+
+    /// Derive key from a shared secret using X9.63 KDF with SHA-512
+    #[inline]
+    pub fn derive_sha512(shared_secret: &[u8], shared_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        Self::derive_with::<Sha512>(shared_secret, shared_info, length)
+    }
+
+    /// Derive key from a shared secret using X9.63 KDF with SHA-256, zeroizing the output on drop
+    #[inline]
+    pub fn derive_zeroizing(shared_secret: &[u8], shared_info: &[u8], length: usize) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::derive(shared_secret, shared_info, length).map(Zeroizing::new)
+    }
+
+    fn derive_with<D: Digest>(shared_secret: &[u8], shared_info: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
+        if length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        }
+
+        let mut output = Vec::with_capacity(length);
+        let mut counter: u32 = 1;
+
+        while output.len() < length {
+            let mut hasher = D::new();
+            hasher.update(shared_secret);
+            hasher.update(counter.to_be_bytes());
+            hasher.update(shared_info);
+
+            let block = hasher.finalize();
+            let take = (length - output.len()).min(block.len());
+            output.extend_from_slice(&block[..take]);
+
+            counter = counter
+                .checked_add(1)
+                .ok_or(CryptoError::InvalidInput(X963_KDF_OUTPUT_TOO_LONG))?;
+        }
+
+        Ok(output)
+    }
+}
 
 /// Secure key derivation with automatic salt generation
 pub struct SecureKeyDerivation;
 
 impl SecureKeyDerivation {
     /// Derive a key using Argon2 with random salt
+    #[cfg(feature = "argon2")]
     #[inline]
     pub fn derive_argon2(password: &[u8], output_length: usize) -> CryptoResult<(Vec<u8>, Vec<u8>)> {
         let salt = SecureRandom::generate_salt()?;
@@ -167,7 +852,7 @@ impl SecureKeyDerivation {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "argon2", feature = "scrypt"))]
 mod tests {
     use super::*;
 
@@ -202,6 +887,92 @@ mod tests {
         assert_ne!(key, key3);
     }
 
+    #[test]
+    fn test_derive_zeroizing_matches_plain() {
+        let salt = b"some_salt_16byte";
+        assert_eq!(
+            Argon2Kdf::derive_key_zeroizing(b"pw", salt, 32).unwrap().as_slice(),
+            Argon2Kdf::derive_key(b"pw", salt, 32).unwrap().as_slice()
+        );
+        assert_eq!(
+            HkdfKdf::derive_sha256_zeroizing(b"ikm", Some(salt), b"info", 32).unwrap().as_slice(),
+            HkdfKdf::derive_sha256(b"ikm", Some(salt), b"info", 32).unwrap().as_slice()
+        );
+        assert_eq!(
+            Pbkdf2Kdf::derive_sha512_zeroizing(b"pw", salt, 1000, 64).unwrap().as_slice(),
+            Pbkdf2Kdf::derive_sha512(b"pw", salt, 1000, 64).unwrap().as_slice()
+        );
+        assert!(ScryptKdf::derive_key_zeroizing(b"pw", salt, 10, 8, 1, 0).is_err());
+        assert_eq!(
+            KbkdfKdf::derive_counter_zeroizing(b"key", b"label", b"ctx", 48).unwrap().as_slice(),
+            KbkdfKdf::derive_counter(b"key", b"label", b"ctx", 48).unwrap().as_slice()
+        );
+        assert_eq!(
+            ConcatKdf::derive_zeroizing(b"z", b"info", 40).unwrap().as_slice(),
+            ConcatKdf::derive(b"z", b"info", 40).unwrap().as_slice()
+        );
+        assert_eq!(
+            X963Kdf::derive_zeroizing(b"z", b"info", 40).unwrap().as_slice(),
+            X963Kdf::derive(b"z", b"info", 40).unwrap().as_slice()
+        );
+    }
+
+    #[test]
+    fn test_argon2_derive_key_with_params() {
+        let password = b"correct horse battery staple";
+        let salt = b"0123456789abcdef";
+
+        // libsodium crypto_pwhash (opslimit 3, memlimit 4 MiB)
+        let argon2id = Argon2Kdf::derive_key_with_params(password, salt, Argon2Variant::Argon2id, 4096, 3, 1, 32).unwrap();
+        assert_eq!(hex::encode(argon2id), "2f8186f7b07132513ebb79a01833cb875faee5432f2c21d0093c70202b339837");
+        let argon2i = Argon2Kdf::derive_key_with_params(password, salt, Argon2Variant::Argon2i, 4096, 3, 1, 32).unwrap();
+        assert_eq!(hex::encode(argon2i), "118d7623bb86ca8047cee42e156b7717f2afbbdf5f241636a98b33d8a652ae3f");
+
+        // The defaults match derive_key
+        assert_eq!(
+            Argon2Kdf::derive_key_with_params(password, salt, Argon2Variant::default(), Argon2Kdf::DEFAULT_MEMORY_KIB, Argon2Kdf::DEFAULT_ITERATIONS, Argon2Kdf::DEFAULT_PARALLELISM, 32).unwrap(),
+            Argon2Kdf::derive_key(password, salt, 32).unwrap()
+        );
+
+        let argon2d = Argon2Kdf::derive_key_with_params_zeroizing(password, salt, Argon2Variant::Argon2d, 64, 1, 2, 16).unwrap();
+        assert_eq!(argon2d.len(), 16);
+
+        // Invalid parameters: no iterations, memory below 8 KiB per lane, zero output
+        assert_eq!(
+            Argon2Kdf::derive_key_with_params(password, salt, Argon2Variant::Argon2id, 4096, 0, 1, 32).unwrap_err(),
+            CryptoError::InvalidInput(ARGON2_INVALID_PARAMS)
+        );
+        assert!(Argon2Kdf::derive_key_with_params(password, salt, Argon2Variant::Argon2id, 8, 1, 4, 32).is_err());
+        assert!(Argon2Kdf::derive_key_with_params(password, salt, Argon2Variant::Argon2id, 4096, 1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_argon2_context() {
+        let password = b"correct horse battery staple";
+        let salt = b"0123456789abcdef";
+
+        // Same libsodium vector, derived repeatedly through one context
+        let mut context = Argon2Context::new(Argon2Variant::Argon2id, 4096, 3, 1).unwrap();
+        for _ in 0..3 {
+            assert_eq!(hex::encode(context.derive_key(password, salt, 32).unwrap()), "2f8186f7b07132513ebb79a01833cb875faee5432f2c21d0093c70202b339837");
+        }
+        assert_eq!(context.derive_key_zeroizing(b"other", salt, 16).unwrap().len(), 16);
+        assert!(context.memory.iter().all(|block| block.as_ref().iter().all(|&word| word == 0)));
+        assert_eq!(context.derive_key(password, salt, 0).unwrap_err(), CryptoError::InvalidInput(ZERO_OUTPUT_LENGTH));
+        assert!(context.derive_key(password, b"short", 32).is_err());
+
+        let hash = context.hash_password(password).unwrap();
+        assert!(hash.starts_with("$argon2id$v=19$m=4096,t=3,p=1$"));
+        assert!(Argon2Kdf::verify_password(password, &hash).unwrap());
+        assert_eq!(context.hash_password_with_salt(password, salt).unwrap(), context.hash_password_with_salt(password, salt).unwrap());
+
+        assert_eq!(
+            Argon2Context::default().derive_key(password, salt, 32).unwrap(),
+            Argon2Kdf::derive_key(password, salt, 32).unwrap()
+        );
+        assert_eq!(Argon2Context::new(Argon2Variant::Argon2i, 4096, 0, 1).err(), Some(CryptoError::InvalidInput(ARGON2_INVALID_PARAMS)));
+    }
+
     #[test]
     fn test_argon2_zero_length() {
         let password = b"test_password";
@@ -281,6 +1052,28 @@ mod tests {
         assert_eq!(key.len(), length);
     }
 
+    #[test]
+    fn test_pbkdf2_with_progress() {
+        let password = b"password";
+        let salt = b"salt";
+
+        let mut reports = 0;
+        let key = Pbkdf2Kdf::derive_sha256_with_progress(password, salt, 5000, 80, |_| {
+            reports += 1;
+            true
+        }).unwrap();
+        assert_eq!(key, Pbkdf2Kdf::derive_sha256(password, salt, 5000, 80).unwrap());
+        assert_eq!(reports, 15000 / 1024 + 1);
+
+        let key = Pbkdf2Kdf::derive_sha512_with_progress(password, salt, 3000, 100, |_| true).unwrap();
+        assert_eq!(key, Pbkdf2Kdf::derive_sha512(password, salt, 3000, 100).unwrap());
+
+        let cancelled = Pbkdf2Kdf::derive_sha256_with_progress(password, salt, 100_000, 32, |fraction| fraction < 0.05);
+        assert!(matches!(cancelled, Err(CryptoError::KeyDerivationFailed(KDF_CANCELLED))));
+        assert!(Pbkdf2Kdf::derive_sha256_with_progress(password, salt, 0, 32, |_| true).is_err());
+        assert!(Pbkdf2Kdf::derive_sha256_with_progress(password, salt, 1, 0, |_| true).is_err());
+    }
+
     #[test]
     fn test_pbkdf2_zero_iterations() {
         let password = b"test_password";
@@ -291,6 +1084,182 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_pbkdf2_hash_password() {
+        let password = b"test_password";
+        let salt = b"test_salt_16byte";
+
+        let hash = Pbkdf2Kdf::hash_password_with_params(password, salt, 1000).unwrap();
+        assert!(hash.starts_with("$pbkdf2-sha256$i=1000,l=32$"));
+
+        assert!(Pbkdf2Kdf::verify_password(password, &hash).unwrap());
+        assert!(!Pbkdf2Kdf::verify_password(b"wrong_password", &hash).unwrap());
+
+        // Stored hash must match the raw derivation with the same parameters
+        let raw = Pbkdf2Kdf::derive_sha256(password, salt, 1000, 32).unwrap();
+        let parsed = PasswordHash::new(&hash).unwrap();
+        assert_eq!(parsed.hash.unwrap().as_bytes(), &raw[..]);
+    }
+
+    #[test]
+    fn test_pbkdf2_verify_rejects_other_algorithms() {
+        let argon2_hash = Argon2Kdf::hash_password(b"test_password").unwrap();
+        assert!(Pbkdf2Kdf::verify_password(b"test_password", &argon2_hash).is_err());
+        assert!(Pbkdf2Kdf::verify_password(b"test_password", "not a phc string").is_err());
+    }
+
+    #[test]
+    fn test_scrypt_hash_password() {
+        let password = b"test_password";
+        let salt = b"test_salt_16byte";
+
+        let hash = ScryptKdf::hash_password_with_params(password, salt, 10, 8, 1).unwrap();
+        assert!(hash.starts_with("$scrypt$ln=10,r=8,p=1$"));
+
+        assert!(ScryptKdf::verify_password(password, &hash).unwrap());
+        assert!(!ScryptKdf::verify_password(b"wrong_password", &hash).unwrap());
+        assert!(ScryptKdf::verify_password(password, "$pbkdf2-sha256$i=1000,l=32$c2FsdA$aGFzaA").is_err());
+    }
+
+    #[test]
+    fn test_password_hash_params_are_bounded() {
+        // Inflated costs are refused before deriving anything
+        let too_large = CryptoError::InvalidInput(PBKDF2_PARAMS_TOO_LARGE);
+        assert_eq!(Pbkdf2Kdf::verify_password(b"pw", "$pbkdf2-sha256$i=4294967295,l=32$c2FsdHNhbHQ$AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA").unwrap_err(), too_large);
+        assert_eq!(Pbkdf2Kdf::hash_password_with_params(b"pw", b"saltsalt", PBKDF2_MAX_ITERATIONS + 1).unwrap_err(), too_large);
+
+        let too_large = CryptoError::InvalidInput(SCRYPT_PARAMS_TOO_LARGE);
+        for params in ["ln=31,r=8,p=1", "ln=20,r=16,p=1", "ln=10,r=8,p=17", "ln=300,r=8,p=1", "ln=63,r=4294967295,p=1"] {
+            let hash = format!("$scrypt${params}$c2FsdHNhbHQ$AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA");
+            assert_eq!(ScryptKdf::verify_password(b"pw", &hash).unwrap_err(), too_large, "{params}");
+        }
+        assert_eq!(ScryptKdf::hash_password_with_params(b"pw", b"saltsalt", 21, 8, 1).unwrap_err(), too_large);
+
+        // The defaults and the maximum memory cost stay accepted
+        assert!(ScryptKdf::check_max_params(ScryptKdf::DEFAULT_LOG_N, ScryptKdf::DEFAULT_R, ScryptKdf::DEFAULT_P).is_ok());
+        assert!(ScryptKdf::check_max_params(20, 8, ScryptKdf::MAX_P).is_ok());
+        let hash = ScryptKdf::hash_password_with_params(b"pw", b"saltsalt", 4, 1, 1).unwrap();
+        assert!(ScryptKdf::verify_password(b"pw", &hash).unwrap());
+    }
+
+    #[test]
+    fn test_scrypt_derive_key() {
+        // RFC 7914 test vector (N = 16, r = 1, p = 1, empty password and salt)
+        let key = ScryptKdf::derive_key(b"", b"", 4, 1, 1, 64).unwrap();
+        let expected = hex::decode("77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906").unwrap();
+        assert_eq!(key, expected);
+
+        assert!(ScryptKdf::derive_key(b"password", b"salt", 4, 1, 1, 0).is_err());
+    }
+
+    #[test]
+    fn test_scrypt_derive_key_with_progress() {
+        // RFC 7914 test vector (N = 1024, r = 8, p = 16)
+        let mut reports = Vec::new();
+        let key = ScryptKdf::derive_key_with_progress(b"password", b"NaCl", 10, 8, 16, 64, |fraction| {
+            reports.push(fraction);
+            true
+        }).unwrap();
+        let expected = hex::decode("fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640").unwrap();
+        assert_eq!(key, expected);
+        assert_eq!(reports.len(), 33);
+        assert!(reports.windows(2).all(|w| w[0] <= w[1]));
+        assert_eq!(reports.last(), Some(&1.0));
+
+        assert_eq!(ScryptKdf::derive_key_with_progress(b"", b"", 4, 1, 1, 64, |_| true).unwrap(), ScryptKdf::derive_key(b"", b"", 4, 1, 1, 64).unwrap());
+
+        let mut calls = 0;
+        let cancelled = ScryptKdf::derive_key_with_progress(b"password", b"NaCl", 10, 8, 16, 64, |_| {
+            calls += 1;
+            calls < 3
+        });
+        assert!(matches!(cancelled, Err(CryptoError::KeyDerivationFailed(KDF_CANCELLED))));
+        assert_eq!(calls, 3);
+        assert!(ScryptKdf::derive_key_with_progress(b"password", b"salt", 4, 1, 1, 0, |_| true).is_err());
+    }
+
+    #[test]
+    fn test_kbkdf_counter_sha256() {
+        let key = b"key_derivation_key_material_1234";
+
+        let derived = KbkdfKdf::derive_counter_sha256(key, b"label", b"context", 42).unwrap();
+        let expected = hex::decode("b0e45786ee3ba3ebbe5db86d73dd918af32675050dfd5ba8a78e2cfe8f3b56b49c80ba9e17996db3b8fd").unwrap();
+        assert_eq!(derived, expected);
+
+        // Default variant uses HMAC-SHA256
+        let derived2 = KbkdfKdf::derive_counter(key, b"label", b"context", 42).unwrap();
+        assert_eq!(derived, derived2);
+
+        // Different context should produce different key
+        let derived3 = KbkdfKdf::derive_counter(key, b"label", b"other", 42).unwrap();
+        assert_ne!(derived, derived3);
+    }
+
+    #[test]
+    fn test_kbkdf_counter_sha512() {
+        let key = b"key_derivation_key_material_1234";
+
+        let derived = KbkdfKdf::derive_counter_sha512(key, b"label", b"context", 80).unwrap();
+        let expected = hex::decode("ef29573b5d57c27efde9ccae818dbb1c2107e85e27bd29c78ff63c95dd122f7515fb0d7379efac9dce7582d123fb13d5c0888084077e16494d601e6d5d27948c218679ef422f0eef6cae104064850ad0").unwrap();
+        assert_eq!(derived, expected);
+    }
+
+    #[test]
+    fn test_kbkdf_zero_length() {
+        let result = KbkdfKdf::derive_counter(b"key", b"label", b"context", 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_concat_kdf_sha256() {
+        let derived = ConcatKdf::derive_sha256(b"shared_secret_value", b"other_info", 48).unwrap();
+        let expected = hex::decode("41ac308255a36c5d43ee80fd5c4ef925861ac644160416b62b976a6cf0a259e4287a48ecbaa3cb414b80275a2e42ab77").unwrap();
+        assert_eq!(derived, expected);
+
+        // Default variant uses SHA-256
+        let derived2 = ConcatKdf::derive(b"shared_secret_value", b"other_info", 48).unwrap();
+        assert_eq!(derived, derived2);
+    }
+
+    #[test]
+    fn test_concat_kdf_sha384() {
+        let derived = ConcatKdf::derive_sha384(b"shared_secret_value", b"other_info", 64).unwrap();
+        let expected = hex::decode("f19c85318e0457981ebb0ad3ca6b0edcb9814391b33ec48464450d7ad03badb0b4bf64c2a95a3f9c021d29891de54d4988c43794a49442fafa4ef9806bbd837c").unwrap();
+        assert_eq!(derived, expected);
+    }
+
+    #[test]
+    fn test_concat_kdf_zero_length() {
+        let result = ConcatKdf::derive(b"shared_secret_value", b"other_info", 0);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_x963_kdf_sha256() {
+        let derived = X963Kdf::derive_sha256(b"shared_secret_value", b"shared_info", 48).unwrap();
+        let expected = hex::decode("785498243f43c043a5c7cd13afc044db6ccc9f5aceb249a3776d1af754a64db4daf00a4a9cfff52b526b339dbd85bb54").unwrap();
+        assert_eq!(derived, expected);
+
+        // Default variant uses SHA-256
+        let derived2 = X963Kdf::derive(b"shared_secret_value", b"shared_info", 48).unwrap();
+        assert_eq!(derived, derived2);
+
+        // Concat KDF places the counter first, so outputs must differ
+        let concat = ConcatKdf::derive(b"shared_secret_value", b"shared_info", 48).unwrap();
+        assert_ne!(derived, concat);
+    }
+
+    #[test]
+    fn test_x963_kdf_sha384_sha512() {
+        let derived = X963Kdf::derive_sha384(b"shared_secret_value", b"shared_info", 64).unwrap();
+        let expected = hex::decode("40a807d3c32753148f80b4755a88204ba28926128607b225764743aeb37e7a7d362460e823ffe51f50e03a6c5cbe12f2ca8643b0114bde3c8791028aa55c3b9e").unwrap();
+        assert_eq!(derived, expected);
+
+        let derived = X963Kdf::derive_sha512(b"shared_secret_value", b"shared_info", 80).unwrap();
+        let expected = hex::decode("44d1614fc86e4955f8d71147a9013db51468f2b72a00f8eb08667b7e8a105732e9bc3bef49814bf3dbe00be742129d017c0489a5555ed45bc4f8538c3cfb8761334a57ced9b0646b7fe915762c92eaab").unwrap();
+        assert_eq!(derived, expected);
+    }
+
     #[test]
     fn test_secure_key_derivation_argon2() {
         let password = b"test_password";
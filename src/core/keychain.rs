@@ -0,0 +1,192 @@
+use crate::error::{CryptoError, CryptoResult, KEYCHAIN_ENTRY_NOT_FOUND, KEYCHAIN_ACCESS_FAILED, KEYCHAIN_INVALID_LABEL};
+use crate::core::random::SecureKey;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Storage for [`SecureKey`]s addressed by label
+pub trait KeychainStore {
+    /// Store `key` under `label`, replacing any existing key
+    fn store(&self, label: &str, key: &SecureKey) -> CryptoResult<()>;
+
+    /// Load the key stored under `label`
+    fn load(&self, label: &str) -> CryptoResult<SecureKey>;
+
+    /// Delete the key stored under `label`
+    fn delete(&self, label: &str) -> CryptoResult<()>;
+
+    /// Check whether a key is stored under `label`
+    fn contains(&self, label: &str) -> CryptoResult<bool> {
+        match self.load(label) {
+            Ok(_) => Ok(true),
+            Err(err) if matches!(err.root(), CryptoError::InvalidInput(KEYCHAIN_ENTRY_NOT_FOUND)) => Ok(false),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+/// In-process keychain, for tests and platforms without an OS keychain
+#[derive(Debug, Default)]
+pub struct MemoryKeychain {
+    entries: Mutex<BTreeMap<String, SecureKey>>,
+}
+
+impl MemoryKeychain {
+    /// Create an empty keychain
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl KeychainStore for MemoryKeychain {
+    fn store(&self, label: &str, key: &SecureKey) -> CryptoResult<()> {
+        validate_label(label)?;
+        self.entries.lock()
+            .map_err(|_| CryptoError::IoFailed(KEYCHAIN_ACCESS_FAILED))?
+            .insert(label.to_string(), key.clone());
+        Ok(())
+    }
+
+    fn load(&self, label: &str) -> CryptoResult<SecureKey> {
+        self.entries.lock()
+            .map_err(|_| CryptoError::IoFailed(KEYCHAIN_ACCESS_FAILED))?
+            .get(label)
+            .cloned()
+            .ok_or(CryptoError::InvalidInput(KEYCHAIN_ENTRY_NOT_FOUND))
+    }
+
+    fn delete(&self, label: &str) -> CryptoResult<()> {
+        self.entries.lock()
+            .map_err(|_| CryptoError::IoFailed(KEYCHAIN_ACCESS_FAILED))?
+            .remove(label)
+            .map(|_| ())
+            .ok_or(CryptoError::InvalidInput(KEYCHAIN_ENTRY_NOT_FOUND))
+    }
+}
+
+/// The platform credential store, selected by feature:
+///
+/// - `keychain-macos`: macOS / iOS Keychain Services
+/// - `keychain-windows`: Windows Credential Manager (DPAPI-protected)
+/// - `keychain-linux`: freedesktop Secret Service (GNOME Keyring, KWallet)
+///
+/// Keys are stored as generic secrets under `service` with the label as the account name.
+#[cfg(any(feature = "keychain-macos", feature = "keychain-windows", feature = "keychain-linux"))]
+#[derive(Debug, Clone)]
+pub struct OsKeychain {
+    service: String,
+}
+
+#[cfg(any(feature = "keychain-macos", feature = "keychain-windows", feature = "keychain-linux"))]
+impl OsKeychain {
+    /// Create a keychain scoped to `service` (e.g. a reverse-DNS application ID)
+    pub fn new(service: &str) -> CryptoResult<Self> {
+        validate_label(service)?;
+        Ok(Self { service: service.to_string() })
+    }
+
+    fn entry(&self, label: &str) -> CryptoResult<keyring::Entry> {
+        validate_label(label)?;
+        keyring::Entry::new(&self.service, label)
+            .map_err(map_keyring_error)
+    }
+}
+
+#[cfg(any(feature = "keychain-macos", feature = "keychain-windows", feature = "keychain-linux"))]
+impl KeychainStore for OsKeychain {
+    fn store(&self, label: &str, key: &SecureKey) -> CryptoResult<()> {
+        self.entry(label)?
+            .set_secret(key.as_bytes())
+            .map_err(map_keyring_error)
+    }
+
+    fn load(&self, label: &str) -> CryptoResult<SecureKey> {
+        self.entry(label)?
+            .get_secret()
+            .map(SecureKey::new)
+            .map_err(map_keyring_error)
+    }
+
+    fn delete(&self, label: &str) -> CryptoResult<()> {
+        self.entry(label)?
+            .delete_credential()
+            .map_err(map_keyring_error)
+    }
+}
+
+#[cfg(any(feature = "keychain-macos", feature = "keychain-windows", feature = "keychain-linux"))]
+fn map_keyring_error(error: keyring::Error) -> CryptoError {
+    match error {
+        keyring::Error::NoEntry => CryptoError::InvalidInput(KEYCHAIN_ENTRY_NOT_FOUND),
+        keyring::Error::TooLong(..) | keyring::Error::Invalid(..) => CryptoError::InvalidInput(KEYCHAIN_INVALID_LABEL),
+        _ => CryptoError::IoFailed(KEYCHAIN_ACCESS_FAILED),
+    }
+}
+
+#[inline]
+fn validate_label(label: &str) -> CryptoResult<()> {
+    if label.is_empty() {
+        return Err(CryptoError::InvalidInput(KEYCHAIN_INVALID_LABEL));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::random::SecureRandom;
+
+    #[test]
+    fn test_memory_keychain() {
+        let keychain = MemoryKeychain::new();
+        let key = SecureRandom::generate_key(32).unwrap();
+
+        assert!(!keychain.contains("db-key").unwrap());
+        keychain.store("db-key", &key).unwrap();
+        assert!(keychain.contains("db-key").unwrap());
+        assert_eq!(keychain.load("db-key").unwrap().as_bytes(), key.as_bytes());
+
+        // Storing again replaces the key
+        let replacement = SecureRandom::generate_key(32).unwrap();
+        keychain.store("db-key", &replacement).unwrap();
+        assert_eq!(keychain.load("db-key").unwrap().as_bytes(), replacement.as_bytes());
+
+        keychain.delete("db-key").unwrap();
+        assert_eq!(keychain.load("db-key").unwrap_err(), CryptoError::InvalidInput(KEYCHAIN_ENTRY_NOT_FOUND));
+        assert!(keychain.delete("db-key").is_err());
+        assert!(keychain.store("", &key).is_err());
+    }
+
+    #[test]
+    fn test_keychain_trait_object() {
+        let keychain: Box<dyn KeychainStore> = Box::new(MemoryKeychain::new());
+        keychain.store("a", &SecureKey::new(vec![1, 2, 3])).unwrap();
+        assert_eq!(keychain.load("a").unwrap().as_bytes(), &[1, 2, 3]);
+    }
+
+    // Backend that wraps its errors with context, as OS keychains do
+    struct ContextKeychain(MemoryKeychain);
+
+    impl KeychainStore for ContextKeychain {
+        fn store(&self, label: &str, key: &SecureKey) -> CryptoResult<()> {
+            self.0.store(label, key).map_err(|err| err.with_context("store", "keychain"))
+        }
+
+        fn load(&self, label: &str) -> CryptoResult<SecureKey> {
+            self.0.load(label).map_err(|err| err.with_context("load", "keychain"))
+        }
+
+        fn delete(&self, label: &str) -> CryptoResult<()> {
+            self.0.delete(label).map_err(|err| err.with_context("delete", "keychain"))
+        }
+    }
+
+    #[test]
+    fn test_contains_sees_through_context() {
+        let keychain = ContextKeychain(MemoryKeychain::new());
+        assert!(matches!(keychain.load("a"), Err(CryptoError::Context(_))));
+        assert!(!keychain.contains("a").unwrap());
+        keychain.store("a", &SecureKey::new(vec![1])).unwrap();
+        assert!(keychain.contains("a").unwrap());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::core::asymmetric::RsaKeyPair;
+use crate::error::{CryptoError, CryptoResult, RSA_KEY_SIZE_TOO_SMALL, RSA_KEY_POOL_INVALID_CONFIG, RSA_KEY_POOL_SPAWN_FAILED};
+use std::collections::VecDeque;
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread;
+
+/// Pool size and refill behavior for an [`RsaKeyPool`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RsaKeyPoolConfig {
+    /// Modulus size of the pooled keys, at least 2048
+    pub bits: usize,
+    /// Number of key pairs kept ready
+    pub capacity: usize,
+    /// The background thread refills the pool to `capacity` once it drops to
+    /// this many keys; must be below `capacity`
+    pub low_water_mark: usize,
+    /// When the pool is empty, generate a key on the calling thread instead
+    /// of waiting for the background thread
+    pub generate_when_empty: bool,
+}
+
+impl Default for RsaKeyPoolConfig {
+    fn default() -> Self {
+        Self { bits: 2048, capacity: 8, low_water_mark: 4, generate_when_empty: true }
+    }
+}
+
+/// RSA key pairs generated ahead of time on a background thread
+///
+/// RSA key generation takes hundreds of milliseconds, so services that mint
+/// a key per session can take one from the pool instead. Keys are generated
+/// with [`RsaKeyPair::generate`] and handed out at most once; keys still in
+/// the pool are zeroized when it is dropped.
+pub struct RsaKeyPool {
+    shared: Arc<Shared>,
+}
+
+struct Shared {
+    config: RsaKeyPoolConfig,
+    state: Mutex<PoolState>,
+    // Wakes the background thread when the pool needs refilling or shuts down
+    refill: Condvar,
+    // Wakes callers waiting in `take` when a key is added
+    ready: Condvar,
+}
+
+struct PoolState {
+    keys: VecDeque<RsaKeyPair>,
+    refilling: bool,
+    shutdown: bool,
+    // Set if the background thread exited after failing to generate a key
+    stopped: bool,
+}
+
+impl RsaKeyPool {
+    /// Start a pool and its background thread, which begins filling it immediately
+    pub fn new(config: RsaKeyPoolConfig) -> CryptoResult<Self> {
+        if config.bits < 2048 {
+            return Err(CryptoError::InvalidInput(RSA_KEY_SIZE_TOO_SMALL));
+        }
+        if config.capacity == 0 || config.low_water_mark >= config.capacity {
+            return Err(CryptoError::InvalidInput(RSA_KEY_POOL_INVALID_CONFIG));
+        }
+
+        let shared = Arc::new(Shared {
+            config,
+            state: Mutex::new(PoolState {
+                keys: VecDeque::with_capacity(config.capacity),
+                refilling: true,
+                shutdown: false,
+                stopped: false,
+            }),
+            refill: Condvar::new(),
+            ready: Condvar::new(),
+        });
+
+        let worker = Arc::clone(&shared);
+        thread::Builder::new()
+            .name("libsilver-rsa-pool".into())
+            .spawn(move || worker.run())
+            .map_err(|_| CryptoError::KeyGenerationFailed(RSA_KEY_POOL_SPAWN_FAILED))?;
+
+        Ok(Self { shared })
+    }
+
+    /// Take a key pair from the pool
+    ///
+    /// If the pool is empty this either generates a key on the calling thread
+    /// or blocks until the background thread adds one, depending on
+    /// [`RsaKeyPoolConfig::generate_when_empty`].
+    pub fn take(&self) -> CryptoResult<RsaKeyPair> {
+        let mut state = self.shared.lock();
+        loop {
+            if let Some(key) = self.shared.pop(&mut state) {
+                return Ok(key);
+            }
+            if self.shared.config.generate_when_empty || state.stopped {
+                drop(state);
+                return RsaKeyPair::generate(self.shared.config.bits);
+            }
+            state = self.shared.ready.wait(state).unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Take a key pair if one is ready, without blocking or generating
+    pub fn try_take(&self) -> Option<RsaKeyPair> {
+        let mut state = self.shared.lock();
+        self.shared.pop(&mut state)
+    }
+
+    /// Number of key pairs ready to be taken
+    pub fn available(&self) -> usize {
+        self.shared.lock().keys.len()
+    }
+
+    /// The pool configuration
+    #[inline]
+    pub fn config(&self) -> &RsaKeyPoolConfig {
+        &self.shared.config
+    }
+}
+
+impl Drop for RsaKeyPool {
+    fn drop(&mut self) {
+        // The thread exits after the key it is generating, without being joined
+        self.shared.lock().shutdown = true;
+        self.shared.refill.notify_one();
+    }
+}
+
+impl Shared {
+    // The pool state stays consistent even if a holder panicked
+    fn lock(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn pop(&self, state: &mut PoolState) -> Option<RsaKeyPair> {
+        let key = state.keys.pop_front()?;
+        if !state.refilling && state.keys.len() <= self.config.low_water_mark {
+            state.refilling = true;
+            self.refill.notify_one();
+        }
+        Some(key)
+    }
+
+    fn run(&self) {
+        let mut state = self.lock();
+        loop {
+            if state.shutdown {
+                return;
+            }
+            if state.keys.len() >= self.config.capacity {
+                state.refilling = false;
+            }
+            if !state.refilling {
+                state = self.refill.wait(state).unwrap_or_else(PoisonError::into_inner);
+                continue;
+            }
+
+            drop(state);
+            let key = RsaKeyPair::generate(self.config.bits);
+            state = self.lock();
+            match key {
+                Ok(key) => {
+                    state.keys.push_back(key);
+                    self.ready.notify_one();
+                }
+                Err(_) => {
+                    // Callers fall back to generating keys themselves
+                    state.stopped = true;
+                    self.ready.notify_all();
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::RsaCrypto;
+
+    #[test]
+    fn test_rsa_key_pool() {
+        let config = RsaKeyPoolConfig { capacity: 1, low_water_mark: 0, generate_when_empty: false, ..RsaKeyPoolConfig::default() };
+        let pool = RsaKeyPool::new(config).unwrap();
+
+        // Blocks until the background thread has generated the first key
+        let keypair = pool.take().unwrap();
+        let ciphertext = RsaCrypto::encrypt(b"session", keypair.public_key()).unwrap();
+        assert_eq!(RsaCrypto::decrypt(&ciphertext, keypair.private_key()).unwrap(), b"session");
+
+        // Taking the only key triggers a refill with a different key
+        let next = pool.take().unwrap();
+        assert_ne!(next.public_key_pem().unwrap(), keypair.public_key_pem().unwrap());
+        assert!(pool.available() <= 1);
+    }
+
+    #[test]
+    fn test_rsa_key_pool_config() {
+        let invalid = [
+            RsaKeyPoolConfig { bits: 1024, ..RsaKeyPoolConfig::default() },
+            RsaKeyPoolConfig { capacity: 0, low_water_mark: 0, ..RsaKeyPoolConfig::default() },
+            RsaKeyPoolConfig { capacity: 4, low_water_mark: 4, ..RsaKeyPoolConfig::default() },
+        ];
+        for config in invalid {
+            assert!(RsaKeyPool::new(config).is_err());
+        }
+        assert_eq!(RsaKeyPool::new(invalid[0]).err(), Some(CryptoError::InvalidInput(RSA_KEY_SIZE_TOO_SMALL)));
+    }
+}
@@ -0,0 +1,264 @@
+use crate::error::{CryptoError, CryptoResult, KEYRING_NO_ACTIVE_KEY, KEYRING_UNKNOWN_KEY_ID, KEYRING_DUPLICATE_KEY_ID, KEYRING_INVALID_KEY_ID, KEYRING_INVALID_ENVELOPE, KEYRING_UNSUPPORTED_VERSION};
+use crate::core::keys::Aes256Key;
+use crate::core::random::SecureKey;
+use crate::core::symmetric::AesGcm;
+use std::collections::BTreeMap;
+use zeroize::Zeroizing;
+
+// Envelope layout:
+//   version (1) | key id length (1) | key id (UTF-8) | nonce (12) + ciphertext + tag
+// The header (version + key id) is authenticated as part of the AAD.
+const KEYRING_ENVELOPE_VERSION: u8 = 1;
+
+/// Set of AES-256-GCM keys identified by ID, with one active key for encryption
+///
+/// Ciphertexts carry the ID of the key that produced them, so after a rotation
+/// old data still decrypts while new data is written under the new key.
+#[derive(Clone, Debug, Default)]
+pub struct KeyRing {
+    keys: BTreeMap<String, Aes256Key>,
+    active: Option<String>,
+}
+
+impl KeyRing {
+    /// Create an empty key ring
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a key under `key_id`; the first key added becomes active
+    pub fn add_key(&mut self, key_id: &str, key: SecureKey) -> CryptoResult<()> {
+        if key_id.is_empty() || key_id.len() > u8::MAX as usize {
+            return Err(CryptoError::InvalidInput(KEYRING_INVALID_KEY_ID));
+        }
+
+        let key = Aes256Key::from_secure_key(key)?;
+
+        if self.keys.contains_key(key_id) {
+            return Err(CryptoError::InvalidInput(KEYRING_DUPLICATE_KEY_ID));
+        }
+
+        self.keys.insert(key_id.to_string(), key);
+        if self.active.is_none() {
+            self.active = Some(key_id.to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Add a key and make it the active encryption key
+    #[inline]
+    pub fn rotate(&mut self, key_id: &str, key: SecureKey) -> CryptoResult<()> {
+        self.add_key(key_id, key)?;
+        self.set_active(key_id)
+    }
+
+    /// Select the key used by [`KeyRing::encrypt`]
+    pub fn set_active(&mut self, key_id: &str) -> CryptoResult<()> {
+        if !self.keys.contains_key(key_id) {
+            return Err(CryptoError::InvalidInput(KEYRING_UNKNOWN_KEY_ID));
+        }
+
+        self.active = Some(key_id.to_string());
+        Ok(())
+    }
+
+    /// Get the ID of the active key
+    #[inline]
+    pub fn active_key_id(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Remove a retired key; removing the active key leaves the ring without one
+    pub fn remove_key(&mut self, key_id: &str) -> CryptoResult<SecureKey> {
+        let key = self.keys.remove(key_id)
+            .ok_or(CryptoError::InvalidInput(KEYRING_UNKNOWN_KEY_ID))?;
+
+        if self.active.as_deref() == Some(key_id) {
+            self.active = None;
+        }
+
+        Ok(key.into_secure_key())
+    }
+
+    /// Check whether a key with `key_id` is present
+    #[inline]
+    pub fn contains(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    /// Get the number of keys in the ring
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Check if the ring is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Encrypt with the active key, embedding its ID in the envelope header
+    #[inline]
+    pub fn encrypt(&self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.encrypt_with_aad(plaintext, &[])
+    }
+
+    /// Encrypt with the active key and additional authenticated data
+    pub fn encrypt_with_aad(&self, plaintext: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        let key_id = self.active.as_deref()
+            .ok_or(CryptoError::InvalidInput(KEYRING_NO_ACTIVE_KEY))?;
+        let key = &self.keys[key_id];
+
+        let mut header = Vec::with_capacity(2 + key_id.len());
+        header.push(KEYRING_ENVELOPE_VERSION);
+        header.push(key_id.len() as u8);
+        header.extend_from_slice(key_id.as_bytes());
+
+        let ciphertext = AesGcm::encrypt_with_aad(plaintext, key, &Self::full_aad(&header, aad))?;
+
+        let mut envelope = header;
+        envelope.extend_from_slice(&ciphertext);
+        Ok(envelope)
+    }
+
+    /// Decrypt an envelope, selecting the key by the ID in its header
+    #[inline]
+    pub fn decrypt(&self, envelope: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.decrypt_with_aad(envelope, &[])
+    }
+
+    /// Decrypt an envelope with additional authenticated data
+    pub fn decrypt_with_aad(&self, envelope: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        let (header, key_id, ciphertext) = Self::split_envelope(envelope)?;
+        let key = self.keys.get(key_id)
+            .ok_or(CryptoError::InvalidInput(KEYRING_UNKNOWN_KEY_ID))?;
+
+        AesGcm::decrypt_with_aad(ciphertext, key, &Self::full_aad(header, aad))
+    }
+
+    /// Decrypt an envelope, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(&self, envelope: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.decrypt_with_aad_zeroizing(envelope, &[])
+    }
+
+    /// Decrypt an envelope with additional authenticated data, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_with_aad_zeroizing(&self, envelope: &[u8], aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.decrypt_with_aad(envelope, aad).map(Zeroizing::new)
+    }
+
+    /// Read the key ID from an envelope header without decrypting it
+    #[inline]
+    pub fn key_id_of(envelope: &[u8]) -> CryptoResult<&str> {
+        Self::split_envelope(envelope).map(|(_, key_id, _)| key_id)
+    }
+
+    fn split_envelope(envelope: &[u8]) -> CryptoResult<(&[u8], &str, &[u8])> {
+        if envelope.len() < 2 {
+            return Err(CryptoError::InvalidInput(KEYRING_INVALID_ENVELOPE));
+        }
+
+        if envelope[0] != KEYRING_ENVELOPE_VERSION {
+            return Err(CryptoError::InvalidInput(KEYRING_UNSUPPORTED_VERSION));
+        }
+
+        let header_len = 2 + envelope[1] as usize;
+        if envelope.len() < header_len {
+            return Err(CryptoError::InvalidInput(KEYRING_INVALID_ENVELOPE));
+        }
+
+        let (header, ciphertext) = envelope.split_at(header_len);
+        let key_id = std::str::from_utf8(&header[2..])
+            .map_err(|_| CryptoError::InvalidInput(KEYRING_INVALID_ENVELOPE))?;
+
+        Ok((header, key_id, ciphertext))
+    }
+
+    #[inline]
+    fn full_aad(header: &[u8], aad: &[u8]) -> Vec<u8> {
+        let mut full = Vec::with_capacity(header.len() + aad.len());
+        full.extend_from_slice(header);
+        full.extend_from_slice(aad);
+        full
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::random::SecureRandom;
+
+    fn random_key() -> SecureKey {
+        SecureRandom::generate_key(32).unwrap()
+    }
+
+    #[test]
+    fn test_keyring_encrypt_decrypt() {
+        let mut ring = KeyRing::new();
+        ring.add_key("2024-01", random_key()).unwrap();
+        assert_eq!(ring.active_key_id(), Some("2024-01"));
+
+        let envelope = ring.encrypt(b"customer record").unwrap();
+        assert_eq!(KeyRing::key_id_of(&envelope).unwrap(), "2024-01");
+        assert_eq!(ring.decrypt(&envelope).unwrap(), b"customer record");
+        assert_eq!(ring.decrypt_zeroizing(&envelope).unwrap().as_slice(), b"customer record");
+    }
+
+    #[test]
+    fn test_keyring_rotation() {
+        let mut ring = KeyRing::new();
+        ring.add_key("v1", random_key()).unwrap();
+        let old_envelope = ring.encrypt(b"old data").unwrap();
+
+        ring.rotate("v2", random_key()).unwrap();
+        assert_eq!(ring.active_key_id(), Some("v2"));
+
+        let new_envelope = ring.encrypt(b"new data").unwrap();
+        assert_eq!(KeyRing::key_id_of(&new_envelope).unwrap(), "v2");
+
+        // Both generations still decrypt
+        assert_eq!(ring.decrypt(&old_envelope).unwrap(), b"old data");
+        assert_eq!(ring.decrypt(&new_envelope).unwrap(), b"new data");
+
+        // After retiring v1 its ciphertexts can no longer be opened
+        ring.remove_key("v1").unwrap();
+        assert!(ring.decrypt(&old_envelope).is_err());
+    }
+
+    #[test]
+    fn test_keyring_with_aad_and_tampering() {
+        let mut ring = KeyRing::new();
+        ring.add_key("a", random_key()).unwrap();
+        ring.add_key("b", random_key()).unwrap();
+
+        let envelope = ring.encrypt_with_aad(b"secret", b"row-42").unwrap();
+        assert_eq!(ring.decrypt_with_aad(&envelope, b"row-42").unwrap(), b"secret");
+        assert!(ring.decrypt_with_aad(&envelope, b"row-43").is_err());
+
+        // Rewriting the key ID to another known key must fail authentication
+        let mut swapped = envelope.clone();
+        swapped[2] = b'b';
+        assert!(ring.decrypt_with_aad(&swapped, b"row-42").is_err());
+    }
+
+    #[test]
+    fn test_keyring_errors() {
+        let mut ring = KeyRing::new();
+        assert!(ring.encrypt(b"data").is_err());
+        assert!(ring.add_key("short", SecureKey::new(vec![0u8; 16])).is_err());
+        assert!(ring.add_key("", random_key()).is_err());
+        assert!(ring.set_active("missing").is_err());
+
+        ring.add_key("k", random_key()).unwrap();
+        assert!(ring.add_key("k", random_key()).is_err());
+
+        ring.remove_key("k").unwrap();
+        assert_eq!(ring.active_key_id(), None);
+        assert!(KeyRing::key_id_of(&[9, 0]).is_err());
+        assert!(KeyRing::key_id_of(&[1, 5, b'a']).is_err());
+    }
+}
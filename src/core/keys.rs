@@ -0,0 +1,387 @@
+use crate::error::{CryptoError, CryptoResult, HMAC_KEY_TOO_SHORT, INVALID_KEY_LENGTH_AES, INVALID_KEY_LENGTH_CHACHA};
+use crate::core::hash::Hmac;
+use crate::core::random::{SecureKey, SecureRandom};
+use crate::core::registry::Algorithm;
+#[cfg(any(feature = "aes", feature = "chacha"))]
+use crate::core::symmetric::Aead;
+#[cfg(feature = "aes")]
+use crate::core::{nonce::AesGcmNonce, symmetric::AesGcm};
+#[cfg(feature = "chacha")]
+use crate::core::{nonce::ChaChaNonce, symmetric::ChaCha20Poly1305Cipher};
+#[cfg(any(feature = "aes", feature = "chacha"))]
+use zeroize::Zeroizing;
+use alloc::vec::Vec;
+
+const AES_256_KEY_SIZE: usize = 32;
+const CHACHA_KEY_SIZE: usize = 32;
+const HMAC_MIN_KEY_SIZE: usize = 32;
+
+// Shared shape of every typed key: a `SecureKey` that can only be built
+// through a length check, with a Debug that never prints the bytes
+macro_rules! typed_key {
+    ($(#[$attr:meta])* $name:ident) => {
+        $(#[$attr])*
+        #[derive(Clone)]
+        pub struct $name(SecureKey);
+
+        impl $name {
+            /// Wrap a [`SecureKey`], keeping its memory lock if it has one
+            #[inline]
+            pub fn from_secure_key(key: SecureKey) -> CryptoResult<Self> {
+                Self::check_len(key.len())?;
+                Ok(Self(key))
+            }
+
+            /// Copy raw key bytes into a new key
+            #[inline]
+            pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+                Self::check_len(bytes.len())?;
+                Ok(Self(SecureKey::new(bytes.to_vec())))
+            }
+
+            /// Get the underlying [`SecureKey`]
+            #[inline]
+            pub fn as_secure_key(&self) -> &SecureKey {
+                &self.0
+            }
+
+            /// Unwrap into the underlying [`SecureKey`]
+            #[inline]
+            pub fn into_secure_key(self) -> SecureKey {
+                self.0
+            }
+
+            /// Get the key bytes, for the byte-level APIs and bindings
+            #[inline]
+            pub fn as_bytes(&self) -> &[u8] {
+                self.0.as_bytes()
+            }
+
+            /// Get the length of the key
+            #[inline]
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Always false; typed keys are never empty
+            #[inline]
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+        }
+
+        impl core::fmt::Debug for $name {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.debug_struct(stringify!($name))
+                    .field("len", &self.len())
+                    .finish_non_exhaustive()
+            }
+        }
+    };
+}
+
+typed_key! {
+    /// A 256-bit AES-GCM key
+    ///
+    /// Only 32-byte buffers become an `Aes256Key`, and the cipher functions
+    /// on it only accept AES-GCM ciphertexts, so a password hash, an HMAC key
+    /// or a ChaCha20 key cannot be passed by mistake:
+    ///
+    /// ```rust
+    /// use libsilver::prelude::*;
+    ///
+    /// let key = Aes256Key::generate()?;
+    /// let ciphertext = key.encrypt(b"typed")?;
+    /// assert_eq!(key.decrypt(&ciphertext)?, b"typed");
+    /// assert!(Aes256Key::from_bytes(&[0u8; 16]).is_err());
+    /// # Ok::<(), CryptoError>(())
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use libsilver::prelude::*;
+    ///
+    /// let key = ChaChaKey::generate()?;
+    /// let ciphertext = Aes256Key::encrypt(&key, b"wrong key type")?;
+    /// # Ok::<(), CryptoError>(())
+    /// ```
+    Aes256Key
+}
+
+impl Aes256Key {
+    /// Generate a new random key
+    #[inline]
+    pub fn generate() -> CryptoResult<Self> {
+        SecureRandom::generate_key(AES_256_KEY_SIZE).map(Self)
+    }
+
+    fn check_len(len: usize) -> CryptoResult<()> {
+        if len != AES_256_KEY_SIZE {
+            return Err(CryptoError::InvalidKey(INVALID_KEY_LENGTH_AES));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "aes")]
+impl Aes256Key {
+
+    /// Encrypt with AES-256-GCM, see [`AesGcm::encrypt`]
+    #[inline]
+    pub fn encrypt(&self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::encrypt(plaintext, self)
+    }
+
+    /// Decrypt with AES-256-GCM, see [`AesGcm::decrypt`]
+    #[inline]
+    pub fn decrypt(&self, ciphertext_with_nonce: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::decrypt(ciphertext_with_nonce, self)
+    }
+
+    /// Encrypt with AES-256-GCM and associated data, see [`AesGcm::encrypt_with_aad`]
+    #[inline]
+    pub fn encrypt_with_aad(&self, plaintext: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::encrypt_with_aad(plaintext, self, aad)
+    }
+
+    /// Decrypt with AES-256-GCM and associated data, see [`AesGcm::decrypt_with_aad`]
+    #[inline]
+    pub fn decrypt_with_aad(&self, ciphertext_with_nonce: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::decrypt_with_aad(ciphertext_with_nonce, self, aad)
+    }
+
+    /// Decrypt with AES-256-GCM, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(&self, ciphertext_with_nonce: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        AesGcm::decrypt_zeroizing(ciphertext_with_nonce, self)
+    }
+
+    /// Decrypt with AES-256-GCM and associated data, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_with_aad_zeroizing(&self, ciphertext_with_nonce: &[u8], aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        AesGcm::decrypt_with_aad_zeroizing(ciphertext_with_nonce, self, aad)
+    }
+
+    /// Encrypt with AES-256-GCM under a caller-chosen nonce, which is consumed
+    ///
+    /// The output has the usual `nonce || ciphertext || tag` layout.
+    #[inline]
+    pub fn encrypt_with_nonce(&self, plaintext: &[u8], nonce: AesGcmNonce, aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm.seal_with_nonce(plaintext, self.as_bytes(), nonce.as_ref(), aad)
+    }
+}
+
+typed_key! {
+    /// A 256-bit ChaCha20-Poly1305 key
+    ChaChaKey
+}
+
+impl ChaChaKey {
+    /// Generate a new random key
+    #[inline]
+    pub fn generate() -> CryptoResult<Self> {
+        SecureRandom::generate_key(CHACHA_KEY_SIZE).map(Self)
+    }
+
+    fn check_len(len: usize) -> CryptoResult<()> {
+        if len != CHACHA_KEY_SIZE {
+            return Err(CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "chacha")]
+impl ChaChaKey {
+
+    /// Encrypt with ChaCha20-Poly1305, see [`ChaCha20Poly1305Cipher::encrypt`]
+    #[inline]
+    pub fn encrypt(&self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher::encrypt(plaintext, self)
+    }
+
+    /// Decrypt with ChaCha20-Poly1305, see [`ChaCha20Poly1305Cipher::decrypt`]
+    #[inline]
+    pub fn decrypt(&self, ciphertext_with_nonce: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher::decrypt(ciphertext_with_nonce, self)
+    }
+
+    /// Encrypt with ChaCha20-Poly1305 and associated data
+    #[inline]
+    pub fn encrypt_with_aad(&self, plaintext: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher::encrypt_with_aad(plaintext, self, aad)
+    }
+
+    /// Decrypt with ChaCha20-Poly1305 and associated data
+    #[inline]
+    pub fn decrypt_with_aad(&self, ciphertext_with_nonce: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher::decrypt_with_aad(ciphertext_with_nonce, self, aad)
+    }
+
+    /// Decrypt with ChaCha20-Poly1305, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(&self, ciphertext_with_nonce: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        ChaCha20Poly1305Cipher::decrypt_zeroizing(ciphertext_with_nonce, self)
+    }
+
+    /// Decrypt with ChaCha20-Poly1305 and associated data, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_with_aad_zeroizing(&self, ciphertext_with_nonce: &[u8], aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        ChaCha20Poly1305Cipher::decrypt_with_aad_zeroizing(ciphertext_with_nonce, self, aad)
+    }
+
+    /// Encrypt with ChaCha20-Poly1305 under a caller-chosen nonce, which is consumed
+    ///
+    /// The output has the usual `nonce || ciphertext || tag` layout.
+    #[inline]
+    pub fn encrypt_with_nonce(&self, plaintext: &[u8], nonce: ChaChaNonce, aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher.seal_with_nonce(plaintext, self.as_bytes(), nonce.as_ref(), aad)
+    }
+}
+
+typed_key! {
+    /// An HMAC key of at least 256 bits
+    ///
+    /// Shorter keys are rejected rather than padded, as RFC 7518 requires for
+    /// HS256 and RFC 2104 recommends for HMAC in general.
+    HmacKey
+}
+
+impl HmacKey {
+    /// Generate a new random 32-byte key
+    #[inline]
+    pub fn generate() -> CryptoResult<Self> {
+        SecureRandom::generate_key(HMAC_MIN_KEY_SIZE).map(Self)
+    }
+
+    fn check_len(len: usize) -> CryptoResult<()> {
+        if len < HMAC_MIN_KEY_SIZE {
+            return Err(CryptoError::InvalidKey(HMAC_KEY_TOO_SHORT));
+        }
+        Ok(())
+    }
+
+    /// Compute HMAC-SHA256
+    #[inline]
+    pub fn sha256(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Hmac::sha256(self, message)
+    }
+
+    /// Compute HMAC-SHA512
+    #[inline]
+    pub fn sha512(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Hmac::sha512(self, message)
+    }
+
+    /// Verify HMAC-SHA256 in constant time
+    #[inline]
+    pub fn verify_sha256(&self, message: &[u8], expected_mac: &[u8]) -> CryptoResult<bool> {
+        Hmac::verify_sha256(self, message, expected_mac)
+    }
+
+    /// Verify HMAC-SHA512 in constant time
+    #[inline]
+    pub fn verify_sha512(&self, message: &[u8], expected_mac: &[u8]) -> CryptoResult<bool> {
+        Hmac::verify_sha512(self, message, expected_mac)
+    }
+}
+
+/// A key for the [`Encryptor`](crate::core::encryptor::Encryptor) and
+/// [`Decryptor`](crate::core::encryptor::Decryptor) builders
+///
+/// Built from `&Aes256Key` or `&ChaChaKey`, so `.key(&key)` takes either;
+/// the builders check it against the algorithm they are set to.
+#[derive(Clone, Copy)]
+pub enum AeadKey<'a> {
+    /// An AES-256-GCM key
+    Aes256(&'a Aes256Key),
+    /// A ChaCha20-Poly1305 key
+    ChaCha(&'a ChaChaKey),
+}
+
+impl AeadKey<'_> {
+    /// The cipher the key is for
+    #[inline]
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            AeadKey::Aes256(_) => Algorithm::Aes256Gcm,
+            AeadKey::ChaCha(_) => Algorithm::ChaCha20Poly1305,
+        }
+    }
+
+    /// Get the key bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            AeadKey::Aes256(key) => key.as_bytes(),
+            AeadKey::ChaCha(key) => key.as_bytes(),
+        }
+    }
+}
+
+impl<'a> From<&'a Aes256Key> for AeadKey<'a> {
+    #[inline]
+    fn from(key: &'a Aes256Key) -> Self {
+        AeadKey::Aes256(key)
+    }
+}
+
+impl<'a> From<&'a ChaChaKey> for AeadKey<'a> {
+    #[inline]
+    fn from(key: &'a ChaChaKey) -> Self {
+        AeadKey::ChaCha(key)
+    }
+}
+
+// Never print the key
+impl core::fmt::Debug for AeadKey<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            AeadKey::Aes256(key) => key.fmt(f),
+            AeadKey::ChaCha(key) => key.fmt(f),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "aes", feature = "chacha"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_typed_keys_check_length() {
+        assert_eq!(Aes256Key::from_bytes(&[0u8; 31]).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_AES));
+        assert_eq!(ChaChaKey::from_bytes(&[0u8; 33]).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
+        assert_eq!(HmacKey::from_bytes(&[0u8; 16]).unwrap_err(), CryptoError::InvalidKey(HMAC_KEY_TOO_SHORT));
+        assert!(HmacKey::from_bytes(&[0u8; 64]).is_ok());
+        assert!(Aes256Key::from_secure_key(SecureRandom::generate_key(16).unwrap()).is_err());
+
+        let key = Aes256Key::generate().unwrap();
+        assert_eq!(key.len(), 32);
+        assert!(!format!("{key:?}").contains(&format!("{:?}", key.as_bytes())));
+        assert_eq!(key.clone().into_secure_key().as_bytes(), key.as_bytes());
+    }
+
+    #[test]
+    fn test_typed_keys_match_byte_apis() {
+        let aes = Aes256Key::generate().unwrap();
+        let ciphertext = aes.encrypt_with_aad(b"data", b"aad").unwrap();
+        assert_eq!(AesGcm::decrypt_with_aad(&ciphertext, &aes, b"aad").unwrap(), b"data");
+        assert_eq!(*aes.decrypt_zeroizing(&aes.encrypt(b"data").unwrap()).unwrap(), b"data");
+
+        let chacha = ChaChaKey::from_bytes(aes.as_bytes()).unwrap();
+        let ciphertext = ChaCha20Poly1305Cipher::encrypt(b"data", &chacha).unwrap();
+        assert_eq!(chacha.decrypt(&ciphertext).unwrap(), b"data");
+        assert!(chacha.decrypt_with_aad(&chacha.encrypt_with_aad(b"data", b"aad").unwrap(), b"other").is_err());
+
+        let nonce = AesGcmNonce::from_counter(1);
+        let ciphertext = chacha.encrypt_with_nonce(b"data", nonce.clone(), b"aad").unwrap();
+        assert_eq!(ciphertext[..12], *nonce.as_bytes());
+        assert_eq!(chacha.decrypt_with_aad(&ciphertext, b"aad").unwrap(), b"data");
+        assert_eq!(aes.encrypt_with_nonce(b"data", nonce.clone(), b"").unwrap()[12..], AesGcm::encrypt_with_nonce(b"data", &aes, nonce.as_bytes()).unwrap());
+
+        let hmac = HmacKey::generate().unwrap();
+        let mac = hmac.sha256(b"message").unwrap();
+        assert_eq!(mac, Hmac::sha256(&hmac, b"message").unwrap());
+        assert!(hmac.verify_sha256(b"message", &mac).unwrap());
+        assert!(hmac.verify_sha512(b"message", &hmac.sha512(b"message").unwrap()).unwrap());
+    }
+}
@@ -0,0 +1,553 @@
+use crate::error::{CryptoError, CryptoResult, KEYSTORE_INVALID_FORMAT, KEYSTORE_UNSUPPORTED_VERSION, KEYSTORE_WRONG_PASSPHRASE, KEYSTORE_ENTRY_EXISTS, KEYSTORE_ENTRY_NOT_FOUND, KEYSTORE_NAME_TOO_LONG, KEYSTORE_READ_FAILED, KEYSTORE_WRITE_FAILED, KEYSTORE_PARAMS_TOO_LARGE, ARGON2_DERIVATION_FAILED, MANAGED_KEY_INVALID_EXPIRY};
+use crate::core::keys::Aes256Key;
+use crate::core::random::{SecureRandom, SecureKey};
+use crate::core::managed::{ManagedKey, NOT_AFTER_METADATA_KEY};
+use crate::core::symmetric::AesGcm;
+#[cfg(feature = "serde")]
+use crate::core::serialization::{BytesRef, IncludeSecrets};
+use argon2::{Algorithm, Argon2, Params, Version};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use zeroize::Zeroizing;
+
+// File layout:
+//   magic "LSKS" | version (1) | m_cost (4) | t_cost (4) | p_cost (4) | salt (32) | AES-GCM(nonce + payload + tag)
+// The header is bound to the ciphertext as AAD so parameters cannot be swapped.
+const KEYSTORE_MAGIC: &[u8; 4] = b"LSKS";
+const KEYSTORE_VERSION: u8 = 1;
+const KEYSTORE_SALT_SIZE: usize = 32;
+const KEYSTORE_HEADER_SIZE: usize = 4 + 1 + 4 + 4 + 4 + KEYSTORE_SALT_SIZE;
+const KEYSTORE_KEY_SIZE: usize = 32;
+
+// Ceilings for Argon2 parameters read from an unauthenticated header: 1 GiB, 10 passes, 16 lanes
+const MAX_HEADER_M_COST: u32 = 1024 * 1024;
+const MAX_HEADER_T_COST: u32 = 10;
+const MAX_HEADER_P_COST: u32 = 16;
+
+/// A named key stored in a [`Keystore`]
+#[derive(Clone, Debug)]
+pub struct KeyEntry {
+    name: String,
+    algorithm: String,
+    created_at: u64,
+    version: u32,
+    metadata: BTreeMap<String, String>,
+    key: SecureKey,
+}
+
+impl KeyEntry {
+    /// Get the entry name
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Get the algorithm label (e.g. `"AES-256-GCM"`)
+    #[inline]
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// Get the creation time of the current key version, in seconds since the Unix epoch
+    #[inline]
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Get the key version, starting at 1 and incremented on every rotation
+    #[inline]
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Get the free-form metadata attached to the entry
+    #[inline]
+    pub fn metadata(&self) -> &BTreeMap<String, String> {
+        &self.metadata
+    }
+
+    /// Get the key material
+    #[inline]
+    pub fn key(&self) -> &SecureKey {
+        &self.key
+    }
+}
+
+#[cfg(feature = "serde")]
+impl KeyEntry {
+    fn serialize_fields<S: serde::Serializer>(&self, serializer: S, include_key: bool) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("KeyEntry", if include_key { 6 } else { 5 })?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("algorithm", &self.algorithm)?;
+        state.serialize_field("created_at", &self.created_at)?;
+        state.serialize_field("version", &self.version)?;
+        state.serialize_field("metadata", &self.metadata)?;
+        if include_key {
+            state.serialize_field("key", &BytesRef(self.key.as_bytes()))?;
+        }
+        state.end()
+    }
+}
+
+/// Metadata only; the key needs [`IncludeSecrets`]
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeyEntry {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.serialize_fields(serializer, false)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for IncludeSecrets<KeyEntry> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize_fields(serializer, true)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for IncludeSecrets<KeyEntry> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(rename = "KeyEntry")]
+        struct Fields {
+            name: String,
+            algorithm: String,
+            created_at: u64,
+            version: u32,
+            #[serde(default)]
+            metadata: BTreeMap<String, String>,
+            #[serde(with = "crate::core::serialization::bytes")]
+            key: Vec<u8>,
+        }
+
+        let Fields { name, algorithm, created_at, version, metadata, key } = Fields::deserialize(deserializer)?;
+        Ok(IncludeSecrets(KeyEntry { name, algorithm, created_at, version, metadata, key: SecureKey::new(key) }))
+    }
+}
+
+/// Passphrase-protected container for multiple named keys
+///
+/// The serialized form derives a wrapping key from the passphrase with Argon2id
+/// and encrypts all entries with AES-256-GCM.
+#[derive(Clone, Debug, Default)]
+pub struct Keystore {
+    entries: BTreeMap<String, KeyEntry>,
+}
+
+impl Keystore {
+    /// Create an empty keystore
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a key under `name`, failing if the name is already taken
+    #[inline]
+    pub fn add(&mut self, name: &str, algorithm: &str, key: SecureKey) -> CryptoResult<()> {
+        self.add_with_metadata(name, algorithm, key, BTreeMap::new())
+    }
+
+    /// Add a key under `name` with free-form metadata, failing if the name is already taken
+    pub fn add_with_metadata(&mut self, name: &str, algorithm: &str, key: SecureKey, metadata: BTreeMap<String, String>) -> CryptoResult<()> {
+        if self.entries.contains_key(name) {
+            return Err(CryptoError::InvalidInput(KEYSTORE_ENTRY_EXISTS));
+        }
+
+        Self::validate_field(name)?;
+        Self::validate_field(algorithm)?;
+        for (k, v) in &metadata {
+            Self::validate_field(k)?;
+            Self::validate_field(v)?;
+        }
+
+        self.entries.insert(name.to_string(), KeyEntry {
+            name: name.to_string(),
+            algorithm: algorithm.to_string(),
+            created_at: unix_now(),
+            version: 1,
+            metadata,
+            key,
+        });
+
+        Ok(())
+    }
+
+    /// Generate and add a random key of `length` bytes under `name`
+    #[inline]
+    pub fn generate(&mut self, name: &str, algorithm: &str, length: usize) -> CryptoResult<()> {
+        let key = SecureRandom::generate_key(length)?;
+        self.add(name, algorithm, key)
+    }
+
+    /// Get the entry stored under `name`
+    #[inline]
+    pub fn get(&self, name: &str) -> Option<&KeyEntry> {
+        self.entries.get(name)
+    }
+
+    /// Remove and return the entry stored under `name`
+    pub fn remove(&mut self, name: &str) -> CryptoResult<KeyEntry> {
+        self.entries.remove(name)
+            .ok_or(CryptoError::InvalidInput(KEYSTORE_ENTRY_NOT_FOUND))
+    }
+
+    /// Add a managed key under its ID, recording the expiry in the entry metadata
+    pub fn add_managed(&mut self, key: ManagedKey) -> CryptoResult<()> {
+        let mut metadata = BTreeMap::new();
+        if let Some(not_after) = key.not_after {
+            metadata.insert(NOT_AFTER_METADATA_KEY.to_string(), not_after.to_string());
+        }
+
+        self.add_with_metadata(&key.id, &key.algorithm, key.key, metadata)?;
+        if let Some(entry) = self.entries.get_mut(&key.id) {
+            entry.created_at = key.created_at;
+        }
+        Ok(())
+    }
+
+    /// Get the entry stored under `name` as a [`ManagedKey`]
+    pub fn get_managed(&self, name: &str) -> CryptoResult<ManagedKey> {
+        let entry = self.entries.get(name)
+            .ok_or(CryptoError::InvalidInput(KEYSTORE_ENTRY_NOT_FOUND))?;
+
+        let not_after = entry.metadata.get(NOT_AFTER_METADATA_KEY)
+            .map(|value| value.parse::<u64>())
+            .transpose()
+            .map_err(|_| CryptoError::InvalidInput(MANAGED_KEY_INVALID_EXPIRY))?;
+
+        Ok(ManagedKey {
+            id: entry.name.clone(),
+            algorithm: entry.algorithm.clone(),
+            created_at: entry.created_at,
+            not_after,
+            key: entry.key.clone(),
+        })
+    }
+
+    /// Replace the key under `name` with fresh random material of the same length
+    ///
+    /// Returns the new key version. The previous key material is zeroized.
+    pub fn rotate(&mut self, name: &str) -> CryptoResult<u32> {
+        let entry = self.entries.get_mut(name)
+            .ok_or(CryptoError::InvalidInput(KEYSTORE_ENTRY_NOT_FOUND))?;
+
+        entry.key = SecureRandom::generate_key(entry.key.len())?;
+        entry.version = entry.version.saturating_add(1);
+        entry.created_at = unix_now();
+
+        Ok(entry.version)
+    }
+
+    /// Iterate over entry names in sorted order
+    #[inline]
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.entries.keys().map(String::as_str)
+    }
+
+    /// Get the number of stored entries
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if the keystore is empty
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Serialize and encrypt the keystore under `passphrase`
+    pub fn to_bytes(&self, passphrase: &[u8]) -> CryptoResult<Vec<u8>> {
+        let params = Params::default();
+        let salt = SecureRandom::generate_salt()?;
+
+        let mut header = Vec::with_capacity(KEYSTORE_HEADER_SIZE);
+        header.extend_from_slice(KEYSTORE_MAGIC);
+        header.push(KEYSTORE_VERSION);
+        header.extend_from_slice(&params.m_cost().to_be_bytes());
+        header.extend_from_slice(&params.t_cost().to_be_bytes());
+        header.extend_from_slice(&params.p_cost().to_be_bytes());
+        header.extend_from_slice(&salt);
+
+        let wrapping_key = Self::derive_wrapping_key(passphrase, &salt, params)?;
+        let payload = self.encode_entries();
+        let ciphertext = AesGcm::encrypt_with_aad(&payload, &wrapping_key, &header)?;
+
+        let mut result = header;
+        result.extend_from_slice(&ciphertext);
+        Ok(result)
+    }
+
+    /// Decrypt and parse a keystore produced by [`Keystore::to_bytes`]
+    pub fn from_bytes(data: &[u8], passphrase: &[u8]) -> CryptoResult<Self> {
+        if data.len() < KEYSTORE_HEADER_SIZE || &data[..4] != KEYSTORE_MAGIC {
+            return Err(CryptoError::EncodingFailed(KEYSTORE_INVALID_FORMAT));
+        }
+
+        if data[4] != KEYSTORE_VERSION {
+            return Err(CryptoError::EncodingFailed(KEYSTORE_UNSUPPORTED_VERSION));
+        }
+
+        let (header, ciphertext) = data.split_at(KEYSTORE_HEADER_SIZE);
+        let m_cost = read_u32(&header[5..9]);
+        let t_cost = read_u32(&header[9..13]);
+        let p_cost = read_u32(&header[13..17]);
+        let salt = &header[17..];
+
+        let params = header_params(m_cost, t_cost, p_cost, KEYSTORE_KEY_SIZE, KEYSTORE_INVALID_FORMAT)?;
+
+        let wrapping_key = Self::derive_wrapping_key(passphrase, salt, params)?;
+        let payload = Zeroizing::new(
+            AesGcm::decrypt_with_aad(ciphertext, &wrapping_key, header)
+                .map_err(|_| CryptoError::DecryptionFailed(KEYSTORE_WRONG_PASSPHRASE))?,
+        );
+
+        Self::decode_entries(&payload)
+    }
+
+    /// Encrypt the keystore under `passphrase` and write it to `path`
+    pub fn save<P: AsRef<Path>>(&self, path: P, passphrase: &[u8]) -> CryptoResult<()> {
+        let data = self.to_bytes(passphrase)?;
+        std::fs::write(path, data)
+            .map_err(|_| CryptoError::IoFailed(KEYSTORE_WRITE_FAILED))
+    }
+
+    /// Read and decrypt a keystore file written by [`Keystore::save`]
+    pub fn load<P: AsRef<Path>>(path: P, passphrase: &[u8]) -> CryptoResult<Self> {
+        let data = std::fs::read(path)
+            .map_err(|_| CryptoError::IoFailed(KEYSTORE_READ_FAILED))?;
+        Self::from_bytes(&data, passphrase)
+    }
+
+    pub(crate) fn derive_wrapping_key(passphrase: &[u8], salt: &[u8], params: Params) -> CryptoResult<Aes256Key> {
+        let mut key = Zeroizing::new(vec![0u8; KEYSTORE_KEY_SIZE]);
+        Argon2::new(Algorithm::Argon2id, Version::V0x13, params)
+            .hash_password_into(passphrase, salt, &mut key)
+            .map_err(|_| CryptoError::KeyDerivationFailed(ARGON2_DERIVATION_FAILED))?;
+        Aes256Key::from_bytes(&key)
+    }
+
+    fn validate_field(value: &str) -> CryptoResult<()> {
+        if value.len() > u16::MAX as usize {
+            return Err(CryptoError::InvalidInput(KEYSTORE_NAME_TOO_LONG));
+        }
+        Ok(())
+    }
+
+    // Payload layout: count (4) | entries, each:
+    //   name | algorithm | created_at (8) | version (4) | metadata count (2) | (key | value)* | key material (4-byte length)
+    // Strings are encoded as a 2-byte length followed by UTF-8 bytes.
+    fn encode_entries(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(Vec::new());
+        out.extend_from_slice(&(self.entries.len() as u32).to_be_bytes());
+
+        for entry in self.entries.values() {
+            write_str(&mut out, &entry.name);
+            write_str(&mut out, &entry.algorithm);
+            out.extend_from_slice(&entry.created_at.to_be_bytes());
+            out.extend_from_slice(&entry.version.to_be_bytes());
+            out.extend_from_slice(&(entry.metadata.len() as u16).to_be_bytes());
+            for (k, v) in &entry.metadata {
+                write_str(&mut out, k);
+                write_str(&mut out, v);
+            }
+            out.extend_from_slice(&(entry.key.len() as u32).to_be_bytes());
+            out.extend_from_slice(entry.key.as_bytes());
+        }
+
+        out
+    }
+
+    fn decode_entries(payload: &[u8]) -> CryptoResult<Self> {
+        let mut reader = Reader { data: payload, pos: 0 };
+        let count = read_u32(reader.take(4)?);
+        let mut entries = BTreeMap::new();
+
+        for _ in 0..count {
+            let name = reader.string()?;
+            let algorithm = reader.string()?;
+            let created_at = u64::from_be_bytes(reader.take(8)?.try_into().unwrap());
+            let version = read_u32(reader.take(4)?);
+
+            let metadata_count = u16::from_be_bytes(reader.take(2)?.try_into().unwrap());
+            let mut metadata = BTreeMap::new();
+            for _ in 0..metadata_count {
+                let k = reader.string()?;
+                let v = reader.string()?;
+                metadata.insert(k, v);
+            }
+
+            let key_len = read_u32(reader.take(4)?) as usize;
+            let key = SecureKey::new(reader.take(key_len)?.to_vec());
+
+            entries.insert(name.clone(), KeyEntry { name, algorithm, created_at, version, metadata, key });
+        }
+
+        if reader.pos != payload.len() {
+            return Err(CryptoError::EncodingFailed(KEYSTORE_INVALID_FORMAT));
+        }
+
+        Ok(Self { entries })
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> CryptoResult<&'a [u8]> {
+        let end = self.pos.checked_add(len)
+            .filter(|&end| end <= self.data.len())
+            .ok_or(CryptoError::EncodingFailed(KEYSTORE_INVALID_FORMAT))?;
+        let slice = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn string(&mut self) -> CryptoResult<String> {
+        let len = u16::from_be_bytes(self.take(2)?.try_into().unwrap()) as usize;
+        String::from_utf8(self.take(len)?.to_vec())
+            .map_err(|_| CryptoError::EncodingFailed(KEYSTORE_INVALID_FORMAT))
+    }
+}
+
+#[inline]
+fn write_str(out: &mut Vec<u8>, value: &str) {
+    out.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    out.extend_from_slice(value.as_bytes());
+}
+
+// Build Argon2 parameters read from a file header, refusing costs above the ceilings
+// before any work is done, since the header is only authenticated after derivation
+pub(crate) fn header_params(m_cost: u32, t_cost: u32, p_cost: u32, output_len: usize, invalid_format: &'static str) -> CryptoResult<Params> {
+    if m_cost > MAX_HEADER_M_COST || t_cost > MAX_HEADER_T_COST || p_cost > MAX_HEADER_P_COST {
+        return Err(CryptoError::InvalidInput(KEYSTORE_PARAMS_TOO_LARGE));
+    }
+
+    Params::new(m_cost, t_cost, p_cost, Some(output_len))
+        .map_err(|_| CryptoError::EncodingFailed(invalid_format))
+}
+
+#[inline]
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes(bytes.try_into().unwrap())
+}
+
+#[inline]
+pub(crate) fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_keystore() -> Keystore {
+        let mut keystore = Keystore::new();
+        keystore.generate("database", "AES-256-GCM", 32).unwrap();
+
+        let mut metadata = BTreeMap::new();
+        metadata.insert("owner".to_string(), "billing".to_string());
+        keystore.add_with_metadata("api", "HMAC-SHA256", SecureKey::new(vec![7u8; 64]), metadata).unwrap();
+        keystore
+    }
+
+    #[test]
+    fn test_keystore_add_get_remove() {
+        let mut keystore = sample_keystore();
+        assert_eq!(keystore.len(), 2);
+        assert_eq!(keystore.names().collect::<Vec<_>>(), vec!["api", "database"]);
+
+        let entry = keystore.get("api").unwrap();
+        assert_eq!(entry.algorithm(), "HMAC-SHA256");
+        assert_eq!(entry.version(), 1);
+        assert_eq!(entry.metadata().get("owner").unwrap(), "billing");
+        assert_eq!(entry.key().as_bytes(), &[7u8; 64][..]);
+
+        // Duplicate names are rejected
+        assert!(keystore.generate("api", "AES-256-GCM", 32).is_err());
+
+        let removed = keystore.remove("api").unwrap();
+        assert_eq!(removed.name(), "api");
+        assert!(keystore.get("api").is_none());
+        assert!(keystore.remove("api").is_err());
+    }
+
+    #[test]
+    fn test_keystore_rotate() {
+        let mut keystore = sample_keystore();
+        let old_key = keystore.get("database").unwrap().key().as_bytes().to_vec();
+
+        let version = keystore.rotate("database").unwrap();
+        assert_eq!(version, 2);
+
+        let entry = keystore.get("database").unwrap();
+        assert_eq!(entry.key().len(), 32);
+        assert_ne!(entry.key().as_bytes(), &old_key[..]);
+
+        assert!(keystore.rotate("missing").is_err());
+    }
+
+    #[test]
+    fn test_keystore_roundtrip() {
+        let keystore = sample_keystore();
+        let passphrase = b"correct horse battery staple";
+
+        let sealed = keystore.to_bytes(passphrase).unwrap();
+        assert_eq!(&sealed[..4], KEYSTORE_MAGIC);
+
+        let restored = Keystore::from_bytes(&sealed, passphrase).unwrap();
+        assert_eq!(restored.len(), 2);
+        for name in keystore.names() {
+            let original = keystore.get(name).unwrap();
+            let loaded = restored.get(name).unwrap();
+            assert_eq!(original.key().as_bytes(), loaded.key().as_bytes());
+            assert_eq!(original.algorithm(), loaded.algorithm());
+            assert_eq!(original.created_at(), loaded.created_at());
+            assert_eq!(original.metadata(), loaded.metadata());
+        }
+
+        // Wrong passphrase and tampered header are rejected
+        assert!(Keystore::from_bytes(&sealed, b"wrong passphrase").is_err());
+        let mut tampered = sealed.clone();
+        tampered[20] ^= 1;
+        assert!(Keystore::from_bytes(&tampered, passphrase).is_err());
+        assert!(Keystore::from_bytes(b"LSKS", passphrase).is_err());
+    }
+
+    #[test]
+    fn test_keystore_rejects_inflated_header() {
+        let sealed = sample_keystore().to_bytes(b"passphrase").unwrap();
+
+        // m_cost = 4 TiB, t_cost = 2^32 - 1, p_cost = 2^24 - 1: each alone must fail before Argon2 runs
+        for (offset, value) in [(5, u32::MAX), (9, u32::MAX), (13, 0x00ff_ffff)] {
+            let mut inflated = sealed.clone();
+            inflated[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+            assert_eq!(Keystore::from_bytes(&inflated, b"passphrase").err(), Some(CryptoError::InvalidInput(KEYSTORE_PARAMS_TOO_LARGE)));
+        }
+
+        assert!(header_params(MAX_HEADER_M_COST, MAX_HEADER_T_COST, MAX_HEADER_P_COST, KEYSTORE_KEY_SIZE, KEYSTORE_INVALID_FORMAT).is_ok());
+        assert_eq!(header_params(8, 0, 1, KEYSTORE_KEY_SIZE, KEYSTORE_INVALID_FORMAT).err(), Some(CryptoError::EncodingFailed(KEYSTORE_INVALID_FORMAT)));
+    }
+
+    #[test]
+    fn test_keystore_save_load() {
+        let keystore = sample_keystore();
+        let path = std::env::temp_dir().join(format!("libsilver-keystore-{}.lsks", SecureRandom::token_hex(8).unwrap()));
+
+        keystore.save(&path, b"passphrase").unwrap();
+        let loaded = Keystore::load(&path, b"passphrase").unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.len(), keystore.len());
+        assert!(Keystore::load(&path, b"passphrase").is_err());
+    }
+}
@@ -0,0 +1,197 @@
+use crate::error::{CryptoError, CryptoResult, MANAGED_KEY_EXPIRED, MANAGED_KEY_UNSUPPORTED_ALGORITHM, MANAGED_KEY_INVALID_EXPIRY};
+use crate::core::keystore::unix_now;
+use crate::core::random::{SecureRandom, SecureKey};
+use crate::core::registry::Algorithm;
+use crate::core::symmetric::Aead;
+use std::time::Duration;
+use zeroize::Zeroizing;
+
+/// Keystore metadata field holding a [`ManagedKey`]'s expiry
+pub const NOT_AFTER_METADATA_KEY: &str = "not_after";
+
+/// Algorithm label for AES-256-GCM managed keys
+pub const ALGORITHM_AES_256_GCM: &str = "AES-256-GCM";
+
+/// Algorithm label for ChaCha20-Poly1305 managed keys
+pub const ALGORITHM_CHACHA20_POLY1305: &str = "ChaCha20-Poly1305";
+
+/// A symmetric key with an ID, algorithm, creation time and optional expiry
+///
+/// Expired keys refuse to encrypt but can still decrypt, so data written
+/// before the `not_after` deadline stays readable during rotation.
+#[derive(Clone, Debug)]
+pub struct ManagedKey {
+    pub(crate) id: String,
+    pub(crate) algorithm: String,
+    pub(crate) created_at: u64,
+    pub(crate) not_after: Option<u64>,
+    pub(crate) key: SecureKey,
+}
+
+impl ManagedKey {
+    /// Wrap `key` with metadata; `not_after` is in seconds since the Unix epoch
+    pub fn new(id: &str, algorithm: &str, key: SecureKey, not_after: Option<u64>) -> CryptoResult<Self> {
+        let created_at = unix_now();
+        if not_after.is_some_and(|not_after| not_after <= created_at) {
+            return Err(CryptoError::InvalidInput(MANAGED_KEY_INVALID_EXPIRY));
+        }
+
+        Ok(Self {
+            id: id.to_string(),
+            algorithm: algorithm.to_string(),
+            created_at,
+            not_after,
+            key,
+        })
+    }
+
+    /// Generate a random 32-byte key that expires after `valid_for`
+    pub fn generate(id: &str, algorithm: &str, valid_for: Duration) -> CryptoResult<Self> {
+        let key = SecureRandom::generate_key(32)?;
+        let not_after = unix_now().saturating_add(valid_for.as_secs());
+        Self::new(id, algorithm, key, Some(not_after))
+    }
+
+    /// Get the key ID
+    #[inline]
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the algorithm label
+    #[inline]
+    pub fn algorithm(&self) -> &str {
+        &self.algorithm
+    }
+
+    /// Get the creation time, in seconds since the Unix epoch
+    #[inline]
+    pub fn created_at(&self) -> u64 {
+        self.created_at
+    }
+
+    /// Get the expiry time, in seconds since the Unix epoch, if any
+    #[inline]
+    pub fn not_after(&self) -> Option<u64> {
+        self.not_after
+    }
+
+    /// Check whether the key has expired
+    #[inline]
+    pub fn is_expired(&self) -> bool {
+        self.is_expired_at(unix_now())
+    }
+
+    /// Check whether the key is expired at `now` (seconds since the Unix epoch)
+    #[inline]
+    pub fn is_expired_at(&self, now: u64) -> bool {
+        self.not_after.is_some_and(|not_after| now >= not_after)
+    }
+
+    /// Get the key material
+    #[inline]
+    pub fn key(&self) -> &SecureKey {
+        &self.key
+    }
+
+    /// Encrypt with the key's algorithm, failing if the key has expired
+    #[inline]
+    pub fn encrypt(&self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.encrypt_with_aad(plaintext, &[])
+    }
+
+    /// Decrypt with the key's algorithm; expired keys may still decrypt
+    #[inline]
+    pub fn decrypt(&self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.decrypt_with_aad(ciphertext, &[])
+    }
+
+    /// Encrypt with the key's algorithm and associated data, failing if the key has expired
+    pub fn encrypt_with_aad(&self, plaintext: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.ensure_not_expired()?;
+        self.cipher()?.encrypt_with_aad(plaintext, self.key.as_bytes(), aad)
+    }
+
+    /// Decrypt with the key's algorithm and associated data; expired keys may still decrypt
+    pub fn decrypt_with_aad(&self, ciphertext: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.cipher()?.decrypt_with_aad(ciphertext, self.key.as_bytes(), aad)
+    }
+
+    /// Decrypt with the key's algorithm, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(&self, ciphertext: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.decrypt_with_aad_zeroizing(ciphertext, &[])
+    }
+
+    /// Decrypt with the key's algorithm and associated data, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_with_aad_zeroizing(&self, ciphertext: &[u8], aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.cipher()?.decrypt_with_aad_zeroizing(ciphertext, self.key.as_bytes(), aad)
+    }
+
+    #[inline]
+    fn cipher(&self) -> CryptoResult<&'static dyn Aead> {
+        Algorithm::from_name(&self.algorithm)
+            .and_then(Algorithm::aead)
+            .map_err(|_| CryptoError::InvalidKey(MANAGED_KEY_UNSUPPORTED_ALGORITHM))
+    }
+
+    #[inline]
+    fn ensure_not_expired(&self) -> CryptoResult<()> {
+        if self.is_expired() {
+            return Err(CryptoError::InvalidKey(MANAGED_KEY_EXPIRED));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::keystore::Keystore;
+
+    #[test]
+    fn test_managed_key_expiry() {
+        let key = ManagedKey::generate("data-2024", ALGORITHM_AES_256_GCM, Duration::from_secs(3600)).unwrap();
+        assert!(!key.is_expired());
+        assert!(key.is_expired_at(key.not_after().unwrap()));
+
+        let ciphertext = key.encrypt(b"record").unwrap();
+        assert_eq!(key.decrypt(&ciphertext).unwrap(), b"record");
+
+        // Simulate the deadline passing: encryption is refused, decryption still works
+        let mut expired = key.clone();
+        expired.not_after = Some(key.created_at());
+        assert_eq!(expired.encrypt(b"record").unwrap_err(), CryptoError::InvalidKey(MANAGED_KEY_EXPIRED));
+        assert_eq!(expired.encrypt_with_aad(b"record", b"aad").unwrap_err(), CryptoError::InvalidKey(MANAGED_KEY_EXPIRED));
+        assert_eq!(expired.decrypt(&ciphertext).unwrap(), b"record");
+
+        assert!(ManagedKey::new("old", ALGORITHM_AES_256_GCM, SecureRandom::generate_key(32).unwrap(), Some(1)).is_err());
+        assert!(ManagedKey::new("x", "RSA", SecureRandom::generate_key(32).unwrap(), None).unwrap().encrypt(b"m").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "chacha")]
+    fn test_managed_key_keystore_roundtrip() {
+        let key = ManagedKey::generate("sessions", ALGORITHM_CHACHA20_POLY1305, Duration::from_secs(86400)).unwrap();
+        let ciphertext = key.encrypt(b"token").unwrap();
+
+        let mut keystore = Keystore::new();
+        keystore.add_managed(key.clone()).unwrap();
+        keystore.add("legacy", ALGORITHM_AES_256_GCM, SecureRandom::generate_key(32).unwrap()).unwrap();
+
+        let restored = Keystore::from_bytes(&keystore.to_bytes(b"pass").unwrap(), b"pass").unwrap();
+        let managed = restored.get_managed("sessions").unwrap();
+        assert_eq!(managed.id(), "sessions");
+        assert_eq!(managed.algorithm(), ALGORITHM_CHACHA20_POLY1305);
+        assert_eq!(managed.created_at(), key.created_at());
+        assert_eq!(managed.not_after(), key.not_after());
+        assert_eq!(managed.decrypt(&ciphertext).unwrap(), b"token");
+        let ciphertext = managed.encrypt_with_aad(b"token", b"user:42").unwrap();
+        assert_eq!(managed.decrypt_with_aad(&ciphertext, b"user:42").unwrap(), b"token");
+        assert!(managed.decrypt(&ciphertext).is_err());
+
+        assert_eq!(restored.get_managed("legacy").unwrap().not_after(), None);
+        assert!(restored.get_managed("missing").is_err());
+    }
+}
@@ -1,12 +1,183 @@
 pub mod symmetric;
+pub mod keys;
+pub mod nonce;
+#[cfg(feature = "aes")]
+pub mod stream;
+pub mod encryptor;
 pub mod asymmetric;
+#[cfg(feature = "secp256k1")]
+pub mod secp256k1;
+#[cfg(all(feature = "std", feature = "rsa"))]
+pub mod keypool;
+pub mod signer;
+pub mod verifier;
+pub mod registry;
+pub mod ecdh;
 pub mod hash;
+pub mod encoding;
 pub mod kdf;
+pub mod blind_index;
 pub mod random;
+pub mod wipe;
+#[cfg(all(feature = "std", feature = "aes", feature = "argon2"))]
+pub mod keystore;
+#[cfg(all(feature = "std", feature = "aes", feature = "argon2"))]
+pub mod managed;
+#[cfg(all(feature = "std", feature = "aes"))]
+pub mod keyring;
+#[cfg(all(feature = "std", feature = "aes", feature = "argon2", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub mod export;
+#[cfg(all(feature = "std", feature = "ecdsa", feature = "ed25519"))]
+pub mod keyformat;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub mod did;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub mod provider;
+#[cfg(feature = "pkcs11")]
+pub mod pkcs11;
+#[cfg(feature = "std")]
+pub mod keychain;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub mod hardware;
+#[cfg(feature = "aes")]
+pub mod envelope;
+pub mod armor;
+#[cfg(all(feature = "std", feature = "aes", feature = "ed25519"))]
+pub mod x3dh;
+#[cfg(all(feature = "std", feature = "aes", feature = "ed25519"))]
+pub mod ratchet;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub mod jose;
+#[cfg(all(feature = "std", feature = "chacha", feature = "ed25519"))]
+pub mod paseto;
+#[cfg(feature = "std")]
+pub mod otp;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa"))]
+pub mod pkcs7;
+#[cfg(all(feature = "std", feature = "ecdsa", feature = "ed25519"))]
+pub mod webauthn;
+pub mod nacl;
+#[cfg(feature = "chacha")]
+pub mod secretstream;
+#[cfg(feature = "openssl-enc")]
+pub mod openssl_enc;
+#[cfg(all(feature = "std", feature = "aes", feature = "ed25519"))]
+pub mod secure_message;
+#[cfg(all(feature = "std", feature = "aes", feature = "chacha", feature = "ecdsa", feature = "ed25519", feature = "blake3", feature = "argon2"))]
+pub mod test_vectors;
+// Also built for the crate's own tests, which run the vendored upstream vectors
+#[cfg(any(feature = "wycheproof", all(test, feature = "std", feature = "full")))]
+pub mod wycheproof;
+#[cfg(feature = "serde")]
+pub mod serialization;
+#[cfg(feature = "threshold")]
+pub mod threshold;
+mod wordlist;
 
 // Re-export commonly used types and functions
-pub use symmetric::{AesGcm, ChaCha20Poly1305Cipher};
-pub use asymmetric::{RsaCrypto, EcdsaCrypto, Ed25519Crypto, RsaKeyPair, EcdsaKeyPair, Ed25519KeyPair};
-pub use hash::{Sha256Hash, Sha512Hash, Blake3Hash, Hmac};
-pub use kdf::{Argon2Kdf, HkdfKdf, Pbkdf2Kdf, SecureKeyDerivation};
-pub use random::{SecureRandom, SecureKey};
\ No newline at end of file
+pub use symmetric::{Aead, Limits};
+#[cfg(feature = "aes")]
+pub use symmetric::{AesGcm, AesGcmContext, WebCryptoAesGcm};
+#[cfg(feature = "chacha")]
+pub use symmetric::ChaCha20Poly1305Cipher;
+pub use keys::{Aes256Key, ChaChaKey, HmacKey, AeadKey};
+pub use nonce::{Nonce, AesGcmNonce, ChaChaNonce, XSalsaNonce};
+#[cfg(feature = "nonce-tracking")]
+pub use nonce::NonceGuard;
+pub use encryptor::{Encryptor, Decryptor, NonceStrategy};
+#[cfg(feature = "aes")]
+pub use stream::{AesGcmStreamEncryptor, AesGcmStreamDecryptor, STREAM_CHUNK_SIZE, STREAM_HEADER_SIZE};
+#[cfg(all(feature = "aes", feature = "std"))]
+pub use stream::StreamPipelineOptions;
+pub use asymmetric::KeyValidation;
+#[cfg(feature = "rsa")]
+pub use asymmetric::{RsaCrypto, RsaKeyPair, RsaKeyGenOptions, RsaSignaturePadding, RsaSignatureHash};
+#[cfg(all(feature = "std", feature = "rsa"))]
+pub use keypool::{RsaKeyPool, RsaKeyPoolConfig};
+#[cfg(feature = "ecdsa")]
+pub use asymmetric::{EcdsaCrypto, EcdsaKeyPair};
+#[cfg(feature = "ed25519")]
+pub use asymmetric::{Ed25519Crypto, Ed25519KeyPair};
+#[cfg(feature = "secp256k1")]
+pub use secp256k1::{Secp256k1Crypto, Secp256k1KeyPair, RECOVERABLE_SIGNATURE_LEN};
+pub use signer::{Signer, Verifier, SignatureAlgorithm};
+#[cfg(feature = "ed25519")]
+pub use verifier::Ed25519VerifierContext;
+#[cfg(feature = "ecdsa")]
+pub use verifier::EcdsaVerifierContext;
+pub use registry::Algorithm;
+pub use ecdh::{Ecdh, EcdhCurve, EcdhKeyPair};
+#[cfg(feature = "aes")]
+pub use ecdh::Ecies;
+pub use encoding::{Base64Variant, Base32Variant, encode_hex, decode_hex, encode_base64, decode_base64, encode_base32, decode_base32, encode_hex_ct, decode_hex_ct, encode_base64_ct, decode_base64_ct, encode_base32_ct, decode_base32_ct, encode_hex_into, decode_hex_into, encode_base64_into, decode_base64_into, hex_encoded_len, base64_encoded_len, base32_encoded_len, encode_base58, decode_base58, encode_base58check, decode_base58check, Bech32Variant, encode_bech32, decode_bech32, decode_bech32_with_hrp};
+pub use hash::{Sha256Hash, Sha512Hash, Sha3_256Hash, Sha3_512Hash, Hmac, StreamingHasher, ConstantTime};
+#[cfg(feature = "std")]
+pub use hash::DEFAULT_HASH_CHUNK_SIZE;
+#[cfg(feature = "blake3")]
+pub use hash::Blake3Hash;
+pub use kdf::{HkdfKdf, Pbkdf2Kdf, KbkdfKdf, ConcatKdf, X963Kdf, SecureKeyDerivation};
+pub use blind_index::{BlindIndex, IndexNormalization};
+#[cfg(feature = "argon2")]
+pub use kdf::{Argon2Kdf, Argon2Context, Argon2Variant};
+#[cfg(feature = "scrypt")]
+pub use kdf::ScryptKdf;
+pub use random::{SecureRandom, SecureRng, SecureKey, Charset};
+pub use wipe::{secure_wipe, SecureVec};
+#[cfg(feature = "std")]
+pub use wipe::wipe_file;
+#[cfg(all(feature = "std", feature = "aes", feature = "argon2"))]
+pub use keystore::{Keystore, KeyEntry};
+#[cfg(all(feature = "std", feature = "aes", feature = "argon2"))]
+pub use managed::{ManagedKey, ALGORITHM_AES_256_GCM, ALGORITHM_CHACHA20_POLY1305};
+#[cfg(all(feature = "std", feature = "aes"))]
+pub use keyring::KeyRing;
+#[cfg(all(feature = "std", feature = "aes", feature = "argon2", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub use export::{KeyExport, ExportableKey, ExportKeyType};
+#[cfg(all(feature = "std", feature = "ecdsa", feature = "ed25519"))]
+pub use keyformat::{AsymmetricKey, KeyAlgorithm};
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub use did::DidKey;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub use provider::KeyProvider;
+#[cfg(feature = "pkcs11")]
+pub use pkcs11::Pkcs11Provider;
+#[cfg(feature = "std")]
+pub use keychain::{KeychainStore, MemoryKeychain};
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub use hardware::{PlatformKeystore, HardwareKeyProvider};
+#[cfg(feature = "aes")]
+pub use envelope::{Envelope, EnvelopeHeader, EnvelopePadding, KekSource};
+#[cfg(all(feature = "std", feature = "aes", feature = "ed25519"))]
+pub use x3dh::{X3dh, X3dhSignedPrekey, X3dhOneTimePrekey, X3dhPrekeyBundle, X3dhInitialMessage, X3dhOutput};
+#[cfg(all(feature = "std", feature = "aes", feature = "ed25519"))]
+pub use ratchet::DoubleRatchet;
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub use jose::{Jwt, JwtAlgorithm, JwtClaims, JwtSigningKey, JwtVerifyingKey, JwtValidation, Jws, JwsOptions, JwsValidation, Jwk, JwkSet};
+#[cfg(all(feature = "std", feature = "chacha", feature = "ed25519"))]
+pub use paseto::{Paseto, PasetoClaims};
+#[cfg(feature = "std")]
+pub use otp::{Otp, OtpAlgorithm, OtpConfig};
+#[cfg(all(feature = "std", feature = "rsa", feature = "ecdsa"))]
+pub use pkcs7::{CmsSignedData, CmsSigningKey};
+#[cfg(all(feature = "std", feature = "ecdsa", feature = "ed25519"))]
+pub use webauthn::{WebAuthn, WebAuthnAssertion, WebAuthnExpectations, WebAuthnPublicKey, WebAuthnVerifiedAssertion};
+pub use nacl::{BoxKeyPair, SealedBox, CryptoBox, SecretBox};
+#[cfg(feature = "chacha")]
+pub use secretstream::{SecretStreamPush, SecretStreamPull, SecretStreamTag, SECRETSTREAM_KEY_LEN, SECRETSTREAM_HEADER_LEN, SECRETSTREAM_OVERHEAD};
+#[cfg(feature = "openssl-enc")]
+pub use openssl_enc::{OpenSslEnc, OpenSslKdf};
+#[cfg(all(feature = "std", feature = "aes", feature = "ed25519"))]
+pub use secure_message::SecureMessage;
+#[cfg(all(feature = "std", feature = "aes", feature = "chacha", feature = "ecdsa", feature = "ed25519", feature = "blake3", feature = "argon2"))]
+pub use test_vectors::{TestVectors, TestVector, TestVectorFailure};
+#[cfg(feature = "wycheproof")]
+pub use wycheproof::{Wycheproof, WycheproofReport, WycheproofFailure, WYCHEPROOF_FILES, WYCHEPROOF_VENDORED_FILES};
+#[cfg(feature = "serde")]
+pub use serialization::IncludeSecrets;
+pub use armor::{Armor, ArmorKind, Armored};
+#[cfg(feature = "kms")]
+pub use envelope::RemoteKms;
+#[cfg(any(feature = "keychain-macos", feature = "keychain-windows", feature = "keychain-linux"))]
+pub use keychain::OsKeychain;
+#[cfg(feature = "threshold")]
+pub use threshold::{FrostThreshold, FrostKeyPackage, FrostPublicKeyPackage, FrostCommitments, FrostSigningNonces, FrostSigningPackage, FrostSignatureShare, FrostIdentifier, FrostDkgRound1Secret, FrostDkgRound1Package, FrostDkgRound2Secret, FrostDkgRound2Package};
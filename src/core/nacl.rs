@@ -0,0 +1,316 @@
+use crate::error::{CryptoError, CryptoResult, NACL_INVALID_PUBLIC_KEY, NACL_INVALID_SECRET_KEY, NACL_INVALID_NONCE, SEALED_BOX_ENCRYPTION_FAILED, SEALED_BOX_DECRYPTION_FAILED, CRYPTO_BOX_ENCRYPTION_FAILED, CRYPTO_BOX_DECRYPTION_FAILED, SECRETBOX_INVALID_KEY, SECRETBOX_ENCRYPTION_FAILED, SECRETBOX_DECRYPTION_FAILED};
+use crate::core::random::{SecureKey, SecureRandom};
+use crypto_box::aead::{Aead, KeyInit};
+use crypto_box::{Nonce, PublicKey, SalsaBox, SecretKey};
+use crypto_secretbox::{Key as SecretBoxKey, XSalsa20Poly1305};
+use rand::rngs::OsRng;
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+/// X25519 public key length (`crypto_box_PUBLICKEYBYTES`)
+pub const NACL_PUBLIC_KEY_LEN: usize = 32;
+
+/// X25519 secret key length (`crypto_box_SECRETKEYBYTES`)
+pub const NACL_SECRET_KEY_LEN: usize = 32;
+
+/// Nonce length for box and secretbox (`crypto_box_NONCEBYTES`)
+pub const NACL_NONCE_LEN: usize = 24;
+
+/// Secretbox key length (`crypto_secretbox_KEYBYTES`)
+pub const SECRETBOX_KEY_LEN: usize = 32;
+
+/// Poly1305 tag length prepended to box and secretbox ciphertexts (`crypto_box_MACBYTES`)
+pub const NACL_TAG_LEN: usize = 16;
+
+/// Sealed box overhead: ephemeral public key plus Poly1305 tag (`crypto_box_SEALBYTES`)
+pub const SEALED_BOX_OVERHEAD: usize = 48;
+
+/// X25519 key pair compatible with libsodium's `crypto_box_keypair`
+#[derive(Clone)]
+pub struct BoxKeyPair {
+    secret_key: SecureKey,
+    public_key: [u8; NACL_PUBLIC_KEY_LEN],
+}
+
+impl BoxKeyPair {
+    /// Generate a new key pair
+    #[inline]
+    pub fn generate() -> CryptoResult<Self> {
+        Self::from_secret_key(SecureRandom::generate_key(NACL_SECRET_KEY_LEN)?.as_bytes())
+    }
+
+    /// Restore a key pair from a 32-byte secret key
+    pub fn from_secret_key(secret_key: &[u8]) -> CryptoResult<Self> {
+        let public_key = secret_key_from_bytes(secret_key)?.public_key().to_bytes();
+        Ok(Self {
+            secret_key: SecureKey::new(secret_key.to_vec()),
+            public_key,
+        })
+    }
+
+    /// Get the public key
+    #[inline]
+    pub fn public_key(&self) -> &[u8; NACL_PUBLIC_KEY_LEN] {
+        &self.public_key
+    }
+
+    /// Get the secret key
+    #[inline]
+    pub fn secret_key(&self) -> &SecureKey {
+        &self.secret_key
+    }
+}
+
+/// Anonymous public-key encryption matching libsodium's `crypto_box_seal`
+///
+/// Output is `ephemeral_pk || crypto_box(plaintext)` with the nonce derived
+/// as BLAKE2b-192(`ephemeral_pk || recipient_pk`).
+pub struct SealedBox;
+
+impl SealedBox {
+    /// Encrypt `plaintext` to a recipient's X25519 public key
+    pub fn seal(recipient_public_key: &[u8], plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        public_key_from_bytes(recipient_public_key)?
+            .seal(&mut OsRng, plaintext)
+            .map_err(|_| CryptoError::EncryptionFailed(SEALED_BOX_ENCRYPTION_FAILED))
+    }
+
+    /// Decrypt a sealed box with the recipient's X25519 secret key
+    pub fn open(ciphertext: &[u8], recipient_secret_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        if ciphertext.len() < SEALED_BOX_OVERHEAD {
+            return Err(CryptoError::DecryptionFailed(SEALED_BOX_DECRYPTION_FAILED));
+        }
+        secret_key_from_bytes(recipient_secret_key)?
+            .unseal(ciphertext)
+            .map_err(|_| CryptoError::DecryptionFailed(SEALED_BOX_DECRYPTION_FAILED))
+    }
+
+    /// Decrypt a sealed box, zeroizing the plaintext on drop
+    #[inline]
+    pub fn open_zeroizing(ciphertext: &[u8], recipient_secret_key: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::open(ciphertext, recipient_secret_key).map(Zeroizing::new)
+    }
+}
+
+/// Authenticated public-key encryption matching NaCl/libsodium `crypto_box`
+///
+/// X25519 + XSalsa20-Poly1305. `*_with_nonce` produce exactly the
+/// `crypto_box_easy` / tweetnacl `nacl.box` output (tag || ciphertext).
+pub struct CryptoBox;
+
+impl CryptoBox {
+    /// Generate a random 24-byte nonce
+    #[inline]
+    pub fn generate_nonce() -> CryptoResult<Vec<u8>> {
+        SecureRandom::generate_nonce(NACL_NONCE_LEN)
+    }
+
+    /// Encrypt from sender to recipient with a random nonce
+    /// Returns: nonce (24 bytes) + tag + ciphertext
+    pub fn encrypt(plaintext: &[u8], recipient_public_key: &[u8], sender_secret_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let nonce = Self::generate_nonce()?;
+        let ciphertext = Self::encrypt_with_nonce(plaintext, &nonce, recipient_public_key, sender_secret_key)?;
+        Ok([nonce, ciphertext].concat())
+    }
+
+    /// Decrypt a message from [`CryptoBox::encrypt`]
+    /// Input format: nonce (24 bytes) + tag + ciphertext
+    pub fn decrypt(ciphertext_with_nonce: &[u8], sender_public_key: &[u8], recipient_secret_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        if ciphertext_with_nonce.len() < NACL_NONCE_LEN + NACL_TAG_LEN {
+            return Err(CryptoError::DecryptionFailed(CRYPTO_BOX_DECRYPTION_FAILED));
+        }
+        let (nonce, ciphertext) = ciphertext_with_nonce.split_at(NACL_NONCE_LEN);
+        Self::decrypt_with_nonce(ciphertext, nonce, sender_public_key, recipient_secret_key)
+    }
+
+    /// Decrypt a message from [`CryptoBox::encrypt`], zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(ciphertext_with_nonce: &[u8], sender_public_key: &[u8], recipient_secret_key: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(ciphertext_with_nonce, sender_public_key, recipient_secret_key).map(Zeroizing::new)
+    }
+
+    /// `crypto_box_easy`: encrypt with a caller-supplied nonce that must never repeat for a key pair
+    pub fn encrypt_with_nonce(plaintext: &[u8], nonce: &[u8], recipient_public_key: &[u8], sender_secret_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let nonce = nonce_from_bytes(nonce)?;
+        SalsaBox::new(&public_key_from_bytes(recipient_public_key)?, &secret_key_from_bytes(sender_secret_key)?)
+            .encrypt(nonce, plaintext)
+            .map_err(|_| CryptoError::EncryptionFailed(CRYPTO_BOX_ENCRYPTION_FAILED))
+    }
+
+    /// `crypto_box_open_easy`: decrypt tag + ciphertext with the given nonce
+    pub fn decrypt_with_nonce(ciphertext: &[u8], nonce: &[u8], sender_public_key: &[u8], recipient_secret_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let nonce = nonce_from_bytes(nonce)?;
+        SalsaBox::new(&public_key_from_bytes(sender_public_key)?, &secret_key_from_bytes(recipient_secret_key)?)
+            .decrypt(nonce, ciphertext)
+            .map_err(|_| CryptoError::DecryptionFailed(CRYPTO_BOX_DECRYPTION_FAILED))
+    }
+}
+
+/// Secret-key authenticated encryption matching NaCl/libsodium `crypto_secretbox`
+///
+/// XSalsa20-Poly1305. `*_with_nonce` produce exactly the
+/// `crypto_secretbox_easy` / tweetnacl `nacl.secretbox` output (tag || ciphertext).
+pub struct SecretBox;
+
+impl SecretBox {
+    /// Generate a new secretbox key (32 bytes)
+    #[inline]
+    pub fn generate_key() -> CryptoResult<Vec<u8>> {
+        SecureRandom::generate_bytes(SECRETBOX_KEY_LEN)
+    }
+
+    /// Generate a random 24-byte nonce
+    #[inline]
+    pub fn generate_nonce() -> CryptoResult<Vec<u8>> {
+        SecureRandom::generate_nonce(NACL_NONCE_LEN)
+    }
+
+    /// Encrypt with a random nonce
+    /// Returns: nonce (24 bytes) + tag + ciphertext
+    pub fn encrypt(plaintext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
+        let nonce = Self::generate_nonce()?;
+        let ciphertext = Self::encrypt_with_nonce(plaintext, key, &nonce)?;
+        Ok([nonce, ciphertext].concat())
+    }
+
+    /// Decrypt a message from [`SecretBox::encrypt`]
+    /// Input format: nonce (24 bytes) + tag + ciphertext
+    pub fn decrypt(ciphertext_with_nonce: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
+        if ciphertext_with_nonce.len() < NACL_NONCE_LEN + NACL_TAG_LEN {
+            return Err(CryptoError::DecryptionFailed(SECRETBOX_DECRYPTION_FAILED));
+        }
+        let (nonce, ciphertext) = ciphertext_with_nonce.split_at(NACL_NONCE_LEN);
+        Self::decrypt_with_nonce(ciphertext, key, nonce)
+    }
+
+    /// Decrypt a message from [`SecretBox::encrypt`], zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(ciphertext_with_nonce: &[u8], key: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(ciphertext_with_nonce, key).map(Zeroizing::new)
+    }
+
+    /// `crypto_secretbox_easy`: encrypt with a caller-supplied nonce that must never repeat for a key
+    pub fn encrypt_with_nonce(plaintext: &[u8], key: &[u8], nonce: &[u8]) -> CryptoResult<Vec<u8>> {
+        let nonce = nonce_from_bytes(nonce)?;
+        Self::cipher(key)?
+            .encrypt(nonce, plaintext)
+            .map_err(|_| CryptoError::EncryptionFailed(SECRETBOX_ENCRYPTION_FAILED))
+    }
+
+    /// `crypto_secretbox_open_easy`: decrypt tag + ciphertext with the given nonce
+    pub fn decrypt_with_nonce(ciphertext: &[u8], key: &[u8], nonce: &[u8]) -> CryptoResult<Vec<u8>> {
+        let nonce = nonce_from_bytes(nonce)?;
+        Self::cipher(key)?
+            .decrypt(nonce, ciphertext)
+            .map_err(|_| CryptoError::DecryptionFailed(SECRETBOX_DECRYPTION_FAILED))
+    }
+
+    #[inline]
+    fn cipher(key: &[u8]) -> CryptoResult<XSalsa20Poly1305> {
+        if key.len() != SECRETBOX_KEY_LEN {
+            return Err(CryptoError::InvalidKey(SECRETBOX_INVALID_KEY));
+        }
+        Ok(XSalsa20Poly1305::new(SecretBoxKey::from_slice(key)))
+    }
+}
+
+#[inline]
+fn nonce_from_bytes(bytes: &[u8]) -> CryptoResult<&Nonce> {
+    if bytes.len() != NACL_NONCE_LEN {
+        return Err(CryptoError::InvalidInput(NACL_INVALID_NONCE));
+    }
+    Ok(Nonce::from_slice(bytes))
+}
+
+#[inline]
+fn public_key_from_bytes(bytes: &[u8]) -> CryptoResult<PublicKey> {
+    PublicKey::from_slice(bytes).map_err(|_| CryptoError::InvalidKey(NACL_INVALID_PUBLIC_KEY))
+}
+
+#[inline]
+fn secret_key_from_bytes(bytes: &[u8]) -> CryptoResult<SecretKey> {
+    SecretKey::from_slice(bytes).map_err(|_| CryptoError::InvalidKey(NACL_INVALID_SECRET_KEY))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // crypto_box_seal output from libsodium for the secret key 0x01..=0x20
+    const LIBSODIUM_SECRET_KEY: [u8; 32] = [
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+        17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+    ];
+    const LIBSODIUM_PUBLIC_KEY: &str = "07a37cbc142093c8b755dc1b10e86cb426374ad16aa853ed0bdfc0b2b86d1c7c";
+    const LIBSODIUM_SEALED: &str = "ab6c1f6f7c9d9afefd6c31063c3dc253d7381d4bb1dacd31e9745f974dd59e1d3a859f4af8f082cd3c8263112b2787b374fe96e78288b7a97d435276c75dfa30e0ec11f9";
+
+    // crypto_box_easy / crypto_secretbox_easy output from libsodium with nonce 0x00..=0x17
+    const LIBSODIUM_BOX: &str = "93c62e2cf8fd33d8940352374f2122c1709aa3793c4f12bfd99e28618c2d75d40067";
+    const LIBSODIUM_SECRETBOX: &str = "512752e777be46fe8ce100bfbf22d5b0c15b626e904854c5bc6d21e76a886177ab164bb1806b00c5";
+
+    #[test]
+    fn test_sealed_box_roundtrip() {
+        let recipient = BoxKeyPair::generate().unwrap();
+        let plaintext = b"anonymous message";
+
+        let sealed = SealedBox::seal(recipient.public_key(), plaintext).unwrap();
+        assert_eq!(sealed.len(), plaintext.len() + SEALED_BOX_OVERHEAD);
+        assert_eq!(SealedBox::open(&sealed, recipient.secret_key().as_bytes()).unwrap(), plaintext);
+        assert_eq!(SealedBox::open_zeroizing(&sealed, recipient.secret_key().as_bytes()).unwrap().as_slice(), plaintext);
+
+        // Each seal uses a fresh ephemeral key
+        assert_ne!(sealed, SealedBox::seal(recipient.public_key(), plaintext).unwrap());
+
+        let other = BoxKeyPair::generate().unwrap();
+        assert!(SealedBox::open(&sealed, other.secret_key().as_bytes()).is_err());
+        assert!(SealedBox::open(&sealed[..SEALED_BOX_OVERHEAD - 1], recipient.secret_key().as_bytes()).is_err());
+        assert!(SealedBox::seal(&[0u8; 31], plaintext).is_err());
+    }
+
+    #[test]
+    fn test_sealed_box_libsodium_vector() {
+        let keypair = BoxKeyPair::from_secret_key(&LIBSODIUM_SECRET_KEY).unwrap();
+        assert_eq!(hex::encode(keypair.public_key()), LIBSODIUM_PUBLIC_KEY);
+
+        let sealed = hex::decode(LIBSODIUM_SEALED).unwrap();
+        assert_eq!(SealedBox::open(&sealed, &LIBSODIUM_SECRET_KEY).unwrap(), b"hello from libsodium");
+    }
+
+    #[test]
+    fn test_crypto_box_libsodium_vector() {
+        let alice = BoxKeyPair::from_secret_key(&LIBSODIUM_SECRET_KEY).unwrap();
+        let bob_secret: Vec<u8> = (33..=64).collect();
+        let bob = BoxKeyPair::from_secret_key(&bob_secret).unwrap();
+        let nonce: Vec<u8> = (0..24).collect();
+
+        let boxed = CryptoBox::encrypt_with_nonce(b"box from libsodium", &nonce, bob.public_key(), alice.secret_key().as_bytes()).unwrap();
+        assert_eq!(hex::encode(&boxed), LIBSODIUM_BOX);
+        assert_eq!(CryptoBox::decrypt_with_nonce(&boxed, &nonce, alice.public_key(), &bob_secret).unwrap(), b"box from libsodium");
+
+        let key: Vec<u8> = (100..132).collect();
+        let secretbox = SecretBox::encrypt_with_nonce(b"secretbox from libsodium", &key, &nonce).unwrap();
+        assert_eq!(hex::encode(&secretbox), LIBSODIUM_SECRETBOX);
+        assert_eq!(SecretBox::decrypt_with_nonce(&secretbox, &key, &nonce).unwrap(), b"secretbox from libsodium");
+    }
+
+    #[test]
+    fn test_crypto_box_and_secretbox_roundtrip() {
+        let alice = BoxKeyPair::generate().unwrap();
+        let bob = BoxKeyPair::generate().unwrap();
+        let message = CryptoBox::encrypt(b"hi bob", bob.public_key(), alice.secret_key().as_bytes()).unwrap();
+        assert_eq!(message.len(), NACL_NONCE_LEN + NACL_TAG_LEN + 6);
+        assert_eq!(CryptoBox::decrypt(&message, alice.public_key(), bob.secret_key().as_bytes()).unwrap(), b"hi bob");
+
+        let mallory = BoxKeyPair::generate().unwrap();
+        assert!(CryptoBox::decrypt(&message, mallory.public_key(), bob.secret_key().as_bytes()).is_err());
+        assert!(CryptoBox::decrypt(&message[..NACL_NONCE_LEN + NACL_TAG_LEN - 1], alice.public_key(), bob.secret_key().as_bytes()).is_err());
+
+        let key = SecretBox::generate_key().unwrap();
+        let mut message = SecretBox::encrypt(b"secret", &key).unwrap();
+        assert_eq!(SecretBox::decrypt(&message, &key).unwrap(), b"secret");
+        message[NACL_NONCE_LEN] ^= 1;
+        assert_eq!(SecretBox::decrypt(&message, &key).unwrap_err(), CryptoError::DecryptionFailed(SECRETBOX_DECRYPTION_FAILED));
+        assert_eq!(SecretBox::encrypt(b"m", &key[..16]).unwrap_err(), CryptoError::InvalidKey(SECRETBOX_INVALID_KEY));
+        assert_eq!(SecretBox::encrypt_with_nonce(b"m", &key, &[0u8; 12]).unwrap_err(), CryptoError::InvalidInput(NACL_INVALID_NONCE));
+    }
+}
@@ -0,0 +1,303 @@
+use crate::error::{CryptoError, CryptoResult, NONCE_LENGTH_MISMATCH};
+use crate::core::encryptor::NonceStrategy;
+use crate::core::encoding::encode_hex;
+use crate::core::random::SecureRandom;
+#[cfg(feature = "nonce-tracking")]
+use crate::error::{NONCE_REUSED, NONCE_GUARD_CAPACITY_ZERO};
+#[cfg(feature = "nonce-tracking")]
+use sha2::{Digest, Sha256};
+#[cfg(feature = "nonce-tracking")]
+use std::cell::RefCell;
+#[cfg(feature = "nonce-tracking")]
+use std::collections::{HashSet, VecDeque};
+
+/// A nonce of exactly `N` bytes
+///
+/// APIs that take a `Nonce<12>` say how long the nonce must be in their
+/// signature, so a wrong-length buffer is rejected once, at construction,
+/// instead of by every cipher call. A `Nonce` is deliberately not `Copy`:
+/// the typed-key functions take it by value, so reusing one with the same
+/// key needs an explicit `clone()`.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let key = Aes256Key::generate()?;
+/// let ciphertext = key.encrypt_with_nonce(b"message", AesGcmNonce::from_counter(7), b"")?;
+/// assert_eq!(ciphertext[..12], [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7]);
+/// assert_eq!(key.decrypt(&ciphertext)?, b"message");
+/// # Ok::<(), CryptoError>(())
+/// ```
+///
+/// A nonce too short for a 64-bit counter cannot be built from one:
+///
+/// ```compile_fail
+/// use libsilver::prelude::*;
+///
+/// let nonce = Nonce::<4>::from_counter(1);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Nonce<const N: usize>([u8; N]);
+
+/// Nonce of AES-256-GCM
+pub type AesGcmNonce = Nonce<12>;
+
+/// Nonce of ChaCha20-Poly1305
+pub type ChaChaNonce = Nonce<12>;
+
+/// Nonce of NaCl box and secretbox (XSalsa20-Poly1305)
+pub type XSalsaNonce = Nonce<24>;
+
+impl<const N: usize> Nonce<N> {
+    /// Nonce size in bytes
+    pub const SIZE: usize = N;
+
+    // Referenced by `from_counter` so a nonce too short for the counter fails to compile
+    const FITS_COUNTER: () = assert!(N >= 8, "nonce is too short to hold a 64-bit counter");
+
+    /// Generate a random nonce
+    ///
+    /// Random 96-bit nonces are safe for up to 2^32 messages per key.
+    #[inline]
+    pub fn random() -> CryptoResult<Self> {
+        let mut bytes = [0u8; N];
+        SecureRandom::fill(&mut bytes)?;
+        Ok(Self(bytes))
+    }
+
+    /// Build the nonce for message number `counter`: zero bytes followed by
+    /// the 64-bit big-endian counter
+    ///
+    /// Counter nonces never repeat as long as each counter value is used
+    /// once per key; keep the counter with the key, not per process.
+    #[inline]
+    pub fn from_counter(counter: u64) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::FITS_COUNTER;
+        let mut bytes = [0u8; N];
+        bytes[N - 8..].copy_from_slice(&counter.to_be_bytes());
+        Self(bytes)
+    }
+
+    /// Use an explicit nonce, which must be exactly `N` bytes
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        bytes.try_into()
+            .map(Self)
+            .map_err(|_| CryptoError::InvalidInput(NONCE_LENGTH_MISMATCH))
+    }
+
+    /// Get the nonce bytes
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Unwrap into the nonce bytes
+    #[inline]
+    pub fn into_bytes(self) -> [u8; N] {
+        self.0
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for Nonce<N> {
+    #[inline]
+    fn from(bytes: [u8; N]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for Nonce<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> From<Nonce<N>> for NonceStrategy {
+    /// A one-shot [`NonceStrategy::Explicit`] nonce
+    #[inline]
+    fn from(nonce: Nonce<N>) -> Self {
+        NonceStrategy::Explicit(nonce.0.to_vec())
+    }
+}
+
+// Nonces are public, so unlike keys they print in full
+impl<const N: usize> core::fmt::Debug for Nonce<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Nonce<{N}>({})", encode_hex(&self.0))
+    }
+}
+
+// Truncated salted SHA-256 of a key, so the guard never holds key material
+#[cfg(feature = "nonce-tracking")]
+type KeyFingerprint = [u8; 16];
+
+/// Detector for (key, nonce) pairs used twice
+///
+/// Remembers the last `capacity` pairs it was shown and rejects a repeat
+/// with [`NONCE_REUSED`]. Keys are recorded as salted fingerprints, never
+/// stored. Pairs older than the window are forgotten, so this catches bugs
+/// (a counter that resets, a hardcoded nonce) rather than proving uniqueness.
+///
+/// Call [`NonceGuard::check`] directly, or [`NonceGuard::install`] it on the
+/// current thread: in debug builds every caller-chosen-nonce encryption
+/// ([`Aead::seal_with_nonce`](crate::core::symmetric::Aead::seal_with_nonce),
+/// `AesGcm::encrypt_with_nonce`, typed-key `encrypt_with_nonce` and
+/// [`Encryptor`](crate::core::encryptor::Encryptor) explicit and counter
+/// nonces) is then checked before sealing. Release builds compile the hook
+/// out. Random nonces are not tracked.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let key = Aes256Key::generate()?;
+/// NonceGuard::new(1024)?.install();
+/// key.encrypt_with_nonce(b"first", AesGcmNonce::from_counter(1), b"")?;
+/// # #[cfg(debug_assertions)]
+/// assert!(key.encrypt_with_nonce(b"second", AesGcmNonce::from_counter(1), b"").is_err());
+/// NonceGuard::uninstall();
+/// # Ok::<(), CryptoError>(())
+/// ```
+#[cfg(feature = "nonce-tracking")]
+pub struct NonceGuard {
+    salt: [u8; 16],
+    capacity: usize,
+    seen: HashSet<(KeyFingerprint, Vec<u8>)>,
+    order: VecDeque<(KeyFingerprint, Vec<u8>)>,
+}
+
+#[cfg(feature = "nonce-tracking")]
+thread_local! {
+    static INSTALLED: RefCell<Option<NonceGuard>> = const { RefCell::new(None) };
+}
+
+#[cfg(feature = "nonce-tracking")]
+impl NonceGuard {
+    /// A guard remembering the last `capacity` pairs
+    pub fn new(capacity: usize) -> CryptoResult<Self> {
+        if capacity == 0 {
+            return Err(CryptoError::InvalidInput(NONCE_GUARD_CAPACITY_ZERO));
+        }
+        let mut salt = [0u8; 16];
+        SecureRandom::fill(&mut salt)?;
+        Ok(Self { salt, capacity, seen: HashSet::new(), order: VecDeque::new() })
+    }
+
+    /// Record that `nonce` is used with `key`, failing if the pair was seen before
+    pub fn check(&mut self, key: &[u8], nonce: &[u8]) -> CryptoResult<()> {
+        let mut fingerprint = [0u8; 16];
+        fingerprint.copy_from_slice(&Sha256::new().chain_update(self.salt).chain_update(key).finalize()[..16]);
+        let entry = (fingerprint, nonce.to_vec());
+
+        if self.seen.contains(&entry) {
+            return Err(CryptoError::InvalidInput(NONCE_REUSED));
+        }
+        if self.order.len() == self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+        self.seen.insert(entry.clone());
+        self.order.push_back(entry);
+        Ok(())
+    }
+
+    /// Number of pairs remembered
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether no pair is remembered
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    /// Forget every pair
+    pub fn clear(&mut self) {
+        self.seen.clear();
+        self.order.clear();
+    }
+
+    /// Check caller-chosen nonces on this thread against this guard, replacing any installed one
+    pub fn install(self) {
+        INSTALLED.with(|installed| *installed.borrow_mut() = Some(self));
+    }
+
+    /// Stop checking on this thread, returning the installed guard
+    pub fn uninstall() -> Option<NonceGuard> {
+        INSTALLED.with(|installed| installed.borrow_mut().take())
+    }
+}
+
+// Hook for the caller-chosen-nonce cipher paths
+#[cfg(all(feature = "nonce-tracking", debug_assertions))]
+pub(crate) fn track_nonce(key: &[u8], nonce: &[u8]) -> CryptoResult<()> {
+    INSTALLED.with(|installed| match installed.borrow_mut().as_mut() {
+        Some(guard) => guard.check(key, nonce),
+        None => Ok(()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nonce_constructors() {
+        assert_eq!(AesGcmNonce::SIZE, 12);
+        assert_ne!(AesGcmNonce::random().unwrap(), AesGcmNonce::random().unwrap());
+
+        let nonce = XSalsaNonce::from_counter(0x0102);
+        assert_eq!(nonce.as_bytes()[..22], [0u8; 22]);
+        assert_eq!(nonce.as_bytes()[22..], [1, 2]);
+
+        assert_eq!(AesGcmNonce::from_bytes(&[7u8; 12]).unwrap(), AesGcmNonce::from([7u8; 12]));
+        assert_eq!(AesGcmNonce::from_bytes(&[7u8; 24]).unwrap_err(), CryptoError::InvalidInput(NONCE_LENGTH_MISMATCH));
+        assert_eq!(format!("{:?}", Nonce::<4>::from([0xab; 4])), "Nonce<4>(abababab)");
+        assert_eq!(NonceStrategy::from(Nonce::from([1u8; 12])), NonceStrategy::Explicit(vec![1u8; 12]));
+    }
+
+    #[cfg(feature = "nonce-tracking")]
+    #[test]
+    fn test_nonce_guard() {
+        assert_eq!(NonceGuard::new(0).err(), Some(CryptoError::InvalidInput(NONCE_GUARD_CAPACITY_ZERO)));
+
+        let mut guard = NonceGuard::new(2).unwrap();
+        assert!(guard.is_empty());
+        guard.check(b"key a", b"nonce 1").unwrap();
+        guard.check(b"key b", b"nonce 1").unwrap();
+        assert_eq!(guard.check(b"key a", b"nonce 1"), Err(CryptoError::InvalidInput(NONCE_REUSED)));
+        assert_eq!(guard.len(), 2);
+
+        // The oldest pair falls out of the window
+        guard.check(b"key a", b"nonce 2").unwrap();
+        guard.check(b"key a", b"nonce 1").unwrap();
+        guard.clear();
+        guard.check(b"key a", b"nonce 2").unwrap();
+    }
+
+    #[cfg(all(feature = "nonce-tracking", feature = "aes", feature = "chacha", debug_assertions))]
+    #[test]
+    fn test_installed_nonce_guard() {
+        use crate::core::keys::{Aes256Key, ChaChaKey};
+        use crate::core::encryptor::Encryptor;
+
+        let key = Aes256Key::generate().unwrap();
+        key.encrypt_with_nonce(b"a", AesGcmNonce::from_counter(1), b"").unwrap();
+        NonceGuard::new(16).unwrap().install();
+        key.encrypt_with_nonce(b"a", AesGcmNonce::from_counter(1), b"").unwrap();
+        assert_eq!(key.encrypt_with_nonce(b"b", AesGcmNonce::from_counter(1), b"").unwrap_err(), CryptoError::InvalidInput(NONCE_REUSED));
+        key.encrypt_with_nonce(b"b", AesGcmNonce::from_counter(2), b"").unwrap();
+        ChaChaKey::generate().unwrap().encrypt_with_nonce(b"c", ChaChaNonce::from_counter(1), b"").unwrap();
+
+        let nonce = NonceStrategy::Explicit(vec![9u8; 12]);
+        Encryptor::new().key(&key).nonce_strategy(nonce.clone()).encrypt(b"d").unwrap();
+        assert!(Encryptor::new().key(&key).nonce_strategy(nonce).encrypt(b"d").is_err());
+
+        assert_eq!(NonceGuard::uninstall().unwrap().len(), 4);
+        key.encrypt_with_nonce(b"a", AesGcmNonce::from_counter(1), b"").unwrap();
+    }
+}
@@ -0,0 +1,181 @@
+//! Legacy: read and write files produced by `openssl enc -aes-256-cbc`
+//!
+//! The format is `"Salted__" || salt (8) || AES-256-CBC(PKCS#7 padded plaintext)`,
+//! with the key and IV derived from the password by `EVP_BytesToKey` or, with
+//! `-pbkdf2`, PBKDF2. There is no authentication: a wrong password is only
+//! noticed when the padding happens to be invalid, and tampering goes
+//! undetected. Use it to migrate old backups into an authenticated format
+//! such as [`Envelope`](crate::core::envelope::Envelope), not for new data.
+
+use crate::error::{CryptoError, CryptoResult, ZERO_ITERATIONS, OPENSSL_ENC_INVALID_FORMAT, OPENSSL_ENC_DECRYPTION_FAILED};
+use crate::core::random::SecureRandom;
+use aes::Aes256;
+use cbc::cipher::{BlockDecryptMut, BlockEncryptMut, KeyIvInit, block_padding::Pkcs7};
+use md5::Md5;
+use pbkdf2::pbkdf2_hmac;
+use sha2::{Digest, Sha256, Sha512};
+use alloc::vec::Vec;
+use zeroize::Zeroizing;
+
+const MAGIC: &[u8; 8] = b"Salted__";
+const SALT_LEN: usize = 8;
+const KEY_LEN: usize = 32;
+const IV_LEN: usize = 16;
+
+/// How `openssl enc` turned the password into a key and IV
+///
+/// The file does not record it, so pick the variant matching the command
+/// line that wrote it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenSslKdf {
+    /// `EVP_BytesToKey` with MD5: `-md md5`, the default before OpenSSL 1.1.0
+    BytesToKeyMd5,
+    /// `EVP_BytesToKey` with SHA-256: no `-pbkdf2`, OpenSSL 1.1.0 and later
+    BytesToKeySha256,
+    /// `-pbkdf2 -iter <iterations>` (PBKDF2-HMAC-SHA256)
+    Pbkdf2Sha256 {
+        /// The `-iter` value; OpenSSL's default is [`OpenSslKdf::DEFAULT_ITERATIONS`]
+        iterations: u32,
+    },
+    /// `-pbkdf2 -iter <iterations> -md sha512`
+    Pbkdf2Sha512 {
+        /// The `-iter` value
+        iterations: u32,
+    },
+}
+
+impl OpenSslKdf {
+    /// Iterations `openssl enc -pbkdf2` uses without `-iter`
+    pub const DEFAULT_ITERATIONS: u32 = 10_000;
+
+    // Key followed by IV
+    fn derive(self, password: &[u8], salt: &[u8]) -> CryptoResult<Zeroizing<[u8; KEY_LEN + IV_LEN]>> {
+        let mut key_iv = Zeroizing::new([0u8; KEY_LEN + IV_LEN]);
+        match self {
+            OpenSslKdf::BytesToKeyMd5 => bytes_to_key::<Md5>(password, salt, &mut key_iv[..]),
+            OpenSslKdf::BytesToKeySha256 => bytes_to_key::<Sha256>(password, salt, &mut key_iv[..]),
+            OpenSslKdf::Pbkdf2Sha256 { iterations } | OpenSslKdf::Pbkdf2Sha512 { iterations } if iterations == 0 => {
+                return Err(CryptoError::InvalidInput(ZERO_ITERATIONS));
+            }
+            OpenSslKdf::Pbkdf2Sha256 { iterations } => pbkdf2_hmac::<Sha256>(password, salt, iterations, &mut key_iv[..]),
+            OpenSslKdf::Pbkdf2Sha512 { iterations } => pbkdf2_hmac::<Sha512>(password, salt, iterations, &mut key_iv[..]),
+        }
+        Ok(key_iv)
+    }
+}
+
+// EVP_BytesToKey with one iteration: D_i = H(D_{i-1} || password || salt)
+fn bytes_to_key<D: Digest>(password: &[u8], salt: &[u8], out: &mut [u8]) {
+    let mut filled = 0;
+    let mut previous = Zeroizing::new(Vec::new());
+    while filled < out.len() {
+        let digest = D::new()
+            .chain_update(&previous[..])
+            .chain_update(password)
+            .chain_update(salt)
+            .finalize();
+        let take = digest.len().min(out.len() - filled);
+        out[filled..filled + take].copy_from_slice(&digest[..take]);
+        filled += take;
+        *previous = digest.to_vec();
+    }
+}
+
+/// `openssl enc -aes-256-cbc` files (legacy, unauthenticated)
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// // openssl enc -d -aes-256-cbc -pbkdf2 -in backup.enc
+/// let kdf = OpenSslKdf::Pbkdf2Sha256 { iterations: OpenSslKdf::DEFAULT_ITERATIONS };
+/// let file = OpenSslEnc::encrypt(b"accounts.csv", b"hunter2", kdf)?;
+/// assert_eq!(OpenSslEnc::decrypt(&file, b"hunter2", kdf)?, b"accounts.csv");
+/// # Ok::<(), CryptoError>(())
+/// ```
+pub struct OpenSslEnc;
+
+impl OpenSslEnc {
+    /// Encrypt like `openssl enc -aes-256-cbc -salt`, with a random salt
+    ///
+    /// Only for systems that can read nothing else; prefer the PBKDF2 variants.
+    pub fn encrypt(plaintext: &[u8], password: &[u8], kdf: OpenSslKdf) -> CryptoResult<Vec<u8>> {
+        let mut salt = [0u8; SALT_LEN];
+        SecureRandom::fill(&mut salt)?;
+        let key_iv = kdf.derive(password, &salt)?;
+
+        let ciphertext = cbc::Encryptor::<Aes256>::new(key_iv[..KEY_LEN].into(), key_iv[KEY_LEN..].into())
+            .encrypt_padded_vec_mut::<Pkcs7>(plaintext);
+        let mut out = Vec::with_capacity(MAGIC.len() + SALT_LEN + ciphertext.len());
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&salt);
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+
+    /// Decrypt a `Salted__` file written by `openssl enc -aes-256-cbc`
+    ///
+    /// Base64 output (`-a`) must be decoded first. A wrong password or KDF
+    /// usually fails, but about one time in 256 yields garbage instead.
+    pub fn decrypt(data: &[u8], password: &[u8], kdf: OpenSslKdf) -> CryptoResult<Vec<u8>> {
+        let ciphertext = data.strip_prefix(MAGIC)
+            .filter(|rest| rest.len() >= SALT_LEN + IV_LEN && (rest.len() - SALT_LEN).is_multiple_of(IV_LEN))
+            .ok_or(CryptoError::EncodingFailed(OPENSSL_ENC_INVALID_FORMAT))?;
+        let (salt, ciphertext) = ciphertext.split_at(SALT_LEN);
+        let key_iv = kdf.derive(password, salt)?;
+
+        cbc::Decryptor::<Aes256>::new(key_iv[..KEY_LEN].into(), key_iv[KEY_LEN..].into())
+            .decrypt_padded_vec_mut::<Pkcs7>(ciphertext)
+            .map_err(|_| CryptoError::DecryptionFailed(OPENSSL_ENC_DECRYPTION_FAILED))
+    }
+
+    /// Decrypt an `openssl enc` file, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(data: &[u8], password: &[u8], kdf: OpenSslKdf) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(data, password, kdf).map(Zeroizing::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAINTEXT: &[u8] = b"backup 2019-03-01: accounts.csv\n";
+
+    #[test]
+    fn test_openssl_enc_vectors() {
+        // `openssl enc -aes-256-cbc <args> -pass pass:hunter2`, OpenSSL 3.0
+        let vectors = [
+            ("-md md5", OpenSslKdf::BytesToKeyMd5, "53616c7465645f5f5de2d914083ed3b65d0030df789e4179f3591df1c4ac577b1247c9cb367c8f962611670788871ac2c9bf434542489b28d9d2144f3b837052"),
+            ("-md sha256", OpenSslKdf::BytesToKeySha256, "53616c7465645f5f2b0f488bab863e59d0cf166dc3284dadcb710eb48a956781402f65246937966c594b2b6b9d73c946efcb844500e08ab213648a445b75dc0a"),
+            ("-pbkdf2", OpenSslKdf::Pbkdf2Sha256 { iterations: OpenSslKdf::DEFAULT_ITERATIONS }, "53616c7465645f5ff50d0b15042d5eb1a4eefe72dc472ab06b152cca575516ccf1e0e03b0cbd9a80416f224af0b62072ebd8994ff9b57d8f9d15908108daa1dc"),
+            ("-pbkdf2 -iter 1000 -md sha512", OpenSslKdf::Pbkdf2Sha512 { iterations: 1000 }, "53616c7465645f5f1504a6faf388f981d56915e8c1badd2185c508592a176972a5faeb1c64f1d725962ba8b921af38565598f1d11bf4560a1b30f6ff9c23d7fe"),
+        ];
+        for (args, kdf, file) in vectors {
+            let file = hex::decode(file).unwrap();
+            assert_eq!(OpenSslEnc::decrypt(&file, b"hunter2", kdf).unwrap(), PLAINTEXT, "{args}");
+            assert_eq!(OpenSslEnc::decrypt_zeroizing(&file, b"hunter2", kdf).unwrap().as_slice(), PLAINTEXT);
+            assert!(OpenSslEnc::decrypt(&file, b"hunter3", kdf).is_err(), "{args}");
+        }
+    }
+
+    #[test]
+    fn test_openssl_enc_roundtrip() {
+        for kdf in [OpenSslKdf::BytesToKeyMd5, OpenSslKdf::BytesToKeySha256, OpenSslKdf::Pbkdf2Sha256 { iterations: 10 }, OpenSslKdf::Pbkdf2Sha512 { iterations: 10 }] {
+            for len in [0, 15, 16, 17, 100] {
+                let plaintext = vec![0x5a; len];
+                let file = OpenSslEnc::encrypt(&plaintext, b"password", kdf).unwrap();
+                assert_eq!(&file[..8], b"Salted__");
+                assert_eq!(file.len(), 16 + (len / 16 + 1) * 16);
+                assert_eq!(OpenSslEnc::decrypt(&file, b"password", kdf).unwrap(), plaintext);
+            }
+        }
+
+        let kdf = OpenSslKdf::Pbkdf2Sha256 { iterations: 10 };
+        let file = OpenSslEnc::encrypt(b"data", b"password", kdf).unwrap();
+        let invalid = CryptoError::EncodingFailed(OPENSSL_ENC_INVALID_FORMAT);
+        assert_eq!(OpenSslEnc::decrypt(&file[8..], b"password", kdf).unwrap_err(), invalid);
+        assert_eq!(OpenSslEnc::decrypt(&file[..file.len() - 1], b"password", kdf).unwrap_err(), invalid);
+        assert_eq!(OpenSslEnc::decrypt(&file[..16], b"password", kdf).unwrap_err(), invalid);
+        assert_eq!(OpenSslEnc::encrypt(b"data", b"password", OpenSslKdf::Pbkdf2Sha256 { iterations: 0 }).unwrap_err(), CryptoError::InvalidInput(ZERO_ITERATIONS));
+    }
+}
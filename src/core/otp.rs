@@ -0,0 +1,237 @@
+use crate::error::{CryptoError, CryptoResult, OTP_INVALID_SECRET, OTP_INVALID_DIGITS, OTP_INVALID_PERIOD, OTP_INVALID_BASE32};
+use crate::core::encoding::{Base32Variant, decode_base32_ct, encode_base32_ct};
+use crate::core::hash::ConstantTime;
+use crate::core::random::SecureRandom;
+use hmac::{Hmac as HmacImpl, Mac};
+use sha1::Sha1;
+use sha2::{Sha256, Sha512};
+use std::time::{SystemTime, UNIX_EPOCH};
+use zeroize::Zeroizing;
+
+/// HMAC hash used to compute one-time passwords
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OtpAlgorithm {
+    /// HMAC-SHA1, the default understood by every authenticator app
+    #[default]
+    Sha1,
+    /// HMAC-SHA256
+    Sha256,
+    /// HMAC-SHA512
+    Sha512,
+}
+
+impl OtpAlgorithm {
+    /// Get the `algorithm` parameter value used in `otpauth://` URIs
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            OtpAlgorithm::Sha1 => "SHA1",
+            OtpAlgorithm::Sha256 => "SHA256",
+            OtpAlgorithm::Sha512 => "SHA512",
+        }
+    }
+}
+
+/// Parameters shared by the issuer and the authenticator
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OtpConfig {
+    /// HMAC hash
+    pub algorithm: OtpAlgorithm,
+    /// Number of digits in a code, 6 to 8
+    pub digits: u32,
+    /// TOTP time step in seconds
+    pub period: u64,
+}
+
+impl Default for OtpConfig {
+    fn default() -> Self {
+        Self { algorithm: OtpAlgorithm::Sha1, digits: 6, period: 30 }
+    }
+}
+
+/// HOTP (RFC 4226) and TOTP (RFC 6238) one-time passwords
+pub struct Otp;
+
+impl Otp {
+    /// Generate a random 160-bit secret
+    #[inline]
+    pub fn generate_secret() -> CryptoResult<Vec<u8>> {
+        SecureRandom::generate_bytes(20)
+    }
+
+    /// Compute the HOTP code for `counter`
+    pub fn hotp(secret: &[u8], counter: u64, config: &OtpConfig) -> CryptoResult<String> {
+        if secret.is_empty() {
+            return Err(CryptoError::InvalidKey(OTP_INVALID_SECRET));
+        }
+        if !(6..=8).contains(&config.digits) {
+            return Err(CryptoError::InvalidInput(OTP_INVALID_DIGITS));
+        }
+
+        let digest = match config.algorithm {
+            OtpAlgorithm::Sha1 => hmac::<HmacImpl<Sha1>>(secret, &counter.to_be_bytes())?,
+            OtpAlgorithm::Sha256 => hmac::<HmacImpl<Sha256>>(secret, &counter.to_be_bytes())?,
+            OtpAlgorithm::Sha512 => hmac::<HmacImpl<Sha512>>(secret, &counter.to_be_bytes())?,
+        };
+
+        // Dynamic truncation (RFC 4226 section 5.3)
+        let offset = (digest[digest.len() - 1] & 0x0f) as usize;
+        let binary = u32::from_be_bytes([digest[offset], digest[offset + 1], digest[offset + 2], digest[offset + 3]]) & 0x7fff_ffff;
+        let code = binary % 10u32.pow(config.digits);
+        Ok(format!("{:0width$}", code, width = config.digits as usize))
+    }
+
+    /// Compute the TOTP code for the Unix time `timestamp`
+    pub fn totp(secret: &[u8], timestamp: u64, config: &OtpConfig) -> CryptoResult<String> {
+        if config.period == 0 {
+            return Err(CryptoError::InvalidInput(OTP_INVALID_PERIOD));
+        }
+        Self::hotp(secret, timestamp / config.period, config)
+    }
+
+    /// Compute the TOTP code for the current time
+    #[inline]
+    pub fn totp_now(secret: &[u8], config: &OtpConfig) -> CryptoResult<String> {
+        Self::totp(secret, unix_now(), config)
+    }
+
+    /// Check a TOTP code at `timestamp`, accepting up to `window` time steps of clock drift either way
+    pub fn verify_totp(secret: &[u8], code: &str, timestamp: u64, window: u64, config: &OtpConfig) -> CryptoResult<bool> {
+        if config.period == 0 {
+            return Err(CryptoError::InvalidInput(OTP_INVALID_PERIOD));
+        }
+
+        let step = timestamp / config.period;
+        let mut valid = false;
+        for counter in step.saturating_sub(window)..=step.saturating_add(window) {
+            let expected = Self::hotp(secret, counter, config)?;
+            valid |= ConstantTime::bytes_eq(expected.as_bytes(), code.as_bytes());
+        }
+        Ok(valid)
+    }
+
+    /// Check a TOTP code against the current time
+    #[inline]
+    pub fn verify_totp_now(secret: &[u8], code: &str, window: u64, config: &OtpConfig) -> CryptoResult<bool> {
+        Self::verify_totp(secret, code, unix_now(), window, config)
+    }
+
+    /// Build the `otpauth://totp/` URI that authenticator apps import from a QR code
+    pub fn provisioning_uri(secret: &[u8], issuer: &str, account: &str, config: &OtpConfig) -> String {
+        format!(
+            "otpauth://totp/{}:{}?secret={}&issuer={}&algorithm={}&digits={}&period={}",
+            percent_encode(issuer),
+            percent_encode(account),
+            Self::base32_encode(secret),
+            percent_encode(issuer),
+            config.algorithm.as_str(),
+            config.digits,
+            config.period,
+        )
+    }
+
+    /// Encode a secret as unpadded RFC 4648 base32
+    #[inline]
+    pub fn base32_encode(data: &[u8]) -> String {
+        encode_base32_ct(data, Base32Variant::NoPad)
+    }
+
+    /// Decode a base32 secret, ignoring case, spaces, dashes and trailing padding
+    pub fn base32_decode(encoded: &str) -> CryptoResult<Vec<u8>> {
+        let compact = Zeroizing::new(encoded.bytes().filter(|c| !matches!(c, b' ' | b'-')).collect::<Vec<u8>>());
+        let end = compact.iter().rposition(|&c| c != b'=').map_or(0, |last| last + 1);
+        decode_base32_ct(&compact[..end], Base32Variant::NoPad)
+            .map(|secret| secret.to_vec())
+            .map_err(|_| CryptoError::InvalidInput(OTP_INVALID_BASE32))
+    }
+}
+
+#[inline]
+fn hmac<M: Mac + hmac::digest::KeyInit>(secret: &[u8], message: &[u8]) -> CryptoResult<Vec<u8>> {
+    let mut mac = <M as Mac>::new_from_slice(secret)
+        .map_err(|_| CryptoError::InvalidKey(OTP_INVALID_SECRET))?;
+    mac.update(message);
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+fn percent_encode(value: &str) -> String {
+    let mut output = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            output.push(byte as char);
+        } else {
+            output.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    output
+}
+
+#[inline]
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hotp_rfc4226_vectors() {
+        let expected = ["755224", "287082", "359152", "969429", "338314", "254676", "287922", "162583", "399871", "520489"];
+        let config = OtpConfig::default();
+        for (counter, code) in expected.iter().enumerate() {
+            assert_eq!(Otp::hotp(b"12345678901234567890", counter as u64, &config).unwrap(), *code);
+        }
+    }
+
+    #[test]
+    fn test_totp_rfc6238_vectors() {
+        let cases = [
+            (OtpAlgorithm::Sha1, &b"12345678901234567890"[..], [(59, "94287082"), (1111111109, "07081804"), (20000000000, "65353130")]),
+            (OtpAlgorithm::Sha256, &b"12345678901234567890123456789012"[..], [(59, "46119246"), (1111111109, "68084774"), (20000000000, "77737706")]),
+            (OtpAlgorithm::Sha512, &b"1234567890123456789012345678901234567890123456789012345678901234"[..], [(59, "90693936"), (1111111109, "25091201"), (20000000000, "47863826")]),
+        ];
+
+        for (algorithm, secret, vectors) in cases {
+            let config = OtpConfig { algorithm, digits: 8, period: 30 };
+            for (timestamp, code) in vectors {
+                assert_eq!(Otp::totp(secret, timestamp, &config).unwrap(), code);
+                assert!(Otp::verify_totp(secret, code, timestamp, 0, &config).unwrap());
+            }
+        }
+    }
+
+    #[test]
+    fn test_totp_verify_window() {
+        let secret = Otp::generate_secret().unwrap();
+        let config = OtpConfig::default();
+        let code = Otp::totp(&secret, 1_000_000, &config).unwrap();
+
+        assert!(Otp::verify_totp(&secret, &code, 1_000_000 + 30, 1, &config).unwrap());
+        assert!(!Otp::verify_totp(&secret, &code, 1_000_000 + 30, 0, &config).unwrap());
+        assert!(!Otp::verify_totp(&secret, &code, 1_000_000 + 90, 1, &config).unwrap());
+        assert!(!Otp::verify_totp(&secret, "12345", 1_000_000, 1, &config).unwrap());
+        assert_eq!(Otp::totp_now(&secret, &config).unwrap().len(), 6);
+
+        assert!(Otp::hotp(b"", 0, &config).is_err());
+        assert!(Otp::hotp(&secret, 0, &OtpConfig { digits: 9, ..config }).is_err());
+        assert!(Otp::totp(&secret, 0, &OtpConfig { period: 0, ..config }).is_err());
+    }
+
+    #[test]
+    fn test_otp_base32_and_uri() {
+        assert_eq!(Otp::base32_encode(b"12345678901234567890"), "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ");
+        assert_eq!(Otp::base32_encode(b"foobar"), "MZXW6YTBOI");
+        assert_eq!(Otp::base32_decode("mzxw 6ytb oi======").unwrap(), b"foobar");
+        assert!(Otp::base32_decode("MZXW1").is_err());
+
+        let secret = Otp::generate_secret().unwrap();
+        assert_eq!(Otp::base32_decode(&Otp::base32_encode(&secret)).unwrap(), secret);
+
+        let uri = Otp::provisioning_uri(b"12345678901234567890", "ACME Co", "alice@example.com", &OtpConfig::default());
+        assert_eq!(uri, "otpauth://totp/ACME%20Co:alice%40example.com?secret=GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ&issuer=ACME%20Co&algorithm=SHA1&digits=6&period=30");
+    }
+}
@@ -0,0 +1,376 @@
+use crate::error::{CryptoError, CryptoResult, PASETO_INVALID_FORMAT, PASETO_INVALID_KEY, PASETO_FOOTER_MISMATCH, PASETO_AUTHENTICATION_FAILED, PASETO_INVALID_SIGNATURE, PASETO_EXPIRED, PASETO_NOT_YET_VALID, PASETO_INVALID_CLAIM};
+use crate::core::asymmetric::{Ed25519Crypto, Ed25519KeyPair};
+use crate::core::hash::ConstantTime;
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use blake2::Blake2bMac;
+use blake2::digest::{KeyInit, Mac};
+use blake2::digest::consts::{U32, U56, U64};
+use blake2::digest::generic_array::ArrayLength;
+use blake2::digest::typenum::{IsLessOrEqual, LeEq, NonZero};
+use chacha20::XChaCha20;
+use chacha20::cipher::{KeyIvInit, StreamCipher};
+use ed25519_dalek::VerifyingKey as Ed25519VerifyingKey;
+use rand::{rngs::OsRng, RngCore};
+use serde_json::Value;
+use std::time::{SystemTime, UNIX_EPOCH};
+use zeroize::Zeroizing;
+
+const LOCAL_HEADER: &str = "v4.local.";
+const PUBLIC_HEADER: &str = "v4.public.";
+const NONCE_SIZE: usize = 32;
+const TAG_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+/// PASETO claims set
+pub type PasetoClaims = serde_json::Map<String, Value>;
+
+/// Platform-Agnostic Security Tokens, version 4
+///
+/// `v4.local` tokens are encrypted with XChaCha20 and authenticated with
+/// keyed BLAKE2b; `v4.public` tokens are signed with Ed25519. The footer is
+/// authenticated but not encrypted, and the implicit assertion is bound to
+/// the token without being stored in it.
+pub struct Paseto;
+
+impl Paseto {
+    /// Size in bytes of a `v4.local` key
+    pub const LOCAL_KEY_SIZE: usize = 32;
+
+    /// Encrypt `payload` into a `v4.local` token
+    pub fn encrypt_local(payload: &[u8], key: &[u8], footer: &[u8], implicit: &[u8]) -> CryptoResult<String> {
+        let mut nonce = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut nonce);
+        Self::encrypt_local_with_nonce(payload, key, footer, implicit, &nonce)
+    }
+
+    /// Decrypt a `v4.local` token whose footer must equal `footer`
+    pub fn decrypt_local(token: &str, key: &[u8], footer: &[u8], implicit: &[u8]) -> CryptoResult<Vec<u8>> {
+        if key.len() != Self::LOCAL_KEY_SIZE {
+            return Err(CryptoError::InvalidKey(PASETO_INVALID_KEY));
+        }
+
+        let body = split_token(token, LOCAL_HEADER, footer)?;
+        if body.len() < NONCE_SIZE + TAG_SIZE {
+            return Err(CryptoError::InvalidInput(PASETO_INVALID_FORMAT));
+        }
+        let (nonce, rest) = body.split_at(NONCE_SIZE);
+        let (ciphertext, tag) = rest.split_at(rest.len() - TAG_SIZE);
+
+        let keys = split_local_key(key, nonce)?;
+        let expected = blake2b_mac::<U32>(&keys.auth_key, &[&pae(&[LOCAL_HEADER.as_bytes(), nonce, ciphertext, footer, implicit])])?;
+        if !ConstantTime::bytes_eq(&expected, tag) {
+            return Err(CryptoError::DecryptionFailed(PASETO_AUTHENTICATION_FAILED));
+        }
+
+        let mut payload = ciphertext.to_vec();
+        xchacha20(&keys.encryption_key, &keys.counter_nonce, &mut payload)?;
+        Ok(payload)
+    }
+
+    /// Sign `payload` into a `v4.public` token
+    pub fn sign_public(payload: &[u8], keypair: &Ed25519KeyPair, footer: &[u8], implicit: &[u8]) -> CryptoResult<String> {
+        let message = pae(&[PUBLIC_HEADER.as_bytes(), payload, footer, implicit]);
+        let signature = Ed25519Crypto::sign(&message, keypair.signing_key())?;
+
+        let mut body = Vec::with_capacity(payload.len() + SIGNATURE_SIZE);
+        body.extend_from_slice(payload);
+        body.extend_from_slice(&signature);
+        Ok(join_token(PUBLIC_HEADER, &body, footer))
+    }
+
+    /// Verify a `v4.public` token whose footer must equal `footer`, returning its payload
+    pub fn verify_public(token: &str, public_key: &Ed25519VerifyingKey, footer: &[u8], implicit: &[u8]) -> CryptoResult<Vec<u8>> {
+        let mut body = split_token(token, PUBLIC_HEADER, footer)?;
+        if body.len() < SIGNATURE_SIZE {
+            return Err(CryptoError::InvalidInput(PASETO_INVALID_FORMAT));
+        }
+        let signature = body.split_off(body.len() - SIGNATURE_SIZE);
+
+        let message = pae(&[PUBLIC_HEADER.as_bytes(), &body, footer, implicit]);
+        if !Ed25519Crypto::verify(&message, &signature, public_key).unwrap_or(false) {
+            return Err(CryptoError::VerificationFailed(PASETO_INVALID_SIGNATURE));
+        }
+        Ok(body)
+    }
+
+    /// Read the footer without verifying the token, e.g. to look up the key by `kid`
+    pub fn footer(token: &str) -> CryptoResult<Vec<u8>> {
+        let body = token.strip_prefix(LOCAL_HEADER)
+            .or_else(|| token.strip_prefix(PUBLIC_HEADER))
+            .ok_or(CryptoError::InvalidInput(PASETO_INVALID_FORMAT))?;
+        match body.split_once('.') {
+            Some((_, footer)) => URL_SAFE_NO_PAD.decode(footer)
+                .map_err(|_| CryptoError::InvalidInput(PASETO_INVALID_FORMAT)),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Check the RFC 3339 `exp` and `nbf` claims of a decrypted or verified payload
+    ///
+    /// Claims that are absent are not required; `leeway` is the allowed
+    /// clock skew in seconds.
+    pub fn validate_claims(claims: &PasetoClaims, leeway: u64) -> CryptoResult<()> {
+        let now = unix_now();
+        let leeway = i64::try_from(leeway).unwrap_or(i64::MAX);
+
+        if let Some(exp) = claims.get("exp") {
+            if now >= time_claim(exp)?.saturating_add(leeway) {
+                return Err(CryptoError::VerificationFailed(PASETO_EXPIRED));
+            }
+        }
+
+        if let Some(nbf) = claims.get("nbf") {
+            if now.saturating_add(leeway) < time_claim(nbf)? {
+                return Err(CryptoError::VerificationFailed(PASETO_NOT_YET_VALID));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn encrypt_local_with_nonce(payload: &[u8], key: &[u8], footer: &[u8], implicit: &[u8], nonce: &[u8; NONCE_SIZE]) -> CryptoResult<String> {
+        if key.len() != Self::LOCAL_KEY_SIZE {
+            return Err(CryptoError::InvalidKey(PASETO_INVALID_KEY));
+        }
+
+        let keys = split_local_key(key, nonce)?;
+
+        let mut body = Vec::with_capacity(NONCE_SIZE + payload.len() + TAG_SIZE);
+        body.extend_from_slice(nonce);
+        body.extend_from_slice(payload);
+        xchacha20(&keys.encryption_key, &keys.counter_nonce, &mut body[NONCE_SIZE..])?;
+
+        let tag = blake2b_mac::<U32>(&keys.auth_key, &[&pae(&[LOCAL_HEADER.as_bytes(), nonce, &body[NONCE_SIZE..], footer, implicit])])?;
+        body.extend_from_slice(&tag);
+        Ok(join_token(LOCAL_HEADER, &body, footer))
+    }
+}
+
+/// Per-token keys derived from the `v4.local` key and nonce
+struct LocalKeys {
+    encryption_key: Zeroizing<Vec<u8>>,
+    counter_nonce: Vec<u8>,
+    auth_key: Zeroizing<Vec<u8>>,
+}
+
+fn split_local_key(key: &[u8], nonce: &[u8]) -> CryptoResult<LocalKeys> {
+    let mut encryption_key = Zeroizing::new(blake2b_mac::<U56>(key, &[b"paseto-encryption-key", nonce])?);
+    let counter_nonce = encryption_key.split_off(32);
+    let auth_key = Zeroizing::new(blake2b_mac::<U32>(key, &[b"paseto-auth-key-for-aead", nonce])?);
+    Ok(LocalKeys { encryption_key, counter_nonce, auth_key })
+}
+
+#[inline]
+fn blake2b_mac<N>(key: &[u8], parts: &[&[u8]]) -> CryptoResult<Vec<u8>>
+where
+    N: ArrayLength<u8> + IsLessOrEqual<U64> + 'static,
+    LeEq<N, U64>: NonZero,
+{
+    let mut mac = <Blake2bMac<N> as KeyInit>::new_from_slice(key)
+        .map_err(|_| CryptoError::InvalidKey(PASETO_INVALID_KEY))?;
+    for part in parts {
+        mac.update(part);
+    }
+    Ok(mac.finalize().into_bytes().to_vec())
+}
+
+#[inline]
+fn xchacha20(key: &[u8], nonce: &[u8], data: &mut [u8]) -> CryptoResult<()> {
+    let mut cipher = XChaCha20::new_from_slices(key, nonce)
+        .map_err(|_| CryptoError::InvalidKey(PASETO_INVALID_KEY))?;
+    cipher.apply_keystream(data);
+    Ok(())
+}
+
+/// Pre-authentication encoding: a length-prefixed concatenation of `pieces`
+fn pae(pieces: &[&[u8]]) -> Vec<u8> {
+    let capacity = 8 + pieces.iter().map(|piece| 8 + piece.len()).sum::<usize>();
+    let mut output = Vec::with_capacity(capacity);
+    output.extend_from_slice(&le64(pieces.len()));
+    for piece in pieces {
+        output.extend_from_slice(&le64(piece.len()));
+        output.extend_from_slice(piece);
+    }
+    output
+}
+
+#[inline]
+fn le64(n: usize) -> [u8; 8] {
+    ((n as u64) & (u64::MAX >> 1)).to_le_bytes()
+}
+
+fn join_token(header: &str, body: &[u8], footer: &[u8]) -> String {
+    let mut token = String::from(header);
+    token.push_str(&URL_SAFE_NO_PAD.encode(body));
+    if !footer.is_empty() {
+        token.push('.');
+        token.push_str(&URL_SAFE_NO_PAD.encode(footer));
+    }
+    token
+}
+
+/// Check the header and footer of a token and decode its body
+fn split_token(token: &str, header: &str, expected_footer: &[u8]) -> CryptoResult<Vec<u8>> {
+    let rest = token.strip_prefix(header)
+        .ok_or(CryptoError::InvalidInput(PASETO_INVALID_FORMAT))?;
+    let (body, footer) = match rest.split_once('.') {
+        Some((body, footer)) => (body, URL_SAFE_NO_PAD.decode(footer)
+            .map_err(|_| CryptoError::InvalidInput(PASETO_INVALID_FORMAT))?),
+        None => (rest, Vec::new()),
+    };
+    if !ConstantTime::bytes_eq(&footer, expected_footer) {
+        return Err(CryptoError::VerificationFailed(PASETO_FOOTER_MISMATCH));
+    }
+    URL_SAFE_NO_PAD.decode(body)
+        .map_err(|_| CryptoError::InvalidInput(PASETO_INVALID_FORMAT))
+}
+
+#[inline]
+fn time_claim(value: &Value) -> CryptoResult<i64> {
+    value.as_str()
+        .and_then(parse_rfc3339)
+        .ok_or(CryptoError::InvalidInput(PASETO_INVALID_CLAIM))
+}
+
+/// Parse `YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)` into Unix seconds
+fn parse_rfc3339(s: &str) -> Option<i64> {
+    let bytes = s.as_bytes();
+    if bytes.len() < 20 || bytes[4] != b'-' || bytes[7] != b'-' || !matches!(bytes[10], b'T' | b't' | b' ')
+        || bytes[13] != b':' || bytes[16] != b':' {
+        return None;
+    }
+    let number = |range: std::ops::Range<usize>| -> Option<i64> {
+        let digits = s.get(range)?;
+        if digits.bytes().all(|b| b.is_ascii_digit()) { digits.parse().ok() } else { None }
+    };
+
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 || second > 60 {
+        return None;
+    }
+
+    let mut rest = &s[19..];
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let end = fraction.find(|c: char| !c.is_ascii_digit()).unwrap_or(fraction.len());
+        if end == 0 {
+            return None;
+        }
+        rest = &fraction[end..];
+    }
+
+    let offset = match rest.as_bytes() {
+        [b'Z' | b'z'] => 0,
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let (hours, minutes) = (number(s.len() - 5..s.len() - 3)?, number(s.len() - 2..s.len())?);
+            if hours > 23 || minutes > 59 {
+                return None;
+            }
+            let offset = hours * 3600 + minutes * 60;
+            if *sign == b'-' { -offset } else { offset }
+        }
+        _ => return None,
+    };
+
+    Some(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second - offset)
+}
+
+/// Days since 1970-01-01 in the proleptic Gregorian calendar
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+#[inline]
+fn unix_now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const VECTOR_LOCAL_KEY: &str = "707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f";
+    const VECTOR_SECRET_KEY: &str = "b4cbfb43df4ce210727d953e4a713307fa19bb7d9f85041438d9e11b942a3774";
+    const VECTOR_LOCAL_PAYLOAD: &[u8] = br#"{"data":"this is a secret message","exp":"2022-01-01T00:00:00+00:00"}"#;
+    const VECTOR_PUBLIC_PAYLOAD: &[u8] = br#"{"data":"this is a signed message","exp":"2022-01-01T00:00:00+00:00"}"#;
+    const VECTOR_FOOTER: &[u8] = br#"{"kid":"zVhMiPBP9fRf2snEcT7gFTioeA9COcNy9DfgL1W60haN"}"#;
+
+    #[test]
+    fn test_paseto_public_vectors() {
+        let keypair = Ed25519KeyPair::from_private_key_bytes(&hex::decode(VECTOR_SECRET_KEY).unwrap()).unwrap();
+
+        // 4-S-1 and 4-S-3 from the PASETO test vectors
+        let token = Paseto::sign_public(VECTOR_PUBLIC_PAYLOAD, &keypair, b"", b"").unwrap();
+        assert_eq!(token, "v4.public.eyJkYXRhIjoidGhpcyBpcyBhIHNpZ25lZCBtZXNzYWdlIiwiZXhwIjoiMjAyMi0wMS0wMVQwMDowMDowMCswMDowMCJ9bg_XBBzds8lTZShVlwwKSgeKpLT3yukTw6JUz3W4h_ExsQV-P0V54zemZDcAxFaSeef1QlXEFtkqxT1ciiQEDA");
+        let token = Paseto::sign_public(VECTOR_PUBLIC_PAYLOAD, &keypair, VECTOR_FOOTER, br#"{"test-vector":"4-S-3"}"#).unwrap();
+        assert_eq!(token, "v4.public.eyJkYXRhIjoidGhpcyBpcyBhIHNpZ25lZCBtZXNzYWdlIiwiZXhwIjoiMjAyMi0wMS0wMVQwMDowMDowMCswMDowMCJ9NPWciuD3d0o5eXJXG5pJy-DiVEoyPYWs1YSTwWHNJq6DZD3je5gf-0M4JR9ipdUSJbIovzmBECeaWmaqcaP0DQ.eyJraWQiOiJ6VmhNaVBCUDlmUmYyc25FY1Q3Z0ZUaW9lQTlDT2NOeTlEZmdMMVc2MGhhTiJ9");
+
+        let payload = Paseto::verify_public(&token, keypair.verifying_key(), VECTOR_FOOTER, br#"{"test-vector":"4-S-3"}"#).unwrap();
+        assert_eq!(payload, VECTOR_PUBLIC_PAYLOAD);
+        assert_eq!(Paseto::footer(&token).unwrap(), VECTOR_FOOTER);
+
+        assert!(Paseto::verify_public(&token, keypair.verifying_key(), VECTOR_FOOTER, b"").is_err());
+        assert!(Paseto::verify_public(&token, keypair.verifying_key(), b"", br#"{"test-vector":"4-S-3"}"#).is_err());
+        let other = Ed25519KeyPair::generate().unwrap();
+        assert!(Paseto::verify_public(&token, other.verifying_key(), VECTOR_FOOTER, br#"{"test-vector":"4-S-3"}"#).is_err());
+    }
+
+    #[test]
+    fn test_paseto_local_vector() {
+        let key = hex::decode(VECTOR_LOCAL_KEY).unwrap();
+
+        // 4-E-1 from the PASETO test vectors (all-zero nonce)
+        let token = Paseto::encrypt_local_with_nonce(VECTOR_LOCAL_PAYLOAD, &key, b"", b"", &[0u8; NONCE_SIZE]).unwrap();
+        assert_eq!(token, "v4.local.AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAQAr68PS4AXe7If_ZgesdkUMvSwscFlAl1pk5HC0e8kApeaqMfGo_7OpBnwJOAbY9V7WU6abu74MmcUE8YWAiaArVI8XJ5hOb_4v9RmDkneN0S92dx0OW4pgy7omxgf3S8c3LlQg");
+        assert_eq!(Paseto::decrypt_local(&token, &key, b"", b"").unwrap(), VECTOR_LOCAL_PAYLOAD);
+    }
+
+    #[test]
+    fn test_paseto_local_roundtrip_and_tampering() {
+        let key = [7u8; 32];
+        let token = Paseto::encrypt_local(b"payload", &key, VECTOR_FOOTER, b"context").unwrap();
+        assert!(token.starts_with("v4.local."));
+        assert_eq!(Paseto::decrypt_local(&token, &key, VECTOR_FOOTER, b"context").unwrap(), b"payload");
+
+        assert!(Paseto::decrypt_local(&token, &[8u8; 32], VECTOR_FOOTER, b"context").is_err());
+        assert!(Paseto::decrypt_local(&token, &key, VECTOR_FOOTER, b"other").is_err());
+        assert!(Paseto::decrypt_local(&token, &key, b"", b"context").is_err());
+        assert!(Paseto::decrypt_local(&token, &key[..16], VECTOR_FOOTER, b"context").is_err());
+
+        let mut tampered = token.into_bytes();
+        tampered[20] = if tampered[20] == b'A' { b'B' } else { b'A' };
+        let tampered = String::from_utf8(tampered).unwrap();
+        assert!(Paseto::decrypt_local(&tampered, &key, VECTOR_FOOTER, b"context").is_err());
+
+        // A local token is never accepted as a public one
+        let keypair = Ed25519KeyPair::generate().unwrap();
+        assert!(Paseto::verify_public(&tampered, keypair.verifying_key(), VECTOR_FOOTER, b"context").is_err());
+    }
+
+    #[test]
+    fn test_paseto_claim_validation() {
+        let claims = |value: Value| value.as_object().unwrap().clone();
+
+        assert!(Paseto::validate_claims(&claims(json!({ "exp": "2099-01-01T00:00:00Z" })), 0).is_ok());
+        assert!(Paseto::validate_claims(&claims(json!({ "exp": "2022-01-01T00:00:00+00:00" })), 0).is_err());
+        assert!(Paseto::validate_claims(&claims(json!({ "nbf": "2099-01-01T00:00:00.000Z" })), 0).is_err());
+        assert!(Paseto::validate_claims(&claims(json!({ "exp": 4_000_000_000u64 })), 0).is_err());
+        assert!(Paseto::validate_claims(&claims(json!({ "sub": "no time claims" })), 0).is_ok());
+
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_rfc3339("2022-01-01T00:00:00+00:00"), Some(1_640_995_200));
+        assert_eq!(parse_rfc3339("2022-01-01T02:30:00.123+02:30"), Some(1_640_995_200));
+        assert_eq!(parse_rfc3339("2000-02-29T12:00:00-01:00"), Some(951_829_200));
+        assert_eq!(parse_rfc3339("2022-13-01T00:00:00Z"), None);
+        assert_eq!(parse_rfc3339("2022-01-01"), None);
+    }
+}
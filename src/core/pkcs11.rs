@@ -0,0 +1,449 @@
+//! PKCS#11 (HSM / smart card) implementation of [`KeyProvider`]
+//!
+//! The vendor module (e.g. `libsofthsm2.so`, `opensc-pkcs11.so`) is loaded at
+//! runtime, so no PKCS#11 headers or libraries are needed at build time.
+
+use crate::error::{CryptoError, CryptoResult, PKCS11_MODULE_LOAD_FAILED, PKCS11_INITIALIZE_FAILED, PKCS11_SESSION_FAILED, PKCS11_LOGIN_FAILED, PKCS11_KEY_NOT_FOUND, PKCS11_ATTRIBUTE_FAILED, PKCS11_UNSUPPORTED_KEY_TYPE, PKCS11_SIGN_FAILED, PKCS11_DECRYPT_FAILED, KEY_PROVIDER_UNSUPPORTED_OPERATION, PUBLIC_KEY_ENCODING_FAILED};
+use crate::core::provider::KeyProvider;
+use libloading::Library;
+use rsa::pkcs8::EncodePublicKey;
+use rsa::{BigUint, RsaPublicKey};
+use sha2::{Digest, Sha256};
+use std::os::raw::{c_ulong, c_void};
+use std::path::Path;
+use std::ptr;
+use std::sync::Mutex;
+
+type CkUlong = c_ulong;
+type CkRv = CkUlong;
+
+const CKR_OK: CkRv = 0x000;
+const CKR_USER_ALREADY_LOGGED_IN: CkRv = 0x100;
+const CKR_CRYPTOKI_ALREADY_INITIALIZED: CkRv = 0x191;
+
+const CKF_OS_LOCKING_OK: CkUlong = 0x2;
+const CKF_RW_SESSION: CkUlong = 0x2;
+const CKF_SERIAL_SESSION: CkUlong = 0x4;
+const CKU_USER: CkUlong = 1;
+
+const CKA_CLASS: CkUlong = 0x000;
+const CKA_LABEL: CkUlong = 0x003;
+const CKA_KEY_TYPE: CkUlong = 0x100;
+const CKA_MODULUS: CkUlong = 0x120;
+const CKA_PUBLIC_EXPONENT: CkUlong = 0x122;
+const CKA_EC_PARAMS: CkUlong = 0x180;
+const CKA_EC_POINT: CkUlong = 0x181;
+
+const CKO_PUBLIC_KEY: CkUlong = 2;
+const CKO_PRIVATE_KEY: CkUlong = 3;
+
+const CKK_RSA: CkUlong = 0x00;
+const CKK_EC: CkUlong = 0x03;
+const CKK_EC_EDWARDS: CkUlong = 0x40;
+
+const CKM_RSA_PKCS_OAEP: CkUlong = 0x0009;
+const CKM_SHA256_RSA_PKCS: CkUlong = 0x0040;
+const CKM_SHA256: CkUlong = 0x0250;
+const CKM_ECDSA: CkUlong = 0x1041;
+const CKM_EDDSA: CkUlong = 0x1057;
+const CKG_MGF1_SHA256: CkUlong = 0x2;
+const CKZ_DATA_SPECIFIED: CkUlong = 0x1;
+
+// DER encoding of the prime256v1 OID
+const P256_EC_PARAMS: &[u8] = &[0x06, 0x08, 0x2a, 0x86, 0x48, 0xce, 0x3d, 0x03, 0x01, 0x07];
+
+// PKCS#11 structures are packed on Windows
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+struct CkAttribute {
+    attr_type: CkUlong,
+    value: *mut c_void,
+    value_len: CkUlong,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+struct CkMechanism {
+    mechanism: CkUlong,
+    parameter: *mut c_void,
+    parameter_len: CkUlong,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+struct CkRsaPkcsOaepParams {
+    hash_alg: CkUlong,
+    mgf: CkUlong,
+    source: CkUlong,
+    source_data: *mut c_void,
+    source_data_len: CkUlong,
+}
+
+#[cfg_attr(windows, repr(C, packed))]
+#[cfg_attr(not(windows), repr(C))]
+struct CkCInitializeArgs {
+    create_mutex: *mut c_void,
+    destroy_mutex: *mut c_void,
+    lock_mutex: *mut c_void,
+    unlock_mutex: *mut c_void,
+    flags: CkUlong,
+    reserved: *mut c_void,
+}
+
+type FnInitialize = unsafe extern "C" fn(*mut c_void) -> CkRv;
+type FnOpenSession = unsafe extern "C" fn(CkUlong, CkUlong, *mut c_void, *mut c_void, *mut CkUlong) -> CkRv;
+type FnCloseSession = unsafe extern "C" fn(CkUlong) -> CkRv;
+type FnLogin = unsafe extern "C" fn(CkUlong, CkUlong, *const u8, CkUlong) -> CkRv;
+type FnFindObjectsInit = unsafe extern "C" fn(CkUlong, *mut CkAttribute, CkUlong) -> CkRv;
+type FnFindObjects = unsafe extern "C" fn(CkUlong, *mut CkUlong, CkUlong, *mut CkUlong) -> CkRv;
+type FnFindObjectsFinal = unsafe extern "C" fn(CkUlong) -> CkRv;
+type FnGetAttributeValue = unsafe extern "C" fn(CkUlong, CkUlong, *mut CkAttribute, CkUlong) -> CkRv;
+type FnOperationInit = unsafe extern "C" fn(CkUlong, *mut CkMechanism, CkUlong) -> CkRv;
+type FnOperation = unsafe extern "C" fn(CkUlong, *const u8, CkUlong, *mut u8, *mut CkUlong) -> CkRv;
+
+struct Functions {
+    close_session: FnCloseSession,
+    find_objects_init: FnFindObjectsInit,
+    find_objects: FnFindObjects,
+    find_objects_final: FnFindObjectsFinal,
+    get_attribute_value: FnGetAttributeValue,
+    sign_init: FnOperationInit,
+    sign: FnOperation,
+    decrypt_init: FnOperationInit,
+    decrypt: FnOperation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pkcs11KeyType {
+    Rsa,
+    EcdsaP256,
+    Ed25519,
+}
+
+/// A private key held by a PKCS#11 token, addressed by its `CKA_LABEL`
+///
+/// Operations on the underlying session are serialized, so the provider can be
+/// shared between threads. The module is never finalized, since other providers
+/// in the process may still be using it.
+pub struct Pkcs11Provider {
+    functions: Functions,
+    session: Mutex<CkUlong>,
+    private_key: CkUlong,
+    public_key: Option<CkUlong>,
+    key_type: Pkcs11KeyType,
+    // Must outlive the function pointers above
+    _library: Library,
+}
+
+impl Pkcs11Provider {
+    /// Load `module`, log into `slot` with `pin` and look up the key pair labelled `label`
+    pub fn open<P: AsRef<Path>>(module: P, slot: u64, pin: &str, label: &str) -> CryptoResult<Self> {
+        // SAFETY: loading a PKCS#11 module runs its initializers; the caller chooses a trusted module.
+        let library = unsafe { Library::new(module.as_ref()) }
+            .map_err(|_| CryptoError::InternalError(PKCS11_MODULE_LOAD_FAILED))?;
+
+        // SAFETY: the symbol types match the PKCS#11 v2.40 function prototypes.
+        let (initialize, open_session, login, functions) = unsafe {
+            let initialize: FnInitialize = *symbol(&library, b"C_Initialize\0")?;
+            let open_session: FnOpenSession = *symbol(&library, b"C_OpenSession\0")?;
+            let login: FnLogin = *symbol(&library, b"C_Login\0")?;
+            let functions = Functions {
+                close_session: *symbol(&library, b"C_CloseSession\0")?,
+                find_objects_init: *symbol(&library, b"C_FindObjectsInit\0")?,
+                find_objects: *symbol(&library, b"C_FindObjects\0")?,
+                find_objects_final: *symbol(&library, b"C_FindObjectsFinal\0")?,
+                get_attribute_value: *symbol(&library, b"C_GetAttributeValue\0")?,
+                sign_init: *symbol(&library, b"C_SignInit\0")?,
+                sign: *symbol(&library, b"C_Sign\0")?,
+                decrypt_init: *symbol(&library, b"C_DecryptInit\0")?,
+                decrypt: *symbol(&library, b"C_Decrypt\0")?,
+            };
+            (initialize, open_session, login, functions)
+        };
+
+        let mut init_args = CkCInitializeArgs {
+            create_mutex: ptr::null_mut(),
+            destroy_mutex: ptr::null_mut(),
+            lock_mutex: ptr::null_mut(),
+            unlock_mutex: ptr::null_mut(),
+            flags: CKF_OS_LOCKING_OK,
+            reserved: ptr::null_mut(),
+        };
+        // SAFETY: init_args is a valid CK_C_INITIALIZE_ARGS for the duration of the call.
+        let rv = unsafe { initialize(&mut init_args as *mut _ as *mut c_void) };
+        if rv != CKR_OK && rv != CKR_CRYPTOKI_ALREADY_INITIALIZED {
+            return Err(CryptoError::InternalError(PKCS11_INITIALIZE_FAILED));
+        }
+
+        let mut session: CkUlong = 0;
+        // SAFETY: no application callback is registered and session is a valid out pointer.
+        let rv = unsafe {
+            open_session(slot as CkUlong, CKF_SERIAL_SESSION | CKF_RW_SESSION, ptr::null_mut(), ptr::null_mut(), &mut session)
+        };
+        if rv != CKR_OK {
+            return Err(CryptoError::InternalError(PKCS11_SESSION_FAILED));
+        }
+
+        let mut provider = Self {
+            functions,
+            session: Mutex::new(session),
+            private_key: 0,
+            public_key: None,
+            key_type: Pkcs11KeyType::Rsa,
+            _library: library,
+        };
+
+        // SAFETY: pin points to pin.len() readable bytes.
+        let rv = unsafe { login(session, CKU_USER, pin.as_ptr(), pin.len() as CkUlong) };
+        if rv != CKR_OK && rv != CKR_USER_ALREADY_LOGGED_IN {
+            return Err(CryptoError::InvalidInput(PKCS11_LOGIN_FAILED));
+        }
+
+        provider.private_key = provider.find_object(session, CKO_PRIVATE_KEY, label)?
+            .ok_or(CryptoError::InvalidKey(PKCS11_KEY_NOT_FOUND))?;
+        provider.public_key = provider.find_object(session, CKO_PUBLIC_KEY, label)?;
+
+        let key_type = provider.get_attribute(session, provider.private_key, CKA_KEY_TYPE)?;
+        provider.key_type = match read_ulong(&key_type)? {
+            CKK_RSA => Pkcs11KeyType::Rsa,
+            CKK_EC => Pkcs11KeyType::EcdsaP256,
+            CKK_EC_EDWARDS => Pkcs11KeyType::Ed25519,
+            _ => return Err(CryptoError::InvalidKey(PKCS11_UNSUPPORTED_KEY_TYPE)),
+        };
+
+        if provider.key_type == Pkcs11KeyType::EcdsaP256 {
+            let params = provider.get_attribute(session, provider.private_key, CKA_EC_PARAMS)?;
+            if params != P256_EC_PARAMS {
+                return Err(CryptoError::InvalidKey(PKCS11_UNSUPPORTED_KEY_TYPE));
+            }
+        }
+
+        Ok(provider)
+    }
+
+    fn find_object(&self, session: CkUlong, class: CkUlong, label: &str) -> CryptoResult<Option<CkUlong>> {
+        let mut class = class;
+        let mut template = [
+            CkAttribute {
+                attr_type: CKA_CLASS,
+                value: &mut class as *mut CkUlong as *mut c_void,
+                value_len: std::mem::size_of::<CkUlong>() as CkUlong,
+            },
+            CkAttribute {
+                attr_type: CKA_LABEL,
+                value: label.as_ptr() as *mut c_void,
+                value_len: label.len() as CkUlong,
+            },
+        ];
+
+        let mut object: CkUlong = 0;
+        let mut count: CkUlong = 0;
+        // SAFETY: the template points to live values and the out pointers are valid.
+        unsafe {
+            if (self.functions.find_objects_init)(session, template.as_mut_ptr(), template.len() as CkUlong) != CKR_OK {
+                return Err(CryptoError::InvalidKey(PKCS11_KEY_NOT_FOUND));
+            }
+            let rv = (self.functions.find_objects)(session, &mut object, 1, &mut count);
+            (self.functions.find_objects_final)(session);
+            if rv != CKR_OK {
+                return Err(CryptoError::InvalidKey(PKCS11_KEY_NOT_FOUND));
+            }
+        }
+
+        Ok((count == 1).then_some(object))
+    }
+
+    fn get_attribute(&self, session: CkUlong, object: CkUlong, attr_type: CkUlong) -> CryptoResult<Vec<u8>> {
+        let mut attribute = CkAttribute { attr_type, value: ptr::null_mut(), value_len: 0 };
+
+        // SAFETY: the first call only queries the length; the second writes into a buffer of that length.
+        unsafe {
+            if (self.functions.get_attribute_value)(session, object, &mut attribute, 1) != CKR_OK {
+                return Err(CryptoError::InternalError(PKCS11_ATTRIBUTE_FAILED));
+            }
+
+            let mut value = vec![0u8; attribute.value_len as usize];
+            attribute.value = value.as_mut_ptr() as *mut c_void;
+            if (self.functions.get_attribute_value)(session, object, &mut attribute, 1) != CKR_OK {
+                return Err(CryptoError::InternalError(PKCS11_ATTRIBUTE_FAILED));
+            }
+
+            value.truncate(attribute.value_len as usize);
+            Ok(value)
+        }
+    }
+
+    fn run_operation(
+        &self,
+        init: FnOperationInit,
+        operation: FnOperation,
+        mechanism: &mut CkMechanism,
+        input: &[u8],
+        error: CryptoError,
+    ) -> CryptoResult<Vec<u8>> {
+        let session = self.session.lock()
+            .map_err(|_| CryptoError::InternalError(PKCS11_SESSION_FAILED))?;
+
+        // SAFETY: the mechanism and input outlive the calls; the first operation call only
+        // queries the output length, the second writes into a buffer of that length.
+        unsafe {
+            if init(*session, mechanism, self.private_key) != CKR_OK {
+                return Err(error);
+            }
+
+            let mut output_len: CkUlong = 0;
+            if operation(*session, input.as_ptr(), input.len() as CkUlong, ptr::null_mut(), &mut output_len) != CKR_OK {
+                return Err(error);
+            }
+
+            let mut output = vec![0u8; output_len as usize];
+            if operation(*session, input.as_ptr(), input.len() as CkUlong, output.as_mut_ptr(), &mut output_len) != CKR_OK {
+                return Err(error);
+            }
+
+            output.truncate(output_len as usize);
+            Ok(output)
+        }
+    }
+
+    fn public_key_attribute(&self, attr_type: CkUlong) -> CryptoResult<Vec<u8>> {
+        let session = self.session.lock()
+            .map_err(|_| CryptoError::InternalError(PKCS11_SESSION_FAILED))?;
+        let object = self.public_key.unwrap_or(self.private_key);
+        self.get_attribute(*session, object, attr_type)
+    }
+}
+
+impl KeyProvider for Pkcs11Provider {
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        let error = CryptoError::SignatureFailed(PKCS11_SIGN_FAILED);
+        let (sign_init, sign) = (self.functions.sign_init, self.functions.sign);
+
+        match self.key_type {
+            Pkcs11KeyType::Rsa => {
+                let mut mechanism = mechanism(CKM_SHA256_RSA_PKCS);
+                self.run_operation(sign_init, sign, &mut mechanism, message, error)
+            }
+            Pkcs11KeyType::EcdsaP256 => {
+                // CKM_ECDSA signs a prehashed digest and returns r || s
+                let digest = Sha256::digest(message);
+                let mut mechanism = mechanism(CKM_ECDSA);
+                self.run_operation(sign_init, sign, &mut mechanism, &digest, error)
+            }
+            Pkcs11KeyType::Ed25519 => {
+                let mut mechanism = mechanism(CKM_EDDSA);
+                self.run_operation(sign_init, sign, &mut mechanism, message, error)
+            }
+        }
+    }
+
+    fn decrypt(&self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        if self.key_type != Pkcs11KeyType::Rsa {
+            return Err(CryptoError::InvalidKey(KEY_PROVIDER_UNSUPPORTED_OPERATION));
+        }
+
+        let mut params = CkRsaPkcsOaepParams {
+            hash_alg: CKM_SHA256,
+            mgf: CKG_MGF1_SHA256,
+            source: CKZ_DATA_SPECIFIED,
+            source_data: ptr::null_mut(),
+            source_data_len: 0,
+        };
+        let mut mechanism = CkMechanism {
+            mechanism: CKM_RSA_PKCS_OAEP,
+            parameter: &mut params as *mut CkRsaPkcsOaepParams as *mut c_void,
+            parameter_len: std::mem::size_of::<CkRsaPkcsOaepParams>() as CkUlong,
+        };
+
+        let error = CryptoError::DecryptionFailed(PKCS11_DECRYPT_FAILED);
+        self.run_operation(self.functions.decrypt_init, self.functions.decrypt, &mut mechanism, ciphertext, error)
+    }
+
+    fn public_key(&self) -> CryptoResult<Vec<u8>> {
+        match self.key_type {
+            Pkcs11KeyType::Rsa => {
+                let modulus = BigUint::from_bytes_be(&self.public_key_attribute(CKA_MODULUS)?);
+                let exponent = BigUint::from_bytes_be(&self.public_key_attribute(CKA_PUBLIC_EXPONENT)?);
+                RsaPublicKey::new(modulus, exponent)
+                    .map_err(|_| CryptoError::InvalidKey(PKCS11_UNSUPPORTED_KEY_TYPE))?
+                    .to_public_key_der()
+                    .map_err(|_| CryptoError::EncodingFailed(PUBLIC_KEY_ENCODING_FAILED))
+                    .map(|der| der.into_vec())
+            }
+            Pkcs11KeyType::EcdsaP256 | Pkcs11KeyType::Ed25519 => {
+                let point = self.public_key_attribute(CKA_EC_POINT)?;
+                Ok(unwrap_octet_string(point))
+            }
+        }
+    }
+}
+
+impl Drop for Pkcs11Provider {
+    fn drop(&mut self) {
+        if let Ok(session) = self.session.lock() {
+            // SAFETY: the session was opened by this provider and the library is still loaded.
+            unsafe {
+                (self.functions.close_session)(*session);
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for Pkcs11Provider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Pkcs11Provider")
+            .field("key_type", &self.key_type)
+            .field("private_key", &self.private_key)
+            .finish_non_exhaustive()
+    }
+}
+
+unsafe fn symbol<'a, T>(library: &'a Library, name: &[u8]) -> CryptoResult<libloading::Symbol<'a, T>> {
+    library.get(name)
+        .map_err(|_| CryptoError::InternalError(PKCS11_MODULE_LOAD_FAILED))
+}
+
+#[inline]
+fn mechanism(mechanism: CkUlong) -> CkMechanism {
+    CkMechanism { mechanism, parameter: ptr::null_mut(), parameter_len: 0 }
+}
+
+fn read_ulong(bytes: &[u8]) -> CryptoResult<CkUlong> {
+    let bytes: [u8; std::mem::size_of::<CkUlong>()] = bytes.try_into()
+        .map_err(|_| CryptoError::InternalError(PKCS11_ATTRIBUTE_FAILED))?;
+    Ok(CkUlong::from_ne_bytes(bytes))
+}
+
+// CKA_EC_POINT is usually a DER OCTET STRING around the raw point; some tokens return it bare
+fn unwrap_octet_string(point: Vec<u8>) -> Vec<u8> {
+    match point.len() {
+        34 | 67 if point[0] == 0x04 && point[1] as usize == point.len() - 2 => point[2..].to_vec(),
+        _ => point,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_module() {
+        let result = Pkcs11Provider::open("/nonexistent/libpkcs11.so", 0, "1234", "key");
+        assert_eq!(result.unwrap_err(), CryptoError::InternalError(PKCS11_MODULE_LOAD_FAILED));
+    }
+
+    #[test]
+    fn test_unwrap_octet_string() {
+        let mut wrapped = vec![0x04, 65, 0x04];
+        wrapped.extend_from_slice(&[0xab; 64]);
+        assert_eq!(unwrap_octet_string(wrapped).len(), 65);
+
+        let mut bare = vec![0x04];
+        bare.extend_from_slice(&[0xab; 64]);
+        assert_eq!(unwrap_octet_string(bare.clone()), bare);
+
+        let mut ed25519 = vec![0x04, 32];
+        ed25519.extend_from_slice(&[0x11; 32]);
+        assert_eq!(unwrap_octet_string(ed25519), vec![0x11; 32]);
+    }
+}
@@ -0,0 +1,395 @@
+use crate::error::{CryptoError, CryptoResult, CMS_INVALID_FORMAT, CMS_ENCODING_FAILED, CMS_INVALID_CERTIFICATE, CMS_CERTIFICATE_KEY_MISMATCH, CMS_UNSUPPORTED_ALGORITHM, CMS_NOT_DETACHED, CMS_SIGNER_NOT_FOUND, CMS_DIGEST_MISMATCH, CMS_INVALID_SIGNATURE};
+use crate::core::asymmetric::{EcdsaKeyPair, RsaKeyPair};
+use cms::cert::{CertificateChoices, IssuerAndSerialNumber};
+use cms::content_info::{CmsVersion, ContentInfo};
+use cms::signed_data::{CertificateSet, EncapsulatedContentInfo, SignedData, SignerIdentifier, SignerInfo, SignerInfos};
+use const_oid::db::{rfc5280, rfc5911, rfc5912};
+use const_oid::ObjectIdentifier;
+use der::asn1::{OctetString, SetOfVec};
+use der::{Any, Decode, Encode};
+use p256::ecdsa::signature::hazmat::PrehashVerifier;
+use p256::ecdsa::VerifyingKey as EcdsaVerifyingKey;
+use p256::pkcs8::{DecodePublicKey, EncodePublicKey};
+use rsa::signature::{SignatureEncoding, Signer};
+use rsa::{Pkcs1v15Sign, RsaPublicKey};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::time::SystemTime;
+use x509_cert::attr::{Attribute, AttributeValue, Attributes};
+use x509_cert::ext::pkix::SubjectKeyIdentifier;
+use x509_cert::spki::AlgorithmIdentifierOwned;
+use x509_cert::time::Time;
+use x509_cert::Certificate;
+
+/// Private key used to create a CMS signature
+#[derive(Clone, Copy)]
+pub enum CmsSigningKey<'a> {
+    /// RSA key pair, signed with RSASSA-PKCS1-v1_5 and SHA-256
+    Rsa(&'a RsaKeyPair),
+    /// P-256 key pair, signed with ECDSA and SHA-256
+    Ecdsa(&'a EcdsaKeyPair),
+}
+
+/// Detached CMS / PKCS#7 SignedData (RFC 5652)
+///
+/// Signatures are DER-encoded `ContentInfo` structures with the signer's
+/// certificate embedded, as produced by `openssl cms -sign -binary` and
+/// accepted by Adobe and Windows signature tooling.
+pub struct CmsSignedData;
+
+impl CmsSignedData {
+    /// Create a detached signature over `content` with a DER-encoded X.509 `certificate`
+    #[inline]
+    pub fn sign_detached(content: &[u8], certificate: &[u8], key: CmsSigningKey<'_>) -> CryptoResult<Vec<u8>> {
+        Self::sign_detached_with_chain(content, certificate, &[], key)
+    }
+
+    /// Create a detached signature, also embedding intermediate certificates from `chain`
+    pub fn sign_detached_with_chain(content: &[u8], certificate: &[u8], chain: &[&[u8]], key: CmsSigningKey<'_>) -> CryptoResult<Vec<u8>> {
+        let signer_certificate = parse_certificate(certificate)?;
+        let spki = signer_certificate.tbs_certificate.subject_public_key_info.to_der()
+            .map_err(|_| CryptoError::InvalidInput(CMS_INVALID_CERTIFICATE))?;
+        if spki != signing_key_spki(key)? {
+            return Err(CryptoError::InvalidKey(CMS_CERTIFICATE_KEY_MISMATCH));
+        }
+
+        let signing_time = Time::try_from(SystemTime::now())
+            .map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))?;
+        let signed_attrs: Attributes = SetOfVec::try_from(vec![
+            attribute(rfc5911::ID_CONTENT_TYPE, Any::encode_from(&rfc5911::ID_DATA))?,
+            attribute(rfc5911::ID_SIGNING_TIME, Any::encode_from(&signing_time))?,
+            attribute(rfc5911::ID_MESSAGE_DIGEST, Any::encode_from(&octet_string(Sha256::digest(content).to_vec())?))?,
+        ]).map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))?;
+
+        // The signature covers the DER encoding of the attributes as a SET OF
+        let signed_attrs_der = signed_attrs.to_der()
+            .map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))?;
+        let (signature_algorithm, signature) = match key {
+            CmsSigningKey::Rsa(keypair) => {
+                let signing_key = rsa::pkcs1v15::SigningKey::<Sha256>::new(keypair.private_key().clone());
+                let signature = signing_key.try_sign(&signed_attrs_der)
+                    .map_err(|_| CryptoError::SignatureFailed(CMS_ENCODING_FAILED))?;
+                (algorithm(rfc5912::RSA_ENCRYPTION, Some(Any::null())), signature.to_vec())
+            }
+            CmsSigningKey::Ecdsa(keypair) => {
+                let signature: p256::ecdsa::Signature = keypair.signing_key().try_sign(&signed_attrs_der)
+                    .map_err(|_| CryptoError::SignatureFailed(CMS_ENCODING_FAILED))?;
+                (algorithm(rfc5912::ECDSA_WITH_SHA_256, None), signature.to_der().as_bytes().to_vec())
+            }
+        };
+
+        let signer_info = SignerInfo {
+            version: CmsVersion::V1,
+            sid: SignerIdentifier::IssuerAndSerialNumber(IssuerAndSerialNumber {
+                issuer: signer_certificate.tbs_certificate.issuer.clone(),
+                serial_number: signer_certificate.tbs_certificate.serial_number.clone(),
+            }),
+            digest_alg: algorithm(rfc5912::ID_SHA_256, None),
+            signed_attrs: Some(signed_attrs),
+            signature_algorithm,
+            signature: octet_string(signature)?,
+            unsigned_attrs: None,
+        };
+
+        let mut certificates = vec![CertificateChoices::Certificate(signer_certificate)];
+        for der in chain {
+            certificates.push(CertificateChoices::Certificate(parse_certificate(der)?));
+        }
+
+        let signed_data = SignedData {
+            version: CmsVersion::V1,
+            digest_algorithms: set_of(vec![algorithm(rfc5912::ID_SHA_256, None)])?,
+            encap_content_info: EncapsulatedContentInfo {
+                econtent_type: rfc5911::ID_DATA,
+                econtent: None,
+            },
+            certificates: Some(CertificateSet(set_of(certificates)?)),
+            crls: None,
+            signer_infos: SignerInfos(set_of(vec![signer_info])?),
+        };
+
+        ContentInfo {
+            content_type: rfc5911::ID_SIGNED_DATA,
+            content: Any::encode_from(&signed_data).map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))?,
+        }
+        .to_der()
+        .map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))
+    }
+
+    /// Verify every signer of a detached signature over `content`
+    ///
+    /// Returns the DER-encoded signer certificates in signer order. Only the
+    /// signatures are checked; callers must decide whether to trust the
+    /// returned certificates.
+    pub fn verify_detached(signed_data: &[u8], content: &[u8]) -> CryptoResult<Vec<Vec<u8>>> {
+        let content_info = ContentInfo::from_der(signed_data)
+            .map_err(|_| CryptoError::InvalidInput(CMS_INVALID_FORMAT))?;
+        if content_info.content_type != rfc5911::ID_SIGNED_DATA {
+            return Err(CryptoError::InvalidInput(CMS_INVALID_FORMAT));
+        }
+        let signed_data: SignedData = content_info.content.decode_as()
+            .map_err(|_| CryptoError::InvalidInput(CMS_INVALID_FORMAT))?;
+        if signed_data.encap_content_info.econtent.is_some() {
+            return Err(CryptoError::InvalidInput(CMS_NOT_DETACHED));
+        }
+        if signed_data.signer_infos.0.is_empty() {
+            return Err(CryptoError::VerificationFailed(CMS_SIGNER_NOT_FOUND));
+        }
+
+        let certificates: Vec<&Certificate> = signed_data.certificates.iter()
+            .flat_map(|set| set.0.iter())
+            .filter_map(|choice| match choice {
+                CertificateChoices::Certificate(certificate) => Some(certificate),
+                _ => None,
+            })
+            .collect();
+
+        let mut signers = Vec::with_capacity(signed_data.signer_infos.0.len());
+        for signer_info in signed_data.signer_infos.0.iter() {
+            let certificate = certificates.iter()
+                .find(|certificate| identifies(&signer_info.sid, certificate))
+                .ok_or(CryptoError::VerificationFailed(CMS_SIGNER_NOT_FOUND))?;
+            verify_signer(signer_info, certificate, &signed_data.encap_content_info.econtent_type, content)?;
+            signers.push(certificate.to_der().map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))?);
+        }
+        Ok(signers)
+    }
+}
+
+fn verify_signer(signer_info: &SignerInfo, certificate: &Certificate, content_type: &ObjectIdentifier, content: &[u8]) -> CryptoResult<()> {
+    let digest_alg = signer_info.digest_alg.oid;
+    let content_digest = digest(digest_alg, content)?;
+
+    // Without signed attributes the signature covers the content itself
+    let signed_digest = match &signer_info.signed_attrs {
+        Some(signed_attrs) => {
+            let message_digest: OctetString = find_attribute(signed_attrs, rfc5911::ID_MESSAGE_DIGEST)?;
+            if message_digest.as_bytes() != content_digest.as_slice() {
+                return Err(CryptoError::VerificationFailed(CMS_DIGEST_MISMATCH));
+            }
+            let signed_type: ObjectIdentifier = find_attribute(signed_attrs, rfc5911::ID_CONTENT_TYPE)?;
+            if &signed_type != content_type {
+                return Err(CryptoError::VerificationFailed(CMS_INVALID_FORMAT));
+            }
+            let signed_attrs_der = signed_attrs.to_der()
+                .map_err(|_| CryptoError::InvalidInput(CMS_INVALID_FORMAT))?;
+            digest(digest_alg, &signed_attrs_der)?
+        }
+        None => content_digest,
+    };
+
+    let spki = &certificate.tbs_certificate.subject_public_key_info;
+    let spki_der = spki.to_der().map_err(|_| CryptoError::InvalidInput(CMS_INVALID_CERTIFICATE))?;
+    let signature = signer_info.signature.as_bytes();
+    let valid = match spki.algorithm.oid {
+        rfc5912::RSA_ENCRYPTION => {
+            let public_key = RsaPublicKey::from_public_key_der(&spki_der)
+                .map_err(|_| CryptoError::InvalidInput(CMS_INVALID_CERTIFICATE))?;
+            public_key.verify(pkcs1v15_scheme(digest_alg)?, &signed_digest, signature).is_ok()
+        }
+        rfc5912::ID_EC_PUBLIC_KEY => {
+            let public_key = EcdsaVerifyingKey::from_public_key_der(&spki_der)
+                .map_err(|_| CryptoError::InvalidInput(CMS_UNSUPPORTED_ALGORITHM))?;
+            p256::ecdsa::Signature::from_der(signature)
+                .is_ok_and(|signature| public_key.verify_prehash(&signed_digest, &signature).is_ok())
+        }
+        _ => return Err(CryptoError::InvalidInput(CMS_UNSUPPORTED_ALGORITHM)),
+    };
+
+    if !valid {
+        return Err(CryptoError::VerificationFailed(CMS_INVALID_SIGNATURE));
+    }
+    Ok(())
+}
+
+fn identifies(sid: &SignerIdentifier, certificate: &Certificate) -> bool {
+    let tbs = &certificate.tbs_certificate;
+    match sid {
+        SignerIdentifier::IssuerAndSerialNumber(id) => id.issuer == tbs.issuer && id.serial_number == tbs.serial_number,
+        SignerIdentifier::SubjectKeyIdentifier(ski) => tbs.extensions.iter()
+            .flatten()
+            .filter(|extension| extension.extn_id == rfc5280::ID_CE_SUBJECT_KEY_IDENTIFIER)
+            .any(|extension| SubjectKeyIdentifier::from_der(extension.extn_value.as_bytes()).is_ok_and(|id| &id == ski)),
+    }
+}
+
+fn find_attribute<'a, T: der::DecodeValue<'a> + der::FixedTag + 'a>(attributes: &'a Attributes, oid: ObjectIdentifier) -> CryptoResult<T> {
+    attributes.iter()
+        .find(|attribute| attribute.oid == oid)
+        .and_then(|attribute| attribute.values.get(0))
+        .and_then(|value| value.decode_as().ok())
+        .ok_or(CryptoError::InvalidInput(CMS_INVALID_FORMAT))
+}
+
+fn digest(oid: ObjectIdentifier, data: &[u8]) -> CryptoResult<Vec<u8>> {
+    match oid {
+        rfc5912::ID_SHA_256 => Ok(Sha256::digest(data).to_vec()),
+        rfc5912::ID_SHA_384 => Ok(Sha384::digest(data).to_vec()),
+        rfc5912::ID_SHA_512 => Ok(Sha512::digest(data).to_vec()),
+        _ => Err(CryptoError::InvalidInput(CMS_UNSUPPORTED_ALGORITHM)),
+    }
+}
+
+fn pkcs1v15_scheme(oid: ObjectIdentifier) -> CryptoResult<Pkcs1v15Sign> {
+    match oid {
+        rfc5912::ID_SHA_256 => Ok(Pkcs1v15Sign::new::<Sha256>()),
+        rfc5912::ID_SHA_384 => Ok(Pkcs1v15Sign::new::<Sha384>()),
+        rfc5912::ID_SHA_512 => Ok(Pkcs1v15Sign::new::<Sha512>()),
+        _ => Err(CryptoError::InvalidInput(CMS_UNSUPPORTED_ALGORITHM)),
+    }
+}
+
+fn signing_key_spki(key: CmsSigningKey<'_>) -> CryptoResult<Vec<u8>> {
+    let der = match key {
+        CmsSigningKey::Rsa(keypair) => keypair.public_key().to_public_key_der(),
+        CmsSigningKey::Ecdsa(keypair) => keypair.verifying_key().to_public_key_der(),
+    };
+    der.map(|der| der.into_vec())
+        .map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))
+}
+
+#[inline]
+fn parse_certificate(der: &[u8]) -> CryptoResult<Certificate> {
+    Certificate::from_der(der).map_err(|_| CryptoError::InvalidInput(CMS_INVALID_CERTIFICATE))
+}
+
+#[inline]
+fn algorithm(oid: ObjectIdentifier, parameters: Option<Any>) -> AlgorithmIdentifierOwned {
+    AlgorithmIdentifierOwned { oid, parameters }
+}
+
+#[inline]
+fn attribute(oid: ObjectIdentifier, value: der::Result<AttributeValue>) -> CryptoResult<Attribute> {
+    let value = value.map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))?;
+    Ok(Attribute { oid, values: set_of(vec![value])? })
+}
+
+#[inline]
+fn octet_string(bytes: Vec<u8>) -> CryptoResult<OctetString> {
+    OctetString::new(bytes).map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))
+}
+
+#[inline]
+fn set_of<T: der::DerOrd>(items: Vec<T>) -> CryptoResult<SetOfVec<T>> {
+    SetOfVec::try_from(items).map_err(|_| CryptoError::EncodingFailed(CMS_ENCODING_FAILED))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::RsaCrypto;
+    use base64::{Engine as _, engine::general_purpose::STANDARD};
+    use der::asn1::BitString;
+    use std::str::FromStr;
+    use std::time::Duration;
+    use x509_cert::certificate::{TbsCertificate, Version};
+    use x509_cert::name::Name;
+    use x509_cert::serial_number::SerialNumber;
+    use x509_cert::spki::SubjectPublicKeyInfoOwned;
+    use x509_cert::time::Validity;
+
+    // `openssl cms -sign -binary -md sha384` over "signed by openssl" with a self-signed P-256 certificate
+    const OPENSSL_SIGNATURE: &str = concat!(
+        "MIIDWgYJKoZIhvcNAQcCoIIDSzCCA0cCAQExDTALBglghkgBZQMEAgIwCwYJKoZIhvcNAQcBoIIBjDCCAYgwggEtoAMCAQICFFev",
+        "iM8KVMe4dl7doDdgagNE6rnVMAoGCCqGSM49BAMCMBkxFzAVBgNVBAMMDk9wZW5TU0wgU2lnbmVyMB4XDTI2MTAxNjE4MDcyOVoX",
+        "DTM2MTAxMzE4MDcyOVowGTEXMBUGA1UEAwwOT3BlblNTTCBTaWduZXIwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAATIIWAD/bqS",
+        "eQb9veLHa0BA+/lsXe6Y5+VVp45U4F4k8E+fp9lKITz1oL9ugBXHVuYLuw5HQa9cNu/2zp7/zYodo1MwUTAdBgNVHQ4EFgQUXNXF",
+        "3A6Fgg86rOk2Jhk7xVIjrQIwHwYDVR0jBBgwFoAUXNXF3A6Fgg86rOk2Jhk7xVIjrQIwDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjO",
+        "PQQDAgNJADBGAiEAmFXYUotfKbWs81jg4zwrHRJdF+SNvSdj/3Aw/w8bd9wCIQDzWfGh5HNbKXOvJE2fwRacmGnnPmkXFvrMKir+",
+        "No0miTGCAZQwggGQAgEBMDEwGTEXMBUGA1UEAwwOT3BlblNTTCBTaWduZXICFFeviM8KVMe4dl7doDdgagNE6rnVMAsGCWCGSAFl",
+        "AwQCAqCB9DAYBgkqhkiG9w0BCQMxCwYJKoZIhvcNAQcBMBwGCSqGSIb3DQEJBTEPFw0yNjEwMTYxODA3MjlaMD8GCSqGSIb3DQEJ",
+        "BDEyBDCEysy2xDqduFAOzPruGtanZ20yEt1IGQ5aoM2mxoxBesJOE3odC7rvfIJN/JtrBUMweQYJKoZIhvcNAQkPMWwwajALBglg",
+        "hkgBZQMEASowCwYJYIZIAWUDBAEWMAsGCWCGSAFlAwQBAjAKBggqhkiG9w0DBzAOBggqhkiG9w0DAgICAIAwDQYIKoZIhvcNAwIC",
+        "AUAwBwYFKw4DAgcwDQYIKoZIhvcNAwICASgwCgYIKoZIzj0EAwMESDBGAiEA76f9OPO7zL9Pl2pxUxJFFFP7rm9ODIi36nTmFqmJ",
+        "tNgCIQD8VdHrzzOW+ddrpAHRYCs72rFTKLeHyLxs0PUSzvA7EQ==",
+    );
+
+    fn self_signed(subject: &str, key: CmsSigningKey<'_>) -> Vec<u8> {
+        let signature_algorithm = match key {
+            CmsSigningKey::Rsa(_) => algorithm(rfc5912::SHA_256_WITH_RSA_ENCRYPTION, Some(Any::null())),
+            CmsSigningKey::Ecdsa(_) => algorithm(rfc5912::ECDSA_WITH_SHA_256, None),
+        };
+        let name = Name::from_str(subject).unwrap();
+        let tbs_certificate = TbsCertificate {
+            version: Version::V3,
+            serial_number: SerialNumber::new(&[0x01, 0x02, 0x03]).unwrap(),
+            signature: signature_algorithm.clone(),
+            issuer: name.clone(),
+            validity: Validity::from_now(Duration::from_secs(3600)).unwrap(),
+            subject: name,
+            subject_public_key_info: SubjectPublicKeyInfoOwned::from_der(&signing_key_spki(key).unwrap()).unwrap(),
+            issuer_unique_id: None,
+            subject_unique_id: None,
+            extensions: None,
+        };
+
+        let tbs_der = tbs_certificate.to_der().unwrap();
+        let signature = match key {
+            CmsSigningKey::Rsa(keypair) => rsa::pkcs1v15::SigningKey::<Sha256>::new(keypair.private_key().clone()).sign(&tbs_der).to_vec(),
+            CmsSigningKey::Ecdsa(keypair) => {
+                let signature: p256::ecdsa::Signature = keypair.signing_key().sign(&tbs_der);
+                signature.to_der().as_bytes().to_vec()
+            }
+        };
+
+        Certificate {
+            tbs_certificate,
+            signature_algorithm,
+            signature: BitString::from_bytes(&signature).unwrap(),
+        }
+        .to_der()
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cms_sign_verify_detached() {
+        let rsa = RsaCrypto::generate_keypair().unwrap();
+        let ecdsa = EcdsaKeyPair::generate().unwrap();
+        let document = b"%PDF-1.7 document bytes";
+
+        for key in [CmsSigningKey::Rsa(&rsa), CmsSigningKey::Ecdsa(&ecdsa)] {
+            let certificate = self_signed("CN=libsilver signer,O=libsilver", key);
+            let signature = CmsSignedData::sign_detached(document, &certificate, key).unwrap();
+
+            let signers = CmsSignedData::verify_detached(&signature, document).unwrap();
+            assert_eq!(signers, vec![certificate]);
+
+            assert_eq!(
+                CmsSignedData::verify_detached(&signature, b"tampered").unwrap_err(),
+                CryptoError::VerificationFailed(CMS_DIGEST_MISMATCH)
+            );
+        }
+    }
+
+    #[test]
+    fn test_cms_embeds_chain_and_checks_key() {
+        let ecdsa = EcdsaKeyPair::generate().unwrap();
+        let other = EcdsaKeyPair::generate().unwrap();
+        let certificate = self_signed("CN=leaf", CmsSigningKey::Ecdsa(&ecdsa));
+        let intermediate = self_signed("CN=intermediate", CmsSigningKey::Ecdsa(&other));
+
+        let signature = CmsSignedData::sign_detached_with_chain(b"data", &certificate, &[&intermediate], CmsSigningKey::Ecdsa(&ecdsa)).unwrap();
+        let content_info = ContentInfo::from_der(&signature).unwrap();
+        let signed_data: SignedData = content_info.content.decode_as().unwrap();
+        assert_eq!(signed_data.certificates.unwrap().0.len(), 2);
+        assert_eq!(CmsSignedData::verify_detached(&signature, b"data").unwrap(), vec![certificate.clone()]);
+
+        // The certificate must belong to the signing key
+        assert_eq!(
+            CmsSignedData::sign_detached(b"data", &certificate, CmsSigningKey::Ecdsa(&other)).unwrap_err(),
+            CryptoError::InvalidKey(CMS_CERTIFICATE_KEY_MISMATCH)
+        );
+        assert!(CmsSignedData::sign_detached(b"data", b"not a certificate", CmsSigningKey::Ecdsa(&ecdsa)).is_err());
+        assert!(CmsSignedData::verify_detached(b"not a signature", b"data").is_err());
+    }
+
+    #[test]
+    fn test_cms_verify_openssl_signature() {
+        let signature = STANDARD.decode(OPENSSL_SIGNATURE).unwrap();
+        let signers = CmsSignedData::verify_detached(&signature, b"signed by openssl").unwrap();
+        assert_eq!(signers.len(), 1);
+        let certificate = Certificate::from_der(&signers[0]).unwrap();
+        assert_eq!(certificate.tbs_certificate.subject.to_string(), "CN=OpenSSL Signer");
+
+        assert!(CmsSignedData::verify_detached(&signature, b"signed by someone else").is_err());
+    }
+}
@@ -0,0 +1,126 @@
+use crate::error::{CryptoError, CryptoResult, KEY_PROVIDER_UNSUPPORTED_OPERATION, PUBLIC_KEY_ENCODING_FAILED};
+use crate::core::asymmetric::{RsaKeyPair, EcdsaKeyPair, Ed25519KeyPair, RsaCrypto, EcdsaCrypto, Ed25519Crypto};
+use rsa::pkcs1v15::SigningKey as RsaSigningKey;
+use rsa::pkcs8::EncodePublicKey;
+use rsa::sha2::Sha256;
+use rsa::signature::{SignatureEncoding, Signer};
+
+/// A private key that can be used without exposing its material
+///
+/// Implemented by the in-memory key pairs and by hardware-backed providers, so
+/// call sites can switch between them without changes.
+///
+/// | Key type | `sign`                     | `decrypt`      | `public_key`            |
+/// |----------|----------------------------|----------------|-------------------------|
+/// | RSA      | PKCS#1 v1.5 with SHA-256   | OAEP (SHA-256) | SPKI DER                |
+/// | P-256    | ECDSA with SHA-256 (r ‖ s) | unsupported    | SEC1 uncompressed point |
+/// | Ed25519  | Ed25519                    | unsupported    | 32 raw bytes            |
+pub trait KeyProvider {
+    /// Sign `message` with the private key
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Decrypt `ciphertext` with the private key
+    fn decrypt(&self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Get the encoded public key
+    fn public_key(&self) -> CryptoResult<Vec<u8>>;
+}
+
+impl KeyProvider for RsaKeyPair {
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        let signing_key = RsaSigningKey::<Sha256>::new(self.private_key().clone());
+        Ok(signing_key.sign(message).to_vec())
+    }
+
+    #[inline]
+    fn decrypt(&self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        RsaCrypto::decrypt(ciphertext, self.private_key())
+    }
+
+    fn public_key(&self) -> CryptoResult<Vec<u8>> {
+        RsaKeyPair::public_key(self).to_public_key_der()
+            .map_err(|_| CryptoError::EncodingFailed(PUBLIC_KEY_ENCODING_FAILED))
+            .map(|der| der.into_vec())
+    }
+}
+
+impl KeyProvider for EcdsaKeyPair {
+    #[inline]
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        EcdsaCrypto::sign(message, self.signing_key())
+    }
+
+    #[inline]
+    fn decrypt(&self, _ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        Err(CryptoError::InvalidKey(KEY_PROVIDER_UNSUPPORTED_OPERATION))
+    }
+
+    #[inline]
+    fn public_key(&self) -> CryptoResult<Vec<u8>> {
+        Ok(self.public_key_bytes())
+    }
+}
+
+impl KeyProvider for Ed25519KeyPair {
+    #[inline]
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Ed25519Crypto::sign(message, self.signing_key())
+    }
+
+    #[inline]
+    fn decrypt(&self, _ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        Err(CryptoError::InvalidKey(KEY_PROVIDER_UNSUPPORTED_OPERATION))
+    }
+
+    #[inline]
+    fn public_key(&self) -> CryptoResult<Vec<u8>> {
+        Ok(self.public_key_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::pkcs1v15::VerifyingKey as RsaVerifyingKey;
+    use rsa::signature::Verifier;
+
+    fn sign_through(provider: &dyn KeyProvider, message: &[u8]) -> Vec<u8> {
+        provider.sign(message).unwrap()
+    }
+
+    #[test]
+    fn test_software_signing_providers() {
+        let message = b"provider message";
+
+        let ed25519 = Ed25519Crypto::generate_keypair().unwrap();
+        let signature = sign_through(&ed25519, message);
+        let verifying_key = Ed25519KeyPair::verifying_key_from_bytes(&ed25519.public_key().unwrap()).unwrap();
+        assert!(Ed25519Crypto::verify(message, &signature, &verifying_key).unwrap());
+        assert!(KeyProvider::decrypt(&ed25519, b"data").is_err());
+
+        let ecdsa = EcdsaCrypto::generate_keypair().unwrap();
+        let signature = sign_through(&ecdsa, message);
+        let verifying_key = EcdsaKeyPair::verifying_key_from_bytes(&KeyProvider::public_key(&ecdsa).unwrap()).unwrap();
+        assert!(EcdsaCrypto::verify(message, &signature, &verifying_key).unwrap());
+        assert!(KeyProvider::decrypt(&ecdsa, b"data").is_err());
+    }
+
+    #[test]
+    fn test_rsa_provider() {
+        use rsa::pkcs8::DecodePublicKey;
+
+        let keypair = RsaCrypto::generate_keypair().unwrap();
+        let provider: Box<dyn KeyProvider> = Box::new(keypair.clone());
+
+        let ciphertext = RsaCrypto::encrypt(b"wrapped key", keypair.public_key()).unwrap();
+        assert_eq!(provider.decrypt(&ciphertext).unwrap(), b"wrapped key");
+
+        let public_key = rsa::RsaPublicKey::from_public_key_der(&provider.public_key().unwrap()).unwrap();
+        assert_eq!(&public_key, keypair.public_key());
+
+        let signature = provider.sign(b"message").unwrap();
+        let verifying_key = RsaVerifyingKey::<Sha256>::new(public_key);
+        let signature = rsa::pkcs1v15::Signature::try_from(signature.as_slice()).unwrap();
+        assert!(verifying_key.verify(b"message", &signature).is_ok());
+    }
+}
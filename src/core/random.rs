@@ -1,7 +1,53 @@
-use crate::error::{CryptoError, CryptoResult, ZERO_LENGTH_INPUT, RANDOM_GENERATION_FAILED};
-use rand::RngCore;
+use crate::error::{CryptoError, CryptoResult, ZERO_LENGTH_INPUT, RANDOM_GENERATION_FAILED, ZERO_RESEED_INTERVAL, EMPTY_CHARSET, SAMPLE_SIZE_TOO_LARGE};
+#[cfg(feature = "rsa")]
+use crate::error::PRIME_SIZE_OUT_OF_RANGE;
+#[cfg(feature = "mlock")]
+use crate::error::MEMORY_LOCK_FAILED;
+use crate::core::wordlist::WORDS;
+use rand::{CryptoRng, Rng, RngCore, SeedableRng};
+use rand::seq::{SliceRandom, index};
 use rand::rngs::OsRng;
+use rand_chacha::ChaCha20Rng;
+use crate::core::encoding::{Base64Variant, encode_base64_ct, encode_hex_ct};
+#[cfg(feature = "rsa")]
+use num_bigint_dig::{BigUint, RandPrime, prime::probably_prime};
 use zeroize::Zeroize;
+use alloc::{vec, vec::Vec, string::String};
+
+const ALPHANUMERIC_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+const HEX_CHARS: &str = "0123456789abcdef";
+const BASE64URL_CHARS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Smallest prime size accepted by [`SecureRandom::generate_prime`]
+pub const MIN_PRIME_BITS: usize = 16;
+/// Largest prime size accepted by [`SecureRandom::generate_prime`]
+pub const MAX_PRIME_BITS: usize = 8192;
+/// Miller-Rabin rounds used for primality testing (followed by a Lucas test, i.e. Baillie-PSW)
+pub const PRIME_MILLER_RABIN_ROUNDS: usize = 20;
+
+/// Character sets for password and token generation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Charset<'a> {
+    /// `A-Z`, `a-z`, `0-9` (~5.95 bits per character)
+    Alphanumeric,
+    /// Lowercase hexadecimal `0-9a-f` (4 bits per character)
+    Hex,
+    /// URL-safe base64 alphabet `A-Z`, `a-z`, `0-9`, `-`, `_` (6 bits per character)
+    Base64Url,
+    /// Caller-provided alphabet; every character is selected with equal probability
+    Custom(&'a str),
+}
+
+impl Charset<'_> {
+    fn alphabet(&self) -> &str {
+        match self {
+            Charset::Alphanumeric => ALPHANUMERIC_CHARS,
+            Charset::Hex => HEX_CHARS,
+            Charset::Base64Url => BASE64URL_CHARS,
+            Charset::Custom(chars) => chars,
+        }
+    }
+}
 
 /// Secure random number generator
 pub struct SecureRandom;
@@ -14,12 +60,32 @@ impl SecureRandom {
         }
 
         let mut bytes = vec![0u8; length];
-        OsRng.try_fill_bytes(&mut bytes)
-            .map_err(|_| CryptoError::RandomGenerationFailed(RANDOM_GENERATION_FAILED))?;
+        Self::fill(&mut bytes)?;
 
         Ok(bytes)
     }
 
+    /// Fill a caller-provided buffer with random bytes without allocating
+    ///
+    /// An empty buffer is a no-op.
+    #[inline]
+    pub fn fill(dest: &mut [u8]) -> CryptoResult<()> {
+        if dest.is_empty() {
+            return Ok(());
+        }
+
+        OsRng.try_fill_bytes(dest)
+            .map_err(|_| CryptoError::RandomGenerationFailed(RANDOM_GENERATION_FAILED))
+    }
+
+    /// Generate `N` random bytes on the stack, e.g. a 12-byte nonce
+    #[inline]
+    pub fn generate_array<const N: usize>() -> CryptoResult<[u8; N]> {
+        let mut bytes = [0u8; N];
+        Self::fill(&mut bytes)?;
+        Ok(bytes)
+    }
+
     /// Generate a random u32
     #[inline]
     pub fn generate_u32() -> CryptoResult<u32> {
@@ -39,6 +105,13 @@ impl SecureRandom {
         Ok(SecureKey::new(bytes))
     }
 
+    /// Generate a cryptographic key on memory-locked pages
+    #[cfg(feature = "mlock")]
+    #[inline]
+    pub fn generate_locked_key(length: usize) -> CryptoResult<SecureKey> {
+        SecureKey::new_locked(Self::generate_bytes(length)?)
+    }
+
     /// Generate a random nonce/IV of specified length
     #[inline]
     pub fn generate_nonce(length: usize) -> CryptoResult<Vec<u8>> {
@@ -50,43 +123,394 @@ impl SecureRandom {
     pub fn generate_salt() -> CryptoResult<Vec<u8>> {
         Self::generate_bytes(32) // 256-bit salt
     }
+
+    /// Shuffle a slice in place with a uniform Fisher-Yates shuffle driven by the OS RNG
+    #[inline]
+    pub fn shuffle<T>(items: &mut [T]) -> CryptoResult<()> {
+        items.shuffle(&mut OsRng);
+        Ok(())
+    }
+
+    /// Choose `k` distinct indices uniformly from `0..n`, in random order
+    ///
+    /// Memory use is proportional to `k`, not `n`, so selecting a few winners
+    /// from a very large population is cheap.
+    pub fn sample_indices(n: usize, k: usize) -> CryptoResult<Vec<usize>> {
+        if k > n {
+            return Err(CryptoError::InvalidInput(SAMPLE_SIZE_TOO_LARGE));
+        }
+
+        Ok(index::sample(&mut OsRng, n, k).into_vec())
+    }
+
+    /// Generate a random prime of exactly `bits` bits, returned as big-endian bytes
+    ///
+    /// Uses the same generator as RSA key generation: candidates have their top two
+    /// bits set, are sieved by small primes, then checked with
+    /// [`PRIME_MILLER_RABIN_ROUNDS`] Miller-Rabin rounds plus a Lucas test.
+    #[cfg(feature = "rsa")]
+    pub fn generate_prime(bits: usize) -> CryptoResult<Vec<u8>> {
+        if !(MIN_PRIME_BITS..=MAX_PRIME_BITS).contains(&bits) {
+            return Err(CryptoError::InvalidInput(PRIME_SIZE_OUT_OF_RANGE));
+        }
+
+        let prime = OsRng.gen_prime(bits);
+        Ok(prime.to_bytes_be())
+    }
+
+    /// Test whether a big-endian integer is (with overwhelming probability) prime
+    #[cfg(feature = "rsa")]
+    #[inline]
+    pub fn is_probable_prime(candidate: &[u8]) -> bool {
+        probably_prime(&BigUint::from_bytes_be(candidate), PRIME_MILLER_RABIN_ROUNDS)
+    }
+
+    /// Generate a random hex token from `num_bytes` random bytes (`2 * num_bytes` characters)
+    ///
+    /// Mirrors Python's `secrets.token_hex`.
+    pub fn token_hex(num_bytes: usize) -> CryptoResult<String> {
+        let mut bytes = Self::generate_bytes(num_bytes)?;
+        let token = encode_hex_ct(&bytes);
+        bytes.zeroize();
+        Ok(token)
+    }
+
+    /// Generate a URL-safe base64 token (no padding) from `num_bytes` random bytes
+    ///
+    /// Mirrors Python's `secrets.token_urlsafe`.
+    pub fn token_urlsafe(num_bytes: usize) -> CryptoResult<String> {
+        let mut bytes = Self::generate_bytes(num_bytes)?;
+        let token = encode_base64_ct(&bytes, Base64Variant::UrlSafeNoPad);
+        bytes.zeroize();
+        Ok(token)
+    }
+
+    /// Generate a random password of `length` characters drawn uniformly from `charset`
+    pub fn generate_password(length: usize, charset: Charset<'_>) -> CryptoResult<String> {
+        if length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_LENGTH_INPUT));
+        }
+
+        let alphabet: Vec<char> = charset.alphabet().chars().collect();
+        if alphabet.is_empty() {
+            return Err(CryptoError::InvalidInput(EMPTY_CHARSET));
+        }
+
+        // gen_range uses rejection sampling, so there is no modulo bias
+        let mut rng = OsRng;
+        let mut password = String::with_capacity(length);
+        for _ in 0..length {
+            password.push(alphabet[rng.gen_range(0..alphabet.len())]);
+        }
+
+        Ok(password)
+    }
+
+    /// Generate a diceware-style passphrase of `word_count` words joined by `separator`
+    ///
+    /// Words come from the 2048-word BIP-39 English list (11 bits of entropy per word).
+    pub fn generate_passphrase(word_count: usize, separator: &str) -> CryptoResult<String> {
+        if word_count == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_LENGTH_INPUT));
+        }
+
+        let mut rng = OsRng;
+        let words: Vec<&str> = (0..word_count)
+            .map(|_| WORDS[rng.gen_range(0..WORDS.len())])
+            .collect();
+
+        Ok(words.join(separator))
+    }
+}
+
+/// Default number of output bytes between automatic reseeds of [`SecureRng`] (1 MiB)
+pub const DEFAULT_RESEED_INTERVAL: u64 = 1024 * 1024;
+
+/// ChaCha20-based deterministic random bit generator seeded from the OS
+///
+/// Amortizes system calls for high-frequency generation (nonces, tokens) by
+/// expanding a 256-bit OS seed with ChaCha20, reseeding from the OS after
+/// `reseed_interval` output bytes or on demand via [`SecureRng::reseed`].
+///
+/// Fork safety: the generator state is plain process memory, so a forked child
+/// inherits an exact copy and would produce the same stream as its parent.
+/// Call [`SecureRng::reseed`] in the child immediately after `fork()`, or create
+/// a fresh instance there. Instances are not shared between threads; keep one
+/// per thread (e.g. in a `thread_local!`).
+pub struct SecureRng {
+    rng: ChaCha20Rng,
+    reseed_interval: u64,
+    bytes_since_reseed: u64,
+}
+
+impl SecureRng {
+    /// Create a new generator seeded from the OS with the default reseed interval
+    #[inline]
+    pub fn new() -> CryptoResult<Self> {
+        Self::with_reseed_interval(DEFAULT_RESEED_INTERVAL)
+    }
+
+    /// Create a new generator that reseeds from the OS after `interval` output bytes
+    pub fn with_reseed_interval(interval: u64) -> CryptoResult<Self> {
+        if interval == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_RESEED_INTERVAL));
+        }
+
+        Ok(Self {
+            rng: Self::seeded_from_os()?,
+            reseed_interval: interval,
+            bytes_since_reseed: 0,
+        })
+    }
+
+    /// Reseed the generator from the OS random number generator
+    #[inline]
+    pub fn reseed(&mut self) -> CryptoResult<()> {
+        self.rng = Self::seeded_from_os()?;
+        self.bytes_since_reseed = 0;
+        Ok(())
+    }
+
+    /// Get the configured reseed interval in bytes
+    #[inline]
+    pub fn reseed_interval(&self) -> u64 {
+        self.reseed_interval
+    }
+
+    /// Get the number of bytes produced since the last reseed
+    #[inline]
+    pub fn bytes_since_reseed(&self) -> u64 {
+        self.bytes_since_reseed
+    }
+
+    /// Fill the buffer with random bytes, reseeding whenever the interval is exhausted
+    ///
+    /// Large buffers are generated in interval-sized chunks with a reseed between
+    /// them, so no more than `reseed_interval` bytes ever come from one seed.
+    pub fn fill(&mut self, dest: &mut [u8]) -> CryptoResult<()> {
+        let mut remaining = dest;
+        while !remaining.is_empty() {
+            if self.bytes_since_reseed >= self.reseed_interval {
+                self.reseed()?;
+            }
+
+            let budget = self.reseed_interval - self.bytes_since_reseed;
+            let take = usize::try_from(budget).map_or(remaining.len(), |budget| budget.min(remaining.len()));
+            let (chunk, rest) = remaining.split_at_mut(take);
+            self.rng.fill_bytes(chunk);
+            self.bytes_since_reseed += take as u64;
+            remaining = rest;
+        }
+        Ok(())
+    }
+
+    /// Generate random bytes of the specified length
+    pub fn generate_bytes(&mut self, length: usize) -> CryptoResult<Vec<u8>> {
+        if length == 0 {
+            return Err(CryptoError::InvalidInput(ZERO_LENGTH_INPUT));
+        }
+
+        let mut bytes = vec![0u8; length];
+        self.fill(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    /// Generate a random nonce/IV of specified length
+    #[inline]
+    pub fn generate_nonce(&mut self, length: usize) -> CryptoResult<Vec<u8>> {
+        self.generate_bytes(length)
+    }
+
+    /// Generate a random u32
+    #[inline]
+    pub fn generate_u32(&mut self) -> CryptoResult<u32> {
+        let mut bytes = [0u8; 4];
+        self.fill(&mut bytes)?;
+        Ok(u32::from_le_bytes(bytes))
+    }
+
+    /// Generate a random u64
+    #[inline]
+    pub fn generate_u64(&mut self) -> CryptoResult<u64> {
+        let mut bytes = [0u8; 8];
+        self.fill(&mut bytes)?;
+        Ok(u64::from_le_bytes(bytes))
+    }
+
+    fn seeded_from_os() -> CryptoResult<ChaCha20Rng> {
+        let mut seed = <ChaCha20Rng as SeedableRng>::Seed::default();
+        OsRng.try_fill_bytes(&mut seed)
+            .map_err(|_| CryptoError::RandomGenerationFailed(RANDOM_GENERATION_FAILED))?;
+
+        let rng = ChaCha20Rng::from_seed(seed);
+        seed.zeroize();
+        Ok(rng)
+    }
+}
+
+impl RngCore for SecureRng {
+    fn next_u32(&mut self) -> u32 {
+        let mut bytes = [0u8; 4];
+        self.fill_bytes(&mut bytes);
+        u32::from_le_bytes(bytes)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        let mut bytes = [0u8; 8];
+        self.fill_bytes(&mut bytes);
+        u64::from_le_bytes(bytes)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).expect("OS random number generator failed during reseed")
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.fill(dest).map_err(|_| rng_error())
+    }
+}
+
+// `rand::Error` carries a message only with std; without it, a custom error code
+#[cfg(feature = "std")]
+fn rng_error() -> rand::Error {
+    rand::Error::new(RANDOM_GENERATION_FAILED)
+}
+
+#[cfg(not(feature = "std"))]
+fn rng_error() -> rand::Error {
+    core::num::NonZeroU32::new(rand::Error::CUSTOM_START).unwrap().into()
+}
+
+impl CryptoRng for SecureRng {}
+
+impl core::fmt::Debug for SecureRng {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecureRng")
+            .field("reseed_interval", &self.reseed_interval)
+            .field("bytes_since_reseed", &self.bytes_since_reseed)
+            .finish()
+    }
 }
 
 /// A secure key that automatically zeros its memory when dropped
-#[derive(Clone)]
+///
+/// With the `mlock` feature, [`SecureKey::new_locked`] places the key on memory pages
+/// that are locked into RAM (`mlock` / `VirtualLock`) so it is never swapped to disk.
 pub struct SecureKey {
+    // Declared before `data` so the pages are unlocked before the buffer is freed
+    #[cfg(feature = "mlock")]
+    lock: Option<MemoryLock>,
     data: Vec<u8>,
 }
 
+// Location of a locked key inside its page-padded buffer
+#[cfg(feature = "mlock")]
+struct MemoryLock {
+    offset: usize,
+    len: usize,
+    _guard: region::LockGuard,
+}
+
 impl SecureKey {
     /// Create a new secure key from bytes
     #[inline]
     pub fn new(data: Vec<u8>) -> Self {
-        Self { data }
+        Self {
+            #[cfg(feature = "mlock")]
+            lock: None,
+            data,
+        }
+    }
+
+    /// Create a secure key on memory-locked pages, zeroizing `data` after copying it
+    ///
+    /// The key gets whole pages to itself, so unlocking it never unlocks another key.
+    #[cfg(feature = "mlock")]
+    pub fn new_locked(mut data: Vec<u8>) -> CryptoResult<Self> {
+        let page_size = region::page::size();
+        let locked_len = data.len().max(1).div_ceil(page_size) * page_size;
+
+        // One spare page lets us find a page-aligned start inside the buffer
+        let mut buffer = vec![0u8; locked_len + page_size];
+        let offset = buffer.as_ptr().align_offset(page_size);
+
+        let guard = region::lock(buffer[offset..].as_ptr(), locked_len)
+            .map_err(|_| CryptoError::InternalError(MEMORY_LOCK_FAILED))?;
+
+        buffer[offset..offset + data.len()].copy_from_slice(&data);
+        let len = data.len();
+        data.zeroize();
+
+        Ok(Self {
+            lock: Some(MemoryLock { offset, len, _guard: guard }),
+            data: buffer,
+        })
+    }
+
+    /// Check whether the key lives on memory-locked pages
+    #[inline]
+    pub fn is_locked(&self) -> bool {
+        #[cfg(feature = "mlock")]
+        {
+            self.lock.is_some()
+        }
+        #[cfg(not(feature = "mlock"))]
+        {
+            false
+        }
     }
 
     /// Get the key data as a slice
     #[inline]
     pub fn as_bytes(&self) -> &[u8] {
+        #[cfg(feature = "mlock")]
+        if let Some(lock) = &self.lock {
+            return &self.data[lock.offset..lock.offset + lock.len];
+        }
         &self.data
     }
 
+    /// Run `f` with access to the key bytes, without handing out a long-lived borrow
+    #[inline]
+    pub fn expose_secret<R>(&self, f: impl FnOnce(&[u8]) -> R) -> R {
+        f(self.as_bytes())
+    }
+
     /// Get the length of the key
     #[inline]
     pub fn len(&self) -> usize {
-        self.data.len()
+        self.as_bytes().len()
     }
 
     /// Check if the key is empty
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.data.is_empty()
+        self.as_bytes().is_empty()
     }
 
     /// Convert to Vec<u8> (consumes the SecureKey)
+    ///
+    /// A memory-locked key is copied out of its locked pages, which are then wiped.
     #[inline]
     pub fn into_bytes(mut self) -> Vec<u8> {
-        std::mem::take(&mut self.data)
+        #[cfg(feature = "mlock")]
+        if self.lock.is_some() {
+            return self.as_bytes().to_vec();
+        }
+        core::mem::take(&mut self.data)
+    }
+}
+
+impl Clone for SecureKey {
+    /// Clones of a locked key are locked too, unless the lock limit has been reached
+    fn clone(&self) -> Self {
+        #[cfg(feature = "mlock")]
+        if self.is_locked() {
+            if let Ok(key) = Self::new_locked(self.as_bytes().to_vec()) {
+                return key;
+            }
+        }
+        Self::new(self.as_bytes().to_vec())
     }
 }
 
@@ -99,13 +523,18 @@ impl Drop for SecureKey {
 impl Zeroize for SecureKey {
     fn zeroize(&mut self) {
         self.data.zeroize();
+        #[cfg(feature = "mlock")]
+        {
+            self.lock = None;
+        }
     }
 }
 
-impl std::fmt::Debug for SecureKey {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Debug for SecureKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("SecureKey")
-            .field("len", &self.data.len())
+            .field("len", &self.len())
+            .field("locked", &self.is_locked())
             .finish()
     }
 }
@@ -113,6 +542,7 @@ impl std::fmt::Debug for SecureKey {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::encoding::decode_base64;
 
     #[test]
     fn test_generate_bytes() {
@@ -130,6 +560,31 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_fill() {
+        let mut buffer = [0u8; 32];
+        SecureRandom::fill(&mut buffer).unwrap();
+        assert_ne!(buffer, [0u8; 32]);
+
+        // Reusing the buffer produces fresh bytes
+        let previous = buffer;
+        SecureRandom::fill(&mut buffer).unwrap();
+        assert_ne!(buffer, previous);
+    }
+
+    #[test]
+    fn test_generate_array() {
+        let nonce: [u8; 12] = SecureRandom::generate_array().unwrap();
+        assert_ne!(nonce, SecureRandom::generate_array::<12>().unwrap());
+        assert_eq!(SecureRandom::generate_array::<0>().unwrap(), [0u8; 0]);
+    }
+
+    #[test]
+    fn test_fill_zero_length() {
+        let mut empty: [u8; 0] = [];
+        assert!(SecureRandom::fill(&mut empty).is_ok());
+    }
+
     #[test]
     fn test_generate_u32() {
         let num1 = SecureRandom::generate_u32().unwrap();
@@ -163,6 +618,35 @@ mod tests {
         assert_eq!(bytes, vec![1, 2, 3, 4]);
     }
 
+    #[test]
+    fn test_secure_key_expose_secret() {
+        let key = SecureKey::new(vec![1, 2, 3, 4]);
+        assert_eq!(key.expose_secret(|bytes| bytes.iter().map(|&b| b as u32).sum::<u32>()), 10);
+        assert!(!key.is_locked());
+    }
+
+    #[cfg(feature = "mlock")]
+    #[test]
+    fn test_secure_key_locked() {
+        let key = SecureKey::new_locked(vec![7; 48]).unwrap();
+        assert!(key.is_locked());
+        assert_eq!(key.as_bytes(), &[7; 48]);
+        assert_eq!(key.len(), 48);
+
+        let clone = key.clone();
+        assert!(clone.is_locked());
+        assert_eq!(clone.as_bytes(), key.as_bytes());
+        drop(key);
+        assert_eq!(clone.expose_secret(|bytes| bytes.to_vec()), vec![7; 48]);
+        assert_eq!(clone.into_bytes(), vec![7; 48]);
+
+        let mut generated = SecureRandom::generate_locked_key(32).unwrap();
+        assert_eq!(generated.len(), 32);
+        generated.zeroize();
+        assert!(generated.is_empty());
+        assert!(!generated.is_locked());
+    }
+
     #[test]
     fn test_generate_salt() {
         let salt = SecureRandom::generate_salt().unwrap();
@@ -174,4 +658,185 @@ mod tests {
         let nonce = SecureRandom::generate_nonce(12).unwrap();
         assert_eq!(nonce.len(), 12);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_shuffle() {
+        let mut items: Vec<u32> = (0..100).collect();
+        SecureRandom::shuffle(&mut items).unwrap();
+
+        let mut sorted = items.clone();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..100).collect::<Vec<u32>>());
+        assert_ne!(items, sorted); // 1 in 100! chance of a false failure
+
+        let mut empty: [u8; 0] = [];
+        assert!(SecureRandom::shuffle(&mut empty).is_ok());
+    }
+
+    #[test]
+    fn test_sample_indices() {
+        let indices = SecureRandom::sample_indices(1_000_000, 10).unwrap();
+        assert_eq!(indices.len(), 10);
+        assert!(indices.iter().all(|&i| i < 1_000_000));
+
+        let mut unique = indices.clone();
+        unique.sort_unstable();
+        unique.dedup();
+        assert_eq!(unique.len(), 10);
+
+        assert_eq!(SecureRandom::sample_indices(5, 5).unwrap().len(), 5);
+        assert!(SecureRandom::sample_indices(5, 0).unwrap().is_empty());
+        assert!(SecureRandom::sample_indices(5, 6).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "rsa")]
+    fn test_generate_prime() {
+        let prime = SecureRandom::generate_prime(256).unwrap();
+        assert_eq!(prime.len(), 32);
+        assert!(prime[0] & 0x80 != 0); // exactly 256 bits
+        assert!(SecureRandom::is_probable_prime(&prime));
+    }
+
+    #[test]
+    #[cfg(feature = "rsa")]
+    fn test_generate_prime_invalid_size() {
+        assert!(SecureRandom::generate_prime(8).is_err());
+        assert!(SecureRandom::generate_prime(MAX_PRIME_BITS + 1).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "rsa")]
+    fn test_is_probable_prime() {
+        assert!(SecureRandom::is_probable_prime(&[0x01, 0x00, 0x01])); // 65537
+        assert!(!SecureRandom::is_probable_prime(&[0x01, 0x00, 0x05])); // 65541 = 3 * 7 * 3121
+    }
+
+    #[test]
+    fn test_token_hex() {
+        let token = SecureRandom::token_hex(16).unwrap();
+        assert_eq!(token.len(), 32);
+        assert!(hex::decode(&token).is_ok());
+        assert_ne!(token, SecureRandom::token_hex(16).unwrap());
+
+        assert!(SecureRandom::token_hex(0).is_err());
+    }
+
+    #[test]
+    fn test_token_urlsafe() {
+        let token = SecureRandom::token_urlsafe(32).unwrap();
+        assert_eq!(token.len(), 43); // ceil(32 * 4 / 3), no padding
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(decode_base64(&token, Base64Variant::UrlSafeNoPad).unwrap().len(), 32);
+
+        assert!(SecureRandom::token_urlsafe(0).is_err());
+    }
+
+    #[test]
+    fn test_generate_password() {
+        let password = SecureRandom::generate_password(24, Charset::Alphanumeric).unwrap();
+        assert_eq!(password.len(), 24);
+        assert!(password.chars().all(|c| c.is_ascii_alphanumeric()));
+
+        let hex = SecureRandom::generate_password(32, Charset::Hex).unwrap();
+        assert!(hex.chars().all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase()));
+
+        let token = SecureRandom::generate_password(32, Charset::Base64Url).unwrap();
+        assert!(token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+
+        let custom = SecureRandom::generate_password(16, Charset::Custom("ab")).unwrap();
+        assert!(custom.chars().all(|c| c == 'a' || c == 'b'));
+    }
+
+    #[test]
+    fn test_generate_password_invalid() {
+        assert!(SecureRandom::generate_password(0, Charset::Alphanumeric).is_err());
+        assert!(SecureRandom::generate_password(8, Charset::Custom("")).is_err());
+    }
+
+    #[test]
+    fn test_generate_password_uses_whole_charset() {
+        // With 4096 draws from 16 symbols every symbol should appear
+        let hex = SecureRandom::generate_password(4096, Charset::Hex).unwrap();
+        assert!(HEX_CHARS.chars().all(|c| hex.contains(c)));
+    }
+
+    #[test]
+    fn test_generate_passphrase() {
+        let passphrase = SecureRandom::generate_passphrase(6, "-").unwrap();
+        let words: Vec<&str> = passphrase.split('-').collect();
+        assert_eq!(words.len(), 6);
+        assert!(words.iter().all(|w| WORDS.contains(w)));
+
+        assert!(SecureRandom::generate_passphrase(0, "-").is_err());
+    }
+
+    #[test]
+    fn test_secure_rng_generate() {
+        let mut rng = SecureRng::new().unwrap();
+
+        let bytes1 = rng.generate_bytes(32).unwrap();
+        let bytes2 = rng.generate_bytes(32).unwrap();
+        assert_eq!(bytes1.len(), 32);
+        assert_ne!(bytes1, bytes2);
+
+        // Independent instances are seeded independently
+        let mut other = SecureRng::new().unwrap();
+        assert_ne!(bytes1, other.generate_bytes(32).unwrap());
+
+        assert!(rng.generate_bytes(0).is_err());
+    }
+
+    #[test]
+    fn test_secure_rng_reseed_interval() {
+        let mut rng = SecureRng::with_reseed_interval(64).unwrap();
+        assert_eq!(rng.reseed_interval(), 64);
+
+        rng.generate_bytes(48).unwrap();
+        assert_eq!(rng.bytes_since_reseed(), 48);
+
+        // Crossing the interval reseeds mid-call: 16 bytes from the old seed, 32 from the new
+        rng.generate_bytes(48).unwrap();
+        assert_eq!(rng.bytes_since_reseed(), 32);
+
+        rng.generate_bytes(32).unwrap();
+        assert_eq!(rng.bytes_since_reseed(), 64);
+
+        // Interval exhausted: the next call reseeds before generating
+        rng.generate_nonce(12).unwrap();
+        assert_eq!(rng.bytes_since_reseed(), 12);
+
+        rng.reseed().unwrap();
+        assert_eq!(rng.bytes_since_reseed(), 0);
+
+        assert!(SecureRng::with_reseed_interval(0).is_err());
+    }
+
+    #[test]
+    fn test_secure_rng_reseeds_within_large_fill() {
+        let mut rng = SecureRng::with_reseed_interval(16).unwrap();
+        let mut buffer = [0u8; 1000];
+        rng.fill(&mut buffer).unwrap();
+        assert_eq!(rng.bytes_since_reseed(), 1000 % 16);
+
+        // Each 16-byte chunk comes from a fresh seed, so no two repeat
+        let chunks: std::collections::BTreeSet<_> = buffer.chunks(16).collect();
+        assert_eq!(chunks.len(), buffer.len().div_ceil(16));
+
+        // The RngCore impl goes through the same chunking: 8 bytes finish the
+        // current seed, then 62 full chunks leave the last seed exhausted
+        RngCore::fill_bytes(&mut rng, &mut buffer);
+        assert_eq!(rng.bytes_since_reseed(), 16);
+        let chunks: std::collections::BTreeSet<_> = buffer[8..].chunks(16).collect();
+        assert_eq!(chunks.len(), 62);
+    }
+
+    #[test]
+    fn test_secure_rng_as_rng_core() {
+        let mut rng = SecureRng::new().unwrap();
+        let mut buffer = [0u8; 16];
+        rng.fill_bytes(&mut buffer);
+        assert_ne!(buffer, [0u8; 16]);
+        assert_ne!(rng.next_u64(), rng.next_u64());
+    }
+}
@@ -0,0 +1,531 @@
+use crate::error::{CryptoError, CryptoResult, DOUBLE_RATCHET_INVALID_MESSAGE, DOUBLE_RATCHET_INVALID_KEY, DOUBLE_RATCHET_NOT_READY, DOUBLE_RATCHET_TOO_MANY_SKIPPED, DOUBLE_RATCHET_HEADER_DECRYPTION_FAILED, HKDF_SHA256_FAILED};
+use crate::core::keys::Aes256Key;
+use crate::core::random::SecureKey;
+use crate::core::symmetric::AesGcm;
+use crate::core::x3dh::X3dhSignedPrekey;
+use hkdf::Hkdf;
+use hmac::{Hmac as HmacImpl, Mac};
+use rand::rngs::OsRng;
+use sha2::Sha256;
+use std::collections::{BTreeMap, VecDeque};
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+const KEY_SIZE: usize = 32;
+// Header layout: ratchet public key (32) | previous chain length (4) | message number (4)
+const HEADER_SIZE: usize = KEY_SIZE + 4 + 4;
+// AES-GCM nonce (12) + header + tag (16)
+const ENCRYPTED_HEADER_SIZE: usize = 12 + HEADER_SIZE + 16;
+const ROOT_INFO: &[u8] = b"libsilver-DoubleRatchet-root";
+const HEADER_KEY_INFO: &[u8] = b"libsilver-DoubleRatchet-header";
+
+/// Maximum number of message keys skipped within a single chain
+pub const MAX_SKIP: u32 = 1000;
+
+/// Maximum number of skipped message keys kept across all chains
+const MAX_SKIPPED_KEYS: usize = 2000;
+
+type Key = Zeroizing<[u8; KEY_SIZE]>;
+
+#[derive(Clone)]
+struct HeaderKeys {
+    send: Option<Key>,
+    recv: Option<Key>,
+    next_send: Key,
+    next_recv: Key,
+}
+
+struct Header {
+    public_key: [u8; KEY_SIZE],
+    previous_chain_length: u32,
+    message_number: u32,
+}
+
+impl Header {
+    fn to_bytes(&self) -> [u8; HEADER_SIZE] {
+        let mut out = [0u8; HEADER_SIZE];
+        out[..KEY_SIZE].copy_from_slice(&self.public_key);
+        out[KEY_SIZE..KEY_SIZE + 4].copy_from_slice(&self.previous_chain_length.to_be_bytes());
+        out[KEY_SIZE + 4..].copy_from_slice(&self.message_number.to_be_bytes());
+        out
+    }
+
+    fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != HEADER_SIZE {
+            return Err(CryptoError::InvalidInput(DOUBLE_RATCHET_INVALID_MESSAGE));
+        }
+
+        Ok(Self {
+            public_key: bytes[..KEY_SIZE].try_into().unwrap(),
+            previous_chain_length: u32::from_be_bytes(bytes[KEY_SIZE..KEY_SIZE + 4].try_into().unwrap()),
+            message_number: u32::from_be_bytes(bytes[KEY_SIZE + 4..].try_into().unwrap()),
+        })
+    }
+}
+
+/// Signal-style Double Ratchet session
+///
+/// Combines a Diffie-Hellman ratchet over X25519 with HMAC-SHA256 symmetric-key
+/// chains, giving forward secrecy and post-compromise recovery per message.
+/// Message keys encrypt with AES-256-GCM, binding the session's associated data
+/// and the message header. Out-of-order messages are handled with a bounded cache
+/// of skipped message keys. With header encryption enabled, ratchet public keys
+/// and counters are hidden from observers too.
+///
+/// Sessions are normally bootstrapped from [`crate::core::x3dh::X3dh`]: the
+/// initiator ratchets against the responder's signed prekey.
+#[derive(Clone)]
+pub struct DoubleRatchet {
+    dh_self: StaticSecret,
+    dh_remote: Option<[u8; KEY_SIZE]>,
+    root_key: Key,
+    chain_send: Option<Key>,
+    chain_recv: Option<Key>,
+    send_count: u32,
+    recv_count: u32,
+    previous_send_count: u32,
+    header_keys: Option<HeaderKeys>,
+    skipped: BTreeMap<([u8; KEY_SIZE], u32), Key>,
+    skipped_order: VecDeque<([u8; KEY_SIZE], u32)>,
+    associated_data: Vec<u8>,
+}
+
+impl DoubleRatchet {
+    /// Start a session as the initiator, ratcheting against the responder's public key
+    pub fn initiate(shared_key: &SecureKey, remote_ratchet_key: &[u8], associated_data: &[u8]) -> CryptoResult<Self> {
+        Self::init_initiator(shared_key, remote_ratchet_key, associated_data, false)
+    }
+
+    /// Start a session as the responder, using the signed prekey the initiator ratcheted against
+    pub fn respond(shared_key: &SecureKey, ratchet_key: &X3dhSignedPrekey, associated_data: &[u8]) -> CryptoResult<Self> {
+        Self::init_responder(shared_key, ratchet_key, associated_data, false)
+    }
+
+    /// Like [`DoubleRatchet::initiate`], with encrypted message headers
+    pub fn initiate_with_header_encryption(shared_key: &SecureKey, remote_ratchet_key: &[u8], associated_data: &[u8]) -> CryptoResult<Self> {
+        Self::init_initiator(shared_key, remote_ratchet_key, associated_data, true)
+    }
+
+    /// Like [`DoubleRatchet::respond`], with encrypted message headers
+    pub fn respond_with_header_encryption(shared_key: &SecureKey, ratchet_key: &X3dhSignedPrekey, associated_data: &[u8]) -> CryptoResult<Self> {
+        Self::init_responder(shared_key, ratchet_key, associated_data, true)
+    }
+
+    /// Check whether message headers are encrypted
+    #[inline]
+    pub fn has_header_encryption(&self) -> bool {
+        self.header_keys.is_some()
+    }
+
+    /// Get the number of cached skipped message keys
+    #[inline]
+    pub fn skipped_message_keys(&self) -> usize {
+        self.skipped.len()
+    }
+
+    /// Encrypt the next message in the session
+    ///
+    /// The responder can only send after receiving the initiator's first message.
+    pub fn encrypt(&mut self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let chain_key = self.chain_send.as_ref()
+            .ok_or(CryptoError::InvalidInput(DOUBLE_RATCHET_NOT_READY))?;
+        let (next_chain_key, message_key) = kdf_chain(chain_key)?;
+
+        let header = Header {
+            public_key: X25519PublicKey::from(&self.dh_self).to_bytes(),
+            previous_chain_length: self.previous_send_count,
+            message_number: self.send_count,
+        };
+
+        let header_bytes = match &self.header_keys {
+            Some(keys) => {
+                let header_key = keys.send.as_ref()
+                    .ok_or(CryptoError::InvalidInput(DOUBLE_RATCHET_NOT_READY))?;
+                AesGcm::encrypt(&header.to_bytes(), &Aes256Key::from_bytes(header_key.as_slice())?)?
+            }
+            None => header.to_bytes().to_vec(),
+        };
+
+        let ciphertext = AesGcm::encrypt_with_aad(plaintext, &Aes256Key::from_bytes(message_key.as_slice())?, &self.message_aad(&header_bytes))?;
+
+        self.chain_send = Some(next_chain_key);
+        self.send_count = self.send_count.checked_add(1)
+            .ok_or(CryptoError::InvalidInput(DOUBLE_RATCHET_TOO_MANY_SKIPPED))?;
+
+        let mut message = header_bytes;
+        message.extend_from_slice(&ciphertext);
+        Ok(message)
+    }
+
+    /// Decrypt a message from the peer, in any order
+    ///
+    /// The session is left untouched if the message fails to decrypt.
+    pub fn decrypt(&mut self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        let mut next = self.clone();
+        let plaintext = next.decrypt_in_place(message)?;
+        *self = next;
+        Ok(plaintext)
+    }
+
+    fn init_initiator(shared_key: &SecureKey, remote_ratchet_key: &[u8], associated_data: &[u8], header_encryption: bool) -> CryptoResult<Self> {
+        let remote: [u8; KEY_SIZE] = remote_ratchet_key.try_into()
+            .map_err(|_| CryptoError::InvalidKey(DOUBLE_RATCHET_INVALID_KEY))?;
+        let root_key = to_key(shared_key)?;
+
+        let dh_self = StaticSecret::random_from_rng(OsRng);
+        let dh_out = diffie_hellman(&dh_self, &remote)?;
+        let (root_key, chain_send, next_header_key) = kdf_root(&root_key, &dh_out)?;
+
+        let header_keys = if header_encryption {
+            let (initiator_header_key, responder_header_key) = initial_header_keys(shared_key)?;
+            Some(HeaderKeys {
+                send: Some(initiator_header_key),
+                recv: None,
+                next_send: next_header_key,
+                next_recv: responder_header_key,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            dh_self,
+            dh_remote: Some(remote),
+            root_key,
+            chain_send: Some(chain_send),
+            chain_recv: None,
+            send_count: 0,
+            recv_count: 0,
+            previous_send_count: 0,
+            header_keys,
+            skipped: BTreeMap::new(),
+            skipped_order: VecDeque::new(),
+            associated_data: associated_data.to_vec(),
+        })
+    }
+
+    fn init_responder(shared_key: &SecureKey, ratchet_key: &X3dhSignedPrekey, associated_data: &[u8], header_encryption: bool) -> CryptoResult<Self> {
+        let header_keys = if header_encryption {
+            let (initiator_header_key, responder_header_key) = initial_header_keys(shared_key)?;
+            Some(HeaderKeys {
+                send: None,
+                recv: None,
+                next_send: responder_header_key,
+                next_recv: initiator_header_key,
+            })
+        } else {
+            None
+        };
+
+        Ok(Self {
+            dh_self: ratchet_key.secret().clone(),
+            dh_remote: None,
+            root_key: to_key(shared_key)?,
+            chain_send: None,
+            chain_recv: None,
+            send_count: 0,
+            recv_count: 0,
+            previous_send_count: 0,
+            header_keys,
+            skipped: BTreeMap::new(),
+            skipped_order: VecDeque::new(),
+            associated_data: associated_data.to_vec(),
+        })
+    }
+
+    fn decrypt_in_place(&mut self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        let header_size = if self.header_keys.is_some() { ENCRYPTED_HEADER_SIZE } else { HEADER_SIZE };
+        if message.len() < header_size {
+            return Err(CryptoError::InvalidInput(DOUBLE_RATCHET_INVALID_MESSAGE));
+        }
+        let (header_bytes, ciphertext) = message.split_at(header_size);
+        let aad = self.message_aad(header_bytes);
+
+        let (header, ratchet) = match self.header_keys.clone() {
+            Some(keys) => {
+                // Cached keys are indexed by the header key of their chain
+                for (header_key, number) in self.skipped.keys().cloned().collect::<Vec<_>>() {
+                    if let Ok(bytes) = AesGcm::decrypt(header_bytes, &Aes256Key::from_bytes(&header_key)?) {
+                        if Header::from_bytes(&bytes)?.message_number == number {
+                            let message_key = self.take_skipped(&(header_key, number));
+                            return AesGcm::decrypt_with_aad(ciphertext, &Aes256Key::from_bytes(message_key.as_slice())?, &aad);
+                        }
+                    }
+                }
+
+                if let Some(bytes) = keys.recv.as_ref().and_then(|key| AesGcm::decrypt(header_bytes, &Aes256Key::from_bytes(key.as_slice()).ok()?).ok()) {
+                    (Header::from_bytes(&bytes)?, false)
+                } else if let Ok(bytes) = AesGcm::decrypt(header_bytes, &Aes256Key::from_bytes(keys.next_recv.as_slice())?) {
+                    (Header::from_bytes(&bytes)?, true)
+                } else {
+                    return Err(CryptoError::DecryptionFailed(DOUBLE_RATCHET_HEADER_DECRYPTION_FAILED));
+                }
+            }
+            None => {
+                let header = Header::from_bytes(header_bytes)?;
+                let skipped_id = (header.public_key, header.message_number);
+                if self.skipped.contains_key(&skipped_id) {
+                    let message_key = self.take_skipped(&skipped_id);
+                    return AesGcm::decrypt_with_aad(ciphertext, &Aes256Key::from_bytes(message_key.as_slice())?, &aad);
+                }
+
+                let ratchet = self.dh_remote != Some(header.public_key);
+                (header, ratchet)
+            }
+        };
+
+        if ratchet {
+            self.skip_message_keys(header.previous_chain_length)?;
+            self.dh_ratchet(&header)?;
+        }
+        self.skip_message_keys(header.message_number)?;
+
+        let chain_key = self.chain_recv.as_ref()
+            .ok_or(CryptoError::InvalidInput(DOUBLE_RATCHET_INVALID_MESSAGE))?;
+        let (next_chain_key, message_key) = kdf_chain(chain_key)?;
+        self.chain_recv = Some(next_chain_key);
+        self.recv_count += 1;
+
+        AesGcm::decrypt_with_aad(ciphertext, &Aes256Key::from_bytes(message_key.as_slice())?, &aad)
+    }
+
+    fn dh_ratchet(&mut self, header: &Header) -> CryptoResult<()> {
+        self.previous_send_count = self.send_count;
+        self.send_count = 0;
+        self.recv_count = 0;
+        self.dh_remote = Some(header.public_key);
+
+        let dh_out = diffie_hellman(&self.dh_self, &header.public_key)?;
+        let (root_key, chain_recv, next_recv_header_key) = kdf_root(&self.root_key, &dh_out)?;
+
+        self.dh_self = StaticSecret::random_from_rng(OsRng);
+        let dh_out = diffie_hellman(&self.dh_self, &header.public_key)?;
+        let (root_key, chain_send, next_send_header_key) = kdf_root(&root_key, &dh_out)?;
+
+        if let Some(keys) = &mut self.header_keys {
+            keys.send = Some(std::mem::replace(&mut keys.next_send, next_send_header_key));
+            keys.recv = Some(std::mem::replace(&mut keys.next_recv, next_recv_header_key));
+        }
+
+        self.root_key = root_key;
+        self.chain_recv = Some(chain_recv);
+        self.chain_send = Some(chain_send);
+        Ok(())
+    }
+
+    fn skip_message_keys(&mut self, until: u32) -> CryptoResult<()> {
+        if until.saturating_sub(self.recv_count) > MAX_SKIP {
+            return Err(CryptoError::InvalidInput(DOUBLE_RATCHET_TOO_MANY_SKIPPED));
+        }
+
+        // Skipped keys are indexed by ratchet public key, or by header key when headers are encrypted
+        let chain_id = match &self.header_keys {
+            Some(keys) => keys.recv.as_deref().copied(),
+            None => self.dh_remote,
+        };
+
+        if let (Some(chain_id), Some(mut chain_key)) = (chain_id, self.chain_recv.clone()) {
+            while self.recv_count < until {
+                let (next_chain_key, message_key) = kdf_chain(&chain_key)?;
+                chain_key = next_chain_key;
+
+                let id = (chain_id, self.recv_count);
+                self.skipped.insert(id, message_key);
+                self.skipped_order.push_back(id);
+                if self.skipped_order.len() > MAX_SKIPPED_KEYS {
+                    if let Some(oldest) = self.skipped_order.pop_front() {
+                        self.skipped.remove(&oldest);
+                    }
+                }
+                self.recv_count += 1;
+            }
+            self.chain_recv = Some(chain_key);
+        }
+
+        Ok(())
+    }
+
+    fn take_skipped(&mut self, id: &([u8; KEY_SIZE], u32)) -> Key {
+        self.skipped_order.retain(|entry| entry != id);
+        self.skipped.remove(id).unwrap()
+    }
+
+    fn message_aad(&self, header: &[u8]) -> Vec<u8> {
+        let mut aad = Vec::with_capacity(self.associated_data.len() + header.len());
+        aad.extend_from_slice(&self.associated_data);
+        aad.extend_from_slice(header);
+        aad
+    }
+}
+
+impl std::fmt::Debug for DoubleRatchet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DoubleRatchet")
+            .field("send_count", &self.send_count)
+            .field("recv_count", &self.recv_count)
+            .field("header_encryption", &self.has_header_encryption())
+            .field("skipped_message_keys", &self.skipped.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[inline]
+fn to_key(key: &SecureKey) -> CryptoResult<Key> {
+    key.as_bytes().try_into()
+        .map(Zeroizing::new)
+        .map_err(|_| CryptoError::InvalidKey(DOUBLE_RATCHET_INVALID_KEY))
+}
+
+#[inline]
+fn diffie_hellman(secret: &StaticSecret, public: &[u8; KEY_SIZE]) -> CryptoResult<Key> {
+    let shared = secret.diffie_hellman(&X25519PublicKey::from(*public));
+    if !shared.was_contributory() {
+        return Err(CryptoError::InvalidKey(DOUBLE_RATCHET_INVALID_KEY));
+    }
+    Ok(Zeroizing::new(shared.to_bytes()))
+}
+
+// KDF_RK: HKDF-SHA256(salt = root key, ikm = DH output) -> root key | chain key | next header key
+fn kdf_root(root_key: &Key, dh_out: &Key) -> CryptoResult<(Key, Key, Key)> {
+    let mut okm = Zeroizing::new([0u8; KEY_SIZE * 3]);
+    Hkdf::<Sha256>::new(Some(root_key.as_slice()), dh_out.as_slice())
+        .expand(ROOT_INFO, okm.as_mut_slice())
+        .map_err(|_| CryptoError::KeyDerivationFailed(HKDF_SHA256_FAILED))?;
+
+    Ok((split_key(&okm[..KEY_SIZE]), split_key(&okm[KEY_SIZE..KEY_SIZE * 2]), split_key(&okm[KEY_SIZE * 2..])))
+}
+
+// KDF_CK: message key = HMAC(chain key, 0x01), next chain key = HMAC(chain key, 0x02)
+fn kdf_chain(chain_key: &Key) -> CryptoResult<(Key, Key)> {
+    let derive = |constant: u8| -> CryptoResult<Key> {
+        let mut mac = <HmacImpl<Sha256> as Mac>::new_from_slice(chain_key.as_slice())
+            .map_err(|_| CryptoError::InvalidKey(DOUBLE_RATCHET_INVALID_KEY))?;
+        mac.update(&[constant]);
+        Ok(Zeroizing::new(mac.finalize().into_bytes().into()))
+    };
+
+    let message_key = derive(0x01)?;
+    let next_chain_key = derive(0x02)?;
+    Ok((next_chain_key, message_key))
+}
+
+// Shared header keys for the first message in each direction, derived from the X3DH secret
+fn initial_header_keys(shared_key: &SecureKey) -> CryptoResult<(Key, Key)> {
+    let mut okm = Zeroizing::new([0u8; KEY_SIZE * 2]);
+    Hkdf::<Sha256>::new(None, shared_key.as_bytes())
+        .expand(HEADER_KEY_INFO, okm.as_mut_slice())
+        .map_err(|_| CryptoError::KeyDerivationFailed(HKDF_SHA256_FAILED))?;
+
+    Ok((split_key(&okm[..KEY_SIZE]), split_key(&okm[KEY_SIZE..])))
+}
+
+#[inline]
+fn split_key(bytes: &[u8]) -> Key {
+    Zeroizing::new(bytes.try_into().unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::Ed25519KeyPair;
+    use crate::core::x3dh::X3dh;
+
+    fn session_pair(header_encryption: bool) -> (DoubleRatchet, DoubleRatchet) {
+        let alice_identity = Ed25519KeyPair::generate().unwrap();
+        let bob_identity = Ed25519KeyPair::generate().unwrap();
+        let signed_prekey = X3dh::generate_signed_prekey(&bob_identity, 1).unwrap();
+        let bundle = X3dh::create_bundle(&bob_identity, &signed_prekey, None);
+
+        let (alice_out, message) = X3dh::initiate(&alice_identity, &bundle).unwrap();
+        let bob_out = X3dh::respond(&bob_identity, &signed_prekey, None, &message).unwrap();
+
+        if header_encryption {
+            (
+                DoubleRatchet::initiate_with_header_encryption(&alice_out.shared_key, &bundle.signed_prekey, &alice_out.associated_data).unwrap(),
+                DoubleRatchet::respond_with_header_encryption(&bob_out.shared_key, &signed_prekey, &bob_out.associated_data).unwrap(),
+            )
+        } else {
+            (
+                DoubleRatchet::initiate(&alice_out.shared_key, &bundle.signed_prekey, &alice_out.associated_data).unwrap(),
+                DoubleRatchet::respond(&bob_out.shared_key, &signed_prekey, &bob_out.associated_data).unwrap(),
+            )
+        }
+    }
+
+    fn conversation(header_encryption: bool) {
+        let (mut alice, mut bob) = session_pair(header_encryption);
+        assert_eq!(alice.has_header_encryption(), header_encryption);
+
+        // Bob cannot speak first
+        assert!(bob.encrypt(b"hi").is_err());
+
+        let m1 = alice.encrypt(b"hello bob").unwrap();
+        assert_eq!(bob.decrypt(&m1).unwrap(), b"hello bob");
+
+        let r1 = bob.encrypt(b"hello alice").unwrap();
+        let r2 = bob.encrypt(b"how are you").unwrap();
+        assert_eq!(alice.decrypt(&r2).unwrap(), b"how are you");
+        assert_eq!(alice.skipped_message_keys(), 1);
+        assert_eq!(alice.decrypt(&r1).unwrap(), b"hello alice");
+        assert_eq!(alice.skipped_message_keys(), 0);
+
+        // Out of order across a ratchet step
+        let m2 = alice.encrypt(b"one").unwrap();
+        let m3 = alice.encrypt(b"two").unwrap();
+        let r3 = bob.encrypt(b"three").unwrap();
+        assert_eq!(bob.decrypt(&m3).unwrap(), b"two");
+        assert_eq!(alice.decrypt(&r3).unwrap(), b"three");
+        let m4 = alice.encrypt(b"four").unwrap();
+        assert_eq!(bob.decrypt(&m4).unwrap(), b"four");
+        assert_eq!(bob.decrypt(&m2).unwrap(), b"one");
+
+        // Replays are rejected
+        assert!(bob.decrypt(&m2).is_err());
+        assert!(bob.decrypt(&m1).is_err());
+    }
+
+    #[test]
+    fn test_double_ratchet_conversation() {
+        conversation(false);
+    }
+
+    #[test]
+    fn test_double_ratchet_header_encryption() {
+        conversation(true);
+
+        // Headers no longer expose the ratchet public key
+        let (mut alice, _) = session_pair(true);
+        let m1 = alice.encrypt(b"a").unwrap();
+        let m2 = alice.encrypt(b"b").unwrap();
+        assert_ne!(m1[..KEY_SIZE], m2[..KEY_SIZE]);
+        assert_eq!(m1.len(), ENCRYPTED_HEADER_SIZE + 12 + 1 + 16);
+    }
+
+    #[test]
+    fn test_double_ratchet_tampering_keeps_state() {
+        let (mut alice, mut bob) = session_pair(false);
+        let m1 = alice.encrypt(b"message").unwrap();
+
+        let mut tampered = m1.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(bob.decrypt(&tampered).is_err());
+
+        // Header fields are authenticated as AAD
+        let mut tampered = m1.clone();
+        tampered[KEY_SIZE] ^= 1;
+        assert!(bob.decrypt(&tampered).is_err());
+
+        assert!(bob.decrypt(&m1[..10]).is_err());
+        assert_eq!(bob.decrypt(&m1).unwrap(), b"message");
+    }
+
+    #[test]
+    fn test_double_ratchet_skip_limit() {
+        let (mut alice, mut bob) = session_pair(false);
+        for _ in 0..=MAX_SKIP {
+            alice.encrypt(b"dropped").unwrap();
+        }
+        let late = alice.encrypt(b"late").unwrap();
+        assert_eq!(bob.decrypt(&late).unwrap_err(), CryptoError::InvalidInput(DOUBLE_RATCHET_TOO_MANY_SKIPPED));
+    }
+}
@@ -0,0 +1,233 @@
+use crate::error::{CryptoError, CryptoResult, UNSUPPORTED_ALGORITHM, ALGORITHM_NOT_AEAD, ALGORITHM_NOT_SIGNATURE};
+#[cfg(feature = "rsa")]
+use crate::error::PUBLIC_KEY_DECODING_FAILED;
+use crate::core::signer::{SignatureAlgorithm, Signer, Verifier};
+use crate::core::symmetric::Aead;
+#[cfg(feature = "aes")]
+use crate::core::symmetric::AesGcm;
+#[cfg(feature = "chacha")]
+use crate::core::symmetric::ChaCha20Poly1305Cipher;
+#[cfg(feature = "ecdsa")]
+use crate::core::asymmetric::EcdsaKeyPair;
+#[cfg(feature = "ed25519")]
+use crate::core::asymmetric::Ed25519KeyPair;
+#[cfg(feature = "rsa")]
+use crate::core::asymmetric::RsaKeyPair;
+#[cfg(feature = "rsa")]
+use rsa::{RsaPublicKey, pkcs8::DecodePublicKey};
+use core::fmt;
+use core::str::FromStr;
+use alloc::boxed::Box;
+
+/// Every algorithm that can be selected at runtime
+///
+/// Names are stable strings for configuration files (`"AES-256-GCM"`,
+/// `"Ed25519"`, ...) and IDs are stable single bytes for wire headers. Both
+/// resolve to the trait objects that implement the algorithm. Every variant
+/// exists in every build; resolving one whose cargo feature is disabled fails
+/// with an unsupported-algorithm error.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let cipher = Algorithm::from_name("ChaCha20-Poly1305")?.aead()?;
+/// let key = cipher.generate_key()?;
+/// let ciphertext = cipher.encrypt(b"configured at runtime", &key)?;
+/// assert_eq!(cipher.decrypt(&ciphertext, &key)?, b"configured at runtime");
+/// # Ok::<(), CryptoError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Algorithm {
+    /// AES-256-GCM authenticated encryption
+    Aes256Gcm,
+    /// ChaCha20-Poly1305 authenticated encryption
+    ChaCha20Poly1305,
+    /// Ed25519 signatures
+    Ed25519,
+    /// ECDSA P-256 signatures with SHA-256
+    EcdsaP256Sha256,
+    /// RSASSA-PSS signatures with SHA-256
+    RsaPssSha256,
+}
+
+impl Algorithm {
+    /// Every registered algorithm, in ID order
+    pub const ALL: [Algorithm; 5] = [
+        Algorithm::Aes256Gcm,
+        Algorithm::ChaCha20Poly1305,
+        Algorithm::Ed25519,
+        Algorithm::EcdsaP256Sha256,
+        Algorithm::RsaPssSha256,
+    ];
+
+    /// Get the algorithm name
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Algorithm::Aes256Gcm => "AES-256-GCM",
+            Algorithm::ChaCha20Poly1305 => "ChaCha20-Poly1305",
+            Algorithm::Ed25519 => "Ed25519",
+            Algorithm::EcdsaP256Sha256 => "ECDSA-P256-SHA256",
+            Algorithm::RsaPssSha256 => "RSA-PSS-SHA256",
+        }
+    }
+
+    /// Look up an algorithm by name
+    pub fn from_name(name: &str) -> CryptoResult<Self> {
+        Self::ALL.into_iter()
+            .find(|algorithm| algorithm.as_str() == name)
+            .ok_or(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM))
+    }
+
+    /// Get the one-byte wire ID
+    #[inline]
+    pub fn id(self) -> u8 {
+        match self {
+            Algorithm::Aes256Gcm => 0x01,
+            Algorithm::ChaCha20Poly1305 => 0x02,
+            Algorithm::Ed25519 => 0x10,
+            Algorithm::EcdsaP256Sha256 => 0x11,
+            Algorithm::RsaPssSha256 => 0x12,
+        }
+    }
+
+    /// Look up an algorithm by wire ID
+    pub fn from_id(id: u8) -> CryptoResult<Self> {
+        Self::ALL.into_iter()
+            .find(|algorithm| algorithm.id() == id)
+            .ok_or(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM))
+    }
+
+    /// Get the cipher of an AEAD algorithm
+    pub fn aead(self) -> CryptoResult<&'static dyn Aead> {
+        match self {
+            #[cfg(feature = "aes")]
+            Algorithm::Aes256Gcm => Ok(&AesGcm),
+            #[cfg(feature = "chacha")]
+            Algorithm::ChaCha20Poly1305 => Ok(&ChaCha20Poly1305Cipher),
+            #[cfg(not(feature = "aes"))]
+            Algorithm::Aes256Gcm => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+            #[cfg(not(feature = "chacha"))]
+            Algorithm::ChaCha20Poly1305 => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+            _ => Err(CryptoError::InvalidInput(ALGORITHM_NOT_AEAD)),
+        }
+    }
+
+    /// Get the signature scheme of a signature algorithm
+    pub fn signature_algorithm(self) -> CryptoResult<SignatureAlgorithm> {
+        match self {
+            Algorithm::Ed25519 => Ok(SignatureAlgorithm::Ed25519),
+            Algorithm::EcdsaP256Sha256 => Ok(SignatureAlgorithm::EcdsaP256Sha256),
+            Algorithm::RsaPssSha256 => Ok(SignatureAlgorithm::RsaPssSha256),
+            _ => Err(CryptoError::InvalidInput(ALGORITHM_NOT_SIGNATURE)),
+        }
+    }
+
+    /// Load a signing key: a 32-byte Ed25519 seed, a 32-byte P-256 scalar
+    /// or a PKCS#8 DER RSA key
+    #[cfg_attr(not(any(feature = "rsa", feature = "ecdsa", feature = "ed25519")), allow(unused_variables))]
+    pub fn signer_from_private_key(self, private_key: &[u8]) -> CryptoResult<Box<dyn Signer>> {
+        match self.signature_algorithm()? {
+            #[cfg(feature = "ed25519")]
+            SignatureAlgorithm::Ed25519 => Ok(Box::new(Ed25519KeyPair::from_private_key_bytes(private_key)?)),
+            #[cfg(feature = "ecdsa")]
+            SignatureAlgorithm::EcdsaP256Sha256 => Ok(Box::new(EcdsaKeyPair::from_private_key_bytes(private_key)?)),
+            #[cfg(feature = "rsa")]
+            SignatureAlgorithm::RsaPssSha256 => Ok(Box::new(RsaKeyPair::from_private_key_der(private_key)?)),
+            #[allow(unreachable_patterns)]
+            _ => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+        }
+    }
+
+    /// Load a verifying key: a 32-byte Ed25519 key, a SEC1 P-256 point or an
+    /// SPKI DER RSA key
+    #[cfg_attr(not(any(feature = "rsa", feature = "ecdsa", feature = "ed25519")), allow(unused_variables))]
+    pub fn verifier_from_public_key(self, public_key: &[u8]) -> CryptoResult<Box<dyn Verifier>> {
+        match self.signature_algorithm()? {
+            #[cfg(feature = "ed25519")]
+            SignatureAlgorithm::Ed25519 => Ok(Box::new(Ed25519KeyPair::verifying_key_from_bytes(public_key)?)),
+            #[cfg(feature = "ecdsa")]
+            SignatureAlgorithm::EcdsaP256Sha256 => Ok(Box::new(EcdsaKeyPair::verifying_key_from_bytes(public_key)?)),
+            #[cfg(feature = "rsa")]
+            SignatureAlgorithm::RsaPssSha256 => RsaPublicKey::from_public_key_der(public_key)
+                .map(|key| Box::new(key) as Box<dyn Verifier>)
+                .map_err(|_| CryptoError::InvalidKey(PUBLIC_KEY_DECODING_FAILED)),
+            #[allow(unreachable_patterns)]
+            _ => Err(CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM)),
+        }
+    }
+}
+
+impl From<SignatureAlgorithm> for Algorithm {
+    #[inline]
+    fn from(algorithm: SignatureAlgorithm) -> Self {
+        match algorithm {
+            SignatureAlgorithm::Ed25519 => Algorithm::Ed25519,
+            SignatureAlgorithm::EcdsaP256Sha256 => Algorithm::EcdsaP256Sha256,
+            SignatureAlgorithm::RsaPssSha256 => Algorithm::RsaPssSha256,
+        }
+    }
+}
+
+impl FromStr for Algorithm {
+    type Err = CryptoError;
+
+    #[inline]
+    fn from_str(name: &str) -> CryptoResult<Self> {
+        Self::from_name(name)
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(all(test, feature = "aes", feature = "chacha", feature = "ecdsa", feature = "ed25519", feature = "rsa"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_algorithm_names_and_ids() {
+        for algorithm in Algorithm::ALL {
+            assert_eq!(Algorithm::from_name(algorithm.as_str()).unwrap(), algorithm);
+            assert_eq!(Algorithm::from_id(algorithm.id()).unwrap(), algorithm);
+            assert_eq!(algorithm.to_string().parse::<Algorithm>().unwrap(), algorithm);
+
+            // Names agree with the trait objects they resolve to
+            match (algorithm.aead(), algorithm.signature_algorithm()) {
+                (Ok(cipher), Err(_)) => assert_eq!(cipher.name(), algorithm.as_str()),
+                (Err(_), Ok(scheme)) => assert_eq!(scheme.as_str(), algorithm.as_str()),
+                _ => panic!("{algorithm} must be exactly one kind"),
+            }
+        }
+
+        assert_eq!(Algorithm::from_name("aes-256-gcm").unwrap_err(), CryptoError::InvalidInput(UNSUPPORTED_ALGORITHM));
+        assert!(Algorithm::from_id(0).is_err());
+        assert_eq!(Algorithm::Ed25519.aead().err().unwrap(), CryptoError::InvalidInput(ALGORITHM_NOT_AEAD));
+    }
+
+    #[test]
+    fn test_signers_from_key_bytes() {
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        let ecdsa = EcdsaKeyPair::generate().unwrap();
+        let keys = [
+            (Algorithm::Ed25519, ed25519.private_key_bytes(), ed25519.public_key_bytes()),
+            (Algorithm::EcdsaP256Sha256, ecdsa.private_key_bytes(), ecdsa.public_key_bytes()),
+        ];
+
+        for (algorithm, private_key, public_key) in keys {
+            let signer = algorithm.signer_from_private_key(&private_key).unwrap();
+            let verifier = algorithm.verifier_from_public_key(&public_key).unwrap();
+            assert_eq!(Algorithm::from(signer.algorithm()), algorithm);
+
+            let signature = signer.sign(b"runtime").unwrap();
+            assert!(verifier.verify(b"runtime", &signature).unwrap());
+        }
+
+        assert!(Algorithm::Ed25519.signer_from_private_key(&[0u8; 5]).is_err());
+        assert!(Algorithm::RsaPssSha256.verifier_from_public_key(b"not DER").is_err());
+        assert_eq!(Algorithm::Aes256Gcm.signer_from_private_key(&[0u8; 32]).err().unwrap(), CryptoError::InvalidInput(ALGORITHM_NOT_SIGNATURE));
+    }
+}
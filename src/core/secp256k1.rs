@@ -0,0 +1,267 @@
+use crate::error::{CryptoError, CryptoResult, INVALID_SIGNATURE_FORMAT, SECP256K1_INVALID_PRIVATE_KEY, SECP256K1_INVALID_PUBLIC_KEY, SECP256K1_SIGNING_FAILED, SECP256K1_RECOVERY_FAILED};
+use crate::core::encoding::encode_hex;
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+use k256::ecdsa::signature::{Signer, Verifier};
+use rand::rngs::OsRng;
+use sha3::{Digest, Keccak256};
+use alloc::string::String;
+use alloc::vec::Vec;
+use zeroize::{ZeroizeOnDrop, Zeroizing};
+
+/// Length of a recoverable signature: `r || s || v`
+pub const RECOVERABLE_SIGNATURE_LEN: usize = 65;
+
+/// secp256k1 key pair
+#[derive(Clone)]
+pub struct Secp256k1KeyPair {
+    signing_key: SigningKey,
+    verifying_key: VerifyingKey,
+}
+
+impl Secp256k1KeyPair {
+    /// Generate a new secp256k1 key pair
+    pub fn generate() -> CryptoResult<Self> {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = *signing_key.verifying_key();
+
+        Ok(Self {
+            signing_key,
+            verifying_key,
+        })
+    }
+
+    /// Get the verifying key (public key)
+    #[inline]
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Get the signing key (private key)
+    #[inline]
+    pub fn signing_key(&self) -> &SigningKey {
+        &self.signing_key
+    }
+
+    /// Export private key bytes
+    #[inline]
+    pub fn private_key_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(Zeroizing::new(self.signing_key.to_bytes()).to_vec())
+    }
+
+    /// Export public key bytes (uncompressed SEC1)
+    #[inline]
+    pub fn public_key_bytes(&self) -> Vec<u8> {
+        self.verifying_key.to_encoded_point(false).as_bytes().to_vec()
+    }
+
+    /// Import from private key bytes; the scalar must be in `1..n`
+    pub fn from_private_key_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        let signing_key = SigningKey::from_slice(bytes)
+            .map_err(|_| CryptoError::InvalidKey(SECP256K1_INVALID_PRIVATE_KEY))?;
+        let verifying_key = *signing_key.verifying_key();
+
+        Ok(Self {
+            signing_key,
+            verifying_key,
+        })
+    }
+
+    /// Import verifying key from SEC1 bytes (compressed or uncompressed)
+    pub fn verifying_key_from_bytes(bytes: &[u8]) -> CryptoResult<VerifyingKey> {
+        VerifyingKey::from_sec1_bytes(bytes)
+            .map_err(|_| CryptoError::InvalidKey(SECP256K1_INVALID_PUBLIC_KEY))
+    }
+
+    /// Ethereum address of this key pair
+    #[inline]
+    pub fn ethereum_address(&self) -> [u8; 20] {
+        Secp256k1Crypto::ethereum_address(&self.verifying_key)
+    }
+}
+
+// The wrapped private key zeroizes itself on drop
+impl ZeroizeOnDrop for Secp256k1KeyPair {}
+
+/// secp256k1 ECDSA signatures, public key recovery and Ethereum addresses
+///
+/// Signatures are low-S normalized, as Bitcoin and Ethereum require, and
+/// verification rejects high-S signatures.
+pub struct Secp256k1Crypto;
+
+impl Secp256k1Crypto {
+    /// Generate a new secp256k1 key pair
+    #[inline]
+    pub fn generate_keypair() -> CryptoResult<Secp256k1KeyPair> {
+        Secp256k1KeyPair::generate()
+    }
+
+    /// Sign with ECDSA secp256k1 and SHA-256 (JOSE `ES256K`), returning `r || s`
+    pub fn sign(message: &[u8], signing_key: &SigningKey) -> CryptoResult<Vec<u8>> {
+        let signature: Signature = signing_key.try_sign(message)
+            .map_err(|_| CryptoError::SignatureFailed(SECP256K1_SIGNING_FAILED))?;
+        Ok(signature.to_bytes().to_vec())
+    }
+
+    /// Verify an ECDSA secp256k1 signature made by [`Self::sign`]
+    pub fn verify(message: &[u8], signature: &[u8], verifying_key: &VerifyingKey) -> CryptoResult<bool> {
+        let signature = Signature::from_slice(signature)
+            .map_err(|_| CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT))?;
+        Ok(verifying_key.verify(message, &signature).is_ok())
+    }
+
+    /// Sign the Keccak-256 hash of `message`, returning `r || s || v` with `v` in `{0, 1}`
+    #[inline]
+    pub fn sign_recoverable(message: &[u8], signing_key: &SigningKey) -> CryptoResult<[u8; RECOVERABLE_SIGNATURE_LEN]> {
+        Self::sign_recoverable_prehash(&Self::keccak256(message), signing_key)
+    }
+
+    /// Sign a 32-byte hash, such as an Ethereum transaction or EIP-191 message hash
+    ///
+    /// Nonces are deterministic (RFC 6979). Add 27 to `v` for APIs that
+    /// expect Ethereum's legacy encoding.
+    pub fn sign_recoverable_prehash(hash: &[u8; 32], signing_key: &SigningKey) -> CryptoResult<[u8; RECOVERABLE_SIGNATURE_LEN]> {
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(hash)
+            .map_err(|_| CryptoError::SignatureFailed(SECP256K1_SIGNING_FAILED))?;
+        let mut out = [0u8; RECOVERABLE_SIGNATURE_LEN];
+        out[..64].copy_from_slice(&signature.to_bytes());
+        out[64] = recovery_id.to_byte();
+        Ok(out)
+    }
+
+    /// Recover the public key that made a [`Self::sign_recoverable`] signature over `message`
+    #[inline]
+    pub fn recover_pubkey(message: &[u8], signature: &[u8]) -> CryptoResult<VerifyingKey> {
+        Self::recover_pubkey_prehash(&Self::keccak256(message), signature)
+    }
+
+    /// Recover the public key from a recoverable signature over a 32-byte hash
+    ///
+    /// `v` may be 0/1 or Ethereum's legacy 27/28.
+    pub fn recover_pubkey_prehash(hash: &[u8; 32], signature: &[u8]) -> CryptoResult<VerifyingKey> {
+        let (signature, v) = match signature {
+            [signature @ .., v] if signature.len() == 64 => (signature, *v),
+            _ => return Err(CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT)),
+        };
+        let recovery_id = match v {
+            0 | 1 => RecoveryId::from_byte(v),
+            27 | 28 => RecoveryId::from_byte(v - 27),
+            _ => None,
+        }.ok_or(CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT))?;
+        let signature = Signature::from_slice(signature)
+            .map_err(|_| CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT))?;
+        // Only low-S signatures are accepted, so each one recovers a single key
+        if signature.normalize_s().is_some() {
+            return Err(CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT));
+        }
+
+        VerifyingKey::recover_from_prehash(hash, &signature, recovery_id)
+            .map_err(|_| CryptoError::VerificationFailed(SECP256K1_RECOVERY_FAILED))
+    }
+
+    /// Ethereum address: the last 20 bytes of the Keccak-256 hash of the uncompressed public key
+    pub fn ethereum_address(verifying_key: &VerifyingKey) -> [u8; 20] {
+        let point = verifying_key.to_encoded_point(false);
+        let hash = Self::keccak256(&point.as_bytes()[1..]);
+        let mut address = [0u8; 20];
+        address.copy_from_slice(&hash[12..]);
+        address
+    }
+
+    /// Format an Ethereum address as `0x`-prefixed hex with the EIP-55 mixed-case checksum
+    pub fn ethereum_address_checksummed(address: &[u8; 20]) -> String {
+        let lower = encode_hex(address);
+        let hash = Self::keccak256(lower.as_bytes());
+        let mut out = String::with_capacity(42);
+        out.push_str("0x");
+        for (index, c) in lower.chars().enumerate() {
+            let nibble = (hash[index / 2] >> (4 * (1 - index % 2))) & 0x0f;
+            out.push(if nibble >= 8 { c.to_ascii_uppercase() } else { c });
+        }
+        out
+    }
+
+    #[inline]
+    fn keccak256(data: &[u8]) -> [u8; 32] {
+        Keccak256::digest(data).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // web3.js `eth.accounts` documentation key
+    const PRIVATE_KEY: &str = "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+
+    #[test]
+    fn test_secp256k1_ethereum_vectors() {
+        let keypair = Secp256k1KeyPair::from_private_key_bytes(&hex::decode(PRIVATE_KEY).unwrap()).unwrap();
+        let address = keypair.ethereum_address();
+        assert_eq!(Secp256k1Crypto::ethereum_address_checksummed(&address), "0x2c7536E3605D9C16a7a3D7b1898e529396a65c23");
+
+        // EIP-191 personal message hash of "Some data"
+        let hash = Secp256k1Crypto::keccak256(b"\x19Ethereum Signed Message:\n9Some data");
+        assert_eq!(hex::encode(hash), "1da44b586eb0729ff70a73c326926f6ed5a25f5b056e7f47fbc6e58d86871655");
+        let signature = Secp256k1Crypto::sign_recoverable_prehash(&hash, keypair.signing_key()).unwrap();
+        assert_eq!(
+            hex::encode(signature),
+            "b91467e570a6466aa9e9876cbcd013baba02900b8979d43fe208a4a4f339f5fd6007e74cd82e037b800186422fc2da167c747ef045e5d18a5f5d4300f8e1a02901"
+        );
+
+        // Legacy v = 27/28 is accepted on recovery
+        let mut legacy = signature;
+        legacy[64] += 27;
+        let recovered = Secp256k1Crypto::recover_pubkey_prehash(&hash, &legacy).unwrap();
+        assert_eq!(Secp256k1Crypto::ethereum_address(&recovered), address);
+
+        // EIP-55 examples
+        for checksummed in ["0x5aAeb6053F3E94C9b9A09f33669435E7Ef1BeAed", "0xfB6916095ca1df60bB79Ce92cE3Ea74c37c5d359", "0xdbF03B407c01E7cD3CBea99509d93f8DDDC8C6FB"] {
+            let address: [u8; 20] = hex::decode(&checksummed[2..]).unwrap().try_into().unwrap();
+            assert_eq!(Secp256k1Crypto::ethereum_address_checksummed(&address), checksummed);
+        }
+    }
+
+    #[test]
+    fn test_secp256k1_sign_and_recover() {
+        let keypair = Secp256k1Crypto::generate_keypair().unwrap();
+        let signature = Secp256k1Crypto::sign(b"message", keypair.signing_key()).unwrap();
+        assert!(Secp256k1Crypto::verify(b"message", &signature, keypair.verifying_key()).unwrap());
+        assert!(!Secp256k1Crypto::verify(b"other", &signature, keypair.verifying_key()).unwrap());
+
+        let signature = Secp256k1Crypto::sign_recoverable(b"transfer 1 ETH", keypair.signing_key()).unwrap();
+        assert!(signature[64] <= 1);
+        assert_eq!(Secp256k1Crypto::recover_pubkey(b"transfer 1 ETH", &signature).unwrap(), *keypair.verifying_key());
+        assert_ne!(Secp256k1Crypto::recover_pubkey(b"transfer 9 ETH", &signature).ok(), Some(*keypair.verifying_key()));
+
+        let public_key = Secp256k1KeyPair::verifying_key_from_bytes(&keypair.public_key_bytes()).unwrap();
+        assert_eq!(Secp256k1Crypto::ethereum_address(&public_key), keypair.ethereum_address());
+        let restored = Secp256k1KeyPair::from_private_key_bytes(&keypair.private_key_bytes()).unwrap();
+        assert_eq!(restored.verifying_key(), keypair.verifying_key());
+    }
+
+    #[test]
+    fn test_secp256k1_rejects_malformed() {
+        let keypair = Secp256k1Crypto::generate_keypair().unwrap();
+        let signature = Secp256k1Crypto::sign_recoverable(b"message", keypair.signing_key()).unwrap();
+        let invalid = CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT);
+
+        assert_eq!(Secp256k1Crypto::recover_pubkey(b"message", &signature[..64]).unwrap_err(), invalid);
+        let mut bad_v = signature;
+        bad_v[64] = 2;
+        assert_eq!(Secp256k1Crypto::recover_pubkey(b"message", &bad_v).unwrap_err(), invalid);
+
+        // High-S twin of a valid signature: n - s
+        let high_s = {
+            let low = Signature::from_slice(&signature[..64]).unwrap();
+            let (r, s) = low.split_scalars();
+            Signature::from_scalars(r, -*s).unwrap()
+        };
+        let mut malleated = signature;
+        malleated[..64].copy_from_slice(&high_s.to_bytes());
+        malleated[64] ^= 1;
+        assert_eq!(Secp256k1Crypto::recover_pubkey(b"message", &malleated).unwrap_err(), invalid);
+
+        assert_eq!(Secp256k1KeyPair::from_private_key_bytes(&[0u8; 32]).err(), Some(CryptoError::InvalidKey(SECP256K1_INVALID_PRIVATE_KEY)));
+        assert_eq!(Secp256k1KeyPair::verifying_key_from_bytes(&[0x04; 65]).unwrap_err(), CryptoError::InvalidKey(SECP256K1_INVALID_PUBLIC_KEY));
+    }
+}
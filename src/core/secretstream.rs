@@ -0,0 +1,391 @@
+//! libsodium's `crypto_secretstream_xchacha20poly1305`, byte for byte
+//!
+//! A stream starts with a 24-byte header, followed by messages of
+//! `tag (1) || ciphertext || Poly1305 tag (16)`. Each message advances the
+//! state, so messages cannot be dropped, reordered or replayed, and a
+//! [`SecretStreamTag::Final`] message marks the legitimate end of the stream.
+
+use crate::error::{CryptoError, CryptoResult, CIPHERTEXT_TOO_SHORT, SECRETSTREAM_INVALID_KEY, SECRETSTREAM_INVALID_HEADER, SECRETSTREAM_MESSAGE_TOO_LARGE, SECRETSTREAM_DECRYPTION_FAILED, SECRETSTREAM_INVALID_TAG};
+use crate::core::random::SecureRandom;
+use chacha20::{ChaCha20, hchacha};
+use chacha20::cipher::{KeyIvInit, StreamCipher, consts::U10};
+use poly1305::Poly1305;
+use poly1305::universal_hash::{KeyInit, UniversalHash};
+use poly1305::universal_hash::generic_array::GenericArray;
+use subtle::ConstantTimeEq;
+use alloc::vec::Vec;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Key length (`crypto_secretstream_xchacha20poly1305_KEYBYTES`)
+pub const SECRETSTREAM_KEY_LEN: usize = 32;
+
+/// Stream header length (`crypto_secretstream_xchacha20poly1305_HEADERBYTES`)
+pub const SECRETSTREAM_HEADER_LEN: usize = 24;
+
+/// Bytes added to each message (`crypto_secretstream_xchacha20poly1305_ABYTES`)
+pub const SECRETSTREAM_OVERHEAD: usize = 17;
+
+// crypto_secretstream_xchacha20poly1305_MESSAGEBYTES_MAX on 64-bit targets:
+// the ChaCha20 block counter starts at 2 for the message
+const MESSAGE_MAX: u64 = 64 * ((1 << 32) - 2);
+const COUNTER_LEN: usize = 4;
+const BLOCK_LEN: usize = 64;
+const MAC_LEN: usize = 16;
+const PAD: [u8; MAC_LEN] = [0u8; MAC_LEN];
+
+/// Tag carried by each message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[repr(u8)]
+pub enum SecretStreamTag {
+    /// An ordinary message (`TAG_MESSAGE`)
+    #[default]
+    Message = 0,
+    /// The end of a logical chunk, such as one record of many (`TAG_PUSH`)
+    Push = 1,
+    /// Derive a new key after this message (`TAG_REKEY`)
+    Rekey = 2,
+    /// The last message of the stream (`TAG_FINAL`); also rekeys
+    Final = 3,
+}
+
+impl SecretStreamTag {
+    #[inline]
+    fn from_byte(byte: u8) -> CryptoResult<Self> {
+        match byte {
+            0 => Ok(SecretStreamTag::Message),
+            1 => Ok(SecretStreamTag::Push),
+            2 => Ok(SecretStreamTag::Rekey),
+            3 => Ok(SecretStreamTag::Final),
+            _ => Err(CryptoError::DecryptionFailed(SECRETSTREAM_INVALID_TAG)),
+        }
+    }
+}
+
+/// Encrypting side of a secretstream (`init_push` and `push`)
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let key = SecureRandom::generate_bytes(SECRETSTREAM_KEY_LEN)?;
+/// let mut push = SecretStreamPush::new(&key)?;
+/// let first = push.push(b"chunk 1", b"", SecretStreamTag::Message)?;
+/// let last = push.push(b"chunk 2", b"", SecretStreamTag::Final)?;
+///
+/// let mut pull = SecretStreamPull::new(&key, push.header())?;
+/// assert_eq!(pull.pull(&first, b"")?, (b"chunk 1".to_vec(), SecretStreamTag::Message));
+/// assert_eq!(pull.pull(&last, b"")?, (b"chunk 2".to_vec(), SecretStreamTag::Final));
+/// # Ok::<(), CryptoError>(())
+/// ```
+pub struct SecretStreamPush {
+    state: State,
+    header: [u8; SECRETSTREAM_HEADER_LEN],
+}
+
+impl SecretStreamPush {
+    /// Start a stream under a 32-byte key with a random header
+    pub fn new(key: &[u8]) -> CryptoResult<Self> {
+        let mut header = [0u8; SECRETSTREAM_HEADER_LEN];
+        SecureRandom::fill(&mut header)?;
+        Self::with_header(key, header)
+    }
+
+    // Deterministic start, for known-answer tests
+    fn with_header(key: &[u8], header: [u8; SECRETSTREAM_HEADER_LEN]) -> CryptoResult<Self> {
+        Ok(Self { state: State::new(key, &header)?, header })
+    }
+
+    /// The header to send ahead of the first message
+    #[inline]
+    pub fn header(&self) -> &[u8; SECRETSTREAM_HEADER_LEN] {
+        &self.header
+    }
+
+    /// Encrypt one message with associated data `ad` (may be empty)
+    ///
+    /// Returns [`SECRETSTREAM_OVERHEAD`] bytes more than `message`.
+    pub fn push(&mut self, message: &[u8], ad: &[u8], tag: SecretStreamTag) -> CryptoResult<Vec<u8>> {
+        if message.len() as u64 > MESSAGE_MAX {
+            return Err(CryptoError::InvalidInput(SECRETSTREAM_MESSAGE_TOO_LARGE));
+        }
+
+        let mut block = [0u8; BLOCK_LEN];
+        block[0] = tag as u8;
+        let mut cipher = self.state.cipher();
+        let mut mac = self.state.poly1305(&mut cipher, ad);
+        cipher.apply_keystream(&mut block);
+        mac.update(&block);
+
+        let mut out = Vec::with_capacity(message.len() + SECRETSTREAM_OVERHEAD);
+        out.push(block[0]);
+        out.extend_from_slice(message);
+        cipher.apply_keystream(&mut out[1..]);
+        mac.update(&out[1..]);
+        let mac = mac.finish(ad.len(), message.len());
+        out.extend_from_slice(&mac);
+
+        self.state.advance(&mac, tag as u8);
+        Ok(out)
+    }
+
+    /// Rekey explicitly (`crypto_secretstream_xchacha20poly1305_rekey`); the
+    /// receiver must call [`SecretStreamPull::rekey`] at the same point
+    #[inline]
+    pub fn rekey(&mut self) {
+        self.state.rekey();
+    }
+}
+
+/// Decrypting side of a secretstream (`init_pull` and `pull`)
+pub struct SecretStreamPull {
+    state: State,
+}
+
+impl SecretStreamPull {
+    /// Start reading a stream from its 24-byte header
+    #[inline]
+    pub fn new(key: &[u8], header: &[u8]) -> CryptoResult<Self> {
+        Ok(Self { state: State::new(key, header)? })
+    }
+
+    /// Decrypt the next message, returning its plaintext and tag
+    ///
+    /// On failure the state is unchanged. Treat a stream that ends without
+    /// a [`SecretStreamTag::Final`] message as truncated.
+    pub fn pull(&mut self, ciphertext: &[u8], ad: &[u8]) -> CryptoResult<(Vec<u8>, SecretStreamTag)> {
+        if ciphertext.len() < SECRETSTREAM_OVERHEAD {
+            return Err(CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+        }
+        let (encrypted_tag, rest) = ciphertext.split_at(1);
+        let (body, stored_mac) = rest.split_at(rest.len() - MAC_LEN);
+
+        let mut block = [0u8; BLOCK_LEN];
+        let mut cipher = self.state.cipher();
+        let mut mac = self.state.poly1305(&mut cipher, ad);
+        cipher.apply_keystream(&mut block);
+        let tag_byte = block[0] ^ encrypted_tag[0];
+        block[0] = encrypted_tag[0];
+        mac.update(&block);
+        mac.update(body);
+        let mac = mac.finish(ad.len(), body.len());
+        if !bool::from(mac.ct_eq(stored_mac)) {
+            return Err(CryptoError::DecryptionFailed(SECRETSTREAM_DECRYPTION_FAILED));
+        }
+        let tag = SecretStreamTag::from_byte(tag_byte)?;
+
+        let mut plaintext = body.to_vec();
+        cipher.apply_keystream(&mut plaintext);
+        self.state.advance(&mac, tag_byte);
+        Ok((plaintext, tag))
+    }
+
+    /// Rekey explicitly, mirroring [`SecretStreamPush::rekey`]
+    #[inline]
+    pub fn rekey(&mut self) {
+        self.state.rekey();
+    }
+}
+
+// libsodium's state: the subkey and a 12-byte ChaCha20 nonce of
+// `counter (4, little-endian) || inonce (8)`
+#[derive(Zeroize, ZeroizeOnDrop)]
+struct State {
+    key: [u8; 32],
+    nonce: [u8; 12],
+}
+
+impl State {
+    fn new(key: &[u8], header: &[u8]) -> CryptoResult<Self> {
+        if key.len() != SECRETSTREAM_KEY_LEN {
+            return Err(CryptoError::InvalidKey(SECRETSTREAM_INVALID_KEY));
+        }
+        if header.len() != SECRETSTREAM_HEADER_LEN {
+            return Err(CryptoError::InvalidInput(SECRETSTREAM_INVALID_HEADER));
+        }
+
+        let mut state = Self {
+            key: hchacha::<U10>(key.into(), header[..16].into()).into(),
+            nonce: [0u8; 12],
+        };
+        state.nonce[COUNTER_LEN..].copy_from_slice(&header[16..]);
+        state.reset_counter();
+        Ok(state)
+    }
+
+    #[inline]
+    fn cipher(&self) -> ChaCha20 {
+        ChaCha20::new(&self.key.into(), &self.nonce.into())
+    }
+
+    // Keys Poly1305 with ChaCha20 block 0 and absorbs the padded AD, leaving
+    // `cipher` at block 1 for the tag block
+    fn poly1305(&self, cipher: &mut ChaCha20, ad: &[u8]) -> Authenticator {
+        let mut poly_key = [0u8; BLOCK_LEN];
+        cipher.apply_keystream(&mut poly_key);
+        let mut mac = Authenticator {
+            poly: Poly1305::new(poly_key[..32].into()),
+            buffer: [0u8; MAC_LEN],
+            buffered: 0,
+        };
+        poly_key.zeroize();
+        mac.update(ad);
+        mac.update(&PAD[..ad.len().wrapping_neg() % MAC_LEN]);
+        mac
+    }
+
+    // Fold the MAC into the nonce and step the counter, rekeying on
+    // `TAG_REKEY` (also set in `TAG_FINAL`) or when the counter wraps
+    fn advance(&mut self, mac: &[u8; MAC_LEN], tag: u8) {
+        for (nonce, mac) in self.nonce[COUNTER_LEN..].iter_mut().zip(mac) {
+            *nonce ^= mac;
+        }
+        let counter = u32::from_le_bytes(self.nonce[..COUNTER_LEN].try_into().unwrap()).wrapping_add(1);
+        self.nonce[..COUNTER_LEN].copy_from_slice(&counter.to_le_bytes());
+        if tag & SecretStreamTag::Rekey as u8 != 0 || counter == 0 {
+            self.rekey();
+        }
+    }
+
+    // The new key and inonce are the old ones encrypted under the current state
+    fn rekey(&mut self) {
+        let mut next = [0u8; 40];
+        next[..32].copy_from_slice(&self.key);
+        next[32..].copy_from_slice(&self.nonce[COUNTER_LEN..]);
+        self.cipher().apply_keystream(&mut next);
+        self.key.copy_from_slice(&next[..32]);
+        self.nonce[COUNTER_LEN..].copy_from_slice(&next[32..]);
+        next.zeroize();
+        self.reset_counter();
+    }
+
+    #[inline]
+    fn reset_counter(&mut self) {
+        self.nonce[..COUNTER_LEN].copy_from_slice(&1u32.to_le_bytes());
+    }
+}
+
+// Poly1305 over input that does not stay block-aligned
+struct Authenticator {
+    poly: Poly1305,
+    buffer: [u8; MAC_LEN],
+    buffered: usize,
+}
+
+impl Authenticator {
+    fn update(&mut self, mut data: &[u8]) {
+        if self.buffered > 0 {
+            let take = data.len().min(MAC_LEN - self.buffered);
+            self.buffer[self.buffered..self.buffered + take].copy_from_slice(&data[..take]);
+            self.buffered += take;
+            data = &data[take..];
+            if self.buffered < MAC_LEN {
+                return;
+            }
+            self.poly.update(&[GenericArray::clone_from_slice(&self.buffer)]);
+            self.buffered = 0;
+        }
+
+        let aligned = data.len() - data.len() % MAC_LEN;
+        self.poly.update_padded(&data[..aligned]);
+        self.buffer[..data.len() - aligned].copy_from_slice(&data[aligned..]);
+        self.buffered = data.len() - aligned;
+    }
+
+    // libsodium pads the message with `(0x10 - 64 + mlen) & 0xf` zeros, which
+    // is `mlen % 16` rather than the distance to the next block; kept for
+    // compatibility. Then come the AD length and the tag block plus message length.
+    fn finish(mut self, ad_len: usize, message_len: usize) -> [u8; MAC_LEN] {
+        self.update(&PAD[..message_len % MAC_LEN]);
+        let mut lengths = [0u8; 16];
+        lengths[..8].copy_from_slice(&(ad_len as u64).to_le_bytes());
+        lengths[8..].copy_from_slice(&((BLOCK_LEN + message_len) as u64).to_le_bytes());
+        self.update(&lengths);
+        let Self { poly, mut buffer, buffered } = self;
+        let mac = poly.compute_unpadded(&buffer[..buffered]).into();
+        buffer.zeroize();
+        mac
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Generated with libsodium 1.0.18: key 00 01 .. 1f, then push, push,
+    // push, crypto_secretstream_xchacha20poly1305_rekey, push, push
+    const HEADER: &str = "3a0c82b0938473425e759cc95eeb32e374c6a41178b03f3e";
+    const MESSAGES: [(&str, &[u8], SecretStreamTag, &str); 4] = [
+        ("first chunk", b"", SecretStreamTag::Message, "e9b76f3ac067460715871f3cdcd5effd2e6bdd2aca4b516bd9c545fa"),
+        ("", b"metadata", SecretStreamTag::Push, "917eecb80f4fd625bc16d55b4836e6c16e"),
+        ("x", b"", SecretStreamTag::Rekey, "aaa67c0ab4418f1931a1faf0379574da38ce763c6023825590fae30768192dd5f857aa7d8c0b5790ceb0f2f0a981c699f19bb100073b44e70c3a7e1411ee9b994f03c5aca4df6299f5d249bafde3b15cabea31d16d2a7dc96e5212f6bf1cc2c3ea22f9ffc6557f9d1b5cb466f9278c8b0ce2f65883"),
+        ("after rekey", b"", SecretStreamTag::Message, "ef4994bffb8f0e45e3ba71e35cf167eeff821a596511a007a82b50f5"),
+    ];
+    const FINAL: &str = "78d32e867ba1da11bc2b40f8021872b3167fe9a559";
+
+    // The third message is 100 'x's
+    fn plaintext(message: &str) -> Vec<u8> {
+        if message == "x" { b"x".repeat(100) } else { message.as_bytes().to_vec() }
+    }
+
+    #[test]
+    fn test_secretstream_libsodium_vectors() {
+        let key: Vec<u8> = (0..32).collect();
+        let header: [u8; 24] = hex::decode(HEADER).unwrap().try_into().unwrap();
+
+        let mut push = SecretStreamPush::with_header(&key, header).unwrap();
+        let mut pull = SecretStreamPull::new(&key, &header).unwrap();
+        for (i, (message, ad, tag, expected)) in MESSAGES.iter().enumerate() {
+            if i == 3 {
+                push.rekey();
+                pull.rekey();
+            }
+            let ciphertext = push.push(&plaintext(message), ad, *tag).unwrap();
+            assert_eq!(hex::encode(&ciphertext), *expected);
+            assert_eq!(pull.pull(&ciphertext, ad).unwrap(), (plaintext(message), *tag));
+        }
+        let last = hex::decode(FINAL).unwrap();
+        assert_eq!(push.push(b"last", b"", SecretStreamTag::Final).unwrap(), last);
+        assert_eq!(pull.pull(&last, b"").unwrap(), (b"last".to_vec(), SecretStreamTag::Final));
+    }
+
+    #[test]
+    fn test_secretstream_rejects_tampering() {
+        let key = SecureRandom::generate_bytes(SECRETSTREAM_KEY_LEN).unwrap();
+        let mut push = SecretStreamPush::new(&key).unwrap();
+        let first = push.push(b"one", b"ad", SecretStreamTag::Message).unwrap();
+        let second = push.push(b"two", b"", SecretStreamTag::Final).unwrap();
+        assert_eq!(first.len(), 3 + SECRETSTREAM_OVERHEAD);
+
+        let mut pull = SecretStreamPull::new(&key, push.header()).unwrap();
+        // Out of order, wrong AD, flipped tag byte: rejected, and the state is kept
+        let failed = CryptoError::DecryptionFailed(SECRETSTREAM_DECRYPTION_FAILED);
+        assert_eq!(pull.pull(&second, b"").unwrap_err(), failed);
+        assert_eq!(pull.pull(&first, b"other").unwrap_err(), failed);
+        let mut tampered = first.clone();
+        tampered[0] ^= 1;
+        assert_eq!(pull.pull(&tampered, b"ad").unwrap_err(), failed);
+        assert_eq!(pull.pull(&first, b"ad").unwrap().0, b"one");
+        // Replays fail
+        assert_eq!(pull.pull(&first, b"ad").unwrap_err(), failed);
+        assert_eq!(pull.pull(&second, b"").unwrap(), (b"two".to_vec(), SecretStreamTag::Final));
+
+        assert_eq!(pull.pull(&[0u8; 16], b"").unwrap_err(), CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+        assert_eq!(SecretStreamPush::new(&key[..16]).err(), Some(CryptoError::InvalidKey(SECRETSTREAM_INVALID_KEY)));
+        assert_eq!(SecretStreamPull::new(&key, &[0u8; 23]).err(), Some(CryptoError::InvalidInput(SECRETSTREAM_INVALID_HEADER)));
+    }
+
+    #[test]
+    fn test_secretstream_counter_wrap_rekeys() {
+        let key = [7u8; 32];
+        let header = [9u8; 24];
+        let mut push = SecretStreamPush::with_header(&key, header).unwrap();
+        let mut pull = SecretStreamPull::new(&key, &header).unwrap();
+        push.state.nonce[..COUNTER_LEN].copy_from_slice(&u32::MAX.to_le_bytes());
+        pull.state.nonce[..COUNTER_LEN].copy_from_slice(&u32::MAX.to_le_bytes());
+
+        let ciphertext = push.push(b"wrap", b"", SecretStreamTag::Message).unwrap();
+        assert_eq!(pull.pull(&ciphertext, b"").unwrap().0, b"wrap");
+        assert_eq!(push.state.nonce[..COUNTER_LEN], 1u32.to_le_bytes());
+        let ciphertext = push.push(b"after", b"", SecretStreamTag::Message).unwrap();
+        assert_eq!(pull.pull(&ciphertext, b"").unwrap().0, b"after");
+    }
+}
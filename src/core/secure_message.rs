@@ -0,0 +1,193 @@
+use crate::error::{CryptoError, CryptoResult, NACL_INVALID_PUBLIC_KEY, NACL_INVALID_SECRET_KEY, SECURE_MESSAGE_INVALID_FORMAT, SECURE_MESSAGE_UNSUPPORTED_VERSION, SECURE_MESSAGE_WEAK_KEY, SECURE_MESSAGE_INVALID_SIGNATURE, SECURE_MESSAGE_SENDER_MISMATCH};
+use crate::core::asymmetric::{Ed25519Crypto, Ed25519KeyPair};
+use crate::core::kdf::HkdfKdf;
+use crate::core::keys::Aes256Key;
+use crate::core::nacl::BoxKeyPair;
+use crate::core::symmetric::AesGcm;
+use ed25519_dalek::VerifyingKey as Ed25519VerifyingKey;
+use rand::rngs::OsRng;
+use x25519_dalek::{EphemeralSecret, PublicKey as X25519PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+// Message layout:
+//   magic "LSSM" | version (1) | ephemeral X25519 public key (32)
+//   | AES-GCM(nonce + [sender Ed25519 key (32) | signature (64) | plaintext] + tag)
+// The header is authenticated as AAD. The signature covers the domain
+// label, the recipient's public key and the plaintext, so a recipient
+// cannot re-encrypt a signed message to a third party as if it were theirs.
+const MESSAGE_MAGIC: &[u8; 4] = b"LSSM";
+const MESSAGE_VERSION: u8 = 1;
+const HEADER_SIZE: usize = 4 + 1 + 32;
+const KEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+const SIGNATURE_DOMAIN: &[u8] = b"libsilver SecureMessage v1 signature";
+const KDF_INFO: &[u8] = b"libsilver SecureMessage v1 key";
+
+/// Sign-then-encrypt messages with sender authentication
+///
+/// The sender signs with Ed25519 and encrypts to the recipient's X25519
+/// public key ([`BoxKeyPair`]) using an ephemeral key, HKDF-SHA256 and
+/// AES-256-GCM.
+pub struct SecureMessage;
+
+impl SecureMessage {
+    /// Sign `plaintext` with `sender` and encrypt it to `recipient_public_key`
+    pub fn seal(sender: &Ed25519KeyPair, recipient_public_key: &[u8], plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let recipient_public_key: [u8; KEY_SIZE] = recipient_public_key.try_into()
+            .map_err(|_| CryptoError::InvalidKey(NACL_INVALID_PUBLIC_KEY))?;
+
+        let signature = Ed25519Crypto::sign(&signed_bytes(&recipient_public_key, plaintext), sender.signing_key())?;
+        let mut payload = Zeroizing::new(Vec::with_capacity(KEY_SIZE + SIGNATURE_SIZE + plaintext.len()));
+        payload.extend_from_slice(&sender.verifying_key().to_bytes());
+        payload.extend_from_slice(&signature);
+        payload.extend_from_slice(plaintext);
+
+        let ephemeral_secret = EphemeralSecret::random_from_rng(OsRng);
+        let ephemeral_public = X25519PublicKey::from(&ephemeral_secret);
+        let shared = ephemeral_secret.diffie_hellman(&X25519PublicKey::from(recipient_public_key));
+        if !shared.was_contributory() {
+            return Err(CryptoError::InvalidKey(SECURE_MESSAGE_WEAK_KEY));
+        }
+
+        let mut message = Vec::with_capacity(HEADER_SIZE + payload.len() + 28);
+        message.extend_from_slice(MESSAGE_MAGIC);
+        message.push(MESSAGE_VERSION);
+        message.extend_from_slice(ephemeral_public.as_bytes());
+
+        let key = derive_key(shared.as_bytes(), ephemeral_public.as_bytes(), &recipient_public_key)?;
+        let ciphertext = AesGcm::encrypt_with_aad(&payload, &key, &message)?;
+        message.extend_from_slice(&ciphertext);
+        Ok(message)
+    }
+
+    /// Decrypt a message and check it was signed by `sender`
+    pub fn open(message: &[u8], recipient: &BoxKeyPair, sender: &Ed25519VerifyingKey) -> CryptoResult<Vec<u8>> {
+        let (signer, plaintext) = Self::open_with_sender(message, recipient)?;
+        if signer != *sender {
+            return Err(CryptoError::VerificationFailed(SECURE_MESSAGE_SENDER_MISMATCH));
+        }
+        Ok(plaintext)
+    }
+
+    /// Decrypt a message, returning the authenticated sender's key for the caller to look up
+    pub fn open_with_sender(message: &[u8], recipient: &BoxKeyPair) -> CryptoResult<(Ed25519VerifyingKey, Vec<u8>)> {
+        if message.len() < HEADER_SIZE || &message[..4] != MESSAGE_MAGIC {
+            return Err(CryptoError::EncodingFailed(SECURE_MESSAGE_INVALID_FORMAT));
+        }
+        if message[4] != MESSAGE_VERSION {
+            return Err(CryptoError::EncodingFailed(SECURE_MESSAGE_UNSUPPORTED_VERSION));
+        }
+        let (header, ciphertext) = message.split_at(HEADER_SIZE);
+        let ephemeral_public: [u8; KEY_SIZE] = header[5..].try_into()
+            .map_err(|_| CryptoError::EncodingFailed(SECURE_MESSAGE_INVALID_FORMAT))?;
+
+        let secret_key: [u8; KEY_SIZE] = recipient.secret_key().as_bytes().try_into()
+            .map_err(|_| CryptoError::InvalidKey(NACL_INVALID_SECRET_KEY))?;
+        let recipient_secret = StaticSecret::from(secret_key);
+        let shared = recipient_secret.diffie_hellman(&X25519PublicKey::from(ephemeral_public));
+        if !shared.was_contributory() {
+            return Err(CryptoError::InvalidKey(SECURE_MESSAGE_WEAK_KEY));
+        }
+
+        let key = derive_key(shared.as_bytes(), &ephemeral_public, recipient.public_key())?;
+        let payload = AesGcm::decrypt_with_aad_zeroizing(ciphertext, &key, header)?;
+        if payload.len() < KEY_SIZE + SIGNATURE_SIZE {
+            return Err(CryptoError::EncodingFailed(SECURE_MESSAGE_INVALID_FORMAT));
+        }
+
+        let (sender, rest) = payload.split_at(KEY_SIZE);
+        let (signature, plaintext) = rest.split_at(SIGNATURE_SIZE);
+        let sender = sender.try_into().ok()
+            .and_then(|sender| Ed25519VerifyingKey::from_bytes(sender).ok())
+            .ok_or(CryptoError::EncodingFailed(SECURE_MESSAGE_INVALID_FORMAT))?;
+        if !Ed25519Crypto::verify(&signed_bytes(recipient.public_key(), plaintext), signature, &sender)? {
+            return Err(CryptoError::VerificationFailed(SECURE_MESSAGE_INVALID_SIGNATURE));
+        }
+
+        Ok((sender, plaintext.to_vec()))
+    }
+}
+
+#[inline]
+fn signed_bytes(recipient_public_key: &[u8; KEY_SIZE], plaintext: &[u8]) -> Vec<u8> {
+    [SIGNATURE_DOMAIN, recipient_public_key, plaintext].concat()
+}
+
+// key = HKDF-SHA256(ikm = X25519(ephemeral, recipient), salt = ephemeral_pk || recipient_pk)
+#[inline]
+fn derive_key(shared: &[u8; KEY_SIZE], ephemeral_public: &[u8; KEY_SIZE], recipient_public: &[u8; KEY_SIZE]) -> CryptoResult<Aes256Key> {
+    let salt = [ephemeral_public.as_slice(), recipient_public].concat();
+    Aes256Key::from_bytes(&HkdfKdf::derive_sha256_zeroizing(shared, Some(&salt), KDF_INFO, KEY_SIZE)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::nacl::SealedBox;
+
+    #[test]
+    fn test_secure_message_roundtrip() {
+        let alice = Ed25519KeyPair::generate().unwrap();
+        let bob = BoxKeyPair::generate().unwrap();
+
+        let message = SecureMessage::seal(&alice, bob.public_key(), b"meet at noon").unwrap();
+        assert_eq!(&message[..5], b"LSSM\x01");
+        assert_eq!(SecureMessage::open(&message, &bob, alice.verifying_key()).unwrap(), b"meet at noon");
+
+        let (sender, plaintext) = SecureMessage::open_with_sender(&message, &bob).unwrap();
+        assert_eq!(&sender, alice.verifying_key());
+        assert_eq!(plaintext, b"meet at noon");
+
+        // Wrong expected sender, wrong recipient, tampering
+        let mallory = Ed25519KeyPair::generate().unwrap();
+        assert_eq!(
+            SecureMessage::open(&message, &bob, mallory.verifying_key()).unwrap_err(),
+            CryptoError::VerificationFailed(SECURE_MESSAGE_SENDER_MISMATCH)
+        );
+        assert!(SecureMessage::open(&message, &BoxKeyPair::generate().unwrap(), alice.verifying_key()).is_err());
+        let mut tampered = message.clone();
+        tampered[10] ^= 1;
+        assert!(SecureMessage::open(&tampered, &bob, alice.verifying_key()).is_err());
+        let mut future = message.clone();
+        future[4] = 2;
+        assert_eq!(
+            SecureMessage::open(&future, &bob, alice.verifying_key()).unwrap_err(),
+            CryptoError::EncodingFailed(SECURE_MESSAGE_UNSUPPORTED_VERSION)
+        );
+        assert!(SecureMessage::seal(&alice, &[0u8; 31], b"m").is_err());
+        assert!(SecureMessage::seal(&alice, &[0u8; 32], b"m").is_err());
+    }
+
+    #[test]
+    fn test_secure_message_signature_bound_to_recipient() {
+        let alice = Ed25519KeyPair::generate().unwrap();
+        let bob = BoxKeyPair::generate().unwrap();
+        let carol = BoxKeyPair::generate().unwrap();
+        let message = SecureMessage::seal(&alice, bob.public_key(), b"for bob only").unwrap();
+
+        // Bob re-encrypts Alice's signed payload to Carol under a valid header
+        let (header, ciphertext) = message.split_at(HEADER_SIZE);
+        let ephemeral_public: [u8; KEY_SIZE] = header[5..].try_into().unwrap();
+        let shared = StaticSecret::from(<[u8; KEY_SIZE]>::try_from(bob.secret_key().as_bytes()).unwrap())
+            .diffie_hellman(&X25519PublicKey::from(ephemeral_public));
+        let key = derive_key(shared.as_bytes(), &ephemeral_public, bob.public_key()).unwrap();
+        let payload = AesGcm::decrypt_with_aad(ciphertext, &key, header).unwrap();
+
+        let forwarder = EphemeralSecret::random_from_rng(OsRng);
+        let forwarder_public = X25519PublicKey::from(&forwarder);
+        let shared = forwarder.diffie_hellman(&X25519PublicKey::from(*carol.public_key()));
+        let mut forwarded = [MESSAGE_MAGIC.as_slice(), &[MESSAGE_VERSION], forwarder_public.as_bytes()].concat();
+        let key = derive_key(shared.as_bytes(), forwarder_public.as_bytes(), carol.public_key()).unwrap();
+        let ciphertext = AesGcm::encrypt_with_aad(&payload, &key, &forwarded).unwrap();
+        forwarded.extend_from_slice(&ciphertext);
+
+        assert_eq!(
+            SecureMessage::open(&forwarded, &carol, alice.verifying_key()).unwrap_err(),
+            CryptoError::VerificationFailed(SECURE_MESSAGE_INVALID_SIGNATURE)
+        );
+
+        // Not confused with other libsilver formats
+        let sealed = SealedBox::seal(bob.public_key(), b"x").unwrap();
+        assert!(SecureMessage::open_with_sender(&sealed, &bob).is_err());
+    }
+}
@@ -0,0 +1,321 @@
+//! Serde support (`serde` feature)
+//!
+//! Public values serialize as-is: [`Algorithm`] and [`SignatureAlgorithm`] as
+//! their names, [`AsymmetricKey`] public keys as JWK objects, [`Nonce`]s and
+//! [`EnvelopeHeader`](crate::core::EnvelopeHeader)s field by field, and a
+//! [`KeyEntry`](crate::core::KeyEntry) as its metadata. Secret key material is
+//! never written unless the value is wrapped in [`IncludeSecrets`].
+//!
+//! Envelopes and signatures are plain byte vectors; use [`bytes`] on those
+//! fields so they are base64url strings in JSON and byte strings in CBOR.
+
+use crate::error::SERDE_INVALID_BYTES;
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use crate::error::SERDE_SECRET_EXCLUDED;
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use crate::core::keyformat::AsymmetricKey;
+use crate::core::nonce::Nonce;
+use crate::core::random::SecureKey;
+use crate::core::registry::Algorithm;
+use crate::core::signer::SignatureAlgorithm;
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use serde::ser;
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use serde_json::Value;
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+use zeroize::Zeroizing;
+
+/// Opt in to serializing the secret key material of `T`
+///
+/// Implemented for [`AsymmetricKey`] (private JWK), [`SecureKey`] (raw bytes)
+/// and [`KeyEntry`](crate::core::KeyEntry) (metadata and key).
+///
+/// ```rust
+/// use libsilver::prelude::*;
+/// use libsilver::core::serialization::IncludeSecrets;
+///
+/// let key = AsymmetricKey::from(&Ed25519KeyPair::generate()?);
+/// assert!(serde_json::to_string(&key).is_err());
+///
+/// let json = serde_json::to_string(&IncludeSecrets(key.clone())).unwrap();
+/// let IncludeSecrets(restored) = serde_json::from_str::<IncludeSecrets<AsymmetricKey>>(&json).unwrap();
+/// assert_eq!(restored.to_raw()?, key.to_raw()?);
+/// # Ok::<(), CryptoError>(())
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct IncludeSecrets<T>(pub T);
+
+// Never print the wrapped secrets
+impl<T> std::fmt::Debug for IncludeSecrets<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("IncludeSecrets").finish_non_exhaustive()
+    }
+}
+
+/// `#[serde(with = "libsilver::core::serialization::bytes")]` for byte fields
+///
+/// Writes unpadded base64url in human-readable formats (JSON, TOML) and a
+/// byte string otherwise (CBOR, bincode). Reads either form.
+pub mod bytes {
+    use super::*;
+    use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+    use std::fmt;
+
+    /// Serialize bytes
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]> + ?Sized,
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&URL_SAFE_NO_PAD.encode(bytes.as_ref()))
+        } else {
+            serializer.serialize_bytes(bytes.as_ref())
+        }
+    }
+
+    /// Deserialize bytes
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(BytesVisitor)
+        } else {
+            deserializer.deserialize_byte_buf(BytesVisitor)
+        }
+    }
+
+    // Largest allocation made up front for a byte sequence before its elements arrive
+    const MAX_SEQ_PREALLOCATION: usize = 4096;
+
+    struct BytesVisitor;
+
+    impl<'de> de::Visitor<'de> for BytesVisitor {
+        type Value = Vec<u8>;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a base64url string or a byte string")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<Vec<u8>, E> {
+            URL_SAFE_NO_PAD.decode(value.trim_end_matches('='))
+                .map_err(|_| E::custom(SERDE_INVALID_BYTES))
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Vec<u8>, E> {
+            Ok(value.to_vec())
+        }
+
+        fn visit_byte_buf<E: de::Error>(self, value: Vec<u8>) -> Result<Vec<u8>, E> {
+            Ok(value)
+        }
+
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+            // The hint is the length claimed by the input; never trust it for allocation
+            let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_SEQ_PREALLOCATION));
+            while let Some(byte) = seq.next_element()? {
+                bytes.push(byte);
+            }
+            Ok(bytes)
+        }
+    }
+}
+
+impl Serialize for Algorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Algorithm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Algorithm::from_name(&name).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for SignatureAlgorithm {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for SignatureAlgorithm {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Algorithm::deserialize(deserializer)?.signature_algorithm().map_err(de::Error::custom)
+    }
+}
+
+impl<const N: usize> Serialize for Nonce<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes::serialize(self, serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for Nonce<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Nonce::from_bytes(&bytes::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+// Write any key, including private ones, as a JWK object
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+fn serialize_jwk<S: Serializer>(key: &AsymmetricKey, serializer: S) -> Result<S::Ok, S::Error> {
+    let jwk = key.to_jwk().map_err(ser::Error::custom)?;
+    let jwk: Value = serde_json::from_str(&jwk).map_err(ser::Error::custom)?;
+    jwk.serialize(serializer)
+}
+
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+fn deserialize_jwk<'de, D: Deserializer<'de>>(deserializer: D) -> Result<AsymmetricKey, D::Error> {
+    let jwk = Zeroizing::new(Value::deserialize(deserializer)?.to_string());
+    AsymmetricKey::from_jwk(&jwk).map_err(de::Error::custom)
+}
+
+/// Public keys only; private keys need [`IncludeSecrets`]
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+impl Serialize for AsymmetricKey {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.is_private() {
+            return Err(ser::Error::custom(SERDE_SECRET_EXCLUDED));
+        }
+        serialize_jwk(self, serializer)
+    }
+}
+
+/// Public keys only; private keys need [`IncludeSecrets`]
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+impl<'de> Deserialize<'de> for AsymmetricKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let key = deserialize_jwk(deserializer)?;
+        if key.is_private() {
+            return Err(de::Error::custom(SERDE_SECRET_EXCLUDED));
+        }
+        Ok(key)
+    }
+}
+
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+impl Serialize for IncludeSecrets<AsymmetricKey> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize_jwk(&self.0, serializer)
+    }
+}
+
+#[cfg(all(feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+impl<'de> Deserialize<'de> for IncludeSecrets<AsymmetricKey> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_jwk(deserializer).map(IncludeSecrets)
+    }
+}
+
+impl Serialize for IncludeSecrets<SecureKey> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes::serialize(self.0.as_bytes(), serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for IncludeSecrets<SecureKey> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        bytes::deserialize(deserializer).map(|key| IncludeSecrets(SecureKey::new(key)))
+    }
+}
+
+// Borrowed bytes written like a `bytes` field
+#[cfg(all(feature = "aes", feature = "argon2"))]
+pub(crate) struct BytesRef<'a>(pub &'a [u8]);
+
+#[cfg(all(feature = "aes", feature = "argon2"))]
+impl Serialize for BytesRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        bytes::serialize(self.0, serializer)
+    }
+}
+
+#[cfg(all(test, feature = "aes", feature = "argon2", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::EcdsaKeyPair;
+    use crate::core::envelope::Envelope;
+    use crate::core::keys::Aes256Key;
+    use crate::core::keystore::{KeyEntry, Keystore};
+    use crate::core::nonce::AesGcmNonce;
+
+    fn to_cbor<T: Serialize>(value: &T) -> Vec<u8> {
+        let mut out = Vec::new();
+        ciborium::ser::into_writer(value, &mut out).unwrap();
+        out
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize, PartialEq, Debug)]
+    struct Record {
+        algorithm: Algorithm,
+        nonce: AesGcmNonce,
+        #[serde(with = "bytes")]
+        signature: Vec<u8>,
+    }
+
+    #[test]
+    fn test_public_values_roundtrip() {
+        let record = Record {
+            algorithm: Algorithm::ChaCha20Poly1305,
+            nonce: AesGcmNonce::from_counter(1),
+            signature: vec![0xfb, 0xff, 0x01],
+        };
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert_eq!(json, serde_json::json!({ "algorithm": "ChaCha20-Poly1305", "nonce": "AAAAAAAAAAAAAAAB", "signature": "-_8B" }));
+        assert_eq!(serde_json::from_value::<Record>(json).unwrap(), record);
+
+        let cbor = to_cbor(&record);
+        assert_eq!(ciborium::de::from_reader::<Record, _>(&cbor[..]).unwrap(), record);
+
+        assert!(serde_json::from_str::<Algorithm>("\"AES-128-CBC\"").is_err());
+        assert!(serde_json::from_str::<SignatureAlgorithm>("\"AES-256-GCM\"").is_err());
+        assert!(serde_json::from_str::<AesGcmNonce>("\"AAAA\"").is_err());
+
+        // An array header claiming 2^64 - 1 elements fails without preallocating them
+        let hostile = [0x9b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff];
+        assert!(ciborium::de::from_reader::<AesGcmNonce, _>(&hostile[..]).is_err());
+    }
+
+    #[test]
+    fn test_secrets_are_opt_in() {
+        let keypair = AsymmetricKey::from(&EcdsaKeyPair::generate().unwrap());
+        let public = keypair.public_key();
+
+        let json = serde_json::to_value(&public).unwrap();
+        assert_eq!(json["kty"], "EC");
+        assert_eq!(serde_json::from_value::<AsymmetricKey>(json).unwrap().to_raw().unwrap(), public.to_raw().unwrap());
+
+        let error = serde_json::to_string(&keypair).unwrap_err();
+        assert!(error.to_string().contains(SERDE_SECRET_EXCLUDED));
+        let private_jwk = serde_json::to_value(IncludeSecrets(keypair.clone())).unwrap();
+        assert!(private_jwk.get("d").is_some());
+        assert!(serde_json::from_value::<AsymmetricKey>(private_jwk.clone()).is_err());
+        let IncludeSecrets(restored) = serde_json::from_value::<IncludeSecrets<AsymmetricKey>>(private_jwk).unwrap();
+        assert_eq!(restored.to_raw().unwrap(), keypair.to_raw().unwrap());
+
+        let mut keystore = Keystore::new();
+        keystore.generate("db", "AES-256-GCM", 32).unwrap();
+        let entry = keystore.get("db").unwrap();
+        let metadata = serde_json::to_value(entry).unwrap();
+        assert_eq!(metadata["name"], "db");
+        assert!(metadata.get("key").is_none());
+
+        let IncludeSecrets(restored): IncludeSecrets<KeyEntry> = ciborium::de::from_reader(&to_cbor(&IncludeSecrets(entry.clone()))[..]).unwrap();
+        assert_eq!((restored.name(), restored.version()), (entry.name(), entry.version()));
+        assert_eq!(restored.key().as_bytes(), entry.key().as_bytes());
+    }
+
+    #[test]
+    fn test_envelope_header_serializes() {
+        let kek = Aes256Key::from_bytes(&[7u8; 32]).unwrap();
+        let envelope = Envelope::encrypt(b"data", &kek, "kek-2024").unwrap();
+        let header = serde_json::to_value(Envelope::header(&envelope).unwrap()).unwrap();
+        assert_eq!(header["algorithm"], "AES-256-GCM");
+        assert_eq!(header["kek_source"], "local");
+        assert_eq!(header["key_id"], "kek-2024");
+        assert!(header["wrapped_key"].is_string());
+    }
+}
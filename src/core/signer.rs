@@ -0,0 +1,218 @@
+use crate::error::CryptoResult;
+#[cfg(feature = "ecdsa")]
+use crate::core::asymmetric::{EcdsaCrypto, EcdsaKeyPair};
+#[cfg(feature = "ed25519")]
+use crate::core::asymmetric::{Ed25519Crypto, Ed25519KeyPair};
+#[cfg(feature = "rsa")]
+use crate::core::asymmetric::{RsaCrypto, RsaKeyPair, RsaSignatureHash, RsaSignaturePadding};
+#[cfg(feature = "ed25519")]
+use ed25519_dalek::VerifyingKey as Ed25519VerifyingKey;
+#[cfg(feature = "ecdsa")]
+use p256::ecdsa::VerifyingKey as EcdsaVerifyingKey;
+#[cfg(feature = "rsa")]
+use rsa::RsaPublicKey;
+#[cfg(feature = "ed25519")]
+use crate::core::verifier::Ed25519VerifierContext;
+#[cfg(feature = "ecdsa")]
+use crate::core::verifier::EcdsaVerifierContext;
+use alloc::vec::Vec;
+
+/// Signature scheme of a [`Signer`] or [`Verifier`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignatureAlgorithm {
+    /// Ed25519 (RFC 8032), 64-byte signatures
+    Ed25519,
+    /// ECDSA P-256 with SHA-256, 64-byte `r || s` signatures
+    EcdsaP256Sha256,
+    /// RSASSA-PSS with SHA-256, MGF1-SHA-256 and a 32-byte salt
+    RsaPssSha256,
+}
+
+impl SignatureAlgorithm {
+    /// Get the algorithm name
+    #[inline]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SignatureAlgorithm::Ed25519 => "Ed25519",
+            SignatureAlgorithm::EcdsaP256Sha256 => "ECDSA-P256-SHA256",
+            SignatureAlgorithm::RsaPssSha256 => "RSA-PSS-SHA256",
+        }
+    }
+}
+
+/// A private key that signs messages
+///
+/// Implemented by every libsilver key pair, so code that only needs "a
+/// signing key" can take `&dyn Signer` instead of one type per scheme. RSA
+/// key pairs sign with PSS and SHA-256; use [`RsaCrypto::sign`] for other
+/// paddings and hashes.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// fn sign_release(signer: &dyn Signer, manifest: &[u8]) -> CryptoResult<Vec<u8>> {
+///     signer.sign(manifest)
+/// }
+///
+/// let keypair = Ed25519KeyPair::generate()?;
+/// let signature = sign_release(&keypair, b"v1.2.0")?;
+/// assert!(Ed25519Crypto::verify(b"v1.2.0", &signature, keypair.verifying_key())?);
+/// # Ok::<(), CryptoError>(())
+/// ```
+pub trait Signer {
+    /// Signature scheme produced by [`Signer::sign`]
+    fn algorithm(&self) -> SignatureAlgorithm;
+
+    /// Sign `message`
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>>;
+}
+
+/// A public key that verifies signatures
+///
+/// Returns `Ok(false)` for a well-formed signature that does not verify and
+/// an error for a malformed one, like the scheme's own `verify` function.
+pub trait Verifier {
+    /// Signature scheme accepted by [`Verifier::verify`]
+    fn algorithm(&self) -> SignatureAlgorithm;
+
+    /// Verify `signature` over `message`
+    fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool>;
+}
+
+#[cfg(feature = "ed25519")]
+impl Signer for Ed25519KeyPair {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::Ed25519
+    }
+
+    #[inline]
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Ed25519Crypto::sign(message, self.signing_key())
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Verifier for Ed25519VerifyingKey {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::Ed25519
+    }
+
+    #[inline]
+    fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        Ed25519Crypto::verify(message, signature, self)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Signer for EcdsaKeyPair {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::EcdsaP256Sha256
+    }
+
+    #[inline]
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        EcdsaCrypto::sign(message, self.signing_key())
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Verifier for EcdsaVerifyingKey {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::EcdsaP256Sha256
+    }
+
+    #[inline]
+    fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        EcdsaCrypto::verify(message, signature, self)
+    }
+}
+
+#[cfg(feature = "ed25519")]
+impl Verifier for Ed25519VerifierContext {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::Ed25519
+    }
+
+    #[inline]
+    fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        Ed25519VerifierContext::verify(self, message, signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl Verifier for EcdsaVerifierContext {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::EcdsaP256Sha256
+    }
+
+    #[inline]
+    fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        EcdsaVerifierContext::verify(self, message, signature)
+    }
+}
+
+#[cfg(feature = "rsa")]
+impl Signer for RsaKeyPair {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::RsaPssSha256
+    }
+
+    #[inline]
+    fn sign(&self, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        RsaCrypto::sign(message, self.private_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256)
+    }
+}
+
+#[cfg(feature = "rsa")]
+impl Verifier for RsaPublicKey {
+    #[inline]
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::RsaPssSha256
+    }
+
+    #[inline]
+    fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        RsaCrypto::verify(message, signature, self, RsaSignaturePadding::Pss, RsaSignatureHash::Sha256)
+    }
+}
+
+#[cfg(all(test, feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+mod tests {
+    use super::*;
+
+    fn sign_and_verify(signer: &dyn Signer, verifier: &dyn Verifier) {
+        assert_eq!(signer.algorithm(), verifier.algorithm());
+        let signature = signer.sign(b"any libsilver signing key").unwrap();
+        assert!(verifier.verify(b"any libsilver signing key", &signature).unwrap());
+        assert!(!verifier.verify(b"a different message", &signature).unwrap());
+    }
+
+    #[test]
+    fn test_signer_and_verifier() {
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        sign_and_verify(&ed25519, ed25519.verifying_key());
+
+        let ecdsa = EcdsaKeyPair::generate().unwrap();
+        sign_and_verify(&ecdsa, ecdsa.verifying_key());
+
+        let rsa = RsaKeyPair::generate(2048).unwrap();
+        sign_and_verify(&rsa, rsa.public_key());
+
+        // Trait signatures are the scheme's own signatures
+        let signature = Signer::sign(&ecdsa, b"message").unwrap();
+        assert!(EcdsaCrypto::verify(b"message", &signature, ecdsa.verifying_key()).unwrap());
+        let signature = Signer::sign(&rsa, b"message").unwrap();
+        assert!(RsaCrypto::verify(b"message", &signature, rsa.public_key(), RsaSignaturePadding::Pss, RsaSignatureHash::Sha256).unwrap());
+
+        // Malformed signatures are errors, as with the scheme functions
+        assert!(Verifier::verify(ed25519.verifying_key(), b"message", &[0u8; 10]).is_err());
+        assert_eq!(SignatureAlgorithm::EcdsaP256Sha256.as_str(), "ECDSA-P256-SHA256");
+    }
+}
@@ -0,0 +1,412 @@
+use crate::error::{CryptoError, CryptoResult, INVALID_KEY_LENGTH_AES, AES_GCM_DECRYPTION_FAILED, STREAM_INVALID_HEADER, STREAM_TRUNCATED, STREAM_TOO_LONG};
+#[cfg(feature = "std")]
+use crate::error::{STREAM_READ_FAILED, STREAM_WRITE_FAILED};
+use crate::core::random::SecureRandom;
+use aes_gcm::{Aes256Gcm, Key, KeyInit};
+use aes_gcm::aead::stream::{DecryptorBE32, EncryptorBE32};
+#[cfg(feature = "std")]
+use aes_gcm::aead::stream::{NewStream, StreamBE32, StreamPrimitive};
+use zeroize::Zeroizing;
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::io::{Read, Write};
+#[cfg(feature = "std")]
+use std::sync::{mpsc, Mutex};
+
+// Stream layout (STREAM construction, big-endian 32-bit segment counter):
+//   nonce prefix (7) | segment 0 | segment 1 | ... | final segment
+// Every segment but the last carries exactly STREAM_CHUNK_SIZE bytes of
+// plaintext plus a 16-byte tag. The final segment (0..=STREAM_CHUNK_SIZE
+// bytes of plaintext) is sealed with the "last" flag set, so truncating the
+// stream at a segment boundary fails authentication.
+const AES_KEY_SIZE: usize = 32;
+const TAG_SIZE: usize = 16;
+
+/// Length of the nonce prefix written at the start of every stream
+pub const STREAM_HEADER_SIZE: usize = 7;
+/// Plaintext bytes per stream segment
+pub const STREAM_CHUNK_SIZE: usize = 64 * 1024;
+const SEGMENT_SIZE: usize = STREAM_CHUNK_SIZE + TAG_SIZE;
+
+/// Options for [`AesGcmStreamEncryptor::encrypt_pipelined`] and [`AesGcmStreamDecryptor::decrypt_pipelined`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "std")]
+pub struct StreamPipelineOptions {
+    /// Threads sealing or opening segments; 0 uses every available core
+    pub threads: usize,
+    /// Segments in flight per thread. Memory use is about
+    /// `threads * queue_depth` segments of 64 KiB
+    pub queue_depth: usize,
+}
+
+#[cfg(feature = "std")]
+impl Default for StreamPipelineOptions {
+    fn default() -> Self {
+        Self { threads: 0, queue_depth: 4 }
+    }
+}
+
+/// Incremental AES-256-GCM encryption for data too large to buffer
+///
+/// Feed plaintext with [`update`](Self::update) and write out whatever it
+/// returns, then append the output of [`finish`](Self::finish).
+pub struct AesGcmStreamEncryptor {
+    encryptor: EncryptorBE32<Aes256Gcm>,
+    header: Option<[u8; STREAM_HEADER_SIZE]>,
+    buffer: Zeroizing<Vec<u8>>,
+}
+
+impl AesGcmStreamEncryptor {
+    /// Start a new stream under a 32-byte key with a random nonce prefix
+    pub fn new(key: &[u8]) -> CryptoResult<Self> {
+        let cipher = new_cipher(key)?;
+        let mut header = [0u8; STREAM_HEADER_SIZE];
+        header.copy_from_slice(&SecureRandom::generate_bytes(STREAM_HEADER_SIZE)?);
+
+        Ok(Self {
+            encryptor: EncryptorBE32::from_aead(cipher, header.as_slice().into()),
+            header: Some(header),
+            buffer: Zeroizing::new(Vec::with_capacity(STREAM_CHUNK_SIZE)),
+        })
+    }
+
+    /// Add plaintext, returning any ciphertext that is ready to be written
+    pub fn update(&mut self, plaintext: &[u8]) -> CryptoResult<Vec<u8>> {
+        let mut output = Vec::with_capacity(plaintext.len() + TAG_SIZE + STREAM_HEADER_SIZE);
+        if let Some(header) = self.header.take() {
+            output.extend_from_slice(&header);
+        }
+        self.buffer.extend_from_slice(plaintext);
+
+        // Keep the latest chunk buffered; it may turn out to be the final segment
+        let mut offset = 0;
+        while self.buffer.len() - offset > STREAM_CHUNK_SIZE {
+            let segment = self.encryptor.encrypt_next(&self.buffer[offset..offset + STREAM_CHUNK_SIZE])
+                .map_err(|_| CryptoError::EncryptionFailed(STREAM_TOO_LONG))?;
+            output.extend_from_slice(&segment);
+            offset += STREAM_CHUNK_SIZE;
+        }
+        self.buffer.drain(..offset);
+        Ok(output)
+    }
+
+    /// Encrypt everything `reader` produces to `writer`, sealing segments on several threads
+    ///
+    /// Reading, encryption and writing overlap: a reader thread hands
+    /// segments to the worker threads and the calling thread writes the
+    /// results in order. The output is byte-for-byte the format of the
+    /// incremental encryptor. Returns the number of bytes written.
+    #[cfg(feature = "std")]
+    pub fn encrypt_pipelined(key: &[u8], reader: impl Read + Send, mut writer: impl Write, options: &StreamPipelineOptions) -> CryptoResult<u64> {
+        let cipher = new_cipher(key)?;
+        let header = SecureRandom::generate_bytes(STREAM_HEADER_SIZE)?;
+        let stream = StreamBE32::from_aead(cipher, header.as_slice().into());
+        writer.write_all(&header).map_err(|err| CryptoError::IoFailed(STREAM_WRITE_FAILED).caused_by(err))?;
+
+        let written = run_pipeline(reader, writer, STREAM_CHUNK_SIZE, options, CryptoError::EncryptionFailed(STREAM_TOO_LONG), |position, last, buffer| {
+            stream.encrypt_in_place(position, last, b"", &mut **buffer)
+                .map_err(|_| CryptoError::EncryptionFailed(STREAM_TOO_LONG))
+        })?;
+        Ok(written + STREAM_HEADER_SIZE as u64)
+    }
+
+    /// Seal the final segment and end the stream
+    pub fn finish(mut self) -> CryptoResult<Vec<u8>> {
+        let mut output = Vec::with_capacity(self.buffer.len() + TAG_SIZE + STREAM_HEADER_SIZE);
+        if let Some(header) = self.header.take() {
+            output.extend_from_slice(&header);
+        }
+        let segment = self.encryptor.encrypt_last(self.buffer.as_slice())
+            .map_err(|_| CryptoError::EncryptionFailed(STREAM_TOO_LONG))?;
+        output.extend_from_slice(&segment);
+        Ok(output)
+    }
+}
+
+/// Incremental decryption of streams produced by [`AesGcmStreamEncryptor`]
+///
+/// Plaintext returned by [`update`](Self::update) is authenticated segment
+/// by segment; the stream as a whole is only known to be complete once
+/// [`finish`](Self::finish) succeeds.
+pub struct AesGcmStreamDecryptor {
+    cipher: Option<Aes256Gcm>,
+    decryptor: Option<DecryptorBE32<Aes256Gcm>>,
+    buffer: Vec<u8>,
+}
+
+impl AesGcmStreamDecryptor {
+    /// Start decrypting a stream under a 32-byte key
+    pub fn new(key: &[u8]) -> CryptoResult<Self> {
+        Ok(Self {
+            cipher: Some(new_cipher(key)?),
+            decryptor: None,
+            buffer: Vec::with_capacity(SEGMENT_SIZE),
+        })
+    }
+
+    /// Add ciphertext, returning any plaintext that has been authenticated
+    pub fn update(&mut self, ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.buffer.extend_from_slice(ciphertext);
+        let mut offset = self.read_header();
+
+        let mut output = Vec::with_capacity(self.buffer.len());
+        if let Some(decryptor) = self.decryptor.as_mut() {
+            // The last segment can only be identified once the input ends
+            while self.buffer.len() - offset > SEGMENT_SIZE {
+                let segment = decryptor.decrypt_next(&self.buffer[offset..offset + SEGMENT_SIZE])
+                    .map_err(|_| CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED))?;
+                output.extend_from_slice(&segment);
+                offset += SEGMENT_SIZE;
+            }
+        }
+        self.buffer.drain(..offset);
+        Ok(output)
+    }
+
+    /// Authenticate the final segment and end the stream
+    pub fn finish(mut self) -> CryptoResult<Vec<u8>> {
+        self.read_header();
+        let decryptor = self.decryptor
+            .ok_or(CryptoError::DecryptionFailed(STREAM_INVALID_HEADER))?;
+        if self.buffer.len() < TAG_SIZE {
+            return Err(CryptoError::DecryptionFailed(STREAM_TRUNCATED));
+        }
+        decryptor.decrypt_last(self.buffer.as_slice())
+            .map_err(|_| CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED))
+    }
+
+    /// Decrypt a whole stream from `reader` to `writer`, opening segments on several threads
+    ///
+    /// Plaintext is written segment by segment once each is authenticated,
+    /// as with [`update`](Self::update); it is only known to be complete
+    /// once this returns `Ok`. Returns the number of plaintext bytes written.
+    #[cfg(feature = "std")]
+    pub fn decrypt_pipelined(key: &[u8], mut reader: impl Read + Send, writer: impl Write, options: &StreamPipelineOptions) -> CryptoResult<u64> {
+        let cipher = new_cipher(key)?;
+        let header = read_segment(&mut reader, STREAM_HEADER_SIZE)?;
+        if header.len() < STREAM_HEADER_SIZE {
+            return Err(CryptoError::DecryptionFailed(STREAM_INVALID_HEADER));
+        }
+        let stream = StreamBE32::from_aead(cipher, header.as_slice().into());
+
+        run_pipeline(reader, writer, SEGMENT_SIZE, options, CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED), |position, last, buffer| {
+            if buffer.len() < TAG_SIZE {
+                return Err(CryptoError::DecryptionFailed(STREAM_TRUNCATED));
+            }
+            stream.decrypt_in_place(position, last, b"", &mut **buffer)
+                .map_err(|_| CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED))
+        })
+    }
+
+    /// Consume the nonce prefix once enough input has arrived
+    fn read_header(&mut self) -> usize {
+        if self.decryptor.is_some() || self.buffer.len() < STREAM_HEADER_SIZE {
+            return 0;
+        }
+        if let Some(cipher) = self.cipher.take() {
+            self.decryptor = Some(DecryptorBE32::from_aead(cipher, self.buffer[..STREAM_HEADER_SIZE].into()));
+        }
+        STREAM_HEADER_SIZE
+    }
+}
+
+#[cfg(feature = "std")]
+pub(crate) type Segment = Zeroizing<Vec<u8>>;
+
+// Reader thread -> worker threads -> calling thread. Each job carries its own
+// result channel and those channels are queued in stream order, so output is
+// written in order and at most `threads * queue_depth` segments are in flight.
+#[cfg(feature = "std")]
+fn run_pipeline<R, W, F>(mut reader: R, mut writer: W, segment_len: usize, options: &StreamPipelineOptions, overflow: CryptoError, process: F) -> CryptoResult<u64>
+where
+    R: Read + Send,
+    W: Write,
+    F: Fn(u32, bool, &mut Segment) -> CryptoResult<()> + Sync,
+{
+    let threads = match options.threads {
+        0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+        n => n,
+    };
+    let (job_tx, job_rx) = mpsc::sync_channel::<(u32, bool, Segment, mpsc::SyncSender<CryptoResult<Segment>>)>(threads);
+    let (order_tx, order_rx) = mpsc::sync_channel(threads * options.queue_depth.max(1));
+    let job_rx = Mutex::new(job_rx);
+
+    std::thread::scope(|scope| {
+        let read_stage = scope.spawn(move || -> CryptoResult<()> {
+            let mut current = read_segment(&mut reader, segment_len)?;
+            let mut position = 0u32;
+            loop {
+                // A full segment is only the last one if nothing follows it
+                let next = if current.len() == segment_len { read_segment(&mut reader, segment_len)? } else { Zeroizing::new(Vec::new()) };
+                let last = next.is_empty();
+                if !last && position == u32::MAX {
+                    return Err(overflow);
+                }
+
+                let (result_tx, result_rx) = mpsc::sync_channel(1);
+                // Either send fails only once the writer has stopped
+                if order_tx.send(result_rx).is_err() || job_tx.send((position, last, current, result_tx)).is_err() || last {
+                    return Ok(());
+                }
+                current = next;
+                position += 1;
+            }
+        });
+
+        for _ in 0..threads {
+            let (job_rx, process) = (&job_rx, &process);
+            scope.spawn(move || loop {
+                let Ok(Ok((position, last, mut segment, result_tx))) = job_rx.lock().map(|rx| rx.recv()) else {
+                    return;
+                };
+                let result = process(position, last, &mut segment).map(|()| segment);
+                let _ = result_tx.send(result);
+            });
+        }
+
+        let mut written = 0u64;
+        for result_rx in order_rx {
+            let Ok(segment) = result_rx.recv() else {
+                break;
+            };
+            let segment = segment?;
+            writer.write_all(&segment).map_err(|err| CryptoError::IoFailed(STREAM_WRITE_FAILED).caused_by(err))?;
+            written += segment.len() as u64;
+        }
+        read_stage.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))?;
+        writer.flush().map_err(|err| CryptoError::IoFailed(STREAM_WRITE_FAILED).caused_by(err))?;
+        Ok(written)
+    })
+}
+
+// Read up to `len` bytes, short only at the end of the input
+#[cfg(feature = "std")]
+pub(crate) fn read_segment(reader: &mut impl Read, len: usize) -> CryptoResult<Segment> {
+    let mut segment = Zeroizing::new(Vec::with_capacity(len + TAG_SIZE));
+    reader.take(len as u64).read_to_end(&mut segment)
+        .map_err(|err| CryptoError::IoFailed(STREAM_READ_FAILED).caused_by(err))?;
+    Ok(segment)
+}
+
+#[inline]
+fn new_cipher(key: &[u8]) -> CryptoResult<Aes256Gcm> {
+    if key.len() != AES_KEY_SIZE {
+        return Err(CryptoError::InvalidKey(INVALID_KEY_LENGTH_AES));
+    }
+    Ok(Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encrypt_in_chunks(key: &[u8], plaintext: &[u8], chunk: usize) -> Vec<u8> {
+        let mut encryptor = AesGcmStreamEncryptor::new(key).unwrap();
+        let mut output = Vec::new();
+        for part in plaintext.chunks(chunk) {
+            output.extend(encryptor.update(part).unwrap());
+        }
+        output.extend(encryptor.finish().unwrap());
+        output
+    }
+
+    fn decrypt_in_chunks(key: &[u8], ciphertext: &[u8], chunk: usize) -> CryptoResult<Vec<u8>> {
+        let mut decryptor = AesGcmStreamDecryptor::new(key)?;
+        let mut output = Vec::new();
+        for part in ciphertext.chunks(chunk) {
+            output.extend(decryptor.update(part)?);
+        }
+        output.extend(decryptor.finish()?);
+        Ok(output)
+    }
+
+    #[test]
+    fn test_stream_roundtrip() {
+        let key = SecureRandom::generate_bytes(32).unwrap();
+        for len in [0, 1, STREAM_CHUNK_SIZE - 1, STREAM_CHUNK_SIZE, STREAM_CHUNK_SIZE + 1, 3 * STREAM_CHUNK_SIZE + 17] {
+            let plaintext: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let ciphertext = encrypt_in_chunks(&key, &plaintext, 10_000);
+
+            let segments = len.saturating_sub(1) / STREAM_CHUNK_SIZE + 1;
+            assert_eq!(ciphertext.len(), STREAM_HEADER_SIZE + len + segments * TAG_SIZE);
+            assert_eq!(decrypt_in_chunks(&key, &ciphertext, 4096).unwrap(), plaintext);
+            assert_eq!(decrypt_in_chunks(&key, &ciphertext, 3).unwrap(), plaintext);
+        }
+    }
+
+    #[test]
+    fn test_stream_rejects_tampering_and_truncation() {
+        let key = SecureRandom::generate_bytes(32).unwrap();
+        let plaintext = vec![0x42u8; 2 * STREAM_CHUNK_SIZE + 100];
+        let ciphertext = encrypt_in_chunks(&key, &plaintext, STREAM_CHUNK_SIZE);
+
+        let mut tampered = ciphertext.clone();
+        tampered[STREAM_HEADER_SIZE + 5] ^= 1;
+        assert!(decrypt_in_chunks(&key, &tampered, 8192).is_err());
+
+        // Dropping the final segment leaves a non-final segment at the end
+        let truncated = &ciphertext[..STREAM_HEADER_SIZE + 2 * SEGMENT_SIZE];
+        assert!(decrypt_in_chunks(&key, truncated, 8192).is_err());
+        assert_eq!(
+            decrypt_in_chunks(&key, &ciphertext[..STREAM_HEADER_SIZE + 10], 8192).unwrap_err(),
+            CryptoError::DecryptionFailed(STREAM_TRUNCATED)
+        );
+        assert_eq!(
+            decrypt_in_chunks(&key, &ciphertext[..3], 8192).unwrap_err(),
+            CryptoError::DecryptionFailed(STREAM_INVALID_HEADER)
+        );
+
+        let wrong_key = SecureRandom::generate_bytes(32).unwrap();
+        assert!(decrypt_in_chunks(&wrong_key, &ciphertext, 8192).is_err());
+        assert!(AesGcmStreamEncryptor::new(&[0u8; 16]).is_err());
+    }
+    #[test]
+    fn test_stream_pipelined_matches_incremental() {
+        let key = SecureRandom::generate_bytes(32).unwrap();
+        for (threads, len) in [(1, 0), (2, 1), (3, STREAM_CHUNK_SIZE), (4, STREAM_CHUNK_SIZE + 1), (0, 5 * STREAM_CHUNK_SIZE + 17)] {
+            let options = StreamPipelineOptions { threads, queue_depth: 2 };
+            let plaintext: Vec<u8> = (0..len).map(|i| (i % 253) as u8).collect();
+
+            let mut ciphertext = Vec::new();
+            let written = AesGcmStreamEncryptor::encrypt_pipelined(&key, &plaintext[..], &mut ciphertext, &options).unwrap();
+            assert_eq!(written, ciphertext.len() as u64);
+            assert_eq!(decrypt_in_chunks(&key, &ciphertext, 10_000).unwrap(), plaintext);
+
+            let incremental = encrypt_in_chunks(&key, &plaintext, 4096);
+            let mut decrypted = Vec::new();
+            let written = AesGcmStreamDecryptor::decrypt_pipelined(&key, &incremental[..], &mut decrypted, &options).unwrap();
+            assert_eq!(written, len as u64);
+            assert_eq!(decrypted, plaintext);
+        }
+    }
+
+    #[test]
+    fn test_stream_pipelined_errors() {
+        let key = SecureRandom::generate_bytes(32).unwrap();
+        let options = StreamPipelineOptions { threads: 2, queue_depth: 1 };
+        let plaintext = vec![7u8; 4 * STREAM_CHUNK_SIZE];
+        let ciphertext = encrypt_in_chunks(&key, &plaintext, STREAM_CHUNK_SIZE);
+        let decrypt = |input: &[u8]| AesGcmStreamDecryptor::decrypt_pipelined(&key, input, Vec::new(), &options);
+
+        // Only segments before the tampered one are written
+        let mut tampered = ciphertext.clone();
+        tampered[STREAM_HEADER_SIZE + 2 * SEGMENT_SIZE + 1] ^= 1;
+        let mut output = Vec::new();
+        assert_eq!(
+            AesGcmStreamDecryptor::decrypt_pipelined(&key, &tampered[..], &mut output, &options),
+            Err(CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED))
+        );
+        assert_eq!(output, &plaintext[..2 * STREAM_CHUNK_SIZE]);
+
+        assert!(decrypt(&ciphertext[..STREAM_HEADER_SIZE + 2 * SEGMENT_SIZE]).is_err());
+        assert_eq!(decrypt(&ciphertext[..STREAM_HEADER_SIZE + 10]), Err(CryptoError::DecryptionFailed(STREAM_TRUNCATED)));
+        assert_eq!(decrypt(&ciphertext[..3]), Err(CryptoError::DecryptionFailed(STREAM_INVALID_HEADER)));
+
+        // A failing writer stops the pipeline instead of blocking it
+        let full = &mut [0u8; 1000][..];
+        assert_eq!(
+            AesGcmStreamEncryptor::encrypt_pipelined(&key, &plaintext[..], full, &options).unwrap_err(),
+            CryptoError::IoFailed(STREAM_WRITE_FAILED)
+        );
+    }
+}
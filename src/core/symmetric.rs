@@ -1,80 +1,83 @@
-use crate::error::{CryptoError, CryptoResult, INVALID_KEY_LENGTH_AES, INVALID_KEY_LENGTH_CHACHA, INVALID_NONCE_LENGTH, CIPHERTEXT_TOO_SHORT, AES_GCM_ENCRYPTION_FAILED, AES_GCM_DECRYPTION_FAILED, CHACHA20_ENCRYPTION_FAILED, CHACHA20_DECRYPTION_FAILED};
+use crate::error::{CryptoError, CryptoResult, LIMIT_PLAINTEXT_TOO_LARGE, LIMIT_AAD_TOO_LARGE, LIMIT_KEY_USAGE_EXCEEDED};
+#[cfg(any(feature = "aes", feature = "chacha"))]
+use crate::error::{INVALID_NONCE_LENGTH, CIPHERTEXT_TOO_SHORT, OUTPUT_BUFFER_TOO_SMALL};
+#[cfg(feature = "aes")]
+use crate::error::{AES_GCM_ENCRYPTION_FAILED, AES_GCM_DECRYPTION_FAILED, AES_GCM_PLAINTEXT_TOO_LARGE, AES_GCM_AAD_TOO_LARGE};
+#[cfg(feature = "chacha")]
+use crate::error::{CHACHA20_ENCRYPTION_FAILED, CHACHA20_DECRYPTION_FAILED};
 use crate::core::random::SecureRandom;
-use aes_gcm::{Aes256Gcm, Key, Nonce, KeyInit};
-use aes_gcm::aead::Aead;
-use chacha20poly1305::{ChaCha20Poly1305, Key as ChaChaKey, Nonce as ChaChaNonce};
+#[cfg(feature = "aes")]
+use aes_gcm::{Aes256Gcm, Key, Nonce};
+#[cfg(feature = "aes")]
+use crate::core::keys::Aes256Key;
+#[cfg(feature = "chacha")]
+use chacha20poly1305::{ChaCha20Poly1305, Key as ChaCha20Key, Nonce as ChaChaNonce};
+#[cfg(feature = "chacha")]
+use crate::core::keys::ChaChaKey;
+// Both cipher crates re-export the same `aead` crate
+#[cfg(feature = "aes")]
+use aes_gcm::aead::{self, Aead as _, AeadInPlace, KeyInit};
+#[cfg(all(feature = "chacha", not(feature = "aes")))]
+use chacha20poly1305::aead::{self, Aead as _, AeadInPlace, KeyInit};
+use zeroize::Zeroizing;
+use alloc::vec::Vec;
+#[cfg(any(feature = "aes", feature = "chacha"))]
+use alloc::vec;
+#[cfg(feature = "aes")]
+use core::sync::atomic::{AtomicU64, Ordering};
 
 
 // Constants for AES-GCM
+#[cfg(feature = "aes")]
 const AES_KEY_SIZE: usize = 32;  // 256 bits
+#[cfg(any(feature = "aes", feature = "chacha"))]
 const AES_NONCE_SIZE: usize = 12; // 96 bits
+#[cfg(any(feature = "aes", feature = "chacha"))]
 const AES_TAG_SIZE: usize = 16;   // 128 bits
+#[cfg(any(feature = "aes", feature = "chacha"))]
 const MIN_CIPHERTEXT_SIZE: usize = AES_NONCE_SIZE + AES_TAG_SIZE; // 28 bytes minimum
 
 /// AES-256-GCM symmetric encryption
+#[cfg(feature = "aes")]
 pub struct AesGcm;
 
+#[cfg(feature = "aes")]
 impl AesGcm {
     /// Generate a new AES-256 key (32 bytes)
     #[inline]
-    pub fn generate_key() -> CryptoResult<Vec<u8>> {
-        SecureRandom::generate_bytes(AES_KEY_SIZE)
+    pub fn generate_key() -> CryptoResult<Aes256Key> {
+        Aes256Key::generate()
     }
 
     /// Encrypt data using AES-256-GCM
     /// Returns: nonce (12 bytes) + ciphertext + tag
     #[inline]
-    pub fn encrypt(plaintext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        Self::validate_key(key)?;
-
-        let key = Key::<Aes256Gcm>::from_slice(key);
-        let cipher = Aes256Gcm::new(key);
-
-        // Generate random nonce
-        let nonce_bytes = SecureRandom::generate_nonce(AES_NONCE_SIZE)?;
-        let nonce = Nonce::from_slice(&nonce_bytes);
-
-        // Encrypt
-        let ciphertext = cipher.encrypt(nonce, plaintext)
-            .map_err(|_| CryptoError::EncryptionFailed(AES_GCM_ENCRYPTION_FAILED))?;
-
-        // Prepend nonce to ciphertext - pre-allocate exact capacity
-        let mut result = Vec::with_capacity(AES_NONCE_SIZE + ciphertext.len());
-        result.extend_from_slice(&nonce_bytes);
-        result.extend_from_slice(&ciphertext);
-
-        Ok(result)
+    pub fn encrypt(plaintext: &[u8], key: &Aes256Key) -> CryptoResult<Vec<u8>> {
+        Self::encrypt_with_aad(plaintext, key, &[])
     }
 
     /// Decrypt data using AES-256-GCM
     /// Input format: nonce (12 bytes) + ciphertext + tag
     #[inline]
-    pub fn decrypt(ciphertext_with_nonce: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        Self::validate_key(key)?;
-        Self::validate_ciphertext_length(ciphertext_with_nonce)?;
-
-        let key = Key::<Aes256Gcm>::from_slice(key);
-        let cipher = Aes256Gcm::new(key);
-
-        // Extract nonce and ciphertext
-        let (nonce_bytes, ciphertext) = ciphertext_with_nonce.split_at(AES_NONCE_SIZE);
-        let nonce = Nonce::from_slice(nonce_bytes);
-
-        // Decrypt
-        let plaintext = cipher.decrypt(nonce, ciphertext)
-            .map_err(|_| CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED))?;
+    pub fn decrypt(ciphertext_with_nonce: &[u8], key: &Aes256Key) -> CryptoResult<Vec<u8>> {
+        Self::decrypt_with_aad(ciphertext_with_nonce, key, &[])
+    }
 
-        Ok(plaintext)
+    /// Decrypt data using AES-256-GCM, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(ciphertext_with_nonce: &[u8], key: &Aes256Key) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(ciphertext_with_nonce, key).map(Zeroizing::new)
     }
 
     /// Encrypt with provided nonce (for testing purposes)
     #[inline]
-    pub fn encrypt_with_nonce(plaintext: &[u8], key: &[u8], nonce: &[u8]) -> CryptoResult<Vec<u8>> {
-        Self::validate_key(key)?;
+    pub fn encrypt_with_nonce(plaintext: &[u8], key: &Aes256Key, nonce: &[u8]) -> CryptoResult<Vec<u8>> {
         Self::validate_nonce(nonce)?;
+        Self::check_sizes(plaintext.len(), 0)?;
+        #[cfg(all(feature = "nonce-tracking", debug_assertions))]
+        crate::core::nonce::track_nonce(key.as_bytes(), nonce)?;
 
-        let key = Key::<Aes256Gcm>::from_slice(key);
-        let cipher = Aes256Gcm::new(key);
+        let cipher = Self::cipher(key);
         let nonce = Nonce::from_slice(nonce);
 
         let ciphertext = cipher.encrypt(nonce, plaintext)
@@ -85,36 +88,35 @@ impl AesGcm {
 
     /// Encrypt with associated data (AAD) for additional authentication
     #[inline]
-    pub fn encrypt_with_aad(plaintext: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
-        Self::validate_key(key)?;
-
-        let key = Key::<Aes256Gcm>::from_slice(key);
-        let cipher = Aes256Gcm::new(key);
-
-        // Generate random nonce
-        let nonce_bytes = SecureRandom::generate_nonce(AES_NONCE_SIZE)?;
-        let nonce = Nonce::from_slice(&nonce_bytes);
-
-        // Encrypt with AAD
-        let ciphertext = cipher.encrypt(nonce, aes_gcm::aead::Payload { msg: plaintext, aad })
-            .map_err(|_| CryptoError::EncryptionFailed(AES_GCM_ENCRYPTION_FAILED))?;
-
-        // Prepend nonce to ciphertext
-        let mut result = Vec::with_capacity(AES_NONCE_SIZE + ciphertext.len());
-        result.extend_from_slice(&nonce_bytes);
-        result.extend_from_slice(&ciphertext);
+    pub fn encrypt_with_aad(plaintext: &[u8], key: &Aes256Key, aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        Self::encrypt_with_limits(plaintext, key, aad, &Limits::default())
+    }
 
+    /// Encrypt with AAD, rejecting plaintext or AAD larger than `limits` allows
+    ///
+    /// Only the message sizes are checked; counting messages per key against
+    /// [`Limits::max_messages_per_key`] needs state, which [`AesGcmContext`]
+    /// and the [`Encryptor`](crate::core::encryptor::Encryptor) builder keep.
+    pub fn encrypt_with_limits(plaintext: &[u8], key: &Aes256Key, aad: &[u8], limits: &Limits) -> CryptoResult<Vec<u8>> {
+        limits.check_message(plaintext.len(), aad.len())?;
+        // Encrypt in place in the output buffer so the ciphertext is never copied
+        let mut result = vec![0u8; Self::ciphertext_len(plaintext.len())];
+        Self::encrypt_into(plaintext, key, aad, &mut result)?;
         Ok(result)
     }
 
     /// Decrypt with associated data (AAD) for additional authentication
     #[inline]
-    pub fn decrypt_with_aad(ciphertext_with_nonce: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
-        Self::validate_key(key)?;
+    pub fn decrypt_with_aad(ciphertext_with_nonce: &[u8], key: &Aes256Key, aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        Self::decrypt_with_limits(ciphertext_with_nonce, key, aad, &Limits::default())
+    }
+
+    /// Decrypt with AAD, rejecting messages larger than `limits` allows before decrypting
+    pub fn decrypt_with_limits(ciphertext_with_nonce: &[u8], key: &Aes256Key, aad: &[u8], limits: &Limits) -> CryptoResult<Vec<u8>> {
         Self::validate_ciphertext_length(ciphertext_with_nonce)?;
+        check_sealed(limits, ciphertext_with_nonce.len(), aad.len())?;
 
-        let key = Key::<Aes256Gcm>::from_slice(key);
-        let cipher = Aes256Gcm::new(key);
+        let cipher = Self::cipher(key);
 
         // Extract nonce and ciphertext
         let (nonce_bytes, ciphertext) = ciphertext_with_nonce.split_at(AES_NONCE_SIZE);
@@ -127,13 +129,56 @@ impl AesGcm {
         Ok(plaintext)
     }
 
-    // Private helper methods for validation
+    /// Decrypt with associated data (AAD), zeroizing the plaintext on drop
     #[inline]
-    fn validate_key(key: &[u8]) -> CryptoResult<()> {
-        if key.len() != AES_KEY_SIZE {
-            return Err(CryptoError::InvalidKey(INVALID_KEY_LENGTH_AES));
-        }
-        Ok(())
+    pub fn decrypt_with_aad_zeroizing(ciphertext_with_nonce: &[u8], key: &Aes256Key, aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt_with_aad(ciphertext_with_nonce, key, aad).map(Zeroizing::new)
+    }
+
+    /// Length of `nonce + ciphertext + tag` for a plaintext of `plaintext_len` bytes
+    #[inline]
+    pub const fn ciphertext_len(plaintext_len: usize) -> usize {
+        AES_NONCE_SIZE + plaintext_len + AES_TAG_SIZE
+    }
+
+    /// Encrypt with AAD into a caller-provided buffer of at least [`Self::ciphertext_len`] bytes
+    /// Returns the number of bytes written
+    pub fn encrypt_into(plaintext: &[u8], key: &Aes256Key, aad: &[u8], out: &mut [u8]) -> CryptoResult<usize> {
+        Self::check_sizes(plaintext.len(), aad.len())?;
+        seal_into(&Self::cipher(key), &SecureRandom::generate_nonce(AES_NONCE_SIZE)?, plaintext, aad, out, AES_GCM_ENCRYPTION_FAILED)
+    }
+
+    /// Decrypt with AAD into a caller-provided buffer of at least `ciphertext_with_nonce.len() - 28` bytes
+    /// Returns the number of bytes written; `out` is zeroed if authentication fails
+    pub fn decrypt_into(ciphertext_with_nonce: &[u8], key: &Aes256Key, aad: &[u8], out: &mut [u8]) -> CryptoResult<usize> {
+        open_into(&Self::cipher(key), ciphertext_with_nonce, aad, out, AES_GCM_DECRYPTION_FAILED)
+    }
+
+    /// Decrypt `nonce || ciphertext || tag` in place, leaving only the plaintext in `buffer`
+    ///
+    /// Avoids a second plaintext-sized allocation for large messages. If
+    /// authentication fails the buffer still holds the ciphertext.
+    #[inline]
+    pub fn decrypt_in_place(buffer: &mut Vec<u8>, key: &Aes256Key) -> CryptoResult<()> {
+        Self::decrypt_in_place_with_aad(buffer, key, b"")
+    }
+
+    /// Decrypt with AAD in place; see [`Self::decrypt_in_place`]
+    #[inline]
+    pub fn decrypt_in_place_with_aad(buffer: &mut Vec<u8>, key: &Aes256Key, aad: &[u8]) -> CryptoResult<()> {
+        Self::decrypt_in_place_with_limits(buffer, key, aad, &Limits::default())
+    }
+
+    /// Decrypt with AAD in place, rejecting messages larger than `limits` allows before decrypting
+    pub fn decrypt_in_place_with_limits(buffer: &mut Vec<u8>, key: &Aes256Key, aad: &[u8], limits: &Limits) -> CryptoResult<()> {
+        check_sealed(limits, buffer.len(), aad.len())?;
+        open_in_place(&Self::cipher(key), buffer, aad, AES_GCM_DECRYPTION_FAILED)
+    }
+
+    // Private helper methods
+    #[inline]
+    fn cipher(key: &Aes256Key) -> Aes256Gcm {
+        Aes256Gcm::new(Key::<Aes256Gcm>::from_slice(key.as_bytes()))
     }
 
     #[inline]
@@ -151,75 +196,679 @@ impl AesGcm {
         }
         Ok(())
     }
+
+    /// Check one message against [`Limits::GCM_MAX_PLAINTEXT_LEN`] and [`Limits::GCM_MAX_AAD_LEN`]
+    ///
+    /// Every AES-GCM encryption runs this check; beyond these sizes the
+    /// counter wraps and the cipher loses its security guarantees.
+    #[inline]
+    pub fn check_sizes(plaintext_len: usize, aad_len: usize) -> CryptoResult<()> {
+        if plaintext_len as u64 > Limits::GCM_MAX_PLAINTEXT_LEN {
+            return Err(CryptoError::InvalidInput(AES_GCM_PLAINTEXT_TOO_LARGE));
+        }
+        if aad_len as u64 > Limits::GCM_MAX_AAD_LEN {
+            return Err(CryptoError::InvalidInput(AES_GCM_AAD_TOO_LARGE));
+        }
+        Ok(())
+    }
+}
+
+/// AES-256-GCM with the key schedule expanded once, for encrypting many
+/// messages under the same key (e.g. database field encryption)
+///
+/// Output is the same `nonce || ciphertext || tag` format as [`AesGcm`]; the
+/// expanded key is wiped when the context is dropped. Every encryption,
+/// including each batch record, counts against [`Limits::max_messages_per_key`].
+#[cfg(feature = "aes")]
+pub struct AesGcmContext {
+    cipher: Aes256Gcm,
+    limits: Limits,
+    messages: AtomicU64,
+}
+
+#[cfg(feature = "aes")]
+impl AesGcmContext {
+    /// Set up a context for a 32-byte AES-256 key with the default [`Limits`]
+    #[inline]
+    pub fn new(key: &Aes256Key) -> CryptoResult<Self> {
+        Self::with_limits(key, Limits::default())
+    }
+
+    /// Set up a context that rejects every message larger than `limits` allows,
+    /// and every encryption past `limits.max_messages_per_key`
+    pub fn with_limits(key: &Aes256Key, limits: Limits) -> CryptoResult<Self> {
+        Ok(Self { cipher: AesGcm::cipher(key), limits, messages: AtomicU64::new(0) })
+    }
+
+    /// Number of messages encrypted under the context's key
+    #[inline]
+    pub fn messages_encrypted(&self) -> u64 {
+        self.messages.load(Ordering::Relaxed)
+    }
+
+    // Count `count` more messages against the limit, all of them or none
+    fn reserve_messages(&self, count: u64) -> CryptoResult<()> {
+        self.messages
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |used| {
+                used.checked_add(count).filter(|&total| total <= self.limits.max_messages_per_key)
+            })
+            .map(|_| ())
+            .map_err(|_| CryptoError::InvalidKey(LIMIT_KEY_USAGE_EXCEEDED))
+    }
+
+    /// Encrypt with associated data (AAD) for additional authentication
+    pub fn encrypt_with_aad(&self, plaintext: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.limits.check_message(plaintext.len(), aad.len())?;
+        AesGcm::check_sizes(plaintext.len(), aad.len())?;
+        self.reserve_messages(1)?;
+        let mut result = vec![0u8; AesGcm::ciphertext_len(plaintext.len())];
+        seal_into(&self.cipher, &SecureRandom::generate_nonce(AES_NONCE_SIZE)?, plaintext, aad, &mut result, AES_GCM_ENCRYPTION_FAILED)?;
+        Ok(result)
+    }
+
+    /// Decrypt with associated data (AAD) for additional authentication
+    pub fn decrypt_with_aad(&self, ciphertext_with_nonce: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::validate_ciphertext_length(ciphertext_with_nonce)?;
+        check_sealed(&self.limits, ciphertext_with_nonce.len(), aad.len())?;
+        let mut result = vec![0u8; ciphertext_with_nonce.len() - MIN_CIPHERTEXT_SIZE];
+        open_into(&self.cipher, ciphertext_with_nonce, aad, &mut result, AES_GCM_DECRYPTION_FAILED)?;
+        Ok(result)
+    }
+
+    /// Encrypt each plaintext under a fresh random nonce
+    ///
+    /// All nonces come from a single RNG call; with the `parallel` feature
+    /// the records are encrypted across the rayon thread pool.
+    #[inline]
+    pub fn encrypt_batch(&self, plaintexts: &[&[u8]]) -> CryptoResult<Vec<Vec<u8>>> {
+        let records: Vec<(&[u8], &[u8])> = plaintexts.iter().map(|plaintext| (*plaintext, &[][..])).collect();
+        self.encrypt_batch_with_aad(&records)
+    }
+
+    /// Encrypt `(plaintext, aad)` records, each under a fresh random nonce
+    ///
+    /// Binding each field to its row or column through the AAD stops
+    /// ciphertexts from being swapped between records.
+    pub fn encrypt_batch_with_aad(&self, records: &[(&[u8], &[u8])]) -> CryptoResult<Vec<Vec<u8>>> {
+        if records.is_empty() {
+            return Ok(Vec::new());
+        }
+        for &(plaintext, aad) in records {
+            self.limits.check_message(plaintext.len(), aad.len())?;
+            AesGcm::check_sizes(plaintext.len(), aad.len())?;
+        }
+        self.reserve_messages(records.len() as u64)?;
+
+        let nonces = SecureRandom::generate_bytes(AES_NONCE_SIZE * records.len())?;
+        let seal = |(&(plaintext, aad), nonce): (&(&[u8], &[u8]), &[u8])| {
+            let mut result = vec![0u8; AesGcm::ciphertext_len(plaintext.len())];
+            seal_into(&self.cipher, nonce, plaintext, aad, &mut result, AES_GCM_ENCRYPTION_FAILED)?;
+            Ok(result)
+        };
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            records.par_iter().zip(nonces.par_chunks(AES_NONCE_SIZE)).map(seal).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            records.iter().zip(nonces.chunks(AES_NONCE_SIZE)).map(seal).collect()
+        }
+    }
+
+    /// Decrypt a batch produced by [`Self::encrypt_batch`]
+    /// Fails as a whole if any record fails to authenticate
+    #[inline]
+    pub fn decrypt_batch(&self, ciphertexts: &[&[u8]]) -> CryptoResult<Vec<Vec<u8>>> {
+        let records: Vec<(&[u8], &[u8])> = ciphertexts.iter().map(|ciphertext| (*ciphertext, &[][..])).collect();
+        self.decrypt_batch_with_aad(&records)
+    }
+
+    /// Decrypt `(ciphertext, aad)` records produced by [`Self::encrypt_batch_with_aad`]
+    /// Fails as a whole if any record fails to authenticate
+    pub fn decrypt_batch_with_aad(&self, records: &[(&[u8], &[u8])]) -> CryptoResult<Vec<Vec<u8>>> {
+        let open = |&(ciphertext, aad): &(&[u8], &[u8])| self.decrypt_with_aad(ciphertext, aad);
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            records.par_iter().map(open).collect()
+        }
+        #[cfg(not(feature = "parallel"))]
+        {
+            records.iter().map(open).collect()
+        }
+    }
+}
+
+/// AES-256-GCM laid out like WebCrypto's `crypto.subtle.encrypt({ name: "AES-GCM" })`
+///
+/// The caller supplies the 12-byte IV and the output is `ciphertext || tag`
+/// with a 128-bit tag and no nonce prefix, so a browser's `iv`,
+/// `additionalData` and result buffer map onto the arguments one to one.
+/// [`WebCryptoAesGcm::to_framed`] and [`WebCryptoAesGcm::from_framed`]
+/// convert to and from the `nonce || ciphertext || tag` format of [`AesGcm`].
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// // In the browser:
+/// //   const iv = crypto.getRandomValues(new Uint8Array(12));
+/// //   const ct = await crypto.subtle.encrypt({ name: "AES-GCM", iv, additionalData }, key, data);
+/// let key = AesGcm::generate_key()?;
+/// let iv = WebCryptoAesGcm::generate_iv()?;
+/// let ciphertext = WebCryptoAesGcm::encrypt(b"form data", &key, &iv, b"user:42")?;
+///
+/// assert_eq!(WebCryptoAesGcm::decrypt(&ciphertext, &key, &iv, b"user:42")?, b"form data");
+/// let framed = WebCryptoAesGcm::to_framed(&iv, &ciphertext)?;
+/// assert_eq!(AesGcm::decrypt_with_aad(&framed, &key, b"user:42")?, b"form data");
+/// # Ok::<(), CryptoError>(())
+/// ```
+#[cfg(feature = "aes")]
+pub struct WebCryptoAesGcm;
+
+#[cfg(feature = "aes")]
+impl WebCryptoAesGcm {
+    /// IV size in bytes
+    pub const IV_SIZE: usize = AES_NONCE_SIZE;
+
+    /// Tag size in bytes, WebCrypto's default `tagLength` of 128 bits
+    pub const TAG_SIZE: usize = AES_TAG_SIZE;
+
+    /// Generate a random 12-byte IV; never reuse one with the same key
+    #[inline]
+    pub fn generate_iv() -> CryptoResult<Vec<u8>> {
+        SecureRandom::generate_nonce(AES_NONCE_SIZE)
+    }
+
+    /// Encrypt like `crypto.subtle.encrypt`, returning `ciphertext || tag`
+    pub fn encrypt(plaintext: &[u8], key: &Aes256Key, iv: &[u8], additional_data: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::validate_nonce(iv)?;
+        AesGcm::check_sizes(plaintext.len(), additional_data.len())?;
+        #[cfg(all(feature = "nonce-tracking", debug_assertions))]
+        crate::core::nonce::track_nonce(key.as_bytes(), iv)?;
+
+        AesGcm::cipher(key)
+            .encrypt(Nonce::from_slice(iv), aes_gcm::aead::Payload { msg: plaintext, aad: additional_data })
+            .map_err(|_| CryptoError::EncryptionFailed(AES_GCM_ENCRYPTION_FAILED))
+    }
+
+    /// Decrypt the `ciphertext || tag` output of `crypto.subtle.encrypt`
+    pub fn decrypt(ciphertext: &[u8], key: &Aes256Key, iv: &[u8], additional_data: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::validate_nonce(iv)?;
+        if ciphertext.len() < AES_TAG_SIZE {
+            return Err(CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+        }
+
+        AesGcm::cipher(key)
+            .decrypt(Nonce::from_slice(iv), aes_gcm::aead::Payload { msg: ciphertext, aad: additional_data })
+            .map_err(|_| CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED))
+    }
+
+    /// Prefix the IV to produce [`AesGcm`]'s `nonce || ciphertext || tag`
+    pub fn to_framed(iv: &[u8], ciphertext: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::validate_nonce(iv)?;
+        if ciphertext.len() < AES_TAG_SIZE {
+            return Err(CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+        }
+
+        let mut framed = Vec::with_capacity(iv.len() + ciphertext.len());
+        framed.extend_from_slice(iv);
+        framed.extend_from_slice(ciphertext);
+        Ok(framed)
+    }
+
+    /// Split [`AesGcm`] output into the IV and the `ciphertext || tag` WebCrypto expects
+    #[inline]
+    pub fn from_framed(framed: &[u8]) -> CryptoResult<(&[u8], &[u8])> {
+        AesGcm::validate_ciphertext_length(framed)?;
+        Ok(framed.split_at(AES_NONCE_SIZE))
+    }
 }
 
 /// ChaCha20-Poly1305 symmetric encryption
+#[cfg(feature = "chacha")]
 pub struct ChaCha20Poly1305Cipher;
 
+#[cfg(feature = "chacha")]
 impl ChaCha20Poly1305Cipher {
     /// Generate a new ChaCha20 key (32 bytes)
     #[inline]
-    pub fn generate_key() -> CryptoResult<Vec<u8>> {
-        SecureRandom::generate_bytes(32)
+    pub fn generate_key() -> CryptoResult<ChaChaKey> {
+        ChaChaKey::generate()
     }
 
     /// Encrypt data using ChaCha20-Poly1305
     /// Returns: nonce (12 bytes) + ciphertext + tag
-    pub fn encrypt(plaintext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        if key.len() != 32 {
-            return Err(CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
-        }
-
-        let key = ChaChaKey::from_slice(key);
-        let cipher = ChaCha20Poly1305::new(key);
+    pub fn encrypt(plaintext: &[u8], key: &ChaChaKey) -> CryptoResult<Vec<u8>> {
+        Self::encrypt_with_aad(plaintext, key, &[])
+    }
 
-        // Generate random nonce
-        let nonce_bytes = SecureRandom::generate_nonce(12)?;
-        let nonce = ChaChaNonce::from_slice(&nonce_bytes);
+    /// Decrypt data using ChaCha20-Poly1305
+    /// Input format: nonce (12 bytes) + ciphertext + tag
+    pub fn decrypt(ciphertext_with_nonce: &[u8], key: &ChaChaKey) -> CryptoResult<Vec<u8>> {
+        Self::decrypt_with_aad(ciphertext_with_nonce, key, &[])
+    }
 
-        // Encrypt
-        let ciphertext = cipher.encrypt(nonce, plaintext)
-            .map_err(|_| CryptoError::EncryptionFailed(CHACHA20_ENCRYPTION_FAILED))?;
+    /// Decrypt data using ChaCha20-Poly1305, zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_zeroizing(ciphertext_with_nonce: &[u8], key: &ChaChaKey) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt(ciphertext_with_nonce, key).map(Zeroizing::new)
+    }
 
-        // Prepend nonce to ciphertext - pre-allocate exact capacity
-        let mut result = Vec::with_capacity(12 + ciphertext.len());
-        result.extend_from_slice(&nonce_bytes);
-        result.extend_from_slice(&ciphertext);
+    /// Encrypt with associated data (AAD) for additional authentication
+    #[inline]
+    pub fn encrypt_with_aad(plaintext: &[u8], key: &ChaChaKey, aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        Self::encrypt_with_limits(plaintext, key, aad, &Limits::default())
+    }
 
+    /// Encrypt with AAD, rejecting plaintext or AAD larger than `limits` allows
+    ///
+    /// Only the message sizes are checked; see [`AesGcm::encrypt_with_limits`].
+    pub fn encrypt_with_limits(plaintext: &[u8], key: &ChaChaKey, aad: &[u8], limits: &Limits) -> CryptoResult<Vec<u8>> {
+        limits.check_message(plaintext.len(), aad.len())?;
+        // Encrypt in place in the output buffer so the ciphertext is never copied
+        let mut result = vec![0u8; Self::ciphertext_len(plaintext.len())];
+        Self::encrypt_into(plaintext, key, aad, &mut result)?;
         Ok(result)
     }
 
-    /// Decrypt data using ChaCha20-Poly1305
-    /// Input format: nonce (12 bytes) + ciphertext + tag
-    pub fn decrypt(ciphertext_with_nonce: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        if key.len() != 32 {
-            return Err(CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
-        }
+    /// Decrypt with associated data (AAD) for additional authentication
+    #[inline]
+    pub fn decrypt_with_aad(ciphertext_with_nonce: &[u8], key: &ChaChaKey, aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        Self::decrypt_with_limits(ciphertext_with_nonce, key, aad, &Limits::default())
+    }
 
+    /// Decrypt with AAD, rejecting messages larger than `limits` allows before decrypting
+    pub fn decrypt_with_limits(ciphertext_with_nonce: &[u8], key: &ChaChaKey, aad: &[u8], limits: &Limits) -> CryptoResult<Vec<u8>> {
         if ciphertext_with_nonce.len() < 12 {
             return Err(CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
         }
+        check_sealed(limits, ciphertext_with_nonce.len(), aad.len())?;
 
-        let key = ChaChaKey::from_slice(key);
-        let cipher = ChaCha20Poly1305::new(key);
+        let cipher = Self::cipher(key);
 
         // Extract nonce and ciphertext
         let (nonce_bytes, ciphertext) = ciphertext_with_nonce.split_at(12);
         let nonce = ChaChaNonce::from_slice(nonce_bytes);
 
-        // Decrypt
-        let plaintext = cipher.decrypt(nonce, ciphertext)
+        // Decrypt with AAD
+        let plaintext = cipher.decrypt(nonce, chacha20poly1305::aead::Payload { msg: ciphertext, aad })
             .map_err(|_| CryptoError::DecryptionFailed(CHACHA20_DECRYPTION_FAILED))?;
 
         Ok(plaintext)
     }
+
+    /// Decrypt with associated data (AAD), zeroizing the plaintext on drop
+    #[inline]
+    pub fn decrypt_with_aad_zeroizing(ciphertext_with_nonce: &[u8], key: &ChaChaKey, aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        Self::decrypt_with_aad(ciphertext_with_nonce, key, aad).map(Zeroizing::new)
+    }
+
+    /// Length of `nonce + ciphertext + tag` for a plaintext of `plaintext_len` bytes
+    #[inline]
+    pub const fn ciphertext_len(plaintext_len: usize) -> usize {
+        AES_NONCE_SIZE + plaintext_len + AES_TAG_SIZE
+    }
+
+    /// Encrypt with AAD into a caller-provided buffer of at least [`Self::ciphertext_len`] bytes
+    /// Returns the number of bytes written
+    pub fn encrypt_into(plaintext: &[u8], key: &ChaChaKey, aad: &[u8], out: &mut [u8]) -> CryptoResult<usize> {
+        seal_into(&Self::cipher(key), &SecureRandom::generate_nonce(AES_NONCE_SIZE)?, plaintext, aad, out, CHACHA20_ENCRYPTION_FAILED)
+    }
+
+    /// Decrypt with AAD into a caller-provided buffer of at least `ciphertext_with_nonce.len() - 28` bytes
+    /// Returns the number of bytes written; `out` is zeroed if authentication fails
+    pub fn decrypt_into(ciphertext_with_nonce: &[u8], key: &ChaChaKey, aad: &[u8], out: &mut [u8]) -> CryptoResult<usize> {
+        open_into(&Self::cipher(key), ciphertext_with_nonce, aad, out, CHACHA20_DECRYPTION_FAILED)
+    }
+
+    /// Decrypt `nonce || ciphertext || tag` in place, leaving only the plaintext in `buffer`
+    ///
+    /// If authentication fails the buffer still holds the ciphertext.
+    #[inline]
+    pub fn decrypt_in_place(buffer: &mut Vec<u8>, key: &ChaChaKey) -> CryptoResult<()> {
+        Self::decrypt_in_place_with_aad(buffer, key, b"")
+    }
+
+    /// Decrypt with AAD in place; see [`Self::decrypt_in_place`]
+    #[inline]
+    pub fn decrypt_in_place_with_aad(buffer: &mut Vec<u8>, key: &ChaChaKey, aad: &[u8]) -> CryptoResult<()> {
+        Self::decrypt_in_place_with_limits(buffer, key, aad, &Limits::default())
+    }
+
+    /// Decrypt with AAD in place, rejecting messages larger than `limits` allows before decrypting
+    pub fn decrypt_in_place_with_limits(buffer: &mut Vec<u8>, key: &ChaChaKey, aad: &[u8], limits: &Limits) -> CryptoResult<()> {
+        check_sealed(limits, buffer.len(), aad.len())?;
+        open_in_place(&Self::cipher(key), buffer, aad, CHACHA20_DECRYPTION_FAILED)
+    }
+
+    #[inline]
+    fn cipher(key: &ChaChaKey) -> ChaCha20Poly1305 {
+        ChaCha20Poly1305::new(ChaCha20Key::from_slice(key.as_bytes()))
+    }
 }
 
+/// Authenticated encryption with associated data, implemented by every
+/// symmetric cipher so callers can be generic over the algorithm
+///
+/// Ciphertexts are `nonce || ciphertext || tag`, exactly as produced by the
+/// cipher's own associated functions. The trait is object safe, so a cipher
+/// chosen at runtime can be held as `&dyn Aead`:
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// fn roundtrip(cipher: &dyn Aead) -> CryptoResult<Vec<u8>> {
+///     let key = cipher.generate_key()?;
+///     let ciphertext = cipher.encrypt_with_aad(b"record", &key, b"user:42")?;
+///     cipher.decrypt_with_aad(&ciphertext, &key, b"user:42")
+/// }
+///
+/// assert_eq!(roundtrip(&AesGcm)?, b"record");
+/// assert_eq!(roundtrip(&ChaCha20Poly1305Cipher)?, b"record");
+/// # Ok::<(), CryptoError>(())
+/// ```
+pub trait Aead {
+    /// Algorithm name, e.g. `"AES-256-GCM"`
+    fn name(&self) -> &'static str;
+
+    /// Key size in bytes
+    fn key_size(&self) -> usize;
+
+    /// Nonce size in bytes, prepended to every ciphertext
+    fn nonce_size(&self) -> usize;
+
+    /// Authentication tag size in bytes, appended to every ciphertext
+    fn tag_size(&self) -> usize;
+
+    /// Encrypt with associated data (AAD)
+    fn encrypt_with_aad(&self, plaintext: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Decrypt with associated data (AAD)
+    fn decrypt_with_aad(&self, ciphertext_with_nonce: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Encrypt with a caller-chosen nonce of [`Aead::nonce_size`] bytes and associated data
+    ///
+    /// The output has the same `nonce || ciphertext || tag` layout. Never use
+    /// a nonce twice with the same key; prefer [`Aead::encrypt_with_aad`].
+    fn seal_with_nonce(&self, plaintext: &[u8], key: &[u8], nonce: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>>;
+
+    /// Generate a random key of [`Aead::key_size`] bytes
+    #[inline]
+    fn generate_key(&self) -> CryptoResult<Vec<u8>> {
+        SecureRandom::generate_bytes(self.key_size())
+    }
+
+    /// Encrypt without associated data
+    #[inline]
+    fn encrypt(&self, plaintext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.encrypt_with_aad(plaintext, key, &[])
+    }
 
+    /// Decrypt without associated data
+    #[inline]
+    fn decrypt(&self, ciphertext_with_nonce: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
+        self.decrypt_with_aad(ciphertext_with_nonce, key, &[])
+    }
 
-#[cfg(test)]
+    /// Decrypt with associated data, zeroizing the plaintext on drop
+    #[inline]
+    fn decrypt_with_aad_zeroizing(&self, ciphertext_with_nonce: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Zeroizing<Vec<u8>>> {
+        self.decrypt_with_aad(ciphertext_with_nonce, key, aad).map(Zeroizing::new)
+    }
+
+    /// Length of `nonce + ciphertext + tag` for a plaintext of `plaintext_len` bytes
+    #[inline]
+    fn ciphertext_len(&self, plaintext_len: usize) -> usize {
+        self.nonce_size() + plaintext_len + self.tag_size()
+    }
+}
+
+#[cfg(feature = "aes")]
+impl Aead for AesGcm {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "AES-256-GCM"
+    }
+
+    #[inline]
+    fn key_size(&self) -> usize {
+        AES_KEY_SIZE
+    }
+
+    #[inline]
+    fn nonce_size(&self) -> usize {
+        AES_NONCE_SIZE
+    }
+
+    #[inline]
+    fn tag_size(&self) -> usize {
+        AES_TAG_SIZE
+    }
+
+    #[inline]
+    fn encrypt_with_aad(&self, plaintext: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::encrypt_with_aad(plaintext, &Aes256Key::from_bytes(key)?, aad)
+    }
+
+    #[inline]
+    fn decrypt_with_aad(&self, ciphertext_with_nonce: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        AesGcm::decrypt_with_aad(ciphertext_with_nonce, &Aes256Key::from_bytes(key)?, aad)
+    }
+
+    fn seal_with_nonce(&self, plaintext: &[u8], key: &[u8], nonce: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        let cipher = AesGcm::cipher(&Aes256Key::from_bytes(key)?);
+        AesGcm::check_sizes(plaintext.len(), aad.len())?;
+        let mut result = vec![0u8; AesGcm::ciphertext_len(plaintext.len())];
+        #[cfg(all(feature = "nonce-tracking", debug_assertions))]
+        crate::core::nonce::track_nonce(key, nonce)?;
+        seal_into(&cipher, nonce, plaintext, aad, &mut result, AES_GCM_ENCRYPTION_FAILED)?;
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "chacha")]
+impl Aead for ChaCha20Poly1305Cipher {
+    #[inline]
+    fn name(&self) -> &'static str {
+        "ChaCha20-Poly1305"
+    }
+
+    #[inline]
+    fn key_size(&self) -> usize {
+        32
+    }
+
+    #[inline]
+    fn nonce_size(&self) -> usize {
+        AES_NONCE_SIZE
+    }
+
+    #[inline]
+    fn tag_size(&self) -> usize {
+        AES_TAG_SIZE
+    }
+
+    #[inline]
+    fn encrypt_with_aad(&self, plaintext: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher::encrypt_with_aad(plaintext, &ChaChaKey::from_bytes(key)?, aad)
+    }
+
+    #[inline]
+    fn decrypt_with_aad(&self, ciphertext_with_nonce: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        ChaCha20Poly1305Cipher::decrypt_with_aad(ciphertext_with_nonce, &ChaChaKey::from_bytes(key)?, aad)
+    }
+
+    fn seal_with_nonce(&self, plaintext: &[u8], key: &[u8], nonce: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
+        let cipher = ChaCha20Poly1305Cipher::cipher(&ChaChaKey::from_bytes(key)?);
+        let mut result = vec![0u8; ChaCha20Poly1305Cipher::ciphertext_len(plaintext.len())];
+        #[cfg(all(feature = "nonce-tracking", debug_assertions))]
+        crate::core::nonce::track_nonce(key, nonce)?;
+        seal_into(&cipher, nonce, plaintext, aad, &mut result, CHACHA20_ENCRYPTION_FAILED)?;
+        Ok(result)
+    }
+}
+
+/// Size and usage bounds for symmetric encryption
+///
+/// The defaults are the limits of AES-256-GCM itself (NIST SP 800-38D): at most
+/// 2^36 - 32 bytes of plaintext and 2^36 bytes of associated data per message,
+/// and 2^32 messages per key with random nonces. Services handling untrusted input should lower
+/// `max_plaintext_len` and `max_aad_len` to what they actually expect.
+///
+/// The one-shot functions of [`AesGcm`] and [`ChaCha20Poly1305Cipher`] and
+/// [`AesGcmContext`] apply the default sizes, or custom ones through their
+/// `*_with_limits` variants. `max_messages_per_key` needs a running count,
+/// so only [`AesGcmContext`] and the
+/// [`Encryptor`](crate::core::encryptor::Encryptor) builder enforce it.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let limits = Limits { max_plaintext_len: 1 << 20, max_aad_len: 256, ..Limits::default() };
+/// let key = AesGcm::generate_key()?;
+/// let mut encryptor = Encryptor::new().limits(limits).key(&key);
+/// assert!(encryptor.encrypt(&[0u8; 1024]).is_ok());
+/// assert!(encryptor.encrypt(&vec![0u8; 2 << 20]).is_err());
+/// # Ok::<(), CryptoError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// Largest plaintext accepted, in bytes
+    pub max_plaintext_len: usize,
+    /// Largest associated data accepted, in bytes
+    pub max_aad_len: usize,
+    /// Most messages one key may encrypt before it must be rotated
+    pub max_messages_per_key: u64,
+}
+
+impl Limits {
+    /// Largest AES-GCM plaintext, 2^36 - 32 bytes (about 64 GiB)
+    pub const GCM_MAX_PLAINTEXT_LEN: u64 = (1 << 36) - 32;
+
+    /// Largest AES-GCM associated data, 2^36 bytes
+    ///
+    /// SP 800-38D allows up to 2^61 - 1 bytes; this is the bound of the
+    /// AES-GCM implementation, and already far beyond any sane header.
+    pub const GCM_MAX_AAD_LEN: u64 = 1 << 36;
+
+    /// Most AES-GCM messages per key with random 96-bit nonces
+    pub const GCM_MAX_RANDOM_NONCE_MESSAGES: u64 = 1 << 32;
+
+    /// No bounds beyond what the platform can address
+    #[inline]
+    pub const fn unlimited() -> Self {
+        Limits { max_plaintext_len: usize::MAX, max_aad_len: usize::MAX, max_messages_per_key: u64::MAX }
+    }
+
+    /// Check the sizes of one message's plaintext and associated data
+    #[inline]
+    pub fn check_message(&self, plaintext_len: usize, aad_len: usize) -> CryptoResult<()> {
+        if plaintext_len > self.max_plaintext_len {
+            return Err(CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+        }
+        if aad_len > self.max_aad_len {
+            return Err(CryptoError::InvalidInput(LIMIT_AAD_TOO_LARGE));
+        }
+        Ok(())
+    }
+
+    /// Check that a key which has encrypted `messages` messages may encrypt another
+    #[inline]
+    pub fn check_usage(&self, messages: u64) -> CryptoResult<()> {
+        if messages >= self.max_messages_per_key {
+            return Err(CryptoError::InvalidKey(LIMIT_KEY_USAGE_EXCEEDED));
+        }
+        Ok(())
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Limits {
+            max_plaintext_len: usize::try_from(Self::GCM_MAX_PLAINTEXT_LEN).unwrap_or(usize::MAX),
+            max_aad_len: usize::try_from(Self::GCM_MAX_AAD_LEN).unwrap_or(usize::MAX),
+            max_messages_per_key: Self::GCM_MAX_RANDOM_NONCE_MESSAGES,
+        }
+    }
+}
+
+// The plaintext of `nonce || ciphertext || tag` is 28 bytes shorter
+#[cfg(any(feature = "aes", feature = "chacha"))]
+#[inline]
+fn check_sealed(limits: &Limits, ciphertext_len: usize, aad_len: usize) -> CryptoResult<()> {
+    limits.check_message(ciphertext_len.saturating_sub(MIN_CIPHERTEXT_SIZE), aad_len)
+}
+
+// Write `nonce || ciphertext || tag` into `out`, encrypting in place
+// (AES-256-GCM and ChaCha20-Poly1305 share the 12-byte nonce and 16-byte tag)
+#[cfg(any(feature = "aes", feature = "chacha"))]
+fn seal_into<C: AeadInPlace>(cipher: &C, nonce_bytes: &[u8], plaintext: &[u8], aad: &[u8], out: &mut [u8], error: &'static str) -> CryptoResult<usize> {
+    if nonce_bytes.len() != AES_NONCE_SIZE {
+        return Err(CryptoError::InvalidInput(INVALID_NONCE_LENGTH));
+    }
+    let len = AES_NONCE_SIZE + plaintext.len() + AES_TAG_SIZE;
+    if out.len() < len {
+        return Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL));
+    }
+
+    let (nonce, rest) = out[..len].split_at_mut(AES_NONCE_SIZE);
+    let (body, tag_out) = rest.split_at_mut(plaintext.len());
+    nonce.copy_from_slice(nonce_bytes);
+    body.copy_from_slice(plaintext);
+
+    let tag = cipher.encrypt_in_place_detached(aead::Nonce::<C>::from_slice(nonce), aad, body)
+        .map_err(|_| CryptoError::EncryptionFailed(error))?;
+    tag_out.copy_from_slice(&tag);
+    Ok(len)
+}
+
+// Decrypt `nonce || ciphertext || tag` into `out`, wiping it on failure
+#[cfg(any(feature = "aes", feature = "chacha"))]
+fn open_into<C: AeadInPlace>(cipher: &C, ciphertext_with_nonce: &[u8], aad: &[u8], out: &mut [u8], error: &'static str) -> CryptoResult<usize> {
+    if ciphertext_with_nonce.len() < MIN_CIPHERTEXT_SIZE {
+        return Err(CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+    }
+    let (nonce, rest) = ciphertext_with_nonce.split_at(AES_NONCE_SIZE);
+    let (body, tag) = rest.split_at(rest.len() - AES_TAG_SIZE);
+    if out.len() < body.len() {
+        return Err(CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL));
+    }
+
+    let plaintext = &mut out[..body.len()];
+    plaintext.copy_from_slice(body);
+    let nonce = aead::Nonce::<C>::from_slice(nonce);
+    let tag = aead::Tag::<C>::from_slice(tag);
+    if cipher.decrypt_in_place_detached(nonce, aad, plaintext, tag).is_err() {
+        plaintext.fill(0);
+        return Err(CryptoError::DecryptionFailed(error));
+    }
+    Ok(body.len())
+}
+
+// Decrypt `nonce || ciphertext || tag` in `buffer`, then drop the nonce and tag.
+// Both ciphers check the tag before decrypting, so a failure leaves the buffer untouched
+#[cfg(any(feature = "aes", feature = "chacha"))]
+fn open_in_place<C: AeadInPlace>(cipher: &C, buffer: &mut Vec<u8>, aad: &[u8], error: &'static str) -> CryptoResult<()> {
+    if buffer.len() < MIN_CIPHERTEXT_SIZE {
+        return Err(CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+    }
+    let (nonce, rest) = buffer.split_at_mut(AES_NONCE_SIZE);
+    let (body, tag) = rest.split_at_mut(rest.len() - AES_TAG_SIZE);
+    cipher.decrypt_in_place_detached(aead::Nonce::<C>::from_slice(nonce), aad, body, aead::Tag::<C>::from_slice(tag))
+        .map_err(|_| CryptoError::DecryptionFailed(error))?;
+
+    buffer.truncate(buffer.len() - AES_TAG_SIZE);
+    buffer.drain(..AES_NONCE_SIZE);
+    Ok(())
+}
+
+#[cfg(all(test, feature = "aes", feature = "chacha"))]
 mod tests {
     use super::*;
+    use crate::error::{INVALID_KEY_LENGTH_AES, INVALID_KEY_LENGTH_CHACHA};
 
     #[test]
     fn test_aes_gcm_encrypt_decrypt() {
@@ -234,13 +883,26 @@ mod tests {
         assert_eq!(decrypted, plaintext);
     }
 
+    #[test]
+    fn test_decrypt_zeroizing() {
+        let key = AesGcm::generate_key().unwrap();
+        let ciphertext = AesGcm::encrypt_with_aad(b"secret", &key, b"aad").unwrap();
+        assert_eq!(AesGcm::decrypt_with_aad_zeroizing(&ciphertext, &key, b"aad").unwrap().as_slice(), b"secret");
+        assert!(AesGcm::decrypt_zeroizing(&ciphertext, &key).is_err());
+
+        let key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+        let ciphertext = ChaCha20Poly1305Cipher::encrypt(b"secret", &key).unwrap();
+        assert_eq!(ChaCha20Poly1305Cipher::decrypt_zeroizing(&ciphertext, &key).unwrap().as_slice(), b"secret");
+    }
+
     #[test]
     fn test_aes_gcm_invalid_key_length() {
         let short_key = vec![0u8; 16]; // Too short
         let plaintext = b"test";
 
-        let result = AesGcm::encrypt(plaintext, &short_key);
-        assert!(result.is_err());
+        // Typed keys refuse the wrong length; the byte-level `Aead` trait checks every call
+        assert_eq!(Aes256Key::from_bytes(&short_key).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_AES));
+        assert_eq!(AesGcm.encrypt(plaintext, &short_key).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_AES));
     }
 
     #[test]
@@ -271,8 +933,8 @@ mod tests {
         let short_key = vec![0u8; 16]; // Too short
         let plaintext = b"test";
 
-        let result = ChaCha20Poly1305Cipher::encrypt(plaintext, &short_key);
-        assert!(result.is_err());
+        assert_eq!(ChaChaKey::from_bytes(&short_key).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
+        assert_eq!(ChaCha20Poly1305Cipher.encrypt(plaintext, &short_key).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
     }
 
     #[test]
@@ -316,6 +978,136 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_chacha20_with_aad() {
+        let key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+        let plaintext = b"Secret message";
+        let aad = b"additional authenticated data";
+
+        let ciphertext = ChaCha20Poly1305Cipher::encrypt_with_aad(plaintext, &key, aad).unwrap();
+        let decrypted = ChaCha20Poly1305Cipher::decrypt_with_aad(&ciphertext, &key, aad).unwrap();
+        assert_eq!(decrypted, plaintext);
+
+        // Empty AAD is interchangeable with the plain API
+        let plain = ChaCha20Poly1305Cipher::encrypt(plaintext, &key).unwrap();
+        assert_eq!(ChaCha20Poly1305Cipher::decrypt_with_aad(&plain, &key, b"").unwrap(), plaintext);
+
+        assert!(ChaCha20Poly1305Cipher::decrypt_with_aad(&ciphertext, &key, b"wrong additional data").is_err());
+        assert!(ChaCha20Poly1305Cipher::decrypt(&ciphertext, &key).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_into_caller_buffer() {
+        let key = AesGcm::generate_key().unwrap();
+        let plaintext = b"written straight into the caller's buffer";
+
+        // Oversized buffers are fine; only the returned length is written
+        let mut ciphertext = vec![0u8; AesGcm::ciphertext_len(plaintext.len()) + 8];
+        let written = AesGcm::encrypt_into(plaintext, &key, b"aad", &mut ciphertext).unwrap();
+        assert_eq!(written, AesGcm::ciphertext_len(plaintext.len()));
+        assert_eq!(AesGcm::decrypt_with_aad(&ciphertext[..written], &key, b"aad").unwrap(), plaintext);
+
+        let mut decrypted = vec![0u8; plaintext.len()];
+        assert_eq!(AesGcm::decrypt_into(&ciphertext[..written], &key, b"aad", &mut decrypted).unwrap(), plaintext.len());
+        assert_eq!(decrypted, plaintext);
+
+        // Failed authentication leaves no ciphertext or plaintext behind
+        let mut wiped = vec![0xffu8; plaintext.len()];
+        assert!(AesGcm::decrypt_into(&ciphertext[..written], &key, b"other", &mut wiped).is_err());
+        assert!(wiped.iter().all(|&b| b == 0));
+
+        assert_eq!(
+            AesGcm::encrypt_into(plaintext, &key, b"", &mut [0u8; 16]).unwrap_err(),
+            CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)
+        );
+        assert_eq!(
+            AesGcm::decrypt_into(&ciphertext[..written], &key, b"aad", &mut [0u8; 4]).unwrap_err(),
+            CryptoError::InvalidInput(OUTPUT_BUFFER_TOO_SMALL)
+        );
+
+        let key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+        let mut ciphertext = vec![0u8; ChaCha20Poly1305Cipher::ciphertext_len(plaintext.len())];
+        ChaCha20Poly1305Cipher::encrypt_into(plaintext, &key, b"", &mut ciphertext).unwrap();
+        assert_eq!(ChaCha20Poly1305Cipher::decrypt(&ciphertext, &key).unwrap(), plaintext);
+        let mut decrypted = vec![0u8; plaintext.len()];
+        ChaCha20Poly1305Cipher::decrypt_into(&ciphertext, &key, b"", &mut decrypted).unwrap();
+        assert_eq!(decrypted, plaintext);
+    }
+
+    #[test]
+    fn test_decrypt_in_place() {
+        let plaintext = vec![0x5au8; 100_000];
+        let key = AesGcm::generate_key().unwrap();
+
+        let mut buffer = AesGcm::encrypt(&plaintext, &key).unwrap();
+        let capacity = buffer.capacity();
+        AesGcm::decrypt_in_place(&mut buffer, &key).unwrap();
+        assert_eq!(buffer, plaintext);
+        assert_eq!(buffer.capacity(), capacity);
+
+        // A failed check leaves the ciphertext for another attempt
+        let ciphertext = AesGcm::encrypt_with_aad(&plaintext, &key, b"aad").unwrap();
+        let mut buffer = ciphertext.clone();
+        assert_eq!(AesGcm::decrypt_in_place(&mut buffer, &key).unwrap_err(), CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED));
+        assert_eq!(buffer, ciphertext);
+        AesGcm::decrypt_in_place_with_aad(&mut buffer, &key, b"aad").unwrap();
+        assert_eq!(buffer, plaintext);
+
+        let mut empty = AesGcm::encrypt(b"", &key).unwrap();
+        AesGcm::decrypt_in_place(&mut empty, &key).unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(AesGcm::decrypt_in_place(&mut vec![0u8; 27], &key).unwrap_err(), CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+
+        let key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+        let mut buffer = ChaCha20Poly1305Cipher::encrypt_with_aad(&plaintext, &key, b"aad").unwrap();
+        assert!(ChaCha20Poly1305Cipher::decrypt_in_place(&mut buffer, &key).is_err());
+        ChaCha20Poly1305Cipher::decrypt_in_place_with_aad(&mut buffer, &key, b"aad").unwrap();
+        assert_eq!(buffer, plaintext);
+    }
+
+    fn aead_roundtrip<A: Aead>(cipher: &A) {
+        let key = cipher.generate_key().unwrap();
+        assert_eq!(key.len(), cipher.key_size());
+
+        let ciphertext = cipher.encrypt_with_aad(b"generic", &key, b"aad").unwrap();
+        assert_eq!(ciphertext.len(), cipher.ciphertext_len(7));
+        assert_eq!(cipher.decrypt_with_aad(&ciphertext, &key, b"aad").unwrap(), b"generic");
+        assert!(cipher.decrypt(&ciphertext, &key).is_err());
+
+        let ciphertext = cipher.encrypt(b"", &key).unwrap();
+        assert_eq!(cipher.decrypt_with_aad_zeroizing(&ciphertext, &key, &[]).unwrap().as_slice(), b"");
+    }
+
+    #[test]
+    fn test_aead_trait() {
+        aead_roundtrip(&AesGcm);
+        aead_roundtrip(&ChaCha20Poly1305Cipher);
+
+        // Trait ciphertexts are interchangeable with the associated functions
+        let ciphers: [&dyn Aead; 2] = [&AesGcm, &ChaCha20Poly1305Cipher];
+        let key = AesGcm.generate_key().unwrap();
+        let aes_key = Aes256Key::from_bytes(&key).unwrap();
+        let ciphertext = ciphers[0].encrypt_with_aad(b"data", &key, b"aad").unwrap();
+        assert_eq!(AesGcm::decrypt_with_aad(&ciphertext, &aes_key, b"aad").unwrap(), b"data");
+        let ciphertext = ChaCha20Poly1305Cipher::encrypt(b"data", &ChaChaKey::from_bytes(&key).unwrap()).unwrap();
+        assert_eq!(ciphers[1].decrypt(&ciphertext, &key).unwrap(), b"data");
+        assert!(ciphers[0].decrypt(&ciphertext, &key).is_err());
+
+        assert_eq!(ciphers.map(|cipher| cipher.name()), ["AES-256-GCM", "ChaCha20-Poly1305"]);
+        assert_eq!(ciphers[1].encrypt(b"x", &[0u8; 16]).unwrap_err(), CryptoError::InvalidKey(INVALID_KEY_LENGTH_CHACHA));
+
+        // Explicit nonces: AES-GCM matches `encrypt_with_nonce`, both ciphers round-trip
+        let nonce = [7u8; 12];
+        let sealed = ciphers[0].seal_with_nonce(b"data", &key, &nonce, &[]).unwrap();
+        assert_eq!(sealed[..12], nonce);
+        assert_eq!(sealed[12..], AesGcm::encrypt_with_nonce(b"data", &aes_key, &nonce).unwrap());
+        for cipher in ciphers {
+            let sealed = cipher.seal_with_nonce(b"data", &key, &nonce, b"aad").unwrap();
+            assert_eq!(cipher.decrypt_with_aad(&sealed, &key, b"aad").unwrap(), b"data");
+            assert_eq!(cipher.seal_with_nonce(b"data", &key, &nonce[..8], b"aad").unwrap_err(), CryptoError::InvalidInput(INVALID_NONCE_LENGTH));
+        }
+    }
+
     #[test]
     fn test_aes_gcm_constants() {
         // Test that our constants are correct
@@ -333,4 +1125,146 @@ mod tests {
         let result = AesGcm::decrypt(&short_ciphertext, &key);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_limits() {
+        let limits = Limits::default();
+        assert_eq!(limits.max_messages_per_key, 1 << 32);
+        assert!(limits.check_message(1 << 20, 1 << 20).is_ok());
+        assert!(limits.check_usage((1 << 32) - 1).is_ok());
+        assert_eq!(limits.check_usage(1 << 32).unwrap_err(), CryptoError::InvalidKey(LIMIT_KEY_USAGE_EXCEEDED));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(limits.check_message(1 << 36, 0).unwrap_err(), CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(limits.check_message(0, (1 << 36) + 1).unwrap_err(), CryptoError::InvalidInput(LIMIT_AAD_TOO_LARGE));
+
+        assert!(AesGcm::check_sizes((1 << 36) - 32, 1 << 36).is_ok());
+        #[cfg(target_pointer_width = "64")]
+        {
+            assert_eq!(AesGcm::check_sizes((1 << 36) - 31, 0).unwrap_err(), CryptoError::InvalidInput(AES_GCM_PLAINTEXT_TOO_LARGE));
+            assert_eq!(AesGcm::check_sizes(0, (1 << 36) + 1).unwrap_err(), CryptoError::InvalidInput(AES_GCM_AAD_TOO_LARGE));
+        }
+
+        let limits = Limits { max_plaintext_len: 16, max_aad_len: 4, max_messages_per_key: 1 };
+        assert!(limits.check_message(16, 4).is_ok());
+        assert_eq!(limits.check_message(17, 0).unwrap_err(), CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE));
+        assert_eq!(limits.check_message(0, 5).unwrap_err(), CryptoError::InvalidInput(LIMIT_AAD_TOO_LARGE));
+        assert!(Limits::unlimited().check_message(usize::MAX, usize::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_one_shot_limits() {
+        let limits = Limits { max_plaintext_len: 8, max_aad_len: 4, ..Limits::default() };
+        let too_large = CryptoError::InvalidInput(LIMIT_PLAINTEXT_TOO_LARGE);
+
+        let key = AesGcm::generate_key().unwrap();
+        let ciphertext = AesGcm::encrypt_with_limits(b"12345678", &key, b"aad", &limits).unwrap();
+        assert_eq!(AesGcm::decrypt_with_limits(&ciphertext, &key, b"aad", &limits).unwrap(), b"12345678");
+        assert_eq!(AesGcm::encrypt_with_limits(b"123456789", &key, b"", &limits).unwrap_err(), too_large);
+        assert_eq!(AesGcm::encrypt_with_limits(b"", &key, b"12345", &limits).unwrap_err(), CryptoError::InvalidInput(LIMIT_AAD_TOO_LARGE));
+        // Oversized ciphertexts are rejected before decryption, and left untouched in place
+        let large = AesGcm::encrypt(&[7u8; 9], &key).unwrap();
+        assert_eq!(AesGcm::decrypt_with_limits(&large, &key, b"", &limits).unwrap_err(), too_large);
+        let mut buffer = large.clone();
+        assert_eq!(AesGcm::decrypt_in_place_with_limits(&mut buffer, &key, b"", &limits).unwrap_err(), too_large);
+        assert_eq!(buffer, large);
+
+        let context = AesGcmContext::with_limits(&key, limits).unwrap();
+        assert_eq!(context.decrypt_with_aad(&large, b"").unwrap_err(), too_large);
+        assert_eq!(context.encrypt_batch(&[b"short", b"far too long"]).unwrap_err(), too_large);
+        assert_eq!(context.decrypt_with_aad(&context.encrypt_with_aad(b"short", b"").unwrap(), b"").unwrap(), b"short");
+
+        let key = ChaCha20Poly1305Cipher::generate_key().unwrap();
+        let large = ChaCha20Poly1305Cipher::encrypt(&[7u8; 9], &key).unwrap();
+        assert_eq!(ChaCha20Poly1305Cipher::encrypt_with_limits(&[7u8; 9], &key, b"", &limits).unwrap_err(), too_large);
+        assert_eq!(ChaCha20Poly1305Cipher::decrypt_with_limits(&large, &key, b"", &limits).unwrap_err(), too_large);
+        let mut buffer = large.clone();
+        assert_eq!(ChaCha20Poly1305Cipher::decrypt_in_place_with_limits(&mut buffer, &key, b"", &limits).unwrap_err(), too_large);
+        ChaCha20Poly1305Cipher::decrypt_in_place(&mut buffer, &key).unwrap();
+        assert_eq!(buffer, [7u8; 9]);
+    }
+
+    #[test]
+    fn test_aes_gcm_context_message_limit() {
+        let key = AesGcm::generate_key().unwrap();
+        let limits = Limits { max_messages_per_key: 1, ..Limits::default() };
+        let exceeded = CryptoError::InvalidKey(LIMIT_KEY_USAGE_EXCEEDED);
+
+        let context = AesGcmContext::with_limits(&key, limits).unwrap();
+        let ciphertext = context.encrypt_with_aad(b"first", b"").unwrap();
+        assert_eq!(context.messages_encrypted(), 1);
+        assert_eq!(context.encrypt_with_aad(b"second", b"").unwrap_err(), exceeded);
+        assert_eq!(context.encrypt_batch(&[b"third"]).unwrap_err(), exceeded);
+        // Decryption is not counted
+        assert_eq!(context.decrypt_with_aad(&ciphertext, b"").unwrap(), b"first");
+
+        // A batch is counted as a whole: one that would cross the limit encrypts nothing
+        let context = AesGcmContext::with_limits(&key, limits).unwrap();
+        assert_eq!(context.encrypt_batch(&[b"one", b"two"]).unwrap_err(), exceeded);
+        assert_eq!(context.messages_encrypted(), 0);
+        assert_eq!(context.encrypt_batch(&[b"one"]).unwrap().len(), 1);
+        assert_eq!(context.encrypt_with_aad(b"two", b"").unwrap_err(), exceeded);
+    }
+
+    #[test]
+    fn test_aes_gcm_context_batch() {
+        let key = AesGcm::generate_key().unwrap();
+        let context = AesGcmContext::new(&key).unwrap();
+
+        let fields: Vec<Vec<u8>> = (0..100u32).map(|i| format!("field-{i}").into_bytes()).collect();
+        let plaintexts: Vec<&[u8]> = fields.iter().map(Vec::as_slice).collect();
+        let ciphertexts = context.encrypt_batch(&plaintexts).unwrap();
+        assert_eq!(ciphertexts.len(), 100);
+        assert_ne!(ciphertexts[0][..AES_NONCE_SIZE], ciphertexts[1][..AES_NONCE_SIZE]);
+
+        // Interoperable with the one-shot API in both directions
+        assert_eq!(AesGcm::decrypt(&ciphertexts[42], &key).unwrap(), b"field-42");
+        let single = AesGcm::encrypt(b"one-shot", &key).unwrap();
+        let refs: Vec<&[u8]> = ciphertexts.iter().map(Vec::as_slice).chain([single.as_slice()]).collect();
+        let decrypted = context.decrypt_batch(&refs).unwrap();
+        assert_eq!(decrypted[..100], fields[..]);
+        assert_eq!(decrypted[100], b"one-shot");
+
+        let rows: Vec<(&[u8], &[u8])> = vec![(b"alice@example.com", b"users:1:email"), (b"bob@example.com", b"users:2:email")];
+        let sealed = context.encrypt_batch_with_aad(&rows).unwrap();
+        let opened = context.decrypt_batch_with_aad(&[(&sealed[0], b"users:1:email"), (&sealed[1], b"users:2:email")]).unwrap();
+        assert_eq!(opened[1], b"bob@example.com");
+        // Swapping ciphertexts between rows fails the whole batch
+        assert!(context.decrypt_batch_with_aad(&[(&sealed[1], b"users:1:email"), (&sealed[0], b"users:2:email")]).is_err());
+
+        assert!(context.encrypt_batch(&[]).unwrap().is_empty());
+        assert!(context.decrypt_batch(&[&[0u8; 20]]).is_err());
+    }
+
+    #[test]
+    fn test_webcrypto_aes_gcm() {
+        // NIST GCM test case 14: AES-256, zero key and IV, 16 zero bytes
+        let key = Aes256Key::from_bytes(&[0u8; 32]).unwrap();
+        let iv = [0u8; 12];
+        let ciphertext = WebCryptoAesGcm::encrypt(&[0u8; 16], &key, &iv, b"").unwrap();
+        assert_eq!(hex::encode(&ciphertext), "cea7403d4d606b6e074ec5d3baf39d18d0d1c8a799996bf0265b98b5d48ab919");
+        assert_eq!(WebCryptoAesGcm::decrypt(&ciphertext, &key, &iv, b"").unwrap(), [0u8; 16]);
+
+        // Same bytes as the framed format, minus the IV
+        let key = AesGcm::generate_key().unwrap();
+        let iv = WebCryptoAesGcm::generate_iv().unwrap();
+        let ciphertext = WebCryptoAesGcm::encrypt(b"form data", &key, &iv, b"aad").unwrap();
+        assert_eq!(ciphertext.len(), 9 + WebCryptoAesGcm::TAG_SIZE);
+        let framed = WebCryptoAesGcm::to_framed(&iv, &ciphertext).unwrap();
+        assert_eq!(framed, AesGcm.seal_with_nonce(b"form data", key.as_bytes(), &iv, b"aad").unwrap());
+        assert_eq!(AesGcm::decrypt_with_aad(&framed, &key, b"aad").unwrap(), b"form data");
+        assert_eq!(WebCryptoAesGcm::from_framed(&framed).unwrap(), (&iv[..], &ciphertext[..]));
+
+        let framed = AesGcm::encrypt(b"", &key).unwrap();
+        let (iv, ciphertext) = WebCryptoAesGcm::from_framed(&framed).unwrap();
+        assert_eq!(WebCryptoAesGcm::decrypt(ciphertext, &key, iv, b"").unwrap(), b"");
+
+        let iv = WebCryptoAesGcm::generate_iv().unwrap();
+        let ciphertext = WebCryptoAesGcm::encrypt(b"form data", &key, &iv, b"aad").unwrap();
+        assert_eq!(WebCryptoAesGcm::decrypt(&ciphertext, &key, &iv, b"other").unwrap_err(), CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED));
+        assert_eq!(WebCryptoAesGcm::encrypt(b"x", &key, &[0u8; 16], b"").unwrap_err(), CryptoError::InvalidInput(INVALID_NONCE_LENGTH));
+        assert_eq!(WebCryptoAesGcm::decrypt(&[0u8; 15], &key, &iv, b"").unwrap_err(), CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+        assert_eq!(WebCryptoAesGcm::to_framed(&iv, &[0u8; 15]).unwrap_err(), CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+        assert_eq!(WebCryptoAesGcm::from_framed(&[0u8; 27]).unwrap_err(), CryptoError::InvalidInput(CIPHERTEXT_TOO_SHORT));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,481 @@
+//! Known-answer test vectors shared by core and every binding
+//!
+//! Each vector names an algorithm, its inputs and the expected output. Byte
+//! strings are lowercase hex; integer parameters (`length`, `iterations`, ...)
+//! are JSON numbers. The exported JSON looks like:
+//!
+//! ```json
+//! { "version": 1, "vectors": [
+//!   { "algorithm": "sha256", "description": "FIPS 180-2 \"abc\"",
+//!     "inputs": { "message": "616263" }, "expected": "ba7816bf..." } ] }
+//! ```
+//!
+//! Inputs and expected output per algorithm:
+//!
+//! | algorithm | inputs | expected |
+//! |---|---|---|
+//! | `sha256`, `sha512`, `sha3-256`, `sha3-512`, `blake3` | `message` | digest |
+//! | `blake3-keyed`, `hmac-sha256`, `hmac-sha512` | `key`, `message` | MAC |
+//! | `hkdf-sha256` | `ikm`, `salt`, `info`, `length` | output key material |
+//! | `pbkdf2-sha256` | `password`, `salt`, `iterations`, `length` | derived key |
+//! | `scrypt` | `password`, `salt`, `log_n`, `r`, `p`, `length` | derived key |
+//! | `argon2id` | `password`, `salt`, `memory_kib`, `iterations`, `parallelism`, `length`, optional `secret` and `ad` | derived key |
+//! | `aes-256-gcm`, `chacha20-poly1305` | `key`, `nonce`, `plaintext`, `aad` | `nonce \|\| ciphertext \|\| tag` |
+//! | `ed25519` | `private_key`, `public_key`, `message` | 64-byte signature |
+//! | `ecdsa-p256-sha256` | `private_key`, `public_key`, `message` | RFC 6979 `r \|\| s` signature |
+//! | `x25519` | `private_key`, `peer_public_key` | shared secret |
+//! | `rsa-pkcs1v15-sha256-verify`, `rsa-pss-sha256-verify` | `public_key` (SPKI DER), `message`, `signature` | `01` if valid, `00` if not |
+//!
+//! AEAD vectors use a fixed nonce, so bindings without nonce injection should
+//! at least check that decrypting `expected` yields `plaintext`. Every
+//! expected value is a literal from the cited source, never libsilver output.
+
+use crate::error::{CryptoError, CryptoResult, TEST_VECTOR_INVALID_FORMAT, TEST_VECTOR_INVALID_INPUT, TEST_VECTOR_UNKNOWN_ALGORITHM, AES_GCM_ENCRYPTION_FAILED, CHACHA20_ENCRYPTION_FAILED, ARGON2_DERIVATION_FAILED, ARGON2_INVALID_PARAMS};
+#[cfg(feature = "rsa")]
+use crate::core::asymmetric::{RsaCrypto, RsaSignatureHash, RsaSignaturePadding};
+use crate::core::asymmetric::{EcdsaCrypto, EcdsaKeyPair, Ed25519Crypto, Ed25519KeyPair};
+use crate::core::ecdh::{Ecdh, EcdhCurve, EcdhKeyPair};
+use crate::core::hash::{Blake3Hash, Sha256Hash, Sha3_256Hash, Sha3_512Hash, Sha512Hash, StreamingHasher};
+use crate::core::kdf::{Argon2Kdf, Argon2Variant, HkdfKdf, Pbkdf2Kdf};
+#[cfg(feature = "scrypt")]
+use crate::core::kdf::ScryptKdf;
+use crate::core::keys::{Aes256Key, ChaChaKey};
+use crate::core::symmetric::{AesGcm, ChaCha20Poly1305Cipher};
+use aes_gcm::aead::{Aead, Payload};
+use aes_gcm::{Aes256Gcm, KeyInit};
+use chacha20poly1305::ChaCha20Poly1305;
+#[cfg(feature = "rsa")]
+use rsa::{pkcs8::DecodePublicKey, RsaPublicKey};
+use serde_json::{json, Map, Value};
+
+/// Version of the JSON layout written by [`TestVectors::to_json`]
+pub const TEST_VECTOR_FORMAT_VERSION: u64 = 1;
+
+// SPKI DER of the OpenSSL-generated 2048-bit key behind the RSA verification vectors
+#[cfg(feature = "rsa")]
+const RSA_VECTOR_PUBLIC_KEY: &str = "30820122300d06092a864886f70d01010105000382010f003082010a0282010100c849fb131574d7fab0c7aab07412dc06c851f194a35e8a117bfab20a3d646f6ae0cabc67cdec17d66567814b388e49d1ddc0ffc7a20fb3faa8aae96cdd3855f237b4465db448fc4317d66e0777e28f13e55df6619226bac2685a272456ca618066af451d955bb9c1614b065a2567d6b73eff9f24c6ec07ffbb530f8260f7a04877ef09109c60e5ebec7e1b037c59d66916088ad523ecde2d8cd96e301433b55373edec76a33ed9d01057cfff244358e27af7e8025233d8397ba742e2c2eacf70606d342f054fa0573cbf8bc345bf8ca3c59a0466acb4e00aee590ba3c797f79dfa2514a33997bfcfa1bd831381650c7f1753be2f74898848daf97fe864fbdc9b0203010001";
+
+/// A single known-answer test
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestVector {
+    /// Algorithm identifier, e.g. `"aes-256-gcm"`
+    pub algorithm: String,
+    /// Where the vector comes from
+    pub description: String,
+    /// Named inputs: hex strings for bytes, numbers for parameters
+    pub inputs: Map<String, Value>,
+    /// Expected output as hex
+    pub expected: String,
+}
+
+/// A vector that did not reproduce
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestVectorFailure {
+    /// Position in the checked list
+    pub index: usize,
+    /// Algorithm of the failing vector
+    pub algorithm: String,
+    /// Description of the failing vector
+    pub description: String,
+    /// Why it failed
+    pub reason: String,
+}
+
+/// Export and check the built-in known-answer suite
+pub struct TestVectors;
+
+impl TestVectors {
+    /// Build the suite from published vectors (FIPS 180/202, RFC 4231/5869/6979/7748/7914/8032/8439/9106,
+    /// the GCM specification and the BLAKE3 and Argon2 reference implementations)
+    pub fn all() -> CryptoResult<Vec<TestVector>> {
+        let mut vectors = Vec::new();
+        let mut add = |algorithm: &str, description: &str, inputs: Value, expected: &str| -> CryptoResult<()> {
+            let inputs = match inputs {
+                Value::Object(inputs) => inputs,
+                _ => return Err(CryptoError::InternalError(TEST_VECTOR_INVALID_FORMAT)),
+            };
+            vectors.push(TestVector { algorithm: algorithm.to_string(), description: description.to_string(), inputs, expected: expected.to_string() });
+            Ok(())
+        };
+
+        let empty = json!({ "message": "" });
+        let abc = json!({ "message": hex::encode(b"abc") });
+        let two_block = json!({ "message": hex::encode(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq") });
+        add("sha256", "FIPS 180-2 empty message", empty.clone(), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855")?;
+        add("sha256", "FIPS 180-2 \"abc\"", abc.clone(), "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad")?;
+        add("sha256", "FIPS 180-2 two-block message", two_block.clone(), "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1")?;
+        add("sha512", "FIPS 180-2 empty message", empty.clone(), "cf83e1357eefb8bdf1542850d66d8007d620e4050b5715dc83f4a921d36ce9ce47d0d13c5d85f2b0ff8318d2877eec2f63b931bd47417a81a538327af927da3e")?;
+        add("sha512", "FIPS 180-2 \"abc\"", abc.clone(), "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f")?;
+        add("sha512", "FIPS 180-2 448-bit message", two_block.clone(), "204a8fc6dda82f0a0ced7beb8e08a41657c16ef468b228a8279be331a703c33596fd15c13b1b07f9aa1d3bea57789ca031ad85c7a71dd70354ec631238ca3445")?;
+        add("sha3-256", "FIPS 202 empty message", empty.clone(), "a7ffc6f8bf1ed76651c14756a061d662f580ff4de43b49fa82d80a4b80f8434a")?;
+        add("sha3-256", "FIPS 202 \"abc\"", abc.clone(), "3a985da74fe225b2045c172d6bd390bd855f086e3e9d525b46bfe24511431532")?;
+        add("sha3-256", "FIPS 202 448-bit message", two_block.clone(), "41c0dba2a9d6240849100376a8235e2c82e1b9998a999e21db32dd97496d3376")?;
+        add("sha3-512", "FIPS 202 empty message", empty.clone(), "a69f73cca23a9ac5c8b567dc185a756e97c982164fe25859e0d1dcc1475c80a615b2123af1f5f94c11e3e9402c3ac558f500199d95b6d3e301758586281dcd26")?;
+        add("sha3-512", "FIPS 202 \"abc\"", abc.clone(), "b751850b1a57168a5693cd924b6b096e08f621827444f70d884f5d0240d2712e10e116e9192af3c91a7ec57647e3934057340b4cf408d5a56592f8274eec53f0")?;
+        add("sha3-512", "FIPS 202 448-bit message", two_block, "04a371e84ecfb5b8b77cb48610fca8182dd457ce6f326a0fd3d7ec2f1e91636dee691fbe0c985302ba1b0d8dc78c086346b533b49c030d99a27daf1139d6e75e")?;
+        add("blake3", "BLAKE3 test_vectors.json, length 0", empty, "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262")?;
+        add("blake3", "BLAKE3 test_vectors.json, length 1", json!({ "message": "00" }), "2d3adedff11b61f14c886e35afa036736dcd87a74d27b5c1510225d0f592e213")?;
+        add("blake3-keyed", "BLAKE3 test_vectors.json, length 0", json!({ "key": hex::encode(b"whats the Elvish word for friend"), "message": "" }), "92b2b75604ed3c761f9d6f62392c8a9227ad0ea3f09573e783f1498a4ed60d26")?;
+
+        let jefe = json!({ "key": hex::encode(b"Jefe"), "message": hex::encode(b"what do ya want for nothing?") });
+        add("hmac-sha256", "RFC 4231 test case 2", jefe.clone(), "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843")?;
+        add("hmac-sha512", "RFC 4231 test case 2", jefe, "164b7a7bfcf819e2e395fbe73b56e0a387bd64222e831fd610270cd7ea2505549758bf75c05a994a6d034f65f8f0e6fdcaeab1a34d4a6b4b636e070a38bce737")?;
+        add("hmac-sha256", "RFC 4231 test case 6 (key longer than block)", json!({ "key": hex::encode([0xaa; 131]), "message": hex::encode(b"Test Using Larger Than Block-Size Key - Hash Key First") }), "60e431591ee0b67f0d8a26aacbf5b77f8e0bc6213728c5140546040f0ee37f54")?;
+
+        add("hkdf-sha256", "RFC 5869 test case 1", json!({
+            "ikm": hex::encode([0x0b; 22]),
+            "salt": "000102030405060708090a0b0c",
+            "info": "f0f1f2f3f4f5f6f7f8f9",
+            "length": 42,
+        }), "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865")?;
+        add("hkdf-sha256", "RFC 5869 test case 3 (empty salt and info)", json!({ "ikm": hex::encode([0x0b; 22]), "salt": "", "info": "", "length": 42 }), "8da4e775a563c18f715f802a063c5a31b8a11f5c5ee1879ec3454e5f3c738d2d9d201395faa4b61a96c8")?;
+        add("pbkdf2-sha256", "RFC 7914 section 11", json!({ "password": hex::encode(b"passwd"), "salt": hex::encode(b"salt"), "iterations": 1, "length": 64 }), "55ac046e56e3089fec1691c22544b605f94185216dde0465e68b9d57c20dacbc49ca9cccf179b645991664b39d77ef317c71b845b1e30bd509112041d3a19783")?;
+        add("pbkdf2-sha256", "RFC 7914 section 11 style, 4096 iterations", json!({ "password": hex::encode(b"password"), "salt": hex::encode(b"salt"), "iterations": 4096, "length": 32 }), "c5e478d59288c841aa530db6845c4c8d962893a001ce4e11a4963873aa98134a")?;
+        #[cfg(feature = "scrypt")]
+        {
+            add("scrypt", "RFC 7914 section 12, empty password", json!({ "password": "", "salt": "", "log_n": 4, "r": 1, "p": 1, "length": 64 }), "77d6576238657b203b19ca42c18a0497f16b4844e3074ae8dfdffa3fede21442fcd0069ded0948f8326a753a0fc81f17e8d3e0fb2e0d3628cf35e20c38d18906")?;
+            add("scrypt", "RFC 7914 section 12, N = 1024", json!({ "password": hex::encode(b"password"), "salt": hex::encode(b"NaCl"), "log_n": 10, "r": 8, "p": 16, "length": 64 }), "fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b3731622eaf30d92e22a3886ff109279d9830dac727afb94a83ee6d8360cbdfa2cc0640")?;
+        }
+        add("argon2id", "RFC 9106 section 5.3", json!({
+            "password": hex::encode([0x01; 32]),
+            "salt": hex::encode([0x02; 16]),
+            "secret": hex::encode([0x03; 8]),
+            "ad": hex::encode([0x04; 12]),
+            "memory_kib": 32,
+            "iterations": 3,
+            "parallelism": 4,
+            "length": 32,
+        }), "0d640df58d78766c08c037a34a8b53c9d01ef0452d75b65eb52520e96b01e659")?;
+        add("argon2id", "Argon2 reference implementation, m=2^8 t=2 p=1", json!({ "password": hex::encode(b"password"), "salt": hex::encode(b"somesalt"), "memory_kib": 256, "iterations": 2, "parallelism": 1, "length": 32 }), "9dfeb910e80bad0311fee20f9c0e2b12c17987b4cac90c2ef54d5b3021c68bfe")?;
+        // Not published; checked against OpenSSL 3's Argon2id so bindings with fixed defaults have a vector
+        add("argon2id", "libsilver default cost", json!({
+            "password": hex::encode(b"password"),
+            "salt": hex::encode(b"somesaltsomesalt"),
+            "memory_kib": 19456,
+            "iterations": 2,
+            "parallelism": 1,
+            "length": 32,
+        }), "2b5dc4054886ec957ef59c73b661c54dd6fb274590b278f657c6d96aac8fa6d1")?;
+
+        // GCM specification (McGrew & Viega) test cases 13-16, AES-256
+        let zero_key = hex::encode([0u8; 32]);
+        let gcm_key = "feffe9928665731c6d6a8f9467308308feffe9928665731c6d6a8f9467308308";
+        let gcm_plaintext = "d9313225f88406e5a55909c5aff5269a86a7a9531534f7da2e4c303d8a318a721c3c0c95956809532fcf0e2449a6b525b16aedf5aa0de657ba637b391aafd255";
+        add("aes-256-gcm", "GCM test case 13", json!({ "key": zero_key, "nonce": hex::encode([0u8; 12]), "plaintext": "", "aad": "" }),
+            "000000000000000000000000530f8afbc74536b9a963b4f1c4cb738b")?;
+        add("aes-256-gcm", "GCM test case 14", json!({ "key": zero_key, "nonce": hex::encode([0u8; 12]), "plaintext": hex::encode([0u8; 16]), "aad": "" }),
+            "000000000000000000000000cea7403d4d606b6e074ec5d3baf39d18d0d1c8a799996bf0265b98b5d48ab919")?;
+        add("aes-256-gcm", "GCM test case 15", json!({ "key": gcm_key, "nonce": "cafebabefacedbaddecaf888", "plaintext": gcm_plaintext, "aad": "" }),
+            "cafebabefacedbaddecaf888522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f662898015adb094dac5d93471bdec1a502270e3cc6c")?;
+        add("aes-256-gcm", "GCM test case 16", json!({ "key": gcm_key, "nonce": "cafebabefacedbaddecaf888", "plaintext": &gcm_plaintext[..120], "aad": "feedfacedeadbeeffeedfacedeadbeefabaddad2" }),
+            "cafebabefacedbaddecaf888522dc1f099567d07f47f37a32a84427d643a8cdcbfe5c0c97598a2bd2555d1aa8cb08e48590dbb3da7b08b1056828838c5f61e6393ba7a0abcc9f66276fc6ece0f4e1768cddf8853bb2d551b")?;
+        add("chacha20-poly1305", "RFC 8439 section 2.8.2", json!({
+            "key": "808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9f",
+            "nonce": "070000004041424344454647",
+            "plaintext": hex::encode(b"Ladies and Gentlemen of the class of '99: If I could offer you only one tip for the future, sunscreen would be it."),
+            "aad": "50515253c0c1c2c3c4c5c6c7",
+        }), "070000004041424344454647d31a8d34648e60db7b86afbc53ef7ec2a4aded51296e08fea9e2b5a736ee62d63dbea45e8ca9671282fafb69da92728b1a71de0a9e060b2905d6a5b67ecd3b3692ddbd7f2d778b8c9803aee328091b58fab324e4fad675945585808b4831d7bc3ff4def08e4b7a9de576d26586cec64b61161ae10b594f09e26a7e902ecbd0600691")?;
+
+        add("ed25519", "RFC 8032 test 1", json!({
+            "private_key": "9d61b19deffd5a60ba844af492ec2cc44449c5697b326919703bac031cae7f60",
+            "public_key": "d75a980182b10ab7d54bfed3c964073a0ee172f3daa62325af021a68f707511a",
+            "message": "",
+        }), "e5564300c360ac729086e2cc806e828a84877f1eb8e5d974d873e065224901555fb8821590a33bacc61e39701cf9b46bd25bf5f0595bbe24655141438e7a100b")?;
+        add("ed25519", "RFC 8032 test 2", json!({
+            "private_key": "4ccd089b28ff96da9db6c346ec114e0f5b8a319f35aba624da8cf6ed4fb8a6fb",
+            "public_key": "3d4017c3e843895a92b70aa74d1b7ebc9c982ccf2ec4968cc0cd55f12af4660c",
+            "message": "72",
+        }), "92a009a9f0d4cab8720e820b5f642540a2b27b5416503f8fb3762223ebdb69da085ac1e43e15996e458f3613d0f11d8c387b2eaeb4302aeeb00d291612bb0c00")?;
+        add("ed25519", "RFC 8032 test 3", json!({
+            "private_key": "c5aa8df43f9f837bedb7442f31dcb7b166d38535076f094b85ce3a2e0b4458f7",
+            "public_key": "fc51cd8e6218a1a38da47ed00230f0580816ed13ba3303ac5deb911548908025",
+            "message": "af82",
+        }), "6291d657deec24024827e69c3abe01a30ce548a284743a445e3680d7db5ac3ac18ff9b538d16f290ae67f760984dc6594a7c15e9716ed28dc027beceea1ec40a")?;
+        add("ecdsa-p256-sha256", "RFC 6979 A.2.5 \"sample\"", json!({
+            "private_key": "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721",
+            "public_key": "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb67903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299",
+            "message": hex::encode(b"sample"),
+        }), "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8")?;
+        add("x25519", "RFC 7748 section 6.1", json!({
+            "private_key": "77076d0a7318a57d3c16c17251b26645df4c2f87ebc0992ab177fba51db92c2a",
+            "peer_public_key": "de9edb7d7b7dc1b4d35b61c2ece435373f8343c85b78674dadfc7e146f882b4f",
+        }), "4a5d9d5ba4ce2de1728e3bf480350f25e07e21c947d19e3376f09b3c1e161742")?;
+
+        // No RSA KATs are published for these paddings, so these were produced with OpenSSL 3
+        // (`openssl dgst -sha256 -sign`, PSS with a 32-byte salt) and check verification only
+        #[cfg(feature = "rsa")]
+        {
+            let message = hex::encode(b"libsilver test vector");
+            let pkcs1v15 = "8bd92503d4ba5012f3d5c33cdaac15d980822b73e9f169943e316817bd9850ec7f576e10088ab8b0218c2ddc5ace844caf5e8e561f15c766b85289ede60f54009dcbc51c357c917c47ecbcc696c6750ad92119e9a899730314ecf3ebb9caacd813fcf2a1e1b4ecefc9c5f69ceaef899dac51a11de5f6414553b1549f85be1b75a10c58fea0eb2de5d7a8dff9823e946bbb26d740da3f27515d5cf5db06e6f1b30ffdd8b0127826f5548bb626de289ac292958b07ed7349456d5e3dbf3a75f448ec064c7d9d4bd7f9ad46ba19a953a722d38bb00c3fcd5d5624e03a82285ec62808cd4249c79c0f4429bbb0398ee12dfb650c8765818d3d0d45b77c9732b07434";
+            let pss = "7c52af0133130bbfe5c69cc0415c8654a6ceccca55f8276256c2f3194bd6f031802e2bc6f1eb71a0616b02bcb46ca6a1c83432fc91a7a5b5d4543ad32836c109c002f90e93328e810f0a1d2785420f96811ea0466e49cb22f4dbdad17a6f0baa3404c22c259aaf096cf928bee60d42247b97e857f68902b2c81a542d96afacacd5baca1ed7456cc0f35fda854420dc3fc9a0f59a80b1ab130905c13e4180e6671010973c92baca0f14eb3dde6fd5c41ecd1526b4440dfe0128ba9171ede7fe8f50401077021efa65d2ffb8c9c51d529e3c42bd20d782c486cf5aa45a3d755bc37e91156451079b28f87e7ce419a2fb10b45fb50f2420e2e24c30b0916a81f741";
+            let verify = |signature: &str, message: &str| json!({ "public_key": RSA_VECTOR_PUBLIC_KEY, "message": message, "signature": signature });
+            add("rsa-pkcs1v15-sha256-verify", "OpenSSL 3 signature, 2048-bit key", verify(pkcs1v15, &message), "01")?;
+            add("rsa-pkcs1v15-sha256-verify", "OpenSSL 3 signature, other message", verify(pkcs1v15, &hex::encode(b"libsilver test vectors")), "00")?;
+            add("rsa-pss-sha256-verify", "OpenSSL 3 signature, 2048-bit key", verify(pss, &message), "01")?;
+            add("rsa-pss-sha256-verify", "OpenSSL 3 PKCS#1 v1.5 signature under PSS", verify(pkcs1v15, &message), "00")?;
+        }
+
+        Ok(vectors)
+    }
+
+    /// Export the built-in suite as JSON
+    #[inline]
+    pub fn export_json() -> CryptoResult<String> {
+        Self::to_json(&Self::all()?)
+    }
+
+    /// Serialize vectors as pretty-printed JSON
+    pub fn to_json(vectors: &[TestVector]) -> CryptoResult<String> {
+        let vectors: Vec<Value> = vectors.iter().map(|vector| json!({
+            "algorithm": vector.algorithm,
+            "description": vector.description,
+            "inputs": vector.inputs,
+            "expected": vector.expected,
+        })).collect();
+        serde_json::to_string_pretty(&json!({ "version": TEST_VECTOR_FORMAT_VERSION, "vectors": vectors }))
+            .map_err(|_| CryptoError::EncodingFailed(TEST_VECTOR_INVALID_FORMAT))
+    }
+
+    /// Parse vectors written by [`TestVectors::to_json`]
+    pub fn from_json(json: &str) -> CryptoResult<Vec<TestVector>> {
+        let document: Value = serde_json::from_str(json)
+            .map_err(|_| CryptoError::InvalidInput(TEST_VECTOR_INVALID_FORMAT))?;
+        if document.get("version").and_then(Value::as_u64) != Some(TEST_VECTOR_FORMAT_VERSION) {
+            return Err(CryptoError::InvalidInput(TEST_VECTOR_INVALID_FORMAT));
+        }
+
+        let vectors = document.get("vectors").and_then(Value::as_array)
+            .ok_or(CryptoError::InvalidInput(TEST_VECTOR_INVALID_FORMAT))?;
+        vectors.iter().map(|vector| {
+            let field = |name: &str| vector.get(name).and_then(Value::as_str).map(str::to_string)
+                .ok_or(CryptoError::InvalidInput(TEST_VECTOR_INVALID_FORMAT));
+            Ok(TestVector {
+                algorithm: field("algorithm")?,
+                description: field("description")?,
+                inputs: vector.get("inputs").and_then(Value::as_object).cloned()
+                    .ok_or(CryptoError::InvalidInput(TEST_VECTOR_INVALID_FORMAT))?,
+                expected: field("expected")?,
+            })
+        }).collect()
+    }
+
+    /// Check one vector against core, including the inverse operation (decrypt, verify) where there is one
+    pub fn check(vector: &TestVector) -> CryptoResult<bool> {
+        let expected = hex_decode(&vector.expected)?;
+        if compute(&vector.algorithm, &vector.inputs)? != expected {
+            return Ok(false);
+        }
+
+        let inputs = &vector.inputs;
+        match vector.algorithm.as_str() {
+            "aes-256-gcm" => Ok(AesGcm::decrypt_with_aad(&expected, &Aes256Key::from_bytes(&bytes(inputs, "key")?)?, &bytes(inputs, "aad")?)? == bytes(inputs, "plaintext")?),
+            "chacha20-poly1305" => Ok(ChaCha20Poly1305Cipher::decrypt_with_aad(&expected, &ChaChaKey::from_bytes(&bytes(inputs, "key")?)?, &bytes(inputs, "aad")?)? == bytes(inputs, "plaintext")?),
+            "ed25519" => {
+                let verifying_key = Ed25519KeyPair::verifying_key_from_bytes(&bytes(inputs, "public_key")?)?;
+                Ed25519Crypto::verify(&bytes(inputs, "message")?, &expected, &verifying_key)
+            }
+            "ecdsa-p256-sha256" => {
+                let verifying_key = EcdsaKeyPair::verifying_key_from_bytes(&bytes(inputs, "public_key")?)?;
+                EcdsaCrypto::verify(&bytes(inputs, "message")?, &expected, &verifying_key)
+            }
+            _ => Ok(true),
+        }
+    }
+
+    /// Check every vector, returning the ones that failed
+    pub fn run(vectors: &[TestVector]) -> Vec<TestVectorFailure> {
+        vectors.iter().enumerate().filter_map(|(index, vector)| {
+            let reason = match Self::check(vector) {
+                Ok(true) => return None,
+                Ok(false) => "output mismatch".to_string(),
+                Err(err) => err.to_string(),
+            };
+            Some(TestVectorFailure { index, algorithm: vector.algorithm.clone(), description: vector.description.clone(), reason })
+        }).collect()
+    }
+
+    /// Parse and check a JSON export, returning the failures
+    #[inline]
+    pub fn run_json(json: &str) -> CryptoResult<Vec<TestVectorFailure>> {
+        Ok(Self::run(&Self::from_json(json)?))
+    }
+}
+
+// Compute the expected output of `algorithm` with core
+fn compute(algorithm: &str, inputs: &Map<String, Value>) -> CryptoResult<Vec<u8>> {
+    match algorithm {
+        "sha256" => Sha256Hash::hash(&bytes(inputs, "message")?),
+        "sha512" => Sha512Hash::hash(&bytes(inputs, "message")?),
+        "sha3-256" => Sha3_256Hash::hash(&bytes(inputs, "message")?),
+        "sha3-512" => Sha3_512Hash::hash(&bytes(inputs, "message")?),
+        "blake3" => Blake3Hash::hash(&bytes(inputs, "message")?),
+        "blake3-keyed" => Blake3Hash::keyed_hash(&bytes(inputs, "key")?, &bytes(inputs, "message")?),
+        // RFC 4231 keys are shorter than an `HmacKey` allows
+        "hmac-sha256" => hmac(StreamingHasher::hmac_sha256(&bytes(inputs, "key")?)?, &bytes(inputs, "message")?),
+        "hmac-sha512" => hmac(StreamingHasher::hmac_sha512(&bytes(inputs, "key")?)?, &bytes(inputs, "message")?),
+        "hkdf-sha256" => HkdfKdf::derive_sha256(&bytes(inputs, "ikm")?, Some(&bytes(inputs, "salt")?), &bytes(inputs, "info")?, number(inputs, "length")?),
+        "pbkdf2-sha256" => Pbkdf2Kdf::derive_sha256(&bytes(inputs, "password")?, &bytes(inputs, "salt")?, number(inputs, "iterations")?, number(inputs, "length")?),
+        #[cfg(feature = "scrypt")]
+        "scrypt" => ScryptKdf::derive_key(
+            &bytes(inputs, "password")?,
+            &bytes(inputs, "salt")?,
+            number(inputs, "log_n")?,
+            number(inputs, "r")?,
+            number(inputs, "p")?,
+            number(inputs, "length")?,
+        ),
+        "argon2id" if inputs.contains_key("secret") || inputs.contains_key("ad") => argon2id_keyed(inputs),
+        "argon2id" => Argon2Kdf::derive_key_with_params(
+            &bytes(inputs, "password")?,
+            &bytes(inputs, "salt")?,
+            Argon2Variant::Argon2id,
+            number(inputs, "memory_kib")?,
+            number(inputs, "iterations")?,
+            number(inputs, "parallelism")?,
+            number(inputs, "length")?,
+        ),
+        "aes-256-gcm" | "chacha20-poly1305" => {
+            let (key, nonce) = (bytes(inputs, "key")?, bytes(inputs, "nonce")?);
+            let payload = Payload { msg: &bytes(inputs, "plaintext")?, aad: &bytes(inputs, "aad")? };
+            if nonce.len() != 12 {
+                return Err(CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT));
+            }
+            let sealed = if algorithm == "aes-256-gcm" {
+                Aes256Gcm::new_from_slice(&key)
+                    .map_err(|_| CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT))?
+                    .encrypt(nonce.as_slice().into(), payload)
+                    .map_err(|_| CryptoError::EncryptionFailed(AES_GCM_ENCRYPTION_FAILED))?
+            } else {
+                ChaCha20Poly1305::new_from_slice(&key)
+                    .map_err(|_| CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT))?
+                    .encrypt(nonce.as_slice().into(), payload)
+                    .map_err(|_| CryptoError::EncryptionFailed(CHACHA20_ENCRYPTION_FAILED))?
+            };
+            Ok([nonce, sealed].concat())
+        }
+        "ed25519" => {
+            let keypair = Ed25519KeyPair::from_private_key_bytes(&bytes(inputs, "private_key")?)?;
+            if keypair.public_key_bytes() != bytes(inputs, "public_key")? {
+                return Err(CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT));
+            }
+            Ed25519Crypto::sign(&bytes(inputs, "message")?, keypair.signing_key())
+        }
+        "ecdsa-p256-sha256" => {
+            let keypair = EcdsaKeyPair::from_private_key_bytes(&bytes(inputs, "private_key")?)?;
+            if keypair.public_key_bytes() != bytes(inputs, "public_key")? {
+                return Err(CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT));
+            }
+            EcdsaCrypto::sign(&bytes(inputs, "message")?, keypair.signing_key())
+        }
+        "x25519" => {
+            let keypair = EcdhKeyPair::from_private_key_bytes(EcdhCurve::X25519, &bytes(inputs, "private_key")?)?;
+            Ok(Ecdh::derive_shared_secret(&keypair, &bytes(inputs, "peer_public_key")?)?.to_vec())
+        }
+        #[cfg(feature = "rsa")]
+        "rsa-pkcs1v15-sha256-verify" | "rsa-pss-sha256-verify" => {
+            let public_key = RsaPublicKey::from_public_key_der(&bytes(inputs, "public_key")?)
+                .map_err(|_| CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT))?;
+            let padding = if algorithm == "rsa-pss-sha256-verify" { RsaSignaturePadding::Pss } else { RsaSignaturePadding::Pkcs1v15 };
+            let valid = RsaCrypto::verify(&bytes(inputs, "message")?, &bytes(inputs, "signature")?, &public_key, padding, RsaSignatureHash::Sha256)?;
+            Ok(vec![valid as u8])
+        }
+        _ => Err(CryptoError::InvalidInput(TEST_VECTOR_UNKNOWN_ALGORITHM)),
+    }
+}
+
+// Argon2id with a secret key and associated data (RFC 9106), which `Argon2Kdf` does not expose
+fn argon2id_keyed(inputs: &Map<String, Value>) -> CryptoResult<Vec<u8>> {
+    let (password, salt) = (bytes(inputs, "password")?, bytes(inputs, "salt")?);
+    let (secret, ad) = (bytes(inputs, "secret")?, bytes(inputs, "ad")?);
+    let length: usize = number(inputs, "length")?;
+    let params = argon2::ParamsBuilder::new()
+        .m_cost(number(inputs, "memory_kib")?)
+        .t_cost(number(inputs, "iterations")?)
+        .p_cost(number(inputs, "parallelism")?)
+        .data(argon2::AssociatedData::new(&ad).map_err(|_| CryptoError::InvalidInput(ARGON2_INVALID_PARAMS))?)
+        .output_len(length)
+        .build()
+        .map_err(|_| CryptoError::InvalidInput(ARGON2_INVALID_PARAMS))?;
+
+    let mut output = vec![0u8; length];
+    argon2::Argon2::new_with_secret(&secret, argon2::Algorithm::Argon2id, argon2::Version::V0x13, params)
+        .and_then(|argon2| argon2.hash_password_into(&password, &salt, &mut output))
+        .map_err(|_| CryptoError::KeyDerivationFailed(ARGON2_DERIVATION_FAILED))?;
+    Ok(output)
+}
+
+#[inline]
+fn hex_decode(value: &str) -> CryptoResult<Vec<u8>> {
+    hex::decode(value).map_err(|_| CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT))
+}
+
+fn bytes(inputs: &Map<String, Value>, name: &str) -> CryptoResult<Vec<u8>> {
+    inputs.get(name).and_then(Value::as_str)
+        .ok_or(CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT))
+        .and_then(hex_decode)
+}
+
+fn hmac(mut hasher: StreamingHasher, message: &[u8]) -> CryptoResult<Vec<u8>> {
+    hasher.update(message);
+    Ok(hasher.finalize())
+}
+
+fn number<T: TryFrom<u64>>(inputs: &Map<String, Value>, name: &str) -> CryptoResult<T> {
+    inputs.get(name).and_then(Value::as_u64)
+        .and_then(|value| T::try_from(value).ok())
+        .ok_or(CryptoError::InvalidInput(TEST_VECTOR_INVALID_INPUT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suite_matches_published_vectors() {
+        let vectors = TestVectors::all().unwrap();
+        for vector in &vectors {
+            let output = compute(&vector.algorithm, &vector.inputs).unwrap();
+            assert_eq!(hex::encode(output), vector.expected, "{} ({})", vector.algorithm, vector.description);
+        }
+        assert!(TestVectors::run(&vectors).is_empty());
+
+        let mut algorithms = vec![
+            "sha256", "sha512", "sha3-256", "sha3-512", "blake3", "blake3-keyed", "hmac-sha256", "hmac-sha512",
+            "hkdf-sha256", "pbkdf2-sha256", "argon2id", "aes-256-gcm", "chacha20-poly1305",
+            "ed25519", "ecdsa-p256-sha256", "x25519",
+        ];
+        if cfg!(feature = "scrypt") {
+            algorithms.push("scrypt");
+        }
+        if cfg!(feature = "rsa") {
+            algorithms.extend(["rsa-pkcs1v15-sha256-verify", "rsa-pss-sha256-verify"]);
+        }
+        for algorithm in algorithms {
+            assert!(vectors.iter().any(|v| v.algorithm == algorithm), "no vector for {algorithm}");
+        }
+    }
+
+    #[test]
+    fn test_json_round_trip_and_runner() {
+        let json = TestVectors::export_json().unwrap();
+        let mut vectors = TestVectors::from_json(&json).unwrap();
+        assert_eq!(vectors, TestVectors::all().unwrap());
+        assert!(TestVectors::run_json(&json).unwrap().is_empty());
+
+        // Flip one bit of one expected output
+        let last = vectors.len() - 1;
+        let mut expected = hex::decode(&vectors[last].expected).unwrap();
+        expected[0] ^= 1;
+        vectors[last].expected = hex::encode(expected);
+        vectors[0].algorithm = "md5".to_string();
+
+        let failures = TestVectors::run(&vectors);
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].index, 0);
+        assert_eq!(failures[1].index, last);
+        assert_eq!(failures[1].reason, "output mismatch");
+
+        assert!(TestVectors::from_json("{\"version\": 2, \"vectors\": []}").is_err());
+        assert!(TestVectors::from_json("not json").is_err());
+    }
+}
@@ -0,0 +1,909 @@
+//! FROST threshold Ed25519 signatures (RFC 9591, `FROST-ED25519-SHA512-v1`)
+//!
+//! Any `min_signers` of `max_signers` participants can jointly produce a standard
+//! Ed25519 signature without the group secret key ever existing in one place.
+//! Keys are created either by a trusted dealer or by a distributed key generation
+//! (DKG) ceremony; signing takes two rounds (commit, then sign) plus aggregation.
+
+use crate::error::{CryptoError, CryptoResult, FROST_INVALID_PARAMETERS, FROST_INVALID_IDENTIFIER, FROST_INVALID_POINT, FROST_INVALID_SCALAR, FROST_NOT_ENOUGH_SIGNERS, FROST_COMMITMENT_MISMATCH, FROST_MISSING_SIGNATURE_SHARE, FROST_INVALID_SIGNATURE_SHARE, FROST_INVALID_PROOF_OF_KNOWLEDGE, FROST_INVALID_SECRET_SHARE, FROST_INVALID_PACKAGE, FROST_UNKNOWN_PARTICIPANT, INVALID_ED25519_PUBLIC_KEY};
+use crate::core::random::SecureRandom;
+use curve25519_dalek::edwards::{CompressedEdwardsY, EdwardsPoint};
+use curve25519_dalek::scalar::Scalar;
+use curve25519_dalek::traits::Identity;
+use ed25519_dalek::VerifyingKey as Ed25519VerifyingKey;
+use sha2::{Digest, Sha512};
+use std::collections::BTreeMap;
+use zeroize::{Zeroize, Zeroizing};
+
+const CONTEXT_STRING: &[u8] = b"FROST-ED25519-SHA512-v1";
+const KEY_PACKAGE_SIZE: usize = 2 + 2 + 32 + 32;
+
+/// Participant identifier (must be non-zero)
+pub type FrostIdentifier = u16;
+
+/// A participant's long-lived signing key share
+#[derive(Clone)]
+pub struct FrostKeyPackage {
+    identifier: FrostIdentifier,
+    min_signers: u16,
+    signing_share: Scalar,
+    verifying_share: EdwardsPoint,
+    group_public_key: EdwardsPoint,
+}
+
+impl FrostKeyPackage {
+    /// Get the participant identifier
+    #[inline]
+    pub fn identifier(&self) -> FrostIdentifier {
+        self.identifier
+    }
+
+    /// Get the signing threshold
+    #[inline]
+    pub fn min_signers(&self) -> u16 {
+        self.min_signers
+    }
+
+    /// Get this participant's public verifying share
+    #[inline]
+    pub fn verifying_share(&self) -> [u8; 32] {
+        self.verifying_share.compress().to_bytes()
+    }
+
+    /// Get the group public key (a regular Ed25519 public key)
+    #[inline]
+    pub fn group_public_key(&self) -> [u8; 32] {
+        self.group_public_key.compress().to_bytes()
+    }
+
+    /// Serialize for storage: identifier (2) | min_signers (2) | signing share (32) | group key (32)
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(Vec::with_capacity(KEY_PACKAGE_SIZE));
+        out.extend_from_slice(&self.identifier.to_be_bytes());
+        out.extend_from_slice(&self.min_signers.to_be_bytes());
+        out.extend_from_slice(self.signing_share.as_bytes());
+        out.extend_from_slice(self.group_public_key.compress().as_bytes());
+        out
+    }
+
+    /// Deserialize a key package produced by [`FrostKeyPackage::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != KEY_PACKAGE_SIZE {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_PACKAGE));
+        }
+
+        let identifier = u16::from_be_bytes([bytes[0], bytes[1]]);
+        let min_signers = u16::from_be_bytes([bytes[2], bytes[3]]);
+        check_identifier(identifier)?;
+        if min_signers < 2 {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_PARAMETERS));
+        }
+
+        let signing_share = decode_scalar(&bytes[4..36])?;
+        let group_public_key = decode_point(&bytes[36..68])?;
+
+        Ok(Self {
+            identifier,
+            min_signers,
+            verifying_share: EdwardsPoint::mul_base(&signing_share),
+            signing_share,
+            group_public_key,
+        })
+    }
+}
+
+impl Drop for FrostKeyPackage {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl std::fmt::Debug for FrostKeyPackage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrostKeyPackage")
+            .field("identifier", &self.identifier)
+            .field("min_signers", &self.min_signers)
+            .field("signing_share", &"[REDACTED]")
+            .finish()
+    }
+}
+
+/// Public information needed to verify signature shares and the final signature
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrostPublicKeyPackage {
+    verifying_shares: BTreeMap<FrostIdentifier, EdwardsPoint>,
+    group_public_key: EdwardsPoint,
+}
+
+impl FrostPublicKeyPackage {
+    /// Get the group public key bytes
+    #[inline]
+    pub fn group_public_key(&self) -> [u8; 32] {
+        self.group_public_key.compress().to_bytes()
+    }
+
+    /// Get the group public key as an Ed25519 verifying key
+    pub fn verifying_key(&self) -> CryptoResult<Ed25519VerifyingKey> {
+        Ed25519VerifyingKey::from_bytes(&self.group_public_key())
+            .map_err(|_| CryptoError::InvalidKey(INVALID_ED25519_PUBLIC_KEY))
+    }
+
+    /// Get a participant's verifying share
+    #[inline]
+    pub fn verifying_share(&self, identifier: FrostIdentifier) -> Option<[u8; 32]> {
+        self.verifying_shares.get(&identifier).map(|p| p.compress().to_bytes())
+    }
+
+    /// Get all participant identifiers
+    #[inline]
+    pub fn identifiers(&self) -> Vec<FrostIdentifier> {
+        self.verifying_shares.keys().copied().collect()
+    }
+}
+
+/// Public nonce commitments broadcast in signing round one
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrostCommitments {
+    hiding: EdwardsPoint,
+    binding: EdwardsPoint,
+}
+
+impl FrostCommitments {
+    /// Serialize as hiding (32) | binding (32)
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        out[..32].copy_from_slice(self.hiding.compress().as_bytes());
+        out[32..].copy_from_slice(self.binding.compress().as_bytes());
+        out
+    }
+
+    /// Deserialize commitments
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != 64 {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_PACKAGE));
+        }
+
+        Ok(Self {
+            hiding: decode_point(&bytes[..32])?,
+            binding: decode_point(&bytes[32..])?,
+        })
+    }
+}
+
+/// Secret nonces from signing round one; consumed by [`FrostThreshold::sign`]
+///
+/// Deliberately not `Clone`: reusing nonces across two signatures leaks the signing share.
+pub struct FrostSigningNonces {
+    hiding: Scalar,
+    binding: Scalar,
+    commitments: FrostCommitments,
+}
+
+impl FrostSigningNonces {
+    /// Get the public commitments for these nonces
+    #[inline]
+    pub fn commitments(&self) -> &FrostCommitments {
+        &self.commitments
+    }
+}
+
+impl Drop for FrostSigningNonces {
+    fn drop(&mut self) {
+        self.hiding.zeroize();
+        self.binding.zeroize();
+    }
+}
+
+impl std::fmt::Debug for FrostSigningNonces {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FrostSigningNonces")
+            .field("commitments", &self.commitments)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The message and the commitments of every participant in this signing session
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrostSigningPackage {
+    commitments: BTreeMap<FrostIdentifier, FrostCommitments>,
+    message: Vec<u8>,
+}
+
+impl FrostSigningPackage {
+    /// Create a signing package from the collected round-one commitments
+    #[inline]
+    pub fn new(commitments: BTreeMap<FrostIdentifier, FrostCommitments>, message: &[u8]) -> Self {
+        Self {
+            commitments,
+            message: message.to_vec(),
+        }
+    }
+
+    /// Get the message being signed
+    #[inline]
+    pub fn message(&self) -> &[u8] {
+        &self.message
+    }
+
+    /// Get the identifiers of the participating signers
+    #[inline]
+    pub fn signers(&self) -> Vec<FrostIdentifier> {
+        self.commitments.keys().copied().collect()
+    }
+}
+
+/// A participant's partial signature from signing round two
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrostSignatureShare {
+    share: Scalar,
+}
+
+impl FrostSignatureShare {
+    /// Serialize the share
+    #[inline]
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.share.to_bytes()
+    }
+
+    /// Deserialize a share
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        Ok(Self { share: decode_scalar(bytes)? })
+    }
+}
+
+/// DKG round-one state kept private by each participant
+pub struct FrostDkgRound1Secret {
+    identifier: FrostIdentifier,
+    min_signers: u16,
+    max_signers: u16,
+    coefficients: Vec<Scalar>,
+    commitment: Vec<EdwardsPoint>,
+}
+
+impl Drop for FrostDkgRound1Secret {
+    fn drop(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+/// DKG round-one package broadcast to every other participant
+#[derive(Clone, Debug, PartialEq)]
+pub struct FrostDkgRound1Package {
+    commitment: Vec<EdwardsPoint>,
+    proof_r: EdwardsPoint,
+    proof_mu: Scalar,
+}
+
+impl FrostDkgRound1Package {
+    /// Serialize as count (2) | commitments (32 each) | proof R (32) | proof mu (32)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(2 + 32 * self.commitment.len() + 64);
+        out.extend_from_slice(&(self.commitment.len() as u16).to_be_bytes());
+        for point in &self.commitment {
+            out.extend_from_slice(point.compress().as_bytes());
+        }
+        out.extend_from_slice(self.proof_r.compress().as_bytes());
+        out.extend_from_slice(self.proof_mu.as_bytes());
+        out
+    }
+
+    /// Deserialize a round-one package
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() < 2 {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_PACKAGE));
+        }
+
+        let count = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+        if count < 2 || bytes.len() != 2 + 32 * count + 64 {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_PACKAGE));
+        }
+
+        let commitment = bytes[2..2 + 32 * count]
+            .chunks_exact(32)
+            .map(decode_point)
+            .collect::<CryptoResult<Vec<_>>>()?;
+        let proof_start = 2 + 32 * count;
+
+        Ok(Self {
+            commitment,
+            proof_r: decode_point(&bytes[proof_start..proof_start + 32])?,
+            proof_mu: decode_scalar(&bytes[proof_start + 32..])?,
+        })
+    }
+}
+
+/// DKG round-two state kept private by each participant
+pub struct FrostDkgRound2Secret {
+    identifier: FrostIdentifier,
+    min_signers: u16,
+    commitment: Vec<EdwardsPoint>,
+    own_share: Scalar,
+}
+
+impl Drop for FrostDkgRound2Secret {
+    fn drop(&mut self) {
+        self.own_share.zeroize();
+    }
+}
+
+/// DKG round-two secret share addressed to a single participant
+///
+/// Must be sent over a confidential, authenticated channel.
+#[derive(Clone)]
+pub struct FrostDkgRound2Package {
+    signing_share: Scalar,
+}
+
+impl FrostDkgRound2Package {
+    /// Serialize the secret share
+    #[inline]
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(self.signing_share.to_bytes().to_vec())
+    }
+
+    /// Deserialize a secret share
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        Ok(Self { signing_share: decode_scalar(bytes)? })
+    }
+}
+
+impl Drop for FrostDkgRound2Package {
+    fn drop(&mut self) {
+        self.signing_share.zeroize();
+    }
+}
+
+impl std::fmt::Debug for FrostDkgRound2Package {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FrostDkgRound2Package([REDACTED])")
+    }
+}
+
+/// FROST(Ed25519, SHA-512) threshold signing
+pub struct FrostThreshold;
+
+impl FrostThreshold {
+    /// Split a freshly generated group key into `max_signers` shares using a trusted dealer
+    pub fn generate_with_dealer(min_signers: u16, max_signers: u16) -> CryptoResult<(Vec<FrostKeyPackage>, FrostPublicKeyPackage)> {
+        check_parameters(min_signers, max_signers)?;
+
+        let coefficients = Zeroizing::new(random_coefficients(min_signers)?);
+        let group_public_key = EdwardsPoint::mul_base(&coefficients[0]);
+
+        let mut key_packages = Vec::with_capacity(max_signers as usize);
+        let mut verifying_shares = BTreeMap::new();
+        for identifier in 1..=max_signers {
+            let signing_share = evaluate_polynomial(&coefficients, identifier);
+            let verifying_share = EdwardsPoint::mul_base(&signing_share);
+            verifying_shares.insert(identifier, verifying_share);
+            key_packages.push(FrostKeyPackage {
+                identifier,
+                min_signers,
+                signing_share,
+                verifying_share,
+                group_public_key,
+            });
+        }
+
+        Ok((key_packages, FrostPublicKeyPackage { verifying_shares, group_public_key }))
+    }
+
+    /// Signing round one: generate fresh nonces and their public commitments
+    pub fn commit(key_package: &FrostKeyPackage) -> CryptoResult<FrostSigningNonces> {
+        let hiding = nonce_generate(&key_package.signing_share)?;
+        let binding = nonce_generate(&key_package.signing_share)?;
+        let commitments = FrostCommitments {
+            hiding: EdwardsPoint::mul_base(&hiding),
+            binding: EdwardsPoint::mul_base(&binding),
+        };
+
+        Ok(FrostSigningNonces { hiding, binding, commitments })
+    }
+
+    /// Signing round two: produce this participant's signature share
+    pub fn sign(signing_package: &FrostSigningPackage, nonces: FrostSigningNonces, key_package: &FrostKeyPackage) -> CryptoResult<FrostSignatureShare> {
+        if signing_package.commitments.len() < key_package.min_signers as usize {
+            return Err(CryptoError::InvalidInput(FROST_NOT_ENOUGH_SIGNERS));
+        }
+
+        match signing_package.commitments.get(&key_package.identifier) {
+            Some(commitments) if *commitments == nonces.commitments => {}
+            _ => return Err(CryptoError::SignatureFailed(FROST_COMMITMENT_MISMATCH)),
+        }
+
+        let binding_factors = compute_binding_factors(&key_package.group_public_key, signing_package);
+        let group_commitment = compute_group_commitment(signing_package, &binding_factors);
+        let challenge = compute_challenge(&group_commitment, &key_package.group_public_key, &signing_package.message);
+        let lambda = lagrange_coefficient(signing_package.commitments.keys(), key_package.identifier);
+
+        let share = nonces.hiding
+            + nonces.binding * binding_factors[&key_package.identifier]
+            + lambda * key_package.signing_share * challenge;
+
+        Ok(FrostSignatureShare { share })
+    }
+
+    /// Check a single signature share, to identify misbehaving participants
+    pub fn verify_signature_share(
+        identifier: FrostIdentifier,
+        signature_share: &FrostSignatureShare,
+        signing_package: &FrostSigningPackage,
+        public_key_package: &FrostPublicKeyPackage,
+    ) -> CryptoResult<bool> {
+        let commitments = signing_package.commitments.get(&identifier)
+            .ok_or(CryptoError::InvalidInput(FROST_UNKNOWN_PARTICIPANT))?;
+        let verifying_share = public_key_package.verifying_shares.get(&identifier)
+            .ok_or(CryptoError::InvalidInput(FROST_UNKNOWN_PARTICIPANT))?;
+
+        let group_public_key = &public_key_package.group_public_key;
+        let binding_factors = compute_binding_factors(group_public_key, signing_package);
+        let group_commitment = compute_group_commitment(signing_package, &binding_factors);
+        let challenge = compute_challenge(&group_commitment, group_public_key, &signing_package.message);
+        let lambda = lagrange_coefficient(signing_package.commitments.keys(), identifier);
+
+        let commitment_share = commitments.hiding + commitments.binding * binding_factors[&identifier];
+        let expected = commitment_share + verifying_share * (challenge * lambda);
+
+        Ok(EdwardsPoint::mul_base(&signature_share.share) == expected)
+    }
+
+    /// Combine signature shares into a standard 64-byte Ed25519 signature
+    pub fn aggregate(
+        signing_package: &FrostSigningPackage,
+        signature_shares: &BTreeMap<FrostIdentifier, FrostSignatureShare>,
+        public_key_package: &FrostPublicKeyPackage,
+    ) -> CryptoResult<Vec<u8>> {
+        if !signing_package.commitments.keys().eq(signature_shares.keys()) {
+            return Err(CryptoError::InvalidInput(FROST_MISSING_SIGNATURE_SHARE));
+        }
+
+        let group_public_key = &public_key_package.group_public_key;
+        let binding_factors = compute_binding_factors(group_public_key, signing_package);
+        let group_commitment = compute_group_commitment(signing_package, &binding_factors);
+        let z: Scalar = signature_shares.values().map(|s| s.share).sum();
+
+        let challenge = compute_challenge(&group_commitment, group_public_key, &signing_package.message);
+        if EdwardsPoint::mul_base(&z) != group_commitment + group_public_key * challenge {
+            return Err(CryptoError::SignatureFailed(FROST_INVALID_SIGNATURE_SHARE));
+        }
+
+        let mut signature = Vec::with_capacity(64);
+        signature.extend_from_slice(group_commitment.compress().as_bytes());
+        signature.extend_from_slice(z.as_bytes());
+        Ok(signature)
+    }
+
+    /// DKG round one: commit to a random polynomial and prove knowledge of its secret
+    pub fn dkg_part1(identifier: FrostIdentifier, min_signers: u16, max_signers: u16) -> CryptoResult<(FrostDkgRound1Secret, FrostDkgRound1Package)> {
+        check_parameters(min_signers, max_signers)?;
+        check_identifier(identifier)?;
+        if identifier > max_signers {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_IDENTIFIER));
+        }
+
+        let coefficients = random_coefficients(min_signers)?;
+        let commitment: Vec<EdwardsPoint> = coefficients.iter().map(EdwardsPoint::mul_base).collect();
+
+        let mut k = random_scalar()?;
+        let proof_r = EdwardsPoint::mul_base(&k);
+        let c = dkg_challenge(identifier, &commitment[0], &proof_r);
+        let proof_mu = k + coefficients[0] * c;
+        k.zeroize();
+
+        let package = FrostDkgRound1Package { commitment: commitment.clone(), proof_r, proof_mu };
+        let secret = FrostDkgRound1Secret { identifier, min_signers, max_signers, coefficients, commitment };
+
+        Ok((secret, package))
+    }
+
+    /// DKG round two: verify everyone's round-one package and compute a secret share for each
+    pub fn dkg_part2(
+        secret: FrostDkgRound1Secret,
+        round1_packages: &BTreeMap<FrostIdentifier, FrostDkgRound1Package>,
+    ) -> CryptoResult<(FrostDkgRound2Secret, BTreeMap<FrostIdentifier, FrostDkgRound2Package>)> {
+        check_round1_packages(secret.identifier, secret.min_signers, secret.max_signers, round1_packages)?;
+
+        for (&identifier, package) in round1_packages {
+            let c = dkg_challenge(identifier, &package.commitment[0], &package.proof_r);
+            if package.proof_r != EdwardsPoint::mul_base(&package.proof_mu) - package.commitment[0] * c {
+                return Err(CryptoError::VerificationFailed(FROST_INVALID_PROOF_OF_KNOWLEDGE));
+            }
+        }
+
+        let round2_packages = round1_packages.keys()
+            .map(|&identifier| {
+                let signing_share = evaluate_polynomial(&secret.coefficients, identifier);
+                (identifier, FrostDkgRound2Package { signing_share })
+            })
+            .collect();
+
+        let round2_secret = FrostDkgRound2Secret {
+            identifier: secret.identifier,
+            min_signers: secret.min_signers,
+            commitment: secret.commitment.clone(),
+            own_share: evaluate_polynomial(&secret.coefficients, secret.identifier),
+        };
+
+        Ok((round2_secret, round2_packages))
+    }
+
+    /// DKG round three: verify the received shares and assemble the final key packages
+    pub fn dkg_part3(
+        secret: &FrostDkgRound2Secret,
+        round1_packages: &BTreeMap<FrostIdentifier, FrostDkgRound1Package>,
+        round2_packages: &BTreeMap<FrostIdentifier, FrostDkgRound2Package>,
+    ) -> CryptoResult<(FrostKeyPackage, FrostPublicKeyPackage)> {
+        if !round1_packages.keys().eq(round2_packages.keys()) {
+            return Err(CryptoError::InvalidInput(FROST_UNKNOWN_PARTICIPANT));
+        }
+
+        let mut signing_share = secret.own_share;
+        for (identifier, package) in round2_packages {
+            let commitment = &round1_packages[identifier].commitment;
+            if EdwardsPoint::mul_base(&package.signing_share) != evaluate_commitment(commitment, secret.identifier) {
+                signing_share.zeroize();
+                return Err(CryptoError::VerificationFailed(FROST_INVALID_SECRET_SHARE));
+            }
+            signing_share += package.signing_share;
+        }
+
+        let mut commitments: BTreeMap<FrostIdentifier, &[EdwardsPoint]> = round1_packages.iter()
+            .map(|(&identifier, package)| (identifier, package.commitment.as_slice()))
+            .collect();
+        commitments.insert(secret.identifier, &secret.commitment);
+
+        let group_public_key: EdwardsPoint = commitments.values().map(|c| c[0]).sum();
+        let verifying_shares: BTreeMap<FrostIdentifier, EdwardsPoint> = commitments.keys()
+            .map(|&j| (j, commitments.values().map(|c| evaluate_commitment(c, j)).sum()))
+            .collect();
+
+        let key_package = FrostKeyPackage {
+            identifier: secret.identifier,
+            min_signers: secret.min_signers,
+            verifying_share: verifying_shares[&secret.identifier],
+            signing_share,
+            group_public_key,
+        };
+
+        Ok((key_package, FrostPublicKeyPackage { verifying_shares, group_public_key }))
+    }
+}
+
+fn check_parameters(min_signers: u16, max_signers: u16) -> CryptoResult<()> {
+    if min_signers < 2 || min_signers > max_signers {
+        return Err(CryptoError::InvalidInput(FROST_INVALID_PARAMETERS));
+    }
+    Ok(())
+}
+
+#[inline]
+fn check_identifier(identifier: FrostIdentifier) -> CryptoResult<()> {
+    if identifier == 0 {
+        return Err(CryptoError::InvalidInput(FROST_INVALID_IDENTIFIER));
+    }
+    Ok(())
+}
+
+fn check_round1_packages(
+    identifier: FrostIdentifier,
+    min_signers: u16,
+    max_signers: u16,
+    round1_packages: &BTreeMap<FrostIdentifier, FrostDkgRound1Package>,
+) -> CryptoResult<()> {
+    if round1_packages.len() != max_signers as usize - 1 || round1_packages.contains_key(&identifier) {
+        return Err(CryptoError::InvalidInput(FROST_UNKNOWN_PARTICIPANT));
+    }
+
+    for (&other, package) in round1_packages {
+        check_identifier(other)?;
+        if other > max_signers {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_IDENTIFIER));
+        }
+        if package.commitment.len() != min_signers as usize {
+            return Err(CryptoError::InvalidInput(FROST_INVALID_PACKAGE));
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_point(bytes: &[u8]) -> CryptoResult<EdwardsPoint> {
+    let bytes: [u8; 32] = bytes.try_into()
+        .map_err(|_| CryptoError::InvalidInput(FROST_INVALID_POINT))?;
+    let point = CompressedEdwardsY(bytes).decompress()
+        .ok_or(CryptoError::InvalidInput(FROST_INVALID_POINT))?;
+
+    if point == EdwardsPoint::identity() || !point.is_torsion_free() {
+        return Err(CryptoError::InvalidInput(FROST_INVALID_POINT));
+    }
+
+    Ok(point)
+}
+
+fn decode_scalar(bytes: &[u8]) -> CryptoResult<Scalar> {
+    let bytes: [u8; 32] = bytes.try_into()
+        .map_err(|_| CryptoError::InvalidInput(FROST_INVALID_SCALAR))?;
+    Option::from(Scalar::from_canonical_bytes(bytes))
+        .ok_or(CryptoError::InvalidInput(FROST_INVALID_SCALAR))
+}
+
+fn random_scalar() -> CryptoResult<Scalar> {
+    let mut wide = Zeroizing::new([0u8; 64]);
+    SecureRandom::fill(wide.as_mut())?;
+    Ok(Scalar::from_bytes_mod_order_wide(&wide))
+}
+
+fn random_coefficients(min_signers: u16) -> CryptoResult<Vec<Scalar>> {
+    (0..min_signers).map(|_| random_scalar()).collect()
+}
+
+fn hash_to_scalar(parts: &[&[u8]]) -> Scalar {
+    Scalar::from_bytes_mod_order_wide(&hash(parts))
+}
+
+fn hash(parts: &[&[u8]]) -> [u8; 64] {
+    let mut hasher = Sha512::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+// nonce_generate from RFC 9591 section 4.1 (H3)
+fn nonce_generate(secret: &Scalar) -> CryptoResult<Scalar> {
+    let mut random_bytes = Zeroizing::new([0u8; 32]);
+    SecureRandom::fill(random_bytes.as_mut())?;
+    Ok(hash_to_scalar(&[CONTEXT_STRING, b"nonce", random_bytes.as_ref(), secret.as_bytes()]))
+}
+
+#[inline]
+fn identifier_scalar(identifier: FrostIdentifier) -> Scalar {
+    Scalar::from(identifier as u64)
+}
+
+fn evaluate_polynomial(coefficients: &[Scalar], identifier: FrostIdentifier) -> Scalar {
+    let x = identifier_scalar(identifier);
+    coefficients.iter().rev().fold(Scalar::ZERO, |acc, c| acc * x + c)
+}
+
+fn evaluate_commitment(commitment: &[EdwardsPoint], identifier: FrostIdentifier) -> EdwardsPoint {
+    let x = identifier_scalar(identifier);
+    commitment.iter().rev().fold(EdwardsPoint::identity(), |acc, c| acc * x + c)
+}
+
+fn lagrange_coefficient<'a>(signers: impl Iterator<Item = &'a FrostIdentifier>, identifier: FrostIdentifier) -> Scalar {
+    let x_i = identifier_scalar(identifier);
+    let mut numerator = Scalar::ONE;
+    let mut denominator = Scalar::ONE;
+
+    for &other in signers.filter(|&&j| j != identifier) {
+        let x_j = identifier_scalar(other);
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+
+    numerator * denominator.invert()
+}
+
+// compute_binding_factors from RFC 9591 section 4.4 (H1, H4, H5)
+fn compute_binding_factors(group_public_key: &EdwardsPoint, signing_package: &FrostSigningPackage) -> BTreeMap<FrostIdentifier, Scalar> {
+    let mut encoded_commitments = Vec::with_capacity(signing_package.commitments.len() * 96);
+    for (&identifier, commitments) in &signing_package.commitments {
+        encoded_commitments.extend_from_slice(identifier_scalar(identifier).as_bytes());
+        encoded_commitments.extend_from_slice(&commitments.to_bytes());
+    }
+
+    let msg_hash = hash(&[CONTEXT_STRING, b"msg", &signing_package.message]);
+    let commitment_hash = hash(&[CONTEXT_STRING, b"com", &encoded_commitments]);
+    let group_public_key = group_public_key.compress();
+
+    signing_package.commitments.keys()
+        .map(|&identifier| {
+            let binding_factor = hash_to_scalar(&[
+                CONTEXT_STRING,
+                b"rho",
+                group_public_key.as_bytes(),
+                &msg_hash,
+                &commitment_hash,
+                identifier_scalar(identifier).as_bytes(),
+            ]);
+            (identifier, binding_factor)
+        })
+        .collect()
+}
+
+fn compute_group_commitment(signing_package: &FrostSigningPackage, binding_factors: &BTreeMap<FrostIdentifier, Scalar>) -> EdwardsPoint {
+    signing_package.commitments.iter()
+        .map(|(identifier, c)| c.hiding + c.binding * binding_factors[identifier])
+        .sum()
+}
+
+// The challenge is the plain Ed25519 one (H2 has no context prefix) so the output verifies as RFC 8032
+fn compute_challenge(group_commitment: &EdwardsPoint, group_public_key: &EdwardsPoint, message: &[u8]) -> Scalar {
+    hash_to_scalar(&[
+        group_commitment.compress().as_bytes(),
+        group_public_key.compress().as_bytes(),
+        message,
+    ])
+}
+
+fn dkg_challenge(identifier: FrostIdentifier, verifying_key: &EdwardsPoint, proof_r: &EdwardsPoint) -> Scalar {
+    hash_to_scalar(&[
+        CONTEXT_STRING,
+        b"dkg",
+        identifier_scalar(identifier).as_bytes(),
+        verifying_key.compress().as_bytes(),
+        proof_r.compress().as_bytes(),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::Ed25519Crypto;
+
+    fn sign_with(
+        key_packages: &[&FrostKeyPackage],
+        public_key_package: &FrostPublicKeyPackage,
+        message: &[u8],
+    ) -> CryptoResult<Vec<u8>> {
+        let nonces: Vec<FrostSigningNonces> = key_packages.iter()
+            .map(|kp| FrostThreshold::commit(kp).unwrap())
+            .collect();
+        let commitments = key_packages.iter().zip(&nonces)
+            .map(|(kp, n)| (kp.identifier(), *n.commitments()))
+            .collect();
+        let signing_package = FrostSigningPackage::new(commitments, message);
+
+        let shares = key_packages.iter().zip(nonces)
+            .map(|(kp, n)| (kp.identifier(), FrostThreshold::sign(&signing_package, n, kp).unwrap()))
+            .collect();
+
+        FrostThreshold::aggregate(&signing_package, &shares, public_key_package)
+    }
+
+    #[test]
+    fn test_trusted_dealer_signing() {
+        let (key_packages, public_key_package) = FrostThreshold::generate_with_dealer(2, 3).unwrap();
+        let verifying_key = public_key_package.verifying_key().unwrap();
+        let message = b"threshold signed message";
+
+        // Every 2-of-3 subset produces a valid Ed25519 signature
+        for (a, b) in [(0, 1), (0, 2), (1, 2)] {
+            let signature = sign_with(&[&key_packages[a], &key_packages[b]], &public_key_package, message).unwrap();
+            assert!(Ed25519Crypto::verify(message, &signature, &verifying_key).unwrap());
+            assert!(!Ed25519Crypto::verify(b"other message", &signature, &verifying_key).unwrap());
+        }
+
+        // All three signers also work
+        let all: Vec<&FrostKeyPackage> = key_packages.iter().collect();
+        let signature = sign_with(&all, &public_key_package, message).unwrap();
+        assert!(Ed25519Crypto::verify(message, &signature, &verifying_key).unwrap());
+    }
+
+    #[test]
+    fn test_dkg_signing() {
+        let (min_signers, max_signers) = (3u16, 5u16);
+
+        let mut round1_secrets = BTreeMap::new();
+        let mut round1_packages = BTreeMap::new();
+        for id in 1..=max_signers {
+            let (secret, package) = FrostThreshold::dkg_part1(id, min_signers, max_signers).unwrap();
+            let package = FrostDkgRound1Package::from_bytes(&package.to_bytes()).unwrap();
+            round1_secrets.insert(id, secret);
+            round1_packages.insert(id, package);
+        }
+
+        let others = |id: u16| -> BTreeMap<u16, FrostDkgRound1Package> {
+            round1_packages.iter().filter(|(&j, _)| j != id).map(|(&j, p)| (j, p.clone())).collect()
+        };
+
+        let mut round2_secrets = BTreeMap::new();
+        let mut inbox: BTreeMap<u16, BTreeMap<u16, FrostDkgRound2Package>> = BTreeMap::new();
+        for (id, secret) in round1_secrets {
+            let (round2_secret, outgoing) = FrostThreshold::dkg_part2(secret, &others(id)).unwrap();
+            for (recipient, package) in outgoing {
+                inbox.entry(recipient).or_default().insert(id, package);
+            }
+            round2_secrets.insert(id, round2_secret);
+        }
+
+        let mut key_packages = Vec::new();
+        let mut public_key_packages = Vec::new();
+        for (id, secret) in &round2_secrets {
+            let (key_package, public_key_package) = FrostThreshold::dkg_part3(secret, &others(*id), &inbox[id]).unwrap();
+            key_packages.push(key_package);
+            public_key_packages.push(public_key_package);
+        }
+
+        // Everyone agrees on the same public view
+        assert!(public_key_packages.windows(2).all(|w| w[0] == w[1]));
+
+        let public_key_package = &public_key_packages[0];
+        let verifying_key = public_key_package.verifying_key().unwrap();
+        let message = b"dkg message";
+        let signers = [&key_packages[0], &key_packages[2], &key_packages[4]];
+        let signature = sign_with(&signers, public_key_package, message).unwrap();
+        assert!(Ed25519Crypto::verify(message, &signature, &verifying_key).unwrap());
+
+        // Below the threshold signing is refused
+        let n1 = FrostThreshold::commit(signers[0]).unwrap();
+        let n2 = FrostThreshold::commit(signers[1]).unwrap();
+        let mut commitments = BTreeMap::new();
+        commitments.insert(signers[0].identifier(), *n1.commitments());
+        commitments.insert(signers[1].identifier(), *n2.commitments());
+        let signing_package = FrostSigningPackage::new(commitments, message);
+        assert!(FrostThreshold::sign(&signing_package, n1, signers[0]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_signature_share_detected() {
+        let (key_packages, public_key_package) = FrostThreshold::generate_with_dealer(2, 3).unwrap();
+        let message = b"message";
+
+        let n1 = FrostThreshold::commit(&key_packages[0]).unwrap();
+        let n2 = FrostThreshold::commit(&key_packages[1]).unwrap();
+        let mut commitments = BTreeMap::new();
+        commitments.insert(1, *n1.commitments());
+        commitments.insert(2, *n2.commitments());
+        let signing_package = FrostSigningPackage::new(commitments, message);
+
+        let s1 = FrostThreshold::sign(&signing_package, n1, &key_packages[0]).unwrap();
+        let s2 = FrostThreshold::sign(&signing_package, n2, &key_packages[1]).unwrap();
+        assert!(FrostThreshold::verify_signature_share(1, &s1, &signing_package, &public_key_package).unwrap());
+
+        let forged = FrostSignatureShare { share: s2.share + Scalar::ONE };
+        assert!(!FrostThreshold::verify_signature_share(2, &forged, &signing_package, &public_key_package).unwrap());
+
+        let mut shares = BTreeMap::new();
+        shares.insert(1, s1);
+        shares.insert(2, forged);
+        assert!(FrostThreshold::aggregate(&signing_package, &shares, &public_key_package).is_err());
+
+        shares.remove(&2);
+        assert!(FrostThreshold::aggregate(&signing_package, &shares, &public_key_package).is_err());
+    }
+
+    #[test]
+    fn test_commitment_mismatch_rejected() {
+        let (key_packages, _) = FrostThreshold::generate_with_dealer(2, 2).unwrap();
+        let n1 = FrostThreshold::commit(&key_packages[0]).unwrap();
+        let n2 = FrostThreshold::commit(&key_packages[1]).unwrap();
+        let stale = FrostThreshold::commit(&key_packages[0]).unwrap();
+
+        let mut commitments = BTreeMap::new();
+        commitments.insert(1, *n1.commitments());
+        commitments.insert(2, *n2.commitments());
+        let signing_package = FrostSigningPackage::new(commitments, b"message");
+
+        assert!(FrostThreshold::sign(&signing_package, stale, &key_packages[0]).is_err());
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let (key_packages, _) = FrostThreshold::generate_with_dealer(2, 3).unwrap();
+        let restored = FrostKeyPackage::from_bytes(&key_packages[1].to_bytes()).unwrap();
+        assert_eq!(restored.identifier(), 2);
+        assert_eq!(restored.min_signers(), 2);
+        assert_eq!(restored.verifying_share(), key_packages[1].verifying_share());
+        assert_eq!(restored.group_public_key(), key_packages[1].group_public_key());
+
+        let nonces = FrostThreshold::commit(&key_packages[0]).unwrap();
+        let commitments = FrostCommitments::from_bytes(&nonces.commitments().to_bytes()).unwrap();
+        assert_eq!(&commitments, nonces.commitments());
+
+        assert!(FrostKeyPackage::from_bytes(&[0u8; 10]).is_err());
+        assert!(FrostCommitments::from_bytes(&[0u8; 64]).is_err());
+        assert!(FrostSignatureShare::from_bytes(&[0xffu8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(FrostThreshold::generate_with_dealer(1, 3).is_err());
+        assert!(FrostThreshold::generate_with_dealer(4, 3).is_err());
+        assert!(FrostThreshold::dkg_part1(0, 2, 3).is_err());
+        assert!(FrostThreshold::dkg_part1(4, 2, 3).is_err());
+    }
+}
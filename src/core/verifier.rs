@@ -0,0 +1,286 @@
+#[cfg(any(feature = "ecdsa", feature = "ed25519"))]
+use crate::error::CryptoResult;
+#[cfg(feature = "ecdsa")]
+use crate::error::{CryptoError, INVALID_SIGNATURE_FORMAT};
+#[cfg(feature = "ecdsa")]
+use crate::core::asymmetric::EcdsaKeyPair;
+#[cfg(feature = "ed25519")]
+use crate::core::asymmetric::{Ed25519Crypto, Ed25519KeyPair};
+#[cfg(feature = "ed25519")]
+use ed25519_dalek::VerifyingKey as Ed25519VerifyingKey;
+#[cfg(feature = "ecdsa")]
+use p256::{AffinePoint, ProjectivePoint, Scalar, U256, ecdsa::{Signature, VerifyingKey}};
+#[cfg(feature = "ecdsa")]
+use p256::elliptic_curve::{ops::{Invert, Reduce}, point::AffineCoordinates, group::Group};
+#[cfg(feature = "ecdsa")]
+use sha2::Digest;
+#[cfg(feature = "ecdsa")]
+use alloc::{boxed::Box, vec::Vec};
+
+// Scalars are consumed 4 bits at a time: 64 windows of 15 nonzero multiples
+#[cfg(feature = "ecdsa")]
+const COMB_WINDOWS: usize = 64;
+#[cfg(feature = "ecdsa")]
+const COMB_ENTRIES: usize = 15;
+
+/// Ed25519 verification key parsed once for repeated use
+///
+/// A thin wrapper over [`ed25519_dalek::VerifyingKey`], which keeps the
+/// decompressed key point, so `verify` skips decoding the key. Accepts
+/// exactly the signatures [`Ed25519Crypto::verify`] accepts: the dalek
+/// `verify` rule, which rejects non-canonical `s` and `R` encodings but
+/// not small-order `R`.
+#[cfg(feature = "ed25519")]
+pub struct Ed25519VerifierContext {
+    verifying_key: Ed25519VerifyingKey,
+}
+
+#[cfg(feature = "ed25519")]
+impl Ed25519VerifierContext {
+    /// Wrap `verifying_key`
+    #[inline]
+    pub fn new(verifying_key: &Ed25519VerifyingKey) -> Self {
+        Self { verifying_key: *verifying_key }
+    }
+
+    /// Parse a 32-byte public key
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        Ed25519KeyPair::verifying_key_from_bytes(bytes).map(|key| Self::new(&key))
+    }
+
+    /// The key this context verifies against
+    #[inline]
+    pub fn verifying_key(&self) -> &Ed25519VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Verify an Ed25519 signature over `message`
+    #[inline]
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        Ed25519Crypto::verify(message, signature, &self.verifying_key)
+    }
+}
+
+/// ECDSA P-256 verification key with fixed-base tables for the key and the generator
+///
+/// Each table holds every 4-bit multiple of the point at every window, so a
+/// scalar multiplication takes 64 point additions and no doublings. The two
+/// tables use about 120 KB per context; build one per trusted issuer and
+/// reuse it. Accepts exactly the signatures [`EcdsaCrypto::verify`](crate::core::asymmetric::EcdsaCrypto::verify)
+/// accepts.
+#[cfg(feature = "ecdsa")]
+pub struct EcdsaVerifierContext {
+    verifying_key: VerifyingKey,
+    key_table: Box<[[AffinePoint; COMB_ENTRIES]]>,
+    generator_table: Box<[[AffinePoint; COMB_ENTRIES]]>,
+}
+
+#[cfg(feature = "ecdsa")]
+impl EcdsaVerifierContext {
+    /// Precompute tables for `verifying_key`
+    pub fn new(verifying_key: &VerifyingKey) -> Self {
+        Self {
+            verifying_key: *verifying_key,
+            key_table: comb_table(ProjectivePoint::from(*verifying_key.as_affine())),
+            generator_table: comb_table(ProjectivePoint::generator()),
+        }
+    }
+
+    /// Parse a SEC1-encoded public key and precompute its tables
+    #[inline]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        EcdsaKeyPair::verifying_key_from_bytes(bytes).map(|key| Self::new(&key))
+    }
+
+    /// The key this context verifies against
+    #[inline]
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+
+    /// Verify a 64-byte `r || s` ECDSA P-256 signature over the SHA-256 hash of `message`
+    pub fn verify(&self, message: &[u8], signature: &[u8]) -> CryptoResult<bool> {
+        let signature = Signature::from_slice(signature)
+            .map_err(|_| CryptoError::InvalidInput(INVALID_SIGNATURE_FORMAT))?;
+        let (r, s) = signature.split_scalars();
+
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&sha2::Sha256::digest(message));
+        let s_inv = *s.invert_vartime();
+        let point = comb_mul(&self.generator_table, &(z * s_inv)) + comb_mul(&self.key_table, &(*r * s_inv));
+
+        Ok(*r == <Scalar as Reduce<U256>>::reduce_bytes(&point.to_affine().x()))
+    }
+}
+
+// table[i][j] = (j + 1) * 16^i * point
+#[cfg(feature = "ecdsa")]
+fn comb_table(point: ProjectivePoint) -> Box<[[AffinePoint; COMB_ENTRIES]]> {
+    let mut table = Vec::with_capacity(COMB_WINDOWS);
+    let mut base = point;
+    for _ in 0..COMB_WINDOWS {
+        let mut multiple = base;
+        let mut multiples = [AffinePoint::IDENTITY; COMB_ENTRIES];
+        for entry in &mut multiples {
+            *entry = multiple.to_affine();
+            multiple += base;
+        }
+        table.push(multiples);
+        // 16 * base
+        base = multiple;
+    }
+    table.into_boxed_slice()
+}
+
+// Scalars here are computed from public values, so skipping zero windows is fine
+#[cfg(feature = "ecdsa")]
+fn comb_mul(table: &[[AffinePoint; COMB_ENTRIES]], scalar: &Scalar) -> ProjectivePoint {
+    let bytes = scalar.to_bytes();
+    let mut result = ProjectivePoint::IDENTITY;
+    for (window, multiples) in table.iter().enumerate() {
+        let byte = bytes[bytes.len() - 1 - window / 2];
+        let digit = if window % 2 == 0 { byte & 0x0f } else { byte >> 4 };
+        if digit != 0 {
+            result += &multiples[usize::from(digit) - 1];
+        }
+    }
+    result
+}
+
+#[cfg(all(test, feature = "ecdsa", feature = "ed25519"))]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::EcdsaCrypto;
+    use crate::core::random::SecureRandom;
+
+    #[test]
+    fn test_ed25519_verifier_context() {
+        let keypair = Ed25519KeyPair::generate().unwrap();
+        let context = Ed25519VerifierContext::from_bytes(&keypair.public_key_bytes()).unwrap();
+        let other = Ed25519KeyPair::generate().unwrap();
+
+        for len in [0, 1, 64, 1000] {
+            let message = if len == 0 { Vec::new() } else { SecureRandom::generate_bytes(len).unwrap() };
+            let signature = Ed25519Crypto::sign(&message, keypair.signing_key()).unwrap();
+            assert!(context.verify(&message, &signature).unwrap());
+
+            let mut tampered = signature.clone();
+            tampered[len % 64] ^= 1;
+            assert_eq!(context.verify(&message, &tampered).unwrap(), Ed25519Crypto::verify(&message, &tampered, keypair.verifying_key()).unwrap());
+            assert!(!context.verify(&message, &tampered).unwrap());
+            assert!(!context.verify(b"other message", &signature).unwrap());
+
+            let foreign = Ed25519Crypto::sign(&message, other.signing_key()).unwrap();
+            assert!(!context.verify(&message, &foreign).unwrap());
+        }
+
+        // Non-canonical s (s + l) is rejected like the non-precomputed path
+        let mut signature = Ed25519Crypto::sign(b"malleable", keypair.signing_key()).unwrap();
+        let s = signature[32..].to_vec();
+        // The group order l, little-endian
+        let l = hex::decode("edd3f55c1a631258d69cf7a2def9de1400000000000000000000000000000010").unwrap();
+        let mut carry = 0u16;
+        for (i, byte) in s.iter().enumerate() {
+            let sum = u16::from(*byte) + u16::from(l[i]) + carry;
+            signature[32 + i] = sum as u8;
+            carry = sum >> 8;
+        }
+        assert!(!context.verify(b"malleable", &signature).unwrap());
+        assert!(!Ed25519Crypto::verify(b"malleable", &signature, keypair.verifying_key()).unwrap());
+
+        assert!(context.verify(b"short", &[0u8; 63]).is_err());
+        assert!(Ed25519VerifierContext::from_bytes(&[0u8; 31]).is_err());
+    }
+
+    #[test]
+    fn test_ecdsa_verifier_context() {
+        let keypair = EcdsaKeyPair::generate().unwrap();
+        let context = EcdsaVerifierContext::from_bytes(&keypair.public_key_bytes()).unwrap();
+        assert_eq!(context.verifying_key(), keypair.verifying_key());
+
+        for i in 0..32u8 {
+            let message = SecureRandom::generate_bytes(usize::from(i) + 1).unwrap();
+            let signature = EcdsaCrypto::sign(&message, keypair.signing_key()).unwrap();
+            assert!(context.verify(&message, &signature).unwrap());
+
+            let mut tampered = signature.clone();
+            tampered[usize::from(i) * 2] ^= 0x80;
+            let expected = EcdsaCrypto::verify(&message, &tampered, keypair.verifying_key());
+            assert_eq!(context.verify(&message, &tampered), expected);
+            assert!(!context.verify(&[i], &signature).unwrap());
+        }
+
+        let other = EcdsaKeyPair::generate().unwrap();
+        let signature = EcdsaCrypto::sign(b"issuer", other.signing_key()).unwrap();
+        assert!(!context.verify(b"issuer", &signature).unwrap());
+        assert!(context.verify(b"issuer", &[0u8; 64]).is_err());
+        assert!(EcdsaVerifierContext::from_bytes(&[4u8; 65]).is_err());
+
+        // Edge scalars: the table lookups cover every window
+        for scalar in [Scalar::ONE, -Scalar::ONE, Scalar::from(0xf0f0_f0f0_u64)] {
+            let expected = ProjectivePoint::generator() * scalar;
+            assert_eq!(comb_mul(&context.generator_table, &scalar), expected);
+        }
+        assert_eq!(comb_mul(&context.generator_table, &Scalar::ZERO), ProjectivePoint::IDENTITY);
+    }
+
+    // Checked-in edge cases in Wycheproof's format, see test-vectors/edge-cases/
+    #[cfg(feature = "std")]
+    fn edge_cases(json: &str) -> Vec<(serde_json::Value, Vec<serde_json::Value>)> {
+        let document: serde_json::Value = serde_json::from_str(json).unwrap();
+        document["testGroups"].as_array().unwrap().iter()
+            .map(|group| (group["publicKey"].clone(), group["tests"].as_array().unwrap().clone()))
+            .collect()
+    }
+
+    #[cfg(feature = "std")]
+    fn field(value: &serde_json::Value, name: &str) -> Vec<u8> {
+        hex::decode(value[name].as_str().unwrap()).unwrap()
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ed25519_edge_cases() {
+        use crate::core::asymmetric::KeyValidation;
+
+        for (public_key, tests) in edge_cases(include_str!("../../test-vectors/edge-cases/ed25519_edge_cases.json")) {
+            let pk = field(&public_key, "pk");
+            let strict = Ed25519VerifierContext::from_bytes(&pk);
+            // Small-order keys are refused on import, but a context built anyway must still agree
+            let legacy = Ed25519KeyPair::verifying_key_from_bytes_with_validation(&pk, KeyValidation::Legacy).unwrap();
+            let legacy_context = Ed25519VerifierContext::new(&legacy);
+
+            for test in tests {
+                let (msg, sig, result) = (field(&test, "msg"), field(&test, "sig"), test["result"].as_str().unwrap());
+                let tc_id = &test["tcId"];
+                assert_eq!(legacy_context.verify(&msg, &sig), Ed25519Crypto::verify(&msg, &sig, &legacy), "tcId {tc_id}");
+
+                let Ok(context) = &strict else {
+                    assert_eq!(result, "invalid", "tcId {tc_id}");
+                    continue;
+                };
+                let verified = context.verify(&msg, &sig).unwrap();
+                assert_eq!(verified, Ed25519Crypto::verify(&msg, &sig, context.verifying_key()).unwrap(), "tcId {tc_id}");
+                if result != "acceptable" {
+                    assert_eq!(verified, result == "valid", "tcId {tc_id}");
+                }
+            }
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ecdsa_edge_cases() {
+        for (public_key, tests) in edge_cases(include_str!("../../test-vectors/edge-cases/ecdsa_secp256r1_sha256_p1363_edge_cases.json")) {
+            let context = EcdsaVerifierContext::from_bytes(&field(&public_key, "uncompressed")).unwrap();
+            for test in tests {
+                let (msg, sig) = (field(&test, "msg"), field(&test, "sig"));
+                let tc_id = &test["tcId"];
+                let verified = context.verify(&msg, &sig);
+                assert_eq!(verified, EcdsaCrypto::verify(&msg, &sig, context.verifying_key()), "tcId {tc_id}");
+                // Out-of-range r or s is a format error, not just a mismatch
+                assert_eq!(verified.unwrap_or(false), test["result"] == "valid", "tcId {tc_id}");
+            }
+        }
+    }
+}
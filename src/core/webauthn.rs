@@ -0,0 +1,321 @@
+use crate::core::keyformat::AsymmetricKey;
+use crate::error::{CryptoError, CryptoResult, WEBAUTHN_INVALID_PUBLIC_KEY, WEBAUTHN_UNSUPPORTED_ALGORITHM, WEBAUTHN_INVALID_AUTHENTICATOR_DATA, WEBAUTHN_INVALID_CLIENT_DATA, WEBAUTHN_TYPE_MISMATCH, WEBAUTHN_CHALLENGE_MISMATCH, WEBAUTHN_ORIGIN_MISMATCH, WEBAUTHN_RP_ID_MISMATCH, WEBAUTHN_USER_NOT_PRESENT, WEBAUTHN_USER_NOT_VERIFIED, WEBAUTHN_COUNTER_REGRESSION, WEBAUTHN_INVALID_SIGNATURE};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use ed25519_dalek::{Signature as Ed25519Signature, VerifyingKey as Ed25519VerifyingKey};
+use p256::ecdsa::signature::Verifier;
+use p256::ecdsa::{Signature as EcdsaSignature, VerifyingKey as EcdsaVerifyingKey};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+const FLAG_USER_PRESENT: u8 = 0x01;
+const FLAG_USER_VERIFIED: u8 = 0x04;
+
+// rpIdHash (32) | flags (1) | signCount (4)
+const AUTHENTICATOR_DATA_MIN_LEN: usize = 37;
+
+/// Credential public key stored at registration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WebAuthnPublicKey {
+    /// ES256 (ECDSA P-256 with SHA-256)
+    Es256(EcdsaVerifyingKey),
+    /// EdDSA (Ed25519)
+    EdDsa(Ed25519VerifyingKey),
+}
+
+impl WebAuthnPublicKey {
+    /// Parse a COSE_Key as found in attested credential data
+    ///
+    /// Parsed by [`AsymmetricKey::from_cose_key`]; RSA credentials (RS256,
+    /// PS256) are well formed but not supported for assertions.
+    pub fn from_cose(bytes: &[u8]) -> CryptoResult<Self> {
+        match AsymmetricKey::from_cose_key(bytes) {
+            Ok(AsymmetricKey::EcdsaPublic(key)) => Ok(WebAuthnPublicKey::Es256(key)),
+            Ok(AsymmetricKey::Ed25519Public(key)) => Ok(WebAuthnPublicKey::EdDsa(key)),
+            Ok(_) => Err(CryptoError::InvalidKey(WEBAUTHN_UNSUPPORTED_ALGORITHM)),
+            Err(err) => Err(CryptoError::InvalidKey(WEBAUTHN_INVALID_PUBLIC_KEY).caused_by(err)),
+        }
+    }
+
+    /// Parse a SEC1-encoded P-256 point (compressed or uncompressed)
+    #[inline]
+    pub fn from_sec1(bytes: &[u8]) -> CryptoResult<Self> {
+        EcdsaVerifyingKey::from_sec1_bytes(bytes)
+            .map(WebAuthnPublicKey::Es256)
+            .map_err(|_| CryptoError::InvalidKey(WEBAUTHN_INVALID_PUBLIC_KEY))
+    }
+}
+
+/// Response fields from `navigator.credentials.get()`
+#[derive(Debug, Clone, Copy)]
+pub struct WebAuthnAssertion<'a> {
+    /// `response.authenticatorData`
+    pub authenticator_data: &'a [u8],
+    /// `response.clientDataJSON`
+    pub client_data_json: &'a [u8],
+    /// `response.signature`
+    pub signature: &'a [u8],
+}
+
+/// Relying party expectations for [`WebAuthn::verify_assertion`]
+#[derive(Debug, Clone)]
+pub struct WebAuthnExpectations {
+    /// Relying party ID, e.g. `example.com`
+    pub rp_id: String,
+    /// Exact origin the ceremony must have run on, e.g. `https://example.com`
+    pub origin: String,
+    /// Challenge issued for this ceremony
+    pub challenge: Vec<u8>,
+    /// Require the UV flag in addition to UP
+    pub require_user_verification: bool,
+}
+
+impl WebAuthnExpectations {
+    /// Expect an assertion for `rp_id` from `origin` over `challenge`
+    pub fn new(rp_id: &str, origin: &str, challenge: &[u8]) -> Self {
+        Self {
+            rp_id: rp_id.to_string(),
+            origin: origin.to_string(),
+            challenge: challenge.to_vec(),
+            require_user_verification: false,
+        }
+    }
+}
+
+/// Result of a successful assertion check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WebAuthnVerifiedAssertion {
+    /// New signature counter to store for the credential
+    pub sign_count: u32,
+    /// Whether the authenticator verified the user (UV flag)
+    pub user_verified: bool,
+}
+
+/// WebAuthn (Level 2) assertion verification for relying parties
+pub struct WebAuthn;
+
+impl WebAuthn {
+    /// Verify an assertion against the stored credential key and signature counter
+    pub fn verify_assertion(
+        assertion: &WebAuthnAssertion<'_>,
+        public_key: &WebAuthnPublicKey,
+        expected: &WebAuthnExpectations,
+        stored_sign_count: u32,
+    ) -> CryptoResult<WebAuthnVerifiedAssertion> {
+        Self::verify_client_data(assertion.client_data_json, expected)?;
+
+        let authenticator_data = assertion.authenticator_data;
+        if authenticator_data.len() < AUTHENTICATOR_DATA_MIN_LEN {
+            return Err(CryptoError::InvalidInput(WEBAUTHN_INVALID_AUTHENTICATOR_DATA));
+        }
+        if authenticator_data[..32] != Sha256::digest(expected.rp_id.as_bytes())[..] {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_RP_ID_MISMATCH));
+        }
+
+        let flags = authenticator_data[32];
+        if flags & FLAG_USER_PRESENT == 0 {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_USER_NOT_PRESENT));
+        }
+        let user_verified = flags & FLAG_USER_VERIFIED != 0;
+        if expected.require_user_verification && !user_verified {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_USER_NOT_VERIFIED));
+        }
+
+        // The signature covers authenticatorData || SHA-256(clientDataJSON)
+        let mut signed = Vec::with_capacity(authenticator_data.len() + 32);
+        signed.extend_from_slice(authenticator_data);
+        signed.extend_from_slice(&Sha256::digest(assertion.client_data_json));
+        let valid = match public_key {
+            WebAuthnPublicKey::Es256(key) => EcdsaSignature::from_der(assertion.signature)
+                .is_ok_and(|signature| key.verify(&signed, &signature).is_ok()),
+            WebAuthnPublicKey::EdDsa(key) => Ed25519Signature::from_slice(assertion.signature)
+                .is_ok_and(|signature| key.verify(&signed, &signature).is_ok()),
+        };
+        if !valid {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_INVALID_SIGNATURE));
+        }
+
+        // Authenticators without a counter always report zero
+        let sign_count = u32::from_be_bytes([authenticator_data[33], authenticator_data[34], authenticator_data[35], authenticator_data[36]]);
+        if (sign_count != 0 || stored_sign_count != 0) && sign_count <= stored_sign_count {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_COUNTER_REGRESSION));
+        }
+
+        Ok(WebAuthnVerifiedAssertion { sign_count, user_verified })
+    }
+
+    fn verify_client_data(client_data_json: &[u8], expected: &WebAuthnExpectations) -> CryptoResult<()> {
+        let client_data: Value = serde_json::from_slice(client_data_json)
+            .map_err(|_| CryptoError::InvalidInput(WEBAUTHN_INVALID_CLIENT_DATA))?;
+        let field = |name: &str| client_data.get(name)
+            .and_then(Value::as_str)
+            .ok_or(CryptoError::InvalidInput(WEBAUTHN_INVALID_CLIENT_DATA));
+
+        if field("type")? != "webauthn.get" {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_TYPE_MISMATCH));
+        }
+        let challenge = URL_SAFE_NO_PAD.decode(field("challenge")?.trim_end_matches('='))
+            .map_err(|_| CryptoError::InvalidInput(WEBAUTHN_INVALID_CLIENT_DATA))?;
+        if challenge != expected.challenge {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_CHALLENGE_MISMATCH));
+        }
+        if field("origin")? != expected.origin {
+            return Err(CryptoError::VerificationFailed(WEBAUTHN_ORIGIN_MISMATCH));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::{EcdsaKeyPair, Ed25519KeyPair};
+    use crate::core::keyformat::{COSE_KEY_KTY, COSE_KEY_ALG, COSE_KEY_CRV, COSE_KEY_X, COSE_KEY_Y};
+    #[cfg(feature = "rsa")]
+    use crate::core::keyformat::{COSE_KEY_N, COSE_KEY_E};
+    use ciborium::Value as CborValue;
+    use p256::ecdsa::signature::Signer;
+
+    const RP_ID: &str = "example.com";
+    const ORIGIN: &str = "https://example.com";
+    const CHALLENGE: &[u8] = b"server-issued-challenge";
+
+    fn authenticator_data(rp_id: &str, flags: u8, sign_count: u32) -> Vec<u8> {
+        let mut data = Sha256::digest(rp_id.as_bytes()).to_vec();
+        data.push(flags);
+        data.extend_from_slice(&sign_count.to_be_bytes());
+        data
+    }
+
+    fn client_data(kind: &str, challenge: &[u8], origin: &str) -> Vec<u8> {
+        serde_json::to_vec(&serde_json::json!({
+            "type": kind,
+            "challenge": URL_SAFE_NO_PAD.encode(challenge),
+            "origin": origin,
+            "crossOrigin": false,
+        })).unwrap()
+    }
+
+    fn signed_message(authenticator_data: &[u8], client_data_json: &[u8]) -> Vec<u8> {
+        [authenticator_data, Sha256::digest(client_data_json).as_slice()].concat()
+    }
+
+    fn cose_key(entries: Vec<(i128, CborValue)>) -> Vec<u8> {
+        let map = entries.into_iter()
+            .map(|(label, value)| (CborValue::Integer(label.try_into().unwrap()), value))
+            .collect();
+        let mut bytes = Vec::new();
+        ciborium::ser::into_writer(&CborValue::Map(map), &mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_webauthn_es256_assertion() {
+        let keypair = EcdsaKeyPair::generate().unwrap();
+        let point = keypair.verifying_key().to_encoded_point(false);
+        let public_key = WebAuthnPublicKey::from_cose(&cose_key(vec![
+            (COSE_KEY_KTY, CborValue::Integer(2.into())),
+            (COSE_KEY_ALG, CborValue::Integer((-7).into())),
+            (COSE_KEY_CRV, CborValue::Integer(1.into())),
+            (COSE_KEY_X, CborValue::Bytes(point.x().unwrap().to_vec())),
+            (COSE_KEY_Y, CborValue::Bytes(point.y().unwrap().to_vec())),
+        ])).unwrap();
+        assert_eq!(public_key, WebAuthnPublicKey::from_sec1(point.as_bytes()).unwrap());
+
+        let authenticator_data = authenticator_data(RP_ID, FLAG_USER_PRESENT | FLAG_USER_VERIFIED, 8);
+        let client_data_json = client_data("webauthn.get", CHALLENGE, ORIGIN);
+        let signature: EcdsaSignature = keypair.signing_key().sign(&signed_message(&authenticator_data, &client_data_json));
+        let signature = signature.to_der();
+        let assertion = WebAuthnAssertion {
+            authenticator_data: &authenticator_data,
+            client_data_json: &client_data_json,
+            signature: signature.as_bytes(),
+        };
+
+        let mut expected = WebAuthnExpectations::new(RP_ID, ORIGIN, CHALLENGE);
+        expected.require_user_verification = true;
+        let verified = WebAuthn::verify_assertion(&assertion, &public_key, &expected, 7).unwrap();
+        assert_eq!(verified, WebAuthnVerifiedAssertion { sign_count: 8, user_verified: true });
+
+        // A counter that did not advance suggests a cloned authenticator
+        assert_eq!(
+            WebAuthn::verify_assertion(&assertion, &public_key, &expected, 8).unwrap_err(),
+            CryptoError::VerificationFailed(WEBAUTHN_COUNTER_REGRESSION)
+        );
+
+        let other = WebAuthnPublicKey::Es256(*EcdsaKeyPair::generate().unwrap().verifying_key());
+        assert_eq!(
+            WebAuthn::verify_assertion(&assertion, &other, &expected, 0).unwrap_err(),
+            CryptoError::VerificationFailed(WEBAUTHN_INVALID_SIGNATURE)
+        );
+    }
+
+    #[test]
+    fn test_webauthn_eddsa_assertion() {
+        let keypair = Ed25519KeyPair::generate().unwrap();
+        let public_key = WebAuthnPublicKey::from_cose(&cose_key(vec![
+            (COSE_KEY_KTY, CborValue::Integer(1.into())),
+            (COSE_KEY_ALG, CborValue::Integer((-8).into())),
+            (COSE_KEY_CRV, CborValue::Integer(6.into())),
+            (COSE_KEY_X, CborValue::Bytes(keypair.verifying_key().to_bytes().to_vec())),
+        ])).unwrap();
+
+        // Counterless authenticators report zero on every assertion
+        let authenticator_data = authenticator_data(RP_ID, FLAG_USER_PRESENT, 0);
+        let client_data_json = client_data("webauthn.get", CHALLENGE, ORIGIN);
+        let signature = ed25519_dalek::Signer::sign(keypair.signing_key(), &signed_message(&authenticator_data, &client_data_json)).to_bytes();
+        let assertion = WebAuthnAssertion {
+            authenticator_data: &authenticator_data,
+            client_data_json: &client_data_json,
+            signature: &signature,
+        };
+
+        let mut expected = WebAuthnExpectations::new(RP_ID, ORIGIN, CHALLENGE);
+        let verified = WebAuthn::verify_assertion(&assertion, &public_key, &expected, 0).unwrap();
+        assert_eq!(verified, WebAuthnVerifiedAssertion { sign_count: 0, user_verified: false });
+
+        expected.require_user_verification = true;
+        assert_eq!(
+            WebAuthn::verify_assertion(&assertion, &public_key, &expected, 0).unwrap_err(),
+            CryptoError::VerificationFailed(WEBAUTHN_USER_NOT_VERIFIED)
+        );
+    }
+
+    #[test]
+    fn test_webauthn_rejects_mismatched_ceremony() {
+        let keypair = Ed25519KeyPair::generate().unwrap();
+        let public_key = WebAuthnPublicKey::EdDsa(*keypair.verifying_key());
+        let expected = WebAuthnExpectations::new(RP_ID, ORIGIN, CHALLENGE);
+        let check = |authenticator_data: Vec<u8>, client_data_json: Vec<u8>| {
+            let signature = ed25519_dalek::Signer::sign(keypair.signing_key(), &signed_message(&authenticator_data, &client_data_json)).to_bytes();
+            let assertion = WebAuthnAssertion {
+                authenticator_data: &authenticator_data,
+                client_data_json: &client_data_json,
+                signature: &signature,
+            };
+            WebAuthn::verify_assertion(&assertion, &public_key, &expected, 0).unwrap_err()
+        };
+
+        let good_auth = || authenticator_data(RP_ID, FLAG_USER_PRESENT, 1);
+        let good_client = || client_data("webauthn.get", CHALLENGE, ORIGIN);
+        assert_eq!(check(good_auth(), client_data("webauthn.create", CHALLENGE, ORIGIN)), CryptoError::VerificationFailed(WEBAUTHN_TYPE_MISMATCH));
+        assert_eq!(check(good_auth(), client_data("webauthn.get", b"stale", ORIGIN)), CryptoError::VerificationFailed(WEBAUTHN_CHALLENGE_MISMATCH));
+        assert_eq!(check(good_auth(), client_data("webauthn.get", CHALLENGE, "https://evil.example")), CryptoError::VerificationFailed(WEBAUTHN_ORIGIN_MISMATCH));
+        assert_eq!(check(authenticator_data("evil.example", FLAG_USER_PRESENT, 1), good_client()), CryptoError::VerificationFailed(WEBAUTHN_RP_ID_MISMATCH));
+        assert_eq!(check(authenticator_data(RP_ID, 0, 1), good_client()), CryptoError::VerificationFailed(WEBAUTHN_USER_NOT_PRESENT));
+        assert_eq!(check(good_auth()[..36].to_vec(), good_client()), CryptoError::InvalidInput(WEBAUTHN_INVALID_AUTHENTICATOR_DATA));
+
+        // Unsupported COSE algorithms (RS256) and malformed keys are rejected
+        assert!(WebAuthnPublicKey::from_cose(&cose_key(vec![(COSE_KEY_KTY, CborValue::Integer(3.into())), (COSE_KEY_ALG, CborValue::Integer((-257).into()))])).is_err());
+        assert_eq!(WebAuthnPublicKey::from_cose(b"\xff").unwrap_err().root(), &CryptoError::InvalidKey(WEBAUTHN_INVALID_PUBLIC_KEY));
+        #[cfg(feature = "rsa")]
+        {
+            let rsa = cose_key(vec![
+                (COSE_KEY_KTY, CborValue::Integer(3.into())),
+                (COSE_KEY_ALG, CborValue::Integer((-257).into())),
+                (COSE_KEY_N, CborValue::Bytes(vec![0xc5; 256])),
+                (COSE_KEY_E, CborValue::Bytes(vec![0x01, 0x00, 0x01])),
+            ]);
+            assert_eq!(WebAuthnPublicKey::from_cose(&rsa).unwrap_err(), CryptoError::InvalidKey(WEBAUTHN_UNSUPPORTED_ALGORITHM));
+        }
+    }
+}
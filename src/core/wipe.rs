@@ -0,0 +1,299 @@
+use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+#[cfg(feature = "std")]
+use crate::error::{CryptoError, CryptoResult, WIPE_FILE_FAILED};
+#[cfg(feature = "std")]
+use std::{fs::OpenOptions, io::Write, path::Path};
+
+// Zeros written per call by `wipe_file`
+#[cfg(feature = "std")]
+const WIPE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Overwrite `buffer` with zeros in a way the compiler will not optimize away
+#[inline]
+pub fn secure_wipe(buffer: &mut [u8]) {
+    buffer.zeroize();
+}
+
+/// Growable scratch buffer for plaintext temporaries, wiped when dropped
+///
+/// A plain `Vec` leaves a copy of its contents behind in freed memory every
+/// time it grows, and [`Zeroizing`](zeroize::Zeroizing) only wipes the last
+/// allocation. `SecureVec` copies into the new allocation itself and wipes
+/// the old one, wipes bytes removed by [`SecureVec::truncate`] and
+/// [`SecureVec::clear`], and never prints its contents.
+///
+/// ```rust
+/// use libsilver::prelude::*;
+///
+/// let mut scratch = SecureVec::with_capacity(16);
+/// scratch.extend_from_slice(b"decrypted ");
+/// scratch.extend_from_slice(b"record that outgrows the first allocation");
+/// assert!(scratch.starts_with(b"decrypted record"));
+/// ```
+#[derive(Default)]
+pub struct SecureVec {
+    data: Vec<u8>,
+}
+
+impl SecureVec {
+    /// An empty buffer; nothing is allocated until the first write
+    #[inline]
+    pub fn new() -> Self {
+        Self { data: Vec::new() }
+    }
+
+    /// An empty buffer with room for `capacity` bytes before it reallocates
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self { data: Vec::with_capacity(capacity) }
+    }
+
+    /// Bytes held
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Whether the buffer holds no bytes
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Bytes the buffer can hold before it reallocates
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.data.capacity()
+    }
+
+    /// Make room for `additional` more bytes, wiping the old allocation if it moves
+    pub fn reserve(&mut self, additional: usize) {
+        let needed = self.data.len().checked_add(additional).expect("capacity overflow");
+        if needed <= self.data.capacity() {
+            return;
+        }
+
+        let mut grown = Vec::with_capacity(needed.max(self.data.capacity().saturating_mul(2)));
+        grown.extend_from_slice(&self.data);
+        let mut old = core::mem::replace(&mut self.data, grown);
+        old.zeroize();
+    }
+
+    /// Append `bytes`
+    #[inline]
+    pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+        self.reserve(bytes.len());
+        self.data.extend_from_slice(bytes);
+    }
+
+    /// Append one byte
+    #[inline]
+    pub fn push(&mut self, byte: u8) {
+        self.reserve(1);
+        self.data.push(byte);
+    }
+
+    /// Resize to `len` bytes, filling new bytes with `value` and wiping removed ones
+    pub fn resize(&mut self, len: usize, value: u8) {
+        if len <= self.data.len() {
+            self.truncate(len);
+        } else {
+            self.reserve(len - self.data.len());
+            self.data.resize(len, value);
+        }
+    }
+
+    /// Keep the first `len` bytes and wipe the rest
+    #[inline]
+    pub fn truncate(&mut self, len: usize) {
+        if let Some(tail) = self.data.get_mut(len..) {
+            tail.zeroize();
+        }
+        self.data.truncate(len);
+    }
+
+    /// Wipe and remove every byte, keeping the allocation
+    #[inline]
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// The contents
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The contents, mutably
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl From<&[u8]> for SecureVec {
+    fn from(bytes: &[u8]) -> Self {
+        Self { data: bytes.to_vec() }
+    }
+}
+
+// Takes ownership of the allocation, so nothing is copied
+impl From<Vec<u8>> for SecureVec {
+    fn from(data: Vec<u8>) -> Self {
+        Self { data }
+    }
+}
+
+impl Deref for SecureVec {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl DerefMut for SecureVec {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+impl AsRef<[u8]> for SecureVec {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.data
+    }
+}
+
+impl AsMut<[u8]> for SecureVec {
+    #[inline]
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+}
+
+// Never print the contents
+impl core::fmt::Debug for SecureVec {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecureVec").field("len", &self.data.len()).finish_non_exhaustive()
+    }
+}
+
+impl Drop for SecureVec {
+    fn drop(&mut self) {
+        self.data.zeroize();
+    }
+}
+
+impl ZeroizeOnDrop for SecureVec {}
+
+/// Overwrite a regular file with zeros, flush it to disk, truncate it and delete it
+///
+/// Best effort only: on SSDs (wear levelling), copy-on-write or journaling
+/// filesystems (btrfs, ZFS, APFS), snapshots and backups, the old blocks may
+/// survive the overwrite. Where that matters, encrypt temporaries under a key
+/// that never leaves memory instead, and wipe the key.
+#[cfg(feature = "std")]
+pub fn wipe_file(path: impl AsRef<Path>) -> CryptoResult<()> {
+    let path = path.as_ref();
+    let failed = |err: std::io::Error| CryptoError::IoFailed(WIPE_FILE_FAILED).caused_by(err);
+
+    // Never follow a symlink to wipe its target, and never wipe a device
+    if !std::fs::symlink_metadata(path).map_err(failed)?.is_file() {
+        return Err(CryptoError::IoFailed(WIPE_FILE_FAILED));
+    }
+
+    // The path may be swapped for a symlink after the check above: refuse to
+    // follow one at open time, then check what was actually opened
+    let mut options = OpenOptions::new();
+    options.write(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::custom_flags(&mut options, libc::O_NOFOLLOW);
+    let mut file = options.open(path).map_err(failed)?;
+    let metadata = file.metadata().map_err(failed)?;
+    if !metadata.is_file() {
+        return Err(CryptoError::IoFailed(WIPE_FILE_FAILED));
+    }
+
+    let zeros = [0u8; WIPE_CHUNK_SIZE];
+    let mut remaining = metadata.len();
+    while remaining > 0 {
+        let chunk = usize::try_from(remaining).map_or(WIPE_CHUNK_SIZE, |remaining| remaining.min(WIPE_CHUNK_SIZE));
+        file.write_all(&zeros[..chunk]).map_err(failed)?;
+        remaining -= chunk as u64;
+    }
+    file.sync_all().map_err(failed)?;
+    file.set_len(0).map_err(failed)?;
+    file.sync_all().map_err(failed)?;
+    drop(file);
+
+    std::fs::remove_file(path).map_err(failed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secure_vec() {
+        let mut buffer = [0xaau8; 8];
+        secure_wipe(&mut buffer[2..]);
+        assert_eq!(buffer, [0xaa, 0xaa, 0, 0, 0, 0, 0, 0]);
+
+        let mut scratch = SecureVec::with_capacity(4);
+        assert!(scratch.is_empty());
+        scratch.extend_from_slice(b"abc");
+        scratch.push(b'd');
+        assert_eq!(scratch.capacity(), 4);
+        scratch.extend_from_slice(b"efgh");
+        assert!(scratch.capacity() >= 8);
+        assert_eq!(scratch.as_slice(), b"abcdefgh");
+
+        scratch.truncate(3);
+        assert_eq!(&*scratch, b"abc");
+        scratch.resize(5, b'x');
+        assert_eq!(scratch.as_ref(), b"abcxx");
+        scratch.as_mut_slice()[0] = b'A';
+        scratch.resize(1, 0);
+        assert_eq!(scratch.as_ref(), b"A");
+        scratch.clear();
+        assert!(scratch.is_empty());
+        assert!(scratch.capacity() >= 8);
+
+        assert_eq!(SecureVec::from(&b"secret"[..]).as_slice(), b"secret");
+        assert_eq!(SecureVec::from(b"secret".to_vec()).len(), 6);
+        assert_eq!(format!("{:?}", SecureVec::from(&b"secret"[..])), "SecureVec { len: 6, .. }");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_wipe_file() {
+        use crate::core::random::SecureRandom;
+
+        let path = std::env::temp_dir().join(format!("libsilver-wipe-{}", SecureRandom::token_hex(8).unwrap()));
+        std::fs::write(&path, SecureRandom::generate_bytes(WIPE_CHUNK_SIZE * 2 + 17).unwrap()).unwrap();
+        wipe_file(&path).unwrap();
+        assert!(!path.exists());
+        assert_eq!(wipe_file(&path), Err(CryptoError::IoFailed(WIPE_FILE_FAILED)));
+
+        // Directories are refused
+        assert_eq!(wipe_file(std::env::temp_dir()), Err(CryptoError::IoFailed(WIPE_FILE_FAILED)));
+
+        // Symlinks are refused and their targets left alone
+        #[cfg(unix)]
+        {
+            let target = path.with_extension("target");
+            let link = path.with_extension("link");
+            std::fs::write(&target, b"keep me").unwrap();
+            std::os::unix::fs::symlink(&target, &link).unwrap();
+            assert_eq!(wipe_file(&link), Err(CryptoError::IoFailed(WIPE_FILE_FAILED)));
+            assert_eq!(std::fs::read(&target).unwrap(), b"keep me");
+            std::fs::remove_file(&link).unwrap();
+            std::fs::remove_file(&target).unwrap();
+        }
+    }
+}
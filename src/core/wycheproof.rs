@@ -0,0 +1,394 @@
+//! Google Wycheproof test-vector harness (`wycheproof` feature)
+//!
+//! Runs the JSON files from the Wycheproof project
+//! (<https://github.com/C2SP/wycheproof>, `testvectors_v1/`) against
+//! libsilver's public APIs. Every test case is marked `valid`, `invalid` or
+//! `acceptable`: valid cases must decrypt to `msg` or verify, invalid cases
+//! must be rejected, and acceptable cases may go either way.
+//!
+//! | file | cases checked |
+//! |---|---|
+//! | `aes_gcm_test.json` | 256-bit key, 96-bit IV, 128-bit tag |
+//! | `chacha20_poly1305_test.json` | all |
+//! | `rsa_oaep_2048_sha256_mgf1sha256_test.json` | empty label |
+//! | `ecdsa_secp256r1_sha256_test.json` (DER signatures) | all |
+//! | `ecdsa_secp256r1_sha256_p1363_test.json` (`r \|\| s` signatures) | all |
+//! | `ed25519_test.json` | all |
+//!
+//! Cases libsilver has no API for (other key, IV or tag sizes, OAEP labels,
+//! other curves and hashes) are counted as skipped rather than failed.
+
+use crate::error::{CryptoError, CryptoResult, WYCHEPROOF_INVALID_FORMAT, WYCHEPROOF_READ_FAILED, INVALID_SIGNATURE_FORMAT};
+use crate::core::asymmetric::{EcdsaCrypto, Ed25519Crypto, RsaCrypto, RsaKeyPair};
+use crate::core::verifier::{EcdsaVerifierContext, Ed25519VerifierContext};
+use crate::core::symmetric::{Aead, AesGcm, ChaCha20Poly1305Cipher};
+use p256::ecdsa::Signature as EcdsaSignature;
+use serde_json::Value;
+use std::path::Path;
+
+/// Wycheproof files [`Wycheproof::run_file`] understands
+pub const WYCHEPROOF_FILES: &[&str] = &[
+    "aes_gcm_test.json",
+    "chacha20_poly1305_test.json",
+    "rsa_oaep_2048_sha256_mgf1sha256_test.json",
+    "ecdsa_secp256r1_sha256_test.json",
+    "ecdsa_secp256r1_sha256_p1363_test.json",
+    "ed25519_test.json",
+];
+
+/// Files from [`WYCHEPROOF_FILES`] checked in under `test-vectors/wycheproof/`
+///
+/// They hold the upstream cases RustCrypto vendors for its own crates, so
+/// they run without a Wycheproof checkout.
+pub const WYCHEPROOF_VENDORED_FILES: &[&str] = &[
+    "aes_gcm_test.json",
+    "chacha20_poly1305_test.json",
+    "ecdsa_secp256r1_sha256_test.json",
+];
+
+/// A Wycheproof case libsilver got wrong
+#[derive(Debug, Clone, PartialEq)]
+pub struct WycheproofFailure {
+    /// Wycheproof test case ID
+    pub tc_id: u64,
+    /// Wycheproof's description of the case
+    pub comment: String,
+    /// Expected result: `valid`, `invalid` or `acceptable`
+    pub result: String,
+    /// What libsilver did instead
+    pub reason: String,
+}
+
+/// Outcome of running one Wycheproof file
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WycheproofReport {
+    /// Cases that behaved as expected
+    pub passed: usize,
+    /// Cases outside what libsilver supports
+    pub skipped: usize,
+    /// Cases that did not
+    pub failures: Vec<WycheproofFailure>,
+}
+
+/// Run Google Wycheproof vectors against libsilver
+pub struct Wycheproof;
+
+impl Wycheproof {
+    /// Run every test group in a Wycheproof JSON document
+    pub fn run_json(json: &str) -> CryptoResult<WycheproofReport> {
+        let document: Value = serde_json::from_str(json)
+            .map_err(|_| CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT))?;
+        let algorithm = document.get("algorithm").and_then(Value::as_str).unwrap_or_default();
+        let groups = document.get("testGroups").and_then(Value::as_array)
+            .ok_or(CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT))?;
+
+        let mut report = WycheproofReport::default();
+        for group in groups {
+            let tests = group.get("tests").and_then(Value::as_array)
+                .ok_or(CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT))?;
+            let checker = Checker::new(algorithm, group)?;
+
+            for test in tests {
+                let result = string(test, "result")?;
+                if !matches!(result, "valid" | "invalid" | "acceptable") {
+                    return Err(CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT));
+                }
+
+                let reason = match checker.check(test)? {
+                    Verdict::Skipped => {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    Verdict::Mismatch => "output mismatch".to_string(),
+                    Verdict::Accepted if result != "invalid" => {
+                        report.passed += 1;
+                        continue;
+                    }
+                    Verdict::Rejected(_) if result != "valid" => {
+                        report.passed += 1;
+                        continue;
+                    }
+                    Verdict::Accepted => "accepted invalid case".to_string(),
+                    Verdict::Rejected(reason) => format!("rejected valid case: {reason}"),
+                };
+                report.failures.push(WycheproofFailure {
+                    tc_id: test.get("tcId").and_then(Value::as_u64).unwrap_or_default(),
+                    comment: test.get("comment").and_then(Value::as_str).unwrap_or_default().to_string(),
+                    result: result.to_string(),
+                    reason,
+                });
+            }
+        }
+        Ok(report)
+    }
+
+    /// Read and run a Wycheproof JSON file
+    pub fn run_file<P: AsRef<Path>>(path: P) -> CryptoResult<WycheproofReport> {
+        let json = std::fs::read_to_string(path)
+            .map_err(|_| CryptoError::IoFailed(WYCHEPROOF_READ_FAILED))?;
+        Self::run_json(&json)
+    }
+}
+
+// What libsilver did with one case
+enum Verdict {
+    Accepted,
+    Rejected(String),
+    // Decrypted, but not to the expected plaintext or ciphertext
+    Mismatch,
+    Skipped,
+}
+
+// A test group with its key imported once
+enum Checker {
+    Aead(&'static dyn Aead),
+    // A key libsilver refuses to import rejects every case in the group
+    RsaOaep(CryptoResult<Box<RsaKeyPair>>),
+    // Signatures are checked with and without the precomputed verifier context
+    Ecdsa { key: CryptoResult<Box<EcdsaVerifierContext>>, der: bool },
+    Ed25519(CryptoResult<Box<Ed25519VerifierContext>>),
+    Unsupported,
+}
+
+impl Checker {
+    fn new(algorithm: &str, group: &Value) -> CryptoResult<Self> {
+        let checker = match (string(group, "type")?, algorithm) {
+            ("AeadTest", "AES-GCM") => Checker::Aead(&AesGcm),
+            ("AeadTest", "CHACHA20-POLY1305") => Checker::Aead(&ChaCha20Poly1305Cipher),
+            ("RsaesOaepDecrypt", _) if string(group, "sha")? == "SHA-256" && string(group, "mgfSha")? == "SHA-256" => {
+                Checker::RsaOaep(RsaKeyPair::from_private_key_der(&bytes(group, "privateKeyPkcs8")?).map(Box::new))
+            }
+            (kind @ ("EcdsaVerify" | "EcdsaP1363Verify"), _) if string(group, "sha")? == "SHA-256" => {
+                let key = public_key(group)?;
+                if string(key, "curve")? != "secp256r1" {
+                    return Ok(Checker::Unsupported);
+                }
+                Checker::Ecdsa {
+                    key: EcdsaVerifierContext::from_bytes(&bytes(key, "uncompressed")?).map(Box::new),
+                    der: kind == "EcdsaVerify",
+                }
+            }
+            ("EddsaVerify", _) => {
+                let key = public_key(group)?;
+                if string(key, "curve")? != "edwards25519" {
+                    return Ok(Checker::Unsupported);
+                }
+                Checker::Ed25519(Ed25519VerifierContext::from_bytes(&bytes(key, "pk")?).map(Box::new))
+            }
+            _ => Checker::Unsupported,
+        };
+        Ok(checker)
+    }
+
+    fn check(&self, test: &Value) -> CryptoResult<Verdict> {
+        match self {
+            Checker::Aead(cipher) => {
+                let (key, iv, aad, tag) = (bytes(test, "key")?, bytes(test, "iv")?, bytes(test, "aad")?, bytes(test, "tag")?);
+                if key.len() != cipher.key_size() || iv.len() != cipher.nonce_size() || tag.len() != cipher.tag_size() {
+                    return Ok(Verdict::Skipped);
+                }
+
+                let sealed = [iv.as_slice(), &bytes(test, "ct")?, &tag].concat();
+                let msg = bytes(test, "msg")?;
+                Ok(match cipher.decrypt_with_aad(&sealed, &key, &aad) {
+                    Err(err) => Verdict::Rejected(err.to_string()),
+                    Ok(plaintext) if plaintext != msg => Verdict::Mismatch,
+                    // Encrypting must reproduce the ciphertext too
+                    Ok(_) if cipher.seal_with_nonce(&msg, &key, &iv, &aad).ok() != Some(sealed) => Verdict::Mismatch,
+                    Ok(_) => Verdict::Accepted,
+                })
+            }
+            Checker::RsaOaep(keypair) => {
+                if !bytes(test, "label")?.is_empty() {
+                    return Ok(Verdict::Skipped);
+                }
+                let keypair = match keypair {
+                    Ok(keypair) => keypair,
+                    Err(err) => return Ok(Verdict::Rejected(err.to_string())),
+                };
+                Ok(match RsaCrypto::decrypt(&bytes(test, "ct")?, keypair.private_key()) {
+                    Err(err) => Verdict::Rejected(err.to_string()),
+                    Ok(plaintext) if plaintext != bytes(test, "msg")? => Verdict::Mismatch,
+                    Ok(_) => Verdict::Accepted,
+                })
+            }
+            Checker::Ecdsa { key, der } => {
+                let key = match key {
+                    Ok(key) => key,
+                    Err(err) => return Ok(Verdict::Rejected(err.to_string())),
+                };
+                let mut signature = bytes(test, "sig")?;
+                if *der {
+                    // libsilver signatures are `r || s`; strict DER parsing is part of the check
+                    signature = match EcdsaSignature::from_der(&signature) {
+                        Ok(signature) => signature.to_bytes().to_vec(),
+                        Err(_) => return Ok(Verdict::Rejected(INVALID_SIGNATURE_FORMAT.to_string())),
+                    };
+                }
+                let msg = bytes(test, "msg")?;
+                let verified = EcdsaCrypto::verify(&msg, &signature, key.verifying_key());
+                if key.verify(&msg, &signature) != verified {
+                    return Ok(Verdict::Mismatch);
+                }
+                Ok(verdict(verified))
+            }
+            Checker::Ed25519(key) => {
+                let key = match key {
+                    Ok(key) => key,
+                    Err(err) => return Ok(Verdict::Rejected(err.to_string())),
+                };
+                let (msg, signature) = (bytes(test, "msg")?, bytes(test, "sig")?);
+                let verified = Ed25519Crypto::verify(&msg, &signature, key.verifying_key());
+                if key.verify(&msg, &signature) != verified {
+                    return Ok(Verdict::Mismatch);
+                }
+                Ok(verdict(verified))
+            }
+            Checker::Unsupported => Ok(Verdict::Skipped),
+        }
+    }
+}
+
+#[inline]
+fn verdict(verified: CryptoResult<bool>) -> Verdict {
+    match verified {
+        Ok(true) => Verdict::Accepted,
+        Ok(false) => Verdict::Rejected("signature mismatch".to_string()),
+        Err(err) => Verdict::Rejected(err.to_string()),
+    }
+}
+
+// Newer files name the group key `publicKey`, older ones `key`
+fn public_key(group: &Value) -> CryptoResult<&Value> {
+    group.get("publicKey").or_else(|| group.get("key"))
+        .ok_or(CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT))
+}
+
+fn string<'a>(value: &'a Value, name: &str) -> CryptoResult<&'a str> {
+    value.get(name).and_then(Value::as_str)
+        .ok_or(CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT))
+}
+
+fn bytes(value: &Value, name: &str) -> CryptoResult<Vec<u8>> {
+    hex::decode(string(value, name)?).map_err(|_| CryptoError::InvalidInput(WYCHEPROOF_INVALID_FORMAT))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::{EcdsaKeyPair, Ed25519KeyPair};
+    use serde_json::json;
+
+    fn case(tc_id: u64, result: &str, fields: Value) -> Value {
+        let mut case = json!({ "tcId": tc_id, "comment": "", "flags": [], "result": result });
+        case.as_object_mut().unwrap().extend(fields.as_object().unwrap().clone());
+        case
+    }
+
+    #[test]
+    fn test_aead_groups() {
+        let key = AesGcm::generate_key().unwrap();
+        let iv = [7u8; 12];
+        let sealed = AesGcm.seal_with_nonce(b"wycheproof", key.as_bytes(), &iv, b"aad").unwrap();
+        let (ct, tag) = sealed[12..].split_at(sealed.len() - 28);
+        let mut bad_tag = tag.to_vec();
+        bad_tag[0] ^= 1;
+
+        let aead = |tc_id, result, tag: &[u8]| case(tc_id, result, json!({
+            "key": hex::encode(key.as_bytes()), "iv": hex::encode(iv), "aad": hex::encode(b"aad"),
+            "msg": hex::encode(b"wycheproof"), "ct": hex::encode(ct), "tag": hex::encode(tag),
+        }));
+        let document = json!({
+            "algorithm": "AES-GCM",
+            "testGroups": [
+                { "type": "AeadTest", "keySize": 256, "ivSize": 96, "tagSize": 128, "tests": [aead(1, "valid", tag), aead(2, "invalid", &bad_tag)] },
+                { "type": "AeadTest", "keySize": 256, "ivSize": 96, "tagSize": 64, "tests": [aead(3, "invalid", &tag[..8])] },
+            ],
+        });
+        let report = Wycheproof::run_json(&document.to_string()).unwrap();
+        assert_eq!((report.passed, report.skipped), (2, 1));
+        assert!(report.failures.is_empty());
+
+        // A vector libsilver disagrees with is reported, not swallowed
+        let document = json!({
+            "algorithm": "AES-GCM",
+            "testGroups": [{ "type": "AeadTest", "tests": [aead(4, "invalid", tag), aead(5, "valid", &bad_tag)] }],
+        });
+        let report = Wycheproof::run_json(&document.to_string()).unwrap();
+        assert_eq!(report.failures.len(), 2);
+        assert_eq!(report.failures[0].tc_id, 4);
+        assert_eq!(report.failures[0].reason, "accepted invalid case");
+        assert!(report.failures[1].reason.starts_with("rejected valid case"));
+    }
+
+    #[test]
+    fn test_signature_groups() {
+        let ed25519 = Ed25519KeyPair::generate().unwrap();
+        let signature = Ed25519Crypto::sign(b"msg", ed25519.signing_key()).unwrap();
+        let ecdsa = EcdsaKeyPair::generate().unwrap();
+        let p1363 = EcdsaCrypto::sign(b"msg", ecdsa.signing_key()).unwrap();
+        let der = EcdsaSignature::from_slice(&p1363).unwrap().to_der().as_bytes().to_vec();
+
+        let sig = |tc_id, result, sig: &[u8]| case(tc_id, result, json!({ "msg": hex::encode(b"msg"), "sig": hex::encode(sig) }));
+        let ecdsa_key = json!({ "curve": "secp256r1", "uncompressed": hex::encode(ecdsa.public_key_bytes()) });
+        let documents = [
+            json!({ "algorithm": "EDDSA", "testGroups": [{
+                "type": "EddsaVerify",
+                "publicKey": { "curve": "edwards25519", "pk": hex::encode(ed25519.public_key_bytes()) },
+                "tests": [sig(1, "valid", &signature), sig(2, "invalid", &signature[..63]), sig(3, "invalid", &[signature[32..].to_vec(), signature[..32].to_vec()].concat())],
+            }] }),
+            json!({ "algorithm": "ECDSA", "testGroups": [
+                { "type": "EcdsaP1363Verify", "sha": "SHA-256", "publicKey": ecdsa_key, "tests": [sig(1, "valid", &p1363), sig(2, "invalid", &[0u8; 64])] },
+                { "type": "EcdsaVerify", "sha": "SHA-256", "publicKey": ecdsa_key, "tests": [sig(3, "valid", &der), sig(4, "invalid", &[der.clone(), vec![0]].concat())] },
+                { "type": "EcdsaVerify", "sha": "SHA-512", "publicKey": ecdsa_key, "tests": [sig(5, "valid", &der)] },
+                // Invalid public key: every case must be rejected
+                { "type": "EcdsaVerify", "sha": "SHA-256", "publicKey": { "curve": "secp256r1", "uncompressed": "04" }, "tests": [sig(6, "invalid", &der)] },
+            ] }),
+        ];
+
+        let reports: Vec<_> = documents.iter().map(|document| Wycheproof::run_json(&document.to_string()).unwrap()).collect();
+        assert_eq!((reports[0].passed, reports[0].skipped), (3, 0));
+        assert_eq!((reports[1].passed, reports[1].skipped), (5, 1));
+        assert!(reports.iter().all(|report| report.failures.is_empty()));
+    }
+
+    #[test]
+    fn test_rsa_oaep_group() {
+        let keypair = RsaKeyPair::generate(2048).unwrap();
+        let ct = RsaCrypto::encrypt(b"msg", keypair.public_key()).unwrap();
+        let mut bad_ct = ct.clone();
+        bad_ct[10] ^= 1;
+
+        let oaep = |tc_id, result, ct: &[u8], label: &str| case(tc_id, result, json!({ "msg": hex::encode(b"msg"), "ct": hex::encode(ct), "label": label }));
+        let document = json!({ "algorithm": "RSAES-OAEP", "testGroups": [{
+            "type": "RsaesOaepDecrypt", "sha": "SHA-256", "mgf": "MGF1", "mgfSha": "SHA-256",
+            "privateKeyPkcs8": hex::encode(keypair.private_key_der().unwrap()),
+            "tests": [oaep(1, "valid", &ct, ""), oaep(2, "invalid", &bad_ct, ""), oaep(3, "valid", &ct, "0102")],
+        }] });
+        let report = Wycheproof::run_json(&document.to_string()).unwrap();
+        assert_eq!((report.passed, report.skipped), (2, 1));
+        assert!(report.failures.is_empty());
+    }
+
+    // Always runs; a missing file fails rather than being skipped
+    #[test]
+    fn test_vendored_vectors() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("test-vectors/wycheproof");
+        for file in WYCHEPROOF_VENDORED_FILES {
+            let report = Wycheproof::run_file(dir.join(file)).unwrap_or_else(|err| panic!("{file}: {err}"));
+            assert!(report.failures.is_empty(), "{file}: {:#?}", report.failures);
+            assert!(report.passed > 0, "{file}");
+        }
+    }
+
+    #[test]
+    fn test_malformed_documents() {
+        assert!(Wycheproof::run_json("not json").is_err());
+        assert!(Wycheproof::run_json("{\"algorithm\": \"AES-GCM\"}").is_err());
+        assert!(Wycheproof::run_json("{\"testGroups\": [{\"type\": \"AeadTest\", \"tests\": [{\"result\": \"maybe\"}]}]}").is_err());
+        assert!(Wycheproof::run_file("/nonexistent/aes_gcm_test.json").is_err());
+
+        let report = Wycheproof::run_json("{\"algorithm\": \"AES-CCM\", \"testGroups\": [{\"type\": \"AeadTest\", \"tests\": [{\"result\": \"valid\"}]}]}").unwrap();
+        assert_eq!(report.skipped, 1);
+    }
+}
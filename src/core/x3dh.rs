@@ -0,0 +1,437 @@
+use crate::error::{CryptoError, CryptoResult, X3DH_INVALID_BUNDLE, X3DH_INVALID_MESSAGE, X3DH_INVALID_SIGNATURE, X3DH_PREKEY_MISMATCH, X3DH_WEAK_KEY, HKDF_SHA256_FAILED};
+use crate::core::asymmetric::{Ed25519Crypto, Ed25519KeyPair};
+use crate::core::random::SecureKey;
+use hkdf::Hkdf;
+use rand::rngs::OsRng;
+use sha2::Sha256;
+use x25519_dalek::{PublicKey as X25519PublicKey, StaticSecret};
+use zeroize::Zeroizing;
+
+const X3DH_INFO: &[u8] = b"libsilver-X3DH";
+const KEY_SIZE: usize = 32;
+const SIGNATURE_SIZE: usize = 64;
+
+/// A medium-term X25519 prekey signed by the owner's identity key
+pub struct X3dhSignedPrekey {
+    id: u32,
+    secret: StaticSecret,
+    public: X25519PublicKey,
+    signature: [u8; SIGNATURE_SIZE],
+}
+
+impl X3dhSignedPrekey {
+    /// Get the prekey ID
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Get the X25519 public key
+    #[inline]
+    pub fn public_key_bytes(&self) -> [u8; KEY_SIZE] {
+        self.public.to_bytes()
+    }
+
+    /// Serialize for storage: id (4) | secret (32) | signature (64)
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(Vec::with_capacity(4 + KEY_SIZE + SIGNATURE_SIZE));
+        out.extend_from_slice(&self.id.to_be_bytes());
+        out.extend_from_slice(self.secret.as_bytes());
+        out.extend_from_slice(&self.signature);
+        out
+    }
+
+    /// Restore a prekey serialized with [`X3dhSignedPrekey::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != 4 + KEY_SIZE + SIGNATURE_SIZE {
+            return Err(CryptoError::InvalidKey(X3DH_INVALID_BUNDLE));
+        }
+
+        let secret = StaticSecret::from(read_key(&bytes[4..4 + KEY_SIZE]));
+        Ok(Self {
+            id: read_u32(&bytes[..4]),
+            public: X25519PublicKey::from(&secret),
+            secret,
+            signature: bytes[4 + KEY_SIZE..].try_into().unwrap(),
+        })
+    }
+
+    pub(crate) fn secret(&self) -> &StaticSecret {
+        &self.secret
+    }
+}
+
+/// A single-use X25519 prekey
+pub struct X3dhOneTimePrekey {
+    id: u32,
+    secret: StaticSecret,
+}
+
+impl X3dhOneTimePrekey {
+    /// Get the prekey ID
+    #[inline]
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Get the X25519 public key
+    #[inline]
+    pub fn public_key_bytes(&self) -> [u8; KEY_SIZE] {
+        X25519PublicKey::from(&self.secret).to_bytes()
+    }
+
+    /// Serialize for storage: id (4) | secret (32)
+    pub fn to_bytes(&self) -> Zeroizing<Vec<u8>> {
+        let mut out = Zeroizing::new(Vec::with_capacity(4 + KEY_SIZE));
+        out.extend_from_slice(&self.id.to_be_bytes());
+        out.extend_from_slice(self.secret.as_bytes());
+        out
+    }
+
+    /// Restore a prekey serialized with [`X3dhOneTimePrekey::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        if bytes.len() != 4 + KEY_SIZE {
+            return Err(CryptoError::InvalidKey(X3DH_INVALID_BUNDLE));
+        }
+
+        Ok(Self {
+            id: read_u32(&bytes[..4]),
+            secret: StaticSecret::from(read_key(&bytes[4..])),
+        })
+    }
+}
+
+/// The public keys a responder publishes so initiators can start sessions offline
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X3dhPrekeyBundle {
+    /// Ed25519 identity public key
+    pub identity_key: [u8; KEY_SIZE],
+    /// Signed prekey ID
+    pub signed_prekey_id: u32,
+    /// X25519 signed prekey
+    pub signed_prekey: [u8; KEY_SIZE],
+    /// Ed25519 signature over `signed_prekey` by `identity_key`
+    pub signed_prekey_signature: [u8; SIGNATURE_SIZE],
+    /// Optional one-time prekey ID and X25519 public key
+    pub one_time_prekey: Option<(u32, [u8; KEY_SIZE])>,
+}
+
+impl X3dhPrekeyBundle {
+    /// Serialize: identity (32) | spk id (4) | spk (32) | signature (64) | has opk (1) | [opk id (4) | opk (32)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(KEY_SIZE + 4 + KEY_SIZE + SIGNATURE_SIZE + 1 + 4 + KEY_SIZE);
+        out.extend_from_slice(&self.identity_key);
+        out.extend_from_slice(&self.signed_prekey_id.to_be_bytes());
+        out.extend_from_slice(&self.signed_prekey);
+        out.extend_from_slice(&self.signed_prekey_signature);
+        write_optional_prekey(&mut out, self.one_time_prekey.map(|(id, key)| (id, Some(key))));
+        out
+    }
+
+    /// Parse a bundle serialized with [`X3dhPrekeyBundle::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        const FIXED: usize = KEY_SIZE + 4 + KEY_SIZE + SIGNATURE_SIZE + 1;
+        if bytes.len() != FIXED && bytes.len() != FIXED + 4 + KEY_SIZE {
+            return Err(CryptoError::InvalidInput(X3DH_INVALID_BUNDLE));
+        }
+
+        let one_time_prekey = match (bytes[FIXED - 1], bytes.len() > FIXED) {
+            (0, false) => None,
+            (1, true) => Some((read_u32(&bytes[FIXED..FIXED + 4]), read_key(&bytes[FIXED + 4..]))),
+            _ => return Err(CryptoError::InvalidInput(X3DH_INVALID_BUNDLE)),
+        };
+
+        Ok(Self {
+            identity_key: read_key(&bytes[..32]),
+            signed_prekey_id: read_u32(&bytes[32..36]),
+            signed_prekey: read_key(&bytes[36..68]),
+            signed_prekey_signature: bytes[68..132].try_into().unwrap(),
+            one_time_prekey,
+        })
+    }
+}
+
+/// The initiator's first message header, sent alongside the first ciphertext
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct X3dhInitialMessage {
+    /// Initiator's Ed25519 identity public key
+    pub identity_key: [u8; KEY_SIZE],
+    /// Initiator's X25519 ephemeral public key
+    pub ephemeral_key: [u8; KEY_SIZE],
+    /// ID of the responder's signed prekey that was used
+    pub signed_prekey_id: u32,
+    /// ID of the responder's one-time prekey that was used, if any
+    pub one_time_prekey_id: Option<u32>,
+}
+
+impl X3dhInitialMessage {
+    /// Serialize: identity (32) | ephemeral (32) | spk id (4) | has opk (1) | [opk id (4)]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(KEY_SIZE * 2 + 4 + 1 + 4);
+        out.extend_from_slice(&self.identity_key);
+        out.extend_from_slice(&self.ephemeral_key);
+        out.extend_from_slice(&self.signed_prekey_id.to_be_bytes());
+        write_optional_prekey(&mut out, self.one_time_prekey_id.map(|id| (id, None)));
+        out
+    }
+
+    /// Parse a message serialized with [`X3dhInitialMessage::to_bytes`]
+    pub fn from_bytes(bytes: &[u8]) -> CryptoResult<Self> {
+        const FIXED: usize = KEY_SIZE * 2 + 4 + 1;
+        let one_time_prekey_id = match (bytes.get(FIXED - 1), bytes.len()) {
+            (Some(0), FIXED) => None,
+            (Some(1), len) if len == FIXED + 4 => Some(read_u32(&bytes[FIXED..])),
+            _ => return Err(CryptoError::InvalidInput(X3DH_INVALID_MESSAGE)),
+        };
+
+        Ok(Self {
+            identity_key: read_key(&bytes[..32]),
+            ephemeral_key: read_key(&bytes[32..64]),
+            signed_prekey_id: read_u32(&bytes[64..68]),
+            one_time_prekey_id,
+        })
+    }
+}
+
+/// Result of an X3DH key agreement
+#[derive(Debug)]
+pub struct X3dhOutput {
+    /// 32-byte shared secret, e.g. the root key for a [`crate::core::ratchet::DoubleRatchet`]
+    pub shared_key: SecureKey,
+    /// `initiator identity || responder identity`, to be bound as AAD to every message
+    pub associated_data: Vec<u8>,
+}
+
+/// X3DH (Extended Triple Diffie-Hellman) initial key agreement
+///
+/// Identity keys are libsilver [`Ed25519KeyPair`]s; their X25519 form is derived
+/// with the standard Edwards-to-Montgomery map, so one key both signs prekeys
+/// and takes part in the key agreement.
+pub struct X3dh;
+
+impl X3dh {
+    /// Generate a signed prekey for `identity`
+    pub fn generate_signed_prekey(identity: &Ed25519KeyPair, id: u32) -> CryptoResult<X3dhSignedPrekey> {
+        let secret = StaticSecret::random_from_rng(OsRng);
+        let public = X25519PublicKey::from(&secret);
+        let signature = Ed25519Crypto::sign(public.as_bytes(), identity.signing_key())?;
+
+        Ok(X3dhSignedPrekey {
+            id,
+            secret,
+            public,
+            signature: signature.try_into().unwrap(),
+        })
+    }
+
+    /// Generate `count` one-time prekeys with consecutive IDs starting at `first_id`
+    pub fn generate_one_time_prekeys(first_id: u32, count: u32) -> Vec<X3dhOneTimePrekey> {
+        (0..count)
+            .map(|i| X3dhOneTimePrekey {
+                id: first_id.wrapping_add(i),
+                secret: StaticSecret::random_from_rng(OsRng),
+            })
+            .collect()
+    }
+
+    /// Assemble the public bundle to publish for `identity`
+    pub fn create_bundle(identity: &Ed25519KeyPair, signed_prekey: &X3dhSignedPrekey, one_time_prekey: Option<&X3dhOneTimePrekey>) -> X3dhPrekeyBundle {
+        X3dhPrekeyBundle {
+            identity_key: identity.verifying_key().to_bytes(),
+            signed_prekey_id: signed_prekey.id,
+            signed_prekey: signed_prekey.public_key_bytes(),
+            signed_prekey_signature: signed_prekey.signature,
+            one_time_prekey: one_time_prekey.map(|opk| (opk.id, opk.public_key_bytes())),
+        }
+    }
+
+    /// Start a session with the owner of `bundle`, verifying the signed prekey first
+    pub fn initiate(identity: &Ed25519KeyPair, bundle: &X3dhPrekeyBundle) -> CryptoResult<(X3dhOutput, X3dhInitialMessage)> {
+        let responder_identity = Ed25519KeyPair::verifying_key_from_bytes(&bundle.identity_key)?;
+        if !Ed25519Crypto::verify(&bundle.signed_prekey, &bundle.signed_prekey_signature, &responder_identity)? {
+            return Err(CryptoError::VerificationFailed(X3DH_INVALID_SIGNATURE));
+        }
+
+        let identity_secret = identity_dh_secret(identity);
+        let ephemeral = StaticSecret::random_from_rng(OsRng);
+        let responder_dh_identity = X25519PublicKey::from(responder_identity.to_montgomery().to_bytes());
+        let signed_prekey = X25519PublicKey::from(bundle.signed_prekey);
+
+        let mut dh_outputs = vec![
+            diffie_hellman(&identity_secret, &signed_prekey)?,
+            diffie_hellman(&ephemeral, &responder_dh_identity)?,
+            diffie_hellman(&ephemeral, &signed_prekey)?,
+        ];
+        if let Some((_, one_time_prekey)) = bundle.one_time_prekey {
+            dh_outputs.push(diffie_hellman(&ephemeral, &X25519PublicKey::from(one_time_prekey))?);
+        }
+
+        let identity_key = identity.verifying_key().to_bytes();
+        let output = derive_output(&dh_outputs, &identity_key, &bundle.identity_key)?;
+        let message = X3dhInitialMessage {
+            identity_key,
+            ephemeral_key: X25519PublicKey::from(&ephemeral).to_bytes(),
+            signed_prekey_id: bundle.signed_prekey_id,
+            one_time_prekey_id: bundle.one_time_prekey.map(|(id, _)| id),
+        };
+
+        Ok((output, message))
+    }
+
+    /// Complete a session started by [`X3dh::initiate`]
+    ///
+    /// The one-time prekey named in `message` must be passed in and is consumed;
+    /// the caller must delete it from storage so it is never reused.
+    pub fn respond(identity: &Ed25519KeyPair, signed_prekey: &X3dhSignedPrekey, one_time_prekey: Option<X3dhOneTimePrekey>, message: &X3dhInitialMessage) -> CryptoResult<X3dhOutput> {
+        if message.signed_prekey_id != signed_prekey.id
+            || message.one_time_prekey_id != one_time_prekey.as_ref().map(|opk| opk.id)
+        {
+            return Err(CryptoError::InvalidKey(X3DH_PREKEY_MISMATCH));
+        }
+
+        let initiator_identity = Ed25519KeyPair::verifying_key_from_bytes(&message.identity_key)?;
+        let initiator_dh_identity = X25519PublicKey::from(initiator_identity.to_montgomery().to_bytes());
+        let ephemeral = X25519PublicKey::from(message.ephemeral_key);
+        let identity_secret = identity_dh_secret(identity);
+
+        let mut dh_outputs = vec![
+            diffie_hellman(&signed_prekey.secret, &initiator_dh_identity)?,
+            diffie_hellman(&identity_secret, &ephemeral)?,
+            diffie_hellman(&signed_prekey.secret, &ephemeral)?,
+        ];
+        if let Some(one_time_prekey) = &one_time_prekey {
+            dh_outputs.push(diffie_hellman(&one_time_prekey.secret, &ephemeral)?);
+        }
+
+        derive_output(&dh_outputs, &message.identity_key, &identity.verifying_key().to_bytes())
+    }
+}
+
+#[inline]
+fn identity_dh_secret(identity: &Ed25519KeyPair) -> StaticSecret {
+    StaticSecret::from(*Zeroizing::new(identity.signing_key().to_scalar_bytes()))
+}
+
+#[inline]
+fn diffie_hellman(secret: &StaticSecret, public: &X25519PublicKey) -> CryptoResult<Zeroizing<[u8; KEY_SIZE]>> {
+    let shared = secret.diffie_hellman(public);
+    if !shared.was_contributory() {
+        return Err(CryptoError::InvalidKey(X3DH_WEAK_KEY));
+    }
+    Ok(Zeroizing::new(shared.to_bytes()))
+}
+
+// SK = HKDF-SHA256(salt = 0^32, ikm = 0xFF^32 || DH1 || DH2 || DH3 [|| DH4], info)
+fn derive_output(dh_outputs: &[Zeroizing<[u8; KEY_SIZE]>], initiator_identity: &[u8; KEY_SIZE], responder_identity: &[u8; KEY_SIZE]) -> CryptoResult<X3dhOutput> {
+    let mut ikm = Zeroizing::new(vec![0xFF; KEY_SIZE]);
+    for dh in dh_outputs {
+        ikm.extend_from_slice(dh.as_slice());
+    }
+
+    let mut shared_key = vec![0u8; KEY_SIZE];
+    Hkdf::<Sha256>::new(Some(&[0u8; KEY_SIZE]), &ikm)
+        .expand(X3DH_INFO, &mut shared_key)
+        .map_err(|_| CryptoError::KeyDerivationFailed(HKDF_SHA256_FAILED))?;
+
+    let mut associated_data = Vec::with_capacity(KEY_SIZE * 2);
+    associated_data.extend_from_slice(initiator_identity);
+    associated_data.extend_from_slice(responder_identity);
+
+    Ok(X3dhOutput { shared_key: SecureKey::new(shared_key), associated_data })
+}
+
+#[inline]
+fn write_optional_prekey(out: &mut Vec<u8>, prekey: Option<(u32, Option<[u8; KEY_SIZE]>)>) {
+    match prekey {
+        Some((id, key)) => {
+            out.push(1);
+            out.extend_from_slice(&id.to_be_bytes());
+            if let Some(key) = key {
+                out.extend_from_slice(&key);
+            }
+        }
+        None => out.push(0),
+    }
+}
+
+#[inline]
+fn read_u32(bytes: &[u8]) -> u32 {
+    u32::from_be_bytes(bytes[..4].try_into().unwrap())
+}
+
+#[inline]
+fn read_key(bytes: &[u8]) -> [u8; KEY_SIZE] {
+    bytes[..KEY_SIZE].try_into().unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_x3dh_agreement() {
+        let alice = Ed25519KeyPair::generate().unwrap();
+        let bob = Ed25519KeyPair::generate().unwrap();
+
+        let signed_prekey = X3dh::generate_signed_prekey(&bob, 1).unwrap();
+        let mut one_time_prekeys = X3dh::generate_one_time_prekeys(100, 3);
+        assert_eq!(one_time_prekeys.len(), 3);
+
+        let bundle = X3dh::create_bundle(&bob, &signed_prekey, one_time_prekeys.first());
+        let bundle = X3dhPrekeyBundle::from_bytes(&bundle.to_bytes()).unwrap();
+
+        let (alice_out, message) = X3dh::initiate(&alice, &bundle).unwrap();
+        let message = X3dhInitialMessage::from_bytes(&message.to_bytes()).unwrap();
+        assert_eq!(message.one_time_prekey_id, Some(100));
+
+        let opk = one_time_prekeys.remove(0);
+        let bob_out = X3dh::respond(&bob, &signed_prekey, Some(opk), &message).unwrap();
+        assert_eq!(alice_out.shared_key.as_bytes(), bob_out.shared_key.as_bytes());
+        assert_eq!(alice_out.associated_data, bob_out.associated_data);
+        assert_eq!(alice_out.associated_data.len(), 64);
+
+        // Wrong or missing one-time prekey
+        assert!(X3dh::respond(&bob, &signed_prekey, None, &message).is_err());
+        assert!(X3dh::respond(&bob, &signed_prekey, Some(one_time_prekeys.remove(0)), &message).is_err());
+    }
+
+    #[test]
+    fn test_x3dh_without_one_time_prekey() {
+        let alice = Ed25519KeyPair::generate().unwrap();
+        let bob = Ed25519KeyPair::generate().unwrap();
+
+        // Prekeys survive a storage roundtrip
+        let signed_prekey = X3dh::generate_signed_prekey(&bob, 7).unwrap();
+        let signed_prekey = X3dhSignedPrekey::from_bytes(&signed_prekey.to_bytes()).unwrap();
+        let opk = X3dh::generate_one_time_prekeys(1, 1).remove(0);
+        assert_eq!(X3dhOneTimePrekey::from_bytes(&opk.to_bytes()).unwrap().public_key_bytes(), opk.public_key_bytes());
+
+        let bundle = X3dh::create_bundle(&bob, &signed_prekey, None);
+        let (alice_out, message) = X3dh::initiate(&alice, &bundle).unwrap();
+        let bob_out = X3dh::respond(&bob, &signed_prekey, None, &message).unwrap();
+        assert_eq!(alice_out.shared_key.as_bytes(), bob_out.shared_key.as_bytes());
+    }
+
+    #[test]
+    fn test_x3dh_rejects_forged_prekey() {
+        let alice = Ed25519KeyPair::generate().unwrap();
+        let bob = Ed25519KeyPair::generate().unwrap();
+        let mallory = Ed25519KeyPair::generate().unwrap();
+
+        // Signed prekey substituted by an attacker
+        let signed_prekey = X3dh::generate_signed_prekey(&bob, 1).unwrap();
+        let forged = X3dh::generate_signed_prekey(&mallory, 1).unwrap();
+        let mut bundle = X3dh::create_bundle(&bob, &signed_prekey, None);
+        bundle.signed_prekey = forged.public_key_bytes();
+        assert_eq!(X3dh::initiate(&alice, &bundle).unwrap_err(), CryptoError::VerificationFailed(X3DH_INVALID_SIGNATURE));
+
+        // Low-order prekey
+        let mut bundle = X3dh::create_bundle(&bob, &signed_prekey, None);
+        bundle.signed_prekey = [0u8; 32];
+        bundle.signed_prekey_signature = Ed25519Crypto::sign(&[0u8; 32], bob.signing_key()).unwrap().try_into().unwrap();
+        assert_eq!(X3dh::initiate(&alice, &bundle).unwrap_err(), CryptoError::InvalidKey(X3DH_WEAK_KEY));
+
+        assert!(X3dhPrekeyBundle::from_bytes(&[0u8; 10]).is_err());
+        assert!(X3dhInitialMessage::from_bytes(&[0u8; 10]).is_err());
+    }
+}
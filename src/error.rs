@@ -1,10 +1,24 @@
-use thiserror::Error;
+use alloc::boxed::Box;
+#[cfg(feature = "error-source")]
+use alloc::sync::Arc;
+use bech32::primitives::decode::{CheckedHrpstringError, ChecksumError};
 
 // Static error messages to avoid allocations
 pub const INVALID_KEY_LENGTH_AES: &str = "AES-256 key must be 32 bytes";
 pub const INVALID_KEY_LENGTH_CHACHA: &str = "ChaCha20 key must be 32 bytes";
 pub const INVALID_NONCE_LENGTH: &str = "Nonce must be 12 bytes";
+pub const NONCE_LENGTH_MISMATCH: &str = "Nonce has the wrong length for this algorithm";
 pub const CIPHERTEXT_TOO_SHORT: &str = "Ciphertext too short";
+pub const OUTPUT_BUFFER_TOO_SMALL: &str = "Output buffer is too small";
+pub const HEX_INVALID: &str = "Invalid hex encoding";
+pub const BASE64_INVALID: &str = "Invalid base64 encoding";
+pub const BASE32_INVALID: &str = "Invalid base32 encoding";
+pub const BASE58CHECK_TOO_SHORT: &str = "Base58Check data is shorter than its checksum";
+pub const BASE58CHECK_CHECKSUM_MISMATCH: &str = "Base58Check checksum does not match";
+pub const BECH32_INVALID_HRP: &str = "Invalid bech32 human-readable part";
+pub const BECH32_HRP_MISMATCH: &str = "Bech32 human-readable part does not match";
+pub const BECH32_CHECKSUM_MISMATCH: &str = "Bech32 checksum does not match";
+pub const BECH32_TOO_LONG: &str = "Bech32 string exceeds the maximum length";
 pub const ZERO_LENGTH_INPUT: &str = "Length cannot be zero";
 pub const ZERO_OUTPUT_LENGTH: &str = "Output length cannot be zero";
 pub const ZERO_ITERATIONS: &str = "Iterations cannot be zero";
@@ -13,15 +27,38 @@ pub const AES_GCM_ENCRYPTION_FAILED: &str = "AES-GCM encryption failed";
 pub const AES_GCM_DECRYPTION_FAILED: &str = "AES-GCM decryption failed";
 pub const CHACHA20_ENCRYPTION_FAILED: &str = "ChaCha20-Poly1305 encryption failed";
 pub const CHACHA20_DECRYPTION_FAILED: &str = "ChaCha20-Poly1305 decryption failed";
+pub const UNSUPPORTED_ALGORITHM: &str = "Unsupported algorithm";
+pub const ALGORITHM_NOT_AEAD: &str = "Algorithm is not an AEAD cipher";
+pub const ALGORITHM_NOT_SIGNATURE: &str = "Algorithm is not a signature scheme";
+pub const ENCRYPTOR_MISSING_KEY: &str = "Encryptor key not set";
+pub const ENCRYPTOR_NONCE_REUSED: &str = "Explicit nonce has already been used";
+pub const ENCRYPTOR_NONCE_EXHAUSTED: &str = "Nonce counter exhausted";
+pub const ENCRYPTOR_ENVELOPE_OPTIONS: &str = "Envelope framing does not support AAD or caller-chosen nonces";
+pub const ENCRYPTOR_KEY_MISMATCH: &str = "Key type does not match the encryptor algorithm";
+pub const ENCRYPTOR_ENVELOPE_KEK: &str = "Envelope KEK must be an AES-256 key";
+pub const LIMIT_PLAINTEXT_TOO_LARGE: &str = "Plaintext exceeds the configured size limit";
+pub const LIMIT_AAD_TOO_LARGE: &str = "Associated data exceeds the configured size limit";
+pub const LIMIT_KEY_USAGE_EXCEEDED: &str = "Key has encrypted its maximum number of messages and must be rotated";
 pub const INVALID_HMAC_KEY: &str = "Invalid HMAC key";
+pub const HMAC_KEY_TOO_SHORT: &str = "HMAC key must be at least 32 bytes";
+pub const BLAKE3_INVALID_KEY_LENGTH: &str = "BLAKE3 keyed hashing requires a 32-byte key";
 pub const ARGON2_DERIVATION_FAILED: &str = "Argon2 key derivation failed";
 pub const HKDF_SHA256_FAILED: &str = "HKDF-SHA256 failed";
 pub const HKDF_SHA512_FAILED: &str = "HKDF-SHA512 failed";
+pub const KBKDF_OUTPUT_TOO_LONG: &str = "KBKDF output length too large";
+pub const CONCAT_KDF_OUTPUT_TOO_LONG: &str = "Concat KDF output length too large";
+pub const X963_KDF_OUTPUT_TOO_LONG: &str = "X9.63 KDF output length too large";
 pub const RANDOM_GENERATION_FAILED: &str = "Failed to generate random bytes";
+pub const ZERO_RESEED_INTERVAL: &str = "Reseed interval cannot be zero";
+pub const EMPTY_CHARSET: &str = "Character set cannot be empty";
+pub const SAMPLE_SIZE_TOO_LARGE: &str = "Sample size cannot exceed population size";
+pub const MEMORY_LOCK_FAILED: &str = "Failed to lock key memory (check RLIMIT_MEMLOCK / working set size)";
+pub const PRIME_SIZE_OUT_OF_RANGE: &str = "Prime size must be between 16 and 8192 bits";
 pub const RSA_KEY_SIZE_TOO_SMALL: &str = "RSA key size must be at least 2048 bits";
 pub const RSA_KEY_GENERATION_FAILED: &str = "RSA key generation failed";
 pub const RSA_ENCRYPTION_FAILED: &str = "RSA encryption failed";
 pub const RSA_DECRYPTION_FAILED: &str = "RSA decryption failed";
+pub const RSA_SIGNING_FAILED: &str = "RSA signing failed";
 pub const PRIVATE_KEY_ENCODING_FAILED: &str = "Failed to encode private key";
 pub const PUBLIC_KEY_ENCODING_FAILED: &str = "Failed to encode public key";
 pub const PRIVATE_KEY_DECODING_FAILED: &str = "Failed to decode private key";
@@ -35,97 +72,558 @@ pub const ED25519_SIGNATURE_INVALID_SIZE: &str = "Ed25519 signature must be 64 b
 pub const INVALID_ED25519_PUBLIC_KEY: &str = "Invalid Ed25519 public key";
 pub const SALT_ENCODING_FAILED: &str = "Salt encoding failed";
 pub const ARGON2_HASHING_FAILED: &str = "Argon2 hashing failed";
+pub const ARGON2_INVALID_PARAMS: &str = "Invalid Argon2 parameters";
+pub const PBKDF2_HASHING_FAILED: &str = "PBKDF2 hashing failed";
+pub const SCRYPT_INVALID_PARAMS: &str = "Invalid scrypt parameters";
+pub const SCRYPT_DERIVATION_FAILED: &str = "scrypt key derivation failed";
+pub const SCRYPT_HASHING_FAILED: &str = "scrypt hashing failed";
+pub const PBKDF2_PARAMS_TOO_LARGE: &str = "PBKDF2 iteration count exceeds the allowed maximum";
+pub const SCRYPT_PARAMS_TOO_LARGE: &str = "scrypt parameters exceed the allowed maximum";
+pub const KDF_CANCELLED: &str = "Key derivation was cancelled";
 pub const INVALID_HASH_FORMAT: &str = "Invalid hash format";
+pub const KEYSTORE_INVALID_FORMAT: &str = "Invalid keystore format";
+pub const KEYSTORE_UNSUPPORTED_VERSION: &str = "Unsupported keystore version";
+pub const KEYSTORE_WRONG_PASSPHRASE: &str = "Wrong passphrase or corrupted keystore";
+pub const KEYSTORE_ENTRY_EXISTS: &str = "Keystore entry already exists";
+pub const KEYSTORE_ENTRY_NOT_FOUND: &str = "Keystore entry not found";
+pub const KEYSTORE_NAME_TOO_LONG: &str = "Keystore field exceeds 65535 bytes";
+pub const KEYSTORE_READ_FAILED: &str = "Failed to read keystore file";
+pub const KEYSTORE_WRITE_FAILED: &str = "Failed to write keystore file";
+pub const KEYSTORE_PARAMS_TOO_LARGE: &str = "Argon2 parameters in header exceed the allowed maximum";
+pub const MANAGED_KEY_EXPIRED: &str = "Key has expired and cannot be used for encryption";
+pub const MANAGED_KEY_UNSUPPORTED_ALGORITHM: &str = "Unsupported managed key algorithm";
+pub const MANAGED_KEY_INVALID_EXPIRY: &str = "Key expiry must be a Unix timestamp in the future";
+pub const KEYRING_NO_ACTIVE_KEY: &str = "Key ring has no active key";
+pub const KEYRING_UNKNOWN_KEY_ID: &str = "Key ID not found in key ring";
+pub const KEYRING_DUPLICATE_KEY_ID: &str = "Key ID already exists in key ring";
+pub const KEYRING_INVALID_KEY_ID: &str = "Key ID must be between 1 and 255 bytes";
+pub const KEYRING_INVALID_ENVELOPE: &str = "Invalid key ring envelope";
+pub const KEYRING_UNSUPPORTED_VERSION: &str = "Unsupported key ring envelope version";
+pub const KEY_EXPORT_INVALID_FORMAT: &str = "Invalid key export format";
+pub const KEY_EXPORT_UNSUPPORTED_VERSION: &str = "Unsupported key export version";
+pub const KEY_EXPORT_WRONG_PASSPHRASE: &str = "Wrong passphrase or corrupted key export";
+pub const KEY_EXPORT_TYPE_MISMATCH: &str = "Key export contains a different key type";
+pub const KEY_PROVIDER_UNSUPPORTED_OPERATION: &str = "Operation not supported by this key type";
+pub const PKCS11_MODULE_LOAD_FAILED: &str = "Failed to load PKCS#11 module";
+pub const PKCS11_INITIALIZE_FAILED: &str = "PKCS#11 module initialization failed";
+pub const PKCS11_SESSION_FAILED: &str = "Failed to open PKCS#11 session";
+pub const PKCS11_LOGIN_FAILED: &str = "PKCS#11 login failed";
+pub const PKCS11_KEY_NOT_FOUND: &str = "PKCS#11 key not found";
+pub const PKCS11_ATTRIBUTE_FAILED: &str = "Failed to read PKCS#11 key attribute";
+pub const PKCS11_UNSUPPORTED_KEY_TYPE: &str = "Unsupported PKCS#11 key type";
+pub const PKCS11_SIGN_FAILED: &str = "PKCS#11 signing failed";
+pub const PKCS11_DECRYPT_FAILED: &str = "PKCS#11 decryption failed";
+pub const KEYCHAIN_ENTRY_NOT_FOUND: &str = "Keychain entry not found";
+pub const KEYCHAIN_ACCESS_FAILED: &str = "Failed to access keychain";
+pub const KEYCHAIN_INVALID_LABEL: &str = "Invalid keychain label";
+pub const HARDWARE_KEY_INVALID_PUBLIC_KEY: &str = "Invalid P-256 public key from platform keystore";
+pub const HARDWARE_KEY_INVALID_SIGNATURE: &str = "Invalid ECDSA signature from platform keystore";
+pub const HARDWARE_KEY_NOT_FOUND: &str = "Hardware key not found";
+pub const HARDWARE_KEYSTORE_FAILED: &str = "Platform keystore operation failed";
+pub const ENVELOPE_INVALID_FORMAT: &str = "Invalid envelope format";
+pub const ENVELOPE_UNSUPPORTED_VERSION: &str = "Unsupported envelope version";
+pub const ENVELOPE_KEY_ID_TOO_LONG: &str = "Envelope key ID or wrapped key exceeds 65535 bytes";
+pub const ENVELOPE_KEK_MISMATCH: &str = "Envelope was sealed with a different kind of KEK";
+pub const ENVELOPE_UNWRAP_FAILED: &str = "Failed to unwrap envelope data key";
+pub const KMS_REQUEST_FAILED: &str = "Remote KMS request failed";
+pub const X3DH_INVALID_BUNDLE: &str = "Invalid X3DH prekey bundle";
+pub const X3DH_INVALID_MESSAGE: &str = "Invalid X3DH initial message";
+pub const X3DH_INVALID_SIGNATURE: &str = "X3DH signed prekey signature is invalid";
+pub const X3DH_PREKEY_MISMATCH: &str = "X3DH message references a different prekey";
+pub const X3DH_WEAK_KEY: &str = "X3DH public key is of low order";
+pub const DOUBLE_RATCHET_INVALID_MESSAGE: &str = "Invalid Double Ratchet message";
+pub const DOUBLE_RATCHET_INVALID_KEY: &str = "Invalid Double Ratchet key";
+pub const DOUBLE_RATCHET_NOT_READY: &str = "Session cannot send until the first message has been received";
+pub const DOUBLE_RATCHET_TOO_MANY_SKIPPED: &str = "Too many skipped messages";
+pub const DOUBLE_RATCHET_HEADER_DECRYPTION_FAILED: &str = "Failed to decrypt Double Ratchet message header";
+pub const JWT_INVALID_FORMAT: &str = "Invalid JWT format";
+pub const JWT_UNSUPPORTED_ALGORITHM: &str = "Unsupported JWT algorithm";
+pub const JWT_ALGORITHM_MISMATCH: &str = "JWT algorithm is not allowed or does not match the key";
+pub const JWT_INVALID_SIGNATURE: &str = "JWT signature is invalid";
+pub const JWT_EXPIRED: &str = "JWT has expired";
+pub const JWT_NOT_YET_VALID: &str = "JWT is not yet valid";
+pub const JWT_MISSING_EXPIRY: &str = "JWT is missing the exp claim";
+pub const JWT_INVALID_AUDIENCE: &str = "JWT audience is not accepted";
+pub const JWT_INVALID_ISSUER: &str = "JWT issuer is not accepted";
+pub const JWS_NOT_DETACHED: &str = "JWS payload is not detached";
+pub const JWS_UNSUPPORTED_CRITICAL: &str = "JWS has a critical header parameter that is not understood";
+pub const JWKS_INVALID: &str = "Invalid JWK Set";
+pub const JWKS_KEY_NOT_FOUND: &str = "No key in the JWK Set matches the token";
+pub const JWKS_KEY_AMBIGUOUS: &str = "Several keys in the JWK Set match a token without a kid";
+pub const PASETO_INVALID_FORMAT: &str = "Invalid PASETO token";
+pub const PASETO_INVALID_KEY: &str = "PASETO v4.local requires a 32-byte key";
+pub const PASETO_FOOTER_MISMATCH: &str = "PASETO footer does not match";
+pub const PASETO_AUTHENTICATION_FAILED: &str = "PASETO token failed authentication";
+pub const PASETO_INVALID_SIGNATURE: &str = "PASETO signature is invalid";
+pub const PASETO_EXPIRED: &str = "PASETO token has expired";
+pub const PASETO_NOT_YET_VALID: &str = "PASETO token is not yet valid";
+pub const PASETO_INVALID_CLAIM: &str = "PASETO time claim is not an RFC 3339 timestamp";
+pub const OTP_INVALID_SECRET: &str = "OTP secret must not be empty";
+pub const OTP_INVALID_DIGITS: &str = "OTP codes must have 6 to 8 digits";
+pub const OTP_INVALID_PERIOD: &str = "TOTP period must be greater than zero";
+pub const OTP_INVALID_BASE32: &str = "Invalid base32 OTP secret";
+pub const CMS_INVALID_FORMAT: &str = "Invalid CMS SignedData structure";
+pub const CMS_ENCODING_FAILED: &str = "Failed to encode CMS SignedData";
+pub const CMS_INVALID_CERTIFICATE: &str = "Invalid X.509 certificate";
+pub const CMS_CERTIFICATE_KEY_MISMATCH: &str = "Certificate public key does not match the signing key";
+pub const CMS_UNSUPPORTED_ALGORITHM: &str = "Unsupported CMS digest or signature algorithm";
+pub const CMS_NOT_DETACHED: &str = "CMS SignedData contains encapsulated content";
+pub const CMS_SIGNER_NOT_FOUND: &str = "CMS signer certificate not found";
+pub const CMS_DIGEST_MISMATCH: &str = "Content digest does not match the signed messageDigest";
+pub const CMS_INVALID_SIGNATURE: &str = "CMS signature is invalid";
+pub const WEBAUTHN_INVALID_PUBLIC_KEY: &str = "Invalid WebAuthn credential public key";
+pub const WEBAUTHN_UNSUPPORTED_ALGORITHM: &str = "Unsupported WebAuthn credential algorithm";
+pub const WEBAUTHN_INVALID_AUTHENTICATOR_DATA: &str = "Invalid WebAuthn authenticator data";
+pub const WEBAUTHN_INVALID_CLIENT_DATA: &str = "Invalid WebAuthn client data JSON";
+pub const WEBAUTHN_TYPE_MISMATCH: &str = "WebAuthn client data is not for an assertion";
+pub const WEBAUTHN_CHALLENGE_MISMATCH: &str = "WebAuthn challenge does not match";
+pub const WEBAUTHN_ORIGIN_MISMATCH: &str = "WebAuthn origin does not match";
+pub const WEBAUTHN_RP_ID_MISMATCH: &str = "WebAuthn RP ID hash does not match";
+pub const WEBAUTHN_USER_NOT_PRESENT: &str = "WebAuthn user presence flag is not set";
+pub const WEBAUTHN_USER_NOT_VERIFIED: &str = "WebAuthn user verification flag is not set";
+pub const WEBAUTHN_COUNTER_REGRESSION: &str = "WebAuthn signature counter did not increase";
+pub const WEBAUTHN_INVALID_SIGNATURE: &str = "WebAuthn assertion signature is invalid";
+pub const NACL_INVALID_PUBLIC_KEY: &str = "NaCl public key must be 32 bytes";
+pub const NACL_INVALID_SECRET_KEY: &str = "NaCl secret key must be 32 bytes";
+pub const SEALED_BOX_ENCRYPTION_FAILED: &str = "Sealed box encryption failed";
+pub const SEALED_BOX_DECRYPTION_FAILED: &str = "Sealed box decryption failed";
+pub const NACL_INVALID_NONCE: &str = "NaCl nonce must be 24 bytes";
+pub const CRYPTO_BOX_ENCRYPTION_FAILED: &str = "crypto_box encryption failed";
+pub const CRYPTO_BOX_DECRYPTION_FAILED: &str = "crypto_box decryption failed";
+pub const SECRETBOX_INVALID_KEY: &str = "Secretbox key must be 32 bytes";
+pub const SECRETBOX_ENCRYPTION_FAILED: &str = "crypto_secretbox encryption failed";
+pub const SECRETBOX_DECRYPTION_FAILED: &str = "crypto_secretbox decryption failed";
+pub const SECURE_MESSAGE_INVALID_FORMAT: &str = "Invalid secure message format";
+pub const SECURE_MESSAGE_UNSUPPORTED_VERSION: &str = "Unsupported secure message version";
+pub const SECURE_MESSAGE_WEAK_KEY: &str = "Secure message public key is of low order";
+pub const SECURE_MESSAGE_INVALID_SIGNATURE: &str = "Secure message sender signature is invalid";
+pub const SECURE_MESSAGE_SENDER_MISMATCH: &str = "Secure message was signed by a different sender";
+pub const STREAM_INVALID_HEADER: &str = "Encrypted stream header is missing or truncated";
+pub const STREAM_TRUNCATED: &str = "Encrypted stream is truncated";
+pub const STREAM_TOO_LONG: &str = "Encrypted stream exceeds the maximum number of segments";
+pub const KEY_FORMAT_INVALID_PEM: &str = "Invalid PEM key";
+pub const KEY_FORMAT_INVALID_DER: &str = "Invalid or unsupported DER key";
+pub const KEY_FORMAT_INVALID_JWK: &str = "Invalid or unsupported JWK";
+pub const KEY_FORMAT_NO_RAW_ENCODING: &str = "RSA keys have no raw encoding";
+pub const KEY_FORMAT_ENCODING_FAILED: &str = "Key encoding failed";
+pub const KEY_FORMAT_INVALID_COSE: &str = "Invalid or unsupported COSE_Key";
+pub const DID_KEY_INVALID: &str = "Invalid did:key identifier";
+pub const DID_KEY_UNSUPPORTED: &str = "did:key supports Ed25519 and P-256 public keys only";
+pub const SERDE_SECRET_EXCLUDED: &str = "Secret key material is only serialized through IncludeSecrets";
+pub const SERDE_INVALID_BYTES: &str = "Invalid base64url byte string";
+pub const ECDH_INVALID_PRIVATE_KEY: &str = "Invalid ECDH private key";
+pub const ECDH_INVALID_PUBLIC_KEY: &str = "Invalid ECDH public key";
+pub const ECDH_WEAK_KEY: &str = "ECDH public key is of low order";
+pub const ECIES_INVALID_CIPHERTEXT: &str = "ECIES ciphertext is too short";
+pub const FROST_INVALID_PARAMETERS: &str = "FROST requires 2 <= min_signers <= max_signers";
+pub const FROST_INVALID_IDENTIFIER: &str = "FROST identifier must be between 1 and max_signers";
+pub const FROST_INVALID_POINT: &str = "Invalid Ed25519 group element";
+pub const FROST_INVALID_SCALAR: &str = "Invalid Ed25519 scalar";
+pub const FROST_NOT_ENOUGH_SIGNERS: &str = "Not enough signers to meet the FROST threshold";
+pub const FROST_COMMITMENT_MISMATCH: &str = "Signing package does not contain this participant's commitments";
+pub const FROST_MISSING_SIGNATURE_SHARE: &str = "Signature shares do not match the signing commitments";
+pub const FROST_INVALID_SIGNATURE_SHARE: &str = "Invalid FROST signature share";
+pub const FROST_INVALID_PROOF_OF_KNOWLEDGE: &str = "Invalid DKG proof of knowledge";
+pub const FROST_INVALID_SECRET_SHARE: &str = "DKG secret share does not match its commitment";
+pub const FROST_INVALID_PACKAGE: &str = "Invalid FROST package encoding";
+pub const FROST_UNKNOWN_PARTICIPANT: &str = "Unknown or missing FROST participant";
+pub const TEST_VECTOR_INVALID_FORMAT: &str = "Invalid test vector JSON";
+pub const TEST_VECTOR_INVALID_INPUT: &str = "Test vector input is missing or malformed";
+pub const TEST_VECTOR_UNKNOWN_ALGORITHM: &str = "Unknown test vector algorithm";
+pub const WYCHEPROOF_INVALID_FORMAT: &str = "Invalid Wycheproof test vector file";
+pub const WYCHEPROOF_READ_FAILED: &str = "Failed to read Wycheproof test vector file";
+pub const ARMOR_INVALID_FORMAT: &str = "Invalid ASCII armor";
+pub const ARMOR_INVALID_HEADER: &str = "Invalid ASCII armor header";
+pub const ARMOR_UNSUPPORTED_VERSION: &str = "Unsupported ASCII armor version";
+pub const ARMOR_CHECKSUM_MISMATCH: &str = "ASCII armor checksum mismatch";
+pub const RSA_KEY_POOL_INVALID_CONFIG: &str = "Invalid RSA key pool configuration";
+pub const RSA_KEY_POOL_SPAWN_FAILED: &str = "Failed to start the RSA key pool thread";
+pub const ENCODER_INVALID_OUTPUT: &str = "Encoder produced non-ASCII output";
+pub const HASH_READ_FAILED: &str = "Failed to read hash input";
+pub const HASH_CHUNK_SIZE_ZERO: &str = "Hash read chunk size must be greater than zero";
+pub const STREAM_READ_FAILED: &str = "Failed to read stream input";
+pub const STREAM_WRITE_FAILED: &str = "Failed to write stream output";
+pub const RSA_WEAK_PUBLIC_EXPONENT: &str = "RSA public exponent must be odd and at least 65537";
+pub const ED25519_WEAK_PUBLIC_KEY: &str = "Ed25519 public key has small order";
+pub const NONCE_REUSED: &str = "Nonce already used with this key";
+pub const NONCE_GUARD_CAPACITY_ZERO: &str = "Nonce guard capacity must be greater than zero";
+pub const AES_GCM_PLAINTEXT_TOO_LARGE: &str = "Plaintext exceeds the AES-GCM limit of 2^36 - 32 bytes";
+pub const AES_GCM_AAD_TOO_LARGE: &str = "Associated data exceeds the AES-GCM limit of 2^36 bytes";
+pub const PEM_INVALID_BOUNDARY: &str = "PEM BEGIN/END lines are missing or malformed";
+pub const PEM_INVALID_BODY: &str = "PEM body is not valid base64";
+pub const PEM_HEADERS_UNSUPPORTED: &str = "PEM headers (such as legacy Proc-Type encryption) are not supported";
+pub const PEM_UNEXPECTED_LABEL: &str = "PEM label does not match the expected key type";
+pub const PEM_ENCRYPTED_KEY: &str = "PEM holds a password-protected private key; decrypt it first";
+pub const DER_INVALID_STRUCTURE: &str = "DER structure is malformed or has trailing data";
+pub const KEY_ALGORITHM_MISMATCH: &str = "Key algorithm does not match the expected key type";
+pub const SIGNATURE_CONTEXT_TOO_LONG: &str = "Signature context must be at most 255 bytes";
+pub const ECDSA_SIGNING_FAILED: &str = "ECDSA signing failed";
+pub const SECP256K1_INVALID_PRIVATE_KEY: &str = "Invalid secp256k1 private key";
+pub const SECP256K1_INVALID_PUBLIC_KEY: &str = "Invalid secp256k1 public key";
+pub const SECP256K1_SIGNING_FAILED: &str = "secp256k1 signing failed";
+pub const SECP256K1_RECOVERY_FAILED: &str = "Could not recover a public key from the secp256k1 signature";
+pub const ED25519_SIGNING_FAILED: &str = "Ed25519 signing failed";
+pub const BLIND_INDEX_INVALID_LENGTH: &str = "Blind index length must be between 1 and 32 bytes";
+pub const ENVELOPE_INVALID_PADDING: &str = "Invalid envelope padding";
+pub const ENVELOPE_COMPRESSION_UNSUPPORTED: &str = "Envelope compression requires the `compression` feature";
+pub const ENVELOPE_INVALID_COMPRESSION: &str = "Invalid compressed envelope payload";
+pub const ENVELOPE_STREAM_ALGORITHM: &str = "Streamed envelopes support AES-256-GCM only";
+pub const ENCRYPTOR_COMPRESSION_REQUIRES_ENVELOPE: &str = "Compression requires envelope framing";
+pub const WIPE_FILE_FAILED: &str = "Failed to wipe file";
+pub const SECRETSTREAM_INVALID_KEY: &str = "Secretstream key must be 32 bytes";
+pub const SECRETSTREAM_INVALID_HEADER: &str = "Secretstream header must be 24 bytes";
+pub const SECRETSTREAM_MESSAGE_TOO_LARGE: &str = "Secretstream message exceeds the maximum size";
+pub const SECRETSTREAM_DECRYPTION_FAILED: &str = "crypto_secretstream decryption failed";
+pub const SECRETSTREAM_INVALID_TAG: &str = "Secretstream message has an unknown tag";
+pub const OPENSSL_ENC_INVALID_FORMAT: &str = "Not an openssl enc file: missing Salted__ header or truncated";
+pub const OPENSSL_ENC_DECRYPTION_FAILED: &str = "openssl enc decryption failed: wrong password or key derivation";
 
 /// Unified error type for all cryptographic operations
-#[derive(Error, Debug, Clone, PartialEq)]
+///
+/// Every variant carries a static message, so creating and returning an error
+/// never allocates. [`CryptoError::with_context`] wraps an error with the
+/// operation and algorithm that raised it; with the `error-source` feature,
+/// errors converted from the underlying crates also keep that error as
+/// [`source()`](std::error::Error::source).
+///
+/// Equality compares [`code`](CryptoError::code) and
+/// [`message`](CryptoError::message) only, so an error with context equals
+/// the bare error it wraps.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 pub enum CryptoError {
-    #[error("Invalid input: {0}")]
+    /// Invalid input
     InvalidInput(&'static str),
 
-    #[error("Invalid key: {0}")]
+    /// Invalid key
     InvalidKey(&'static str),
 
-    #[error("Encryption failed: {0}")]
+    /// Encryption failed
     EncryptionFailed(&'static str),
 
-    #[error("Decryption failed: {0}")]
+    /// Decryption failed
     DecryptionFailed(&'static str),
 
-    #[error("Key generation failed: {0}")]
+    /// Key generation failed
     KeyGenerationFailed(&'static str),
 
-    #[error("Signature generation failed: {0}")]
+    /// Signature generation failed
     SignatureFailed(&'static str),
 
-    #[error("Signature verification failed: {0}")]
+    /// Signature verification failed
     VerificationFailed(&'static str),
 
-    #[error("Hash operation failed: {0}")]
+    /// Hash operation failed
     HashFailed(&'static str),
 
-    #[error("Key derivation failed: {0}")]
+    /// Key derivation failed
     KeyDerivationFailed(&'static str),
 
-    #[error("Random number generation failed: {0}")]
+    /// Random number generation failed
     RandomGenerationFailed(&'static str),
 
-    #[error("Encoding/Decoding failed: {0}")]
+    /// Encoding/Decoding failed
     EncodingFailed(&'static str),
 
-    #[error("Internal error: {0}")]
+    /// I/O operation failed
+    IoFailed(&'static str),
+
+    /// Internal error
     InternalError(&'static str),
+
+    /// Another error, with the operation and algorithm that raised it
+    Context(Box<ErrorContext>),
+}
+
+/// Where a [`CryptoError::Context`] error happened
+#[derive(Debug, Clone)]
+pub struct ErrorContext {
+    /// The wrapped error
+    pub error: CryptoError,
+    /// Operation that failed, e.g. `"decrypt"`; empty if unknown
+    pub operation: &'static str,
+    /// Algorithm in use, e.g. `"AES-256-GCM"`; empty if unknown
+    pub algorithm: &'static str,
+    #[cfg(feature = "error-source")]
+    source: Option<Arc<dyn std::error::Error + Send + Sync>>,
+}
+
+impl CryptoError {
+    /// Stable numeric code for bindings, equal to the C ABI status of the same name
+    ///
+    /// | code | variant |
+    /// |---|---|
+    /// | 3 | `InvalidInput` |
+    /// | 4 | `InvalidKey` |
+    /// | 5 | `EncryptionFailed` |
+    /// | 6 | `DecryptionFailed` |
+    /// | 7 | `KeyGenerationFailed` |
+    /// | 8 | `SignatureFailed` |
+    /// | 9 | `VerificationFailed` |
+    /// | 10 | `HashFailed` |
+    /// | 11 | `KeyDerivationFailed` |
+    /// | 12 | `RandomGenerationFailed` |
+    /// | 13 | `EncodingFailed` |
+    /// | 14 | `IoFailed` |
+    /// | 15 | `InternalError` |
+    ///
+    /// Codes 0-2 are reserved for binding-level statuses (success, null
+    /// pointer, buffer too small). Errors with context report the code of the
+    /// error they wrap.
+    pub fn code(&self) -> u32 {
+        match self {
+            CryptoError::InvalidInput(_) => 3,
+            CryptoError::InvalidKey(_) => 4,
+            CryptoError::EncryptionFailed(_) => 5,
+            CryptoError::DecryptionFailed(_) => 6,
+            CryptoError::KeyGenerationFailed(_) => 7,
+            CryptoError::SignatureFailed(_) => 8,
+            CryptoError::VerificationFailed(_) => 9,
+            CryptoError::HashFailed(_) => 10,
+            CryptoError::KeyDerivationFailed(_) => 11,
+            CryptoError::RandomGenerationFailed(_) => 12,
+            CryptoError::EncodingFailed(_) => 13,
+            CryptoError::IoFailed(_) => 14,
+            CryptoError::InternalError(_) => 15,
+            CryptoError::Context(context) => context.error.code(),
+        }
+    }
+
+    /// The static message, without the category prefix or context
+    pub fn message(&self) -> &'static str {
+        match self {
+            CryptoError::InvalidInput(message)
+            | CryptoError::InvalidKey(message)
+            | CryptoError::EncryptionFailed(message)
+            | CryptoError::DecryptionFailed(message)
+            | CryptoError::KeyGenerationFailed(message)
+            | CryptoError::SignatureFailed(message)
+            | CryptoError::VerificationFailed(message)
+            | CryptoError::HashFailed(message)
+            | CryptoError::KeyDerivationFailed(message)
+            | CryptoError::RandomGenerationFailed(message)
+            | CryptoError::EncodingFailed(message)
+            | CryptoError::IoFailed(message)
+            | CryptoError::InternalError(message) => message,
+            CryptoError::Context(context) => context.error.message(),
+        }
+    }
+
+    /// The innermost error, with any context removed
+    pub fn root(&self) -> &CryptoError {
+        match self {
+            CryptoError::Context(context) => context.error.root(),
+            error => error,
+        }
+    }
+
+    /// The operation and algorithm attached by [`CryptoError::with_context`], if any
+    #[inline]
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            CryptoError::Context(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Record the operation and algorithm that raised this error
+    ///
+    /// This boxes the error, so call it on the error path only:
+    ///
+    /// ```rust
+    /// use libsilver::prelude::*;
+    ///
+    /// let key = Aes256Key::from_bytes(&[0u8; 32])?;
+    /// let err = AesGcm::decrypt(&[0u8; 40], &key)
+    ///     .map_err(|err| err.with_context("decrypt", "AES-256-GCM"))
+    ///     .unwrap_err();
+    /// assert_eq!(err.code(), 6);
+    /// assert_eq!(err.context().unwrap().operation, "decrypt");
+    /// assert!(matches!(err.root(), CryptoError::DecryptionFailed(_)));
+    /// # Ok::<(), CryptoError>(())
+    /// ```
+    pub fn with_context(self, operation: &'static str, algorithm: &'static str) -> Self {
+        match self {
+            // Keep the source of an error converted from an underlying crate
+            CryptoError::Context(mut context) if context.operation.is_empty() && context.algorithm.is_empty() => {
+                context.operation = operation;
+                context.algorithm = algorithm;
+                CryptoError::Context(context)
+            }
+            error => CryptoError::Context(Box::new(ErrorContext {
+                error,
+                operation,
+                algorithm,
+                #[cfg(feature = "error-source")]
+                source: None,
+            })),
+        }
+    }
+
+    // Keep `source` as the underlying error with `error-source`; drop it otherwise
+    #[cfg(feature = "error-source")]
+    pub(crate) fn caused_by<E: std::error::Error + Send + Sync + 'static>(self, source: E) -> Self {
+        CryptoError::Context(Box::new(ErrorContext { error: self, operation: "", algorithm: "", source: Some(Arc::new(source)) }))
+    }
+
+    #[cfg(not(feature = "error-source"))]
+    #[inline]
+    pub(crate) fn caused_by<E>(self, _source: E) -> Self {
+        self
+    }
+}
+
+impl PartialEq for CryptoError {
+    fn eq(&self, other: &Self) -> bool {
+        self.code() == other.code() && self.message() == other.message()
+    }
+}
+
+impl core::fmt::Display for CryptoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CryptoError::InvalidInput(message) => write!(f, "Invalid input: {message}"),
+            CryptoError::InvalidKey(message) => write!(f, "Invalid key: {message}"),
+            CryptoError::EncryptionFailed(message) => write!(f, "Encryption failed: {message}"),
+            CryptoError::DecryptionFailed(message) => write!(f, "Decryption failed: {message}"),
+            CryptoError::KeyGenerationFailed(message) => write!(f, "Key generation failed: {message}"),
+            CryptoError::SignatureFailed(message) => write!(f, "Signature generation failed: {message}"),
+            CryptoError::VerificationFailed(message) => write!(f, "Signature verification failed: {message}"),
+            CryptoError::HashFailed(message) => write!(f, "Hash operation failed: {message}"),
+            CryptoError::KeyDerivationFailed(message) => write!(f, "Key derivation failed: {message}"),
+            CryptoError::RandomGenerationFailed(message) => write!(f, "Random number generation failed: {message}"),
+            CryptoError::EncodingFailed(message) => write!(f, "Encoding/Decoding failed: {message}"),
+            CryptoError::IoFailed(message) => write!(f, "I/O operation failed: {message}"),
+            CryptoError::InternalError(message) => write!(f, "Internal error: {message}"),
+            CryptoError::Context(context) => match (context.operation, context.algorithm) {
+                ("", "") => write!(f, "{}", context.error),
+                (operation, "") => write!(f, "{operation}: {}", context.error),
+                ("", algorithm) => write!(f, "{algorithm}: {}", context.error),
+                (operation, algorithm) => write!(f, "{operation} ({algorithm}): {}", context.error),
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CryptoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            #[cfg(feature = "error-source")]
+            CryptoError::Context(context) => match &context.source {
+                Some(source) => Some(source.as_ref()),
+                None => context.error.source(),
+            },
+            _ => None,
+        }
+    }
 }
 
 /// Result type alias for cryptographic operations
 pub type CryptoResult<T> = Result<T, CryptoError>;
 
+#[cfg(feature = "aes")]
 impl From<aes_gcm::Error> for CryptoError {
-    fn from(_err: aes_gcm::Error) -> Self {
-        CryptoError::EncryptionFailed(AES_GCM_ENCRYPTION_FAILED)
+    fn from(err: aes_gcm::Error) -> Self {
+        CryptoError::EncryptionFailed(AES_GCM_ENCRYPTION_FAILED).caused_by(err)
     }
 }
 
+#[cfg(feature = "rsa")]
 impl From<rsa::Error> for CryptoError {
-    fn from(_err: rsa::Error) -> Self {
-        CryptoError::EncryptionFailed(RSA_ENCRYPTION_FAILED)
+    fn from(err: rsa::Error) -> Self {
+        CryptoError::EncryptionFailed(RSA_ENCRYPTION_FAILED).caused_by(err)
     }
 }
 
+#[cfg(feature = "ed25519")]
 impl From<ed25519_dalek::SignatureError> for CryptoError {
-    fn from(_err: ed25519_dalek::SignatureError) -> Self {
-        CryptoError::SignatureFailed("Ed25519 signature failed")
+    fn from(err: ed25519_dalek::SignatureError) -> Self {
+        CryptoError::SignatureFailed("Ed25519 signature failed").caused_by(err)
     }
 }
 
+#[cfg(feature = "argon2")]
 impl From<argon2::Error> for CryptoError {
-    fn from(_err: argon2::Error) -> Self {
-        CryptoError::KeyDerivationFailed(ARGON2_DERIVATION_FAILED)
+    fn from(err: argon2::Error) -> Self {
+        CryptoError::KeyDerivationFailed(ARGON2_DERIVATION_FAILED).caused_by(err)
     }
 }
 
 impl From<hkdf::InvalidLength> for CryptoError {
-    fn from(_err: hkdf::InvalidLength) -> Self {
-        CryptoError::KeyDerivationFailed(HKDF_SHA256_FAILED)
+    fn from(err: hkdf::InvalidLength) -> Self {
+        CryptoError::KeyDerivationFailed(HKDF_SHA256_FAILED).caused_by(err)
     }
 }
 
 // Note: PBKDF2 doesn't expose InvalidLength in current version, so we handle errors manually
 
 impl From<getrandom::Error> for CryptoError {
-    fn from(_err: getrandom::Error) -> Self {
-        CryptoError::RandomGenerationFailed(RANDOM_GENERATION_FAILED)
+    fn from(err: getrandom::Error) -> Self {
+        CryptoError::RandomGenerationFailed(RANDOM_GENERATION_FAILED).caused_by(err)
     }
 }
 
 impl From<hex::FromHexError> for CryptoError {
-    fn from(_err: hex::FromHexError) -> Self {
-        CryptoError::EncodingFailed("Hex decoding error")
+    fn from(err: hex::FromHexError) -> Self {
+        CryptoError::EncodingFailed("Hex decoding error").caused_by(err)
     }
 }
 
 impl From<base64::DecodeError> for CryptoError {
-    fn from(_err: base64::DecodeError) -> Self {
-        CryptoError::EncodingFailed("Base64 decoding error")
+    fn from(err: base64::DecodeError) -> Self {
+        CryptoError::EncodingFailed("Base64 decoding error").caused_by(err)
+    }
+}
+
+impl From<CheckedHrpstringError> for CryptoError {
+    fn from(err: CheckedHrpstringError) -> Self {
+        match err {
+            CheckedHrpstringError::Checksum(ChecksumError::InvalidResidue) => CryptoError::VerificationFailed(BECH32_CHECKSUM_MISMATCH).caused_by(err),
+            _ => CryptoError::EncodingFailed("Bech32 decoding error").caused_by(err),
+        }
+    }
+}
+
+impl From<bs58::decode::Error> for CryptoError {
+    fn from(err: bs58::decode::Error) -> Self {
+        CryptoError::EncodingFailed("Base58 decoding error").caused_by(err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes_and_context() {
+        let err = CryptoError::DecryptionFailed(AES_GCM_DECRYPTION_FAILED);
+        assert_eq!(err.code(), 6);
+        assert_eq!(err.message(), AES_GCM_DECRYPTION_FAILED);
+        assert!(err.context().is_none());
+
+        let wrapped = err.clone().with_context("decrypt", "AES-256-GCM");
+        assert_eq!(wrapped, err);
+        assert_eq!(wrapped.code(), 6);
+        assert_eq!(wrapped.root(), &err);
+        assert_eq!(wrapped.to_string(), "decrypt (AES-256-GCM): Decryption failed: AES-GCM decryption failed");
+        assert_ne!(wrapped, CryptoError::EncryptionFailed(AES_GCM_DECRYPTION_FAILED));
+        assert_eq!(CryptoError::InternalError("").with_context("sign", "").to_string(), "sign: Internal error: ");
+    }
+
+    #[test]
+    #[cfg(feature = "error-source")]
+    fn test_error_source() {
+        use std::error::Error;
+
+        let err = CryptoError::from(hex::FromHexError::OddLength);
+        assert_eq!(err, CryptoError::EncodingFailed("Hex decoding error"));
+        assert_eq!(err.source().unwrap().to_string(), hex::FromHexError::OddLength.to_string());
+
+        // Adding context keeps the source
+        let err = err.with_context("decode", "hex");
+        assert_eq!(err.context().unwrap().operation, "decode");
+        assert!(err.source().is_some());
+        assert!(CryptoError::InvalidInput("").source().is_none());
     }
 }
@@ -5,13 +5,24 @@
 //!
 //! ## Features
 //!
-//! - **Symmetric Encryption**: AES-256-GCM, ChaCha20-Poly1305
-//! - **Asymmetric Encryption**: RSA-OAEP
-//! - **Digital Signatures**: ECDSA P-256, Ed25519
-//! - **Hashing**: SHA-256, SHA-512, BLAKE3, HMAC
-//! - **Key Derivation**: Argon2, HKDF, PBKDF2
+//! - **Symmetric Encryption**: AES-256-GCM (including chunked streaming), ChaCha20-Poly1305, NaCl secretbox; AES-GCM and ChaCha20-Poly1305 share the [`Aead`](core::Aead) trait and the [`Encryptor`](core::Encryptor) builder; typed keys such as [`Aes256Key`](core::Aes256Key) and sized [`Nonce`](core::Nonce)s turn key and nonce mix-ups into compile errors
+//! - **Asymmetric Encryption**: RSA-OAEP, X25519/P-256 ECDH and ECIES, libsodium-compatible sealed boxes and NaCl box
+//! - **Digital Signatures**: RSA-PSS and PKCS#1 v1.5, ECDSA P-256, Ed25519, FROST threshold Ed25519 (`threshold` feature), behind the [`Signer`](core::Signer) and [`Verifier`](core::Verifier) traits
+//! - **Hashing**: SHA-256, SHA-512, SHA-3, BLAKE3 (plain, keyed, key derivation), HMAC
+//! - **Key Derivation**: Argon2, scrypt, HKDF, PBKDF2, KBKDF, Concat KDF, X9.63 KDF
 //! - **Secure Random Generation**: OS-backed random number generation
-//! - **Memory Safety**: Automatic zeroization of sensitive data
+//! - **Key Formats**: PEM, DER, JWK and raw key conversions for RSA, ECDSA P-256 and Ed25519; Serde support with opt-in secrets (`serde` feature)
+//! - **Algorithm Registry**: [`Algorithm`](core::Algorithm) names and wire IDs that select ciphers and signature schemes at runtime
+//! - **Key Management**: Passphrase-protected keystore with key rotation and expiry, key rings for zero-downtime rotation, password-protected key export, PKCS#11 and mobile hardware key providers, OS keychain storage, envelope encryption with pluggable remote KMS (`kms` feature)
+//! - **Tokens**: JWT signing and validation (HS256/384/512, RS256, PS256, ES256, EdDSA), PASETO v4, HOTP/TOTP
+//! - **Document Signing**: Detached CMS / PKCS#7 SignedData
+//! - **WebAuthn**: Passkey assertion verification
+//! - **Secure Messaging**: X3DH key agreement, Double Ratchet sessions, sign-then-encrypt messages
+//! - **Memory Safety**: Automatic zeroization of sensitive data, optional memory-locked keys (`mlock` feature)
+//! - **Interoperability**: Known-answer test vectors exported as JSON so every binding can check byte-level compatibility with core
+//! - **Bindings**: One declaration per byte-level operation in [`api`], from which the C ABI and Node.js surfaces are generated
+//! - **`no_std`**: With `default-features = false` the crypto core builds on `alloc` alone; modules that need files, clocks or JSON require the default `std` feature
+//! - **Per-algorithm features**: `aes`, `chacha`, `rsa`, `ecdsa`, `ed25519`, `blake3`, `argon2` and `scrypt`, all enabled by the default `full` feature; drop the ones a WASM or mobile build does not need
 //!
 //! ## Quick Start
 //!
@@ -40,8 +51,24 @@
 //! # Ok::<(), libsilver::error::CryptoError>(())
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+// UniFFI's generated scaffolding compares function pointers
+#![cfg_attr(feature = "uniffi", allow(unpredictable_function_pointer_comparisons))]
+
+extern crate alloc;
+
+#[cfg(all(feature = "std", feature = "aes", feature = "chacha", feature = "ecdsa", feature = "ed25519", feature = "blake3", feature = "argon2"))]
+pub mod api;
 pub mod core;
 pub mod error;
+#[cfg(all(feature = "uniffi", feature = "rsa", feature = "ecdsa", feature = "ed25519"))]
+pub mod mobile;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+/// Hex, base64, base32, base58 and bech32 codecs, with constant-time variants for secrets
+pub use crate::core::encoding;
 
 // Re-export for convenience
 pub use error::{CryptoError, CryptoResult};
@@ -55,41 +82,48 @@ pub mod prelude {
 // High-level convenience functions
 pub mod crypto {
     use crate::prelude::*;
+    use alloc::vec::Vec;
 
     /// High-level symmetric encryption using AES-256-GCM
+    #[cfg(feature = "aes")]
     #[inline]
     pub fn encrypt_aes(plaintext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        AesGcm::encrypt(plaintext, key)
+        AesGcm::encrypt(plaintext, &Aes256Key::from_bytes(key)?)
     }
 
     /// High-level symmetric decryption using AES-256-GCM
+    #[cfg(feature = "aes")]
     #[inline]
     pub fn decrypt_aes(ciphertext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        AesGcm::decrypt(ciphertext, key)
+        AesGcm::decrypt(ciphertext, &Aes256Key::from_bytes(key)?)
     }
 
     /// High-level symmetric encryption using AES-256-GCM with additional authenticated data
+    #[cfg(feature = "aes")]
     #[inline]
     pub fn encrypt_aes_with_aad(plaintext: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
-        AesGcm::encrypt_with_aad(plaintext, key, aad)
+        AesGcm::encrypt_with_aad(plaintext, &Aes256Key::from_bytes(key)?, aad)
     }
 
     /// High-level symmetric decryption using AES-256-GCM with additional authenticated data
+    #[cfg(feature = "aes")]
     #[inline]
     pub fn decrypt_aes_with_aad(ciphertext: &[u8], key: &[u8], aad: &[u8]) -> CryptoResult<Vec<u8>> {
-        AesGcm::decrypt_with_aad(ciphertext, key, aad)
+        AesGcm::decrypt_with_aad(ciphertext, &Aes256Key::from_bytes(key)?, aad)
     }
 
     /// High-level symmetric encryption using ChaCha20-Poly1305
+    #[cfg(feature = "chacha")]
     #[inline]
     pub fn encrypt_chacha20(plaintext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        ChaCha20Poly1305Cipher::encrypt(plaintext, key)
+        ChaCha20Poly1305Cipher::encrypt(plaintext, &ChaChaKey::from_bytes(key)?)
     }
 
     /// High-level symmetric decryption using ChaCha20-Poly1305
+    #[cfg(feature = "chacha")]
     #[inline]
     pub fn decrypt_chacha20(ciphertext: &[u8], key: &[u8]) -> CryptoResult<Vec<u8>> {
-        ChaCha20Poly1305Cipher::decrypt(ciphertext, key)
+        ChaCha20Poly1305Cipher::decrypt(ciphertext, &ChaChaKey::from_bytes(key)?)
     }
 
     /// Generate a secure random key of specified length
@@ -105,19 +139,21 @@ pub mod crypto {
     }
 
     /// Hash data using BLAKE3
+    #[cfg(feature = "blake3")]
     #[inline]
     pub fn hash_blake3(data: &[u8]) -> CryptoResult<Vec<u8>> {
         Blake3Hash::hash(data)
     }
 
     /// Derive key from password using Argon2
+    #[cfg(feature = "argon2")]
     #[inline]
     pub fn derive_key_argon2(password: &[u8], salt: &[u8], length: usize) -> CryptoResult<Vec<u8>> {
         Argon2Kdf::derive_key(password, salt, length)
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "aes", feature = "ed25519", feature = "blake3", feature = "argon2"))]
 mod tests {
     use super::prelude::*;
 
@@ -0,0 +1,244 @@
+//! UniFFI surface for the Kotlin and Swift bindings
+//!
+//! [`PlatformKeystore`] is exported as a callback interface: the generated
+//! Kotlin and Swift code declares it, `AndroidKeystoreBackend` and
+//! `SecureEnclaveBackend` implement it, and [`HardwareKey`] runs every call
+//! through the same [`HardwareKeyProvider`] Rust applications use.
+
+use crate::core::hardware::{self, HardwareKeyProvider};
+use crate::core::provider::KeyProvider;
+use crate::error::{CryptoError, CryptoResult, HARDWARE_KEY_NOT_FOUND, HARDWARE_KEYSTORE_FAILED};
+use std::fmt;
+use std::sync::Arc;
+
+/// Error raised across the binding boundary
+#[derive(Debug, PartialEq, Eq, uniffi::Error)]
+pub enum KeystoreError {
+    /// No key is stored under the alias
+    NotFound { message: String },
+    /// The platform keystore failed, e.g. the user cancelled authentication
+    Platform { message: String },
+    /// libsilver rejected the input or the platform's output
+    Crypto { message: String },
+}
+
+impl fmt::Display for KeystoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KeystoreError::NotFound { message } | KeystoreError::Platform { message } | KeystoreError::Crypto { message } => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for KeystoreError {}
+
+impl From<uniffi::UnexpectedUniFFICallbackError> for KeystoreError {
+    fn from(err: uniffi::UnexpectedUniFFICallbackError) -> Self {
+        KeystoreError::Platform { message: err.reason }
+    }
+}
+
+impl From<CryptoError> for KeystoreError {
+    fn from(err: CryptoError) -> Self {
+        let message = err.to_string();
+        match err.root() {
+            CryptoError::InvalidKey(HARDWARE_KEY_NOT_FOUND) => KeystoreError::NotFound { message },
+            CryptoError::IoFailed(HARDWARE_KEYSTORE_FAILED) => KeystoreError::Platform { message },
+            _ => KeystoreError::Crypto { message },
+        }
+    }
+}
+
+/// Platform keystore implemented in Kotlin or Swift
+///
+/// Mirrors [`hardware::PlatformKeystore`]; see there for the accepted key and
+/// signature encodings.
+#[uniffi::export(callback_interface)]
+pub trait PlatformKeystore: Send + Sync {
+    /// Generate a new non-exportable P-256 key under `alias` and return its public key
+    fn generate(&self, alias: String) -> Result<Vec<u8>, KeystoreError>;
+
+    /// Get the public key of the key stored under `alias`
+    fn public_key(&self, alias: String) -> Result<Vec<u8>, KeystoreError>;
+
+    /// Sign `message` with ECDSA P-256 / SHA-256
+    fn sign(&self, alias: String, message: Vec<u8>) -> Result<Vec<u8>, KeystoreError>;
+
+    /// Perform ECDH with an uncompressed SEC1 peer public key, returning the shared x-coordinate
+    fn agree(&self, alias: String, peer_public_key: Vec<u8>) -> Result<Vec<u8>, KeystoreError>;
+
+    /// Delete the key stored under `alias`
+    fn delete(&self, alias: String) -> Result<(), KeystoreError>;
+}
+
+// Adapts a foreign keystore to the Rust trait `HardwareKeyProvider` uses
+struct ForeignKeystore(Box<dyn PlatformKeystore>);
+
+impl ForeignKeystore {
+    fn call<T>(result: Result<T, KeystoreError>) -> CryptoResult<T> {
+        result.map_err(|err| match err {
+            KeystoreError::NotFound { .. } => CryptoError::InvalidKey(HARDWARE_KEY_NOT_FOUND).caused_by(err),
+            _ => CryptoError::IoFailed(HARDWARE_KEYSTORE_FAILED).caused_by(err),
+        })
+    }
+}
+
+impl hardware::PlatformKeystore for ForeignKeystore {
+    fn generate(&self, alias: &str) -> CryptoResult<Vec<u8>> {
+        Self::call(self.0.generate(alias.to_string()))
+    }
+
+    fn public_key(&self, alias: &str) -> CryptoResult<Vec<u8>> {
+        Self::call(self.0.public_key(alias.to_string()))
+    }
+
+    fn sign(&self, alias: &str, message: &[u8]) -> CryptoResult<Vec<u8>> {
+        Self::call(self.0.sign(alias.to_string(), message.to_vec()))
+    }
+
+    fn agree(&self, alias: &str, peer_public_key: &[u8]) -> CryptoResult<Vec<u8>> {
+        Self::call(self.0.agree(alias.to_string(), peer_public_key.to_vec()))
+    }
+
+    fn delete(&self, alias: &str) -> CryptoResult<()> {
+        Self::call(self.0.delete(alias.to_string()))
+    }
+}
+
+/// Hardware-resident P-256 key, backed by a [`PlatformKeystore`] from the bindings
+///
+/// Signatures come back as `r || s` and public keys as uncompressed SEC1,
+/// whatever encoding the platform uses.
+#[derive(Debug, uniffi::Object)]
+pub struct HardwareKey {
+    provider: HardwareKeyProvider,
+}
+
+#[uniffi::export]
+impl HardwareKey {
+    /// Generate a new hardware key under `alias`
+    #[uniffi::constructor]
+    pub fn generate(keystore: Box<dyn PlatformKeystore>, alias: String) -> Result<Arc<Self>, KeystoreError> {
+        let provider = HardwareKeyProvider::generate(Arc::new(ForeignKeystore(keystore)), &alias)?;
+        Ok(Arc::new(Self { provider }))
+    }
+
+    /// Use an existing hardware key stored under `alias`
+    #[uniffi::constructor]
+    pub fn open(keystore: Box<dyn PlatformKeystore>, alias: String) -> Result<Arc<Self>, KeystoreError> {
+        let provider = HardwareKeyProvider::open(Arc::new(ForeignKeystore(keystore)), &alias)?;
+        Ok(Arc::new(Self { provider }))
+    }
+
+    /// Get the key alias
+    pub fn alias(&self) -> String {
+        self.provider.alias().to_string()
+    }
+
+    /// Get the uncompressed SEC1 public key
+    pub fn public_key(&self) -> Result<Vec<u8>, KeystoreError> {
+        Ok(self.provider.public_key()?)
+    }
+
+    /// Sign `message` with ECDSA P-256 / SHA-256, returning a low-S `r || s` signature
+    pub fn sign(&self, message: Vec<u8>) -> Result<Vec<u8>, KeystoreError> {
+        Ok(self.provider.sign(&message)?)
+    }
+
+    /// Derive an ECDH shared secret with a peer's SEC1-encoded P-256 public key
+    pub fn ecdh(&self, peer_public_key: Vec<u8>) -> Result<Vec<u8>, KeystoreError> {
+        Ok(self.provider.ecdh(&peer_public_key)?.as_bytes().to_vec())
+    }
+
+    /// Delete the hardware key
+    pub fn delete(&self) -> Result<(), KeystoreError> {
+        Ok(self.provider.clone().delete()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::asymmetric::{EcdsaCrypto, EcdsaKeyPair};
+    use p256::ecdsa::signature::Signer;
+    use p256::ecdsa::{Signature, SigningKey};
+    use p256::PublicKey;
+    use rand::rngs::OsRng;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    // Stands in for a Kotlin/Swift backend: raw SEC1 keys, DER signatures, NotFound for missing aliases
+    #[derive(Default)]
+    struct ForeignStub {
+        keys: Arc<Mutex<BTreeMap<String, SigningKey>>>,
+    }
+
+    impl ForeignStub {
+        fn key(&self, alias: &str) -> Result<SigningKey, KeystoreError> {
+            self.keys.lock().unwrap().get(alias).cloned()
+                .ok_or(KeystoreError::NotFound { message: alias.to_string() })
+        }
+    }
+
+    impl PlatformKeystore for ForeignStub {
+        fn generate(&self, alias: String) -> Result<Vec<u8>, KeystoreError> {
+            self.keys.lock().unwrap().insert(alias.clone(), SigningKey::random(&mut OsRng));
+            self.public_key(alias)
+        }
+
+        fn public_key(&self, alias: String) -> Result<Vec<u8>, KeystoreError> {
+            Ok(self.key(&alias)?.verifying_key().to_encoded_point(false).as_bytes().to_vec())
+        }
+
+        fn sign(&self, alias: String, message: Vec<u8>) -> Result<Vec<u8>, KeystoreError> {
+            let signature: Signature = self.key(&alias)?.sign(&message);
+            Ok(signature.to_der().as_bytes().to_vec())
+        }
+
+        fn agree(&self, alias: String, peer_public_key: Vec<u8>) -> Result<Vec<u8>, KeystoreError> {
+            let peer = PublicKey::from_sec1_bytes(&peer_public_key)
+                .map_err(|_| KeystoreError::Platform { message: "bad peer key".to_string() })?;
+            let shared = p256::ecdh::diffie_hellman(self.key(&alias)?.as_nonzero_scalar(), peer.as_affine());
+            Ok(shared.raw_secret_bytes().to_vec())
+        }
+
+        fn delete(&self, alias: String) -> Result<(), KeystoreError> {
+            self.keys.lock().unwrap().remove(&alias).map(|_| ())
+                .ok_or(KeystoreError::NotFound { message: alias })
+        }
+    }
+
+    #[test]
+    fn test_hardware_key_through_foreign_keystore() {
+        let keys = Arc::new(Mutex::new(BTreeMap::new()));
+        let key = HardwareKey::generate(Box::new(ForeignStub { keys: keys.clone() }), "device".to_string()).unwrap();
+        assert_eq!(key.alias(), "device");
+
+        let public_key = key.public_key().unwrap();
+        let signature = key.sign(b"challenge".to_vec()).unwrap();
+        assert_eq!(signature.len(), 64);
+        let verifying_key = EcdsaKeyPair::verifying_key_from_bytes(&public_key).unwrap();
+        assert!(EcdsaCrypto::verify(b"challenge", &signature, &verifying_key).unwrap());
+
+        let peer = p256::SecretKey::random(&mut OsRng);
+        let shared = key.ecdh(peer.public_key().to_sec1_bytes().to_vec()).unwrap();
+        let device_public = PublicKey::from_sec1_bytes(&public_key).unwrap();
+        let expected = p256::ecdh::diffie_hellman(peer.to_nonzero_scalar(), device_public.as_affine());
+        assert_eq!(shared, expected.raw_secret_bytes().to_vec());
+
+        let reopened = HardwareKey::open(Box::new(ForeignStub { keys }), "device".to_string()).unwrap();
+        assert_eq!(reopened.public_key().unwrap(), public_key);
+        key.delete().unwrap();
+    }
+
+    #[test]
+    fn test_keystore_errors_round_trip() {
+        let missing = HardwareKey::open(Box::new(ForeignStub::default()), "missing".to_string()).err();
+        assert!(matches!(missing, Some(KeystoreError::NotFound { .. })));
+
+        let key = HardwareKey::generate(Box::new(ForeignStub::default()), "device".to_string()).unwrap();
+        assert!(matches!(key.ecdh(vec![0x04; 10]), Err(KeystoreError::Crypto { .. })));
+        key.delete().unwrap();
+        assert!(matches!(key.sign(b"gone".to_vec()), Err(KeystoreError::NotFound { .. })));
+    }
+}
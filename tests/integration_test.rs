@@ -7,7 +7,7 @@ fn test_full_encryption_workflow() {
     let salt = SecureRandom::generate_salt().unwrap();
     
     // Derive encryption key from password
-    let encryption_key = Argon2Kdf::derive_key(password, &salt, 32).unwrap();
+    let encryption_key = Aes256Key::from_bytes(&Argon2Kdf::derive_key(password, &salt, 32).unwrap()).unwrap();
     
     // Original data
     let original_data = b"This is sensitive data that needs to be encrypted and authenticated.";
@@ -22,7 +22,7 @@ fn test_full_encryption_workflow() {
     assert_eq!(original_data, &decrypted_data[..]);
     
     // Test that wrong password fails
-    let wrong_key = Argon2Kdf::derive_key(b"wrong_password", &salt, 32).unwrap();
+    let wrong_key = Aes256Key::from_bytes(&Argon2Kdf::derive_key(b"wrong_password", &salt, 32).unwrap()).unwrap();
     let wrong_decrypt_result = AesGcm::decrypt(&ciphertext, &wrong_key);
     assert!(wrong_decrypt_result.is_err());
 }
@@ -65,7 +65,7 @@ fn test_hybrid_encryption_workflow() {
     let symmetric_key = AesGcm::generate_key().unwrap();
     
     // Encrypt the symmetric key with RSA
-    let encrypted_key = RsaCrypto::encrypt(&symmetric_key, rsa_keypair.public_key()).unwrap();
+    let encrypted_key = RsaCrypto::encrypt(symmetric_key.as_bytes(), rsa_keypair.public_key()).unwrap();
     
     // Encrypt the large data with AES
     let encrypted_data = AesGcm::encrypt(&large_data, &symmetric_key).unwrap();
@@ -73,10 +73,10 @@ fn test_hybrid_encryption_workflow() {
     // Decryption process
     // 1. Decrypt the symmetric key with RSA
     let decrypted_key = RsaCrypto::decrypt(&encrypted_key, rsa_keypair.private_key()).unwrap();
-    assert_eq!(symmetric_key, decrypted_key);
+    assert_eq!(symmetric_key.as_bytes(), decrypted_key);
     
     // 2. Decrypt the data with the symmetric key
-    let decrypted_data = AesGcm::decrypt(&encrypted_data, &decrypted_key).unwrap();
+    let decrypted_data = AesGcm::decrypt(&encrypted_data, &Aes256Key::from_bytes(&decrypted_key).unwrap()).unwrap();
     assert_eq!(large_data, decrypted_data);
 }
 
@@ -151,13 +151,13 @@ fn test_hash_consistency_and_verification() {
     assert_ne!(sha256_1, sha256_different);
     
     // Test HMAC consistency
-    let key = b"hmac_key";
-    let hmac1 = Hmac::sha256(key, data).unwrap();
-    let hmac2 = Hmac::sha256(key, data).unwrap();
+    let key = HmacKey::from_bytes(b"hmac_key_of_at_least_thirty_two!").unwrap();
+    let hmac1 = Hmac::sha256(&key, data).unwrap();
+    let hmac2 = Hmac::sha256(&key, data).unwrap();
     assert_eq!(hmac1, hmac2);
     
-    assert!(Hmac::verify_sha256(key, data, &hmac1).unwrap());
-    assert!(!Hmac::verify_sha256(b"wrong_key", data, &hmac1).unwrap());
+    assert!(Hmac::verify_sha256(&key, data, &hmac1).unwrap());
+    assert!(!Hmac::verify_sha256(&HmacKey::generate().unwrap(), data, &hmac1).unwrap());
 }
 
 #[test]
@@ -190,8 +190,10 @@ fn test_error_handling() {
     let short_key = vec![0u8; 16];
     let plaintext = b"test";
     
-    assert!(AesGcm::encrypt(plaintext, &short_key).is_err());
-    assert!(ChaCha20Poly1305Cipher::encrypt(plaintext, &short_key).is_err());
+    assert!(Aes256Key::from_bytes(&short_key).is_err());
+    assert!(ChaChaKey::from_bytes(&short_key).is_err());
+    assert!(AesGcm.encrypt(plaintext, &short_key).is_err());
+    assert!(ChaCha20Poly1305Cipher.encrypt(plaintext, &short_key).is_err());
     
     // Invalid ciphertext (too short)
     let valid_key = AesGcm::generate_key().unwrap();
@@ -208,3 +210,32 @@ fn test_error_handling() {
     
     assert!(Ed25519Crypto::verify(message, &short_signature, ed25519_keypair.verifying_key()).is_err());
 }
+
+#[test]
+#[cfg(feature = "std")]
+fn test_exported_test_vectors_are_current() {
+    // Bindings load test-vectors/kat.json; regenerate it with LIBSILVER_UPDATE_VECTORS=1
+    let path = concat!(env!("CARGO_MANIFEST_DIR"), "/test-vectors/kat.json");
+    let exported = TestVectors::export_json().unwrap() + "\n";
+    if std::env::var_os("LIBSILVER_UPDATE_VECTORS").is_some() {
+        std::fs::write(path, &exported).unwrap();
+    }
+
+    let committed = std::fs::read_to_string(path).unwrap();
+    assert_eq!(committed, exported, "test-vectors/kat.json is stale; rerun with LIBSILVER_UPDATE_VECTORS=1");
+    assert!(TestVectors::run_json(&committed).unwrap().is_empty());
+}
+
+#[test]
+#[cfg(feature = "wycheproof")]
+fn test_wycheproof_vectors() {
+    // The vendored files always run, from the crate's unit tests. Point
+    // WYCHEPROOF_DIR at a checkout's testvectors_v1/ to run every file.
+    let Some(dir) = std::env::var_os("WYCHEPROOF_DIR") else { return };
+    for file in WYCHEPROOF_FILES {
+        let path = std::path::Path::new(&dir).join(file);
+        assert!(path.exists(), "{file} is missing from WYCHEPROOF_DIR");
+        let report = Wycheproof::run_file(&path).unwrap();
+        assert!(report.failures.is_empty(), "{file}: {:#?}", report.failures);
+    }
+}